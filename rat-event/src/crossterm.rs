@@ -16,6 +16,15 @@ pub mod modifiers {
     pub const CONTROL_ALT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0110);
     pub const CONTROL_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0011);
     pub const ALT_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0101);
+    pub const CONTROL_ALT_SHIFT: KeyModifiers = KeyModifiers::from_bits_truncate(0b0000_0111);
+}
+
+/// A copy of the crossterm-KeyEventState.
+pub mod state {
+    use crossterm::event::KeyEventState;
+
+    pub const NONE: KeyEventState = KeyEventState::NONE;
+    pub const KEYPAD: KeyEventState = KeyEventState::KEYPAD;
 }
 
 /// This macro produces pattern matches for crossterm events.
@@ -58,6 +67,8 @@ pub mod modifiers {
 /// ```bnf
 /// "key" ("press"|"release") (modifier "-")? "'" char "'"
 /// "keycode" ("press"|"release") (modifier "-")? keycode
+/// "keycode" ("press"|"release") (modifier "-")? "Keypad(" keycode ")"
+/// "keycode" ("press"|"release") (modifier "-")? "Media(" mediakeycode ")"
 /// "mouse" ("down"|"up"|"drag") (modifier "-")? button "for" col_id "," row_id
 /// "mouse" "any" (modifier)? ("for" mouseevt)?
 /// "mouse" "moved" ("for" col_id "," row_id)?
@@ -72,18 +83,38 @@ pub mod modifiers {
 ///
 /// ```bnf
 /// modifier := <<one of the KeyModifiers's>> | "CONTROL_SHIFT" | "ALT_SHIFT"
+///             | "CONTROL_ALT" | "CONTROL_ALT_SHIFT"
 /// char := <<some character>>
 /// keycode := <<one of the defined KeyCode's>>
+/// mediakeycode := <<one of the defined MediaKeyCode's>>
 /// button := <<one of the defined MouseButton's>>
 /// ```
 ///
-/// __Caveat__
-/// `ct_event!(key press SHIFT-'f')` doesn't work. It must be
-/// `ct_event!(key press SHIFT-'F')` with capital F. This holds for
-/// any combination of SHIFT too.
+/// __Shift__
+/// For a char literal with a modifier that includes SHIFT,
+/// both the lower- and uppercase letter match. `SHIFT-'f'` and
+/// `CONTROL_SHIFT-'p'` work as expected.
+///
+/// An uppercase char literal without modifier matches with
+/// or without SHIFT. `key press 'P'` is fine.
+///
+/// This only works for literals, not for other patterns.
+///
+/// __Keypad__
+/// Keypad keys can only be distinguished if the kitty
+/// keyboard protocol is enabled. `Keypad(Enter)` matches only
+/// the keypad Enter, a plain `Enter` matches both.
 ///
 #[macro_export]
 macro_rules! ct_event {
+    (key press $keychar:tt) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::__ct_mod!(NONE $keychar),
+            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
     (key press $keychar:pat) => {
         crossterm::event::Event::Key(crossterm::event::KeyEvent {
             code: crossterm::event::KeyCode::Char($keychar),
@@ -99,6 +130,14 @@ macro_rules! ct_event {
             ..
         })
     };
+    (key press $mod:ident-$keychar:tt) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char($crate::__ct_char!($mod $keychar)),
+            modifiers: $crate::crossterm::modifiers::$mod,
+            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
     (key press $mod:ident-$keychar:pat) => {
         crossterm::event::Event::Key(crossterm::event::KeyEvent {
             code: crossterm::event::KeyCode::Char($keychar),
@@ -107,6 +146,14 @@ macro_rules! ct_event {
             ..
         })
     };
+    (key release $keychar:tt) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char($keychar),
+            modifiers: $crate::__ct_mod!(NONE $keychar),
+            kind: crossterm::event::KeyEventKind::Release,
+            ..
+        })
+    };
     (key release $keychar:pat) => {
         crossterm::event::Event::Key(crossterm::event::KeyEvent {
             code: crossterm::event::KeyCode::Char($keychar),
@@ -122,6 +169,14 @@ macro_rules! ct_event {
             ..
         })
     };
+    (key release $mod:ident-$keychar:tt) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Char($crate::__ct_char!($mod $keychar)),
+            modifiers: $crate::crossterm::modifiers::$mod,
+            kind: crossterm::event::KeyEventKind::Release,
+            ..
+        })
+    };
     (key release $mod:ident-$keychar:pat) => {
         crossterm::event::Event::Key(crossterm::event::KeyEvent {
             code: crossterm::event::KeyCode::Char($keychar),
@@ -147,6 +202,40 @@ macro_rules! ct_event {
             ..
         })
     };
+    (keycode press Media($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Media(crossterm::event::MediaKeyCode::$code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode press $mod:ident-Media($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Media(crossterm::event::MediaKeyCode::$code),
+            modifiers: $crate::crossterm::modifiers::$mod,
+            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            ..
+        })
+    };
+    (keycode press Keypad($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::$code,
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            state: $crate::crossterm::state::KEYPAD,
+            ..
+        })
+    };
+    (keycode press $mod:ident-Keypad($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::$code,
+            modifiers: $crate::crossterm::modifiers::$mod,
+            kind: crossterm::event::KeyEventKind::Press|crossterm::event::KeyEventKind::Repeat,
+            state: $crate::crossterm::state::KEYPAD,
+            ..
+        })
+    };
     (keycode press $code:ident) => {
         crossterm::event::Event::Key(crossterm::event::KeyEvent {
             code: crossterm::event::KeyCode::$code,
@@ -179,6 +268,40 @@ macro_rules! ct_event {
             ..
         })
     };
+    (keycode release Media($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Media(crossterm::event::MediaKeyCode::$code),
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Release,
+            ..
+        })
+    };
+    (keycode release $mod:ident-Media($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::Media(crossterm::event::MediaKeyCode::$code),
+            modifiers: $crate::crossterm::modifiers::$mod,
+            kind: crossterm::event::KeyEventKind::Release,
+            ..
+        })
+    };
+    (keycode release Keypad($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::$code,
+            modifiers: $crate::crossterm::modifiers::NONE,
+            kind: crossterm::event::KeyEventKind::Release,
+            state: $crate::crossterm::state::KEYPAD,
+            ..
+        })
+    };
+    (keycode release $mod:ident-Keypad($code:ident)) => {
+        crossterm::event::Event::Key(crossterm::event::KeyEvent {
+            code: crossterm::event::KeyCode::$code,
+            modifiers: $crate::crossterm::modifiers::$mod,
+            kind: crossterm::event::KeyEventKind::Release,
+            state: $crate::crossterm::state::KEYPAD,
+            ..
+        })
+    };
     (keycode release $code:ident) => {
         crossterm::event::Event::Key(crossterm::event::KeyEvent {
             code: crossterm::event::KeyCode::$code,
@@ -368,3 +491,277 @@ macro_rules! ct_event {
         crossterm::event::Event::Paste($value)
     };
 }
+
+/// Maps a char literal for a modifier that contains SHIFT.
+/// Letters match both the upper- and lowercase variant.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ct_char {
+    (SHIFT $keychar:tt) => {
+        $crate::__ct_shift_char!($keychar)
+    };
+    (CONTROL_SHIFT $keychar:tt) => {
+        $crate::__ct_shift_char!($keychar)
+    };
+    (ALT_SHIFT $keychar:tt) => {
+        $crate::__ct_shift_char!($keychar)
+    };
+    (CONTROL_ALT_SHIFT $keychar:tt) => {
+        $crate::__ct_shift_char!($keychar)
+    };
+    ($mod:ident $keychar:tt) => {
+        $keychar
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ct_shift_char {
+    ('a') => {
+        'a' | 'A'
+    };
+    ('A') => {
+        'a' | 'A'
+    };
+    ('b') => {
+        'b' | 'B'
+    };
+    ('B') => {
+        'b' | 'B'
+    };
+    ('c') => {
+        'c' | 'C'
+    };
+    ('C') => {
+        'c' | 'C'
+    };
+    ('d') => {
+        'd' | 'D'
+    };
+    ('D') => {
+        'd' | 'D'
+    };
+    ('e') => {
+        'e' | 'E'
+    };
+    ('E') => {
+        'e' | 'E'
+    };
+    ('f') => {
+        'f' | 'F'
+    };
+    ('F') => {
+        'f' | 'F'
+    };
+    ('g') => {
+        'g' | 'G'
+    };
+    ('G') => {
+        'g' | 'G'
+    };
+    ('h') => {
+        'h' | 'H'
+    };
+    ('H') => {
+        'h' | 'H'
+    };
+    ('i') => {
+        'i' | 'I'
+    };
+    ('I') => {
+        'i' | 'I'
+    };
+    ('j') => {
+        'j' | 'J'
+    };
+    ('J') => {
+        'j' | 'J'
+    };
+    ('k') => {
+        'k' | 'K'
+    };
+    ('K') => {
+        'k' | 'K'
+    };
+    ('l') => {
+        'l' | 'L'
+    };
+    ('L') => {
+        'l' | 'L'
+    };
+    ('m') => {
+        'm' | 'M'
+    };
+    ('M') => {
+        'm' | 'M'
+    };
+    ('n') => {
+        'n' | 'N'
+    };
+    ('N') => {
+        'n' | 'N'
+    };
+    ('o') => {
+        'o' | 'O'
+    };
+    ('O') => {
+        'o' | 'O'
+    };
+    ('p') => {
+        'p' | 'P'
+    };
+    ('P') => {
+        'p' | 'P'
+    };
+    ('q') => {
+        'q' | 'Q'
+    };
+    ('Q') => {
+        'q' | 'Q'
+    };
+    ('r') => {
+        'r' | 'R'
+    };
+    ('R') => {
+        'r' | 'R'
+    };
+    ('s') => {
+        's' | 'S'
+    };
+    ('S') => {
+        's' | 'S'
+    };
+    ('t') => {
+        't' | 'T'
+    };
+    ('T') => {
+        't' | 'T'
+    };
+    ('u') => {
+        'u' | 'U'
+    };
+    ('U') => {
+        'u' | 'U'
+    };
+    ('v') => {
+        'v' | 'V'
+    };
+    ('V') => {
+        'v' | 'V'
+    };
+    ('w') => {
+        'w' | 'W'
+    };
+    ('W') => {
+        'w' | 'W'
+    };
+    ('x') => {
+        'x' | 'X'
+    };
+    ('X') => {
+        'x' | 'X'
+    };
+    ('y') => {
+        'y' | 'Y'
+    };
+    ('Y') => {
+        'y' | 'Y'
+    };
+    ('z') => {
+        'z' | 'Z'
+    };
+    ('Z') => {
+        'z' | 'Z'
+    };
+    ($keychar:tt) => {
+        $keychar
+    };
+}
+
+/// Modifiers for a char literal without explicit modifier.
+/// Uppercase letters match with or without SHIFT.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ct_mod {
+    (NONE 'A') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'B') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'C') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'D') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'E') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'F') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'G') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'H') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'I') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'J') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'K') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'L') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'M') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'N') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'O') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'P') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'Q') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'R') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'S') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'T') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'U') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'V') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'W') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'X') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'Y') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE 'Z') => {
+        $crate::crossterm::modifiers::NONE | $crate::crossterm::modifiers::SHIFT
+    };
+    (NONE $keychar:tt) => {
+        $crate::crossterm::modifiers::NONE
+    };
+}
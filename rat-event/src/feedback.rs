@@ -0,0 +1,97 @@
+//!
+//! Side-channel for user feedback from event-handlers.
+//!
+//! Event-handlers deep inside a widget sometimes have something
+//! to tell the user ("clipboard not available") that is not an error,
+//! and they have no access to the status line. They can call
+//! [feedback] and the application picks up the messages later
+//! with [take_feedback].
+//!
+//! Nothing is collected until a sink is installed with
+//! [install_feedback]. The sink holds a limited number of messages,
+//! if it is full the oldest message is dropped.
+//!
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+/// Severity of a feedback message.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Plain information.
+    #[default]
+    Info,
+    /// Something didn't work out, but this is not an error.
+    Warning,
+}
+
+/// One feedback message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Feedback {
+    pub severity: Severity,
+    pub msg: Cow<'static, str>,
+}
+
+#[derive(Debug)]
+struct Sink {
+    capacity: usize,
+    msg: VecDeque<Feedback>,
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Install a sink for feedback messages for the current thread.
+///
+/// The sink holds at most `capacity` messages. Installing a sink
+/// again drops all collected messages.
+pub fn install_feedback(capacity: usize) {
+    SINK.with_borrow_mut(|v| {
+        *v = Some(Sink {
+            capacity: capacity.max(1),
+            msg: VecDeque::new(),
+        })
+    });
+}
+
+/// Remove the sink for the current thread.
+/// All further messages are discarded.
+pub fn uninstall_feedback() {
+    SINK.with_borrow_mut(|v| *v = None);
+}
+
+/// Is a feedback sink installed for the current thread?
+pub fn has_feedback_sink() -> bool {
+    SINK.with_borrow(|v| v.is_some())
+}
+
+/// Send a feedback message.
+///
+/// Does nothing if no sink is installed. The message is only
+/// converted if it will be kept.
+pub fn feedback(severity: Severity, msg: impl Into<Cow<'static, str>>) {
+    SINK.with_borrow_mut(|v| {
+        if let Some(sink) = v {
+            if sink.msg.len() >= sink.capacity {
+                sink.msg.pop_front();
+            }
+            sink.msg.push_back(Feedback {
+                severity,
+                msg: msg.into(),
+            });
+        }
+    });
+}
+
+/// Take all collected messages, oldest first.
+pub fn take_feedback() -> Vec<Feedback> {
+    SINK.with_borrow_mut(|v| {
+        if let Some(sink) = v {
+            sink.msg.drain(..).collect()
+        } else {
+            Vec::new()
+        }
+    })
+}
@@ -3,8 +3,14 @@
 use std::cmp::max;
 
 pub mod crossterm;
+mod feedback;
 pub mod util;
 
+pub use feedback::{
+    feedback, has_feedback_sink, install_feedback, take_feedback, uninstall_feedback, Feedback,
+    Severity,
+};
+
 /// All the regular and expected event-handling a widget can do.
 ///
 /// All the normal key-handling, maybe dependent on an internal
@@ -3,9 +3,13 @@
 //!
 
 use crate::Outcome;
-use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use ratatui::layout::{Position, Rect};
 use std::cell::Cell;
+use std::cmp::max;
+use std::rc::{Rc, Weak};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::time::SystemTime;
 
@@ -118,7 +122,255 @@ pub fn mouse_trap(event: &crossterm::event::Event, area: Rect) -> Outcome {
     }
 }
 
-/// Click states for double click.
+/// Axis for a [DragTracker].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DragAxis {
+    /// Track both axes.
+    #[default]
+    Both,
+    /// Only horizontal movement counts.
+    Horizontal,
+    /// Only vertical movement counts.
+    Vertical,
+}
+
+/// Result of [DragTracker::handle].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DragOutcome {
+    /// Nothing to do with a drag.
+    #[default]
+    Continue,
+    /// The mouse left the dead-zone and the drag starts.
+    /// Gives the position of the press.
+    ///
+    /// The current delta is available with [DragTracker::delta].
+    Started((u16, u16)),
+    /// The mouse moved while dragging.
+    /// Gives the delta to the origin and the current position.
+    Moved((i32, i32), (u16, u16)),
+    /// The button has been released after a drag.
+    /// Gives the final position.
+    Ended((u16, u16)),
+    /// The button has been released within the dead-zone.
+    /// This is a click, not a drag.
+    Clicked((u16, u16)),
+    /// The drag has been canceled with Esc, another mouse-button,
+    /// or the release of the button got lost.
+    Canceled,
+}
+
+/// State machine for mouse drags.
+///
+/// Feed it all events and it reports the progress of a drag
+/// with the left mouse button. A drag starts with a press inside
+/// one of the given areas and continues when the mouse leaves them.
+///
+/// ```rust ignore
+/// match state.drag.handle(state.area, event) {
+///     DragOutcome::Started(_) => {
+///         state.drag.start_value.set(state.offset);
+///         // ...
+///     }
+///     DragOutcome::Moved(delta, _) => {
+///         // apply delta to start_value
+///     }
+///     _ => {}
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DragTracker {
+    /// Movement of up to this many cells still counts as a click.
+    /// Defaults to 0.
+    pub dead_zone: u16,
+    /// Only track movement along this axis.
+    pub axis: DragAxis,
+    /// Modifiers required for the press.
+    pub modifiers: KeyModifiers,
+
+    /// Position of the press.
+    pub origin: Cell<Option<(u16, u16)>>,
+    /// Last position.
+    pub pos: Cell<(u16, u16)>,
+    /// Which of the areas got the press.
+    pub item: Cell<Option<usize>>,
+    /// The mouse left the dead-zone.
+    pub active: Cell<bool>,
+    /// Free for the widget: the value of whatever is dragged at
+    /// the start of the drag. Apply the delta to this value.
+    pub start_value: Cell<usize>,
+}
+
+impl Default for DragTracker {
+    fn default() -> Self {
+        Self {
+            dead_zone: 0,
+            axis: Default::default(),
+            modifiers: KeyModifiers::NONE,
+            origin: Default::default(),
+            pos: Default::default(),
+            item: Default::default(),
+            active: Default::default(),
+            start_value: Default::default(),
+        }
+    }
+}
+
+impl DragTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Movement of up to this many cells still counts as a click.
+    pub fn dead_zone(mut self, cells: u16) -> Self {
+        self.dead_zone = cells;
+        self
+    }
+
+    /// Only track movement along this axis.
+    pub fn axis(mut self, axis: DragAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Modifiers required for the press.
+    pub fn modifiers(mut self, modifiers: KeyModifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
+
+    /// The button is pressed, but the drag may not have started yet.
+    pub fn is_pressed(&self) -> bool {
+        self.origin.get().is_some()
+    }
+
+    /// A drag is active.
+    pub fn is_dragging(&self) -> bool {
+        self.active.get()
+    }
+
+    /// Which of the areas got the press.
+    pub fn item(&self) -> Option<usize> {
+        self.item.get()
+    }
+
+    /// Delta of the last position to the origin.
+    /// Respects the axis.
+    pub fn delta(&self) -> (i32, i32) {
+        let Some(origin) = self.origin.get() else {
+            return (0, 0);
+        };
+        let pos = self.pos.get();
+        let dx = pos.0 as i32 - origin.0 as i32;
+        let dy = pos.1 as i32 - origin.1 as i32;
+        match self.axis {
+            DragAxis::Both => (dx, dy),
+            DragAxis::Horizontal => (dx, 0),
+            DragAxis::Vertical => (0, dy),
+        }
+    }
+
+    /// Stop tracking without any result.
+    pub fn cancel(&self) {
+        self.origin.set(None);
+        self.item.set(None);
+        self.active.set(false);
+    }
+
+    /// Handle the events for a single area.
+    pub fn handle(&self, area: Rect, event: &crossterm::event::Event) -> DragOutcome {
+        self.handle_n(&[area], event)
+    }
+
+    /// Handle the events for a list of areas.
+    /// The press decides which of the areas is dragged.
+    pub fn handle_n(&self, areas: &[Rect], event: &crossterm::event::Event) -> DragOutcome {
+        match event {
+            crossterm::event::Event::Mouse(m) => self.handle_mouse(areas, m),
+            crossterm::event::Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                kind: KeyEventKind::Press,
+                ..
+            }) if self.active.get() => {
+                self.cancel();
+                DragOutcome::Canceled
+            }
+            _ => DragOutcome::Continue,
+        }
+    }
+
+    fn handle_mouse(&self, areas: &[Rect], event: &MouseEvent) -> DragOutcome {
+        let pos = (event.column, event.row);
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // the release got lost.
+                let was_active = self.active.get();
+                self.cancel();
+
+                if event.modifiers == self.modifiers {
+                    if let Some(n) = item_at(areas, pos.0, pos.1) {
+                        self.origin.set(Some(pos));
+                        self.pos.set(pos);
+                        self.item.set(Some(n));
+                    }
+                }
+
+                if was_active {
+                    DragOutcome::Canceled
+                } else {
+                    DragOutcome::Continue
+                }
+            }
+            MouseEventKind::Down(_) | MouseEventKind::Moved => {
+                let was_active = self.active.get();
+                self.cancel();
+                if was_active {
+                    DragOutcome::Canceled
+                } else {
+                    DragOutcome::Continue
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(origin) = self.origin.get() else {
+                    return DragOutcome::Continue;
+                };
+                self.pos.set(pos);
+                if self.active.get() {
+                    DragOutcome::Moved(self.delta(), pos)
+                } else if self.distance() > self.dead_zone as u32 {
+                    self.active.set(true);
+                    DragOutcome::Started(origin)
+                } else {
+                    DragOutcome::Continue
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                if self.origin.get().is_none() {
+                    return DragOutcome::Continue;
+                }
+                self.pos.set(pos);
+                let was_active = self.active.get();
+                let click = self.distance() <= self.dead_zone as u32;
+                self.cancel();
+                if was_active {
+                    DragOutcome::Ended(pos)
+                } else if click {
+                    DragOutcome::Clicked(pos)
+                } else {
+                    DragOutcome::Continue
+                }
+            }
+            _ => DragOutcome::Continue,
+        }
+    }
+
+    // distance from the origin, respecting the axis.
+    fn distance(&self) -> u32 {
+        let (dx, dy) = self.delta();
+        max(dx.unsigned_abs(), dy.unsigned_abs())
+    }
+}
+
+/// Click states for double and triple click.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Clicks {
     #[default]
@@ -126,6 +378,92 @@ pub enum Clicks {
     Down1(usize),
     Up1(usize),
     Down2(usize),
+    Up2(usize),
+    Down3(usize),
+    Up3(usize),
+}
+
+// State machine for double and triple clicks.
+//
+// Returns the number of clicks when the button is released.
+// Calling this again with the same event doesn't change the
+// state, so doubleclick() and tripleclick() can be used for
+// the same event.
+fn count_clicks(
+    time: &Cell<Option<SystemTime>>,
+    click: &Cell<Clicks>,
+    pos: &Cell<(u16, u16)>,
+    item: Option<usize>,
+    event: &MouseEvent,
+    filter: KeyModifiers,
+) -> u8 {
+    match event {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers,
+        } if *modifiers == filter => {
+            let Some(n) = item else {
+                time.set(None);
+                click.set(Clicks::None);
+                return 0;
+            };
+
+            let now = click_clock();
+            let same = pos.get() == (*column, *row);
+            let in_time = if let Some(time) = time.get() {
+                now.duration_since(time).unwrap_or_default().as_millis() as u32
+                    <= double_click_timeout()
+            } else {
+                true
+            };
+
+            let next = match click.get() {
+                Clicks::Down1(v) | Clicks::Down2(v) | Clicks::Down3(v) if v == n && same => {
+                    // seen this one already.
+                    return 0;
+                }
+                Clicks::Up1(v) if v == n && same && in_time => Clicks::Down2(n),
+                Clicks::Up2(v) if v == n && same && in_time => Clicks::Down3(n),
+                _ => Clicks::Down1(n),
+            };
+            time.set(Some(now));
+            pos.set((*column, *row));
+            click.set(next);
+            0
+        }
+        MouseEvent {
+            kind: MouseEventKind::Up(MouseButton::Left),
+            modifiers,
+            ..
+        } if *modifiers == filter => {
+            let Some(n) = item else {
+                click.set(Clicks::None);
+                return 0;
+            };
+
+            match click.get() {
+                Clicks::Down1(v) | Clicks::Up1(v) if v == n => {
+                    click.set(Clicks::Up1(n));
+                    1
+                }
+                Clicks::Down2(v) | Clicks::Up2(v) if v == n => {
+                    click.set(Clicks::Up2(n));
+                    2
+                }
+                Clicks::Down3(v) | Clicks::Up3(v) if v == n => {
+                    click.set(Clicks::Up3(n));
+                    3
+                }
+                _ => {
+                    click.set(Clicks::None);
+                    0
+                }
+            }
+        }
+        _ => 0,
+    }
 }
 
 /// Some state for mouse interactions.
@@ -138,6 +476,8 @@ pub struct MouseFlags {
     pub time: Cell<Option<SystemTime>>,
     /// Flag for the first down.
     pub click: Cell<Clicks>,
+    /// Position of the last click.
+    pub pos: Cell<(u16, u16)>,
     /// Drag enabled.
     pub drag: Cell<bool>,
     /// Hover detect.
@@ -317,71 +657,44 @@ impl MouseFlags {
     /// ```
     ///
     pub fn doubleclick2(&self, area: Rect, event: &MouseEvent, filter: KeyModifiers) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Down(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                if area.contains((*column, *row).into()) {
-                    match self.click.get() {
-                        Clicks::Up1(_) => {
-                            if let Some(time) = self.time.get() {
-                                if time.elapsed().unwrap_or_default().as_millis() as u32
-                                    > double_click_timeout()
-                                {
-                                    self.time.set(Some(SystemTime::now()));
-                                    self.click.set(Clicks::Down1(0));
-                                    break 'f false;
-                                }
-                            }
-                            self.click.set(Clicks::Down2(0));
-                        }
-                        _ => {
-                            self.time.set(Some(SystemTime::now()));
-                            self.click.set(Clicks::Down1(0));
-                        }
-                    }
-                    break 'f false;
-                } else {
-                    self.time.set(None);
-                    self.click.set(Clicks::None);
-                    break 'f false;
-                }
-            }
-            MouseEvent {
-                kind: MouseEventKind::Up(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                if area.contains((*column, *row).into()) {
-                    match self.click.get() {
-                        Clicks::Down1(_) => {
-                            self.click.set(Clicks::Up1(0));
-                            break 'f false;
-                        }
-                        Clicks::Up1(_) => {
-                            self.click.set(Clicks::None);
-                            break 'f true;
-                        }
-                        Clicks::Down2(_) => {
-                            self.click.set(Clicks::None);
-                            break 'f true;
-                        }
-                        _ => {
-                            self.click.set(Clicks::None);
-                            break 'f false;
-                        }
-                    }
-                } else {
-                    self.click.set(Clicks::None);
-                    break 'f false;
-                }
-            }
-            _ => false,
-        }
+        self.clicks2(area, event, filter) == 2
+    }
+
+    /// Checks for triple-click events.
+    ///
+    /// This can be used together with doubleclick() for the
+    /// same event.
+    ///
+    /// ```rust ignore
+    /// match event {
+    ///         Event::Mouse(m) if state.mouse.tripleclick(state.area, m) => {
+    ///             state.select_line();
+    ///             Outcome::Changed
+    ///         }
+    ///         Event::Mouse(m) if state.mouse.doubleclick(state.area, m) => {
+    ///             state.select_word();
+    ///             Outcome::Changed
+    ///         }
+    /// }
+    /// ```
+    pub fn tripleclick(&self, area: Rect, event: &MouseEvent) -> bool {
+        self.tripleclick2(area, event, KeyModifiers::NONE)
+    }
+
+    /// Checks for triple-click events.
+    /// This one can have an extra KeyModifiers.
+    pub fn tripleclick2(&self, area: Rect, event: &MouseEvent, filter: KeyModifiers) -> bool {
+        self.clicks2(area, event, filter) == 3
+    }
+
+    /// Counts the clicks at the same position.
+    ///
+    /// Returns the number of clicks when the mouse button is
+    /// released, 0 otherwise. Calling this more than once
+    /// with the same event returns the same result.
+    pub fn clicks2(&self, area: Rect, event: &MouseEvent, filter: KeyModifiers) -> u8 {
+        let item = area.contains((event.column, event.row).into()).then_some(0);
+        count_clicks(&self.time, &self.click, &self.pos, item, event, filter)
     }
 }
 
@@ -395,6 +708,8 @@ pub struct MouseFlagsN {
     pub time: Cell<Option<SystemTime>>,
     /// Flag for the first down.
     pub click: Cell<Clicks>,
+    /// Position of the last click.
+    pub pos: Cell<(u16, u16)>,
     /// Drag enabled.
     pub drag: Cell<Option<usize>>,
     /// Hover detect.
@@ -566,88 +881,126 @@ impl MouseFlagsN {
     /// ```
     ///
     pub fn doubleclick2(&self, areas: &[Rect], event: &MouseEvent, filter: KeyModifiers) -> bool {
-        match event {
-            MouseEvent {
-                kind: MouseEventKind::Down(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                for (n, area) in areas.iter().enumerate() {
-                    if area.contains((*column, *row).into()) {
-                        match self.click.get() {
-                            Clicks::Up1(v) => {
-                                if let Some(time) = self.time.get() {
-                                    if time.elapsed().unwrap_or_default().as_millis() as u32
-                                        > double_click_timeout()
-                                    {
-                                        self.time.set(Some(SystemTime::now()));
-                                        self.click.set(Clicks::Down1(n));
-                                        break 'f false;
-                                    }
-                                }
-                                if n == v {
-                                    self.click.set(Clicks::Down2(n));
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            _ => {
-                                self.time.set(Some(SystemTime::now()));
-                                self.click.set(Clicks::Down1(n));
-                            }
-                        }
-                        break 'f false;
-                    }
-                }
-                self.time.set(None);
-                self.click.set(Clicks::None);
-                false
-            }
-            MouseEvent {
-                kind: MouseEventKind::Up(MouseButton::Left),
-                column,
-                row,
-                modifiers,
-            } if *modifiers == filter => 'f: {
-                for (n, area) in areas.iter().enumerate() {
-                    if area.contains((*column, *row).into()) {
-                        match self.click.get() {
-                            Clicks::Down1(v) => {
-                                if n == v {
-                                    self.click.set(Clicks::Up1(v));
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            Clicks::Up1(v) => {
-                                if n == v {
-                                    self.click.set(Clicks::None);
-                                    break 'f true;
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            Clicks::Down2(v) => {
-                                if n == v {
-                                    self.click.set(Clicks::None);
-                                    break 'f true;
-                                } else {
-                                    self.click.set(Clicks::None);
-                                }
-                            }
-                            _ => {
-                                self.click.set(Clicks::None);
-                            }
-                        }
-                        break 'f false;
-                    }
-                }
-                self.click.set(Clicks::None);
-                false
+        self.clicks2(areas, event, filter) == 2
+    }
+
+    /// Checks for triple-click events.
+    ///
+    /// This can be used together with doubleclick() for the
+    /// same event.
+    pub fn tripleclick(&self, areas: &[Rect], event: &MouseEvent) -> bool {
+        self.tripleclick2(areas, event, KeyModifiers::NONE)
+    }
+
+    /// Checks for triple-click events.
+    /// This one can have an extra KeyModifiers.
+    pub fn tripleclick2(&self, areas: &[Rect], event: &MouseEvent, filter: KeyModifiers) -> bool {
+        self.clicks2(areas, event, filter) == 3
+    }
+
+    /// Counts the clicks at the same position.
+    ///
+    /// Returns the number of clicks when the mouse button is
+    /// released, 0 otherwise. All clicks must be in the same area.
+    /// Calling this more than once with the same event returns the
+    /// same result.
+    pub fn clicks2(&self, areas: &[Rect], event: &MouseEvent, filter: KeyModifiers) -> u8 {
+        let item = self.item_at(areas, event.column, event.row);
+        count_clicks(&self.time, &self.click, &self.pos, item, event, filter)
+    }
+}
+
+/// Tracks an interaction that waits for an answer from
+/// some background work.
+///
+/// Each [start](PendingOp::start) creates a new [OpToken] and
+/// makes all earlier tokens stale. When the answer arrives,
+/// the token tells if it is still wanted. Dropping the
+/// PendingOp makes all its tokens stale too.
+///
+/// Add this to your widget state. With rat-salsa use
+/// `AppContext::defer`, which drops stale answers automatically.
+///
+/// A clone starts without a pending operation.
+#[derive(Debug, Default)]
+pub struct PendingOp {
+    inner: Rc<PendingInner>,
+}
+
+#[derive(Debug, Default)]
+struct PendingInner {
+    current: Cell<u64>,
+    pending: Cell<bool>,
+}
+
+/// Identifies one operation of a [PendingOp].
+#[derive(Debug, Clone)]
+pub struct OpToken {
+    id: u64,
+    op: Weak<PendingInner>,
+}
+
+impl Clone for PendingOp {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl PendingOp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new operation. All earlier tokens become stale.
+    pub fn start(&self) -> OpToken {
+        let id = self.inner.current.get() + 1;
+        self.inner.current.set(id);
+        self.inner.pending.set(true);
+        OpToken {
+            id,
+            op: Rc::downgrade(&self.inner),
+        }
+    }
+
+    /// Abandon the current operation. All tokens become stale.
+    pub fn cancel(&self) {
+        self.inner.current.set(self.inner.current.get() + 1);
+        self.inner.pending.set(false);
+    }
+
+    /// Is the token the current operation?
+    pub fn is_current(&self, token: &OpToken) -> bool {
+        token.is_current()
+    }
+
+    /// Waiting for the answer of the current operation.
+    pub fn is_pending(&self) -> bool {
+        self.inner.pending.get()
+    }
+}
+
+impl OpToken {
+    /// The token is still the current operation of its [PendingOp].
+    pub fn is_current(&self) -> bool {
+        if let Some(op) = self.op.upgrade() {
+            op.current.get() == self.id
+        } else {
+            false
+        }
+    }
+
+    /// The answer for this token has arrived.
+    ///
+    /// Ends the pending state if this is still the current
+    /// operation. Returns false for a stale token.
+    pub fn finish(&self) -> bool {
+        if let Some(op) = self.op.upgrade() {
+            if op.current.get() == self.id {
+                op.pending.set(false);
+                return true;
             }
-            _ => false,
         }
+        false
     }
 }
 
@@ -665,6 +1018,22 @@ pub fn double_click_timeout() -> u32 {
     DOUBLE_CLICK.load(Ordering::Acquire)
 }
 
+thread_local! {
+    static CLICK_CLOCK: Cell<fn() -> SystemTime> = const { Cell::new(SystemTime::now) };
+}
+
+/// Replaces the clock used for double/triple-click detection.
+///
+/// This is set per thread and intended for testing.
+pub fn set_click_clock(clock: fn() -> SystemTime) {
+    CLICK_CLOCK.with(|v| v.set(clock));
+}
+
+/// Current time for double/triple-click detection.
+pub fn click_clock() -> SystemTime {
+    CLICK_CLOCK.with(|v| v.get())()
+}
+
 static ENHANCED_KEYS: AtomicBool = AtomicBool::new(false);
 
 /// Are enhanced keys available?
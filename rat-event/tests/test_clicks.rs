@@ -0,0 +1,122 @@
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::util::{set_click_clock, MouseFlags, MouseFlagsN};
+use ratatui::layout::Rect;
+use std::cell::Cell;
+use std::time::{Duration, SystemTime};
+
+thread_local! {
+    static NOW: Cell<u64> = const { Cell::new(0) };
+}
+
+fn now() -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_millis(NOW.with(|v| v.get()))
+}
+
+fn wait(millis: u64) {
+    NOW.with(|v| v.set(v.get() + millis));
+}
+
+fn down(column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+fn up(column: u16, row: u16) -> MouseEvent {
+    MouseEvent {
+        kind: MouseEventKind::Up(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    }
+}
+
+// click and return the click count on release.
+fn click(m: &MouseFlags, area: Rect, column: u16, row: u16) -> u8 {
+    assert_eq!(m.clicks2(area, &down(column, row), KeyModifiers::NONE), 0);
+    wait(20);
+    let r = m.clicks2(area, &up(column, row), KeyModifiers::NONE);
+    wait(20);
+    r
+}
+
+#[test]
+fn test_double_triple() {
+    set_click_clock(now);
+    let area = Rect::new(0, 0, 10, 10);
+    let m = MouseFlags::default();
+
+    assert_eq!(click(&m, area, 1, 1), 1);
+    assert_eq!(click(&m, area, 1, 1), 2);
+    assert_eq!(click(&m, area, 1, 1), 3);
+    // starts over
+    assert_eq!(click(&m, area, 1, 1), 1);
+    assert_eq!(click(&m, area, 1, 1), 2);
+}
+
+#[test]
+fn test_timeout() {
+    set_click_clock(now);
+    let area = Rect::new(0, 0, 10, 10);
+    let m = MouseFlags::default();
+
+    assert_eq!(click(&m, area, 1, 1), 1);
+    wait(500);
+    assert_eq!(click(&m, area, 1, 1), 1);
+    assert_eq!(click(&m, area, 1, 1), 2);
+    wait(500);
+    assert_eq!(click(&m, area, 1, 1), 1);
+}
+
+#[test]
+fn test_position() {
+    set_click_clock(now);
+    let area = Rect::new(0, 0, 10, 10);
+    let m = MouseFlags::default();
+
+    // fast clicks on different rows
+    assert_eq!(click(&m, area, 1, 1), 1);
+    assert_eq!(click(&m, area, 1, 2), 1);
+    assert_eq!(click(&m, area, 1, 3), 1);
+    assert_eq!(click(&m, area, 1, 3), 2);
+    // outside
+    assert_eq!(click(&m, area, 11, 3), 0);
+    assert_eq!(click(&m, area, 1, 3), 1);
+}
+
+#[test]
+fn test_repeated_query() {
+    set_click_clock(now);
+    let area = Rect::new(0, 0, 10, 10);
+    let m = MouseFlags::default();
+
+    click(&m, area, 1, 1);
+    click(&m, area, 1, 1);
+    assert!(!m.tripleclick(area, &down(1, 1)));
+    assert!(!m.doubleclick(area, &down(1, 1)));
+    assert!(m.tripleclick(area, &up(1, 1)));
+    assert!(!m.doubleclick(area, &up(1, 1)));
+
+    click(&m, area, 1, 1);
+    assert!(!m.tripleclick(area, &down(1, 1)));
+    assert!(!m.doubleclick(area, &down(1, 1)));
+    assert!(!m.tripleclick(area, &up(1, 1)));
+    assert!(m.doubleclick(area, &up(1, 1)));
+}
+
+#[test]
+fn test_areas() {
+    set_click_clock(now);
+    let areas = [Rect::new(0, 0, 10, 1), Rect::new(0, 1, 10, 1)];
+    let m = MouseFlagsN::default();
+
+    m.clicks2(&areas, &down(1, 0), KeyModifiers::NONE);
+    assert_eq!(m.clicks2(&areas, &up(1, 0), KeyModifiers::NONE), 1);
+    m.clicks2(&areas, &down(1, 1), KeyModifiers::NONE);
+    assert_eq!(m.clicks2(&areas, &up(1, 1), KeyModifiers::NONE), 1);
+    m.clicks2(&areas, &down(1, 1), KeyModifiers::NONE);
+    assert!(m.doubleclick(&areas, &up(1, 1)));
+}
@@ -0,0 +1,102 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode,
+};
+use rat_event::ct_event;
+
+fn key(code: KeyCode, modifiers: KeyModifiers, kind: KeyEventKind) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn keypad(code: KeyCode) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::KEYPAD,
+    })
+}
+
+fn classify(event: &Event) -> &'static str {
+    match event {
+        ct_event!(key press CONTROL_SHIFT-'p') => "ctrl-shift-p",
+        ct_event!(key press CONTROL_ALT_SHIFT-'x') => "ctrl-alt-shift-x",
+        ct_event!(key press SHIFT-'f') => "shift-f",
+        ct_event!(key press 'Q') => "Q",
+        ct_event!(key press CONTROL-'a') => "ctrl-a",
+        ct_event!(key press 'a'..='c') => "a-c",
+        ct_event!(key release SHIFT-'r') => "release-shift-r",
+        ct_event!(keycode press Keypad(Enter)) => "kp-enter",
+        ct_event!(keycode press Enter) => "enter",
+        ct_event!(keycode press CONTROL-Keypad(Home)) => "ctrl-kp-home",
+        ct_event!(keycode press Media(Play)) => "play",
+        ct_event!(keycode press SHIFT-Media(TrackNext)) => "shift-next",
+        ct_event!(key press c) => {
+            if *c == 'z' {
+                "z"
+            } else {
+                "char"
+            }
+        }
+        _ => "none",
+    }
+}
+
+#[test]
+fn test_keys() {
+    use KeyEventKind::{Press, Release, Repeat};
+    let c = KeyModifiers::CONTROL;
+    let s = KeyModifiers::SHIFT;
+    let a = KeyModifiers::ALT;
+    let n = KeyModifiers::NONE;
+
+    let table = [
+        (key(KeyCode::Char('P'), c | s, Press), "ctrl-shift-p"),
+        (key(KeyCode::Char('p'), c | s, Press), "ctrl-shift-p"),
+        (key(KeyCode::Char('P'), c | s, Repeat), "ctrl-shift-p"),
+        (key(KeyCode::Char('p'), c, Press), "none"),
+        (
+            key(KeyCode::Char('X'), c | a | s, Press),
+            "ctrl-alt-shift-x",
+        ),
+        (key(KeyCode::Char('F'), s, Press), "shift-f"),
+        (key(KeyCode::Char('f'), s, Press), "shift-f"),
+        (key(KeyCode::Char('Q'), n, Press), "Q"),
+        (key(KeyCode::Char('Q'), s, Press), "Q"),
+        (key(KeyCode::Char('q'), n, Press), "char"),
+        (key(KeyCode::Char('a'), c, Press), "ctrl-a"),
+        (key(KeyCode::Char('A'), c | s, Press), "none"),
+        (key(KeyCode::Char('b'), n, Press), "a-c"),
+        (key(KeyCode::Char('z'), n, Press), "z"),
+        (key(KeyCode::Char('R'), s, Release), "release-shift-r"),
+        (key(KeyCode::Char('R'), s, Press), "none"),
+        (keypad(KeyCode::Enter), "kp-enter"),
+        (key(KeyCode::Enter, n, Press), "enter"),
+        (
+            Event::Key(KeyEvent {
+                code: KeyCode::Home,
+                modifiers: c,
+                kind: Press,
+                state: KeyEventState::KEYPAD,
+            }),
+            "ctrl-kp-home",
+        ),
+        (key(KeyCode::Media(MediaKeyCode::Play), n, Press), "play"),
+        (
+            key(KeyCode::Media(MediaKeyCode::TrackNext), s, Press),
+            "shift-next",
+        ),
+        (
+            key(KeyCode::Media(MediaKeyCode::TrackNext), n, Press),
+            "none",
+        ),
+    ];
+
+    for (event, expect) in table {
+        assert_eq!(classify(&event), expect, "{:?}", event);
+    }
+}
@@ -0,0 +1,149 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::util::{DragAxis, DragOutcome, DragTracker};
+use ratatui::layout::Rect;
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn down(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::Down(MouseButton::Left), column, row)
+}
+
+fn drag(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::Drag(MouseButton::Left), column, row)
+}
+
+fn up(column: u16, row: u16) -> Event {
+    mouse(MouseEventKind::Up(MouseButton::Left), column, row)
+}
+
+const AREA: Rect = Rect::new(5, 5, 10, 10);
+
+#[test]
+fn test_drag() {
+    let d = DragTracker::new();
+
+    assert_eq!(d.handle(AREA, &down(6, 6)), DragOutcome::Continue);
+    assert!(d.is_pressed());
+    assert!(!d.is_dragging());
+    assert_eq!(d.item(), Some(0));
+
+    assert_eq!(d.handle(AREA, &drag(7, 6)), DragOutcome::Started((6, 6)));
+    assert!(d.is_dragging());
+    assert_eq!(d.delta(), (1, 0));
+
+    // outside the area
+    assert_eq!(
+        d.handle(AREA, &drag(20, 2)),
+        DragOutcome::Moved((14, -4), (20, 2))
+    );
+    assert_eq!(d.handle(AREA, &up(20, 3)), DragOutcome::Ended((20, 3)));
+    assert!(!d.is_pressed());
+    assert_eq!(d.item(), None);
+}
+
+#[test]
+fn test_outside() {
+    let d = DragTracker::new();
+    assert_eq!(d.handle(AREA, &down(1, 1)), DragOutcome::Continue);
+    assert!(!d.is_pressed());
+    assert_eq!(d.handle(AREA, &drag(6, 6)), DragOutcome::Continue);
+    assert_eq!(d.handle(AREA, &up(6, 6)), DragOutcome::Continue);
+}
+
+#[test]
+fn test_click() {
+    let d = DragTracker::new();
+    d.handle(AREA, &down(6, 6));
+    assert_eq!(d.handle(AREA, &up(6, 6)), DragOutcome::Clicked((6, 6)));
+
+    let d = DragTracker::new().dead_zone(1);
+    d.handle(AREA, &down(6, 6));
+    assert_eq!(d.handle(AREA, &drag(7, 7)), DragOutcome::Continue);
+    assert_eq!(d.handle(AREA, &up(7, 7)), DragOutcome::Clicked((7, 7)));
+
+    d.handle(AREA, &down(6, 6));
+    assert_eq!(d.handle(AREA, &drag(7, 7)), DragOutcome::Continue);
+    assert_eq!(d.handle(AREA, &drag(8, 7)), DragOutcome::Started((6, 6)));
+    assert_eq!(d.delta(), (2, 1));
+}
+
+#[test]
+fn test_axis() {
+    let d = DragTracker::new().axis(DragAxis::Vertical);
+    d.handle(AREA, &down(6, 6));
+    // horizontal movement doesn't count
+    assert_eq!(d.handle(AREA, &drag(9, 6)), DragOutcome::Continue);
+    assert_eq!(d.handle(AREA, &drag(9, 8)), DragOutcome::Started((6, 6)));
+    assert_eq!(d.delta(), (0, 2));
+}
+
+#[test]
+fn test_modifiers() {
+    let d = DragTracker::new().modifiers(KeyModifiers::CONTROL);
+    d.handle(AREA, &down(6, 6));
+    assert!(!d.is_pressed());
+
+    d.handle(
+        AREA,
+        &Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 6,
+            row: 6,
+            modifiers: KeyModifiers::CONTROL,
+        }),
+    );
+    assert!(d.is_pressed());
+    // only the press needs the modifiers.
+    assert_eq!(d.handle(AREA, &drag(7, 6)), DragOutcome::Started((6, 6)));
+}
+
+#[test]
+fn test_cancel() {
+    let d = DragTracker::new();
+    d.handle(AREA, &down(6, 6));
+    d.handle(AREA, &drag(8, 6));
+    assert_eq!(
+        d.handle(AREA, &Event::Key(KeyEvent::from(KeyCode::Esc))),
+        DragOutcome::Canceled
+    );
+    assert!(!d.is_pressed());
+    assert_eq!(d.handle(AREA, &drag(9, 6)), DragOutcome::Continue);
+
+    // lost release
+    d.handle(AREA, &down(6, 6));
+    d.handle(AREA, &drag(8, 6));
+    assert_eq!(
+        d.handle(AREA, &mouse(MouseEventKind::Moved, 8, 6)),
+        DragOutcome::Canceled
+    );
+
+    // other button
+    d.handle(AREA, &down(6, 6));
+    d.handle(AREA, &drag(8, 6));
+    assert_eq!(
+        d.handle(AREA, &mouse(MouseEventKind::Down(MouseButton::Right), 8, 6)),
+        DragOutcome::Canceled
+    );
+}
+
+#[test]
+fn test_items() {
+    let areas = [Rect::new(0, 0, 1, 10), Rect::new(5, 0, 1, 10)];
+    let d = DragTracker::new();
+    d.handle_n(&areas, &down(5, 3));
+    assert_eq!(d.item(), Some(1));
+    assert_eq!(
+        d.handle_n(&areas, &drag(3, 3)),
+        DragOutcome::Started((5, 3))
+    );
+    assert_eq!(d.delta(), (-2, 0));
+}
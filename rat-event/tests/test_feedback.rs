@@ -0,0 +1,39 @@
+use rat_event::{feedback, install_feedback, take_feedback, uninstall_feedback, Severity};
+
+#[test]
+fn test_no_sink() {
+    feedback(Severity::Info, "lost");
+    assert!(take_feedback().is_empty());
+}
+
+#[test]
+fn test_feedback() {
+    install_feedback(3);
+    feedback(Severity::Info, "one");
+    feedback(Severity::Warning, String::from("two"));
+
+    let f = take_feedback();
+    assert_eq!(f.len(), 2);
+    assert_eq!(f[0].severity, Severity::Info);
+    assert_eq!(f[0].msg, "one");
+    assert_eq!(f[1].severity, Severity::Warning);
+    assert_eq!(f[1].msg, "two");
+    assert!(take_feedback().is_empty());
+
+    uninstall_feedback();
+    feedback(Severity::Info, "lost");
+    assert!(take_feedback().is_empty());
+}
+
+#[test]
+fn test_bounded() {
+    install_feedback(2);
+    feedback(Severity::Info, "1");
+    feedback(Severity::Info, "2");
+    feedback(Severity::Info, "3");
+
+    let f = take_feedback();
+    assert_eq!(f.len(), 2);
+    assert_eq!(f[0].msg, "2");
+    assert_eq!(f[1].msg, "3");
+}
@@ -1,5 +1,5 @@
 use crate::focus::core::FocusCore;
-use crate::{FocusFlag, HasFocus, Navigation};
+use crate::{FocusFlag, HasFocus, Navigation, NavigationPolicy};
 pub use core::FocusBuilder;
 use rat_event::{ct_event, HandleEvent, MouseOnly, Outcome, Regular};
 use ratatui::layout::Rect;
@@ -329,6 +329,45 @@ impl Focus {
         }
     }
 
+    /// Focus the first navigable widget of the container
+    /// with the given flag.
+    pub fn focus_first_in(&self, container: &FocusFlag) -> bool {
+        focus_debug!(self.core.log, "focus first in {:?} ", container.name());
+        self.core.first_container(container)
+    }
+
+    /// Focus the last navigable widget of the container
+    /// with the given flag.
+    pub fn focus_last_in(&self, container: &FocusFlag) -> bool {
+        focus_debug!(self.core.log, "focus last in {:?} ", container.name());
+        self.core.last_container(container)
+    }
+
+    /// Navigation policy at the ends of the focus list.
+    pub fn policy(&self) -> NavigationPolicy {
+        self.core.policy
+    }
+
+    /// Navigation policy at the ends of the focus list.
+    pub fn set_policy(&mut self, policy: NavigationPolicy) {
+        self.core.policy = policy;
+    }
+
+    /// Focus follows the mouse after this many mouse-move events
+    /// over the same widget. None if disabled.
+    pub fn hover_focus(&self) -> Option<usize> {
+        self.core.hover_focus
+    }
+
+    /// Focus follows the mouse after this many mouse-move events
+    /// over the same widget. None disables this.
+    ///
+    /// See [FocusBuilder::hover_focus].
+    pub fn set_hover_focus(&mut self, moves: Option<usize>) {
+        self.core.hover_focus = moves;
+        self.core.hover.set(None);
+    }
+
     /// Clear the focus for all widgets.
     ///
     /// When navigating after this focus will restart somewhere,
@@ -403,7 +442,18 @@ impl Focus {
         }
     }
 
+    /// Remove all widgets whose focus-flag has been dropped.
+    ///
+    /// This is only useful for a Focus built with
+    /// [FocusBuilder::weak], otherwise all flags are kept alive.
+    pub fn purge_dead(&mut self) {
+        focus_debug!(self.core.log, "focus purge dead");
+        self.core.purge_dead();
+    }
+
     /// Debug destructuring.
+    ///
+    /// Dropped flags are returned as a default flag.
     #[allow(clippy::type_complexity)]
     pub fn clone_destruct(
         &self,
@@ -419,12 +469,108 @@ impl Focus {
 }
 
 mod core {
-    use crate::{Focus, FocusFlag, HasFocus, Navigation};
+    use crate::{Focus, FocusFlag, FocusFlagCore, HasFocus, Navigation, NavigationPolicy};
     use fxhash::FxBuildHasher;
     use ratatui::layout::Rect;
     use std::cell::Cell;
     use std::collections::HashSet;
+    use std::fmt::{Debug, Formatter};
     use std::ops::Range;
+    use std::rc::{Rc, Weak};
+
+    /// Weak reference to a FocusFlag.
+    ///
+    /// All accessors act as if the flag is cleared once
+    /// the flag has been dropped.
+    #[derive(Clone)]
+    struct WeakFlag(Weak<FocusFlagCore>);
+
+    impl WeakFlag {
+        fn new(flag: &FocusFlag) -> Self {
+            Self(Rc::downgrade(&flag.0))
+        }
+
+        fn upgrade(&self) -> Option<FocusFlag> {
+            self.0.upgrade().map(FocusFlag)
+        }
+
+        /// Flag still exists.
+        fn is_alive(&self) -> bool {
+            self.0.strong_count() > 0
+        }
+
+        /// Same as [FocusFlag::widget_id]. The weak reference keeps
+        /// the allocation, so this will not be reused.
+        fn widget_id(&self) -> usize {
+            self.0.as_ptr() as usize
+        }
+
+        fn name(&self) -> String {
+            match self.0.upgrade() {
+                Some(v) => v.name.to_string(),
+                None => "<dropped>".to_string(),
+            }
+        }
+
+        fn get(&self) -> bool {
+            self.0.upgrade().map(|v| v.focus.get()).unwrap_or_default()
+        }
+
+        fn set(&self, focus: bool) {
+            if let Some(v) = self.0.upgrade() {
+                v.focus.set(focus);
+            }
+        }
+
+        fn lost(&self) -> bool {
+            self.0.upgrade().map(|v| v.lost.get()).unwrap_or_default()
+        }
+
+        fn set_lost(&self, lost: bool) {
+            if let Some(v) = self.0.upgrade() {
+                v.lost.set(lost);
+            }
+        }
+
+        fn gained(&self) -> bool {
+            self.0.upgrade().map(|v| v.gained.get()).unwrap_or_default()
+        }
+
+        fn set_gained(&self, gained: bool) {
+            if let Some(v) = self.0.upgrade() {
+                v.gained.set(gained);
+            }
+        }
+
+        fn clear(&self) {
+            if let Some(v) = self.0.upgrade() {
+                v.focus.set(false);
+                v.lost.set(false);
+                v.gained.set(false);
+            }
+        }
+    }
+
+    impl Debug for WeakFlag {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self.upgrade() {
+                Some(v) => v.fmt(f),
+                None => f.write_str("FocusFlag <dropped>"),
+            }
+        }
+    }
+
+    impl PartialEq for WeakFlag {
+        fn eq(&self, other: &Self) -> bool {
+            Weak::ptr_eq(&self.0, &other.0)
+        }
+    }
+
+    impl PartialEq<FocusFlag> for WeakFlag {
+        fn eq(&self, other: &FocusFlag) -> bool {
+            self.widget_id() == other.widget_id()
+        }
+    }
 
     /// Builder for the Focus.
     #[derive(Debug, Default)]
@@ -433,6 +579,14 @@ mod core {
 
         log: Cell<bool>,
 
+        // only weak references to the flags.
+        weak: bool,
+        // navigation at the ends.
+        policy: NavigationPolicy,
+        // focus follows the mouse after n moves.
+        hover_focus: Option<usize>,
+        hover: Option<(usize, usize)>,
+
         // base z value.
         // starting a container adds the z-value of the container
         // to the z_base. closing the container subtracts from the
@@ -444,12 +598,13 @@ mod core {
 
         // new core
         focus_ids: HashSet<usize, FxBuildHasher>,
-        focus_flags: Vec<FocusFlag>,
+        focus_flags: Vec<WeakFlag>,
         duplicate: Vec<bool>,
         areas: Vec<(Rect, u16)>,
         navigable: Vec<Navigation>,
         container_ids: HashSet<usize, FxBuildHasher>,
         containers: Vec<(Container, Range<usize>)>,
+        keep_alive: Vec<FocusFlag>,
     }
 
     impl FocusBuilder {
@@ -459,6 +614,10 @@ mod core {
                 last.last.clear();
 
                 Self {
+                    weak: last.core.weak,
+                    policy: last.core.policy,
+                    hover_focus: last.core.hover_focus,
+                    hover: last.core.hover.get(),
                     last: last.core,
                     log: Default::default(),
                     z_base: 0,
@@ -469,11 +628,16 @@ mod core {
                     navigable: last.last.navigable,
                     container_ids: last.last.container_ids,
                     containers: last.last.containers,
+                    keep_alive: last.last.keep_alive,
                 }
             } else {
                 Self {
                     last: FocusCore::default(),
                     log: Default::default(),
+                    weak: false,
+                    policy: Default::default(),
+                    hover_focus: None,
+                    hover: None,
                     z_base: Default::default(),
                     focus_ids: Default::default(),
                     focus_flags: Default::default(),
@@ -482,6 +646,7 @@ mod core {
                     navigable: Default::default(),
                     container_ids: Default::default(),
                     containers: Default::default(),
+                    keep_alive: Default::default(),
                 }
             }
         }
@@ -515,12 +680,94 @@ mod core {
             b.build()
         }
 
+        /// Shortcut for building the focus for multiple
+        /// top-level containers.
+        ///
+        /// The parts are added in order.
+        pub fn build_for_parts(parts: &[&dyn HasFocus]) -> Focus {
+            let mut b = FocusBuilder::new(None);
+            b.widgets(parts.iter().copied());
+            b.build()
+        }
+
+        /// Shortcut for rebuilding the focus for multiple
+        /// top-level containers.
+        ///
+        /// See [rebuild_for](FocusBuilder::rebuild_for)
+        pub fn rebuild_for_parts(parts: &[&dyn HasFocus], old: Option<Focus>) -> Focus {
+            let mut b = FocusBuilder::new(old);
+            b.widgets(parts.iter().copied());
+            b.build()
+        }
+
         /// Do some logging of the build.
         pub fn enable_log(self) -> Self {
             self.log.set(true);
             self
         }
 
+        /// Only keep weak references to the focus-flags.
+        ///
+        /// Widgets whose state has been dropped are skipped by
+        /// next(), prev() and focus_at() instead of being kept
+        /// alive by the Focus. Use [Focus::purge_dead] to remove them
+        /// completely.
+        ///
+        /// A rebuild with the old Focus keeps this setting.
+        pub fn weak(mut self) -> Self {
+            self.weak = true;
+            self
+        }
+
+        /// Navigation policy at the ends of the focus list.
+        ///
+        /// Default is [NavigationPolicy::Wrap].
+        /// A rebuild with the old Focus keeps this setting.
+        pub fn policy(mut self, policy: NavigationPolicy) -> Self {
+            self.policy = policy;
+            self
+        }
+
+        /// Focus follows the mouse.
+        ///
+        /// Moving the mouse over a widget focuses it, once the
+        /// pointer has rested on the same widget for the given
+        /// number of consecutive mouse-move events. This avoids
+        /// thrashing the focus when the mouse skims across widgets.
+        ///
+        /// Widgets with [Navigation::None] are skipped, as with clicks.
+        /// A rebuild with the old Focus keeps this setting.
+        pub fn hover_focus(mut self, moves: usize) -> Self {
+            self.hover_focus = Some(moves);
+            self
+        }
+
+        /// Navigation policy at the ends of a container.
+        ///
+        /// Call this after [start](Self::start) of the container,
+        /// e.g. in the build() function of the container.
+        ///
+        /// Default is [NavigationPolicy::Escalate].
+        pub fn container_policy(
+            &mut self,
+            container_flag: &FocusFlag,
+            policy: NavigationPolicy,
+        ) -> &mut Self {
+            focus_debug!(
+                self.log,
+                "container policy {:?} {:?}",
+                container_flag,
+                policy
+            );
+            for (c, _) in self.containers.iter_mut().rev() {
+                if c.container_flag == *container_flag {
+                    c.policy = policy;
+                    break;
+                }
+            }
+            self
+        }
+
         /// Add a widget by calling its build function.
         /// The build function of the HasFocus trait can
         ///
@@ -530,17 +777,67 @@ mod core {
             self
         }
 
+        /// Add the widget only if the condition holds.
+        ///
+        /// Use this for widgets that are not always visible.
+        /// The order of the other widgets stays the same.
+        pub fn widget_if(&mut self, cond: bool, widget: &dyn HasFocus) -> &mut Self {
+            if cond {
+                widget.build(self);
+            }
+            self
+        }
+
         /// Add a bunch of widget.
         ///
+        /// Accepts anything that iterates over widgets.
+        /// For different widget types use an array with
+        /// `[&a as &dyn HasFocus, &b, &c]`.
+        ///
         /// The widget is added to all open containers.
         #[inline]
-        pub fn widgets<const N: usize>(&mut self, widgets: [&dyn HasFocus; N]) -> &mut Self {
+        pub fn widgets<'a, W>(&mut self, widgets: impl IntoIterator<Item = &'a W>) -> &mut Self
+        where
+            W: HasFocus + ?Sized + 'a,
+        {
             for widget in widgets {
                 widget.build(self);
             }
             self
         }
 
+        /// Add a container widget and its content.
+        ///
+        /// Calls [start](Self::start), then the closure
+        /// and ends the container afterwards.
+        pub fn container(
+            &mut self,
+            container: &dyn HasFocus,
+            content: impl FnOnce(&mut FocusBuilder),
+        ) -> &mut Self {
+            let tag = self.start(container);
+            content(self);
+            self.end(tag);
+            self
+        }
+
+        /// Add a container and its content.
+        ///
+        /// Calls [start_with_flags](Self::start_with_flags),
+        /// then the closure and ends the container afterwards.
+        pub fn container_with_flags(
+            &mut self,
+            container_flag: FocusFlag,
+            area: Rect,
+            area_z: u16,
+            content: impl FnOnce(&mut FocusBuilder),
+        ) -> &mut Self {
+            let tag = self.start_with_flags(container_flag, area, area_z);
+            content(self);
+            self.end(tag);
+            self
+        }
+
         /// Start a container widget. Must be matched with
         /// the equivalent [end](Self::end). Uses focus(), area() and
         /// z_area() of the given container. navigable() is
@@ -623,7 +920,10 @@ mod core {
             focus_debug!(self.log, "widget {:?}", focus);
 
             self.focus_ids.insert(focus.widget_id());
-            self.focus_flags.push(focus);
+            self.focus_flags.push(WeakFlag::new(&focus));
+            if !self.weak && !duplicate {
+                self.keep_alive.push(focus);
+            }
             self.duplicate.push(duplicate);
             self.areas.push((area, self.z_base + area_z));
             self.navigable.push(navigable);
@@ -658,13 +958,17 @@ mod core {
             self.container_ids.insert(container_flag.widget_id());
             self.containers.push((
                 Container {
-                    container_flag: container_flag.clone(),
+                    container_flag: WeakFlag::new(&container_flag),
                     area: (area, self.z_base),
                     delta_z: area_z,
+                    policy: NavigationPolicy::Escalate,
                     complete: false,
                 },
                 len..len,
             ));
+            if !self.weak {
+                self.keep_alive.push(container_flag.clone());
+            }
 
             container_flag
         }
@@ -704,6 +1008,10 @@ mod core {
                 last: self.last,
                 core: FocusCore {
                     log: Cell::new(log),
+                    weak: self.weak,
+                    policy: self.policy,
+                    hover_focus: self.hover_focus,
+                    hover: Cell::new(self.hover),
                     focus_ids: self.focus_ids,
                     focus_flags: self.focus_flags,
                     duplicate: self.duplicate,
@@ -711,6 +1019,7 @@ mod core {
                     navigable: self.navigable,
                     container_ids: self.container_ids,
                     containers: self.containers,
+                    keep_alive: self.keep_alive,
                 },
             }
         }
@@ -722,12 +1031,14 @@ mod core {
         /// Summarizes all the contained FocusFlags.
         /// If any of them has the focus set, this will be set too.
         /// This can help if you build compound widgets.
-        container_flag: FocusFlag,
+        container_flag: WeakFlag,
         /// Area for the whole compound.
         /// Contains the area and a z-value.
         area: (Rect, u16),
         /// Delta Z value compared to the enclosing container.
         delta_z: u16,
+        /// Navigation at the ends of the container.
+        policy: NavigationPolicy,
         /// Flag for construction.
         complete: bool,
     }
@@ -737,11 +1048,19 @@ mod core {
     pub(super) struct FocusCore {
         /// Focus logging
         pub(super) log: Cell<bool>,
+        /// Only weak references to the flags.
+        weak: bool,
+        /// Navigation at the ends of the list.
+        pub(super) policy: NavigationPolicy,
+        /// Focus follows the mouse after n moves.
+        pub(super) hover_focus: Option<usize>,
+        /// Widget-id under the mouse and the number of moves.
+        pub(super) hover: Cell<Option<(usize, usize)>>,
 
         /// List of focus-ids.
         focus_ids: HashSet<usize, FxBuildHasher>,
         /// List of flags.
-        focus_flags: Vec<FocusFlag>,
+        focus_flags: Vec<WeakFlag>,
         /// Is the flag the primary flag, or just a duplicate
         /// to allow for multiple areas.
         duplicate: Vec<bool>,
@@ -757,6 +1076,8 @@ mod core {
         /// all disjoint or completely contained within one other.
         /// No criss-cross intersections.
         containers: Vec<(Container, Range<usize>)>,
+        /// Strong references to all flags, unless weak.
+        keep_alive: Vec<FocusFlag>,
     }
 
     impl FocusCore {
@@ -769,6 +1090,7 @@ mod core {
             self.navigable.clear();
             self.container_ids.clear();
             self.containers.clear();
+            self.keep_alive.clear();
         }
 
         /// Is a widget?
@@ -781,7 +1103,7 @@ mod core {
             self.focus_flags
                 .iter()
                 .enumerate()
-                .find(|(_, f)| *f == focus_flag)
+                .find(|(_, f)| **f == *focus_flag)
                 .map(|(idx, _)| idx)
         }
 
@@ -798,7 +1120,7 @@ mod core {
             self.containers
                 .iter()
                 .enumerate()
-                .find(|(_, (c, _))| &c.container_flag == container_flag)
+                .find(|(_, (c, _))| c.container_flag == *container_flag)
                 .map(|(idx, (_, range))| (idx, range.clone()))
         }
 
@@ -844,6 +1166,7 @@ mod core {
                     .map(|(c, r)| (c, Self::shift(start, r))),
             );
             self.container_ids.extend(container.container_ids.iter());
+            self.keep_alive.append(&mut container.keep_alive);
         }
 
         /// Remove everything for the given container.
@@ -881,8 +1204,17 @@ mod core {
                 *r = Self::shrink(crange.start..crange.end, r.clone());
             }
 
+            let (keep_alive, retain) = self.keep_alive.drain(..).partition(|v| {
+                focus_ids.contains(&v.widget_id()) || sub_container_ids.contains(&v.widget_id())
+            });
+            self.keep_alive = retain;
+
             FocusCore {
                 log: Cell::new(false),
+                weak: self.weak,
+                policy: self.policy,
+                hover_focus: self.hover_focus,
+                hover: Default::default(),
                 focus_ids,
                 focus_flags,
                 duplicate,
@@ -890,9 +1222,35 @@ mod core {
                 navigable,
                 container_ids: sub_container_ids,
                 containers: sub_containers,
+                keep_alive,
             }
         }
 
+        /// Remove all flags that have been dropped.
+        pub(super) fn purge_dead(&mut self) {
+            for n in (0..self.focus_flags.len()).rev() {
+                if !self.focus_flags[n].is_alive() {
+                    let f = self.focus_flags.remove(n);
+                    self.focus_ids.remove(&f.widget_id());
+                    self.duplicate.remove(n);
+                    self.areas.remove(n);
+                    self.navigable.remove(n);
+                    for (_, r) in &mut self.containers {
+                        *r = Self::shrink(n..n + 1, r.clone());
+                    }
+                }
+            }
+            let container_ids = &mut self.container_ids;
+            self.containers.retain(|(c, _)| {
+                if c.container_flag.is_alive() {
+                    true
+                } else {
+                    container_ids.remove(&c.container_flag.widget_id());
+                    false
+                }
+            });
+        }
+
         // shift the ranges left by n
         fn shift(n: usize, range: Range<usize>) -> Range<usize> {
             range.start + n..range.end + n
@@ -1041,13 +1399,15 @@ mod core {
         }
 
         /// Set the initial focus.
-        pub(super) fn first_container(&self, container: &FocusFlag) {
+        pub(super) fn first_container(&self, container: &FocusFlag) -> bool {
             self.__start_change(true);
+            let mut r = false;
             if let Some((_idx, range)) = self.container_index_of(container) {
                 if let Some(n) = self.first_navigable(range.start) {
                     if n < range.end {
                         focus_debug!(self.log, "    -> focus {:?}", self.focus_flags[n].name());
                         self.__focus(n, true);
+                        r = true;
                     }
                 } else {
                     focus_debug!(self.log, "    -> no navigable widget");
@@ -1056,6 +1416,26 @@ mod core {
                 focus_debug!(self.log, "    => container not found");
             }
             self.__accumulate();
+            r
+        }
+
+        /// Focus the last widget of a container.
+        pub(super) fn last_container(&self, container: &FocusFlag) -> bool {
+            self.__start_change(true);
+            let mut r = false;
+            if let Some((_idx, range)) = self.container_index_of(container) {
+                if let Some(n) = range.clone().rev().find(|n| self.is_navigable(*n)) {
+                    focus_debug!(self.log, "    -> focus {:?}", self.focus_flags[n].name());
+                    self.__focus(n, true);
+                    r = true;
+                } else {
+                    focus_debug!(self.log, "    -> no navigable widget");
+                }
+            } else {
+                focus_debug!(self.log, "    => container not found");
+            }
+            self.__accumulate();
+            r
         }
 
         /// Set the focus at the given index.
@@ -1069,6 +1449,51 @@ mod core {
         /// Traverses the list to find the matching widget.
         /// Checks the area and the z-areas.
         pub(super) fn focus_at(&self, col: u16, row: u16) -> bool {
+            if let Some(n) = self.focus_target(col, row) {
+                self.__start_change(true);
+                let r = self.__focus(n, true);
+                self.__accumulate();
+                focus_debug!(self.log, "    -> focus {:?}", self.focus_flags[n].name());
+                r
+            } else {
+                false
+            }
+        }
+
+        /// Mouse moved to the position.
+        ///
+        /// Returns None if there is nothing to focus at the position,
+        /// otherwise if the focus changed.
+        pub(super) fn hover_at(&self, moves: usize, col: u16, row: u16) -> Option<bool> {
+            let Some(n) = self.focus_target(col, row) else {
+                self.hover.set(None);
+                return None;
+            };
+            if self.focus_flags[n].get() {
+                self.hover.set(None);
+                return Some(false);
+            }
+
+            let id = self.focus_flags[n].widget_id();
+            let count = match self.hover.get() {
+                Some((hover_id, count)) if hover_id == id => count + 1,
+                _ => 1,
+            };
+            if count >= moves {
+                self.hover.set(None);
+                self.__start_change(true);
+                let r = self.__focus(n, true);
+                self.__accumulate();
+                focus_debug!(self.log, "    -> focus {:?}", self.focus_flags[n].name());
+                Some(r)
+            } else {
+                self.hover.set(Some((id, count)));
+                Some(false)
+            }
+        }
+
+        /// Find the widget that gets the focus for the position.
+        fn focus_target(&self, col: u16, row: u16) -> Option<usize> {
             let pos = (col, row).into();
 
             enum ZOrder {
@@ -1081,7 +1506,7 @@ mod core {
             // search containers first. the widgets inside have the same z and are
             // more specific, so they should override.
             for (idx, (sub, _)) in self.containers.iter().enumerate() {
-                if sub.area.0.contains(pos) {
+                if sub.area.0.contains(pos) && sub.container_flag.is_alive() {
                     focus_debug!(
                         self.log,
                         "    container area-match {:?}",
@@ -1101,7 +1526,7 @@ mod core {
             }
             // search widgets
             for (idx, area) in self.areas.iter().enumerate() {
-                if area.0.contains(pos) {
+                if area.0.contains(pos) && self.focus_flags[idx].is_alive() {
                     focus_debug!(
                         self.log,
                         "    area-match {:?}",
@@ -1125,32 +1550,20 @@ mod core {
                 match idx {
                     ZOrder::Widget(idx) => {
                         if self.navigable[idx] != Navigation::None {
-                            self.__start_change(true);
-                            let r = self.__focus(idx, true);
-                            self.__accumulate();
-                            focus_debug!(
-                                self.log,
-                                "    -> focus {:?}",
-                                self.focus_flags[idx].name()
-                            );
-                            return r;
+                            return Some(idx);
                         } else {
                             focus_debug!(
                                 self.log,
                                 "    -> not mouse reachable {:?}",
                                 self.focus_flags[idx].name()
                             );
-                            return false;
+                            return None;
                         }
                     }
                     ZOrder::Container(idx) => {
                         let range = &self.containers[idx].1;
                         if let Some(n) = self.first_navigable(range.start) {
-                            self.__start_change(true);
-                            let r = self.__focus(n, true);
-                            self.__accumulate();
-                            focus_debug!(self.log, "    -> focus {:?}", self.focus_flags[n].name());
-                            return r;
+                            return Some(n);
                         }
                     }
                 }
@@ -1159,14 +1572,14 @@ mod core {
             // last is on top
             focus_debug!(self.log, "    -> no widget at pos");
 
-            false
+            None
         }
 
         /// Expel focus from the given container.
         pub(super) fn expel_container(&self, flag: FocusFlag) -> bool {
             if let Some((_idx, range)) = self.container_index_of(&flag) {
                 self.__start_change(true);
-                let n = self.next_navigable(range.end);
+                let n = self.next_navigable(range.end).unwrap_or(range.end);
                 self.__focus(n, true);
                 self.__accumulate();
 
@@ -1190,10 +1603,16 @@ mod core {
             for (n, p) in self.focus_flags.iter().enumerate() {
                 if p.lost() {
                     focus_debug!(self.log, "    current {:?}", p.name());
-                    let n = self.next_navigable(n);
-                    self.__focus(n, true);
-                    self.__accumulate();
-                    return true;
+                    return if let Some(n) = self.next_navigable(n) {
+                        self.__focus(n, true);
+                        self.__accumulate();
+                        true
+                    } else {
+                        // stop: keep the focus.
+                        self.__focus(n, true);
+                        self.__accumulate();
+                        false
+                    };
                 }
             }
             if let Some(n) = self.first_navigable(0) {
@@ -1212,10 +1631,16 @@ mod core {
             for (i, p) in self.focus_flags.iter().enumerate() {
                 if p.lost() {
                     focus_debug!(self.log, "    current {:?}", p.name());
-                    let n = self.prev_navigable(i);
-                    self.__focus(n, true);
-                    self.__accumulate();
-                    return true;
+                    return if let Some(n) = self.prev_navigable(i) {
+                        self.__focus(n, true);
+                        self.__accumulate();
+                        true
+                    } else {
+                        // stop: keep the focus.
+                        self.__focus(i, true);
+                        self.__accumulate();
+                        false
+                    };
                 }
             }
             if let Some(n) = self.first_navigable(0) {
@@ -1239,17 +1664,37 @@ mod core {
 
         /// Currently focused.
         pub(super) fn focused(&self) -> Option<FocusFlag> {
-            self.focus_flags.iter().find(|v| v.get()).cloned()
+            self.focus_flags
+                .iter()
+                .find(|v| v.get())
+                .and_then(|v| v.upgrade())
         }
 
         /// Last lost focus.
         pub(super) fn lost_focus(&self) -> Option<FocusFlag> {
-            self.focus_flags.iter().find(|v| v.lost()).cloned()
+            self.focus_flags
+                .iter()
+                .find(|v| v.lost())
+                .and_then(|v| v.upgrade())
         }
 
         /// Current gained focus.
         pub(super) fn gained_focus(&self) -> Option<FocusFlag> {
-            self.focus_flags.iter().find(|v| v.gained()).cloned()
+            self.focus_flags
+                .iter()
+                .find(|v| v.gained())
+                .and_then(|v| v.upgrade())
+        }
+
+        /// Keyboard navigable and still alive.
+        fn is_navigable(&self, n: usize) -> bool {
+            matches!(
+                self.navigable[n],
+                Navigation::Reach
+                    | Navigation::ReachLeaveBack
+                    | Navigation::ReachLeaveFront
+                    | Navigation::Regular
+            ) && self.focus_flags[n].is_alive()
         }
 
         /// First navigable flag starting at n.
@@ -1260,17 +1705,11 @@ mod core {
                 if start < self.focus_flags.len() {
                     self.focus_flags[start].name()
                 } else {
-                    "beginning"
+                    "beginning".to_string()
                 }
             );
             for n in start..self.focus_flags.len() {
-                if matches!(
-                    self.navigable[n],
-                    Navigation::Reach
-                        | Navigation::ReachLeaveBack
-                        | Navigation::ReachLeaveFront
-                        | Navigation::Regular
-                ) {
+                if self.is_navigable(n) {
                     focus_debug!(self.log, "    -> {:?}", self.focus_flags[n].name());
                     return Some(n);
                 }
@@ -1279,72 +1718,99 @@ mod core {
             None
         }
 
+        /// Containers around the given index, innermost first,
+        /// and the whole list last. With the navigation policy.
+        fn bounds_of(&self, idx: usize) -> Vec<(NavigationPolicy, Range<usize>)> {
+            let mut bounds = self
+                .containers
+                .iter()
+                .rev()
+                .filter(|(_, r)| r.contains(&idx))
+                .map(|(c, r)| (c.policy, r.clone()))
+                .collect::<Vec<_>>();
+            bounds.sort_by_key(|(_, r)| r.len());
+            let policy = match self.policy {
+                NavigationPolicy::Escalate => NavigationPolicy::Wrap,
+                v => v,
+            };
+            bounds.push((policy, 0..self.focus_flags.len()));
+            bounds
+        }
+
         /// Next navigable flag, starting at start.
-        fn next_navigable(&self, start: usize) -> usize {
+        ///
+        /// Returns None if a [NavigationPolicy::Stop] is hit.
+        fn next_navigable(&self, start: usize) -> Option<usize> {
             focus_debug!(
                 self.log,
                 "next navigable after {:?}",
                 if start < self.focus_flags.len() {
                     self.focus_flags[start].name()
                 } else {
-                    "last"
+                    "last".to_string()
                 }
             );
 
-            let mut n = start;
-            loop {
-                n = if n + 1 < self.focus_flags.len() {
-                    n + 1
-                } else {
-                    0
-                };
-                if matches!(
-                    self.navigable[n],
-                    Navigation::Reach
-                        | Navigation::ReachLeaveBack
-                        | Navigation::ReachLeaveFront
-                        | Navigation::Regular
-                ) {
+            for (policy, range) in self.bounds_of(start) {
+                if let Some(n) = (start + 1..range.end).find(|n| self.is_navigable(*n)) {
                     focus_debug!(self.log, "    -> {}:{:?}", n, self.focus_flags[n].name());
-                    return n;
+                    return Some(n);
                 }
-                if n == start {
-                    focus_debug!(self.log, "    -> {}:end at start", n);
-                    return n;
+                match policy {
+                    NavigationPolicy::Wrap => {
+                        if let Some(n) = (range.start..start).find(|n| self.is_navigable(*n)) {
+                            focus_debug!(self.log, "    -> {}:{:?}", n, self.focus_flags[n].name());
+                            return Some(n);
+                        } else {
+                            focus_debug!(self.log, "    -> {}:end at start", start);
+                            return Some(start);
+                        }
+                    }
+                    NavigationPolicy::Stop => {
+                        focus_debug!(self.log, "    -> stop");
+                        return None;
+                    }
+                    NavigationPolicy::Escalate => {}
                 }
             }
+            Some(start)
         }
 
         /// Previous navigable flag, starting at start.
-        fn prev_navigable(&self, start: usize) -> usize {
+        ///
+        /// Returns None if a [NavigationPolicy::Stop] is hit.
+        fn prev_navigable(&self, start: usize) -> Option<usize> {
             focus_debug!(
                 self.log,
                 "prev navigable before {:?}",
                 self.focus_flags[start].name()
             );
 
-            let mut n = start;
-            loop {
-                n = if n > 0 {
-                    n - 1
-                } else {
-                    self.focus_flags.len() - 1
-                };
-                if matches!(
-                    self.navigable[n],
-                    Navigation::Reach
-                        | Navigation::ReachLeaveBack
-                        | Navigation::ReachLeaveFront
-                        | Navigation::Regular
-                ) {
+            for (policy, range) in self.bounds_of(start) {
+                if let Some(n) = (range.start..start).rev().find(|n| self.is_navigable(*n)) {
                     focus_debug!(self.log, "    -> {}:{:?}", n, self.focus_flags[n].name());
-                    return n;
+                    return Some(n);
                 }
-                if n == start {
-                    focus_debug!(self.log, "    -> {}:end at start", n);
-                    return n;
+                match policy {
+                    NavigationPolicy::Wrap => {
+                        if let Some(n) =
+                            (start + 1..range.end).rev().find(|n| self.is_navigable(*n))
+                        {
+                            focus_debug!(self.log, "    -> {}:{:?}", n, self.focus_flags[n].name());
+                            return Some(n);
+                        } else {
+                            focus_debug!(self.log, "    -> {}:end at start", start);
+                            return Some(start);
+                        }
+                    }
+                    NavigationPolicy::Stop => {
+                        focus_debug!(self.log, "    -> stop");
+                        return None;
+                    }
+                    NavigationPolicy::Escalate => {}
                 }
             }
+            Some(start)
         }
 
         /// Debug destructuring.
@@ -1359,13 +1825,22 @@ mod core {
             Vec<(FocusFlag, (Rect, u16), Range<usize>)>,
         ) {
             (
-                self.focus_flags.clone(),
+                self.focus_flags
+                    .iter()
+                    .map(|v| v.upgrade().unwrap_or_default())
+                    .collect::<Vec<_>>(),
                 self.duplicate.clone(),
                 self.areas.clone(),
                 self.navigable.clone(),
                 self.containers
                     .iter()
-                    .map(|(v, w)| (v.container_flag.clone(), v.area, w.clone()))
+                    .map(|(v, w)| {
+                        (
+                            v.container_flag.upgrade().unwrap_or_default(),
+                            v.area,
+                            w.clone(),
+                        )
+                    })
                     .collect::<Vec<_>>(),
             )
         }
@@ -1374,7 +1849,7 @@ mod core {
     #[cfg(test)]
     mod test {
         use crate::focus::core::FocusCore;
-        use crate::{FocusBuilder, FocusFlag, HasFocus};
+        use crate::{FocusBuilder, FocusFlag, HasFocus, NavigationPolicy};
         use ratatui::layout::Rect;
 
         #[test]
@@ -1500,6 +1975,257 @@ mod core {
             assert_eq!(ff.core.focus_flags[5], c);
             assert_eq!(ff.core.containers[0].1, 1..4);
         }
+
+        #[test]
+        fn test_policy() {
+            let a = FocusFlag::named("a");
+            let b = FocusFlag::named("b");
+            let c = FocusFlag::named("c");
+            let d = FocusFlag::named("d");
+            let e = FocusFlag::named("e");
+            let cc = FocusFlag::named("cc");
+
+            let build = |policy: NavigationPolicy, c_policy: NavigationPolicy| {
+                let mut fb = FocusBuilder::new(None).policy(policy);
+                fb.widget(&a);
+                let tag = fb.start_with_flags(cc.clone(), Rect::default(), 0);
+                fb.container_policy(&cc, c_policy);
+                fb.widget(&b);
+                fb.widget(&c);
+                fb.widget(&d);
+                fb.end(tag);
+                fb.widget(&e);
+                fb.build()
+            };
+
+            // escalate
+            let focus = build(NavigationPolicy::Wrap, NavigationPolicy::Escalate);
+            focus.focus(&d);
+            assert!(focus.next());
+            assert!(e.get());
+            assert!(focus.next());
+            assert!(a.get());
+            assert!(focus.prev());
+            assert!(e.get());
+
+            // stop at the end of the list
+            let focus = build(NavigationPolicy::Stop, NavigationPolicy::Escalate);
+            focus.focus(&e);
+            assert!(!focus.next());
+            assert!(e.get());
+            focus.focus(&a);
+            assert!(!focus.prev());
+            assert!(a.get());
+
+            // wrap inside the container
+            let focus = build(NavigationPolicy::Wrap, NavigationPolicy::Wrap);
+            focus.focus(&d);
+            assert!(focus.next());
+            assert!(b.get());
+            assert!(focus.prev());
+            assert!(d.get());
+
+            // stop inside the container
+            let focus = build(NavigationPolicy::Wrap, NavigationPolicy::Stop);
+            focus.focus(&d);
+            assert!(!focus.next());
+            assert!(d.get());
+            assert!(!d.lost() && !d.gained());
+            focus.focus(&b);
+            assert!(!focus.prev());
+            assert!(b.get());
+
+            assert!(focus.focus_last_in(&cc));
+            assert!(d.get());
+            assert!(focus.focus_first_in(&cc));
+            assert!(b.get());
+        }
+
+        #[test]
+        fn test_hover_focus() {
+            use crate::{Focus, Navigation};
+            use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+            use rat_event::{HandleEvent, Outcome, Regular};
+
+            let mouse = |kind: MouseEventKind, column: u16, row: u16| {
+                Event::Mouse(MouseEvent {
+                    kind,
+                    column,
+                    row,
+                    modifiers: KeyModifiers::NONE,
+                })
+            };
+            let moved = |column: u16, row: u16| mouse(MouseEventKind::Moved, column, row);
+
+            let a = FocusFlag::named("a");
+            let b = FocusFlag::named("b");
+            let c = FocusFlag::named("c");
+            let popup = FocusFlag::named("popup");
+
+            let build = |old: Option<Focus>| {
+                let mut fb = FocusBuilder::new(old);
+                fb.widget_with_flags(a.clone(), Rect::new(0, 0, 10, 1), 0, Navigation::Regular);
+                fb.widget_with_flags(b.clone(), Rect::new(0, 1, 10, 1), 0, Navigation::Mouse);
+                fb.widget_with_flags(c.clone(), Rect::new(0, 2, 10, 1), 0, Navigation::None);
+                fb.widget_with_flags(popup.clone(), Rect::new(5, 0, 5, 1), 1, Navigation::Regular);
+                fb.build()
+            };
+
+            // off by default.
+            let mut focus = build(None);
+            focus.first();
+            assert_eq!(focus.hover_focus(), None);
+            assert_eq!(focus.handle(&moved(0, 1), Regular), Outcome::Continue);
+            assert!(a.get());
+
+            let mut focus = FocusBuilder::new(Some(focus)).hover_focus(3);
+            focus.widget_with_flags(a.clone(), Rect::new(0, 0, 10, 1), 0, Navigation::Regular);
+            focus.widget_with_flags(b.clone(), Rect::new(0, 1, 10, 1), 0, Navigation::Mouse);
+            focus.widget_with_flags(c.clone(), Rect::new(0, 2, 10, 1), 0, Navigation::None);
+            let mut focus = focus.build();
+            assert_eq!(focus.hover_focus(), Some(3));
+
+            // needs 3 moves over the same widget.
+            assert_eq!(focus.handle(&moved(0, 1), Regular), Outcome::Unchanged);
+            assert_eq!(focus.handle(&moved(1, 1), Regular), Outcome::Unchanged);
+            assert!(a.get());
+            assert_eq!(focus.handle(&moved(2, 1), Regular), Outcome::Changed);
+            assert!(b.get() && b.gained());
+            assert!(a.lost());
+
+            // skimming across doesn't change anything.
+            assert_eq!(focus.handle(&moved(0, 0), Regular), Outcome::Unchanged);
+            assert_eq!(focus.handle(&moved(0, 1), Regular), Outcome::Unchanged);
+            assert_eq!(focus.handle(&moved(0, 0), Regular), Outcome::Unchanged);
+            assert_eq!(focus.handle(&moved(0, 1), Regular), Outcome::Unchanged);
+            assert!(b.get());
+            assert!(!b.gained() && !a.lost());
+
+            // not reachable, or nothing there.
+            assert_eq!(focus.handle(&moved(0, 2), Regular), Outcome::Continue);
+            assert_eq!(focus.handle(&moved(0, 5), Regular), Outcome::Continue);
+
+            // the count survives a rebuild.
+            assert_eq!(focus.handle(&moved(0, 0), Regular), Outcome::Unchanged);
+            assert_eq!(focus.handle(&moved(0, 0), Regular), Outcome::Unchanged);
+            let mut focus = build(Some(focus));
+            assert_eq!(focus.hover_focus(), Some(3));
+            assert_eq!(focus.handle(&moved(0, 0), Regular), Outcome::Changed);
+            assert!(a.get());
+
+            // respects the z-order.
+            for _ in 0..2 {
+                assert_eq!(focus.handle(&moved(6, 0), Regular), Outcome::Unchanged);
+            }
+            assert_eq!(focus.handle(&moved(6, 0), Regular), Outcome::Changed);
+            assert!(popup.get());
+
+            // clicks are immediate.
+            assert_eq!(
+                focus.handle(
+                    &mouse(MouseEventKind::Down(MouseButton::Left), 0, 1),
+                    Regular
+                ),
+                Outcome::Changed
+            );
+            assert!(b.get());
+
+            focus.set_hover_focus(None);
+            assert_eq!(focus.handle(&moved(0, 0), Regular), Outcome::Continue);
+            assert!(b.get());
+        }
+
+        #[test]
+        fn test_helper() {
+            let a = FocusFlag::named("a");
+            let rows = [FocusFlag::named("r0"), FocusFlag::named("r1")];
+            let cc = FocusFlag::named("cc");
+            let hidden = FocusFlag::named("hidden");
+            let b = FocusFlag::named("b");
+
+            let mut fb = FocusBuilder::new(None);
+            fb.widget(&a);
+            fb.container_with_flags(cc.clone(), Rect::default(), 0, |fb| {
+                fb.widgets(rows.iter());
+                fb.widget_if(false, &hidden);
+            });
+            fb.widget_if(true, &b);
+            let ff = fb.build();
+            assert_eq!(ff.core.focus_flags.len(), 4);
+            assert_eq!(ff.core.focus_flags[1], rows[0]);
+            assert_eq!(ff.core.focus_flags[2], rows[1]);
+            assert_eq!(ff.core.focus_flags[3], b);
+            assert_eq!(ff.core.containers[0].1, 1..3);
+
+            let ff = FocusBuilder::build_for_parts(&[&a, &b]);
+            assert_eq!(ff.core.focus_flags.len(), 2);
+
+            let mut fb = FocusBuilder::new(None);
+            fb.widgets([&a as &dyn HasFocus, &b]);
+            assert_eq!(fb.build().core.focus_flags.len(), 2);
+        }
+
+        #[test]
+        fn test_weak() {
+            let a = FocusFlag::named("a");
+            let b = FocusFlag::named("b");
+            let c = FocusFlag::named("c");
+            let cc = FocusFlag::named("cc");
+
+            let mut fb = FocusBuilder::new(None).weak();
+            fb.widget(&a);
+            let cc_end = fb.start_with_flags(cc.clone(), Rect::default(), 0);
+            fb.widget(&b);
+            fb.end(cc_end);
+            fb.widget(&c);
+            let mut ff = fb.build();
+
+            ff.first();
+            assert!(a.get());
+            drop(b);
+            ff.next();
+            assert!(c.get());
+            ff.prev();
+            assert!(a.get());
+            ff.prev();
+            assert!(c.get());
+            assert_eq!(ff.focused(), Some(c.clone()));
+
+            ff.purge_dead();
+            assert_eq!(ff.core.focus_flags.len(), 2);
+            assert_eq!(ff.core.focus_flags[0], a);
+            assert_eq!(ff.core.focus_flags[1], c);
+            assert_eq!(ff.core.containers[0].1, 1..1);
+
+            drop(cc);
+            ff.purge_dead();
+            assert!(ff.core.containers.is_empty());
+
+            // rebuild keeps the mode
+            let mut fb = FocusBuilder::new(Some(ff));
+            fb.widget(&a);
+            let ff = fb.build();
+            assert!(ff.core.weak);
+            assert!(ff.core.keep_alive.is_empty());
+        }
+
+        #[test]
+        fn test_strong() {
+            let a = FocusFlag::named("a");
+            let b = FocusFlag::named("b");
+
+            let mut fb = FocusBuilder::new(None);
+            fb.widget(&a);
+            fb.widget(&b);
+            let mut ff = fb.build();
+
+            ff.first();
+            drop(b);
+            ff.next();
+            assert_eq!(ff.focused_name().as_deref(), Some("b"));
+            ff.purge_dead();
+            assert_eq!(ff.core.focus_flags.len(), 2);
+        }
     }
 }
 
@@ -1544,6 +2270,7 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for Focus {
         match event {
             ct_event!(mouse down Left for column, row) => {
                 focus_debug!(self.core.log, "mouse down {},{}", column, row);
+                self.core.hover.set(None);
                 if self.focus_at(*column, *row) {
                     focus_debug!(
                         self.core.log,
@@ -1556,6 +2283,33 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for Focus {
                     Outcome::Continue
                 }
             }
+            ct_event!(mouse moved for column, row) if self.core.hover_focus.is_some() => {
+                let moves = self.core.hover_focus.unwrap_or_default();
+                if self.navigation() == Some(Navigation::Lock) {
+                    self.reset_lost_gained();
+                    return Outcome::Continue;
+                }
+                match self.core.hover_at(moves, *column, *row) {
+                    Some(true) => {
+                        focus_debug!(
+                            self.core.log,
+                            "mouse hover {},{} -> {:?}",
+                            column,
+                            row,
+                            self.focused().map(|v| v.name().to_string())
+                        );
+                        Outcome::Changed
+                    }
+                    Some(false) => {
+                        self.reset_lost_gained();
+                        Outcome::Unchanged
+                    }
+                    None => {
+                        self.reset_lost_gained();
+                        Outcome::Continue
+                    }
+                }
+            }
             _ => {
                 self.reset_lost_gained();
                 Outcome::Continue
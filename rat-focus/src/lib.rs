@@ -134,6 +134,24 @@ pub enum Navigation {
     Regular,
 }
 
+/// What happens when next()/prev() reaches the end of a container
+/// or the end of the whole focus list.
+///
+/// Set it for the Focus with [FocusBuilder::policy] and for
+/// single containers with [FocusBuilder::container_policy].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NavigationPolicy {
+    /// Start again at the other end.
+    /// Default for the Focus.
+    #[default]
+    Wrap,
+    /// Stay with the last widget. next()/prev() return false.
+    Stop,
+    /// Continue with the widgets after/before the container.
+    /// Default for containers. Acts like Wrap for the Focus.
+    Escalate,
+}
+
 /// Trait for a widget that takes part of focus handling.
 ///
 /// When used for a simple widget implement
@@ -184,7 +184,9 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
                     }
                 }
                 ct_event!(keycode press CONTROL-Down) | ct_event!(keycode press CONTROL-End) => {
-                    if self.move_to_row(self.rows.saturating_sub(1)) {
+                    if self.virtual_rows {
+                        TableOutcome::LoadTail
+                    } else if self.move_to_row(self.rows.saturating_sub(1)) {
                         TableOutcome::Selected
                     } else {
                         TableOutcome::Unchanged
@@ -251,6 +253,11 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, TableOutcome> for TableState<CellSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> TableOutcome {
+        let r = self.handle_hover(event);
+        if r.is_consumed() {
+            return r;
+        }
+
         let mut r = match event {
             ct_event!(mouse any for m) if self.mouse.drag(self.table_area, m) => {
                 if self.move_to(self.cell_at_drag((m.column, m.row))) {
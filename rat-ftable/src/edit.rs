@@ -10,7 +10,12 @@
 //! Keys while editing are
 //! * Esc - Cancel editing.
 //! * Enter - Commit current edit and edit next/append a row.
+//!   Or stay on the row, see [EnterMode].
 //! * Up/Down - Commit current edit.
+//!
+//! Before any commit [EditorValidate::validate] is called.
+//! If it fails, the edit continues and the event is reported
+//! as Unchanged.
 use rat_focus::HasFocus;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
@@ -30,8 +35,28 @@ pub trait TableEditor {
     fn render(&self, area: Rect, cell_areas: &[Rect], buf: &mut Buffer, state: &mut Self::State);
 }
 
+/// Validation hook for the editor widget state.
+///
+/// The edit widgets call this before they turn Enter/Up/Down
+/// into one of the Commit outcomes.
+pub trait EditorValidate {
+    /// Validate the current value of the editor.
+    /// Returns an error message if the value is not acceptable.
+    fn validate(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Result of the last validation. None if the value is ok.
+    ///
+    /// The editor can use this to render an invalid style
+    /// and show the message.
+    fn set_validation_error(&mut self, err: Option<String>) {
+        _ = err;
+    }
+}
+
 /// Trait for the editor widget state
-pub trait TableEditorState: HasFocus {
+pub trait TableEditorState: HasFocus + EditorValidate {
     /// Some external context.
     type Context<'a>: Clone;
     /// Type of data.
@@ -60,3 +85,15 @@ pub enum Mode {
     Edit,
     Insert,
 }
+
+/// Behaviour of Enter while editing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EnterMode {
+    /// Commit and edit the next row, or append a row
+    /// after the last one.
+    #[default]
+    NextRow,
+    /// Commit and continue editing the same row.
+    /// Spreadsheet style.
+    Stay,
+}
@@ -8,7 +8,7 @@
 //! There is `examples/table_edit1.rs`.
 
 use crate::_private::NonExhaustive;
-use crate::edit::{Mode, TableEditor, TableEditorState};
+use crate::edit::{EnterMode, Mode, TableEditor, TableEditorState};
 use crate::event::{EditOutcome, TableOutcome};
 use crate::rowselection::RowSelection;
 use crate::{Table, TableSelection, TableState};
@@ -52,6 +52,11 @@ pub struct EditableTableState<S> {
     pub table: TableState<RowSelection>,
     /// Editor
     pub editor: S,
+    /// Behaviour of Enter while editing.
+    pub enter_mode: EnterMode,
+    /// Error from the last failed validation.
+    /// __read only__
+    pub validation_error: Option<String>,
 
     pub mouse: MouseFlags,
 
@@ -128,6 +133,8 @@ where
             mode: Mode::View,
             table: Default::default(),
             editor: S::default(),
+            enter_mode: Default::default(),
+            validation_error: None,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -143,6 +150,8 @@ where
             .field("mode", &self.mode)
             .field("table", &self.table)
             .field("editor", &self.editor)
+            .field("enter_mode", &self.enter_mode)
+            .field("validation_error", &self.validation_error)
             .field("mouse", &self.mouse)
             .finish()
     }
@@ -205,6 +214,15 @@ where
             }
         }
     }
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        match self.mode {
+            Mode::View => {}
+            Mode::Edit | Mode::Insert => {
+                self.editor.relocate_popup(shift, clip);
+            }
+        }
+    }
 }
 
 impl<S> EditableTableState<S> {
@@ -214,6 +232,8 @@ impl<S> EditableTableState<S> {
             mode: Mode::View,
             table: TableState::new(),
             editor,
+            enter_mode: Default::default(),
+            validation_error: None,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -225,6 +245,8 @@ impl<S> EditableTableState<S> {
             mode: Mode::View,
             table: TableState::named(name),
             editor,
+            enter_mode: Default::default(),
+            validation_error: None,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -245,6 +267,28 @@ where
         self.mode == Mode::Insert
     }
 
+    /// Behaviour of Enter while editing.
+    pub fn set_enter_mode(&mut self, enter_mode: EnterMode) {
+        self.enter_mode = enter_mode;
+    }
+
+    /// Error from the last failed validation.
+    pub fn validation_error(&self) -> Option<&str> {
+        self.validation_error.as_deref()
+    }
+
+    /// Run the editors validation.
+    ///
+    /// Stores the result and hands it on to the editor.
+    /// Returns true if the value is ok.
+    pub fn validate(&mut self) -> bool {
+        let err = self.editor.validate().err();
+        let ok = err.is_none();
+        self.editor.set_validation_error(err.clone());
+        self.validation_error = err;
+        ok
+    }
+
     /// Remove the item at the selected row.
     ///
     /// This doesn't change the actual list of items, but does
@@ -347,6 +391,9 @@ where
 
     fn _stop(&mut self) {
         self.mode = Mode::View;
+        if self.validation_error.take().is_some() {
+            self.editor.set_validation_error(None);
+        }
         self.table.scroll_to_col(0);
     }
 }
@@ -376,18 +423,24 @@ where
                         EditOutcome::Cancel
                     }
                     ct_event!(keycode press Enter) => {
-                        if self.table.selected_checked() < Some(self.table.rows().saturating_sub(1))
+                        if !self.validate() {
+                            EditOutcome::Unchanged
+                        } else if self.enter_mode == EnterMode::Stay {
+                            EditOutcome::CommitAndStay
+                        } else if self.table.selected_checked()
+                            < Some(self.table.rows().saturating_sub(1))
                         {
                             EditOutcome::CommitAndEdit
                         } else {
                             EditOutcome::CommitAndAppend
                         }
                     }
-                    ct_event!(keycode press Up) => {
-                        EditOutcome::Commit
-                    }
-                    ct_event!(keycode press Down) => {
-                        EditOutcome::Commit
+                    ct_event!(keycode press Up) | ct_event!(keycode press Down) => {
+                        if self.validate() {
+                            EditOutcome::Commit
+                        } else {
+                            EditOutcome::Unchanged
+                        }
                     }
                     _ => EditOutcome::Continue,
                 });
@@ -438,6 +491,8 @@ where
                 TableOutcome::Unchanged => EditOutcome::Unchanged,
                 TableOutcome::Changed => EditOutcome::Changed,
                 TableOutcome::Selected => EditOutcome::Changed,
+                TableOutcome::LoadTail => EditOutcome::Changed,
+                TableOutcome::RowMoved { .. } => EditOutcome::Changed,
             }
         }
     }
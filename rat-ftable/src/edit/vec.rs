@@ -8,7 +8,7 @@
 //! For examples go to the rat-widget crate.
 //! There is `examples/table_edit2.rs`.
 
-use crate::edit::{Mode, TableEditor, TableEditorState};
+use crate::edit::{EnterMode, Mode, TableEditor, TableEditorState};
 use crate::rowselection::RowSelection;
 use crate::textdata::Row;
 use crate::{Table, TableContext, TableData, TableState};
@@ -70,6 +70,10 @@ where
     editor: S,
     /// Data store
     editor_data: Rc<RefCell<Vec<S::Value>>>,
+    /// Behaviour of Enter while editing.
+    enter_mode: EnterMode,
+    /// Error from the last failed validation.
+    validation_error: Option<String>,
 
     mouse: MouseFlags,
 }
@@ -181,6 +185,8 @@ where
             table: Default::default(),
             editor: S::default(),
             editor_data: Rc::new(RefCell::new(Vec::default())),
+            enter_mode: Default::default(),
+            validation_error: None,
             mouse: Default::default(),
         }
     }
@@ -197,6 +203,8 @@ where
             .field("table", &self.table)
             .field("editor", &self.editor)
             .field("editor_data", &self.editor_data)
+            .field("enter_mode", &self.enter_mode)
+            .field("validation_error", &self.validation_error)
             .field("mouse", &self.mouse)
             .finish()
     }
@@ -262,6 +270,15 @@ where
             }
         }
     }
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        match self.mode {
+            Mode::View => {}
+            Mode::Edit | Mode::Insert => {
+                self.editor.relocate_popup(shift, clip);
+            }
+        }
+    }
 }
 
 impl<S> EditableTableVecState<S>
@@ -274,6 +291,8 @@ where
             table: TableState::new(),
             editor,
             editor_data: Rc::new(RefCell::new(vec![])),
+            enter_mode: Default::default(),
+            validation_error: None,
             mouse: Default::default(),
         }
     }
@@ -284,6 +303,8 @@ where
             table: TableState::named(name),
             editor,
             editor_data: Rc::new(RefCell::new(vec![])),
+            enter_mode: Default::default(),
+            validation_error: None,
             mouse: Default::default(),
         }
     }
@@ -313,6 +334,33 @@ where
         self.mode == Mode::Insert
     }
 
+    /// Behaviour of Enter while editing.
+    pub fn enter_mode(&self) -> EnterMode {
+        self.enter_mode
+    }
+
+    /// Behaviour of Enter while editing.
+    pub fn set_enter_mode(&mut self, enter_mode: EnterMode) {
+        self.enter_mode = enter_mode;
+    }
+
+    /// Error from the last failed validation.
+    pub fn validation_error(&self) -> Option<&str> {
+        self.validation_error.as_deref()
+    }
+
+    /// Run the editors validation.
+    ///
+    /// Stores the result and hands it on to the editor.
+    /// Returns true if the value is ok.
+    pub fn validate(&mut self) -> bool {
+        let err = self.editor.validate().err();
+        let ok = err.is_none();
+        self.editor.set_validation_error(err.clone());
+        self.validation_error = err;
+        ok
+    }
+
     /// Remove the item at the selected row.
     pub fn remove(&mut self, row: usize) {
         if self.mode != Mode::View {
@@ -423,8 +471,23 @@ where
         Ok(())
     }
 
+    pub fn commit_and_stay(&mut self, ctx: S::Context<'_>) -> Result<(), S::Err> {
+        let Some(row) = self.table.selected_checked() else {
+            return Ok(());
+        };
+
+        self.commit(ctx.clone())?;
+        if row < self.editor_data.borrow().len() {
+            self.edit(row, ctx.clone())?;
+        }
+        Ok(())
+    }
+
     fn _stop(&mut self) {
         self.mode = Mode::View;
+        if self.validation_error.take().is_some() {
+            self.editor.set_validation_error(None);
+        }
         self.table.scroll_to_col(0);
     }
 }
@@ -458,7 +521,14 @@ where
                     Outcome::Changed
                 }
                 ct_event!(keycode press Enter) => {
-                    if self.table.selected_checked() < Some(self.table.rows().saturating_sub(1)) {
+                    if !self.validate() {
+                        Outcome::Unchanged
+                    } else if self.enter_mode == EnterMode::Stay {
+                        self.commit_and_stay(ctx.clone())?;
+                        Outcome::Changed
+                    } else if self.table.selected_checked()
+                        < Some(self.table.rows().saturating_sub(1))
+                    {
                         self.commit_and_edit(ctx.clone())?;
                         Outcome::Changed
                     } else {
@@ -466,13 +536,13 @@ where
                         Outcome::Changed
                     }
                 }
-                ct_event!(keycode press Up) => {
-                    self.commit(ctx.clone())?;
-                    Outcome::Changed
-                }
-                ct_event!(keycode press Down) => {
-                    self.commit(ctx.clone())?;
-                    Outcome::Changed
+                ct_event!(keycode press Up) | ct_event!(keycode press Down) => {
+                    if self.validate() {
+                        self.commit(ctx.clone())?;
+                        Outcome::Changed
+                    } else {
+                        Outcome::Unchanged
+                    }
                 }
                 _ => Outcome::Continue,
             });
@@ -0,0 +1,133 @@
+//!
+//! Export rows as CSV or TSV.
+//!
+//! The table only knows how to render its cells, so the text
+//! for each cell comes from [TableData::render_cell_text] or
+//! [TableDataIter::render_cell_text]. Cells without a text
+//! are exported as empty fields.
+//!
+//! The result can be put in the clipboard and pasted in a
+//! spreadsheet.
+//!
+//! ```rust ignore
+//! ct_event!(key press CONTROL-'c') => {
+//!     let text = state.table.export_selection(&Data(&data), ExportFormat::Tsv);
+//!     clipboard.set_string(text)?;
+//! }
+//! ```
+//!
+
+use crate::{TableData, TableDataIter};
+use std::ops::Range;
+
+/// Export format.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma separated values.
+    ///
+    /// Fields containing a comma, a quote or a line-break are
+    /// quoted, quotes are doubled.
+    Csv,
+    /// Tab separated values.
+    ///
+    /// This is what spreadsheets put in the clipboard. There
+    /// is no quoting, tabs and line-breaks in a field are
+    /// replaced with a space.
+    #[default]
+    Tsv,
+}
+
+impl ExportFormat {
+    fn separator(&self) -> char {
+        match self {
+            ExportFormat::Csv => ',',
+            ExportFormat::Tsv => '\t',
+        }
+    }
+
+    fn push_field(&self, field: &str, buf: &mut String) {
+        match self {
+            ExportFormat::Csv => {
+                if field.contains([',', '"', '\n', '\r']) {
+                    buf.push('"');
+                    for c in field.chars() {
+                        if c == '"' {
+                            buf.push('"');
+                        }
+                        buf.push(c);
+                    }
+                    buf.push('"');
+                } else {
+                    buf.push_str(field);
+                }
+            }
+            ExportFormat::Tsv => {
+                if field.contains(['\t', '\n', '\r']) {
+                    buf.extend(field.chars().map(|c| match c {
+                        '\t' | '\n' | '\r' => ' ',
+                        c => c,
+                    }));
+                } else {
+                    buf.push_str(field);
+                }
+            }
+        }
+    }
+}
+
+/// Export the given rows and columns.
+///
+/// Each row ends with a `\n`. Rows outside the data are skipped.
+pub fn export_rows<'a>(
+    data: &dyn TableData<'a>,
+    rows: impl Iterator<Item = usize>,
+    columns: Range<usize>,
+    format: ExportFormat,
+) -> String {
+    let mut buf = String::new();
+    for row in rows {
+        if row >= data.rows() {
+            continue;
+        }
+        for column in columns.clone() {
+            if column > columns.start {
+                buf.push(format.separator());
+            }
+            if let Some(text) = data.render_cell_text(column, row) {
+                format.push_field(text.as_ref(), &mut buf);
+            }
+        }
+        buf.push('\n');
+    }
+    buf
+}
+
+/// Export the given rows and columns from a [TableDataIter].
+///
+/// The rows must be in ascending order, as the iterator can
+/// only move forward. Other rows are skipped.
+pub fn export_rows_iter<'a>(
+    data: &mut dyn TableDataIter<'a>,
+    rows: impl Iterator<Item = usize>,
+    columns: Range<usize>,
+    format: ExportFormat,
+) -> String {
+    let mut buf = String::new();
+    let mut next = 0;
+    for row in rows {
+        if row < next || !data.nth(row - next) {
+            continue;
+        }
+        next = row + 1;
+        for column in columns.clone() {
+            if column > columns.start {
+                buf.push(format.separator());
+            }
+            if let Some(text) = data.render_cell_text(column) {
+                format.push_field(text.as_ref(), &mut buf);
+            }
+        }
+        buf.push('\n');
+    }
+    buf
+}
@@ -2,6 +2,7 @@
 
 mod cellselection;
 pub mod edit;
+pub mod export;
 mod noselection;
 mod rowselection;
 mod rowsetselection;
@@ -9,10 +10,11 @@ mod table;
 pub mod textdata;
 mod util;
 
-use crate::textdata::Row;
+use crate::textdata::{CellFormat, Overflow, Row};
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Constraint, Rect};
+use ratatui::layout::{Alignment, Constraint, Rect};
 use ratatui::style::Style;
+use std::borrow::Cow;
 
 /// Render-context for rendering a table-cell.
 #[derive(Debug)]
@@ -40,6 +42,14 @@ pub struct TableContext {
     /// Total area for the current row.
     pub row_area: Rect,
 
+    /// Alignment of the column.
+    /// See [ColumnSpec](crate::textdata::ColumnSpec).
+    pub align: Alignment,
+    /// Overflow policy of the column.
+    pub overflow: Overflow,
+    /// Formatter of the column.
+    pub format: Option<CellFormat>,
+
     /// Construct with `..Default::default()`
     pub non_exhaustive: NonExhaustive,
 }
@@ -82,6 +92,26 @@ pub trait TableData<'a> {
         Vec::default()
     }
 
+    /// Group of the row.
+    ///
+    /// The rows of a group must be contiguous. The table adds
+    /// a one line group header before the first row of each group.
+    /// If the table is scrolled into a group, the header of the
+    /// group sticks to the top of the table.
+    ///
+    /// The header line is part of the area of the first row, all
+    /// row indices are still indices into the data and the
+    /// headers can't be selected.
+    #[allow(unused_variables)]
+    fn group_of(&self, row: usize) -> Option<usize> {
+        None
+    }
+
+    /// Render the header for the group.
+    /// The area is one line high.
+    #[allow(unused_variables)]
+    fn render_group_header(&self, group: usize, area: Rect, buf: &mut Buffer) {}
+
     /// Render the cell given by column/row.
     /// * ctx - a lot of context data.
     fn render_cell(
@@ -92,6 +122,24 @@ pub trait TableData<'a> {
         area: Rect,
         buf: &mut Buffer,
     );
+
+    /// Plain text of the cell given by column/row.
+    ///
+    /// Used for exporting rows, see [export](crate::export).
+    #[allow(unused_variables)]
+    fn render_cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    /// Full content of the cell given by column/row.
+    ///
+    /// If this doesn't fit the cell, the cell is marked as truncated
+    /// and the table can show this text as a tooltip when the mouse
+    /// hovers over the cell. See [Table::show_tooltip].
+    #[allow(unused_variables)]
+    fn cell_tooltip(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        None
+    }
 }
 
 impl<'a> TableData<'a> for Box<dyn TableData<'a> + 'a> {
@@ -119,6 +167,14 @@ impl<'a> TableData<'a> for Box<dyn TableData<'a> + 'a> {
         (**self).widths()
     }
 
+    fn group_of(&self, row: usize) -> Option<usize> {
+        (**self).group_of(row)
+    }
+
+    fn render_group_header(&self, group: usize, area: Rect, buf: &mut Buffer) {
+        (**self).render_group_header(group, area, buf)
+    }
+
     fn render_cell(
         &self,
         ctx: &TableContext,
@@ -129,6 +185,14 @@ impl<'a> TableData<'a> for Box<dyn TableData<'a> + 'a> {
     ) {
         (**self).render_cell(ctx, column, row, area, buf)
     }
+
+    fn render_cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        (**self).render_cell_text(column, row)
+    }
+
+    fn cell_tooltip(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        (**self).cell_tooltip(column, row)
+    }
 }
 
 /// Trait for accessing the table-data by the Table.
@@ -185,6 +249,22 @@ pub trait TableDataIter<'a> {
     /// Render the cell for the current line.
     /// * ctx - a lot of context data.
     fn render_cell(&self, ctx: &TableContext, column: usize, area: Rect, buf: &mut Buffer);
+
+    /// Plain text of the cell for the current line.
+    ///
+    /// Used for exporting rows, see [export](crate::export).
+    #[allow(unused_variables)]
+    fn render_cell_text(&self, column: usize) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    /// Full content of the cell for the current line.
+    ///
+    /// See [TableData::cell_tooltip].
+    #[allow(unused_variables)]
+    fn cell_tooltip(&self, column: usize) -> Option<Cow<'_, str>> {
+        None
+    }
 }
 
 /// Trait for the different selection models used by Table.
@@ -245,6 +325,22 @@ pub mod event {
         Changed,
         /// The selection has changed.
         Selected,
+        /// End was requested for a table with
+        /// [virtual_rows](crate::Table::virtual_rows).
+        /// The application should load the tail of the data,
+        /// update the estimated row count and move there.
+        LoadTail,
+        /// A row has been moved by drag and drop or by keyboard.
+        /// See [TableState::reorder](crate::TableState::reorder).
+        ///
+        /// The application must move the row in its data.
+        /// The selection is already at the new position.
+        RowMoved {
+            /// Original row.
+            from: usize,
+            /// New row.
+            to: usize,
+        },
     }
 
     impl ConsumedEvent for TableOutcome {
@@ -260,6 +356,8 @@ pub mod event {
                 TableOutcome::Unchanged => Outcome::Unchanged,
                 TableOutcome::Changed => Outcome::Changed,
                 TableOutcome::Selected => Outcome::Changed,
+                TableOutcome::LoadTail => Outcome::Changed,
+                TableOutcome::RowMoved { .. } => Outcome::Changed,
             }
         }
     }
@@ -279,6 +377,8 @@ pub mod event {
         Changed,
         /// Double click occurred. Contains (column, row)
         ClickClick(usize, usize),
+        /// Triple click occurred. Contains (column, row)
+        ClickClickClick(usize, usize),
     }
 
     impl From<DoubleClickOutcome> for Outcome {
@@ -288,6 +388,7 @@ pub mod event {
                 DoubleClickOutcome::Unchanged => Outcome::Unchanged,
                 DoubleClickOutcome::Changed => Outcome::Changed,
                 DoubleClickOutcome::ClickClick(_, _) => Outcome::Changed,
+                DoubleClickOutcome::ClickClickClick(_, _) => Outcome::Changed,
             }
         }
     }
@@ -329,6 +430,8 @@ pub mod event {
         CommitAndAppend,
         /// Commit the edit, edit next line.
         CommitAndEdit,
+        /// Commit the edit, continue editing the same line.
+        CommitAndStay,
         /// Insert an item at the selection.
         Insert,
         /// Remove the item at the selection.
@@ -364,6 +467,7 @@ pub mod event {
                 EditOutcome::Commit => Outcome::Unchanged,
                 EditOutcome::CommitAndAppend => Outcome::Unchanged,
                 EditOutcome::CommitAndEdit => Outcome::Unchanged,
+                EditOutcome::CommitAndStay => Outcome::Unchanged,
             }
         }
     }
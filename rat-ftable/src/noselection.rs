@@ -1,6 +1,6 @@
 use crate::event::TableOutcome;
 use crate::{TableSelection, TableState};
-use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Regular};
 use rat_focus::HasFocus;
 use rat_scrolled::event::ScrollOutcome;
 use rat_scrolled::ScrollAreaState;
@@ -64,7 +64,9 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
                 ct_event!(keycode press CONTROL-Down)
                 | ct_event!(keycode press CONTROL-End)
                 | ct_event!(keycode press End) => {
-                    if self.scroll_to_row(self.rows.saturating_sub(1)) {
+                    if self.virtual_rows {
+                        TableOutcome::LoadTail
+                    } else if self.scroll_to_row(self.rows.saturating_sub(1)) {
                         TableOutcome::Changed
                     } else {
                         TableOutcome::Unchanged
@@ -128,6 +130,11 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, TableOutcome> for TableState<NoSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> TableOutcome {
+        let r = self.handle_hover(event);
+        if r.is_consumed() {
+            return r;
+        }
+
         let mut sas = ScrollAreaState::new()
             .area(self.inner)
             .h_scroll(&mut self.hscroll)
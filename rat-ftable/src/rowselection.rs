@@ -132,8 +132,29 @@ impl RowSelection {
 
 impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<RowSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> TableOutcome {
+        if self.is_dragging() {
+            if let ct_event!(keycode press Esc) = event {
+                self.cancel_drag();
+                return TableOutcome::Changed;
+            }
+        }
+
         let res = if self.is_focused() {
             match event {
+                ct_event!(keycode press ALT-Up) if self.reorder => {
+                    if let Some((from, to)) = self.move_selected_row(true) {
+                        TableOutcome::RowMoved { from, to }
+                    } else {
+                        TableOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press ALT-Down) if self.reorder => {
+                    if let Some((from, to)) = self.move_selected_row(false) {
+                        TableOutcome::RowMoved { from, to }
+                    } else {
+                        TableOutcome::Unchanged
+                    }
+                }
                 ct_event!(keycode press Up) => {
                     if self.move_up(1) {
                         TableOutcome::Selected
@@ -160,7 +181,9 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
                 ct_event!(keycode press CONTROL-Down)
                 | ct_event!(keycode press CONTROL-End)
                 | ct_event!(keycode press End) => {
-                    if self.move_to(self.rows.saturating_sub(1)) {
+                    if self.virtual_rows {
+                        TableOutcome::LoadTail
+                    } else if self.move_to(self.rows.saturating_sub(1)) {
                         TableOutcome::Selected
                     } else {
                         TableOutcome::Unchanged
@@ -224,6 +247,18 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, TableOutcome> for TableState<RowSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> TableOutcome {
+        let r = self.handle_hover(event);
+        if r.is_consumed() {
+            return r;
+        }
+
+        if self.reorder {
+            let r = handle_reorder(self, event);
+            if r.is_consumed() {
+                return r;
+            }
+        }
+
         let mut r = match event {
             ct_event!(mouse any for m) if self.mouse.drag(self.table_area, m) => {
                 if self.move_to(self.row_at_drag((m.column, m.row))) {
@@ -334,6 +369,65 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, TableOutcome> for TableStat
     }
 }
 
+/// Drag and drop of the selected row.
+///
+/// A mouse-down on the selected row is left to the normal
+/// click handling. The drag starts with the first mouse drag
+/// after that.
+fn handle_reorder(
+    state: &mut TableState<RowSelection>,
+    event: &crossterm::event::Event,
+) -> TableOutcome {
+    match event {
+        ct_event!(mouse down Left for column, row) => {
+            state.drag_anchor = if state.table_area.contains((*column, *row).into()) {
+                state
+                    .row_at_clicked((*column, *row))
+                    .filter(|v| Some(*v) == state.selected_checked())
+            } else {
+                None
+            };
+            TableOutcome::Continue
+        }
+        ct_event!(mouse drag Left for column, row) if state.drag_anchor.is_some() => {
+            // auto-scroll at the edges.
+            let scrolled = if *row <= state.table_area.top() {
+                state.scroll_up(1)
+            } else if *row + 1 >= state.table_area.bottom() {
+                state.scroll_down(1)
+            } else {
+                false
+            };
+            let hover = min(
+                state.row_at_drag((*column, *row)),
+                state.rows.saturating_sub(1),
+            );
+            if scrolled || state.drag_hover != Some(hover) {
+                state.drag_hover = Some(hover);
+                TableOutcome::Changed
+            } else {
+                TableOutcome::Unchanged
+            }
+        }
+        ct_event!(mouse up Left for _column, _row) if state.is_dragging() => {
+            let from = state.drag_anchor.take().expect("anchor");
+            let to = state.drag_hover.take().expect("hover");
+            state.mouse.drag.set(false);
+            if from != to {
+                state.move_to(to);
+                TableOutcome::RowMoved { from, to }
+            } else {
+                TableOutcome::Changed
+            }
+        }
+        ct_event!(mouse up Left for _column, _row) => {
+            state.drag_anchor = None;
+            TableOutcome::Continue
+        }
+        _ => TableOutcome::Continue,
+    }
+}
+
 /// Handle all events.
 /// Table events are only processed if focus is true.
 /// Mouse events are processed if they are in range.
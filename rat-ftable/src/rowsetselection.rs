@@ -236,7 +236,9 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
                 ct_event!(keycode press CONTROL-Down)
                 | ct_event!(keycode press CONTROL-End)
                 | ct_event!(keycode press End) => {
-                    if self.move_to(self.rows.saturating_sub(1), false) {
+                    if self.virtual_rows {
+                        TableOutcome::LoadTail
+                    } else if self.move_to(self.rows.saturating_sub(1), false) {
                         TableOutcome::Selected
                     } else {
                         TableOutcome::Unchanged
@@ -346,6 +348,7 @@ impl HandleEvent<crossterm::event::Event, Regular, TableOutcome> for TableState<
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, TableOutcome> for TableState<RowSetSelection> {
     fn handle(&mut self, event: &crossterm::event::Event, _: MouseOnly) -> TableOutcome {
+        flow!(self.handle_hover(event));
         flow!(match event {
             ct_event!(mouse any for m) | ct_event!(mouse any CONTROL for m)
                 if self.mouse.drag(self.table_area, m)
@@ -1,12 +1,14 @@
 #![allow(clippy::collapsible_if)]
 
 use crate::_private::NonExhaustive;
-use crate::event::{DoubleClick, DoubleClickOutcome};
+use crate::event::{DoubleClick, DoubleClickOutcome, TableOutcome};
+use crate::export::{export_rows, ExportFormat};
 use crate::selection::{CellSelection, RowSelection, RowSetSelection};
 use crate::table::data::{DataRepr, DataReprIter};
-use crate::textdata::{Row, TextTableData};
-use crate::util::{fallback_select_style, revert_style, transfer_buffer};
+use crate::textdata::{render_column_text, ColumnSpec, Row, TextTableData};
+use crate::util::{fallback_select_style, render_drop_indicator, revert_style, transfer_buffer};
 use crate::{TableContext, TableData, TableDataIter, TableSelection};
+use crossterm::event::MouseEventKind;
 use rat_event::util::MouseFlags;
 use rat_event::{ct_event, HandleEvent};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
@@ -14,10 +16,11 @@ use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
 use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Flex, Layout, Rect};
-use ratatui::style::Style;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Text;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
-use ratatui::widgets::{Block, StatefulWidget, Widget};
+use ratatui::widgets::{Block, Clear, StatefulWidget, Widget};
 use std::cmp::{max, min};
 use std::collections::HashSet;
 use std::fmt::Debug;
@@ -43,11 +46,13 @@ use std::rc::Rc;
 pub struct Table<'a, Selection> {
     data: DataRepr<'a>,
     no_row_count: bool,
+    virtual_rows: bool,
 
     header: Option<Row<'a>>,
     footer: Option<Row<'a>>,
 
     widths: Vec<Constraint>,
+    column_specs: Vec<ColumnSpec>,
     flex: Flex,
     column_spacing: u16,
     layout_width: Option<u16>,
@@ -73,6 +78,9 @@ pub struct Table<'a, Selection> {
     show_footer_focus: bool,
 
     focus_style: Option<Style>,
+    drop_style: Option<Style>,
+    tooltip_style: Option<Style>,
+    show_tooltip: bool,
 
     debug: bool,
 
@@ -87,6 +95,7 @@ mod data {
     use ratatui::buffer::Buffer;
     use ratatui::layout::Rect;
     use ratatui::style::{Style, Stylize};
+    use std::borrow::Cow;
     use std::fmt::{Debug, Formatter};
 
     #[derive(Default)]
@@ -145,6 +154,53 @@ mod data {
         IterIter(Box<dyn TableDataIter<'a> + 'a>),
     }
 
+    impl DataReprIter<'_, '_> {
+        /// Group of the current row.
+        pub(super) fn group_of(&self) -> Option<usize> {
+            match self {
+                DataReprIter::IterData(v, Some(n)) => v.group_of(*n),
+                DataReprIter::IterDataRef(v, Some(n)) => v.group_of(*n),
+                _ => None,
+            }
+        }
+
+        /// The current row is the first row of a group.
+        pub(super) fn group_start(&self) -> Option<usize> {
+            let (group, prev) = match self {
+                DataReprIter::IterData(v, Some(n)) => {
+                    (v.group_of(*n), n.checked_sub(1).and_then(|p| v.group_of(p)))
+                }
+                DataReprIter::IterDataRef(v, Some(n)) => {
+                    (v.group_of(*n), n.checked_sub(1).and_then(|p| v.group_of(p)))
+                }
+                _ => return None,
+            };
+            if group.is_some() && group != prev {
+                group
+            } else {
+                None
+            }
+        }
+
+        /// Height of the group header for the current row.
+        pub(super) fn group_header_height(&self) -> u16 {
+            if self.group_start().is_some() {
+                1
+            } else {
+                0
+            }
+        }
+
+        /// Render a group header.
+        pub(super) fn render_group_header(&self, group: usize, area: Rect, buf: &mut Buffer) {
+            match self {
+                DataReprIter::IterData(v, _) => v.render_group_header(group, area, buf),
+                DataReprIter::IterDataRef(v, _) => v.render_group_header(group, area, buf),
+                _ => {}
+            }
+        }
+    }
+
     impl<'a> TableDataIter<'a> for DataReprIter<'a, '_> {
         fn rows(&self) -> Option<usize> {
             match self {
@@ -185,8 +241,12 @@ mod data {
                 DataReprIter::None => 1,
                 DataReprIter::Invalid(_) => 1,
                 DataReprIter::IterText(v, n) => v.row_height(n.expect("row")),
-                DataReprIter::IterData(v, n) => v.row_height(n.expect("row")),
-                DataReprIter::IterDataRef(v, n) => v.row_height(n.expect("row")),
+                DataReprIter::IterData(v, n) => {
+                    v.row_height(n.expect("row")) + self.group_header_height()
+                }
+                DataReprIter::IterDataRef(v, n) => {
+                    v.row_height(n.expect("row")) + self.group_header_height()
+                }
                 DataReprIter::IterIter(v) => v.row_height(),
             }
         }
@@ -231,6 +291,15 @@ mod data {
                 DataReprIter::IterIter(v) => v.render_cell(ctx, column, area, buf),
             }
         }
+
+        fn cell_tooltip(&self, column: usize) -> Option<Cow<'_, str>> {
+            match self {
+                DataReprIter::IterData(v, Some(n)) => v.cell_tooltip(column, *n),
+                DataReprIter::IterDataRef(v, Some(n)) => v.cell_tooltip(column, *n),
+                DataReprIter::IterIter(v) => v.cell_tooltip(column),
+                _ => None,
+            }
+        }
     }
 }
 
@@ -254,6 +323,10 @@ pub struct TableStyle {
     pub show_footer_focus: bool,
 
     pub focus_style: Option<Style>,
+    /// Drop indicator when reordering rows.
+    pub drop: Option<Style>,
+    /// Tooltip for truncated cells.
+    pub tooltip: Option<Style>,
 
     pub block: Option<Block<'static>>,
     pub border_style: Option<Style>,
@@ -302,6 +375,16 @@ pub struct TableState<Selection> {
     pub rows: usize,
     // debug info
     pub _counted_rows: usize,
+    /// Table is in virtual mode. See [Table::virtual_rows].
+    /// __read only__ Renewed with each render.
+    pub virtual_rows: bool,
+    /// Estimated row count for virtual mode.
+    /// __read+write__ Use [TableState::set_estimated_rows].
+    pub estimated_rows: usize,
+    /// Row height for the last page in virtual mode.
+    /// The tallest row of the visible window.
+    /// __read only__ Renewed with each render.
+    pub virtual_row_height: u16,
     /// Column count.
     /// __read only__ Renewed with each render.
     pub columns: usize,
@@ -317,6 +400,29 @@ pub struct TableState<Selection> {
     /// __read+write__ selection model. selection is not bound by rows.
     pub selection: Selection,
 
+    /// Reorder rows by drag and drop, or with Alt+Up/Down.
+    /// Only supported for [RowSelection].
+    /// __read+write__
+    pub reorder: bool,
+    /// Selected row under the last mouse-down. A reorder
+    /// drag starts with the next mouse drag.
+    /// __read only__
+    pub drag_anchor: Option<usize>,
+    /// Current target row of a reorder drag.
+    /// __read only__
+    pub drag_hover: Option<usize>,
+
+    /// Cell (column, row) under the mouse.
+    /// __read only__
+    pub hover: Option<(usize, usize)>,
+    /// Visible cells whose [cell_tooltip](crate::TableData::cell_tooltip)
+    /// doesn't fit the cell.
+    /// __read only__ Renewed with each render.
+    pub truncated: HashSet<(usize, usize)>,
+    /// Area of the tooltip, if one is shown.
+    /// __read only__ Renewed with each render.
+    pub tooltip_area: Option<Rect>,
+
     /// Helper for mouse interactions.
     pub mouse: MouseFlags,
 
@@ -328,9 +434,11 @@ impl<Selection> Default for Table<'_, Selection> {
         Self {
             data: Default::default(),
             no_row_count: Default::default(),
+            virtual_rows: Default::default(),
             header: Default::default(),
             footer: Default::default(),
             widths: Default::default(),
+            column_specs: Default::default(),
             flex: Default::default(),
             column_spacing: Default::default(),
             layout_width: Default::default(),
@@ -352,6 +460,9 @@ impl<Selection> Default for Table<'_, Selection> {
             select_footer_style: Default::default(),
             show_footer_focus: Default::default(),
             focus_style: Default::default(),
+            drop_style: Default::default(),
+            tooltip_style: Default::default(),
+            show_tooltip: Default::default(),
             debug: Default::default(),
             _phantom: Default::default(),
         }
@@ -621,6 +732,44 @@ impl<'a, Selection> Table<'a, Selection> {
         self
     }
 
+    /// Virtual mode for huge datasets with an unknown total.
+    ///
+    /// The row count is taken from [TableState::set_estimated_rows]
+    /// and the table never iterates beyond the visible window.
+    /// Rows that the iterator can't deliver are rendered empty.
+    ///
+    /// End and Ctrl+End don't try to reach the end of the data,
+    /// they return [TableOutcome::LoadTail](crate::event::TableOutcome::LoadTail)
+    /// instead. The application loads the tail, updates the
+    /// estimate with the now known count and moves there.
+    ///
+    /// ```rust ignore
+    /// match table_state.handle(event, Regular) {
+    ///     TableOutcome::LoadTail => {
+    ///         let total = log.load_tail();
+    ///         table_state.set_estimated_rows(total);
+    ///         table_state.move_to(total.saturating_sub(1));
+    ///         Control::Changed
+    ///     }
+    ///     r => r.into(),
+    /// }
+    /// ```
+    ///
+    /// The rows at the end are not known, so the last page
+    /// assumes each of them is as tall as the tallest row of
+    /// the visible window. Once the last row is visible the
+    /// last page is exact.
+    ///
+    /// With [RowSelection](crate::selection::RowSelection)
+    /// the selection may point beyond the estimate.
+    /// [TableState::move_to] raises the estimate in that case,
+    /// and scroll-to-selected works as usual. Moving with
+    /// the keyboard or with scroll_selected stays within the estimate.
+    pub fn virtual_rows(mut self, virtual_rows: bool) -> Self {
+        self.virtual_rows = virtual_rows;
+        self
+    }
+
     /// Set the table-header.
     #[inline]
     pub fn header(mut self, header: Row<'a>) -> Self {
@@ -645,6 +794,20 @@ impl<'a, Selection> Table<'a, Selection> {
         self
     }
 
+    /// Alignment, overflow policy and formatting per column.
+    ///
+    /// The default cell renderer for [Row]s uses these, and
+    /// the header and footer use alignment and overflow policy.
+    /// Custom [TableData::render_cell] implementations get them
+    /// via the [TableContext].
+    pub fn column_specs<I>(mut self, specs: I) -> Self
+    where
+        I: IntoIterator<Item = ColumnSpec>,
+    {
+        self.column_specs = specs.into_iter().collect();
+        self
+    }
+
     /// Flex for layout.
     #[inline]
     pub fn flex(mut self, flex: Flex) -> Self {
@@ -740,6 +903,12 @@ impl<'a, Selection> Table<'a, Selection> {
         if styles.focus_style.is_some() {
             self.focus_style = styles.focus_style;
         }
+        if styles.drop.is_some() {
+            self.drop_style = styles.drop;
+        }
+        if styles.tooltip.is_some() {
+            self.tooltip_style = styles.tooltip;
+        }
         if let Some(border_style) = styles.border_style {
             self.block = self.block.map(|v| v.border_style(border_style));
         }
@@ -862,6 +1031,37 @@ impl<'a, Selection> Table<'a, Selection> {
         self
     }
 
+    /// Style for the drop indicator when reordering rows.
+    /// It's patched onto the row-line above the drop position.
+    ///
+    /// Defaults to underlined.
+    #[inline]
+    pub fn drop_style(mut self, drop_style: Option<Style>) -> Self {
+        self.drop_style = drop_style;
+        self
+    }
+
+    /// Show a tooltip with the full content of a truncated cell
+    /// when the mouse hovers over it.
+    ///
+    /// The content comes from [TableData::cell_tooltip].
+    /// Without this the application can still use
+    /// [TableState::hovered_cell] and render a tooltip itself.
+    #[inline]
+    pub fn show_tooltip(mut self, show: bool) -> Self {
+        self.show_tooltip = show;
+        self
+    }
+
+    /// Style for the tooltip.
+    ///
+    /// Defaults to the reverted table style.
+    #[inline]
+    pub fn tooltip_style(mut self, tooltip_style: Option<Style>) -> Self {
+        self.tooltip_style = tooltip_style;
+        self
+    }
+
     /// Just some utility to help with debugging. Usually does nothing.
     pub fn debug(mut self, debug: bool) -> Self {
         self.debug = debug;
@@ -958,7 +1158,10 @@ where
         buf: &mut Buffer,
         state: &mut TableState<Selection>,
     ) {
-        if let Some(rows) = data.rows() {
+        state.virtual_rows = self.virtual_rows;
+        if self.virtual_rows {
+            state.rows = state.estimated_rows;
+        } else if let Some(rows) = data.rows() {
             state.rows = rows;
         }
         state.columns = self.widths.len();
@@ -969,6 +1172,22 @@ where
             .block(self.block.as_ref())
             .h_scroll(self.hscroll.as_ref())
             .v_scroll(self.vscroll.as_ref());
+        // measure with a row-height of 1, if the row count is known.
+        // it's corrected after rendering anyway.
+        let header_footer = self.header.as_ref().map(|v| v.height).unwrap_or(0)
+            + self.footer.as_ref().map(|v| v.height).unwrap_or(0);
+        let v_content = if self.virtual_rows || data.rows().is_some() {
+            Some(state.rows + header_footer as usize)
+        } else {
+            None
+        };
+        sa.measure(
+            area,
+            Some(self.total_width(0) as usize),
+            v_content,
+            Some(&mut state.hscroll),
+            Some(&mut state.vscroll),
+        );
         state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
 
         let l_rows = self.layout_areas(state.inner);
@@ -1011,6 +1230,9 @@ where
 
         // render table
         state.row_areas.clear();
+        state.truncated.clear();
+        state.tooltip_area = None;
+        let mut tooltip = None;
         state.vscroll.set_page_len(0);
         state.hscroll.set_page_len(area.width as usize);
 
@@ -1018,6 +1240,7 @@ where
         let mut row = None;
         let mut row_y = state.table_area.y;
         let mut row_heights = Vec::new();
+        let mut grouped = false;
         #[cfg(debug_assertions)]
         let mut insane_offset = false;
 
@@ -1031,23 +1254,56 @@ where
             select_style: None,
             space_area: Default::default(),
             row_area: Default::default(),
+            align: Default::default(),
+            overflow: Default::default(),
+            format: None,
             non_exhaustive: NonExhaustive,
         };
 
         if data.nth(state.vscroll.offset()) {
             row = Some(state.vscroll.offset());
+
+            // scrolled into a group. pin the group header to the top.
+            if let Some(group) = data.group_of() {
+                if data.group_start().is_none() && state.table_area.height > 0 {
+                    let render_header_area = Rect::new(0, 0, width, 1);
+                    row_buf.resize(render_header_area);
+                    row_buf.set_style(render_header_area, self.style);
+                    data.render_group_header(group, render_header_area, &mut row_buf);
+                    transfer_buffer(
+                        &mut row_buf,
+                        state.hscroll.offset() as u16,
+                        Rect::new(state.table_area.x, row_y, state.table_area.width, 1),
+                        buf,
+                    );
+                    row_y += 1;
+                }
+            }
+
             loop {
+                grouped |= data.group_of().is_some();
                 ctx.row_style = data.row_style();
                 // We render each row to a temporary buffer.
                 // For ease of use we start each row at 0,0.
                 // We still only render at least partially visible cells.
                 let render_row_area = Rect::new(0, 0, width, data.row_height());
-                ctx.row_area = render_row_area;
                 row_buf.resize(render_row_area);
+                row_buf.set_style(render_row_area, self.style);
+                // A group header goes before the cells.
+                let header_height = data.group_header_height().min(render_row_area.height);
+                if let Some(group) = data.group_start() {
+                    let render_header_area = Rect::new(0, 0, width, header_height);
+                    data.render_group_header(group, render_header_area, &mut row_buf);
+                }
+                let render_cells_area = Rect::new(
+                    0,
+                    header_height,
+                    width,
+                    render_row_area.height - header_height,
+                );
+                ctx.row_area = render_cells_area;
                 if let Some(row_style) = ctx.row_style {
-                    row_buf.set_style(render_row_area, row_style);
-                } else {
-                    row_buf.set_style(render_row_area, self.style);
+                    row_buf.set_style(render_cells_area, row_style);
                 }
                 row_heights.push(render_row_area.height);
 
@@ -1075,17 +1331,27 @@ where
 
                         let render_cell_area = Rect::new(
                             l_columns[col].x,
-                            0,
+                            render_cells_area.y,
                             l_columns[col].width,
-                            render_row_area.height,
+                            render_cells_area.height,
                         );
                         ctx.space_area = Rect::new(
                             l_spacers[col + 1].x,
-                            0,
+                            render_cells_area.y,
                             l_spacers[col + 1].width,
-                            render_row_area.height,
+                            render_cells_area.height,
                         );
 
+                        if let Some(spec) = self.column_specs.get(col) {
+                            ctx.align = spec.align;
+                            ctx.overflow = spec.overflow;
+                            ctx.format.clone_from(&spec.format);
+                        } else {
+                            ctx.align = Default::default();
+                            ctx.overflow = Default::default();
+                            ctx.format = None;
+                        }
+
                         if state.selection.is_selected_cell(col, row.expect("row")) {
                             ctx.selected_cell = true;
                             ctx.selected_row = false;
@@ -1138,6 +1404,19 @@ where
                                 row_buf.set_style(ctx.space_area, select_style);
                             }
                             data.render_cell(&ctx, col, render_cell_area, &mut row_buf);
+
+                            if let Some(text) = data.cell_tooltip(col) {
+                                let cell = (col, row.expect("row"));
+                                let text = Text::from(text.into_owned());
+                                if text.width() > render_cell_area.width as usize
+                                    || text.height() > render_cell_area.height as usize
+                                {
+                                    state.truncated.insert(cell);
+                                    if self.show_tooltip && state.hover == Some(cell) {
+                                        tooltip = Some(text);
+                                    }
+                                }
+                            }
                         }
 
                         col += 1;
@@ -1166,7 +1445,8 @@ where
             // so don't alter row here.
 
             // if this first skip fails all bets are off.
-            if data.rows().is_none() || data.rows() == Some(0) {
+            // virtual tables may just not have loaded this part yet.
+            if self.virtual_rows || data.rows().is_none() || data.rows() == Some(0) {
                 // this is ok
             } else {
                 #[cfg(debug_assertions)]
@@ -1176,12 +1456,51 @@ where
             }
         }
 
+        if let (Some(from), Some(to)) = (state.drag_anchor, state.drag_hover) {
+            render_drop_indicator(
+                &state.row_areas,
+                state.vscroll.offset(),
+                from,
+                to,
+                self.drop_style.unwrap_or(Style::new().underlined()),
+                buf,
+            );
+        }
+
+        if let (Some(text), Some((col, row))) = (tooltip, state.hover) {
+            self.render_tooltip(text, col, row, buf, state);
+        }
+
         // maximum offsets
         #[allow(unused_variables)]
         let algorithm;
         #[allow(unused_assignments)]
         {
-            if let Some(rows) = data.rows() {
+            if self.virtual_rows {
+                algorithm = 3;
+
+                // Never iterate beyond the visible window.
+                // The row count is whatever the application says.
+                state.rows = state.estimated_rows;
+                state._counted_rows = state.estimated_rows;
+                if state.vscroll.page_len() == 0 {
+                    state.vscroll.set_page_len(state.table_area.height as usize);
+                }
+                state.virtual_row_height = row_heights.iter().copied().max().unwrap_or(1).max(1);
+                // the last row is visible, the heights are known.
+                let last_page = if row.is_some_and(|v| v + 1 >= state.rows) {
+                    state.calc_last_page(row_heights, grouped)
+                } else {
+                    None
+                };
+                if let Some(last_page) = last_page {
+                    state
+                        .vscroll
+                        .set_max_offset(state.rows.saturating_sub(last_page));
+                } else {
+                    state.vscroll.set_max_offset(state.virtual_max_offset());
+                }
+            } else if let Some(rows) = data.rows() {
                 algorithm = 0;
                 // skip to a guess for the last page.
                 // the guess uses row-height is 1, which may read a few more lines than
@@ -1199,6 +1518,7 @@ where
                     let mut sum_height = row_heights.iter().sum::<u16>();
                     row = Some(row.map_or(nth_row, |row| row + nth_row + 1));
                     loop {
+                        grouped |= data.group_of().is_some();
                         let row_height = data.row_height();
                         row_heights.push(row_height);
 
@@ -1237,7 +1557,7 @@ where
                 state._counted_rows = row.map_or(0, |v| v + 1);
 
                 // have we got a page worth of data?
-                if let Some(last_page) = state.calc_last_page(row_heights) {
+                if let Some(last_page) = state.calc_last_page(row_heights, grouped) {
                     state.vscroll.set_max_offset(state.rows - last_page);
                 } else {
                     // we don't have enough data to establish the last page.
@@ -1277,6 +1597,7 @@ where
                 // Read all the rest to establish the exact row-count.
                 let mut sum_height = row_heights.iter().sum::<u16>();
                 while data.nth(0) {
+                    grouped |= data.group_of().is_some();
                     let row_height = data.row_height();
                     row_heights.push(row_height);
 
@@ -1296,7 +1617,7 @@ where
                 state._counted_rows = row.map_or(0, |v| v + 1);
 
                 // have we got a page worth of data?
-                if let Some(last_page) = state.calc_last_page(row_heights) {
+                if let Some(last_page) = state.calc_last_page(row_heights, grouped) {
                     state.vscroll.set_max_offset(state.rows - last_page);
                 } else {
                     state.vscroll.set_max_offset(0);
@@ -1340,6 +1661,44 @@ where
         }
     }
 
+    /// Render the tooltip below the cell, or above if there
+    /// is not enough space.
+    fn render_tooltip(
+        &self,
+        text: Text<'_>,
+        col: usize,
+        row: usize,
+        buf: &mut Buffer,
+        state: &mut TableState<Selection>,
+    ) {
+        let Some((row_area, cells)) = state.row_cells(row) else {
+            return;
+        };
+        let cell_area = cells[col];
+        if cell_area.is_empty() {
+            return;
+        }
+
+        let width = (text.width() as u16).min(buf.area.width);
+        let height = (text.height() as u16).min(buf.area.height);
+        let x = cell_area.x.min(buf.area.right().saturating_sub(width));
+        let y = if row_area.bottom() + height <= buf.area.bottom() {
+            row_area.bottom()
+        } else {
+            row_area.y.saturating_sub(height).max(buf.area.y)
+        };
+        let tooltip_area = Rect::new(x, y, width, height).intersection(buf.area);
+
+        Clear.render(tooltip_area, buf);
+        buf.set_style(
+            tooltip_area,
+            self.tooltip_style.unwrap_or(revert_style(self.style)),
+        );
+        text.render(tooltip_area, buf);
+
+        state.tooltip_area = Some(tooltip_area);
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn render_footer(
         &self,
@@ -1396,7 +1755,15 @@ where
                         if let Some(cell_style) = cell.style {
                             row_buf.set_style(render_cell_area, cell_style);
                         }
-                        cell.content.clone().render(render_cell_area, &mut row_buf);
+                        let spec = self.column_specs.get(col);
+                        render_column_text(
+                            &cell.content,
+                            spec.map(|v| v.align).unwrap_or_default(),
+                            spec.map(|v| v.overflow).unwrap_or_default(),
+                            None,
+                            render_cell_area,
+                            &mut row_buf,
+                        );
                     }
                 }
 
@@ -1464,7 +1831,15 @@ where
                         if let Some(cell_style) = cell.style {
                             row_buf.set_style(render_cell_area, cell_style);
                         }
-                        cell.content.clone().render(render_cell_area, &mut row_buf);
+                        let spec = self.column_specs.get(col);
+                        render_column_text(
+                            &cell.content,
+                            spec.map(|v| v.align).unwrap_or_default(),
+                            spec.map(|v| v.overflow).unwrap_or_default(),
+                            None,
+                            render_cell_area,
+                            &mut row_buf,
+                        );
                     }
                 }
 
@@ -1563,6 +1938,8 @@ impl Default for TableStyle {
             show_header_focus: false,
             show_footer_focus: false,
             focus_style: None,
+            drop: None,
+            tooltip: None,
             block: None,
             border_style: None,
             scroll: None,
@@ -1585,10 +1962,19 @@ impl<Selection: Clone> Clone for TableState<Selection> {
             footer_area: self.footer_area,
             rows: self.rows,
             _counted_rows: self._counted_rows,
+            virtual_rows: self.virtual_rows,
+            estimated_rows: self.estimated_rows,
+            virtual_row_height: self.virtual_row_height,
             columns: self.columns,
             vscroll: self.vscroll.clone(),
             hscroll: self.hscroll.clone(),
             selection: self.selection.clone(),
+            reorder: self.reorder,
+            drag_anchor: None,
+            drag_hover: None,
+            hover: None,
+            truncated: self.truncated.clone(),
+            tooltip_area: self.tooltip_area,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -1609,10 +1995,19 @@ impl<Selection: Default> Default for TableState<Selection> {
             footer_area: Default::default(),
             rows: Default::default(),
             _counted_rows: Default::default(),
+            virtual_rows: Default::default(),
+            estimated_rows: Default::default(),
+            virtual_row_height: 1,
             columns: Default::default(),
             vscroll: Default::default(),
             hscroll: Default::default(),
             selection: Default::default(),
+            reorder: Default::default(),
+            drag_anchor: Default::default(),
+            drag_hover: Default::default(),
+            hover: Default::default(),
+            truncated: Default::default(),
+            tooltip_area: Default::default(),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -1646,6 +2041,7 @@ impl<Selection> RelocatableState for TableState<Selection> {
         relocate_areas(self.row_areas.as_mut_slice(), shift, clip);
         relocate_areas(self.column_areas.as_mut_slice(), shift, clip);
         relocate_areas(self.column_layout.as_mut_slice(), shift, clip);
+        self.tooltip_area = self.tooltip_area.map(|v| relocate_area(v, shift, clip));
 
         self.hscroll.relocate(shift, clip);
         self.vscroll.relocate(shift, clip);
@@ -1653,18 +2049,25 @@ impl<Selection> RelocatableState for TableState<Selection> {
 }
 
 impl<Selection> TableState<Selection> {
-    fn calc_last_page(&self, mut row_heights: Vec<u16>) -> Option<usize> {
+    fn calc_last_page(&self, mut row_heights: Vec<u16>, grouped: bool) -> Option<usize> {
+        // leave room for a pinned group header.
+        let page_height = if grouped {
+            self.table_area.height.saturating_sub(1)
+        } else {
+            self.table_area.height
+        };
+
         let mut sum_heights = 0;
         let mut n_rows = 0;
         while let Some(h) = row_heights.pop() {
             sum_heights += h;
             n_rows += 1;
-            if sum_heights >= self.table_area.height {
+            if sum_heights >= page_height {
                 break;
             }
         }
 
-        if sum_heights < self.table_area.height {
+        if sum_heights < page_height {
             None
         } else {
             Some(n_rows)
@@ -1702,6 +2105,32 @@ impl<Selection> TableState<Selection> {
     pub fn columns(&self) -> usize {
         self.columns
     }
+
+    /// Estimated number of rows for [Table::virtual_rows].
+    #[inline]
+    pub fn estimated_rows(&self) -> usize {
+        self.estimated_rows
+    }
+
+    /// Set the estimated number of rows for [Table::virtual_rows].
+    ///
+    /// The scrollbar works with this value. It can be changed
+    /// any time the application knows better, and takes effect
+    /// immediately, not only after the next render.
+    pub fn set_estimated_rows(&mut self, rows: usize) {
+        self.estimated_rows = rows;
+        if self.virtual_rows {
+            self.rows = rows;
+            self.vscroll.set_max_offset(self.virtual_max_offset());
+        }
+    }
+
+    /// Max row offset for [Table::virtual_rows], with each row
+    /// as tall as [virtual_row_height](TableState::virtual_row_height).
+    fn virtual_max_offset(&self) -> usize {
+        let page_rows = (self.table_area.height / self.virtual_row_height.max(1)).max(1);
+        self.rows.saturating_sub(page_rows as usize)
+    }
 }
 
 // Table areas
@@ -1790,6 +2219,75 @@ impl<Selection> TableState<Selection> {
     }
 }
 
+// Hover
+impl<Selection> TableState<Selection> {
+    /// Cell (column, row) under the mouse.
+    ///
+    /// Can be used to render a tooltip with a Hover widget.
+    pub fn hovered_cell(&self) -> Option<(usize, usize)> {
+        self.hover
+    }
+
+    /// The [cell_tooltip](crate::TableData::cell_tooltip) didn't
+    /// fit the cell at the last render.
+    pub fn is_truncated(&self, column: usize, row: usize) -> bool {
+        self.truncated.contains(&(column, row))
+    }
+
+    /// The hovered cell is truncated, and a tooltip is due.
+    pub fn has_tooltip(&self) -> bool {
+        self.hover.is_some_and(|v| self.truncated.contains(&v))
+    }
+
+    /// Clear the hover state.
+    pub fn clear_hover(&mut self) -> bool {
+        self.hover.take().is_some()
+    }
+
+    /// Track the cell under the mouse.
+    ///
+    /// Only mouse-moves are consumed. They report Unchanged if only
+    /// the hovered cell changes, and Changed if a tooltip appears
+    /// or disappears.
+    pub(crate) fn handle_hover(&mut self, event: &crossterm::event::Event) -> TableOutcome {
+        let ct_event!(mouse any for m) = event else {
+            return TableOutcome::Continue;
+        };
+
+        let old_hover = self.hover;
+        let old_tooltip = self.has_tooltip();
+
+        let pos = (m.column, m.row);
+        if !self.table_area.contains(pos.into()) {
+            self.hover = None;
+        } else {
+            match m.kind {
+                MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+                    self.hover = self.cell_at_clicked(pos);
+                }
+                MouseEventKind::ScrollUp
+                | MouseEventKind::ScrollDown
+                | MouseEventKind::ScrollLeft
+                | MouseEventKind::ScrollRight => {
+                    // the content under the mouse changes.
+                    self.hover = None;
+                }
+                _ => {}
+            }
+        }
+
+        if m.kind != MouseEventKind::Moved {
+            TableOutcome::Continue
+        } else if old_tooltip != self.has_tooltip() {
+            TableOutcome::Changed
+        } else if old_hover != self.hover && self.hover.is_some() {
+            TableOutcome::Unchanged
+        } else {
+            TableOutcome::Continue
+        }
+    }
+}
+
 // Offset related.
 impl<Selection: TableSelection> TableState<Selection> {
     /// Sets both offsets to 0.
@@ -1946,6 +2444,17 @@ impl<Selection: TableSelection> TableState<Selection> {
 }
 
 impl TableState<RowSelection> {
+    /// Export the selected row.
+    /// See [export](crate::export).
+    pub fn export_selection<'a>(&self, data: &dyn TableData<'a>, format: ExportFormat) -> String {
+        export_rows(
+            data,
+            self.selection.selected().into_iter(),
+            0..self.columns,
+            format,
+        )
+    }
+
     /// Update the state to match adding items.
     /// This corrects the number of rows, offset and selection.
     pub fn items_added(&mut self, pos: usize, n: usize) {
@@ -2024,8 +2533,14 @@ impl TableState<RowSelection> {
 
     /// Move the selection to the given row.
     /// Ensures the row is visible afterward.
+    ///
+    /// For [Table::virtual_rows] the row may be beyond the
+    /// estimated row count. The estimate is raised to include it.
     #[inline]
     pub fn move_to(&mut self, row: usize) -> bool {
+        if self.virtual_rows && row >= self.rows {
+            self.set_estimated_rows(row.saturating_add(1));
+        }
         let r = self.selection.move_to(row, self.rows.saturating_sub(1));
         let s = self.scroll_to_row(self.selection.selected().expect("row"));
         r || s
@@ -2048,9 +2563,46 @@ impl TableState<RowSelection> {
         let s = self.scroll_to_row(self.selection.selected().expect("row"));
         r || s
     }
+
+    /// Is a reorder drag active.
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.drag_hover.is_some()
+    }
+
+    /// Cancel a reorder drag.
+    pub fn cancel_drag(&mut self) -> bool {
+        self.drag_hover = None;
+        self.drag_anchor.take().is_some()
+    }
+
+    /// Move the selected row by keyboard.
+    ///
+    /// Moves the selection and returns the (from, to) rows
+    /// of the move, if any.
+    pub fn move_selected_row(&mut self, up: bool) -> Option<(usize, usize)> {
+        let from = self.selected_checked()?;
+        let to = if up {
+            from.checked_sub(1)?
+        } else if from + 1 < self.rows {
+            from + 1
+        } else {
+            return None;
+        };
+        self.move_to(to);
+        Some((from, to))
+    }
 }
 
 impl TableState<RowSetSelection> {
+    /// Export the selected rows in ascending order.
+    /// See [export](crate::export).
+    pub fn export_selection<'a>(&self, data: &dyn TableData<'a>, format: ExportFormat) -> String {
+        let mut rows = self.selection.selected().into_iter().collect::<Vec<_>>();
+        rows.sort();
+        export_rows(data, rows.into_iter(), 0..self.columns, format)
+    }
+
     /// Clear the selection.
     #[inline]
     pub fn clear_selection(&mut self) {
@@ -2148,6 +2700,16 @@ impl TableState<RowSetSelection> {
 }
 
 impl TableState<CellSelection> {
+    /// Export the selected cell.
+    /// See [export](crate::export).
+    pub fn export_selection<'a>(&self, data: &dyn TableData<'a>, format: ExportFormat) -> String {
+        if let Some((column, row)) = self.selection.selected() {
+            export_rows(data, [row].into_iter(), column..column + 1, format)
+        } else {
+            String::new()
+        }
+    }
+
     #[inline]
     pub fn clear_selection(&mut self) {
         self.selection.clear();
@@ -2261,13 +2823,20 @@ impl TableState<CellSelection> {
 impl<Selection> HandleEvent<crossterm::event::Event, DoubleClick, DoubleClickOutcome>
     for TableState<Selection>
 {
-    /// Handles double-click events on the table.
+    /// Handles double-click and triple-click events on the table.
     fn handle(
         &mut self,
         event: &crossterm::event::Event,
         _keymap: DoubleClick,
     ) -> DoubleClickOutcome {
         match event {
+            ct_event!(mouse any for m) if self.mouse.tripleclick(self.table_area, m) => {
+                if let Some((col, row)) = self.cell_at_clicked((m.column, m.row)) {
+                    DoubleClickOutcome::ClickClickClick(col, row)
+                } else {
+                    DoubleClickOutcome::Continue
+                }
+            }
             ct_event!(mouse any for m) if self.mouse.doubleclick(self.table_area, m) => {
                 if let Some((col, row)) = self.cell_at_clicked((m.column, m.row)) {
                     DoubleClickOutcome::ClickClick(col, row)
@@ -2,14 +2,22 @@
 //! Implements a Row and a Cell struct that are compatible to ratatui.
 //! You only need these if you use preformatted data.
 //!
+//! [ColumnSpec] adds alignment, overflow handling and formatting
+//! per column. Set them with [Table::column_specs](crate::Table::column_specs).
+//!
 
 use crate::_private::NonExhaustive;
 use crate::{TableContext, TableData};
+use format_num_pattern::{Locale, NumberFmtError, NumberFormat, NumberSymbols};
+use rat_text::WidthPolicy;
 use ratatui::buffer::Buffer;
-use ratatui::layout::Rect;
-use ratatui::prelude::{Style, Text};
+use ratatui::layout::{Alignment, Rect};
+use ratatui::prelude::{Line, Span, Style, Text};
 use ratatui::style::Styled;
 use ratatui::widgets::Widget;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Internal impl for TableData using pre-rendered Cells.
 #[derive(Debug, Default, Clone)]
@@ -59,18 +67,285 @@ impl<'a> TableData<'a> for TextTableData<'a> {
         }
     }
 
-    fn render_cell(&self, _ctx: &TableContext, c: usize, r: usize, area: Rect, buf: &mut Buffer) {
+    fn render_cell(&self, ctx: &TableContext, c: usize, r: usize, area: Rect, buf: &mut Buffer) {
         if let Some(row) = self.rows.get(r) {
             if let Some(cell) = row.cell(c) {
                 if let Some(style) = cell.style {
                     buf.set_style(area, style);
                 }
-                cell.content.clone().render(area, buf);
+                render_text(ctx, &cell.content, area, buf);
+            }
+        }
+    }
+}
+
+/// Overflow policy for text that is wider than the cell.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Cut off at the cell border.
+    #[default]
+    Clip,
+    /// Replace the start with an ellipsis.
+    EllipsisLeft,
+    /// Replace the end with an ellipsis.
+    EllipsisRight,
+    /// Replace the middle with an ellipsis. Good for paths.
+    EllipsisMiddle,
+}
+
+/// Formatter for the content of a column.
+///
+/// Gets the text of one line of the cell and returns
+/// the replacement.
+#[derive(Clone)]
+pub struct CellFormat(Rc<dyn Fn(&str) -> String>);
+
+impl Debug for CellFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CellFormat").finish_non_exhaustive()
+    }
+}
+
+impl CellFormat {
+    /// New formatter.
+    pub fn new(f: impl Fn(&str) -> String + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+
+    /// Format numbers with the given pattern and the
+    /// number symbols of the locale.
+    ///
+    /// See [format_num_pattern::NumberFormat] for the pattern.
+    /// Text that is not a number is left as is.
+    pub fn number(pattern: &str, locale: Locale) -> Result<Self, NumberFmtError> {
+        let format = NumberFormat::news(pattern, NumberSymbols::numeric(locale))?;
+        Ok(Self::new(move |s| {
+            let t = s.trim();
+            let r = if let Ok(v) = t.parse::<i128>() {
+                format.fmt(v)
+            } else if let Ok(v) = t.parse::<f64>() {
+                format.fmt(v)
+            } else {
+                return s.to_string();
+            };
+            match r {
+                Ok(v) => v.trim().to_string(),
+                Err(_) => s.to_string(),
             }
+        }))
+    }
+
+    /// Apply the formatter.
+    pub fn format(&self, s: &str) -> String {
+        (self.0)(s)
+    }
+}
+
+/// Metadata for one column.
+#[derive(Debug, Clone)]
+pub struct ColumnSpec {
+    /// Alignment of the cell content.
+    pub align: Alignment,
+    /// Handling of content wider than the cell.
+    pub overflow: Overflow,
+    /// Formatter for the cell content.
+    pub format: Option<CellFormat>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for ColumnSpec {
+    fn default() -> Self {
+        Self {
+            align: Alignment::Left,
+            overflow: Default::default(),
+            format: Default::default(),
+            non_exhaustive: NonExhaustive,
         }
     }
 }
 
+impl ColumnSpec {
+    /// New column with the defaults.
+    /// Left aligned and clipped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Right aligned number column.
+    ///
+    /// See [CellFormat::number].
+    pub fn number(pattern: &str, locale: Locale) -> Result<Self, NumberFmtError> {
+        Ok(Self::new()
+            .align(Alignment::Right)
+            .format(CellFormat::number(pattern, locale)?))
+    }
+
+    /// Alignment.
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Overflow policy.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Formatter.
+    pub fn format(mut self, format: CellFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+}
+
+/// Render the text with the alignment, formatting and
+/// overflow policy of the column given in the context.
+///
+/// This is what the default cell renderer uses.
+/// Explicit alignments of the text take precedence.
+pub fn render_text(ctx: &TableContext, text: &Text<'_>, area: Rect, buf: &mut Buffer) {
+    render_column_text(
+        text,
+        ctx.align,
+        ctx.overflow,
+        ctx.format.as_ref(),
+        area,
+        buf,
+    );
+}
+
+pub(crate) fn render_column_text(
+    text: &Text<'_>,
+    align: Alignment,
+    overflow: Overflow,
+    format: Option<&CellFormat>,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let lines = text
+        .lines
+        .iter()
+        .map(|line| {
+            let line = if let Some(format) = format {
+                let s = line
+                    .spans
+                    .iter()
+                    .map(|v| v.content.as_ref())
+                    .collect::<String>();
+                let style = line.spans.first().map(|v| v.style).unwrap_or_default();
+                Line {
+                    spans: vec![Span::styled(format.format(&s), style)],
+                    ..line.clone()
+                }
+            } else {
+                line.clone()
+            };
+            fit_line(line, area.width, overflow)
+        })
+        .collect::<Vec<_>>();
+
+    Text {
+        lines,
+        style: text.style,
+        alignment: Some(text.alignment.unwrap_or(align)),
+    }
+    .render(area, buf);
+}
+
+/// Fit a line to the given width using the overflow policy.
+///
+/// Widths are calculated per grapheme, so wide characters never
+/// overflow the width. The ellipsis takes the style of its neighbour.
+pub fn fit_line(line: Line<'_>, width: u16, overflow: Overflow) -> Line<'_> {
+    let policy = WidthPolicy::default();
+    let width = width as usize;
+
+    if overflow == Overflow::Clip {
+        return line;
+    }
+    let line_width = line
+        .spans
+        .iter()
+        .flat_map(|v| v.content.graphemes(true))
+        .map(|v| policy.width(v) as usize)
+        .sum::<usize>();
+    if line_width <= width {
+        return line;
+    }
+
+    let graphemes = line
+        .spans
+        .iter()
+        .flat_map(|v| v.content.graphemes(true).map(|g| (g, v.style)))
+        .collect::<Vec<_>>();
+    let avail = width.saturating_sub(1);
+
+    // count of graphemes from the start and from the end that fit.
+    let head = |max: usize| {
+        let mut w = 0;
+        graphemes
+            .iter()
+            .take_while(|(g, _)| {
+                w += policy.width(g) as usize;
+                w <= max
+            })
+            .count()
+    };
+    let tail = |max: usize| {
+        let mut w = 0;
+        graphemes
+            .iter()
+            .rev()
+            .take_while(|(g, _)| {
+                w += policy.width(g) as usize;
+                w <= max
+            })
+            .count()
+    };
+
+    let (n_head, n_tail) = match overflow {
+        Overflow::Clip => unreachable!(),
+        Overflow::EllipsisLeft => (0, tail(avail)),
+        Overflow::EllipsisRight => (head(avail), 0),
+        Overflow::EllipsisMiddle => {
+            let n_head = head(avail.div_ceil(2));
+            let head_width = graphemes[..n_head]
+                .iter()
+                .map(|(g, _)| policy.width(g) as usize)
+                .sum::<usize>();
+            (n_head, tail(avail - head_width))
+        }
+    };
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut push = |g: &str, style: Style| match spans.last_mut() {
+        Some(last) if last.style == style => last.content.to_mut().push_str(g),
+        _ => spans.push(Span::styled(g.to_string(), style)),
+    };
+    for (g, style) in &graphemes[..n_head] {
+        push(g, *style);
+    }
+    if width > 0 {
+        let style = graphemes
+            .get(n_head.saturating_sub(1))
+            .or(graphemes.last())
+            .map(|(_, v)| *v)
+            .unwrap_or_default();
+        push("…", style);
+    }
+    for (g, style) in &graphemes[graphemes.len() - n_tail..] {
+        push(g, *style);
+    }
+
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
 impl Default for Row<'_> {
     fn default() -> Self {
         Self {
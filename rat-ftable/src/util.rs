@@ -50,3 +50,45 @@ pub(crate) fn transfer_buffer(tmp: &mut Buffer, h_offset: u16, view_area: Rect,
         }
     }
 }
+
+/// Render the drop indicator for reordering rows.
+///
+/// The style is patched onto the last line of the row above the
+/// drop position. If that row is not visible, the first line of
+/// the target row is used.
+///
+/// * row_areas: Visible row areas starting with offset.
+/// * from: Row being dragged.
+/// * to: Target row.
+pub(crate) fn render_drop_indicator(
+    row_areas: &[Rect],
+    offset: usize,
+    from: usize,
+    to: usize,
+    style: Style,
+    buf: &mut Buffer,
+) {
+    if from == to {
+        return;
+    }
+
+    let visible = |row: usize| {
+        row.checked_sub(offset)
+            .and_then(|v| row_areas.get(v))
+            .filter(|v| !v.is_empty())
+    };
+
+    let above = if to > from {
+        Some(to)
+    } else {
+        to.checked_sub(1)
+    };
+    let line = if let Some(area) = above.and_then(visible) {
+        Rect::new(area.x, area.bottom() - 1, area.width, 1)
+    } else if let Some(area) = visible(to) {
+        Rect::new(area.x, area.y, area.width, 1)
+    } else {
+        return;
+    };
+    buf.set_style(line, style);
+}
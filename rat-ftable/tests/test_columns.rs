@@ -0,0 +1,140 @@
+use format_num_pattern::Locale;
+use rat_ftable::selection::RowSelection;
+use rat_ftable::textdata::{fit_line, CellFormat, ColumnSpec, Overflow, Row};
+use rat_ftable::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Constraint, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::StatefulWidget;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn fit(s: &str, width: u16, overflow: Overflow) -> String {
+    fit_line(Line::from(s), width, overflow).to_string()
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].symbol())
+        .collect::<String>()
+}
+
+#[test]
+fn test_fit() {
+    assert_eq!(fit("abcdefgh", 5, Overflow::Clip), "abcdefgh");
+    assert_eq!(fit("abcdefgh", 8, Overflow::EllipsisRight), "abcdefgh");
+    assert_eq!(fit("abcdefgh", 5, Overflow::EllipsisRight), "abcd…");
+    assert_eq!(fit("abcdefgh", 5, Overflow::EllipsisLeft), "…efgh");
+    assert_eq!(
+        fit("/usr/local/bin", 7, Overflow::EllipsisMiddle),
+        "/us…bin"
+    );
+    assert_eq!(
+        fit("/usr/local/bin", 8, Overflow::EllipsisMiddle),
+        "/usr…bin"
+    );
+    assert_eq!(fit("abc", 1, Overflow::EllipsisRight), "…");
+    assert_eq!(fit("abc", 0, Overflow::EllipsisRight), "");
+}
+
+#[test]
+fn test_fit_wide() {
+    // never overflows, even if a wide char would only fit halfway.
+    assert_eq!(fit("日本語テキスト", 6, Overflow::EllipsisRight), "日本…");
+    assert_eq!(fit("日本語テキスト", 6, Overflow::EllipsisLeft), "…スト");
+    assert_eq!(
+        fit("日本語テキスト", 7, Overflow::EllipsisMiddle),
+        "日…スト"
+    );
+}
+
+#[test]
+fn test_fit_styles() {
+    let red = Style::new().fg(Color::Red);
+    let blue = Style::new().fg(Color::Blue);
+    let l = Line::from(vec![Span::styled("abcd", red), Span::styled("efgh", blue)]);
+
+    let l = fit_line(l, 6, Overflow::EllipsisRight);
+    assert_eq!(
+        l.spans,
+        vec![Span::styled("abcd", red), Span::styled("e…", blue)]
+    );
+}
+
+#[test]
+fn test_number_format() {
+    let f = CellFormat::number("###,##0.00", Locale::en_US).expect("fmt");
+    assert_eq!(f.format("234567.5"), "234,567.50");
+    assert_eq!(f.format("12"), "12.00");
+    assert_eq!(f.format("n/a"), "n/a");
+
+    let f = CellFormat::number("###,##0", Locale::de_AT).expect("fmt");
+    assert_eq!(f.format("12345"), "12.345");
+}
+
+#[test]
+fn test_render_rows() {
+    let mut state = TableState::<RowSelection>::new();
+    let area = Rect::new(0, 0, 20, 3);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .rows([
+            Row::new(["12345", "a/long/path/name"]),
+            Row::new(["7", "short"]),
+        ])
+        .header(Row::new(["Num", "Path"]))
+        .widths([Constraint::Length(8), Constraint::Length(10)])
+        .column_spacing(1)
+        .column_specs([
+            ColumnSpec::number("###,##0", Locale::en_US).expect("fmt"),
+            ColumnSpec::new().overflow(Overflow::EllipsisMiddle),
+        ])
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(line(&buf, 0), "     Num Path       ");
+    assert_eq!(line(&buf, 1), "  12,345 a/lon…name ");
+    assert_eq!(line(&buf, 2), "       7 short      ");
+}
+
+struct Data {
+    seen: Rc<RefCell<Vec<(usize, Alignment, Overflow)>>>,
+}
+
+impl<'a> TableData<'a> for Data {
+    fn rows(&self) -> usize {
+        1
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(5), Constraint::Length(5)]
+    }
+
+    fn render_cell(&self, ctx: &TableContext, column: usize, _row: usize, _: Rect, _: &mut Buffer) {
+        self.seen
+            .borrow_mut()
+            .push((column, ctx.align, ctx.overflow));
+    }
+}
+
+#[test]
+fn test_context() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let mut state = TableState::<RowSelection>::new();
+    let area = Rect::new(0, 0, 20, 3);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .data(Data { seen: seen.clone() })
+        .column_specs([ColumnSpec::new()
+            .align(Alignment::Center)
+            .overflow(Overflow::EllipsisLeft)])
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(
+        *seen.borrow(),
+        vec![
+            (0, Alignment::Center, Overflow::EllipsisLeft),
+            (1, Alignment::Left, Overflow::Clip)
+        ]
+    );
+}
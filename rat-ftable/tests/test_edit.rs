@@ -0,0 +1,212 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_event::{ct_event, HandleEvent};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_ftable::edit::table::EditableTableState;
+use rat_ftable::edit::{EditorValidate, EnterMode, TableEditorState};
+use rat_ftable::event::EditOutcome;
+use ratatui::layout::Rect;
+
+/// Two cells, the first must be a number.
+#[derive(Debug, Default)]
+struct NumEditorState {
+    focus: FocusFlag,
+    col: usize,
+    cells: [String; 2],
+    invalid: Option<String>,
+}
+
+impl HasFocus for NumEditorState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.leaf_widget(self);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    fn area(&self) -> Rect {
+        Rect::default()
+    }
+}
+
+impl EditorValidate for NumEditorState {
+    fn validate(&self) -> Result<(), String> {
+        match self.cells[0].parse::<u32>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("not a number: {:?}", self.cells[0])),
+        }
+    }
+
+    fn set_validation_error(&mut self, err: Option<String>) {
+        self.invalid = err;
+    }
+}
+
+impl TableEditorState for NumEditorState {
+    type Context<'a> = ();
+    type Value = [String; 2];
+    type Err = ();
+
+    fn create_value(&self, _ctx: ()) -> Result<Self::Value, Self::Err> {
+        Ok(Default::default())
+    }
+
+    fn set_value(&mut self, value: Self::Value, _ctx: ()) -> Result<(), Self::Err> {
+        self.cells = value;
+        Ok(())
+    }
+
+    fn value(&mut self, _ctx: ()) -> Result<Option<Self::Value>, Self::Err> {
+        Ok(Some(self.cells.clone()))
+    }
+
+    fn focused_col(&self) -> Option<usize> {
+        Some(self.col)
+    }
+}
+
+impl HandleEvent<Event, (), EditOutcome> for NumEditorState {
+    fn handle(&mut self, event: &Event, _qualifier: ()) -> EditOutcome {
+        match event {
+            ct_event!(keycode press Tab) => {
+                self.col = (self.col + 1) % 2;
+                EditOutcome::Changed
+            }
+            ct_event!(key press c) => {
+                self.cells[self.col].push(*c);
+                EditOutcome::Changed
+            }
+            ct_event!(keycode press Backspace) => {
+                self.cells[self.col].pop();
+                EditOutcome::Changed
+            }
+            _ => EditOutcome::Continue,
+        }
+    }
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn editing(rows: usize) -> EditableTableState<NumEditorState> {
+    let mut state = EditableTableState::new(NumEditorState::default());
+    state.table.focus.set(true);
+    state.table.rows = rows;
+    state.edit(0);
+    state
+}
+
+#[test]
+fn test_enter_fails() {
+    let mut state = editing(3);
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('x')), ()),
+        EditOutcome::Changed
+    );
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), ()),
+        EditOutcome::Unchanged
+    );
+    assert!(state.is_editing());
+    assert_eq!(state.validation_error(), Some("not a number: \"x\""));
+    assert_eq!(state.editor.invalid.as_deref(), Some("not a number: \"x\""));
+
+    // Up/Down are commits too.
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), ()),
+        EditOutcome::Unchanged
+    );
+    assert_eq!(state.handle(&key(KeyCode::Up), ()), EditOutcome::Unchanged);
+
+    // fix the value
+    assert_eq!(
+        state.handle(&key(KeyCode::Backspace), ()),
+        EditOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('1')), ()),
+        EditOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), ()),
+        EditOutcome::CommitAndEdit
+    );
+    assert_eq!(state.validation_error(), None);
+    assert_eq!(state.editor.invalid, None);
+}
+
+#[test]
+fn test_tab_enter() {
+    let mut state = editing(1);
+
+    // tab moves to the second cell, the first stays invalid.
+    assert_eq!(state.handle(&key(KeyCode::Tab), ()), EditOutcome::Changed);
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('a')), ()),
+        EditOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), ()),
+        EditOutcome::Unchanged
+    );
+    assert!(state.validation_error().is_some());
+
+    // tab back and fix it. last row appends.
+    assert_eq!(state.handle(&key(KeyCode::Tab), ()), EditOutcome::Changed);
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('7')), ()),
+        EditOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), ()),
+        EditOutcome::CommitAndAppend
+    );
+
+    state.commit();
+    assert!(!state.is_editing());
+    assert_eq!(state.validation_error(), None);
+}
+
+#[test]
+fn test_esc_clears() {
+    let mut state = editing(3);
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), ()),
+        EditOutcome::Unchanged
+    );
+    assert!(state.validation_error().is_some());
+
+    // cancel doesn't validate.
+    assert_eq!(state.handle(&key(KeyCode::Esc), ()), EditOutcome::Cancel);
+    state.cancel();
+    assert!(!state.is_editing());
+    assert_eq!(state.validation_error(), None);
+    assert_eq!(state.editor.invalid, None);
+}
+
+#[test]
+fn test_commit_and_stay() {
+    let mut state = editing(3);
+    state.set_enter_mode(EnterMode::Stay);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), ()),
+        EditOutcome::Unchanged
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('4')), ()),
+        EditOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), ()),
+        EditOutcome::CommitAndStay
+    );
+    assert_eq!(state.table.selected(), Some(0));
+}
@@ -0,0 +1,139 @@
+use rat_ftable::export::{export_rows, export_rows_iter, ExportFormat};
+use rat_ftable::selection::{CellSelection, RowSelection, RowSetSelection};
+use rat_ftable::{Table, TableContext, TableData, TableDataIter, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{StatefulWidget, Widget};
+use std::borrow::Cow;
+
+const DATA: [[&str; 3]; 4] = [
+    ["plain", "1", "x"],
+    ["with, comma", "2", "say \"hi\""],
+    ["two\nlines", "3", "a\tb"],
+    ["", "4", "last"],
+];
+
+struct Data;
+
+impl<'a> TableData<'a> for Data {
+    fn rows(&self) -> usize {
+        DATA.len()
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(5); 3]
+    }
+
+    fn render_cell(
+        &self,
+        _ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        DATA[row][column].render(area, buf);
+    }
+
+    fn render_cell_text(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        // the last column has no text in the first row.
+        if column == 2 && row == 0 {
+            None
+        } else {
+            Some(Cow::Borrowed(DATA[row][column]))
+        }
+    }
+}
+
+struct DataIter(Option<usize>);
+
+impl<'a> TableDataIter<'a> for DataIter {
+    fn rows(&self) -> Option<usize> {
+        Some(DATA.len())
+    }
+
+    fn nth(&mut self, n: usize) -> bool {
+        let row = self.0.map(|v| v + 1 + n).unwrap_or(n);
+        self.0 = Some(row);
+        row < DATA.len()
+    }
+
+    fn render_cell(&self, _ctx: &TableContext, column: usize, area: Rect, buf: &mut Buffer) {
+        DATA[self.0.expect("row")][column].render(area, buf);
+    }
+
+    fn render_cell_text(&self, column: usize) -> Option<Cow<'_, str>> {
+        Some(Cow::Owned(DATA[self.0.expect("row")][column].to_string()))
+    }
+}
+
+fn render<Selection: rat_ftable::TableSelection>(state: &mut TableState<Selection>) {
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buf = Buffer::empty(area);
+    Table::default().data(Data).render(area, &mut buf, state);
+}
+
+#[test]
+fn test_csv() {
+    let text = export_rows(&Data, 0..4, 0..3, ExportFormat::Csv);
+    assert_eq!(
+        text,
+        "plain,1,\n\
+         \"with, comma\",2,\"say \"\"hi\"\"\"\n\
+         \"two\nlines\",3,a\tb\n\
+         ,4,last\n"
+    );
+}
+
+#[test]
+fn test_tsv() {
+    let text = export_rows(&Data, [1, 2, 9].into_iter(), 0..3, ExportFormat::Tsv);
+    assert_eq!(
+        text,
+        "with, comma\t2\tsay \"hi\"\n\
+         two lines\t3\ta b\n"
+    );
+
+    let text = export_rows(&Data, [3].into_iter(), 1..3, ExportFormat::Tsv);
+    assert_eq!(text, "4\tlast\n");
+}
+
+#[test]
+fn test_iter() {
+    let text = export_rows_iter(
+        &mut DataIter(None),
+        [1, 3].into_iter(),
+        0..2,
+        ExportFormat::Csv,
+    );
+    assert_eq!(text, "\"with, comma\",2\n,4\n");
+}
+
+#[test]
+fn test_selection() {
+    let mut state = TableState::<RowSelection>::new();
+    render(&mut state);
+    assert_eq!(state.export_selection(&Data, ExportFormat::Tsv), "");
+    state.select(Some(3));
+    assert_eq!(
+        state.export_selection(&Data, ExportFormat::Tsv),
+        "\t4\tlast\n"
+    );
+
+    let mut state = TableState::<RowSetSelection>::new();
+    render(&mut state);
+    state.add_selected(3);
+    state.add_selected(0);
+    assert_eq!(
+        state.export_selection(&Data, ExportFormat::Csv),
+        "plain,1,\n,4,last\n"
+    );
+
+    let mut state = TableState::<CellSelection>::new();
+    render(&mut state);
+    state.select_cell(Some((2, 1)));
+    assert_eq!(
+        state.export_selection(&Data, ExportFormat::Csv),
+        "\"say \"\"hi\"\"\"\n"
+    );
+}
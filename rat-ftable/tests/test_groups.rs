@@ -0,0 +1,97 @@
+use rat_ftable::selection::RowSelection;
+use rat_ftable::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{StatefulWidget, Widget};
+
+/// Rows with uneven groups.
+struct Grouped;
+
+const GROUPS: [usize; 8] = [0, 0, 1, 1, 1, 1, 1, 2];
+
+impl<'a> TableData<'a> for Grouped {
+    fn rows(&self) -> usize {
+        GROUPS.len()
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(4)]
+    }
+
+    fn group_of(&self, row: usize) -> Option<usize> {
+        Some(GROUPS[row])
+    }
+
+    fn render_group_header(&self, group: usize, area: Rect, buf: &mut Buffer) {
+        format!("G{}", group).render(area, buf);
+    }
+
+    fn render_cell(
+        &self,
+        _ctx: &TableContext,
+        _column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        format!("r{}", row).render(area, buf);
+    }
+}
+
+fn render(state: &mut TableState<RowSelection>) -> Vec<String> {
+    let area = Rect::new(0, 0, 4, 6);
+    let mut buf = Buffer::empty(area);
+    Table::new().data(Grouped).render(area, &mut buf, state);
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn test_headers() {
+    let mut state = TableState::<RowSelection>::new();
+    let lines = render(&mut state);
+    assert_eq!(lines, vec!["G0", "r0", "r1", "G1", "r2", "r3"]);
+
+    // header line belongs to the first row of the group.
+    assert_eq!(state.row_areas[0], Rect::new(0, 0, 4, 2));
+    assert_eq!(state.row_areas[2], Rect::new(0, 3, 4, 2));
+}
+
+#[test]
+fn test_pinned_header() {
+    let mut state = TableState::<RowSelection>::new();
+    render(&mut state);
+    state.set_row_offset(3);
+    let lines = render(&mut state);
+    assert_eq!(lines, vec!["G1", "r3", "r4", "r5", "r6", "G2"]);
+    assert_eq!(state.vscroll.max_offset(), 4);
+}
+
+#[test]
+fn test_data_rows() {
+    let mut state = TableState::<RowSelection>::new();
+    render(&mut state);
+
+    // headers are not rows.
+    assert_eq!(state.row_at_clicked((0, 0)), Some(0));
+    assert_eq!(state.row_at_clicked((0, 3)), Some(2));
+    assert_eq!(state.row_at_clicked((0, 4)), Some(2));
+    assert_eq!(state.row_at_clicked((0, 5)), Some(3));
+
+    state.set_row_offset(3);
+    render(&mut state);
+    assert_eq!(state.row_at_clicked((0, 0)), None);
+    assert_eq!(state.row_at_clicked((0, 1)), Some(3));
+
+    assert!(state.move_to(7));
+    assert_eq!(state.selected(), Some(7));
+    assert!(state.move_to(2));
+    assert_eq!(state.selected(), Some(2));
+}
@@ -0,0 +1,114 @@
+use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, MouseOnly};
+use rat_ftable::event::TableOutcome;
+use rat_ftable::selection::RowSelection;
+use rat_ftable::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::text::Span;
+use ratatui::widgets::{StatefulWidget, Widget};
+use std::borrow::Cow;
+
+struct Data;
+
+const TEXT: [&str; 3] = ["short", "a rather long text", "ok"];
+
+impl<'a> TableData<'a> for Data {
+    fn rows(&self) -> usize {
+        TEXT.len()
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(8), Constraint::Length(4)]
+    }
+
+    fn render_cell(
+        &self,
+        _ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if column == 0 {
+            Span::from(TEXT[row]).render(area, buf);
+        }
+    }
+
+    fn cell_tooltip(&self, column: usize, row: usize) -> Option<Cow<'_, str>> {
+        if column == 0 {
+            Some(Cow::Borrowed(TEXT[row]))
+        } else {
+            None
+        }
+    }
+}
+
+fn render(state: &mut TableState<RowSelection>) -> Buffer {
+    let area = Rect::new(0, 0, 20, 6);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .data(Data)
+        .column_spacing(1)
+        .show_tooltip(true)
+        .render(area, &mut buf, state);
+    buf
+}
+
+fn moved(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Moved,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()
+}
+
+#[test]
+fn test_truncated() {
+    let mut state = TableState::<RowSelection>::new();
+    render(&mut state);
+
+    assert!(!state.is_truncated(0, 0));
+    assert!(state.is_truncated(0, 1));
+    assert!(!state.is_truncated(1, 1));
+    assert_eq!(state.truncated.len(), 1);
+}
+
+#[test]
+fn test_hover() {
+    let mut state = TableState::<RowSelection>::new();
+    render(&mut state);
+
+    // new cell, no tooltip
+    let r = state.handle(&moved(1, 0), MouseOnly);
+    assert_eq!(r, TableOutcome::Unchanged);
+    assert_eq!(state.hovered_cell(), Some((0, 0)));
+    assert!(!state.has_tooltip());
+
+    // same cell
+    let r = state.handle(&moved(2, 0), MouseOnly);
+    assert_eq!(r, TableOutcome::Continue);
+
+    // tooltip appears
+    let r = state.handle(&moved(1, 1), MouseOnly);
+    assert_eq!(r, TableOutcome::Changed);
+    assert!(state.has_tooltip());
+
+    let buf = render(&mut state);
+    assert_eq!(state.tooltip_area, Some(Rect::new(0, 2, 18, 1)));
+    assert_eq!(line(&buf, 2), "a rather long text  ");
+
+    // leave
+    let r = state.handle(&moved(1, 10), MouseOnly);
+    assert_eq!(r, TableOutcome::Changed);
+    assert_eq!(state.hovered_cell(), None);
+
+    let buf = render(&mut state);
+    assert_eq!(state.tooltip_area, None);
+    assert_eq!(line(&buf, 2), "ok                  ");
+}
@@ -0,0 +1,147 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use rat_event::{HandleEvent, Regular};
+use rat_ftable::event::{DoubleClickOutcome, TableOutcome};
+use rat_ftable::selection::RowSelection;
+use rat_ftable::textdata::Row;
+use rat_ftable::{handle_doubleclick_events, Table, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Modifier;
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut TableState<RowSelection>) -> Buffer {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .rows((0..20).map(|v| Row::new([format!("{}", v)])))
+        .widths([Constraint::Length(10)])
+        .render(area, &mut buf, state);
+    buf
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn mouse(kind: MouseEventKind, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: 1,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn state() -> TableState<RowSelection> {
+    let mut state = TableState::<RowSelection>::new();
+    state.reorder = true;
+    state.focus.set(true);
+    render(&mut state);
+    state.select(Some(1));
+    state
+}
+
+#[test]
+fn test_drag() {
+    let mut state = state();
+
+    // the mouse-down is a normal click.
+    let r = state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 1), Regular);
+    assert_eq!(r, TableOutcome::Unchanged);
+    assert_eq!(state.drag_anchor, Some(1));
+    assert!(!state.is_dragging());
+
+    // the drag starts with the first mouse drag.
+    let r = state.handle(&mouse(MouseEventKind::Drag(MouseButton::Left), 3), Regular);
+    assert_eq!(r, TableOutcome::Changed);
+    assert!(state.is_dragging());
+    assert_eq!(state.drag_hover, Some(3));
+
+    // indicator below row 3
+    let buf = render(&mut state);
+    assert!(buf[(0, 3)].modifier.contains(Modifier::UNDERLINED));
+    assert!(!buf[(0, 2)].modifier.contains(Modifier::UNDERLINED));
+
+    let r = state.handle(&mouse(MouseEventKind::Up(MouseButton::Left), 3), Regular);
+    assert_eq!(r, TableOutcome::RowMoved { from: 1, to: 3 });
+    assert_eq!(state.selected(), Some(3));
+    assert_eq!(state.drag_anchor, None);
+    assert_eq!(state.drag_hover, None);
+}
+
+#[test]
+fn test_click() {
+    let mut state = state();
+
+    // a click without drag doesn't start a reorder,
+    // and a double-click is still recognized.
+    let mut dc = DoubleClickOutcome::Continue;
+    for kind in [
+        MouseEventKind::Down(MouseButton::Left),
+        MouseEventKind::Up(MouseButton::Left),
+        MouseEventKind::Down(MouseButton::Left),
+        MouseEventKind::Up(MouseButton::Left),
+    ] {
+        let event = mouse(kind, 1);
+        dc = handle_doubleclick_events(&mut state, &event);
+        let r = state.handle(&event, Regular);
+        if kind == MouseEventKind::Up(MouseButton::Left) {
+            assert_eq!(r, TableOutcome::Continue);
+        }
+    }
+    assert_eq!(dc, DoubleClickOutcome::ClickClick(0, 1));
+    assert_eq!(state.drag_anchor, None);
+    assert!(!state.is_dragging());
+    assert_eq!(state.selected(), Some(1));
+}
+
+#[test]
+fn test_drag_cancel() {
+    let mut state = state();
+
+    state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 1), Regular);
+    state.handle(&mouse(MouseEventKind::Drag(MouseButton::Left), 3), Regular);
+    let r = state.handle(&key(KeyCode::Esc, KeyModifiers::NONE), Regular);
+    assert_eq!(r, TableOutcome::Changed);
+    assert_eq!(state.drag_anchor, None);
+
+    let buf = render(&mut state);
+    assert!(!buf[(0, 3)].modifier.contains(Modifier::UNDERLINED));
+
+    let r = state.handle(&mouse(MouseEventKind::Up(MouseButton::Left), 3), Regular);
+    assert_ne!(r, TableOutcome::RowMoved { from: 1, to: 3 });
+    assert_eq!(state.selected(), Some(1));
+}
+
+#[test]
+fn test_drag_autoscroll() {
+    let mut state = state();
+
+    state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 1), Regular);
+    state.handle(&mouse(MouseEventKind::Drag(MouseButton::Left), 4), Regular);
+    assert_eq!(state.row_offset(), 1);
+}
+
+#[test]
+fn test_keyboard() {
+    let mut state = state();
+
+    let r = state.handle(&key(KeyCode::Up, KeyModifiers::ALT), Regular);
+    assert_eq!(r, TableOutcome::RowMoved { from: 1, to: 0 });
+    let r = state.handle(&key(KeyCode::Up, KeyModifiers::ALT), Regular);
+    assert_eq!(r, TableOutcome::Unchanged);
+    let r = state.handle(&key(KeyCode::Down, KeyModifiers::ALT), Regular);
+    assert_eq!(r, TableOutcome::RowMoved { from: 0, to: 1 });
+
+    state.reorder = false;
+    let r = state.handle(&key(KeyCode::Down, KeyModifiers::ALT), Regular);
+    assert_ne!(r, TableOutcome::RowMoved { from: 1, to: 2 });
+}
@@ -0,0 +1,170 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_event::{HandleEvent, Regular};
+use rat_ftable::event::TableOutcome;
+use rat_ftable::selection::RowSelection;
+use rat_ftable::{Table, TableContext, TableDataIter, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{StatefulWidget, Widget};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Simulates a huge log. Records the furthest row it was asked for.
+struct Log {
+    total: usize,
+    height: u16,
+    row: Option<usize>,
+    furthest: Rc<Cell<usize>>,
+}
+
+impl<'a> TableDataIter<'a> for Log {
+    fn rows(&self) -> Option<usize> {
+        None
+    }
+
+    fn nth(&mut self, n: usize) -> bool {
+        let row = self.row.map_or(n, |v| v + n + 1);
+        self.row = Some(row);
+        self.furthest.set(self.furthest.get().max(row));
+        row < self.total
+    }
+
+    fn row_height(&self) -> u16 {
+        self.height
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(10)]
+    }
+
+    fn render_cell(&self, _ctx: &TableContext, _column: usize, area: Rect, buf: &mut Buffer) {
+        format!("{}", self.row.expect("row")).render(area, buf);
+    }
+}
+
+fn render(total: usize, state: &mut TableState<RowSelection>) -> usize {
+    render_height(total, 1, state)
+}
+
+fn render_height(total: usize, height: u16, state: &mut TableState<RowSelection>) -> usize {
+    let furthest = Rc::new(Cell::new(0));
+    let area = Rect::new(0, 0, 10, 10);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .iter(Log {
+            total,
+            height,
+            row: None,
+            furthest: furthest.clone(),
+        })
+        .virtual_rows(true)
+        .render(area, &mut buf, state);
+    furthest.get()
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_window_only() {
+    let mut state = TableState::<RowSelection>::new();
+    state.set_estimated_rows(10_000_000);
+
+    let furthest = render(10_000_000, &mut state);
+    assert!(furthest <= 10);
+    assert_eq!(state.rows(), 10_000_000);
+    assert_eq!(state.vscroll.max_offset(), 10_000_000 - 10);
+
+    state.set_row_offset(5_000_000);
+    let furthest = render(10_000_000, &mut state);
+    assert!(furthest <= 5_000_010);
+    assert_eq!(state.row_offset(), 5_000_000);
+}
+
+#[test]
+fn test_tall_rows() {
+    let mut state = TableState::<RowSelection>::new();
+    state.set_estimated_rows(100);
+
+    // 5 rows of height 2 fit the page.
+    render_height(100, 2, &mut state);
+    assert_eq!(state.virtual_row_height, 2);
+    assert_eq!(state.vscroll.max_offset(), 95);
+    state.set_estimated_rows(200);
+    assert_eq!(state.vscroll.max_offset(), 195);
+
+    // the last row can be scrolled into view.
+    state.set_row_offset(195);
+    let furthest = render_height(200, 2, &mut state);
+    assert!(furthest <= 200);
+    assert_eq!(state.row_offset(), 195);
+    assert_eq!(state.vscroll.max_offset(), 195);
+    assert!(state.row_areas[4].height > 0);
+}
+
+#[test]
+fn test_end_loads_tail() {
+    let mut state = TableState::<RowSelection>::new();
+    state.focus.set(true);
+    state.set_estimated_rows(1_000);
+    render(2_000, &mut state);
+
+    let r = state.handle(&key(KeyCode::End, KeyModifiers::NONE), Regular);
+    assert_eq!(r, TableOutcome::LoadTail);
+    let r = state.handle(&key(KeyCode::End, KeyModifiers::CONTROL), Regular);
+    assert_eq!(r, TableOutcome::LoadTail);
+    assert_eq!(state.selected(), None);
+
+    // the app answers with the real count.
+    state.set_estimated_rows(2_000);
+    assert!(state.move_to(1_999));
+    assert_eq!(state.selected(), Some(1_999));
+    assert_eq!(state.row_offset(), 1_990);
+}
+
+#[test]
+fn test_select_beyond_estimate() {
+    let mut state = TableState::<RowSelection>::new();
+    state.focus.set(true);
+    state.set_estimated_rows(100);
+    render(1_000, &mut state);
+
+    // move_to raises the estimate and scrolls to the selection.
+    assert!(state.move_to(500));
+    assert_eq!(state.selected(), Some(500));
+    assert_eq!(state.estimated_rows(), 501);
+    assert_eq!(state.row_offset(), 491);
+
+    let furthest = render(1_000, &mut state);
+    assert!(furthest <= 501);
+    assert_eq!(state.rows(), 501);
+    assert_eq!(state.row_offset(), 491);
+
+    // keyboard movement stays within the estimate.
+    let r = state.handle(&key(KeyCode::Down, KeyModifiers::NONE), Regular);
+    assert_eq!(r, TableOutcome::Unchanged);
+    assert_eq!(state.selected(), Some(500));
+}
+
+#[test]
+fn test_scroll_selected() {
+    let mut state = TableState::<RowSelection>::new();
+    state.set_scroll_selection(true);
+    state.set_estimated_rows(100);
+    render(50, &mut state);
+
+    // selection runs past the rows the data delivers.
+    // the table renders empty rows there.
+    assert!(state.move_to(80));
+    assert_eq!(state.row_offset(), 71);
+    assert!(!state.scroll_to_selected());
+    let furthest = render(50, &mut state);
+    assert_eq!(state.row_offset(), 71);
+    assert!(furthest <= 71);
+}
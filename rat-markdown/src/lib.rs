@@ -10,16 +10,22 @@ mod dump;
 mod format;
 mod operations;
 mod parser;
+mod preview;
 mod styles;
+mod table;
 mod util;
 
 pub use styles::{parse_md_styles, MDStyle};
 pub mod op {
     pub use crate::format::{md_format, reformat};
     pub use crate::operations::{md_backtab, md_line_break, md_make_header, md_tab};
+    pub use crate::table::{
+        md_table_add_column, md_table_add_row, md_table_reformat, md_table_remove_column,
+    };
 }
 use crate::operations::md_insert_quotes;
 pub use dump::{md_dump, md_dump_styles};
+pub use preview::{render_markdown, MDHighlight, MDPreview};
 
 /// Event qualifier.
 #[derive(Debug)]
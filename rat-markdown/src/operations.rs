@@ -1,4 +1,5 @@
 use crate::parser::{parse_md_header, parse_md_item, parse_md_row};
+use crate::table::{md_table_next_cell, md_table_prev_cell};
 use crate::util::str_line_len;
 use crate::MDStyle;
 use rat_text::event::TextOutcome;
@@ -57,16 +58,21 @@ pub fn md_make_header(state: &mut TextAreaState, header: u8) -> TextOutcome {
     }
 }
 
-/// Navigate with Tab in a table.
+/// Navigate with Tab in a table. At the last cell of a row
+/// this continues with the first cell of the next row.
 /// If there is a list item jump to the indent of the
 /// current or the previous list item.
 pub fn md_tab(state: &mut TextAreaState) -> TextOutcome {
     if is_md_table(state) {
         let cursor = state.cursor();
-        let row = state.line_at(cursor.y);
-        let x = next_tab_md_row(row.as_ref(), cursor.x);
-        state.set_cursor((x, cursor.y), false);
-        state.set_move_col(Some(x));
+        let pos = if let Some(pos) = md_table_next_cell(state) {
+            pos
+        } else {
+            let row = state.line_at(cursor.y);
+            TextPosition::new(next_tab_md_row(row.as_ref(), cursor.x), cursor.y)
+        };
+        state.set_cursor(pos, false);
+        state.set_move_col(Some(pos.x));
 
         TextOutcome::TextChanged
     } else if is_md_item(state) {
@@ -103,16 +109,21 @@ pub fn md_tab(state: &mut TextAreaState) -> TextOutcome {
     }
 }
 
-/// Navigate in a table with BackTab
+/// Navigate in a table with BackTab. At the first cell of a row
+/// this continues with the last cell of the previous row.
 pub fn md_backtab(state: &mut TextAreaState) -> TextOutcome {
     if is_md_table(state) {
         let cursor = state.cursor();
 
-        let row_str = state.line_at(cursor.y);
-        let x = prev_tab_md_row(row_str.as_ref(), cursor.x);
+        let pos = if let Some(pos) = md_table_prev_cell(state) {
+            pos
+        } else {
+            let row_str = state.line_at(cursor.y);
+            TextPosition::new(prev_tab_md_row(row_str.as_ref(), cursor.x), cursor.y)
+        };
 
-        state.set_cursor((x, cursor.y), false);
-        state.set_move_col(Some(x));
+        state.set_cursor(pos, false);
+        state.set_move_col(Some(pos.x));
         TextOutcome::TextChanged
     } else {
         TextOutcome::Continue
@@ -0,0 +1,713 @@
+use crate::styles::{md_options, MDStyle};
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Parser, Tag, TagEnd};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+use std::mem;
+use unicode_display_width::width as unicode_width;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Callback for syntax highlighting of fenced code blocks.
+///
+/// Gets the language tag of the code block (the first word of the
+/// info string, or "" if there is none), the code itself and the
+/// style for CodeBlock. Returns None to use the default rendering.
+pub type MDHighlight<'a> = dyn Fn(&str, &str, Style) -> Option<Vec<Line<'static>>> + 'a;
+
+/// Renders markdown to a ratatui [Text].
+///
+/// Uses the same pulldown-cmark options as [parse_md_styles](crate::parse_md_styles),
+/// so the preview agrees with what is highlighted in the editor.
+///
+/// The styles are given as a slice indexed by [MDStyle], the same
+/// array that is used for `TextArea::text_style()`. Missing entries
+/// fall back to `Style::default()`.
+///
+/// Paragraphs, headings, list items, block quotes and footnotes are
+/// wrapped at the given width. Code blocks, html blocks and tables
+/// are not wrapped.
+pub struct MDPreview<'a> {
+    width: u16,
+    styles: &'a [Style],
+    highlight: Option<&'a MDHighlight<'a>>,
+}
+
+/// Render the markdown text with the given styles.
+///
+/// See [MDPreview].
+pub fn render_markdown(txt: &str, width: u16, styles: &[Style]) -> Text<'static> {
+    MDPreview::new(width).styles(styles).render(txt)
+}
+
+impl Default for MDPreview<'_> {
+    fn default() -> Self {
+        Self {
+            width: 65,
+            styles: &[],
+            highlight: None,
+        }
+    }
+}
+
+impl<'a> MDPreview<'a> {
+    pub fn new(width: u16) -> Self {
+        Self {
+            width,
+            ..Default::default()
+        }
+    }
+
+    /// Wrap width.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Styles indexed by [MDStyle].
+    pub fn styles(mut self, styles: &'a [Style]) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Highlighter for fenced code blocks.
+    pub fn highlight(mut self, highlight: &'a MDHighlight<'a>) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Render the markdown.
+    pub fn render(&self, txt: &str) -> Text<'static> {
+        let mut r = Render {
+            p: self,
+            lines: Vec::new(),
+            blocks: Vec::new(),
+            inline: Vec::new(),
+            style: vec![Style::default()],
+            need_gap: false,
+            list: Vec::new(),
+            code: None,
+            table: None,
+            skip: 0,
+        };
+
+        for e in Parser::new_ext(txt, md_options()) {
+            r.event(e);
+        }
+        r.flush();
+
+        Text::from(r.lines)
+    }
+
+    fn style(&self, style: MDStyle) -> Style {
+        self.styles.get(style as usize).copied().unwrap_or_default()
+    }
+}
+
+/// Line prefix for one nesting level.
+#[derive(Debug)]
+struct Block {
+    first: Option<Vec<Span<'static>>>,
+    follow: Vec<Span<'static>>,
+}
+
+#[derive(Debug)]
+enum Token {
+    Text(String, Style),
+    Space(Style),
+    Break,
+}
+
+#[derive(Debug, Default)]
+struct Table {
+    align: Vec<Alignment>,
+    head: usize,
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+    cell: Option<Vec<Span<'static>>>,
+}
+
+struct Render<'p, 'a> {
+    p: &'p MDPreview<'a>,
+    lines: Vec<Line<'static>>,
+
+    // nesting for list items, block quotes, ...
+    blocks: Vec<Block>,
+    // inline text of the current paragraph
+    inline: Vec<Token>,
+    // inline style stack
+    style: Vec<Style>,
+    // add an empty line before the next block
+    need_gap: bool,
+    // next number for ordered lists
+    list: Vec<Option<u64>>,
+    // collected code block
+    code: Option<(String, String)>,
+    // collected table
+    table: Option<Table>,
+    // inside a metadata block
+    skip: usize,
+}
+
+impl Render<'_, '_> {
+    fn event(&mut self, e: Event<'_>) {
+        if self.skip > 0 {
+            match e {
+                Event::Start(Tag::MetadataBlock(_)) => self.skip += 1,
+                Event::End(TagEnd::MetadataBlock(_)) => self.skip -= 1,
+                _ => {}
+            }
+            return;
+        }
+
+        match e {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(t) => {
+                if let Some((_, code)) = &mut self.code {
+                    code.push_str(&t);
+                } else {
+                    self.text(&t, self.cur_style());
+                }
+            }
+            Event::Code(t) => {
+                let style = self.cur_style().patch(self.p.style(MDStyle::CodeInline));
+                self.word(&t, style);
+            }
+            Event::InlineMath(t) => {
+                let style = self.cur_style().patch(self.p.style(MDStyle::MathInline));
+                self.word(&format!("${}$", t), style);
+            }
+            Event::DisplayMath(t) => {
+                let style = self.cur_style().patch(self.p.style(MDStyle::MathDisplay));
+                self.word(&format!("$${}$$", t), style);
+            }
+            Event::Html(t) => {
+                let style = self.cur_style().patch(self.p.style(MDStyle::Html));
+                for l in t.lines() {
+                    let mut line = self.prefix();
+                    line.push(Span::styled(l.to_string(), style));
+                    self.lines.push(Line::from(line));
+                }
+            }
+            Event::InlineHtml(t) => {
+                let style = self.cur_style().patch(self.p.style(MDStyle::Html));
+                self.word(&t, style);
+            }
+            Event::FootnoteReference(t) => {
+                let style = self
+                    .cur_style()
+                    .patch(self.p.style(MDStyle::FootnoteReference));
+                self.word(&format!("[^{}]", t), style);
+            }
+            Event::SoftBreak => {
+                self.push_token(Token::Space(self.cur_style()));
+            }
+            Event::HardBreak => {
+                self.push_token(Token::Break);
+            }
+            Event::Rule => {
+                self.gap();
+                let avail = self.avail();
+                let mut line = self.prefix();
+                line.push(Span::styled(
+                    "─".repeat(avail as usize),
+                    self.p.style(MDStyle::Rule),
+                ));
+                self.lines.push(Line::from(line));
+                self.need_gap = true;
+            }
+            Event::TaskListMarker(checked) => {
+                let style = self
+                    .cur_style()
+                    .patch(self.p.style(MDStyle::TaskListMarker));
+                self.word(if checked { "[x]" } else { "[ ]" }, style);
+                self.push_token(Token::Space(self.cur_style()));
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => {
+                self.flush();
+                self.gap();
+            }
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.gap();
+                let style = match level {
+                    HeadingLevel::H1 => MDStyle::Heading1,
+                    HeadingLevel::H2 => MDStyle::Heading2,
+                    HeadingLevel::H3 => MDStyle::Heading3,
+                    HeadingLevel::H4 => MDStyle::Heading4,
+                    HeadingLevel::H5 => MDStyle::Heading5,
+                    HeadingLevel::H6 => MDStyle::Heading6,
+                };
+                self.push_style(style);
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.gap();
+                let style = self.p.style(MDStyle::BlockQuote);
+                self.blocks.push(Block {
+                    first: None,
+                    follow: vec![Span::styled("│ ", style)],
+                });
+                self.push_style(MDStyle::BlockQuote);
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                self.gap();
+                let lang = match kind {
+                    CodeBlockKind::Indented => String::new(),
+                    CodeBlockKind::Fenced(info) => {
+                        info.split_whitespace().next().unwrap_or("").to_string()
+                    }
+                };
+                self.code = Some((lang, String::new()));
+            }
+            Tag::HtmlBlock => {
+                self.flush();
+                self.gap();
+            }
+            Tag::List(start) => {
+                self.flush();
+                // nested lists follow their item directly.
+                if self.list.is_empty() {
+                    self.gap();
+                }
+                self.need_gap = false;
+                self.list.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let mark = match self.list.last_mut() {
+                    Some(Some(nr)) => {
+                        let mark = format!("{}. ", nr);
+                        *nr += 1;
+                        mark
+                    }
+                    _ => "• ".to_string(),
+                };
+                let width = unicode_width(&mark) as usize;
+                self.blocks.push(Block {
+                    first: Some(vec![Span::styled(mark, self.p.style(MDStyle::ItemTag))]),
+                    follow: vec![Span::raw(" ".repeat(width))],
+                });
+            }
+            Tag::FootnoteDefinition(name) => {
+                self.flush();
+                self.gap();
+                let mark = format!("[^{}]: ", name);
+                let width = unicode_width(&mark) as usize;
+                self.blocks.push(Block {
+                    first: Some(vec![Span::styled(
+                        mark,
+                        self.p.style(MDStyle::FootnoteDefinition),
+                    )]),
+                    follow: vec![Span::raw(" ".repeat(width))],
+                });
+            }
+            Tag::DefinitionList => {
+                self.flush();
+                self.gap();
+            }
+            Tag::DefinitionListTitle => {
+                self.flush();
+                self.push_style(MDStyle::DefinitionListTitle);
+            }
+            Tag::DefinitionListDefinition => {
+                self.flush();
+                self.blocks.push(Block {
+                    first: None,
+                    follow: vec![Span::raw("    ")],
+                });
+                self.push_style(MDStyle::DefinitionListDefinition);
+            }
+            Tag::Table(align) => {
+                self.flush();
+                self.gap();
+                self.table = Some(Table {
+                    align,
+                    ..Default::default()
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(table) = &mut self.table {
+                    table.cell = Some(Vec::new());
+                }
+            }
+            Tag::Emphasis => self.push_style(MDStyle::Emphasis),
+            Tag::Strong => self.push_style(MDStyle::Strong),
+            Tag::Strikethrough => self.push_style(MDStyle::Strikethrough),
+            Tag::Link { .. } => self.push_style(MDStyle::Link),
+            Tag::Image { .. } => {
+                self.push_style(MDStyle::Image);
+                self.word("[image: ", self.cur_style());
+            }
+            Tag::MetadataBlock(_) => {
+                self.skip += 1;
+            }
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush();
+                self.need_gap = true;
+            }
+            TagEnd::Heading(_) => {
+                self.flush();
+                self.style.pop();
+                self.need_gap = true;
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.style.pop();
+                self.blocks.pop();
+                self.need_gap = true;
+            }
+            TagEnd::CodeBlock => {
+                if let Some((lang, code)) = self.code.take() {
+                    self.code_block(&lang, &code);
+                }
+                self.need_gap = true;
+            }
+            TagEnd::HtmlBlock => {
+                self.need_gap = true;
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.list.pop();
+                self.need_gap = true;
+            }
+            TagEnd::Item | TagEnd::FootnoteDefinition => {
+                self.flush();
+                // empty item
+                if let Some(Block { first: Some(_), .. }) = self.blocks.last() {
+                    let line = self.prefix();
+                    self.lines.push(Line::from(line));
+                }
+                self.blocks.pop();
+                if tag == TagEnd::FootnoteDefinition {
+                    self.need_gap = true;
+                }
+            }
+            TagEnd::DefinitionList => {
+                self.flush();
+                self.need_gap = true;
+            }
+            TagEnd::DefinitionListTitle => {
+                self.flush();
+                self.style.pop();
+            }
+            TagEnd::DefinitionListDefinition => {
+                self.flush();
+                self.style.pop();
+                self.blocks.pop();
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.table(table);
+                }
+                self.need_gap = true;
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.head = table.rows.len();
+                }
+            }
+            TagEnd::TableRow => {}
+            TagEnd::TableCell => {
+                if let Some(table) = &mut self.table {
+                    if let Some(cell) = table.cell.take() {
+                        if let Some(row) = table.rows.last_mut() {
+                            row.push(cell);
+                        }
+                    }
+                }
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link => {
+                self.style.pop();
+            }
+            TagEnd::Image => {
+                self.word("]", self.cur_style());
+                self.style.pop();
+            }
+            TagEnd::MetadataBlock(_) => {}
+        }
+    }
+
+    fn cur_style(&self) -> Style {
+        self.style.last().copied().unwrap_or_default()
+    }
+
+    fn push_style(&mut self, style: MDStyle) {
+        let style = self.cur_style().patch(self.p.style(style));
+        self.style.push(style);
+    }
+
+    // Add text, split at whitespace.
+    fn text(&mut self, txt: &str, style: Style) {
+        if let Some(table) = &mut self.table {
+            if let Some(cell) = &mut table.cell {
+                cell.push(Span::styled(txt.to_string(), style));
+            }
+            return;
+        }
+
+        let mut word = String::new();
+        for c in txt.chars() {
+            if c.is_whitespace() {
+                if !word.is_empty() {
+                    self.inline.push(Token::Text(mem::take(&mut word), style));
+                }
+                self.inline.push(Token::Space(style));
+            } else {
+                word.push(c);
+            }
+        }
+        if !word.is_empty() {
+            self.inline.push(Token::Text(word, style));
+        }
+    }
+
+    // Add text that is not split.
+    fn word(&mut self, txt: &str, style: Style) {
+        if let Some(table) = &mut self.table {
+            if let Some(cell) = &mut table.cell {
+                cell.push(Span::styled(txt.to_string(), style));
+            }
+            return;
+        }
+        self.inline.push(Token::Text(txt.to_string(), style));
+    }
+
+    fn push_token(&mut self, token: Token) {
+        let style = self.cur_style();
+        if let Some(table) = &mut self.table {
+            if let Some(cell) = &mut table.cell {
+                cell.push(Span::styled(" ", style));
+            }
+            return;
+        }
+        self.inline.push(token);
+    }
+
+    // Empty line between blocks.
+    fn gap(&mut self) {
+        if self.need_gap {
+            let line = self.follow_prefix();
+            self.lines.push(Line::from(line));
+            self.need_gap = false;
+        }
+    }
+
+    // Prefix for the next line. Uses up the first-line prefixes.
+    fn prefix(&mut self) -> Vec<Span<'static>> {
+        let mut prefix = Vec::new();
+        for block in self.blocks.iter_mut() {
+            if let Some(first) = block.first.take() {
+                prefix.extend(first);
+            } else {
+                prefix.extend(block.follow.iter().cloned());
+            }
+        }
+        prefix
+    }
+
+    // Prefix for empty lines. Leaves first-line prefixes alone.
+    fn follow_prefix(&self) -> Vec<Span<'static>> {
+        let mut prefix = Vec::new();
+        for block in self.blocks.iter() {
+            if block.first.is_none() {
+                prefix.extend(block.follow.iter().cloned());
+            } else {
+                let width = block.follow.iter().map(|v| v.width()).sum::<usize>();
+                prefix.push(Span::raw(" ".repeat(width)));
+            }
+        }
+        prefix
+    }
+
+    fn prefix_width(&self) -> u16 {
+        self.blocks
+            .iter()
+            .map(|v| v.follow.iter().map(|v| v.width()).sum::<usize>())
+            .sum::<usize>() as u16
+    }
+
+    fn avail(&self) -> u16 {
+        self.p.width.saturating_sub(self.prefix_width()).max(1)
+    }
+
+    // Wrap the collected inline text.
+    fn flush(&mut self) {
+        if self.inline.is_empty() {
+            return;
+        }
+
+        let avail = self.avail() as usize;
+        let tokens = mem::take(&mut self.inline);
+
+        let mut line = Vec::<Span<'static>>::new();
+        let mut line_width = 0;
+        let mut space = None;
+
+        for token in tokens {
+            match token {
+                Token::Space(style) => {
+                    if line_width > 0 {
+                        space = Some(style);
+                    }
+                }
+                Token::Break => {
+                    self.push_line(mem::take(&mut line));
+                    line_width = 0;
+                    space = None;
+                }
+                Token::Text(txt, style) => {
+                    let width = unicode_width(&txt) as usize;
+                    // continue a word without intermediate space.
+                    if space.is_none() && line_width > 0 {
+                        if line_width + width <= avail {
+                            line.push(Span::styled(txt, style));
+                            line_width += width;
+                            continue;
+                        }
+                    }
+
+                    if let Some(space_style) = space.take() {
+                        if line_width + 1 + width <= avail {
+                            line.push(Span::styled(" ", space_style));
+                            line.push(Span::styled(txt, style));
+                            line_width += 1 + width;
+                            continue;
+                        }
+                    }
+
+                    if line_width > 0 {
+                        self.push_line(mem::take(&mut line));
+                        line_width = 0;
+                    }
+
+                    if width <= avail {
+                        line.push(Span::styled(txt, style));
+                        line_width = width;
+                    } else {
+                        // break overlong words.
+                        let mut part = String::new();
+                        for g in txt.graphemes(true) {
+                            let gw = unicode_width(g) as usize;
+                            if line_width + gw > avail && line_width > 0 {
+                                line.push(Span::styled(mem::take(&mut part), style));
+                                self.push_line(mem::take(&mut line));
+                                line_width = 0;
+                            }
+                            part.push_str(g);
+                            line_width += gw;
+                        }
+                        if !part.is_empty() {
+                            line.push(Span::styled(part, style));
+                        }
+                    }
+                }
+            }
+        }
+
+        if !line.is_empty() {
+            self.push_line(line);
+        }
+    }
+
+    fn push_line(&mut self, spans: Vec<Span<'static>>) {
+        let mut line = self.prefix();
+        line.extend(spans);
+        self.lines.push(Line::from(line));
+    }
+
+    fn code_block(&mut self, lang: &str, code: &str) {
+        let style = self.cur_style().patch(self.p.style(MDStyle::CodeBlock));
+        let code = code.strip_suffix('\n').unwrap_or(code);
+
+        let code_lines = if let Some(highlight) = self.p.highlight {
+            highlight(lang, code, style)
+        } else {
+            None
+        };
+        let code_lines = code_lines.unwrap_or_else(|| {
+            code.lines()
+                .map(|v| Line::from(Span::styled(v.to_string(), style)))
+                .collect()
+        });
+
+        for code_line in code_lines {
+            let mut line = self.prefix();
+            line.extend(code_line.spans);
+            self.lines.push(Line::from(line));
+        }
+    }
+
+    fn table(&mut self, table: Table) {
+        let style = self.cur_style().patch(self.p.style(MDStyle::Table));
+        let head_style = style.patch(self.p.style(MDStyle::TableHead));
+        let row_style = style.patch(self.p.style(MDStyle::TableRow));
+
+        let cell_width = |cell: &Vec<Span<'static>>| cell.iter().map(|v| v.width()).sum::<usize>();
+
+        let mut widths = Vec::<usize>::new();
+        for row in table.rows.iter() {
+            for (idx, cell) in row.iter().enumerate() {
+                if widths.len() <= idx {
+                    widths.push(0);
+                }
+                widths[idx] = widths[idx].max(cell_width(cell));
+            }
+        }
+
+        for (row_idx, row) in table.rows.into_iter().enumerate() {
+            let is_head = row_idx < table.head;
+            let cur_style = if is_head { head_style } else { row_style };
+
+            let mut line = self.prefix();
+            for (idx, width) in widths.iter().enumerate() {
+                if idx > 0 {
+                    line.push(Span::styled(" │ ", style));
+                }
+                let cell = row.get(idx).cloned().unwrap_or_default();
+                let fill = width - cell_width(&cell);
+                let (before, after) = match table.align.get(idx) {
+                    Some(Alignment::Right) => (fill, 0),
+                    Some(Alignment::Center) => (fill / 2, fill - fill / 2),
+                    _ => (0, fill),
+                };
+                if before > 0 {
+                    line.push(Span::styled(" ".repeat(before), cur_style));
+                }
+                for span in cell {
+                    let span_style = cur_style.patch(span.style);
+                    line.push(Span::styled(span.content, span_style));
+                }
+                if after > 0 {
+                    line.push(Span::styled(" ".repeat(after), cur_style));
+                }
+            }
+            self.lines.push(Line::from(line));
+
+            if is_head && row_idx + 1 == table.head {
+                let mut line = self.prefix();
+                for (idx, width) in widths.iter().enumerate() {
+                    if idx > 0 {
+                        line.push(Span::styled("─┼─", style));
+                    }
+                    line.push(Span::styled("─".repeat(*width), style));
+                }
+                self.lines.push(Line::from(line));
+            }
+        }
+    }
+}
@@ -106,6 +106,18 @@ impl TryFrom<usize> for MDStyle {
     }
 }
 
+/// Parser options used for styling and preview.
+pub(crate) fn md_options() -> Options {
+    Options::ENABLE_MATH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_SMART_PUNCTUATION
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_GFM
+        | Options::ENABLE_DEFINITION_LIST
+}
+
 /// Parse the text and return the byte ranges for each structural element.
 ///
 /// Returns a format suitable for TextArea: byte-range->style-idx.
@@ -114,18 +126,7 @@ impl TryFrom<usize> for MDStyle {
 pub fn parse_md_styles(txt: &str) -> Vec<(Range<usize>, usize)> {
     let mut styles = Vec::new();
 
-    let p = Parser::new_ext(
-        txt,
-        Options::ENABLE_MATH
-            | Options::ENABLE_TASKLISTS
-            | Options::ENABLE_TABLES
-            | Options::ENABLE_STRIKETHROUGH
-            | Options::ENABLE_SMART_PUNCTUATION
-            | Options::ENABLE_FOOTNOTES
-            | Options::ENABLE_GFM
-            | Options::ENABLE_DEFINITION_LIST,
-    )
-    .into_offset_iter();
+    let p = Parser::new_ext(txt, md_options()).into_offset_iter();
 
     for (_, linkdef) in p.reference_definitions().iter() {
         styles.push((linkdef.span.clone(), MDStyle::LinkDef as usize));
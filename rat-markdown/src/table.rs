@@ -0,0 +1,410 @@
+use crate::parser::parse_md_row;
+use crate::util::str_line_len;
+use crate::MDStyle;
+use rat_text::event::TextOutcome;
+use rat_text::text_area::TextAreaState;
+use rat_text::{upos_type, TextPosition, TextRange};
+use std::fmt::Write;
+use unicode_display_width::width as unicode_width;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Column alignment as given by the delimiter row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MDAlign {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+/// Parsed table.
+#[derive(Debug)]
+struct MDTable {
+    // indent of the first row
+    indent: String,
+    // header + body rows. trimmed cell text.
+    rows: Vec<Vec<String>>,
+    // line ending per row.
+    newline: Vec<String>,
+    // line ending of the delimiter row.
+    delim_newline: String,
+    // alignment per column.
+    align: Vec<MDAlign>,
+    // cursor row, excluding the delimiter row.
+    cursor_row: usize,
+    // cursor cell
+    cursor_cell: usize,
+    // cursor grapheme offset into the trimmed cell text.
+    cursor_offset: upos_type,
+}
+
+/// Reformat the table at the cursor position.
+///
+/// Realigns all pipes and pads the cells to the widest
+/// entry per column. Alignment markers in the delimiter
+/// row are kept. The cursor stays in the same cell.
+pub fn md_table_reformat(state: &mut TextAreaState) -> TextOutcome {
+    let Some((rows, mut table)) = md_table(state) else {
+        return TextOutcome::Continue;
+    };
+    table.normalize();
+    write_table(state, rows, &table);
+    TextOutcome::TextChanged
+}
+
+/// Add a column to the table at the cursor position.
+///
+/// * after_current: Insert after the current column, otherwise before.
+pub fn md_table_add_column(state: &mut TextAreaState, after_current: bool) -> TextOutcome {
+    let Some((rows, mut table)) = md_table(state) else {
+        return TextOutcome::Continue;
+    };
+    table.normalize();
+
+    let col = if after_current {
+        (table.cursor_cell + 1).min(table.align.len())
+    } else {
+        table.cursor_cell
+    };
+    for row in table.rows.iter_mut() {
+        row.insert(col, String::new());
+    }
+    table.align.insert(col, MDAlign::None);
+    table.cursor_cell = col;
+    table.cursor_offset = 0;
+
+    write_table(state, rows, &table);
+    TextOutcome::TextChanged
+}
+
+/// Remove the column at the cursor position.
+///
+/// The last remaining column is not removed.
+pub fn md_table_remove_column(state: &mut TextAreaState) -> TextOutcome {
+    let Some((rows, mut table)) = md_table(state) else {
+        return TextOutcome::Continue;
+    };
+    table.normalize();
+
+    if table.align.len() <= 1 {
+        return TextOutcome::Unchanged;
+    }
+
+    let col = table.cursor_cell.min(table.align.len() - 1);
+    for row in table.rows.iter_mut() {
+        row.remove(col);
+    }
+    table.align.remove(col);
+    table.cursor_cell = col.min(table.align.len() - 1);
+    table.cursor_offset = 0;
+
+    write_table(state, rows, &table);
+    TextOutcome::TextChanged
+}
+
+/// Add an empty row after the row at the cursor position.
+///
+/// If the cursor is in the header the row is added as
+/// first row of the body.
+pub fn md_table_add_row(state: &mut TextAreaState) -> TextOutcome {
+    let Some((rows, mut table)) = md_table(state) else {
+        return TextOutcome::Continue;
+    };
+    table.normalize();
+
+    let row = table.cursor_row + 1;
+    table
+        .rows
+        .insert(row, vec![String::new(); table.align.len()]);
+    let newline = table.newline[table.cursor_row].clone();
+    table.newline.insert(row, newline);
+    table.cursor_row = row;
+    table.cursor_offset = 0;
+
+    write_table(state, rows, &table);
+    TextOutcome::TextChanged
+}
+
+/// Find the next cell in the table.
+/// Wraps to the first cell of the next row.
+pub(crate) fn md_table_next_cell(state: &TextAreaState) -> Option<TextPosition> {
+    let cursor = state.cursor();
+    let rows = md_table_rows(state)?;
+
+    let line = state.line_at(cursor.y);
+    let row = parse_md_row(0, line.as_ref(), cursor.x);
+    if row.cursor_cell + 1 < row.row.len() {
+        let x = row.row[row.cursor_cell + 1].txt_graphemes.start;
+        if x < str_line_len(line.as_ref()) {
+            return Some(TextPosition::new(x, cursor.y));
+        }
+    }
+
+    let mut y = cursor.y + 1;
+    // skip the delimiter row.
+    if y == rows.start + 1 {
+        y += 1;
+    }
+    if y < rows.end {
+        let line = state.line_at(y);
+        let row = parse_md_row(0, line.as_ref(), 0);
+        let x = row.row.get(1).map(|v| v.txt_graphemes.start).unwrap_or(0);
+        Some(TextPosition::new(x, y))
+    } else {
+        None
+    }
+}
+
+/// Find the previous cell in the table.
+/// Wraps to the last cell of the previous row.
+pub(crate) fn md_table_prev_cell(state: &TextAreaState) -> Option<TextPosition> {
+    let cursor = state.cursor();
+    let rows = md_table_rows(state)?;
+
+    let line = state.line_at(cursor.y);
+    let row = parse_md_row(0, line.as_ref(), cursor.x);
+    if row.cursor_cell > 1 {
+        let x = row.row[row.cursor_cell - 1].txt_graphemes.start;
+        return Some(TextPosition::new(x, cursor.y));
+    }
+
+    let mut y = cursor.y;
+    if y == rows.start {
+        return None;
+    }
+    y -= 1;
+    // skip the delimiter row.
+    if y == rows.start + 1 {
+        y -= 1;
+    }
+    let line = state.line_at(y);
+    let row = parse_md_row(0, line.as_ref(), 0);
+    // last cell before the trailing |
+    let x = if row.row.len() > 2 {
+        row.row[row.row.len() - 2].txt_graphemes.start
+    } else {
+        0
+    };
+    Some(TextPosition::new(x, y))
+}
+
+/// Rows of the table at the cursor position.
+fn md_table_rows(state: &TextAreaState) -> Option<std::ops::Range<upos_type>> {
+    let mut cursor = state.cursor();
+    // after the trailing | at the end of the line.
+    if cursor.x > 0 && cursor.x >= state.line_width(cursor.y) {
+        cursor.x = state.line_width(cursor.y) - 1;
+    }
+    let cursor_byte = state.byte_at(cursor).start;
+    let table_byte = state.style_match(cursor_byte, MDStyle::Table as usize)?;
+    let table_range = state.byte_range(table_byte);
+
+    let end = if table_range.end.x == 0 {
+        table_range.end.y
+    } else {
+        table_range.end.y + 1
+    };
+    Some(table_range.start.y..end.min(state.len_lines()))
+}
+
+/// Parse the table at the cursor position.
+fn md_table(state: &TextAreaState) -> Option<(std::ops::Range<upos_type>, MDTable)> {
+    let cursor = state.cursor();
+    let rows = md_table_rows(state)?;
+
+    let mut table = MDTable {
+        indent: String::new(),
+        rows: Vec::new(),
+        newline: Vec::new(),
+        delim_newline: String::new(),
+        align: Vec::new(),
+        cursor_row: 0,
+        cursor_cell: 0,
+        cursor_offset: 0,
+    };
+
+    for (n, y) in rows.clone().enumerate() {
+        let line = state.line_at(y);
+        let newline = match &line[line.trim_end_matches(['\n', '\r']).len()..] {
+            "" => state.newline().to_string(),
+            v => v.to_string(),
+        };
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        let x = if y == cursor.y { cursor.x } else { 0 };
+        let mut row = parse_md_row(0, line, x);
+
+        let leading_pipe = line.trim_start().starts_with('|');
+        let trailing_pipe = line.trim_end().ends_with('|') && row.row.len() > 1;
+        let first = if leading_pipe { 1 } else { 0 };
+        let last = if trailing_pipe {
+            row.row.len() - 1
+        } else {
+            row.row.len()
+        };
+
+        if n == 0 {
+            let indent_len = line.len() - line.trim_start().len();
+            table.indent = line[..indent_len].to_string();
+        }
+
+        // past the last cell, use the end of the last cell.
+        if y == cursor.y && x >= str_line_len(line) {
+            let cell = last.max(first + 1) - 1;
+            row.cursor_cell = cell;
+            row.cursor_offset = str_line_len(row.row[cell].txt.trim_end());
+        }
+
+        let cells = row.row[first..last.max(first)]
+            .iter()
+            .map(|v| v.txt.trim().to_string())
+            .collect::<Vec<_>>();
+
+        if n == 1 {
+            table.align = cells.iter().map(|v| parse_align(v)).collect();
+            table.delim_newline = newline;
+            if y == cursor.y {
+                table.cursor_row = 0;
+                table.cursor_cell = row.cursor_cell.saturating_sub(first);
+            }
+            continue;
+        }
+
+        if y == cursor.y {
+            table.cursor_row = table.rows.len();
+            table.cursor_cell = row.cursor_cell.saturating_sub(first);
+
+            // offset into the trimmed text.
+            if let Some(cell) = row.row.get(row.cursor_cell) {
+                let leading = cell.txt.len() - cell.txt.trim_start().len();
+                let leading = str_line_len(&cell.txt[..leading]);
+                let len = str_line_len(cell.txt.trim());
+                table.cursor_offset = row.cursor_offset.saturating_sub(leading).min(len);
+            }
+        }
+
+        table.rows.push(cells);
+        table.newline.push(newline);
+    }
+
+    if table.rows.is_empty() {
+        return None;
+    }
+
+    Some((rows, table))
+}
+
+fn parse_align(txt: &str) -> MDAlign {
+    match (txt.starts_with(':'), txt.ends_with(':') && txt.len() > 1) {
+        (true, true) => MDAlign::Center,
+        (true, false) => MDAlign::Left,
+        (false, true) => MDAlign::Right,
+        (false, false) => MDAlign::None,
+    }
+}
+
+impl MDTable {
+    /// Make all rows the same length.
+    fn normalize(&mut self) {
+        let cols = self
+            .rows
+            .iter()
+            .map(|v| v.len())
+            .max()
+            .unwrap_or_default()
+            .max(self.align.len())
+            .max(1);
+        for row in self.rows.iter_mut() {
+            row.resize(cols, String::new());
+        }
+        self.align.resize(cols, MDAlign::None);
+        self.cursor_cell = self.cursor_cell.min(cols - 1);
+    }
+
+    /// Create the formatted text and the cursor position relative to it.
+    ///
+    /// Each row keeps its line ending.
+    fn format(&self) -> (String, TextPosition) {
+        let mut width = vec![1; self.align.len()];
+        for row in self.rows.iter() {
+            for (idx, cell) in row.iter().enumerate() {
+                width[idx] = width[idx].max(unicode_width(cell) as usize);
+            }
+        }
+
+        let indent_len = str_line_len(&self.indent);
+        let mut cursor = TextPosition::new(0, 0);
+        let mut out = String::new();
+        for (n, row) in self.rows.iter().enumerate() {
+            let y = if n == 0 { 0 } else { n + 1 };
+            if n == 1 {
+                out.push_str(&self.indent);
+                for (align, w) in self.align.iter().zip(width.iter()) {
+                    let _ = match align {
+                        MDAlign::None => write!(out, "|{}", "-".repeat(w + 2)),
+                        MDAlign::Left => write!(out, "|:{}", "-".repeat(w + 1)),
+                        MDAlign::Right => write!(out, "|{}:", "-".repeat(w + 1)),
+                        MDAlign::Center => write!(out, "|:{}:", "-".repeat(*w)),
+                    };
+                }
+                out.push('|');
+                out.push_str(&self.delim_newline);
+            }
+
+            out.push_str(&self.indent);
+            let mut x = indent_len;
+            for (idx, (cell, w)) in row.iter().zip(width.iter()).enumerate() {
+                let fill = w - unicode_width(cell) as usize;
+                let (before, after) = match self.align[idx] {
+                    MDAlign::Right => (fill, 0),
+                    MDAlign::Center => (fill / 2, fill - fill / 2),
+                    MDAlign::None | MDAlign::Left => (0, fill),
+                };
+
+                out.push_str("| ");
+                x += 2;
+                out.push_str(&" ".repeat(before));
+                x += before as upos_type;
+                if n == self.cursor_row && idx == self.cursor_cell {
+                    cursor = TextPosition::new(x + self.cursor_offset, y as upos_type);
+                }
+                out.push_str(cell);
+                x += cell.graphemes(true).count() as upos_type;
+                out.push_str(&" ".repeat(after + 1));
+                x += after as upos_type + 1;
+            }
+            out.push('|');
+            if n + 1 < self.rows.len() || self.rows.len() == 1 {
+                out.push_str(&self.newline[n]);
+            }
+        }
+
+        // header only, add the delimiter row.
+        if self.rows.len() == 1 {
+            out.push_str(&self.indent);
+            for w in width.iter() {
+                let _ = write!(out, "|{}", "-".repeat(w + 2));
+            }
+            out.push('|');
+        }
+
+        (out, cursor)
+    }
+}
+
+/// Replace the table rows with the formatted table.
+fn write_table(state: &mut TextAreaState, rows: std::ops::Range<upos_type>, table: &MDTable) {
+    let (txt, cursor) = table.format();
+
+    let last = rows.end - 1;
+    let range = TextRange::new((0, rows.start), (state.line_width(last), last));
+
+    state.begin_undo_seq();
+    state.delete_range(range);
+    state
+        .value
+        .insert_str(range.start, &txt)
+        .expect("valid_pos");
+    state.set_cursor((cursor.x, rows.start + cursor.y), false);
+    state.end_undo_seq();
+}
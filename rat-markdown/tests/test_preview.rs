@@ -0,0 +1,59 @@
+use rat_markdown::render_markdown;
+use ratatui::text::Text;
+
+fn lines(txt: &Text<'_>) -> Vec<String> {
+    txt.lines.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_paragraph() {
+    let t = render_markdown("aaa bbb ccc\nddd eee", 8, &[]);
+    assert_eq!(lines(&t), vec!["aaa bbb", "ccc ddd", "eee"]);
+}
+
+#[test]
+fn test_hard_break() {
+    let t = render_markdown("aaa  \nbbb\\\nccc", 20, &[]);
+    assert_eq!(lines(&t), vec!["aaa", "bbb", "ccc"]);
+}
+
+#[test]
+fn test_nested_list() {
+    let t = render_markdown("* aaa\n  * bbb ccc\n  * ddd\n* eee\n\npara", 10, &[]);
+    assert_eq!(
+        lines(&t),
+        vec![
+            "• aaa",
+            "  • bbb",
+            "    ccc",
+            "  • ddd",
+            "• eee",
+            "",
+            "para"
+        ]
+    );
+}
+
+#[test]
+fn test_ordered_list() {
+    let t = render_markdown("3. aaa\n4. bbb\n   1. ccc", 20, &[]);
+    assert_eq!(lines(&t), vec!["3. aaa", "4. bbb", "   1. ccc"]);
+}
+
+#[test]
+fn test_quote() {
+    let t = render_markdown("> aaa bbb\n>\n> ccc", 7, &[]);
+    assert_eq!(lines(&t), vec!["│ aaa", "│ bbb", "│ ", "│ ccc"]);
+}
+
+#[test]
+fn test_code_block() {
+    let t = render_markdown("# Head\n\n```rust\nfn a() {}\n  b\n```", 5, &[]);
+    assert_eq!(lines(&t), vec!["Head", "", "fn a() {}", "  b"]);
+}
+
+#[test]
+fn test_table() {
+    let t = render_markdown("|a|bbbb|\n|--|:-:|\n|ccc|d|", 20, &[]);
+    assert_eq!(lines(&t), vec!["a   │ bbbb", "────┼─────", "ccc │  d  "]);
+}
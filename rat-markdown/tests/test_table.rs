@@ -0,0 +1,149 @@
+use rat_markdown::op::{
+    md_backtab, md_tab, md_table_add_column, md_table_add_row, md_table_reformat,
+    md_table_remove_column,
+};
+use rat_markdown::parse_md_styles;
+use rat_text::text_area::TextAreaState;
+use rat_text::TextPosition;
+use ratatui::layout::Rect;
+
+fn state(txt: &str, cursor: (u32, u32)) -> TextAreaState {
+    let mut state = TextAreaState::new();
+    state.inner = Rect::new(0, 0, 80, 20);
+    state.set_text(txt);
+    state.set_styles(parse_md_styles(txt));
+    state.set_cursor(cursor, false);
+    state
+}
+
+fn restyle(state: &mut TextAreaState) {
+    let txt = state.text();
+    state.set_styles(parse_md_styles(&txt));
+}
+
+#[test]
+fn test_reformat_ragged() {
+    let mut s = state("|a|bbb|\n|:-|--:|\n|cccc|d|e|\n|f\n\npara", (1, 2));
+    md_table_reformat(&mut s);
+    assert_eq!(
+        s.text(),
+        "| a    | bbb |   |\n\
+         |:-----|----:|---|\n\
+         | cccc |   d | e |\n\
+         | f    |     |   |\n\
+         \n\
+         para"
+    );
+    // stays in cell 'cccc'
+    assert_eq!(s.cursor(), TextPosition::new(2, 2));
+}
+
+#[test]
+fn test_reformat_cursor_offset() {
+    let mut s = state("| aa |b|\n|-|-|\n|x|  yyy|", (6, 2));
+    md_table_reformat(&mut s);
+    assert_eq!(s.text(), "| aa | b   |\n|----|-----|\n| x  | yyy |");
+    // second y
+    assert_eq!(s.cursor(), TextPosition::new(8, 2));
+}
+
+#[test]
+fn test_reformat_center() {
+    let mut s = state("|a|\n|:-:|\n|bbbbb|", (0, 0));
+    md_table_reformat(&mut s);
+    assert_eq!(s.text(), "|   a   |\n|:-----:|\n| bbbbb |");
+}
+
+#[test]
+fn test_reformat_unicode() {
+    let mut s = state("|名前|x|\n|-|-|\n|ab|ü|", (4, 2));
+    md_table_reformat(&mut s);
+    assert_eq!(s.text(), "| 名前 | x |\n|------|---|\n| ab   | ü |");
+    assert_eq!(s.cursor(), TextPosition::new(9, 2));
+}
+
+#[test]
+fn test_add_column() {
+    let mut s = state("|a|b|\n|-|-|\n|c|d|", (1, 2));
+    md_table_add_column(&mut s, true);
+    assert_eq!(s.text(), "| a |   | b |\n|---|---|---|\n| c |   | d |");
+    assert_eq!(s.cursor(), TextPosition::new(6, 2));
+
+    restyle(&mut s);
+    s.set_cursor((2, 0), false);
+    md_table_add_column(&mut s, false);
+    assert_eq!(
+        s.text(),
+        "|   | a |   | b |\n|---|---|---|---|\n|   | c |   | d |"
+    );
+    assert_eq!(s.cursor(), TextPosition::new(2, 0));
+}
+
+#[test]
+fn test_remove_column() {
+    let mut s = state("|a|b|c|\n|:-|-:|-|\n|d|e|f|", (3, 2));
+    md_table_remove_column(&mut s);
+    assert_eq!(s.text(), "| a | c |\n|:--|---|\n| d | f |");
+    assert_eq!(s.cursor(), TextPosition::new(6, 2));
+}
+
+#[test]
+fn test_add_row() {
+    let mut s = state("|a|b|\n|-|-|\n|c|d|", (1, 0));
+    md_table_add_row(&mut s);
+    assert_eq!(s.text(), "| a | b |\n|---|---|\n|   |   |\n| c | d |");
+    assert_eq!(s.cursor(), TextPosition::new(2, 2));
+}
+
+#[test]
+fn test_tab() {
+    let mut s = state("|a|b|\n|-|-|\n|c|d|\n", (1, 0));
+    md_tab(&mut s);
+    assert_eq!(s.cursor(), TextPosition::new(3, 0));
+    md_tab(&mut s);
+    assert_eq!(s.cursor(), TextPosition::new(1, 2));
+    md_tab(&mut s);
+    assert_eq!(s.cursor(), TextPosition::new(3, 2));
+    md_backtab(&mut s);
+    assert_eq!(s.cursor(), TextPosition::new(1, 2));
+    md_backtab(&mut s);
+    assert_eq!(s.cursor(), TextPosition::new(3, 0));
+}
+
+#[test]
+fn test_cursor_after_row() {
+    // after the trailing | works on the last cell.
+    let mut s = state("|a|b|\n|-|-|\n|c|d|\n\npara", (5, 2));
+    md_table_add_column(&mut s, true);
+    assert_eq!(
+        s.text(),
+        "| a | b |   |\n|---|---|---|\n| c | d |   |\n\npara"
+    );
+    assert_eq!(s.cursor(), TextPosition::new(10, 2));
+
+    // at the end of the text.
+    let mut s = state("|a|b|\n|-|-|\n|c|d|", (5, 2));
+    md_table_remove_column(&mut s);
+    assert_eq!(s.text(), "| a |\n|---|\n| c |");
+
+    let mut s = state("|a|bb|\n|-|-|\n|c|d|", (6, 0));
+    md_table_reformat(&mut s);
+    assert_eq!(s.text(), "| a | bb |\n|---|----|\n| c | d  |");
+    // behind bb
+    assert_eq!(s.cursor(), TextPosition::new(8, 0));
+}
+
+#[test]
+fn test_keep_newline() {
+    let mut s = state("|a|b|\r\n|-|-|\n|c|d|\r\n\npara", (1, 2));
+    md_table_reformat(&mut s);
+    assert_eq!(s.text(), "| a | b |\r\n|---|---|\n| c | d |\r\n\npara");
+
+    restyle(&mut s);
+    s.set_cursor((2, 0), false);
+    md_table_add_row(&mut s);
+    assert_eq!(
+        s.text(),
+        "| a | b |\r\n|---|---|\n|   |   |\r\n| c | d |\r\n\npara"
+    );
+}
@@ -4,13 +4,16 @@ use crate::_private::NonExhaustive;
 use crate::menuitem::{MenuItem, Separator};
 use rat_popup::PopupStyle;
 use ratatui::prelude::Style;
+use std::any::Any;
 use std::fmt::Debug;
 use std::ops::Range;
+use std::sync::Arc;
 
 pub mod menubar;
 pub mod menuitem;
 pub mod menuline;
 pub mod popup_menu;
+pub mod recent;
 mod util;
 
 pub mod event {
@@ -56,6 +59,23 @@ pub mod event {
         ///
         /// Used by Menubar for results from a popup-menu. Is (main-idx, popup-idx);
         MenuActivated(usize, usize),
+
+        /// A popup-menuitem with a payload was activated.
+        ///
+        /// Used by Menubar instead of MenuActivated, if the item has a
+        /// payload. Is (main-idx, popup-idx). The payload is available via
+        /// [MenubarState::payload](crate::menubar::MenubarState::payload)
+        /// until the next render.
+        MenuActivatedPayload(usize, usize),
+
+        /// A menuitem in a cascading submenu was activated.
+        ///
+        /// Used by PopupMenu. Is (first-idx, last-idx) of the path
+        /// through the submenus. The full path is available via
+        /// [PopupMenuState::activated_path](crate::popup_menu::PopupMenuState::activated_path).
+        ///
+        /// Used by Menubar with the main-idx as first-idx.
+        SubmenuActivated(usize, usize),
     }
 
     impl ConsumedEvent for MenuOutcome {
@@ -74,6 +94,8 @@ pub mod event {
                 MenuOutcome::Activated(_) => Outcome::Changed,
                 MenuOutcome::MenuSelected(_, _) => Outcome::Changed,
                 MenuOutcome::MenuActivated(_, _) => Outcome::Changed,
+                MenuOutcome::MenuActivatedPayload(_, _) => Outcome::Changed,
+                MenuOutcome::SubmenuActivated(_, _) => Outcome::Changed,
                 MenuOutcome::Hide => Outcome::Changed,
             }
         }
@@ -224,6 +246,15 @@ impl<'a> MenuBuilder<'a> {
         self
     }
 
+    /// Sets the payload for the last item.
+    /// If there is no last item does nothing.
+    pub fn payload(&mut self, payload: impl Any + Send + Sync) -> &mut Self {
+        if let Some(last) = self.items.last_mut() {
+            last.payload = Some(Arc::new(payload));
+        }
+        self
+    }
+
     /// Sets the last item to disabled.
     /// If there is no last item does nothing.
     pub fn disabled(&mut self, disable: bool) -> &mut Self {
@@ -233,6 +264,17 @@ impl<'a> MenuBuilder<'a> {
         self
     }
 
+    /// Sets a cascading submenu for the last item.
+    /// If there is no last item does nothing.
+    pub fn submenu(&mut self, f: impl FnOnce(&mut MenuBuilder<'a>)) -> &mut Self {
+        if let Some(last) = self.items.last_mut() {
+            let mut builder = MenuBuilder::new();
+            f(&mut builder);
+            last.submenu = Some(builder.items);
+        }
+        self
+    }
+
     /// Build and deconstruct.
     pub fn items(self) -> Vec<MenuItem<'a>> {
         self.items
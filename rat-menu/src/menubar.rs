@@ -14,18 +14,28 @@
 //! and must be called before the [Regular] event-handlers to work correctly.
 //! Event-handling for the menu line is via the [Regular] event-handler.
 //!
+//! __Overflow__
+//!
+//! Menus that don't fit the menu line are collapsed into a
+//! trailing '≡' item. Left/Right moves through them as usual,
+//! and the popup shows the items of the selected hidden menu.
+//! Clicking the '≡' opens a popup with the hidden menus instead.
+//! All outcomes use the original menu indices.
+//!
 use crate::event::MenuOutcome;
 use crate::menuline::{MenuLine, MenuLineState};
 use crate::popup_menu::{PopupMenu, PopupMenuState};
-use crate::{MenuStructure, MenuStyle};
-use rat_event::{ConsumedEvent, HandleEvent, MouseOnly, Popup, Regular};
+use crate::{MenuBuilder, MenuStructure, MenuStyle};
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Popup, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_popup::Placement;
+use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Rect};
 use ratatui::style::Style;
 use ratatui::text::Line;
 use ratatui::widgets::{Block, StatefulWidget};
+use std::any::Any;
 use std::fmt::Debug;
 
 /// Menubar widget.
@@ -92,6 +102,9 @@ pub struct MenubarState {
     pub bar: MenuLineState,
     /// State for the last rendered popup menu.
     pub popup: PopupMenuState,
+    /// The popup lists the hidden menus of the overflow item.
+    /// __read+write__
+    pub overflow_list: bool,
 }
 
 impl Default for Menubar<'_> {
@@ -314,6 +327,10 @@ fn render_menu_popup(
         return;
     };
 
+    if !state.bar.is_hidden(selected) {
+        state.overflow_list = false;
+    }
+
     if state.popup.is_active() {
         let item = state.bar.item_areas[selected];
 
@@ -334,7 +351,14 @@ fn render_menu_popup(
             .disabled_style_opt(widget.disabled_style)
             .right_style_opt(widget.right_style);
 
-        structure.submenu(selected, &mut popup.menu);
+        if state.overflow_list {
+            let overflow = state.bar.overflow.expect("overflow");
+            let mut menus = MenuBuilder::new();
+            structure.menus(&mut menus);
+            popup.menu.items = menus.items.split_off(overflow);
+        } else {
+            structure.submenu(selected, &mut popup.menu);
+        }
 
         if !popup.menu.items.is_empty() {
             let area = state.bar.item_areas[selected];
@@ -342,9 +366,13 @@ fn render_menu_popup(
 
             // Combined area + each part with a z-index.
             state.area = state.bar.area.union(state.popup.popup.area);
+            for (area, _) in state.popup.submenu_areas() {
+                state.area = state.area.union(area);
+            }
         }
     } else {
         state.popup = Default::default();
+        state.overflow_list = false;
     }
 }
 
@@ -389,7 +417,45 @@ impl MenubarState {
 
     /// Selected as menu/submenu
     pub fn selected(&self) -> (Option<usize>, Option<usize>) {
-        (self.bar.selected, self.popup.selected)
+        if self.overflow_list {
+            (self.bar.selected, None)
+        } else {
+            (self.bar.selected, self.popup.selected)
+        }
+    }
+
+    /// Index path of the last item activated in a cascading
+    /// submenu. Starts with the main-idx.
+    pub fn activated_path(&self) -> Vec<usize> {
+        let mut path = Vec::new();
+        path.extend(self.bar.selected);
+        path.extend_from_slice(self.popup.activated_path());
+        path
+    }
+
+    /// Payload of a popup-menuitem.
+    ///
+    /// Only the items of the last rendered popup are available,
+    /// so this is valid until the next render.
+    pub fn payload(&self, main: usize, sub: usize) -> Option<&dyn Any> {
+        if self.bar.selected != Some(main) {
+            return None;
+        }
+        match self.popup.payloads.get(sub) {
+            Some(Some(payload)) => Some(payload.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl RelocatableState for MenubarState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.bar.relocate(shift, clip);
+    }
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        self.popup.relocate_popup(shift, clip);
     }
 }
 
@@ -402,6 +468,9 @@ impl HasFocus for MenubarState {
             self.popup.popup.area_z,
             Navigation::Mouse,
         );
+        for (area, z) in self.popup.submenu_areas() {
+            builder.widget_with_flags(self.focus(), area, z, Navigation::Mouse);
+        }
     }
 
     fn focus(&self) -> FocusFlag {
@@ -442,14 +511,25 @@ where
 
     if state.bar.is_focused() {
         let mut r = if let Some(selected) = state.bar.selected() {
-            if state.popup_active() {
+            if state.popup_active() && state.overflow_list {
+                handle_overflow_list(state, event, qualifier1)
+            } else if state.popup_active() {
                 match state.popup.handle(event, qualifier1) {
                     MenuOutcome::Hide => {
                         // only hide on focus lost. ignore this one.
                         MenuOutcome::Continue
                     }
                     MenuOutcome::Selected(n) => MenuOutcome::MenuSelected(selected, n),
-                    MenuOutcome::Activated(n) => MenuOutcome::MenuActivated(selected, n),
+                    MenuOutcome::Activated(n) => {
+                        if matches!(state.popup.payloads.get(n), Some(Some(_))) {
+                            MenuOutcome::MenuActivatedPayload(selected, n)
+                        } else {
+                            MenuOutcome::MenuActivated(selected, n)
+                        }
+                    }
+                    MenuOutcome::SubmenuActivated(_, n) => {
+                        MenuOutcome::SubmenuActivated(selected, n)
+                    }
                     r => r,
                 }
             } else {
@@ -463,13 +543,21 @@ where
             let old_selected = state.bar.selected();
             let r = state.bar.handle(event, qualifier2);
             match r {
-                MenuOutcome::Selected(_) => {
+                MenuOutcome::Selected(n) => {
                     if state.bar.selected == old_selected {
                         state.popup.flip_active();
                     } else {
                         state.popup.select(None);
                         state.popup.set_active(true);
                     }
+                    // a click on the overflow item lists the hidden menus.
+                    state.overflow_list = state.popup_active()
+                        && matches!(event, ct_event!(mouse down Left for x,y)
+                            if state.bar.overflow_area.contains((*x, *y).into()));
+                    if state.overflow_list {
+                        let overflow = state.bar.overflow.expect("overflow");
+                        state.popup.select(Some(n - overflow));
+                    }
                 }
                 MenuOutcome::Activated(_) => {
                     state.popup.flip_active();
@@ -485,6 +573,39 @@ where
     }
 }
 
+/// Popup with the list of hidden menus.
+///
+/// Selecting a hidden menu selects it in the menu line too,
+/// activating it shows its items instead.
+fn handle_overflow_list<Q>(
+    state: &mut MenubarState,
+    event: &crossterm::event::Event,
+    qualifier: Q,
+) -> MenuOutcome
+where
+    PopupMenuState: HandleEvent<crossterm::event::Event, Q, MenuOutcome>,
+{
+    let overflow = state.bar.overflow.expect("overflow");
+    match state.popup.handle(event, qualifier) {
+        MenuOutcome::Hide => {
+            // only hide on focus lost. ignore this one.
+            MenuOutcome::Continue
+        }
+        MenuOutcome::Selected(n) => {
+            state.bar.select(Some(overflow + n));
+            MenuOutcome::Selected(overflow + n)
+        }
+        MenuOutcome::Activated(n) => {
+            state.bar.select(Some(overflow + n));
+            state.overflow_list = false;
+            state.popup.select(None);
+            state.popup.set_active(true);
+            MenuOutcome::Selected(overflow + n)
+        }
+        r => r,
+    }
+}
+
 /// Handle menu events for the popup-menu.
 ///
 /// This one is separate, as it needs to be called before other event-handlers
@@ -3,8 +3,10 @@
 //!
 
 use crate::_private::NonExhaustive;
+use std::any::Any;
 use std::borrow::Cow;
 use std::ops::Range;
+use std::sync::Arc;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Separator style
@@ -37,6 +39,16 @@ pub struct MenuItem<'a> {
 
     /// Separator after the item.
     pub separator: Option<Separator>,
+    /// Application data for the item, e.g. the path for
+    /// a recent file.
+    ///
+    /// See [MenubarState::payload](crate::menubar::MenubarState::payload).
+    pub payload: Option<Arc<dyn Any + Send + Sync>>,
+    /// Items of a cascading submenu. Only used by PopupMenu.
+    ///
+    /// The item shows a submenu marker, and activating it opens
+    /// the submenu instead.
+    pub submenu: Option<Vec<MenuItem<'a>>>,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -50,6 +62,8 @@ impl Default for MenuItem<'_> {
             right: Default::default(),
             disabled: false,
             separator: None,
+            payload: None,
+            submenu: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -64,6 +78,8 @@ impl<'a> MenuItem<'a> {
             right: Default::default(),
             disabled: false,
             separator: Default::default(),
+            payload: None,
+            submenu: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -104,6 +120,8 @@ impl<'a> MenuItem<'a> {
             right: Cow::Borrowed(""),
             disabled: false,
             separator: Default::default(),
+            payload: None,
+            submenu: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -117,6 +135,8 @@ impl<'a> MenuItem<'a> {
             right: Default::default(),
             disabled: false,
             separator: Default::default(),
+            payload: None,
+            submenu: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -131,6 +151,8 @@ impl<'a> MenuItem<'a> {
             right: Cow::Borrowed(""),
             disabled: false,
             separator: Default::default(),
+            payload: None,
+            submenu: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -145,6 +167,8 @@ impl<'a> MenuItem<'a> {
             right: Cow::Borrowed(""),
             disabled: false,
             separator: Default::default(),
+            payload: None,
+            submenu: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -161,6 +185,8 @@ impl<'a> MenuItem<'a> {
             right: Default::default(),
             disabled: false,
             separator: Some(separator),
+            payload: None,
+            submenu: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -183,14 +209,33 @@ impl<'a> MenuItem<'a> {
         self
     }
 
+    /// Application data for the item.
+    pub fn payload(mut self, payload: impl Any + Send + Sync) -> Self {
+        self.payload = Some(Arc::new(payload));
+        self
+    }
+
+    /// Items for a cascading submenu.
+    pub fn submenu(mut self, items: Vec<MenuItem<'a>>) -> Self {
+        self.submenu = Some(items);
+        self
+    }
+
     /// Text-width in graphemes for item.
     pub fn item_width(&self) -> u16 {
         self.item.graphemes(true).count() as u16 - if self.navchar.is_some() { 1 } else { 0 }
     }
 
     /// Text-width in graphemes for right.
+    ///
+    /// An item with a submenu and no right text uses the
+    /// width of the submenu marker.
     pub fn right_width(&self) -> u16 {
-        self.right.graphemes(true).count() as u16
+        if self.right.is_empty() && self.submenu.is_some() {
+            1
+        } else {
+            self.right.graphemes(true).count() as u16
+        }
     }
 
     /// Text-height.
@@ -8,6 +8,7 @@ use crate::{MenuBuilder, MenuItem, MenuStyle};
 use rat_event::util::MouseFlags;
 use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Style, Stylize};
@@ -39,8 +40,17 @@ pub struct MenuLineState {
     /// __readonly__. renewed for each render.
     pub area: Rect,
     /// Areas for each item.
+    ///
+    /// Items that don't fit share the area of the overflow item.
     /// __readonly__. renewed for each render.
     pub item_areas: Vec<Rect>,
+    /// Index of the first item that doesn't fit.
+    /// These items are collapsed into a trailing overflow item.
+    /// __readonly__. renewed for each render.
+    pub overflow: Option<usize>,
+    /// Area of the overflow item.
+    /// __readonly__. renewed for each render.
+    pub overflow_area: Rect,
     /// Hot keys
     /// __readonly__. renewed for each render.
     pub navchar: Vec<Option<char>>,
@@ -240,9 +250,18 @@ impl StatefulWidget for MenuLine<'_> {
     }
 }
 
+/// Symbol for the overflow item.
+const OVERFLOW: &str = "\u{2261}";
+
+fn item_width(item: &MenuItem<'_>) -> u16 {
+    item.item_width() + item.right_width() + if item.right.is_empty() { 0 } else { 2 }
+}
+
 fn render_ref(widget: &MenuLine<'_>, area: Rect, buf: &mut Buffer, state: &mut MenuLineState) {
     state.area = area;
     state.item_areas.clear();
+    state.overflow = None;
+    state.overflow_area = Rect::default();
 
     state.navchar = widget
         .menu
@@ -300,9 +319,33 @@ fn render_ref(widget: &MenuLine<'_>, area: Rect, buf: &mut Buffer, state: &mut M
         item_area.x += item_area.width + 1;
     }
 
+    // everything that doesn't fit goes into the overflow item.
+    let total = widget
+        .menu
+        .items
+        .iter()
+        .map(|v| item_width(v) + 1)
+        .sum::<u16>()
+        .saturating_sub(1);
+    if item_area.x.saturating_add(total) > area.right() {
+        let mut x = item_area.x;
+        let mut visible = 0;
+        for item in widget.menu.items.iter() {
+            // leave room for the overflow item.
+            if x + item_width(item) + 1 + Span::from(OVERFLOW).width() as u16 > area.right() {
+                break;
+            }
+            x += item_width(item) + 1;
+            visible += 1;
+        }
+        state.overflow = Some(visible);
+    }
+
     for (n, item) in widget.menu.items.iter().enumerate() {
-        item_area.width =
-            item.item_width() + item.right_width() + if item.right.is_empty() { 0 } else { 2 };
+        if state.overflow.is_some_and(|v| n >= v) {
+            break;
+        }
+        item_area.width = item_width(item);
         if item_area.right() >= area.right() {
             item_area = item_area.clamp(area);
         }
@@ -348,6 +391,41 @@ fn render_ref(widget: &MenuLine<'_>, area: Rect, buf: &mut Buffer, state: &mut M
 
         item_area.x += item_area.width + 1;
     }
+
+    if let Some(overflow) = state.overflow {
+        // show the title of a selected hidden item.
+        let (label, style) = match state.selected {
+            Some(n) if n >= overflow => {
+                let item = &widget.menu.items[n];
+                let label = if let Some(highlight) = item.highlight.clone() {
+                    // without the _
+                    format!(
+                        "{} {}{}",
+                        OVERFLOW,
+                        &item.item[..highlight.start - 1],
+                        &item.item[highlight.start..]
+                    )
+                } else {
+                    format!("{} {}", OVERFLOW, item.item)
+                };
+                if item.disabled {
+                    (label, disabled_style)
+                } else {
+                    (label, focus_style)
+                }
+            }
+            _ => (OVERFLOW.to_string(), widget.style),
+        };
+        let label = Line::from(label);
+        item_area.width = label.width() as u16;
+        let overflow_area = item_area.intersection(area);
+        label.style(style).render(overflow_area, buf);
+
+        state.overflow_area = overflow_area;
+        for _ in overflow..widget.menu.items.len() {
+            state.item_areas.push(overflow_area);
+        }
+    }
 }
 
 impl HasFocus for MenuLineState {
@@ -367,6 +445,14 @@ impl HasFocus for MenuLineState {
 }
 
 #[allow(clippy::len_without_is_empty)]
+impl RelocatableState for MenuLineState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        relocate_areas(&mut self.item_areas, shift, clip);
+        self.overflow_area = relocate_area(self.overflow_area, shift, clip);
+    }
+}
+
 impl MenuLineState {
     pub fn new() -> Self {
         Self::default()
@@ -500,10 +586,18 @@ impl MenuLineState {
     }
 
     /// Item at position.
+    ///
+    /// For the overflow item this is the first hidden item.
     #[inline]
     pub fn item_at(&self, pos: (u16, u16)) -> Option<usize> {
         self.mouse.item_at(&self.item_areas, pos.0, pos.1)
     }
+
+    /// The item doesn't fit and is part of the overflow.
+    #[inline]
+    pub fn is_hidden(&self, n: usize) -> bool {
+        self.overflow.is_some_and(|v| n >= v)
+    }
 }
 
 impl Clone for MenuLineState {
@@ -511,6 +605,8 @@ impl Clone for MenuLineState {
         Self {
             area: self.area,
             item_areas: self.item_areas.clone(),
+            overflow: self.overflow,
+            overflow_area: self.overflow_area,
             navchar: self.navchar.clone(),
             disabled: self.disabled.clone(),
             selected: self.selected,
@@ -526,6 +622,8 @@ impl Default for MenuLineState {
         Self {
             area: Default::default(),
             item_areas: vec![],
+            overflow: None,
+            overflow_area: Default::default(),
             navchar: vec![],
             disabled: vec![],
             selected: None,
@@ -14,25 +14,43 @@
 //!
 //! If no special boundary is set, the widget tries to stay
 //! inside the `buffer.area`.
+//!
+//! __Submenus__
+//!
+//! A [MenuItem] with a [submenu](MenuItem::submenu) opens a
+//! cascading submenu to the right, or to the left if there is
+//! not enough space. Submenus can be nested.
+//!
+//! Hovering an item with the mouse opens its submenu after
+//! [PopupMenuState::submenu_delay]. While moving the mouse
+//! towards an open submenu, the items passed on the way don't
+//! take over the selection immediately (the 'safe triangle').
+//! Both need the elapsed time, which the application feeds
+//! via [PopupMenuState::tick], e.g. from a timer or after
+//! each render.
 
 use crate::_private::NonExhaustive;
 use crate::event::MenuOutcome;
 use crate::util::revert_style;
 use crate::{MenuBuilder, MenuItem, MenuStyle, Separator};
 use rat_event::util::{mouse_trap, MouseFlags};
-use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Popup};
+use rat_event::{ct_event, flow, ConsumedEvent, HandleEvent, MouseOnly, Popup};
 use rat_popup::event::PopupOutcome;
 pub use rat_popup::PopupConstraint;
 use rat_popup::{PopupCore, PopupCoreState};
+use rat_reloc::{relocate_areas, RelocatableState};
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Rect, Size};
+use ratatui::layout::{Alignment, Rect, Size};
 use ratatui::prelude::StatefulWidget;
 use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Span};
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{Block, Padding, Widget};
+use std::any::Any;
 use std::cmp::max;
+use std::sync::Arc;
+use std::time::Duration;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Popup menu.
@@ -67,11 +85,38 @@ pub struct PopupMenuState {
     pub navchar: Vec<Option<char>>,
     /// Disabled menu-items.
     pub disabled: Vec<bool>,
+    /// Payload for each item.
+    /// __readonly__. renewed when the active popup is rendered,
+    /// kept after it is hidden.
+    pub payloads: Vec<Option<Arc<dyn Any + Send + Sync>>>,
+
+    /// Items with a submenu.
+    /// __readonly__. renewed for each render.
+    pub has_submenu: Vec<bool>,
 
     /// Selected item.
     /// __read+write__
     pub selected: Option<usize>,
 
+    /// Open submenu of the selected item.
+    /// Each open submenu has its own state, which
+    /// forms the chain of open submenus.
+    /// __readonly__. created when a submenu is opened.
+    pub submenu: Option<Box<PopupMenuState>>,
+    /// Delay before a hovered item opens its submenu
+    /// or takes over from a different open submenu.
+    /// __read+write__
+    pub submenu_delay: Duration,
+    /// Index path of the last item activated in a submenu.
+    /// __readonly__
+    pub activated_path: Vec<usize>,
+
+    /// Hovered item and the time it has been hovered.
+    /// __used for mouse interaction__
+    pub hover: Option<(usize, Duration)>,
+    /// Last mouse position.
+    /// __used for mouse interaction__
+    pub last_mouse: Option<(u16, u16)>,
     /// Mouse flags
     /// __used for mouse interaction__
     pub mouse: MouseFlags,
@@ -87,7 +132,14 @@ impl Default for PopupMenuState {
             sep_areas: vec![],
             navchar: vec![],
             disabled: vec![],
+            payloads: vec![],
+            has_submenu: vec![],
             selected: None,
+            submenu: None,
+            submenu_delay: Duration::from_millis(300),
+            activated_path: vec![],
+            hover: None,
+            last_mouse: None,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -130,24 +182,53 @@ impl PopupMenu<'_> {
         let horiz_offset = 1;
         let horiz_offset_sep = 0;
 
+        // the popup may have been shrunk to fit.
+        // scroll the selected item into view.
+        let visible = inner.height.saturating_sub(2 * vert_offset);
+        let total = self.menu.items.iter().map(MenuItem::height).sum::<u16>();
+        state.popup.v_scroll.set_page_len(visible as usize);
+        state
+            .popup
+            .v_scroll
+            .set_max_offset(total.saturating_sub(visible) as usize);
+        let offset = state.popup.v_scroll.offset();
+        state.popup.v_scroll.set_offset(offset);
+        if let Some(selected) = state.selected {
+            let sel_row = self
+                .menu
+                .items
+                .iter()
+                .take(selected)
+                .map(MenuItem::height)
+                .sum::<u16>() as usize;
+            state.popup.v_scroll.scroll_to_pos(sel_row);
+        }
+        let offset = state.popup.v_scroll.offset() as u16;
+
         state.item_areas.clear();
         state.sep_areas.clear();
 
         let mut row = 0;
 
         for item in &self.menu.items {
-            state.item_areas.push(Rect::new(
-                inner.x + horiz_offset,
-                inner.y + row + vert_offset,
-                inner.width.saturating_sub(2 * horiz_offset),
-                1,
-            ));
-            state.sep_areas.push(Rect::new(
-                inner.x + horiz_offset_sep,
-                inner.y + row + 1 + vert_offset,
-                inner.width.saturating_sub(2 * horiz_offset_sep),
-                if item.separator.is_some() { 1 } else { 0 },
-            ));
+            if row < offset || row + 1 > offset + visible {
+                state.item_areas.push(Rect::new(inner.x, inner.y, 0, 0));
+                state.sep_areas.push(Rect::new(inner.x, inner.y, 0, 0));
+            } else {
+                let sep_visible = item.separator.is_some() && row + 2 <= offset + visible;
+                state.item_areas.push(Rect::new(
+                    inner.x + horiz_offset,
+                    inner.y + row - offset + vert_offset,
+                    inner.width.saturating_sub(2 * horiz_offset),
+                    1,
+                ));
+                state.sep_areas.push(Rect::new(
+                    inner.x + horiz_offset_sep,
+                    inner.y + row - offset + 1 + vert_offset,
+                    inner.width.saturating_sub(2 * horiz_offset_sep),
+                    if sep_visible { 1 } else { 0 },
+                ));
+            }
 
             row += item.height();
         }
@@ -240,6 +321,12 @@ impl<'a> PopupMenu<'a> {
         self
     }
 
+    /// Render a shadow.
+    pub fn shadow(mut self, shadow: bool) -> Self {
+        self.popup = self.popup.shadow(shadow);
+        self
+    }
+
     /// Set a style-set.
     pub fn styles(mut self, styles: MenuStyle) -> Self {
         self.style = styles.style;
@@ -367,11 +454,24 @@ fn render_popup_menu(
 ) {
     if !state.is_active() {
         state.clear_areas();
+        state.submenu = None;
         return;
     }
 
     state.navchar = widget.menu.items.iter().map(|v| v.navchar).collect();
     state.disabled = widget.menu.items.iter().map(|v| v.disabled).collect();
+    state.payloads = widget
+        .menu
+        .items
+        .iter()
+        .map(|v| v.payload.clone())
+        .collect();
+    state.has_submenu = widget
+        .menu
+        .items
+        .iter()
+        .map(|v| v.submenu.is_some())
+        .collect();
 
     let size = widget.size();
     let area = Rect::new(0, 0, size.width, size.height);
@@ -379,6 +479,53 @@ fn render_popup_menu(
     (&widget.popup).render(area, buf, &mut state.popup);
     widget.layout(state.popup.area, state.popup.widget_area, state);
     render_items(widget, buf, state);
+    render_submenu(widget, buf, state);
+}
+
+fn render_submenu(widget: &PopupMenu<'_>, buf: &mut Buffer, state: &mut PopupMenuState) {
+    if !state.is_submenu_open() {
+        state.submenu = None;
+        return;
+    }
+    let items = state
+        .selected
+        .and_then(|v| widget.menu.items.get(v))
+        .and_then(|v| v.submenu.as_ref());
+    let (Some(selected), Some(items)) = (state.selected, items) else {
+        state.submenu = None;
+        return;
+    };
+
+    // align the first item of the submenu with the selected item.
+    let item_area = state.item_areas[selected];
+    let rel_area = Rect::new(state.popup.area.x, item_area.y, state.popup.area.width, 1);
+    let padding = widget.popup.get_block_padding();
+    let vert_offset = if widget.popup.get_block_size().height == 0 {
+        1
+    } else {
+        0
+    };
+
+    let submenu = PopupMenu {
+        menu: MenuBuilder {
+            items: items.clone(),
+        },
+        width: None,
+        popup: widget
+            .popup
+            .clone()
+            .constraint(PopupConstraint::RightOrLeft(Alignment::Left, rel_area))
+            .offset((0, -(padding.top as i16 + vert_offset))),
+        style: widget.style,
+        highlight_style: widget.highlight_style,
+        disabled_style: widget.disabled_style,
+        right_style: widget.right_style,
+        focus_style: widget.focus_style,
+    };
+
+    if let Some(sub_state) = state.submenu.as_mut() {
+        render_popup_menu(&submenu, Rect::default(), buf, sub_state);
+    }
 }
 
 fn render_items(widget: &PopupMenu<'_>, buf: &mut Buffer, state: &mut PopupMenuState) {
@@ -432,20 +579,23 @@ fn render_items(widget: &PopupMenu<'_>, buf: &mut Buffer, state: &mut PopupMenuS
         };
         item_line.style(style).render(item_area, buf);
 
-        if !item.right.is_empty() {
-            let right_width = item.right.graphemes(true).count() as u16;
+        let right = if item.right.is_empty() && item.submenu.is_some() {
+            "\u{25B8}"
+        } else {
+            item.right.as_ref()
+        };
+        if !right.is_empty() {
+            let right_width = right.graphemes(true).count() as u16;
             if right_width < item_area.width {
                 let delta = item_area.width.saturating_sub(right_width);
                 item_area.x += delta;
                 item_area.width -= delta;
             }
-            Span::from(item.right.as_ref())
-                .style(right_style)
-                .render(item_area, buf);
+            Span::from(right).style(right_style).render(item_area, buf);
         }
 
-        if let Some(separator) = item.separator {
-            let sep_area = state.sep_areas[n];
+        let sep_area = state.sep_areas[n];
+        if let Some(separator) = item.separator.filter(|_| sep_area.height > 0) {
             let sym = match separator {
                 Separator::Empty => " ",
                 Separator::Plain => "\u{2500}",
@@ -463,6 +613,19 @@ fn render_items(widget: &PopupMenu<'_>, buf: &mut Buffer, state: &mut PopupMenuS
     }
 }
 
+impl RelocatableState for PopupMenuState {
+    fn relocate(&mut self, _shift: (i16, i16), _clip: Rect) {}
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        self.popup.relocate(shift, clip);
+        relocate_areas(&mut self.item_areas, shift, clip);
+        relocate_areas(&mut self.sep_areas, shift, clip);
+        if let Some(submenu) = &mut self.submenu {
+            submenu.relocate_popup(shift, clip);
+        }
+    }
+}
+
 impl PopupMenuState {
     /// New
     #[inline]
@@ -503,6 +666,8 @@ impl PopupMenuState {
         self.popup.set_active(active);
         if !active {
             self.clear_areas();
+            self.submenu = None;
+            self.hover = None;
         }
     }
 
@@ -513,6 +678,7 @@ impl PopupMenuState {
         self.navchar.clear();
         self.item_areas.clear();
         self.disabled.clear();
+        self.has_submenu.clear();
     }
 
     /// Number of items.
@@ -642,10 +808,220 @@ impl PopupMenuState {
     pub fn item_at(&self, pos: (u16, u16)) -> Option<usize> {
         self.mouse.item_at(&self.item_areas, pos.0, pos.1)
     }
+
+    /// The selected item has a submenu.
+    pub fn selected_has_submenu(&self) -> bool {
+        self.selected
+            .is_some_and(|v| self.has_submenu.get(v) == Some(&true))
+    }
+
+    /// Is the submenu of the selected item open.
+    pub fn is_submenu_open(&self) -> bool {
+        self.submenu.as_ref().is_some_and(|v| v.is_active())
+    }
+
+    /// Open the submenu of the selected item and select
+    /// its first item.
+    pub fn open_submenu(&mut self) -> bool {
+        if self.open_submenu_impl() {
+            if let Some(submenu) = self.submenu.as_mut() {
+                submenu.selected = Some(0);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn open_submenu_impl(&mut self) -> bool {
+        if !self.selected_has_submenu() || self.is_submenu_open() {
+            return false;
+        }
+        let mut submenu = PopupMenuState::new();
+        submenu.submenu_delay = self.submenu_delay;
+        submenu.popup.area_z = self.popup.area_z + 1;
+        submenu.popup.set_active(true);
+        self.submenu = Some(Box::new(submenu));
+        true
+    }
+
+    /// Close the open submenu, and all submenus below it.
+    pub fn close_submenu(&mut self) -> bool {
+        self.submenu.take().is_some_and(|v| v.is_active())
+    }
+
+    /// Areas of the chain of open submenus.
+    pub fn submenu_areas(&self) -> Vec<(Rect, u16)> {
+        let mut areas = Vec::new();
+        let mut submenu = self.submenu.as_ref();
+        while let Some(sub) = submenu.filter(|v| v.is_active()) {
+            areas.push((sub.popup.area, sub.popup.area_z));
+            submenu = sub.submenu.as_ref();
+        }
+        areas
+    }
+
+    /// Index path of the last item activated in a submenu.
+    ///
+    /// Starts with the item in this menu and ends with the
+    /// activated item.
+    pub fn activated_path(&self) -> &[usize] {
+        &self.activated_path
+    }
+
+    /// Advance the hover timing by the elapsed time.
+    ///
+    /// Opens the submenu of a hovered item, or switches to
+    /// a hovered item that was delayed by the safe triangle,
+    /// once [submenu_delay](PopupMenuState::submenu_delay) is over.
+    /// Call this regularly while the menu is active, e.g. from a
+    /// timer or with the time since the last call after each render.
+    pub fn tick(&mut self, elapsed: Duration) -> MenuOutcome {
+        if !self.is_active() {
+            return MenuOutcome::Continue;
+        }
+
+        let r0 = if let Some(submenu) = self.submenu.as_mut() {
+            submenu.tick(elapsed)
+        } else {
+            MenuOutcome::Continue
+        };
+
+        let r1 = if let Some((idx, time)) = self.hover {
+            let time = time + elapsed;
+            if time >= self.submenu_delay {
+                self.hover = None;
+                let mut changed = false;
+                if self.selected != Some(idx) && self.select(Some(idx)) {
+                    self.close_submenu();
+                    changed = true;
+                }
+                changed |= self.open_submenu_impl();
+                if changed {
+                    MenuOutcome::Changed
+                } else {
+                    MenuOutcome::Unchanged
+                }
+            } else {
+                self.hover = Some((idx, time));
+                MenuOutcome::Unchanged
+            }
+        } else {
+            MenuOutcome::Continue
+        };
+
+        max(r0, r1)
+    }
+
+    /// Mouse moved to the given position.
+    fn hover_at(&mut self, pos: (u16, u16)) -> MenuOutcome {
+        let Some(idx) = self.item_at(pos).filter(|v| !self.disabled[*v]) else {
+            self.hover = None;
+            return MenuOutcome::Unchanged;
+        };
+        if self.hover.map(|v| v.0) != Some(idx) {
+            self.hover = Some((idx, Duration::ZERO));
+        }
+
+        if self.selected == Some(idx) || self.in_safe_triangle(pos) {
+            // switch is delayed, see tick().
+            MenuOutcome::Unchanged
+        } else {
+            self.close_submenu();
+            self.selected = Some(idx);
+            MenuOutcome::Selected(idx)
+        }
+    }
+
+    /// Is the position in the triangle between the last mouse
+    /// position and the near edge of the open submenu.
+    fn in_safe_triangle(&self, pos: (u16, u16)) -> bool {
+        let Some(submenu) = self.submenu.as_ref().filter(|v| v.is_active()) else {
+            return false;
+        };
+        let Some(apex) = self.last_mouse else {
+            return false;
+        };
+        let area = submenu.popup.area;
+        if area.is_empty() {
+            return false;
+        }
+
+        let edge = if area.x >= self.popup.area.right() {
+            area.left()
+        } else {
+            area.right() - 1
+        } as i32;
+        let a = (apex.0 as i32, apex.1 as i32);
+        let b = (edge, area.top() as i32);
+        let c = (edge, area.bottom() as i32 - 1);
+        let p = (pos.0 as i32, pos.1 as i32);
+
+        let side =
+            |u: (i32, i32), v: (i32, i32)| (v.0 - u.0) * (p.1 - u.1) - (v.1 - u.1) * (p.0 - u.0);
+        let (d0, d1, d2) = (side(a, b), side(b, c), side(c, a));
+        let neg = d0 < 0 || d1 < 0 || d2 < 0;
+        let pos = d0 > 0 || d1 > 0 || d2 > 0;
+        !(neg && pos)
+    }
+
+    /// Forward to the open submenu.
+    fn handle_submenu(&mut self, event: &crossterm::event::Event) -> MenuOutcome {
+        let Some(submenu) = self.submenu.as_mut() else {
+            return MenuOutcome::Continue;
+        };
+        let r = submenu.handle(event, Popup);
+        let sub_active = submenu.is_active();
+        let sub_path = submenu.activated_path.clone();
+
+        if r.is_consumed() && matches!(event, ct_event!(mouse moved)) {
+            // mouse arrived in the submenu.
+            self.hover = None;
+        }
+
+        match r {
+            MenuOutcome::Activated(n) => {
+                let first = self.selected.expect("selected");
+                self.activated_path = vec![first, n];
+                self.set_active(false);
+                MenuOutcome::SubmenuActivated(first, n)
+            }
+            MenuOutcome::SubmenuActivated(_, n) => {
+                let first = self.selected.expect("selected");
+                self.activated_path = vec![first];
+                self.activated_path.extend(sub_path);
+                self.set_active(false);
+                MenuOutcome::SubmenuActivated(first, n)
+            }
+            MenuOutcome::Hide => {
+                // click outside the submenu. this menu decides.
+                self.close_submenu();
+                MenuOutcome::Continue
+            }
+            MenuOutcome::Continue => match event {
+                ct_event!(keycode press Left) => {
+                    self.close_submenu();
+                    MenuOutcome::Changed
+                }
+                _ => MenuOutcome::Continue,
+            },
+            r => {
+                if !sub_active {
+                    // esc closes only the submenu.
+                    self.submenu = None;
+                }
+                r
+            }
+        }
+    }
 }
 
 impl HandleEvent<crossterm::event::Event, Popup, MenuOutcome> for PopupMenuState {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Popup) -> MenuOutcome {
+        if self.is_active() {
+            flow!(self.handle_submenu(event));
+        }
+
         let r0 = match self.popup.handle(event, Popup) {
             PopupOutcome::Hide => MenuOutcome::Hide,
             r => r.into(),
@@ -656,10 +1032,21 @@ impl HandleEvent<crossterm::event::Event, Popup, MenuOutcome> for PopupMenuState
                 ct_event!(key press ANY-c) => {
                     let r = self.navigate(*c);
                     if matches!(r, MenuOutcome::Activated(_)) {
+                        if self.selected_has_submenu() {
+                            self.open_submenu();
+                            return MenuOutcome::Changed;
+                        }
                         self.set_active(false);
                     }
                     r
                 }
+                ct_event!(keycode press Right) => {
+                    if self.open_submenu() {
+                        MenuOutcome::Changed
+                    } else {
+                        MenuOutcome::Continue
+                    }
+                }
                 ct_event!(keycode press Up) => {
                     if self.prev_item() {
                         MenuOutcome::Selected(self.selected.expect("selected"))
@@ -693,7 +1080,10 @@ impl HandleEvent<crossterm::event::Event, Popup, MenuOutcome> for PopupMenuState
                     MenuOutcome::Changed
                 }
                 ct_event!(keycode press Enter) => {
-                    if let Some(select) = self.selected {
+                    if self.selected_has_submenu() {
+                        self.open_submenu();
+                        MenuOutcome::Changed
+                    } else if let Some(select) = self.selected {
                         self.set_active(false);
                         MenuOutcome::Activated(select)
                     } else {
@@ -732,18 +1122,26 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, MenuOutcome> for PopupMenuS
                 ct_event!(mouse moved for col, row)
                     if self.popup.widget_area.contains((*col, *row).into()) =>
                 {
-                    if self.select_at((*col, *row)) {
-                        MenuOutcome::Selected(self.selected().expect("selection"))
-                    } else {
-                        MenuOutcome::Unchanged
-                    }
+                    let r = self.hover_at((*col, *row));
+                    self.last_mouse = Some((*col, *row));
+                    r
                 }
                 ct_event!(mouse down Left for col, row)
                     if self.popup.widget_area.contains((*col, *row).into()) =>
                 {
-                    if self.item_at((*col, *row)).is_some() {
-                        self.set_active(false);
-                        MenuOutcome::Activated(self.selected().expect("selection"))
+                    let pos = (*col, *row);
+                    if self.item_at(pos).is_some() {
+                        if self.selected != self.item_at(pos) && self.select_at(pos) {
+                            self.close_submenu();
+                        }
+                        if self.selected_has_submenu() {
+                            self.hover = None;
+                            self.open_submenu_impl();
+                            MenuOutcome::Changed
+                        } else {
+                            self.set_active(false);
+                            MenuOutcome::Activated(self.selected().expect("selection"))
+                        }
                     } else {
                         MenuOutcome::Unchanged
                     }
@@ -0,0 +1,182 @@
+//!
+//! List of recently used files/directories for a menu.
+//!
+//! Each menu-item carries its path as payload, so the
+//! activated item can be found even if the list changed
+//! in the meantime.
+//!
+//! ```rust ignore
+//! match menu.handle(event, Popup) {
+//!     MenuOutcome::MenuActivatedPayload(main, sub) => {
+//!         if let Some(path) = menu
+//!             .payload(main, sub)
+//!             .and_then(|v| v.downcast_ref::<PathBuf>())
+//!         {
+//!             open(path);
+//!         }
+//!     }
+//!     ...
+//! }
+//! ```
+//!
+use crate::{MenuBuilder, MenuStructure};
+use std::fmt::Debug;
+use std::path::Path;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Bounded list of recent items, most recent first.
+///
+/// Can be used as a complete [MenuStructure], or call
+/// [RecentList::items] from your own [MenuStructure::submenu].
+#[derive(Debug, Clone)]
+pub struct RecentList<T> {
+    items: Vec<T>,
+    max_len: usize,
+    max_width: usize,
+    title: String,
+}
+
+impl<T> Default for RecentList<T> {
+    fn default() -> Self {
+        Self {
+            items: Default::default(),
+            max_len: 10,
+            max_width: 40,
+            title: "Recent".into(),
+        }
+    }
+}
+
+impl<T> RecentList<T>
+where
+    T: AsRef<Path> + Clone + PartialEq,
+{
+    /// New list with the given maximum length.
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            max_len,
+            ..Default::default()
+        }
+    }
+
+    /// Restore from a Vec, most recent first.
+    pub fn from_vec(max_len: usize, items: Vec<T>) -> Self {
+        let mut s = Self::new(max_len);
+        for item in items.into_iter().rev() {
+            s.add(item);
+        }
+        s
+    }
+
+    /// Copy to a Vec, most recent first.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.items.clone()
+    }
+
+    /// Maximum length.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Maximum width of the menu text. Longer
+    /// paths are shortened in the middle.
+    pub fn set_max_width(&mut self, width: usize) {
+        self.max_width = width;
+    }
+
+    /// Maximum width of the menu text.
+    pub fn max_width(&self) -> usize {
+        self.max_width
+    }
+
+    /// Title, when used as a MenuStructure.
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    /// Add an item as the most recent one.
+    /// A duplicate is moved to the front.
+    pub fn add(&mut self, item: T) {
+        self.items.retain(|v| *v != item);
+        self.items.insert(0, item);
+        self.items.truncate(self.max_len);
+    }
+
+    /// Remove an item.
+    pub fn remove(&mut self, item: &T) -> bool {
+        let len = self.items.len();
+        self.items.retain(|v| v != item);
+        len != self.items.len()
+    }
+
+    /// Remove all.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Item.
+    pub fn get(&self, n: usize) -> Option<&T> {
+        self.items.get(n)
+    }
+
+    /// Iterate all items, most recent first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+}
+
+impl<T> RecentList<T>
+where
+    T: AsRef<Path> + Clone + PartialEq + Send + Sync + 'static,
+{
+    /// Add the items to a menu. Each item carries a clone
+    /// of its value as payload.
+    pub fn items(&self, menu: &mut MenuBuilder<'_>) {
+        for item in self.items.iter() {
+            let text = item.as_ref().to_string_lossy();
+            menu.item_string(shorten_middle(&text, self.max_width))
+                .payload(item.clone());
+        }
+    }
+}
+
+impl<'a, T> MenuStructure<'a> for RecentList<T>
+where
+    T: AsRef<Path> + Clone + PartialEq + Debug + Send + Sync + 'static,
+{
+    fn menus(&'a self, menu: &mut MenuBuilder<'a>) {
+        menu.item_str(self.title.as_str());
+    }
+
+    fn submenu(&'a self, n: usize, submenu: &mut MenuBuilder<'a>) {
+        if n == 0 {
+            self.items(submenu);
+        }
+    }
+}
+
+/// Shorten the text in the middle to the given number of graphemes.
+fn shorten_middle(text: &str, width: usize) -> String {
+    let len = text.graphemes(true).count();
+    if len <= width || width == 0 {
+        return text.to_string();
+    }
+    let head = (width - 1) / 2;
+    let tail = width - 1 - head;
+
+    let mut res = String::new();
+    res.extend(text.graphemes(true).take(head));
+    res.push('…');
+    res.extend(text.graphemes(true).skip(len - tail));
+    res
+}
@@ -5,6 +5,7 @@
 use ratatui::layout::{Alignment, Rect};
 
 mod popup;
+pub mod shadow;
 
 pub use popup::*;
 
@@ -70,6 +71,10 @@ pub enum Placement {
     AboveOrBelow,
     /// Below or above dependent on available space. Aligned left.
     BelowOrAbove,
+    /// Right or left dependent on available space. Aligned top.
+    RightOrLeft,
+    /// Left or right dependent on available space. Aligned top.
+    LeftOrRight,
     /// Use the render-area for the popup, but place it at position (x,y).
     Position(u16, u16),
 }
@@ -84,6 +89,8 @@ impl Placement {
             Placement::Right => PopupConstraint::Right(alignment, rel_area),
             Placement::AboveOrBelow => PopupConstraint::AboveOrBelow(alignment, rel_area),
             Placement::BelowOrAbove => PopupConstraint::BelowOrAbove(alignment, rel_area),
+            Placement::RightOrLeft => PopupConstraint::RightOrLeft(alignment, rel_area),
+            Placement::LeftOrRight => PopupConstraint::LeftOrRight(alignment, rel_area),
             Placement::Position(x, y) => PopupConstraint::Position(x, y),
         }
     }
@@ -117,6 +124,11 @@ pub enum PopupConstraint {
     AboveOrBelow(Alignment, Rect),
     /// Below or above dependent on available space. Aligned left.
     BelowOrAbove(Alignment, Rect),
+    /// Right or left dependent on available space. Aligned top.
+    /// Used for cascading submenus.
+    RightOrLeft(Alignment, Rect),
+    /// Left or right dependent on available space. Aligned top.
+    LeftOrRight(Alignment, Rect),
     /// Use the render-area for the popup, but place it at position (x,y).
     Position(u16, u16),
 }
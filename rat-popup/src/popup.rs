@@ -1,5 +1,6 @@
 use crate::_private::NonExhaustive;
 use crate::event::PopupOutcome;
+use crate::shadow::{Shadow, ShadowStyle};
 use crate::{Placement, PopupConstraint};
 use rat_event::util::MouseFlags;
 use rat_event::{ct_event, HandleEvent, Popup};
@@ -14,7 +15,7 @@ use ratatui::style::{Style, Stylize};
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{Block, Padding, StatefulWidget};
 use std::cell::Cell;
-use std::cmp::max;
+use std::cmp::{max, min};
 
 /// Provides the core for popup widgets.
 ///
@@ -33,6 +34,15 @@ use std::cmp::max;
 /// The widget doesn't change its active/visible state by itself,
 /// it's up to the caller to do this.
 ///
+/// ## Opening/Closing
+///
+/// [PopupCoreState::open_animated] and [PopupCoreState::close_animated]
+/// go through the phases [PopupPhase::Opening] and [PopupPhase::Closing].
+/// The application advances the [progress](PopupCoreState::progress)
+/// from a timer with [PopupCoreState::advance]. Meanwhile, the popup
+/// area only gets a part of its height, and reveals the content
+/// top-down.
+///
 /// __See__
 /// See the examples some variants.
 ///
@@ -48,6 +58,9 @@ pub struct PopupCore<'a> {
     pub h_scroll: Option<Scroll<'a>>,
     pub v_scroll: Option<Scroll<'a>>,
 
+    pub shadow: bool,
+    pub shadow_style: ShadowStyle,
+
     pub non_exhaustive: NonExhaustive,
 }
 
@@ -68,11 +81,27 @@ pub struct PopupStyle {
     pub alignment: Option<Alignment>,
     /// Placement
     pub placement: Option<Placement>,
+    /// Shadow style.
+    pub shadow: Option<ShadowStyle>,
 
     /// non-exhaustive struct.
     pub non_exhaustive: NonExhaustive,
 }
 
+/// Open/close phases of the popup.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PopupPhase {
+    /// Not visible.
+    #[default]
+    Closed,
+    /// Becoming visible. Renders with the current progress.
+    Opening,
+    /// Fully visible.
+    Open,
+    /// Becoming invisible. Renders with the current progress.
+    Closing,
+}
+
 /// State for the PopupCore.
 #[derive(Debug)]
 pub struct PopupCoreState {
@@ -108,6 +137,13 @@ pub struct PopupCoreState {
     /// __read+write__
     pub active: FocusFlag,
 
+    /// Open/close phase.
+    /// __read only__. Use open_animated/close_animated/advance.
+    pub phase: PopupPhase,
+    /// Progress of opening/closing in percent.
+    /// __read only__. Use advance.
+    pub progress: u8,
+
     /// Mouse flags.
     /// __read+write__
     pub mouse: MouseFlags,
@@ -126,6 +162,8 @@ impl Default for PopupCore<'_> {
             block: None,
             h_scroll: None,
             v_scroll: None,
+            shadow: false,
+            shadow_style: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -198,6 +236,9 @@ impl<'a> PopupCore<'a> {
         if let Some(block) = styles.block {
             self.block = Some(block);
         }
+        if let Some(shadow) = styles.shadow {
+            self.shadow_style = shadow;
+        }
         if let Some(styles) = styles.scroll {
             if let Some(h_scroll) = self.h_scroll {
                 self.h_scroll = Some(h_scroll.styles(styles.clone()));
@@ -255,6 +296,18 @@ impl<'a> PopupCore<'a> {
         self
     }
 
+    /// Render a shadow.
+    pub fn shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    /// Style for the shadow.
+    pub fn shadow_style(mut self, style: ShadowStyle) -> Self {
+        self.shadow_style = style;
+        self
+    }
+
     /// Get the padding the block imposes as  Size.
     pub fn get_block_size(&self) -> Size {
         let area = Rect::new(0, 0, 20, 20);
@@ -320,8 +373,18 @@ fn render_popup(widget: &PopupCore<'_>, area: Rect, buf: &mut Buffer, state: &mu
     }
 
     state.area = widget._layout(area, widget.boundary_area.unwrap_or(buf.area));
+    if matches!(state.phase, PopupPhase::Opening | PopupPhase::Closing) {
+        // reveal top-down
+        let height = (state.area.height as u32 * state.progress as u32 / 100) as u16;
+        state.area.height = max(1, height);
+    }
 
     reset_buf_area(state.area, buf);
+    if widget.shadow {
+        Shadow::new()
+            .styles(widget.shadow_style.clone())
+            .render(state.area, buf, &mut ());
+    }
     if widget.block.is_none() {
         buf.set_style(state.area, fallback_popup_style(widget.style))
     }
@@ -373,6 +436,13 @@ impl PopupCore<'_> {
             within.saturating_sub(len)
         }
         let bottom = right;
+        fn align(alignment: Alignment, len: u16, within: u16) -> u16 {
+            match alignment {
+                Alignment::Left => 0,
+                Alignment::Center => center(len, within),
+                Alignment::Right => right(len, within),
+            }
+        }
 
         // offsets may change
         let mut offset = self.offset;
@@ -460,118 +530,54 @@ impl PopupCore<'_> {
                 area.height,
             ),
 
-            PopupConstraint::AboveOrBelow(Alignment::Left, rel) => {
-                if area.height.saturating_add_signed(-self.offset.1) < rel.y {
-                    Rect::new(
-                        rel.x,
-                        rel.y.saturating_sub(area.height),
-                        area.width,
-                        area.height,
-                    )
-                } else {
+            PopupConstraint::AboveOrBelow(alignment, rel) => {
+                let (above, height) =
+                    flip_vertical(true, rel, area.height, offset.1, boundary_area);
+                if !above {
                     offset = (offset.0, -offset.1);
-                    Rect::new(
-                        rel.x, //
-                        rel.bottom(),
-                        area.width,
-                        area.height,
-                    )
                 }
-            }
-            PopupConstraint::AboveOrBelow(Alignment::Center, rel) => {
-                if area.height.saturating_add_signed(-self.offset.1) < rel.y {
-                    Rect::new(
-                        rel.x + center(area.width, rel.width),
-                        rel.y.saturating_sub(area.height),
-                        area.width,
-                        area.height,
-                    )
+                let x = rel.x + align(alignment, area.width, rel.width);
+                if above {
+                    Rect::new(x, rel.y.saturating_sub(height), area.width, height)
                 } else {
-                    offset = (offset.0, -offset.1);
-                    Rect::new(
-                        rel.x + center(area.width, rel.width), //
-                        rel.bottom(),
-                        area.width,
-                        area.height,
-                    )
+                    Rect::new(x, rel.bottom(), area.width, height)
                 }
             }
-            PopupConstraint::AboveOrBelow(Alignment::Right, rel) => {
-                if area.height.saturating_add_signed(-self.offset.1) < rel.y {
-                    Rect::new(
-                        rel.x + right(area.width, rel.width),
-                        rel.y.saturating_sub(area.height),
-                        area.width,
-                        area.height,
-                    )
-                } else {
+            PopupConstraint::BelowOrAbove(alignment, rel) => {
+                let (above, height) =
+                    flip_vertical(false, rel, area.height, offset.1, boundary_area);
+                if above {
                     offset = (offset.0, -offset.1);
-                    Rect::new(
-                        rel.x + right(area.width, rel.width), //
-                        rel.bottom(),
-                        area.width,
-                        area.height,
-                    )
                 }
-            }
-            PopupConstraint::BelowOrAbove(Alignment::Left, rel) => {
-                if (rel.bottom() + area.height).saturating_add_signed(self.offset.1)
-                    <= boundary_area.height
-                {
-                    Rect::new(
-                        rel.x, //
-                        rel.bottom(),
-                        area.width,
-                        area.height,
-                    )
+                let x = rel.x + align(alignment, area.width, rel.width);
+                if above {
+                    Rect::new(x, rel.y.saturating_sub(height), area.width, height)
                 } else {
-                    offset = (offset.0, -offset.1);
-                    Rect::new(
-                        rel.x,
-                        rel.y.saturating_sub(area.height),
-                        area.width,
-                        area.height,
-                    )
+                    Rect::new(x, rel.bottom(), area.width, height)
                 }
             }
-            PopupConstraint::BelowOrAbove(Alignment::Center, rel) => {
-                if (rel.bottom() + area.height).saturating_add_signed(self.offset.1)
-                    <= boundary_area.height
-                {
-                    Rect::new(
-                        rel.x + center(area.width, rel.width), //
-                        rel.bottom(),
-                        area.width,
-                        area.height,
-                    )
+            PopupConstraint::RightOrLeft(alignment, rel) => {
+                let right = flip_horizontal(true, rel, area.width, offset.0, boundary_area);
+                if !right {
+                    offset = (-offset.0, offset.1);
+                }
+                let y = rel.y + align(alignment, area.height, rel.height);
+                if right {
+                    Rect::new(rel.right(), y, area.width, area.height)
                 } else {
-                    offset = (offset.0, -offset.1);
-                    Rect::new(
-                        rel.x + center(area.width, rel.width),
-                        rel.y.saturating_sub(area.height),
-                        area.width,
-                        area.height,
-                    )
+                    Rect::new(rel.x.saturating_sub(area.width), y, area.width, area.height)
                 }
             }
-            PopupConstraint::BelowOrAbove(Alignment::Right, rel) => {
-                if (rel.bottom() + area.height).saturating_add_signed(self.offset.1)
-                    <= boundary_area.height
-                {
-                    Rect::new(
-                        rel.x + right(area.width, rel.width), //
-                        rel.bottom(),
-                        area.width,
-                        area.height,
-                    )
+            PopupConstraint::LeftOrRight(alignment, rel) => {
+                let right = flip_horizontal(false, rel, area.width, offset.0, boundary_area);
+                if right {
+                    offset = (-offset.0, offset.1);
+                }
+                let y = rel.y + align(alignment, area.height, rel.height);
+                if right {
+                    Rect::new(rel.right(), y, area.width, area.height)
                 } else {
-                    offset = (offset.0, -offset.1);
-                    Rect::new(
-                        rel.x + right(area.width, rel.width),
-                        rel.y.saturating_sub(area.height),
-                        area.width,
-                        area.height,
-                    )
+                    Rect::new(rel.x.saturating_sub(area.width), y, area.width, area.height)
                 }
             }
         };
@@ -610,6 +616,93 @@ impl PopupCore<'_> {
     }
 }
 
+/// Choose above or below for the flipping constraints.
+///
+/// Uses the preferred side if the popup fits, otherwise the other side.
+/// If it fits on neither side, it uses the side with more space and
+/// shrinks the popup to fit. The widget is expected to scroll then.
+///
+/// The offset is given for the preferred side and mirrored for the
+/// other side.
+///
+/// Returns (above, height).
+fn flip_vertical(
+    prefer_above: bool,
+    rel: Rect,
+    height: u16,
+    offset: i16,
+    boundary_area: Rect,
+) -> (bool, u16) {
+    let (above_dy, below_dy) = if prefer_above {
+        (offset as i32, -(offset as i32))
+    } else {
+        (-(offset as i32), offset as i32)
+    };
+    let space_above = rel.y as i32 - boundary_area.top() as i32 + above_dy;
+    let space_below = boundary_area.bottom() as i32 - rel.bottom() as i32 - below_dy;
+
+    let (preferred, other) = if prefer_above {
+        (space_above, space_below)
+    } else {
+        (space_below, space_above)
+    };
+
+    // no space at all, leave it to keep-in-sight.
+    if preferred <= 0 && other <= 0 {
+        return (prefer_above, height);
+    }
+
+    let above = if height as i32 <= preferred {
+        prefer_above
+    } else if height as i32 <= other {
+        !prefer_above
+    } else if preferred >= other {
+        prefer_above
+    } else {
+        !prefer_above
+    };
+    let space = if above { space_above } else { space_below };
+
+    (above, min(height as i32, max(space, 0)) as u16)
+}
+
+/// Choose right or left for the flipping constraints.
+///
+/// Uses the preferred side if the popup fits, otherwise the side
+/// with more space. The offset is given for the preferred side and
+/// mirrored for the other side.
+///
+/// Returns right.
+fn flip_horizontal(
+    prefer_right: bool,
+    rel: Rect,
+    width: u16,
+    offset: i16,
+    boundary_area: Rect,
+) -> bool {
+    let (right_dx, left_dx) = if prefer_right {
+        (offset as i32, -(offset as i32))
+    } else {
+        (-(offset as i32), offset as i32)
+    };
+    let space_right = boundary_area.right() as i32 - rel.right() as i32 - right_dx;
+    let space_left = rel.x as i32 - boundary_area.left() as i32 + left_dx;
+
+    let (preferred, other) = if prefer_right {
+        (space_right, space_left)
+    } else {
+        (space_left, space_right)
+    };
+
+    if width as i32 <= preferred {
+        prefer_right
+    } else if width as i32 <= other || other > preferred {
+        !prefer_right
+    } else {
+        prefer_right
+    }
+}
+
 impl Default for PopupStyle {
     fn default() -> Self {
         Self {
@@ -620,6 +713,7 @@ impl Default for PopupStyle {
             scroll: None,
             alignment: None,
             placement: None,
+            shadow: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -634,6 +728,8 @@ impl Clone for PopupCoreState {
             h_scroll: self.h_scroll.clone(),
             v_scroll: self.v_scroll.clone(),
             active: FocusFlag::named(self.active.name()),
+            phase: self.phase,
+            progress: self.progress,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -649,6 +745,8 @@ impl Default for PopupCoreState {
             h_scroll: Default::default(),
             v_scroll: Default::default(),
             active: FocusFlag::named("popup"),
+            phase: Default::default(),
+            progress: 0,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -659,6 +757,8 @@ impl RelocatableState for PopupCoreState {
     fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
         self.area = relocate_area(self.area, shift, clip);
         self.widget_area = relocate_area(self.widget_area, shift, clip);
+        self.v_scroll.relocate(shift, clip);
+        self.h_scroll.relocate(shift, clip);
     }
 }
 
@@ -700,7 +800,16 @@ impl PopupCoreState {
     /// Show the popup.
     /// This will set gained/lost flags according to the change.
     /// If the popup is hidden this will clear all flags.
+    ///
+    /// This skips any opening/closing phase.
     pub fn set_active(&mut self, active: bool) -> bool {
+        if active {
+            self.phase = PopupPhase::Open;
+            self.progress = 100;
+        } else {
+            self.phase = PopupPhase::Closed;
+            self.progress = 0;
+        }
         let old_value = self.is_active();
         if active {
             if !self.is_active() {
@@ -724,6 +833,64 @@ impl PopupCoreState {
         old_value != self.is_active()
     }
 
+    /// Start opening the popup. The popup is active from now on,
+    /// and renders with the current progress until it is fully open.
+    pub fn open_animated(&mut self) -> bool {
+        if !self.is_active() {
+            self.set_active(true);
+            self.phase = PopupPhase::Opening;
+            self.progress = 0;
+            true
+        } else if self.phase == PopupPhase::Closing {
+            self.phase = PopupPhase::Opening;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Start closing the popup. The popup stays active until
+    /// the closing phase is done, and still covers its area.
+    pub fn close_animated(&mut self) -> bool {
+        if self.is_active() && self.phase != PopupPhase::Closing {
+            self.phase = PopupPhase::Closing;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Popup is opening or closing.
+    pub fn is_animating(&self) -> bool {
+        matches!(self.phase, PopupPhase::Opening | PopupPhase::Closing)
+    }
+
+    /// Advance the opening/closing progress by n percent.
+    ///
+    /// Switches to [PopupPhase::Open] when fully open, and
+    /// deactivates the popup when fully closed.
+    ///
+    /// Returns true if anything changed.
+    pub fn advance(&mut self, n: u8) -> bool {
+        match self.phase {
+            PopupPhase::Opening => {
+                self.progress = self.progress.saturating_add(n).min(100);
+                if self.progress == 100 {
+                    self.phase = PopupPhase::Open;
+                }
+                true
+            }
+            PopupPhase::Closing => {
+                self.progress = self.progress.saturating_sub(n);
+                if self.progress == 0 {
+                    self.set_active(false);
+                }
+                true
+            }
+            PopupPhase::Open | PopupPhase::Closed => false,
+        }
+    }
+
     /// Clear the areas.
     pub fn clear_areas(&mut self) {
         self.area = Default::default();
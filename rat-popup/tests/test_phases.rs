@@ -0,0 +1,88 @@
+use rat_popup::shadow::ShadowStyle;
+use rat_popup::{PopupCore, PopupCoreState, PopupPhase};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut PopupCoreState, buf: &mut Buffer) {
+    PopupCore::new()
+        .shadow(true)
+        .shadow_style(ShadowStyle {
+            style: Style::new().bg(Color::Black),
+            ..Default::default()
+        })
+        .render(Rect::new(2, 2, 10, 10), buf, state);
+}
+
+#[test]
+fn test_opening() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let mut state = PopupCoreState::new();
+
+    assert!(state.open_animated());
+    assert!(state.is_active());
+    assert_eq!(state.phase, PopupPhase::Opening);
+    assert_eq!(state.progress, 0);
+
+    // at least one row.
+    render(&mut state, &mut buf);
+    assert_eq!(state.area, Rect::new(2, 2, 10, 1));
+
+    assert!(state.advance(50));
+    render(&mut state, &mut buf);
+    assert_eq!(state.area, Rect::new(2, 2, 10, 5));
+
+    assert!(state.advance(70));
+    assert_eq!(state.phase, PopupPhase::Open);
+    assert_eq!(state.progress, 100);
+    assert!(!state.advance(10));
+    render(&mut state, &mut buf);
+    assert_eq!(state.area, Rect::new(2, 2, 10, 10));
+}
+
+#[test]
+fn test_closing() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let mut state = PopupCoreState::new();
+    state.set_active(true);
+    assert_eq!(state.phase, PopupPhase::Open);
+
+    assert!(state.close_animated());
+    assert!(!state.close_animated());
+    assert!(state.is_active());
+
+    // still covers the upper part while closing.
+    state.advance(70);
+    render(&mut state, &mut buf);
+    assert_eq!(state.area, Rect::new(2, 2, 10, 3));
+
+    state.advance(70);
+    assert_eq!(state.phase, PopupPhase::Closed);
+    assert!(!state.is_active());
+    assert!(state.active.lost());
+    render(&mut state, &mut buf);
+    assert_eq!(state.area, Rect::default());
+}
+
+#[test]
+fn test_shadow() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let mut state = PopupCoreState::new();
+    state.set_active(true);
+    render(&mut state, &mut buf);
+
+    assert_eq!(buf[(12, 3)].bg, Color::Black);
+    assert_eq!(buf[(3, 12)].bg, Color::Black);
+    assert_eq!(buf[(12, 2)].bg, Color::Reset);
+    assert_eq!(buf[(2, 12)].bg, Color::Reset);
+
+    // the shadow follows a partially open popup.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    state.set_active(false);
+    state.open_animated();
+    state.advance(50);
+    render(&mut state, &mut buf);
+    assert_eq!(buf[(3, 7)].bg, Color::Black);
+    assert_eq!(buf[(3, 12)].bg, Color::Reset);
+}
@@ -0,0 +1,144 @@
+use rat_popup::{PopupConstraint, PopupCore};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+
+fn layout(constraint: PopupConstraint, height: u16, boundary: Rect) -> Rect {
+    let buf = Buffer::empty(boundary);
+    PopupCore::new()
+        .constraint(constraint)
+        .layout(Rect::new(0, 0, 10, height), &buf)
+}
+
+#[test]
+fn test_below_or_above() {
+    let boundary = Rect::new(0, 0, 40, 20);
+
+    // enough space below
+    let rel = Rect::new(5, 2, 10, 1);
+    let area = layout(
+        PopupConstraint::BelowOrAbove(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 3, 10, 5));
+
+    // flip above
+    let rel = Rect::new(5, 18, 10, 1);
+    let area = layout(
+        PopupConstraint::BelowOrAbove(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 13, 10, 5));
+
+    // exactly fits below
+    let rel = Rect::new(5, 14, 10, 1);
+    let area = layout(
+        PopupConstraint::BelowOrAbove(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 15, 10, 5));
+}
+
+#[test]
+fn test_above_or_below() {
+    let boundary = Rect::new(0, 0, 40, 20);
+
+    let rel = Rect::new(5, 10, 10, 1);
+    let area = layout(
+        PopupConstraint::AboveOrBelow(Alignment::Right, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 5, 10, 5));
+
+    // flip below
+    let rel = Rect::new(5, 2, 10, 1);
+    let area = layout(
+        PopupConstraint::AboveOrBelow(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 3, 10, 5));
+}
+
+#[test]
+fn test_boundary_offset() {
+    // boundary doesn't start at 0
+    let boundary = Rect::new(0, 10, 40, 10);
+
+    let rel = Rect::new(5, 12, 10, 1);
+    let area = layout(
+        PopupConstraint::AboveOrBelow(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 13, 10, 5));
+
+    let rel = Rect::new(5, 17, 10, 1);
+    let area = layout(
+        PopupConstraint::BelowOrAbove(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 12, 10, 5));
+}
+
+#[test]
+fn test_shrink() {
+    let boundary = Rect::new(0, 0, 40, 10);
+
+    // more space above
+    let rel = Rect::new(5, 6, 10, 1);
+    let area = layout(
+        PopupConstraint::BelowOrAbove(Alignment::Left, rel),
+        8,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 0, 10, 6));
+
+    // more space below
+    let rel = Rect::new(5, 3, 10, 1);
+    let area = layout(
+        PopupConstraint::AboveOrBelow(Alignment::Left, rel),
+        8,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(5, 4, 10, 6));
+
+    // never covers the widget
+    assert!(area.intersection(rel).is_empty());
+}
+
+#[test]
+fn test_right_or_left() {
+    let boundary = Rect::new(0, 0, 40, 20);
+
+    // enough space right
+    let rel = Rect::new(5, 2, 10, 1);
+    let area = layout(
+        PopupConstraint::RightOrLeft(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(15, 2, 10, 5));
+
+    // flip left
+    let rel = Rect::new(20, 2, 15, 1);
+    let area = layout(
+        PopupConstraint::RightOrLeft(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(10, 2, 10, 5));
+
+    // prefer left, but no space
+    let rel = Rect::new(5, 2, 10, 1);
+    let area = layout(
+        PopupConstraint::LeftOrRight(Alignment::Left, rel),
+        5,
+        boundary,
+    );
+    assert_eq!(area, Rect::new(15, 2, 10, 5));
+}
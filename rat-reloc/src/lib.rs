@@ -11,9 +11,66 @@ use ratatui::layout::{Position, Rect};
 ///
 /// Container widgets that support this will call relocate()
 /// after rendering the widgets.
+///
+/// ## Nested containers
+///
+/// If containers are nested, each of them adds another shift and
+/// clip. These can be collected and applied at once with
+/// [relocate_with](RelocatableState::relocate_with).
+///
+/// ## Popups
+///
+/// Popups are not part of the area of the container, they
+/// are only moved along with it. Widgets with a popup relocate
+/// the popup areas in [relocate_popup](RelocatableState::relocate_popup)
+/// instead of relocate(). relocate_popup() gets the
+/// combined shift of all containers, but only the outermost clip.
+///
+/// The main widget and the popup are rendered separately, so
+/// containers relocate them separately too. Only the popup
+/// areas that have been rendered into the container buffer
+/// must be relocated with relocate_popup().
 pub trait RelocatableState {
     /// Relocate the areas in this widgets state.
     fn relocate(&mut self, shift: (i16, i16), clip: Rect);
+
+    /// Relocate the areas of a popup.
+    ///
+    /// The default does nothing.
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        _ = (shift, clip);
+    }
+
+    /// Clear the areas of a widget that is not visible.
+    fn relocate_hidden(&mut self) {
+        self.relocate((0, 0), Rect::default());
+    }
+
+    /// Clear the popup areas of a widget that is not visible.
+    ///
+    /// A popup that is still active after its widget has been
+    /// scrolled out of view must not catch any mouse events.
+    fn relocate_popup_hidden(&mut self) {
+        self.relocate_popup((0, 0), Rect::default());
+    }
+
+    /// Apply a stack of relocations at once.
+    ///
+    /// The transforms are ordered innermost container first,
+    /// outermost last.
+    ///
+    /// The regular areas are clipped by each of the containers.
+    /// The popup areas are only clipped by the outermost container.
+    /// Add the screen area as last transform with a (0,0) shift
+    /// to clip the popups only to the screen.
+    fn relocate_with(&mut self, transforms: &[((i16, i16), Rect)]) {
+        let Some(outer) = transforms.last() else {
+            return;
+        };
+        let (shift, clip) = compose_relocations(transforms);
+        self.relocate(shift, clip);
+        self.relocate_popup(shift, outer.1);
+    }
 }
 
 /// Create the implementation of RelocatableState for the
@@ -25,6 +82,10 @@ macro_rules! impl_relocatable_state {
             fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
                 $(self.$n.relocate(shift, clip);)*
             }
+
+            fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+                $(self.$n.relocate_popup(shift, clip);)*
+            }
         }
     };
 }
@@ -43,6 +104,28 @@ impl RelocatableState for [Rect] {
     }
 }
 
+/// Combine a stack of relocations into one shift and clip.
+///
+/// The transforms are ordered innermost container first,
+/// outermost last. Each clip is given in the coordinates of
+/// the next outer container. The combined clip is the
+/// intersection of all clip areas after shifting them to
+/// the outermost coordinates.
+pub fn compose_relocations(transforms: &[((i16, i16), Rect)]) -> ((i16, i16), Rect) {
+    let mut iter = transforms.iter();
+    let Some((mut shift, mut clip)) = iter.next().copied() else {
+        return ((0, 0), Rect::default());
+    };
+    for (next_shift, next_clip) in iter {
+        shift = (
+            shift.0.saturating_add(next_shift.0),
+            shift.1.saturating_add(next_shift.1),
+        );
+        clip = clipped(relocate(clip, *next_shift), *next_clip);
+    }
+    (shift, clip)
+}
+
 /// Shift the area by offset and clip it.
 pub fn relocate_areas(area: &mut [Rect], shift: (i16, i16), clip: Rect) {
     for a in area {
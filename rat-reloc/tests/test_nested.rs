@@ -0,0 +1,96 @@
+use rat_reloc::{compose_relocations, relocate_area, RelocatableState};
+use ratatui::layout::Rect;
+
+/// Widget with a popup.
+#[derive(Debug, Default)]
+struct PopupWidget {
+    area: Rect,
+    popup: Rect,
+}
+
+impl RelocatableState for PopupWidget {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+    }
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        self.popup = relocate_area(self.popup, shift, clip);
+    }
+}
+
+#[test]
+fn test_compose() {
+    assert_eq!(compose_relocations(&[]), ((0, 0), Rect::default()));
+
+    let inner = ((5, 3), Rect::new(5, 3, 10, 5));
+    let outer = ((2, 10), Rect::new(2, 10, 20, 8));
+    let (shift, clip) = compose_relocations(&[inner, outer]);
+    assert_eq!(shift, (7, 13));
+    assert_eq!(clip, Rect::new(7, 13, 10, 5));
+
+    // inner view scrolled, partially outside the outer view.
+    let inner = ((-4, -2), Rect::new(16, 3, 10, 5));
+    let (shift, clip) = compose_relocations(&[inner, outer]);
+    assert_eq!(shift, (-2, 8));
+    assert_eq!(clip, Rect::new(18, 13, 4, 5));
+}
+
+#[test]
+fn test_nested_popup() {
+    // inner view: buffer coordinates are shifted by (5,3)
+    // and clipped to its widget area in outer buffer coordinates.
+    let inner = ((5, 3), Rect::new(5, 3, 10, 5));
+    // outer view on the screen.
+    let outer = ((2, 10), Rect::new(2, 10, 20, 8));
+    // the screen.
+    let screen = ((0, 0), Rect::new(0, 0, 80, 24));
+
+    let mut w = PopupWidget {
+        area: Rect::new(0, 3, 8, 1),
+        // popup below the widget, extends beyond the inner view.
+        popup: Rect::new(0, 4, 8, 10),
+    };
+    w.relocate_with(&[inner, outer, screen]);
+
+    // widget is clipped by both views.
+    assert_eq!(w.area, Rect::new(7, 16, 8, 1));
+    // popup is shifted by both views, but only clipped by the screen.
+    assert_eq!(w.popup, Rect::new(7, 17, 8, 7));
+}
+
+#[test]
+fn test_nested_order() {
+    // applying the transforms one by one clips the popup
+    // to the inner view.
+    let inner = ((5, 3), Rect::new(5, 3, 10, 5));
+    let outer = ((2, 10), Rect::new(2, 10, 20, 8));
+
+    let mut w = PopupWidget {
+        area: Rect::new(0, 0, 8, 1),
+        popup: Rect::new(0, 1, 8, 10),
+    };
+    w.relocate_with(&[inner]);
+    assert_eq!(w.popup, Rect::new(5, 4, 8, 4));
+
+    let mut w = PopupWidget {
+        area: Rect::new(0, 0, 8, 1),
+        popup: Rect::new(0, 1, 8, 10),
+    };
+    w.relocate_with(&[inner, outer]);
+    assert_eq!(w.area, Rect::new(7, 13, 8, 1));
+    assert_eq!(w.popup, Rect::new(7, 14, 8, 4));
+}
+
+#[test]
+fn test_hidden() {
+    let mut w = PopupWidget {
+        area: Rect::new(3, 3, 8, 1),
+        popup: Rect::new(3, 4, 8, 10),
+    };
+    w.relocate_hidden();
+    assert_eq!(w.area, Rect::default());
+    assert_eq!(w.popup, Rect::new(3, 4, 8, 10));
+
+    w.relocate_popup_hidden();
+    assert_eq!(w.popup, Rect::default());
+}
@@ -60,7 +60,7 @@ pub mod global {
     use crate::event::LifeEvent;
     use crate::game::LifeGameState;
     use rat_salsa::Control;
-    use rat_salsa::PollEvents;
+    use rat_salsa::{PollError, PollEvents};
     use rat_theme::dark_theme::DarkTheme;
     use std::any::Any;
     use std::cell::RefCell;
@@ -123,7 +123,7 @@ pub mod global {
             self
         }
 
-        fn poll(&mut self) -> Result<bool, Error> {
+        fn poll(&mut self) -> Result<bool, PollError<Error>> {
             Ok(self.next <= SystemTime::now())
         }
 
@@ -0,0 +1,109 @@
+//!
+//! Status line segment with the timings of the event-loop.
+//!
+//! Needs the feature `debug-status`.
+//!
+//! ```rust ignore
+//! let debug = DebugStatus::new(ctx.stats)
+//!     .style(ctx.g.theme.status_base())
+//!     .label_style(ctx.g.theme.statusline_style()[1]);
+//! let layout = Layout::horizontal([
+//!     Constraint::Fill(1),
+//!     Constraint::Length(debug.width()),
+//! ])
+//! .split(area);
+//! status.render(layout[0], buf, &mut state.status);
+//! debug.render(layout[1], buf);
+//! ```
+//!
+
+use crate::FrameStats;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+/// Renders the [FrameStats] as
+///
+/// `#count R render ~average E event Q queue T tasks`
+///
+/// right aligned.
+///
+/// * count: frame count.
+/// * render: render time of the last frame and the average
+///   over the last 16 frames.
+/// * event: time for handling the last application event.
+/// * queue: results waiting in the control-queue.
+/// * tasks: running background tasks.
+#[derive(Debug, Default, Clone)]
+pub struct DebugStatus {
+    stats: FrameStats,
+    style: Style,
+    label_style: Option<Style>,
+}
+
+impl DebugStatus {
+    /// New widget.
+    ///
+    /// Use [RenderContext::stats](crate::RenderContext::stats).
+    pub fn new(stats: FrameStats) -> Self {
+        Self {
+            stats,
+            ..Default::default()
+        }
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Style for the labels.
+    pub fn label_style(mut self, style: impl Into<Style>) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Width needed to render everything.
+    pub fn width(&self) -> u16 {
+        self.line().width() as u16
+    }
+
+    fn line(&self) -> Line<'static> {
+        let label_style = self.label_style.unwrap_or(self.style);
+        let label = |s: &'static str| Span::styled(s, label_style);
+        let value = |s: String| Span::styled(s, self.style);
+
+        Line::from(vec![
+            label("#"),
+            value(format!("{} ", self.stats.count)),
+            label("R"),
+            value(format!(
+                " {:.1?} ~{:.1?} ",
+                self.stats.render, self.stats.render_avg
+            )),
+            label("E"),
+            value(format!(" {:.1?} ", self.stats.event)),
+            label("Q"),
+            value(format!(" {} ", self.stats.queue_len)),
+            label("T"),
+            value(format!(" {}", self.stats.tasks)),
+        ])
+        .alignment(Alignment::Right)
+    }
+}
+
+impl Widget for DebugStatus {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &DebugStatus {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.line().render(area, buf);
+    }
+}
@@ -0,0 +1,207 @@
+//!
+//! Dispatches events to handlers that registered for them.
+//!
+
+use crate::Control;
+use rat_widget::event::ConsumedEvent;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+type Matcher<Input> = Rc<dyn Fn(&Input) -> bool>;
+type Handler<State, Input, Event> = Rc<dyn Fn(&mut State, &Input) -> Control<Event>>;
+
+struct Entry<State, Input, Event> {
+    matcher: Matcher<Input>,
+    handler: Handler<State, Input, Event>,
+    broadcast: bool,
+}
+
+impl<State, Input, Event> Clone for Entry<State, Input, Event> {
+    fn clone(&self) -> Self {
+        Self {
+            matcher: self.matcher.clone(),
+            handler: self.handler.clone(),
+            broadcast: self.broadcast,
+        }
+    }
+}
+
+/// Dispatches events to handlers that registered for them.
+///
+/// Instead of matching the full event-type in every module,
+/// each module registers a matcher for the events it's interested
+/// in. Then dispatch() calls
+///
+/// * the handlers whose matcher accepts the event, in registration
+///   order. It stops as soon as one handler consumes the event.
+///   Handlers registered with [broadcast](EventDispatcher::broadcast)
+///   don't stop the dispatch, all their results are combined.
+/// * the fallback handlers in registration order, if nothing
+///   consumed the event.
+///
+/// ```rust ignore
+/// // init
+/// state.dispatcher.on(
+///     |e| matches!(e, AppEvent::FileSaved(_)),
+///     |s: &mut Minimal, e| s.files.saved(e),
+/// );
+/// state.dispatcher.broadcast(
+///     |e| matches!(e, AppEvent::Event(_)),
+///     |s: &mut Minimal, e| s.status.on_event(e),
+/// );
+/// state.dispatcher.log_unhandled();
+///
+/// // event
+/// let dispatcher = state.dispatcher.clone();
+/// flow!(dispatcher.dispatch(state, event));
+/// ```
+///
+/// The dispatcher is cheap to clone. This is necessary if you
+/// store it as part of the state it is dispatching for.
+pub struct EventDispatcher<State, Input, Event> {
+    entries: Vec<Entry<State, Input, Event>>,
+    fallbacks: Vec<Handler<State, Input, Event>>,
+}
+
+impl<State, Input, Event> Clone for EventDispatcher<State, Input, Event> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            fallbacks: self.fallbacks.clone(),
+        }
+    }
+}
+
+impl<State, Input, Event> Default for EventDispatcher<State, Input, Event> {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+            fallbacks: Default::default(),
+        }
+    }
+}
+
+impl<State, Input, Event> Debug for EventDispatcher<State, Input, Event> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventDispatcher")
+            .field("handlers", &self.entries.len())
+            .field(
+                "broadcast",
+                &self.entries.iter().filter(|v| v.broadcast).count(),
+            )
+            .field("fallbacks", &self.fallbacks.len())
+            .finish()
+    }
+}
+
+impl<State, Input, Event> EventDispatcher<State, Input, Event> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a handler for all events accepted by the matcher.
+    ///
+    /// Dispatch stops if the handler consumes the event.
+    pub fn on(
+        &mut self,
+        matcher: impl Fn(&Input) -> bool + 'static,
+        handler: impl Fn(&mut State, &Input) -> Control<Event> + 'static,
+    ) -> &mut Self {
+        self.entries.push(Entry {
+            matcher: Rc::new(matcher),
+            handler: Rc::new(handler),
+            broadcast: false,
+        });
+        self
+    }
+
+    /// Add a handler for all events accepted by the matcher.
+    ///
+    /// Dispatch continues after this handler, even if it
+    /// consumes the event.
+    pub fn broadcast(
+        &mut self,
+        matcher: impl Fn(&Input) -> bool + 'static,
+        handler: impl Fn(&mut State, &Input) -> Control<Event> + 'static,
+    ) -> &mut Self {
+        self.entries.push(Entry {
+            matcher: Rc::new(matcher),
+            handler: Rc::new(handler),
+            broadcast: true,
+        });
+        self
+    }
+
+    /// Add a handler that is called if nothing else
+    /// consumed the event.
+    pub fn fallback(
+        &mut self,
+        handler: impl Fn(&mut State, &Input) -> Control<Event> + 'static,
+    ) -> &mut Self {
+        self.fallbacks.push(Rc::new(handler));
+        self
+    }
+
+    /// Remove all handlers.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.fallbacks.clear();
+    }
+
+    /// Dispatch the event.
+    ///
+    /// Returns the result of the handler that consumed the event,
+    /// combined with the results of all broadcast handlers.
+    /// Or Control::Continue if nothing consumed the event.
+    pub fn dispatch(&self, state: &mut State, event: &Input) -> Control<Event> {
+        let mut r = Control::Continue;
+        for entry in &self.entries {
+            if (entry.matcher)(event) {
+                let rr = (entry.handler)(state, event);
+                let stop = rr.is_consumed() && !entry.broadcast;
+                r = combine(r, rr);
+                if stop {
+                    return r;
+                }
+            }
+        }
+        if r.is_consumed() {
+            return r;
+        }
+        for handler in &self.fallbacks {
+            let r = handler(state, event);
+            if r.is_consumed() {
+                return r;
+            }
+        }
+        Control::Continue
+    }
+}
+
+impl<State, Input, Event> EventDispatcher<State, Input, Event>
+where
+    Input: Debug,
+{
+    /// Add a fallback that logs all unhandled events.
+    ///
+    /// This only logs in debug builds, and it never consumes
+    /// the event.
+    pub fn log_unhandled(&mut self) -> &mut Self {
+        self.fallback(|_, _event| {
+            #[cfg(debug_assertions)]
+            log::debug!("unhandled event {:?}", _event);
+            Control::Continue
+        })
+    }
+}
+
+// Keep all consumed results.
+fn combine<Event>(r: Control<Event>, rr: Control<Event>) -> Control<Event> {
+    if !r.is_consumed() {
+        rr
+    } else if !rr.is_consumed() {
+        r
+    } else {
+        r.and(rr)
+    }
+}
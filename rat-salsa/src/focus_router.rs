@@ -0,0 +1,147 @@
+//!
+//! Routes events to the event-handler of the focused widget.
+//!
+
+use crate::Control;
+use rat_widget::event::ConsumedEvent;
+use rat_widget::focus::FocusFlag;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+type Handler<State, Input, Event> = Rc<dyn Fn(&mut State, &Input) -> Control<Event>>;
+
+/// Routes events to the event-handler of the focused widget.
+///
+/// Instead of calling every event-handler, register them once
+/// with the FocusFlag of the widget. Then route() calls
+///
+/// * all popup handlers in registration order. Popups must see
+///   the events first, as they are drawn above everything else.
+/// * the handlers whose FocusFlag is set, in registration order.
+///   This includes container flags.
+/// * all fallback handlers in registration order.
+///
+/// It stops as soon as one handler consumes the event.
+///
+/// ```rust ignore
+/// // init
+/// state.router.popup(|s: &mut Minimal, e| s.menu.handle(e, Popup).into());
+/// state.router.widget(&state.name.focus, |s: &mut Minimal, e| s.name.handle(e, Regular).into());
+/// state.router.widget(&state.table.focus, |s: &mut Minimal, e| s.table.handle(e, Regular).into());
+/// state.router.fallback(|s: &mut Minimal, e| s.status.handle(e, MouseOnly).into());
+///
+/// // event
+/// let router = state.router.clone();
+/// flow!(router.route(state, event));
+/// ```
+///
+/// The router is cheap to clone. This is necessary if you
+/// store it as part of the state it is routing for.
+pub struct FocusRouter<State, Input, Event> {
+    popups: Vec<Handler<State, Input, Event>>,
+    widgets: Vec<(FocusFlag, Handler<State, Input, Event>)>,
+    fallbacks: Vec<Handler<State, Input, Event>>,
+}
+
+impl<State, Input, Event> Clone for FocusRouter<State, Input, Event> {
+    fn clone(&self) -> Self {
+        Self {
+            popups: self.popups.clone(),
+            widgets: self.widgets.clone(),
+            fallbacks: self.fallbacks.clone(),
+        }
+    }
+}
+
+impl<State, Input, Event> Default for FocusRouter<State, Input, Event> {
+    fn default() -> Self {
+        Self {
+            popups: Default::default(),
+            widgets: Default::default(),
+            fallbacks: Default::default(),
+        }
+    }
+}
+
+impl<State, Input, Event> Debug for FocusRouter<State, Input, Event> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FocusRouter")
+            .field("popups", &self.popups.len())
+            .field(
+                "widgets",
+                &self.widgets.iter().map(|(v, _)| v).collect::<Vec<_>>(),
+            )
+            .field("fallbacks", &self.fallbacks.len())
+            .finish()
+    }
+}
+
+impl<State, Input, Event> FocusRouter<State, Input, Event> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a handler that is always called first.
+    pub fn popup(
+        &mut self,
+        handler: impl Fn(&mut State, &Input) -> Control<Event> + 'static,
+    ) -> &mut Self {
+        self.popups.push(Rc::new(handler));
+        self
+    }
+
+    /// Add a handler that is called if the flag is focused.
+    pub fn widget(
+        &mut self,
+        focus: &FocusFlag,
+        handler: impl Fn(&mut State, &Input) -> Control<Event> + 'static,
+    ) -> &mut Self {
+        self.widgets.push((focus.clone(), Rc::new(handler)));
+        self
+    }
+
+    /// Add a handler that is called if nothing else
+    /// consumed the event.
+    pub fn fallback(
+        &mut self,
+        handler: impl Fn(&mut State, &Input) -> Control<Event> + 'static,
+    ) -> &mut Self {
+        self.fallbacks.push(Rc::new(handler));
+        self
+    }
+
+    /// Remove all handlers.
+    pub fn clear(&mut self) {
+        self.popups.clear();
+        self.widgets.clear();
+        self.fallbacks.clear();
+    }
+
+    /// Route the event.
+    ///
+    /// Returns the result of the handler that consumed the event,
+    /// or Control::Continue.
+    pub fn route(&self, state: &mut State, event: &Input) -> Control<Event> {
+        for handler in &self.popups {
+            let r = handler(state, event);
+            if r.is_consumed() {
+                return r;
+            }
+        }
+        for (focus, handler) in &self.widgets {
+            if focus.get() {
+                let r = handler(state, event);
+                if r.is_consumed() {
+                    return r;
+                }
+            }
+        }
+        for handler in &self.fallbacks {
+            let r = handler(state, event);
+            if r.is_consumed() {
+                return r;
+            }
+        }
+        Control::Continue
+    }
+}
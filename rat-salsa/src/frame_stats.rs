@@ -0,0 +1,70 @@
+//!
+//! Timing and load of the event-loop.
+//!
+
+use std::time::Duration;
+
+/// Number of frames for the average render time.
+const AVG_FRAMES: usize = 16;
+
+/// Timing and load of the event-loop.
+///
+/// Available as [AppContext::frame_stats](crate::AppContext::frame_stats)
+/// and [RenderContext::stats](crate::RenderContext::stats).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Frame count.
+    pub count: usize,
+    /// Time for rendering and drawing the last frame.
+    pub render: Duration,
+    /// Average render time over the last 16 frames.
+    pub render_avg: Duration,
+    /// Time for handling the last application event.
+    pub event: Duration,
+    /// Results waiting in the control-queue.
+    pub queue_len: usize,
+    /// Background tasks that are queued or running.
+    pub tasks: usize,
+}
+
+/// Collects the timings.
+#[derive(Debug, Default)]
+pub(crate) struct FrameTimes {
+    render: Duration,
+    render_ring: [Duration; AVG_FRAMES],
+    render_len: usize,
+    render_pos: usize,
+    event: Duration,
+}
+
+impl FrameTimes {
+    /// A frame has been rendered.
+    pub(crate) fn rendered(&mut self, duration: Duration) {
+        self.render = duration;
+        self.render_ring[self.render_pos] = duration;
+        self.render_pos = (self.render_pos + 1) % AVG_FRAMES;
+        self.render_len = (self.render_len + 1).min(AVG_FRAMES);
+    }
+
+    /// An application event has been handled.
+    pub(crate) fn event_handled(&mut self, duration: Duration) {
+        self.event = duration;
+    }
+
+    /// Fill in the timings.
+    pub(crate) fn stats(&self, count: usize, queue_len: usize, tasks: usize) -> FrameStats {
+        let render_avg = if self.render_len > 0 {
+            self.render_ring[..self.render_len].iter().sum::<Duration>() / self.render_len as u32
+        } else {
+            Duration::default()
+        };
+        FrameStats {
+            count,
+            render: self.render,
+            render_avg,
+            event: self.event,
+            queue_len,
+            tasks,
+        }
+    }
+}
@@ -1,29 +1,58 @@
+use crate::frame_stats::FrameTimes;
+use crate::framework::autosave::Autosave;
 use crate::framework::control_queue::ControlQueue;
+use crate::framework::deferred::Deferred;
+use crate::framework::modal::ModalSupport;
+use crate::framework::periodic::Periodic;
+use crate::poll::crossterm::{
+    clear_failed, is_input_blocked, set_abort_keys, set_input_blocked, set_too_small, take_resized,
+};
+use crate::poll::rendered::set_rendered;
 #[cfg(feature = "async")]
 use crate::poll::PollTokio;
 use crate::poll::{PollRendered, PollTasks, PollTimers};
-use crate::run_config::RunConfig;
-use crate::{AppContext, AppState, AppWidget, Control, RenderContext};
+use crate::rendered::RenderedEvent;
+use crate::run_config::{InterceptFn, InterceptResultFn, RunConfig, TooSmallFn};
+use crate::{
+    AppContext, AppState, AppWidget, Control, Intercept, PollError, PollEvents, RenderContext,
+};
 use crossbeam::channel::{SendError, TryRecvError};
+use crossterm::event::{DisableFocusChange, EnableFocusChange};
+use crossterm::ExecutableCommand;
 use poll_queue::PollQueue;
+use rat_widget::event::{install_feedback, uninstall_feedback};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::text::Line;
+use ratatui::widgets::{Paragraph, Widget, Wrap};
 use std::any::TypeId;
-use std::cmp::min;
+use std::cell::RefCell;
+use std::cmp::{min, Reverse};
+use std::io::stdout;
+use std::mem;
 use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{io, thread};
 
+mod autosave;
 pub(crate) mod control_queue;
+pub(crate) mod deferred;
+pub(crate) mod modal;
+pub(crate) mod periodic;
 mod poll_queue;
 
 const SLEEP: u64 = 250_000; // µs
 const BACKOFF: u64 = 10_000; // µs
 const FAST_SLEEP: u64 = 100; // µs
+const FAIRNESS: usize = 8; // events per priority and round
+const RETRY: u64 = 10_000; // µs
+const RETRY_MAX: u64 = 1_000_000; // µs
 
 fn _run_tui<App, Global, Event, Error>(
     app: App,
     global: &mut Global,
     state: &mut App::State,
-    cfg: &mut RunConfig<Event, Error>,
+    cfg: &mut RunConfig<Event, Error, Global>,
 ) -> Result<(), Error>
 where
     App: AppWidget<Global, Event, Error> + 'static,
@@ -31,8 +60,35 @@ where
     Event: Send + 'static,
     Error: Send + 'static + From<TryRecvError> + From<io::Error> + From<SendError<()>>,
 {
-    let term = cfg.term.as_mut();
+    let mut autosave = cfg
+        .autosave
+        .clone()
+        .map(|(path, interval)| Autosave::new(path, interval));
+    let track_terminal_focus = cfg.track_terminal_focus;
+    let idle = cfg.idle;
+    let color_depth = cfg.color_depth.unwrap_or_else(|| cfg.term.color_depth());
+    let modal = ModalSupport::new(cfg.term.as_mut());
     let poll = cfg.poll.as_mut_slice();
+    let poll_stats = RefCell::new(mem::take(&mut cfg.stats));
+    let mut intercept = mem::take(&mut cfg.intercept);
+    let mut intercept_result = mem::take(&mut cfg.intercept_result);
+    let max_queue_burst = cfg.max_queue_burst;
+    let min_size = cfg.min_size;
+    let mut too_small = cfg.too_small.take();
+
+    // polling order by priority.
+    let mut poll_order = (0..poll.len()).collect::<Vec<_>>();
+    poll_order.sort_by_key(|n| Reverse(poll_stats.borrow()[*n].priority));
+    let mut poll_drained = vec![0usize; poll.len()];
+    let poll_input = poll
+        .iter()
+        .enumerate()
+        .filter_map(|(n, v)| if v.is_input() { Some(n) } else { None })
+        .collect::<Vec<_>>();
+    let mut queue_burst = 0usize;
+    // next retry and backoff after a recoverable error.
+    let mut poll_retry = vec![None; poll.len()];
+    let mut fatal = None;
 
     let timers = poll.iter().find_map(|v| {
         v.as_any()
@@ -58,64 +114,114 @@ where
             .map(|t| t.get_tasks())
     });
     let queue = ControlQueue::default();
+    let deferred = Deferred::default();
+    let periodic = Periodic::default();
+    let frame_times = RefCell::new(FrameTimes::default());
+
+    set_input_blocked(false);
+    set_too_small(false);
+    set_abort_keys(cfg.abort_keys.clone());
+    clear_failed();
+    if cfg.feedback > 0 {
+        install_feedback(cfg.feedback);
+    } else {
+        uninstall_feedback();
+    }
 
     let mut appctx = AppContext {
         g: global,
         focus: None,
         count: 0,
+        terminal_focused: true,
+        color_depth,
+        terminal_size: Size::default(),
         timers,
         tasks,
         #[cfg(feature = "async")]
         tokio,
         queue: &queue,
+        deferred: &deferred,
+        periodic: &periodic,
+        poll_stats: &poll_stats,
+        frame_times: &frame_times,
+        modal: &modal,
+        last_error_meta: None,
+        poll_warning: None,
+        feedback: Vec::new(),
+        feedback_capacity: cfg.feedback,
     };
 
     let poll_queue = PollQueue::default();
     let mut poll_sleep = Duration::from_micros(SLEEP);
+    let mut last_activity = Instant::now();
 
-    // init state
+    // restore and init state
+    if let Some(autosave) = &mut autosave {
+        autosave.restore(state)?;
+        if let Some(timers) = &appctx.timers {
+            autosave.start(timers);
+        }
+    }
     state.init(&mut appctx)?;
 
     // initial render
-    appctx.count = term.render(&mut |frame| {
+    let start = Instant::now();
+    let mut full_redraw = true;
+    let stats = appctx.frame_stats();
+    appctx.count = modal.term.borrow_mut().render(&mut |frame| {
         let mut ctx = RenderContext {
             g: appctx.g,
             count: frame.count(),
             cursor: None,
+            input_blocked: is_input_blocked(),
+            color_depth,
+            stats,
         };
         let frame_area = frame.area();
-        app.render(frame_area, frame.buffer_mut(), state, &mut ctx)?;
-        if let Some((cursor_x, cursor_y)) = ctx.cursor {
-            frame.set_cursor_position((cursor_x, cursor_y));
+        appctx.terminal_size = frame_area.as_size();
+        if is_too_small(min_size, frame_area) {
+            set_too_small(true);
+            render_too_small(min_size, &mut too_small, frame_area, frame.buffer_mut());
+        } else {
+            set_too_small(false);
+            app.render(frame_area, frame.buffer_mut(), state, &mut ctx)?;
+            if let Some((cursor_x, cursor_y)) = ctx.cursor {
+                frame.set_cursor_position((cursor_x, cursor_y));
+            }
         }
+        modal.last_frame.borrow_mut().clone_from(frame.buffer_mut());
         Ok(frame.count())
     })?;
+    frame_times.borrow_mut().rendered(start.elapsed());
     if let Some(idx) = rendered_event {
+        set_rendered(RenderedEvent {
+            count: appctx.count,
+            duration: start.elapsed(),
+            full_redraw,
+        });
         queue.push(poll[idx].read());
     }
 
     'ui: loop {
-        // panic on worker panic
-        if let Some(tasks) = &appctx.tasks {
-            if !tasks.check_liveness() {
-                dbg!("worker panicked");
-                break 'ui;
-            }
-        }
-
         // No events queued, check here.
         if queue.is_empty() {
             // The events are not processed immediately, but all
             // notifies are queued in the poll_queue.
             if poll_queue.is_empty() {
-                for (n, p) in poll.iter_mut().enumerate() {
-                    match p.poll() {
+                poll_drained.fill(0);
+                for n in poll_order.iter().copied() {
+                    poll_stats.borrow_mut()[n].last_poll = Some(Instant::now());
+                    match poll_source(poll[n].as_mut(), &mut poll_retry[n]) {
                         Ok(true) => {
                             poll_queue.push(n);
                         }
                         Ok(false) => {}
-                        Err(e) => {
-                            queue.push(Err(e));
+                        Err(PollError::Recoverable(e)) => {
+                            queue.push(poll_warning(n, e, state, &mut appctx));
+                        }
+                        Err(PollError::Fatal(e)) => {
+                            fatal = Some(e);
+                            break 'ui;
                         }
                     }
                 }
@@ -132,6 +238,16 @@ where
                 } else {
                     poll_sleep
                 };
+                let t = if let Some(periodic_sleep) = periodic.sleep_time() {
+                    min(periodic_sleep, t)
+                } else {
+                    t
+                };
+                let t = if let Some(idle) = idle {
+                    min(idle.saturating_sub(last_activity.elapsed()), t)
+                } else {
+                    t
+                };
                 thread::sleep(t);
                 if poll_sleep < Duration::from_micros(SLEEP) {
                     // Back off slowly.
@@ -145,38 +261,185 @@ where
 
         // All the fall-out of the last event has cleared.
         // Run the next event.
+        if queue.is_empty() {
+            deferred.poll(&queue);
+        }
+        if queue.is_empty() {
+            if let Some(tasks) = &appctx.tasks {
+                periodic.poll(tasks, &queue);
+            }
+        }
+        if queue.is_empty() {
+            if let (Some(autosave), Some(timers)) = (&mut autosave, &appctx.timers) {
+                if let Err(e) = autosave.poll(timers, state) {
+                    queue.push(Err(e.into()));
+                }
+            }
+        }
+        if queue.is_empty() && poll_queue.is_empty() {
+            if let Some(idle) = idle {
+                if last_activity.elapsed() >= idle {
+                    // wait the full duration before the next call.
+                    last_activity = Instant::now();
+                    queue.push(state.idle(&mut appctx));
+                }
+            }
+        }
         if queue.is_empty() {
             if let Some(h) = poll_queue.take() {
-                queue.push(poll[h].read());
+                last_activity = Instant::now();
+                let r = poll[h].read();
+                queue.push_meta(r, poll[h].read_meta());
+                poll_stats.borrow_mut()[h].events += 1;
+
+                // check the minimum size with each resize.
+                if take_resized() && min_size.is_some() {
+                    queue.push(Ok(Control::Changed));
+                }
+
+                if track_terminal_focus {
+                    if let Some(focused) = poll[h].read_focus() {
+                        if focused != appctx.terminal_focused {
+                            appctx.terminal_focused = focused;
+                            if let Some(timers) = &appctx.timers {
+                                timers.set_paused(!focused);
+                            }
+                            if focused {
+                                queue.push(state.focus_gained(&mut appctx));
+                            } else {
+                                queue.push(state.focus_lost(&mut appctx));
+                            }
+                        }
+                    }
+                }
+
+                // drain higher priority sources first.
+                let priority = poll_stats.borrow()[h].priority as usize;
+                poll_drained[h] += 1;
+                if priority > 0 && poll_drained[h] < FAIRNESS * priority {
+                    poll_stats.borrow_mut()[h].last_poll = Some(Instant::now());
+                    match poll_source(poll[h].as_mut(), &mut poll_retry[h]) {
+                        Ok(true) => poll_queue.push_front(h),
+                        Ok(false) => {}
+                        Err(PollError::Recoverable(e)) => {
+                            queue.push(poll_warning(h, e, state, &mut appctx));
+                        }
+                        Err(PollError::Fatal(e)) => {
+                            fatal = Some(e);
+                            break 'ui;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Don't let a long chain of results starve the input.
+        if queue.is_empty() {
+            queue_burst = 0;
+        } else if max_queue_burst > 0
+            && queue_burst >= max_queue_burst
+            && !queue.next_is_quit_or_err()
+        {
+            queue_burst = 0;
+            for n in poll_input.iter().copied() {
+                poll_stats.borrow_mut()[n].last_poll = Some(Instant::now());
+                // it may already wait in the poll_queue.
+                let ready = poll_queue.remove(n)
+                    || match poll_source(poll[n].as_mut(), &mut poll_retry[n]) {
+                        Ok(v) => v,
+                        Err(PollError::Recoverable(e)) => {
+                            queue.push(poll_warning(n, e, state, &mut appctx));
+                            false
+                        }
+                        Err(PollError::Fatal(e)) => {
+                            fatal = Some(e);
+                            break 'ui;
+                        }
+                    };
+                if ready {
+                    last_activity = Instant::now();
+                    queue.push(poll[n].read());
+                    poll_stats.borrow_mut()[n].events += 1;
+                }
             }
         }
 
         // Result of event-handling.
-        if let Some(ctrl) = queue.take() {
+        if let Some((ctrl, meta)) = queue.take() {
+            queue_burst += 1;
+            let ctrl = match ctrl {
+                Ok(Control::Multiple(v)) => Ok(Control::Multiple(v)),
+                Ok(ctrl) => Ok(intercept_control(ctrl, &mut intercept_result, &mut appctx)),
+                Err(e) => Err(e),
+            };
             match ctrl {
                 Err(e) => {
-                    queue.push(state.error(e, &mut appctx));
+                    appctx.last_error_meta = meta;
+                    let r = state.error(e, &mut appctx);
+                    appctx.last_error_meta = None;
+                    queue.push(r);
                 }
                 Ok(Control::Continue) => {}
                 Ok(Control::Unchanged) => {}
                 Ok(Control::Changed) => {
-                    let r = term.render(&mut |frame| {
+                    // events that must be processed before rendering.
+                    let mut urgent = Vec::new();
+                    for p in poll.iter_mut() {
+                        match p.read_urgent() {
+                            Ok(Some(v)) => urgent.push(Ok(v)),
+                            Ok(None) => {}
+                            Err(e) => urgent.push(Err(e)),
+                        }
+                    }
+                    if !urgent.is_empty() {
+                        urgent.push(Ok(Control::Changed));
+                        queue.push_front(urgent.into_iter());
+                        continue;
+                    }
+
+                    let start = Instant::now();
+                    let stats = appctx.frame_stats();
+                    let r = modal.term.borrow_mut().render(&mut |frame| {
                         let mut ctx = RenderContext {
                             g: appctx.g,
                             count: frame.count(),
                             cursor: None,
+                            input_blocked: is_input_blocked(),
+                            color_depth,
+                            stats,
                         };
                         let frame_area = frame.area();
-                        app.render(frame_area, frame.buffer_mut(), state, &mut ctx)?;
-                        if let Some((cursor_x, cursor_y)) = ctx.cursor {
-                            frame.set_cursor_position((cursor_x, cursor_y));
+                        appctx.terminal_size = frame_area.as_size();
+                        if is_too_small(min_size, frame_area) {
+                            set_too_small(true);
+                            render_too_small(
+                                min_size,
+                                &mut too_small,
+                                frame_area,
+                                frame.buffer_mut(),
+                            );
+                        } else {
+                            set_too_small(false);
+                            app.render(frame_area, frame.buffer_mut(), state, &mut ctx)?;
+                            if let Some((cursor_x, cursor_y)) = ctx.cursor {
+                                frame.set_cursor_position((cursor_x, cursor_y));
+                            }
                         }
+                        // ratatui redraws everything after a resize.
+                        full_redraw = modal.last_frame.borrow().area != frame_area;
+                        modal.last_frame.borrow_mut().clone_from(frame.buffer_mut());
                         Ok(frame.count())
                     });
                     match r {
                         Ok(v) => {
                             appctx.count = v;
+                            frame_times.borrow_mut().rendered(start.elapsed());
                             if let Some(h) = rendered_event {
+                                set_rendered(RenderedEvent {
+                                    count: v,
+                                    duration: start.elapsed(),
+                                    full_redraw,
+                                });
                                 queue.push(poll[h].read());
                             }
                         }
@@ -184,18 +447,160 @@ where
                     }
                 }
                 Ok(Control::Event(a)) => {
-                    queue.push(state.event(&a, &mut appctx));
+                    let start = Instant::now();
+                    let r = dispatch_event(a, &mut intercept, state, &mut appctx);
+                    appctx.collect_feedback();
+                    frame_times.borrow_mut().event_handled(start.elapsed());
+                    queue.push(r);
                 }
                 Ok(Control::Quit) => {
                     break 'ui;
                 }
+                Ok(Control::Multiple(v)) => {
+                    queue.push_front(v.into_iter().map(Ok));
+                }
             }
         }
     }
 
-    state.shutdown(&mut appctx)?;
+    if let Some(autosave) = &mut autosave {
+        autosave.save(state)?;
+    }
+    let r = state.shutdown(&mut appctx);
+    uninstall_feedback();
+
+    // the failure of the event-source comes first.
+    if let Some(e) = fatal {
+        return Err(e);
+    }
+    r
+}
+
+/// Poll one event-source.
+///
+/// A source that failed with a recoverable error is skipped
+/// until its backoff has passed.
+fn poll_source<Event, Error>(
+    source: &mut dyn PollEvents<Event, Error>,
+    retry: &mut Option<(Instant, Duration)>,
+) -> Result<bool, PollError<Error>>
+where
+    Event: Send + 'static,
+    Error: Send + 'static,
+{
+    if let Some((next, _)) = retry {
+        if Instant::now() < *next {
+            return Ok(false);
+        }
+    }
+    match source.poll() {
+        Ok(v) => {
+            *retry = None;
+            Ok(v)
+        }
+        Err(PollError::Recoverable(e)) => {
+            let backoff = match retry {
+                Some((_, backoff)) => min(*backoff * 2, Duration::from_micros(RETRY_MAX)),
+                None => Duration::from_micros(RETRY),
+            };
+            *retry = Some((Instant::now() + backoff, backoff));
+            Err(PollError::Recoverable(e))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Report a recoverable error of an event-source to AppState::error().
+fn poll_warning<Global, Event, Error, State>(
+    n: usize,
+    err: Error,
+    state: &mut State,
+    ctx: &mut AppContext<'_, Global, Event, Error>,
+) -> Result<Control<Event>, Error>
+where
+    Event: Send + 'static,
+    Error: Send + 'static,
+    State: AppState<Global, Event, Error> + ?Sized,
+{
+    ctx.poll_warning = Some(ctx.poll_stats.borrow()[n].name);
+    let r = state.error(err, ctx);
+    ctx.poll_warning = None;
+    r
+}
 
-    Ok(())
+/// The area is below the minimum size.
+fn is_too_small(min_size: Option<Size>, area: Rect) -> bool {
+    if let Some(min_size) = min_size {
+        area.width < min_size.width || area.height < min_size.height
+    } else {
+        false
+    }
+}
+
+/// Render the screen for a terminal below the minimum size.
+fn render_too_small(
+    min_size: Option<Size>,
+    too_small: &mut Option<TooSmallFn>,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let min_size = min_size.unwrap_or_default();
+    if let Some(too_small) = too_small {
+        too_small(min_size, area, buf);
+    } else {
+        let text = format!(
+            "Terminal too small: need {}x{}, have {}x{}",
+            min_size.width, min_size.height, area.width, area.height
+        );
+        let lines = if area.width > 0 {
+            (text.len() as u16).div_ceil(area.width)
+        } else {
+            1
+        };
+        let y = area.y + area.height.saturating_sub(lines) / 2;
+        Paragraph::new(Line::from(text))
+            .centered()
+            .wrap(Wrap { trim: true })
+            .render(Rect::new(area.x, y, area.width, area.bottom() - y), buf);
+    }
+}
+
+/// Run the result interceptors in order.
+fn intercept_control<Global, Event, Error>(
+    mut ctrl: Control<Event>,
+    intercept: &mut [InterceptResultFn<Global, Event, Error>],
+    ctx: &mut AppContext<'_, Global, Event, Error>,
+) -> Control<Event>
+where
+    Event: Send + 'static,
+    Error: Send + 'static,
+{
+    for f in intercept.iter_mut() {
+        ctrl = f(ctrl, ctx);
+    }
+    ctrl
+}
+
+/// Run the event interceptors in order and then AppState::event().
+fn dispatch_event<Global, Event, Error, State>(
+    mut event: Event,
+    intercept: &mut [InterceptFn<Global, Event, Error>],
+    state: &mut State,
+    ctx: &mut AppContext<'_, Global, Event, Error>,
+) -> Result<Control<Event>, Error>
+where
+    Event: Send + 'static,
+    Error: Send + 'static,
+    State: AppState<Global, Event, Error> + ?Sized,
+{
+    for f in intercept.iter_mut() {
+        match f(&event, ctx) {
+            Intercept::Pass => {}
+            Intercept::Replace(e) => event = e,
+            Intercept::Consume(r) => return Ok(r),
+        }
+    }
+    state.event(&event, ctx)
 }
 
 /// Run the event-loop
@@ -283,7 +688,7 @@ pub fn run_tui<Widget, Global, Event, Error>(
     app: Widget,
     global: &mut Global,
     state: &mut Widget::State,
-    mut cfg: RunConfig<Event, Error>,
+    mut cfg: RunConfig<Event, Error, Global>,
 ) -> Result<(), Error>
 where
     Widget: AppWidget<Global, Event, Error> + 'static,
@@ -291,17 +696,40 @@ where
     Event: Send + 'static,
     Error: Send + 'static + From<TryRecvError> + From<io::Error> + From<SendError<()>>,
 {
+    match cfg.dry_check() {
+        Ok(warnings) => {
+            for w in warnings {
+                log::warn!("{}", w);
+            }
+        }
+        Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidInput, e).into()),
+    }
+
     cfg.term.init()?;
+    if cfg.track_terminal_focus {
+        stdout().execute(EnableFocusChange)?;
+    }
 
     let r = match catch_unwind(AssertUnwindSafe(|| _run_tui(app, global, state, &mut cfg))) {
         Ok(v) => v,
         Err(e) => {
+            if cfg.track_terminal_focus {
+                _ = stdout().execute(DisableFocusChange);
+            }
             _ = cfg.term.shutdown();
             resume_unwind(e);
         }
     };
 
-    cfg.term.shutdown()?;
+    // restore as much as possible, the terminal might be gone.
+    let r_focus = if cfg.track_terminal_focus {
+        stdout().execute(DisableFocusChange).map(|_| ())
+    } else {
+        Ok(())
+    };
+    let r_term = cfg.term.shutdown();
 
-    r
+    r?;
+    r_focus?;
+    r_term
 }
@@ -0,0 +1,123 @@
+//!
+//! Periodic persistence of the application state.
+//!
+
+use crate::timer::{TimerHandle, Timers};
+use crate::AppState;
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::OsString;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Calls [AppState::save_state] periodically and writes
+/// the result if it changed.
+#[derive(Debug)]
+pub(crate) struct Autosave {
+    path: PathBuf,
+    interval: Duration,
+    timer: Option<TimerHandle>,
+    last_hash: Option<u64>,
+}
+
+impl Autosave {
+    pub(crate) fn new(path: PathBuf, interval: Duration) -> Self {
+        Self {
+            path,
+            interval,
+            timer: None,
+            last_hash: None,
+        }
+    }
+
+    /// Read the last saved state and call [AppState::restore_state].
+    /// A missing file is not an error.
+    pub(crate) fn restore<Global, Event, Error, State>(
+        &mut self,
+        state: &mut State,
+    ) -> Result<(), io::Error>
+    where
+        State: AppState<Global, Event, Error> + ?Sized,
+        Event: 'static + Send,
+        Error: 'static + Send,
+    {
+        match fs::read(&self.path) {
+            Ok(bytes) => {
+                self.last_hash = Some(hash(&bytes));
+                state.restore_state(&bytes);
+                Ok(())
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Start the timer for the interval.
+    pub(crate) fn start(&mut self, timers: &Timers) {
+        self.timer = Some(timers.add_internal(self.interval));
+    }
+
+    /// Save if the timer is due.
+    pub(crate) fn poll<Global, Event, Error, State>(
+        &mut self,
+        timers: &Timers,
+        state: &State,
+    ) -> Result<(), io::Error>
+    where
+        State: AppState<Global, Event, Error> + ?Sized,
+        Event: 'static + Send,
+        Error: 'static + Send,
+    {
+        match self.timer {
+            Some(timer) if timers.take_internal(timer) => self.save(state),
+            _ => Ok(()),
+        }
+    }
+
+    /// Save now. Skips writing if nothing changed since the
+    /// last save.
+    pub(crate) fn save<Global, Event, Error, State>(
+        &mut self,
+        state: &State,
+    ) -> Result<(), io::Error>
+    where
+        State: AppState<Global, Event, Error> + ?Sized,
+        Event: 'static + Send,
+        Error: 'static + Send,
+    {
+        let Some(bytes) = state.save_state() else {
+            return Ok(());
+        };
+        let hash = hash(&bytes);
+        if self.last_hash == Some(hash) {
+            return Ok(());
+        }
+        write_atomic(&self.path, &bytes)?;
+        self.last_hash = Some(hash);
+        Ok(())
+    }
+}
+
+fn hash(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Write to a temp file in the same directory and rename
+/// it to the final name.
+fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), io::Error> {
+    let mut tmp_name = OsString::from(".");
+    tmp_name.push(path.file_name().unwrap_or_default());
+    tmp_name.push(".tmp");
+    let tmp = path.with_file_name(tmp_name);
+
+    {
+        let mut f = fs::File::create(&tmp)?;
+        f.write_all(bytes)?;
+        f.sync_all()?;
+    }
+    fs::rename(&tmp, path)
+}
@@ -2,9 +2,14 @@
 //! Queue for all the results from event-handling.
 //!
 
-use crate::Control;
+use crate::{Control, TaskMeta};
 use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::rc::{Rc, Weak};
+
+/// Result of event-handling and the background task that
+/// produced it, if any.
+type Queued<Event, Error> = (Result<Control<Event>, Error>, Option<TaskMeta>);
 
 /// Queue for event-handling results.
 #[derive(Debug)]
@@ -13,7 +18,7 @@ where
     Event: 'static + Send,
     Error: 'static + Send,
 {
-    queue: RefCell<VecDeque<Result<Control<Event>, Error>>>,
+    queue: Rc<RefCell<VecDeque<Queued<Event, Error>>>>,
 }
 
 impl<Event, Error> Default for ControlQueue<Event, Error>
@@ -23,7 +28,7 @@ where
 {
     fn default() -> Self {
         Self {
-            queue: RefCell::new(VecDeque::default()),
+            queue: Rc::new(RefCell::new(VecDeque::default())),
         }
     }
 }
@@ -38,13 +43,55 @@ where
         self.queue.borrow().is_empty()
     }
 
+    /// number of queued results.
+    pub(crate) fn len(&self) -> usize {
+        self.queue.borrow().len()
+    }
+
+    /// the next result is a Quit or an error.
+    pub(crate) fn next_is_quit_or_err(&self) -> bool {
+        matches!(
+            self.queue.borrow().front(),
+            Some((Ok(Control::Quit), _)) | Some((Err(_), _))
+        )
+    }
+
     /// take the first result.
-    pub(crate) fn take(&self) -> Option<Result<Control<Event>, Error>> {
+    pub(crate) fn take(&self) -> Option<Queued<Event, Error>> {
         self.queue.borrow_mut().pop_front()
     }
 
     /// push a new result to the queue.
     pub(crate) fn push(&self, ctrl: Result<Control<Event>, Error>) {
-        self.queue.borrow_mut().push_back(ctrl);
+        self.queue.borrow_mut().push_back((ctrl, None));
+    }
+
+    /// push a new result of a background task to the queue.
+    pub(crate) fn push_meta(&self, ctrl: Result<Control<Event>, Error>, meta: Option<TaskMeta>) {
+        self.queue.borrow_mut().push_back((ctrl, meta));
+    }
+
+    /// Weak handle to push results from outside the event-loop,
+    /// e.g. from an observable. Does nothing after the
+    /// event-loop has ended.
+    pub(crate) fn weak_push(&self) -> impl Fn(Control<Event>) + 'static {
+        let queue: Weak<RefCell<VecDeque<Queued<Event, Error>>>> = Rc::downgrade(&self.queue);
+        move |ctrl| {
+            if let Some(queue) = queue.upgrade() {
+                queue.borrow_mut().push_back((Ok(ctrl), None));
+            }
+        }
+    }
+
+    /// push results to the front of the queue.
+    /// they will be processed next, in the given order.
+    pub(crate) fn push_front(
+        &self,
+        ctrl: impl DoubleEndedIterator<Item = Result<Control<Event>, Error>>,
+    ) {
+        let mut queue = self.queue.borrow_mut();
+        for v in ctrl.rev() {
+            queue.push_front((v, None));
+        }
     }
 }
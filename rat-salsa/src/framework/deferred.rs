@@ -0,0 +1,70 @@
+//!
+//! Continuations waiting for their background work.
+//!
+
+use crate::framework::control_queue::ControlQueue;
+use crate::Control;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+
+/// Checks if the work is done and runs the continuation.
+///
+/// Returns None while the work is still running.
+pub(crate) type DeferredFn<Event, Error> =
+    Box<dyn FnMut() -> Option<Result<Control<Event>, Error>>>;
+
+/// Continuations of [AppContext::defer](crate::AppContext::defer).
+pub(crate) struct Deferred<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    ops: RefCell<Vec<DeferredFn<Event, Error>>>,
+}
+
+impl<Event, Error> Debug for Deferred<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Deferred")
+            .field("ops", &self.ops.borrow().len())
+            .finish()
+    }
+}
+
+impl<Event, Error> Default for Deferred<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    fn default() -> Self {
+        Self {
+            ops: Default::default(),
+        }
+    }
+}
+
+impl<Event, Error> Deferred<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    /// Add a continuation.
+    pub(crate) fn push(&self, op: DeferredFn<Event, Error>) {
+        self.ops.borrow_mut().push(op);
+    }
+
+    /// Queue the results of all finished continuations.
+    pub(crate) fn poll(&self, queue: &ControlQueue<Event, Error>) {
+        self.ops.borrow_mut().retain_mut(|op| {
+            if let Some(r) = op() {
+                queue.push(r);
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! Nested event-loop for modal prompts.
+//!
+
+use crate::poll::crossterm::is_input_blocked;
+use crate::terminal::Terminal;
+use crate::{AppContext, RenderContext};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::io;
+use std::time::Duration;
+
+const POLL: u64 = 50; // ms
+
+/// Access to the terminal for a nested event-loop.
+pub(crate) struct ModalSupport<'a, Error>
+where
+    Error: 'static + Send,
+{
+    /// The terminal.
+    pub(crate) term: RefCell<&'a mut dyn Terminal<Error>>,
+    /// Copy of the last rendered frame of the main loop.
+    pub(crate) last_frame: RefCell<Buffer>,
+}
+
+impl<Error> Debug for ModalSupport<'_, Error>
+where
+    Error: 'static + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModalSupport")
+            .field("term", &"...")
+            .field("last_frame", &self.last_frame.borrow().area)
+            .finish()
+    }
+}
+
+impl<'a, Error> ModalSupport<'a, Error>
+where
+    Error: 'static + Send,
+{
+    pub(crate) fn new(term: &'a mut dyn Terminal<Error>) -> Self {
+        Self {
+            term: RefCell::new(term),
+            last_frame: RefCell::new(Buffer::empty(Rect::default())),
+        }
+    }
+}
+
+/// Runs the nested event-loop until `event` returns a value.
+#[allow(clippy::type_complexity)]
+pub(crate) fn run_modal<Global, Event, Error, S, T>(
+    ctx: &mut AppContext<'_, Global, Event, Error>,
+    state: &mut S,
+    render: &mut dyn FnMut(
+        Rect,
+        &mut Buffer,
+        &mut S,
+        &mut RenderContext<'_, Global>,
+    ) -> Result<(), Error>,
+    event: &mut dyn FnMut(
+        &crossterm::event::Event,
+        &mut S,
+        &mut AppContext<'_, Global, Event, Error>,
+    ) -> Result<Option<T>, Error>,
+) -> Result<T, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send + From<io::Error>,
+{
+    let modal = ctx.modal;
+    loop {
+        let color_depth = ctx.color_depth;
+        let stats = ctx.frame_stats();
+        let count = modal.term.borrow_mut().render(&mut |frame| {
+            let area = frame.area();
+            let buf = frame.buffer_mut();
+
+            // dimmed parent ui
+            let last_frame = modal.last_frame.borrow();
+            for pos in area.intersection(last_frame.area).positions() {
+                buf[pos] = last_frame[pos].clone();
+            }
+            buf.set_style(area, Style::new().add_modifier(Modifier::DIM));
+
+            let mut rctx = RenderContext {
+                g: &mut *ctx.g,
+                count: frame.count(),
+                cursor: None,
+                input_blocked: is_input_blocked(),
+                color_depth,
+                stats,
+            };
+            render(area, frame.buffer_mut(), state, &mut rctx)?;
+            if let Some((cursor_x, cursor_y)) = rctx.cursor {
+                frame.set_cursor_position((cursor_x, cursor_y));
+            }
+            Ok(frame.count())
+        })?;
+        ctx.count = count;
+
+        // wait for the next event.
+        let ct_event = loop {
+            let ct_event = modal
+                .term
+                .borrow_mut()
+                .read_event(Duration::from_millis(POLL))?;
+            if let Some(ct_event) = ct_event {
+                break ct_event;
+            }
+        };
+        if let Some(v) = event(&ct_event, state, ctx)? {
+            return Ok(v);
+        }
+    }
+}
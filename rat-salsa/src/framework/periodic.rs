@@ -0,0 +1,187 @@
+//!
+//! Periodic background tasks.
+//!
+
+use crate::framework::control_queue::ControlQueue;
+use crate::thread_pool::{BoxTask, Cancel, Overlap, PeriodicHandle, ThreadPool};
+use crate::TaskMeta;
+use crossbeam::channel::SendError;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+/// Creates the task for each run.
+pub(crate) type TaskFactory<Event, Error> = Box<dyn FnMut() -> BoxTask<Event, Error>>;
+
+/// One schedule.
+struct Job<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    name: Option<String>,
+    interval: Duration,
+    next: Instant,
+    overlap: Overlap,
+    factory: TaskFactory<Event, Error>,
+    handle: PeriodicHandle,
+    /// Cancel for the last run.
+    cancel: Option<Cancel>,
+    /// A run has been queued.
+    pending: bool,
+}
+
+/// Schedules of [AppContext::spawn_periodic](crate::AppContext::spawn_periodic).
+pub(crate) struct Periodic<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    jobs: RefCell<Vec<Job<Event, Error>>>,
+}
+
+impl<Event, Error> Debug for Periodic<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Periodic")
+            .field("jobs", &self.jobs.borrow().len())
+            .finish()
+    }
+}
+
+impl<Event, Error> Default for Periodic<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    fn default() -> Self {
+        Self {
+            jobs: Default::default(),
+        }
+    }
+}
+
+impl<Event, Error> Periodic<Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    /// Add a schedule. The first run is after one interval.
+    pub(crate) fn push(
+        &self,
+        name: Option<&str>,
+        interval: Duration,
+        overlap: Overlap,
+        factory: TaskFactory<Event, Error>,
+    ) -> PeriodicHandle {
+        let handle = PeriodicHandle::new();
+        self.jobs.borrow_mut().push(Job {
+            name: name.map(|v| v.to_string()),
+            interval,
+            next: Instant::now() + interval,
+            overlap,
+            factory,
+            handle: handle.clone(),
+            cancel: None,
+            pending: false,
+        });
+        handle
+    }
+
+    /// Time until the next run is due.
+    pub(crate) fn sleep_time(&self) -> Option<Duration> {
+        let now = Instant::now();
+        self.jobs
+            .borrow()
+            .iter()
+            .map(|v| {
+                if v.pending && !v.handle.is_running() {
+                    Duration::ZERO
+                } else {
+                    v.next.saturating_duration_since(now)
+                }
+            })
+            .min()
+    }
+
+    /// Start all runs that are due.
+    ///
+    /// Errors when spawning are queued.
+    pub(crate) fn poll(&self, tasks: &ThreadPool<Event, Error>, queue: &ControlQueue<Event, Error>)
+    where
+        Error: From<SendError<()>>,
+    {
+        let now = Instant::now();
+        self.jobs.borrow_mut().retain_mut(|job| {
+            if job.handle.is_stopped() {
+                return false;
+            }
+
+            let mut start = false;
+            if now >= job.next {
+                // missed ticks are not repeated.
+                job.next += job.interval;
+                if job.next <= now {
+                    job.next = now + job.interval;
+                }
+
+                if job.handle.is_running() {
+                    match job.overlap {
+                        Overlap::Skip => {}
+                        Overlap::Queue => job.pending = true,
+                        Overlap::Cancel => {
+                            if let Some(cancel) = &job.cancel {
+                                cancel.cancel();
+                            }
+                            start = true;
+                        }
+                    }
+                } else {
+                    start = true;
+                }
+            } else if job.pending && !job.handle.is_running() {
+                start = true;
+            }
+
+            if start {
+                job.pending = false;
+                if let Err(e) = Self::start(tasks, job) {
+                    queue.push(Err(e.into()));
+                }
+            }
+            true
+        });
+    }
+
+    fn start(
+        tasks: &ThreadPool<Event, Error>,
+        job: &mut Job<Event, Error>,
+    ) -> Result<(), SendError<()>> {
+        let running = job.handle.running();
+        let task = (job.factory)();
+
+        running.fetch_add(1, Ordering::AcqRel);
+        let finish = running.clone();
+        match tasks.spawn(
+            TaskMeta::new(job.name.as_deref()),
+            Box::new(move |cancel, send| {
+                let r = task(cancel, send);
+                finish.fetch_sub(1, Ordering::AcqRel);
+                r
+            }),
+        ) {
+            Ok(cancel) => {
+                job.cancel = Some(cancel);
+                Ok(())
+            }
+            Err(e) => {
+                running.fetch_sub(1, Ordering::AcqRel);
+                Err(e)
+            }
+        }
+    }
+}
@@ -18,8 +18,25 @@ impl PollQueue {
         self.queue.borrow_mut().pop_front()
     }
 
+    /// Push a handle to the front of the queue.
+    pub(crate) fn push_front(&self, poll: usize) {
+        self.queue.borrow_mut().push_front(poll);
+    }
+
     /// Push a handle to the queue.
     pub(crate) fn push(&self, poll: usize) {
         self.queue.borrow_mut().push_back(poll);
     }
+
+    /// Remove a handle from the queue.
+    /// Returns true if it was queued.
+    pub(crate) fn remove(&self, poll: usize) -> bool {
+        let mut queue = self.queue.borrow_mut();
+        if let Some(idx) = queue.iter().position(|v| *v == poll) {
+            queue.remove(idx);
+            true
+        } else {
+            false
+        }
+    }
 }
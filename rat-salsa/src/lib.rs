@@ -1,25 +1,44 @@
 #![doc = include_str!("../readme.md")]
 
+use crate::frame_stats::FrameTimes;
 use crate::framework::control_queue::ControlQueue;
-use crate::thread_pool::{Cancel, ThreadPool};
+use crate::framework::deferred::Deferred;
+use crate::framework::modal::ModalSupport;
+use crate::framework::periodic::Periodic;
+use crate::observable::{ObservableReader, ObservableWriter};
+use crate::terminal::ColorDepth;
+use crate::thread_pool::{Cancel, Overlap, PeriodicHandle, ThreadPool};
 use crate::timer::{TimerDef, TimerHandle, Timers};
 #[cfg(feature = "async")]
 use crate::tokio_tasks::TokioTasks;
-use crossbeam::channel::{SendError, Sender};
-use rat_widget::event::{ConsumedEvent, HandleEvent, Outcome, Regular};
+use crossbeam::channel::{bounded, Receiver, SendError, Sender, TryRecvError};
+use rat_widget::event::util::OpToken;
+use rat_widget::event::{ConsumedEvent, Feedback, HandleEvent, Outcome, Regular};
 use rat_widget::focus::Focus;
 use ratatui::buffer::Buffer;
-use ratatui::layout::Rect;
+use ratatui::layout::{Rect, Size};
+use ratatui::text::Text;
+use ratatui::widgets::Widget;
+use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::fmt::Debug;
 #[cfg(feature = "async")]
 use std::future::Future;
 use std::mem;
 use std::rc::Rc;
+use std::sync::atomic::{self, AtomicU64};
+use std::time::{Duration, Instant};
 #[cfg(feature = "async")]
 use tokio::task::AbortHandle;
 
+#[cfg(feature = "debug-status")]
+pub mod debug_status;
+mod event_dispatcher;
+mod focus_router;
+mod frame_stats;
 mod framework;
+mod module_host;
+pub mod observable;
 mod poll_events;
 pub mod rendered;
 mod run_config;
@@ -31,8 +50,9 @@ mod tokio_tasks;
 
 /// Event sources.
 pub mod poll {
-    mod crossterm;
-    mod rendered;
+    pub(crate) mod crossterm;
+    pub(crate) mod rendered;
+    mod stdin;
     mod thread_pool;
     mod timer;
     #[cfg(feature = "async")]
@@ -40,15 +60,20 @@ pub mod poll {
 
     pub use crossterm::PollCrossterm;
     pub use rendered::PollRendered;
+    pub use stdin::PollStdin;
     pub use thread_pool::PollTasks;
     pub use timer::PollTimers;
     #[cfg(feature = "async")]
     pub use tokio_tasks::PollTokio;
 }
 
+pub use event_dispatcher::EventDispatcher;
+pub use focus_router::FocusRouter;
+pub use frame_stats::FrameStats;
 pub use framework::run_tui;
-pub use poll_events::PollEvents;
-pub use run_config::RunConfig;
+pub use module_host::{Module, ModuleHost};
+pub use poll_events::{PollError, PollEvents, PollStats};
+pub use run_config::{ConfigError, RunConfig, Service};
 
 /// Result enum for event handling.
 ///
@@ -63,12 +88,15 @@ pub use run_config::RunConfig;
 /// the event-handler comes last. If an error is returned, everything
 /// send to the queue will be executed nonetheless.
 ///
+/// Or you can return [Control::Multiple] if you don't have access
+/// to the context.
+///
 /// __See__
 ///
 /// - [flow!](rat_widget::event::flow)
 /// - [try_flow!](rat_widget::event::try_flow)
 /// - [ConsumedEvent]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 #[must_use]
 #[non_exhaustive]
 pub enum Control<Event> {
@@ -94,55 +122,74 @@ pub enum Control<Event> {
     Event(Event),
     /// Quit the application.
     Quit,
+    /// More than one result.
+    ///
+    /// The event-loop processes them in order before anything
+    /// else in the queue.
+    ///
+    /// For the ordering the maximum of the contained values
+    /// is used, see [rank](Control::rank). Ties are broken by
+    /// comparing the contents, so two Multiple are only equal
+    /// if all their contents are equal.
+    Multiple(Vec<Control<Event>>),
+}
+
+impl<Event> Control<Event> {
+    /// Combine with a second result.
+    ///
+    /// Appends to an existing Multiple.
+    pub fn and(self, other: Control<Event>) -> Control<Event> {
+        match self {
+            Control::Multiple(mut v) => {
+                v.push(other);
+                Control::Multiple(v)
+            }
+            v => Control::Multiple(vec![v, other]),
+        }
+    }
+
+    /// Priority of the result.
+    ///
+    /// Continue < Unchanged < Changed < Event < Quit, and
+    /// the maximum of the contents for Multiple.
+    pub fn rank(&self) -> u8 {
+        match self {
+            Control::Continue => 0,
+            Control::Unchanged => 1,
+            Control::Changed => 2,
+            Control::Event(_) => 3,
+            Control::Quit => 4,
+            Control::Multiple(v) => v.iter().map(|v| v.rank()).max().unwrap_or(0),
+        }
+    }
 }
 
 impl<Event> Eq for Control<Event> {}
 
+/// Events are compared by discriminant only, the contents
+/// of Multiple are compared element-wise.
 impl<Event> PartialEq for Control<Event> {
     fn eq(&self, other: &Self) -> bool {
-        mem::discriminant(self) == mem::discriminant(other)
+        match (self, other) {
+            (Control::Multiple(a), Control::Multiple(b)) => a == b,
+            (a, b) => mem::discriminant(a) == mem::discriminant(b),
+        }
     }
 }
 
+/// Orders by [rank](Control::rank). With the same rank a single
+/// result comes before a Multiple, and two Multiple compare
+/// their contents.
 impl<Event> Ord for Control<Event> {
     fn cmp(&self, other: &Self) -> Ordering {
-        match self {
-            Control::Continue => match other {
-                Control::Continue => Ordering::Equal,
-                Control::Unchanged => Ordering::Less,
-                Control::Changed => Ordering::Less,
-                Control::Event(_) => Ordering::Less,
-                Control::Quit => Ordering::Less,
-            },
-            Control::Unchanged => match other {
-                Control::Continue => Ordering::Greater,
-                Control::Unchanged => Ordering::Equal,
-                Control::Changed => Ordering::Less,
-                Control::Event(_) => Ordering::Less,
-                Control::Quit => Ordering::Less,
-            },
-            Control::Changed => match other {
-                Control::Continue => Ordering::Greater,
-                Control::Unchanged => Ordering::Greater,
-                Control::Changed => Ordering::Equal,
-                Control::Event(_) => Ordering::Less,
-                Control::Quit => Ordering::Less,
-            },
-            Control::Event(_) => match other {
-                Control::Continue => Ordering::Greater,
-                Control::Unchanged => Ordering::Greater,
-                Control::Changed => Ordering::Greater,
-                Control::Event(_) => Ordering::Equal,
-                Control::Quit => Ordering::Less,
-            },
-            Control::Quit => match other {
-                Control::Continue => Ordering::Greater,
-                Control::Unchanged => Ordering::Greater,
-                Control::Changed => Ordering::Greater,
-                Control::Event(_) => Ordering::Greater,
-                Control::Quit => Ordering::Equal,
-            },
-        }
+        self.rank()
+            .cmp(&other.rank())
+            .then_with(|| match (self, other) {
+                (Control::Multiple(a), Control::Multiple(b)) => a.cmp(b),
+                (Control::Multiple(_), _) => Ordering::Greater,
+                (_, Control::Multiple(_)) => Ordering::Less,
+                _ => Ordering::Equal,
+            })
     }
 }
 
@@ -154,7 +201,11 @@ impl<Event> PartialOrd for Control<Event> {
 
 impl<Event> ConsumedEvent for Control<Event> {
     fn is_consumed(&self) -> bool {
-        !matches!(self, Control::Continue)
+        match self {
+            Control::Continue => false,
+            Control::Multiple(v) => v.iter().any(|v| v.is_consumed()),
+            _ => true,
+        }
     }
 }
 
@@ -169,6 +220,23 @@ impl<Event, T: Into<Outcome>> From<T> for Control<Event> {
     }
 }
 
+/// Result of an event interceptor.
+///
+/// See [RunConfig::intercept].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Intercept<Event> {
+    /// Pass the event on to the next interceptor
+    /// and finally to [AppState::event].
+    Pass,
+    /// Replace the event. The next interceptor and
+    /// [AppState::event] get the new event.
+    Replace(Event),
+    /// The event is consumed. Nothing after this is called
+    /// and the result is processed as usual.
+    Consume(Control<Event>),
+}
+
 ///
 /// AppWidget mimics StatefulWidget and adds a [RenderContext]
 ///
@@ -231,7 +299,63 @@ where
         Ok(Control::Continue)
     }
 
+    /// The application is idle.
+    ///
+    /// Needs [RunConfig::idle]. Called when no event source had
+    /// an event for the configured duration. Do some low-priority
+    /// work here, but keep each call short.
+    fn idle(
+        &mut self,
+        ctx: &mut AppContext<'_, Global, Event, Error>,
+    ) -> Result<Control<Event>, Error> {
+        Ok(Control::Continue)
+    }
+
+    /// The terminal window lost the focus.
+    ///
+    /// Needs [RunConfig::track_terminal_focus]. Pause animations
+    /// and expensive work here. Timers marked with
+    /// [TimerDef::pause_on_unfocus] are paused automatically.
+    fn focus_lost(
+        &mut self,
+        ctx: &mut AppContext<'_, Global, Event, Error>,
+    ) -> Result<Control<Event>, Error> {
+        Ok(Control::Continue)
+    }
+
+    /// The terminal window gained the focus.
+    ///
+    /// Needs [RunConfig::track_terminal_focus].
+    fn focus_gained(
+        &mut self,
+        ctx: &mut AppContext<'_, Global, Event, Error>,
+    ) -> Result<Control<Event>, Error> {
+        Ok(Control::Continue)
+    }
+
+    /// Snapshot of the state that should survive a restart.
+    /// Window layout, selected tabs, recent files, etc.
+    ///
+    /// Called periodically and before shutdown if
+    /// [RunConfig::autosave] is set. Return None to skip saving.
+    fn save_state(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Restore the snapshot created by save_state().
+    ///
+    /// Runs before init() if [RunConfig::autosave] is set
+    /// and the file exists.
+    fn restore_state(&mut self, bytes: &[u8]) {}
+
     /// Do error handling.
+    ///
+    /// Use [AppContext::last_error_meta] to find out which
+    /// background task produced the error.
+    ///
+    /// [AppContext::poll_warning] is set for recoverable failures of an
+    /// event-source. Fatal ones end the event-loop and are returned by
+    /// [run_tui] after shutdown().
     fn error(
         &self,
         event: Error,
@@ -241,6 +365,30 @@ where
     }
 }
 
+/// Provenance of a background task.
+///
+/// See [AppContext::last_error_meta].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskMeta {
+    /// Unique id of the task.
+    pub id: u64,
+    /// Name given with spawn_named().
+    pub name: Option<String>,
+    /// Time the task was spawned.
+    pub started_at: Instant,
+}
+
+impl TaskMeta {
+    pub(crate) fn new(name: Option<&str>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+        Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            name: name.map(|v| v.to_string()),
+            started_at: Instant::now(),
+        }
+    }
+}
+
 ///
 /// Application context for event handling.
 ///
@@ -256,6 +404,12 @@ where
     pub focus: Option<Focus>,
     /// Last frame count rendered.
     pub count: usize,
+    /// Terminal window has the focus.
+    pub(crate) terminal_focused: bool,
+    /// Color capabilities of the terminal.
+    pub(crate) color_depth: ColorDepth,
+    /// Size of the last rendered frame.
+    pub(crate) terminal_size: Size,
 
     /// Application timers.
     pub(crate) timers: Option<Rc<Timers>>,
@@ -266,6 +420,26 @@ where
     pub(crate) tokio: Option<Rc<TokioTasks<Event, Error>>>,
     /// Queue foreground tasks.
     pub(crate) queue: &'a ControlQueue<Event, Error>,
+    /// Continuations waiting for background work.
+    pub(crate) deferred: &'a Deferred<Event, Error>,
+    /// Periodic background work.
+    pub(crate) periodic: &'a Periodic<Event, Error>,
+    /// Event-source diagnostics.
+    pub(crate) poll_stats: &'a RefCell<Vec<PollStats>>,
+    /// Render and event timings.
+    pub(crate) frame_times: &'a RefCell<FrameTimes>,
+    /// Terminal access for nested event-loops.
+    pub(crate) modal: &'a ModalSupport<'a, Error>,
+    /// Background task that produced the error currently
+    /// handled by [AppState::error].
+    pub(crate) last_error_meta: Option<TaskMeta>,
+    /// Event-source that failed with the error currently
+    /// handled by [AppState::error].
+    pub(crate) poll_warning: Option<&'static str>,
+    /// Collected feedback messages.
+    pub(crate) feedback: Vec<Feedback>,
+    /// Capacity for the feedback messages.
+    pub(crate) feedback_capacity: usize,
 }
 
 ///
@@ -279,6 +453,14 @@ pub struct RenderContext<'a, Global> {
     pub count: usize,
     /// Output cursor position. Set after rendering is complete.
     pub cursor: Option<(u16, u16)>,
+    /// Input is blocked. See [AppContext::set_input_blocked].
+    /// Can be used to render some busy indicator.
+    pub input_blocked: bool,
+    /// Color capabilities of the terminal.
+    pub color_depth: ColorDepth,
+    /// Timing and load of the event-loop, as of
+    /// the start of this frame.
+    pub stats: FrameStats,
 }
 
 impl<Global, Event, Error> AppContext<'_, Global, Event, Error>
@@ -292,7 +474,7 @@ where
     ///
     /// Panics if no timer support is configured.
     #[inline]
-    pub fn add_timer(&self, t: TimerDef) -> TimerHandle {
+    pub fn add_timer(&self, t: TimerDef<Event>) -> TimerHandle {
         self.timers
             .as_ref()
             .expect("No timers configured. In main() add RunConfig::default()?.poll(PollTimers)")
@@ -316,15 +498,119 @@ where
     /// Remove the old timer and create a new one.
     /// If the old timer no longer exists it just creates the new one.
     ///
+    /// If the new timer has no [TimerDef::event] it keeps the
+    /// event of the old one.
+    ///
     /// __Panic__
     ///
     /// Panics if no timer support is configured.
     #[inline]
-    pub fn replace_timer(&self, h: Option<TimerHandle>, t: TimerDef) -> TimerHandle {
-        if let Some(h) = h {
-            self.remove_timer(h);
-        }
-        self.add_timer(t)
+    pub fn replace_timer(&self, h: Option<TimerHandle>, t: TimerDef<Event>) -> TimerHandle {
+        self.timers
+            .as_ref()
+            .expect("No timers configured. In main() add RunConfig::default()?.poll(PollTimers)")
+            .replace(h, t)
+    }
+
+    /// Pause a timer.
+    ///
+    /// The remaining time until the next tick is frozen until
+    /// [resume_timer](Self::resume_timer).
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no timer support is configured.
+    #[inline]
+    pub fn pause_timer(&self, h: TimerHandle) {
+        self.timers
+            .as_ref()
+            .expect("No timers configured. In main() add RunConfig::default()?.poll(PollTimers)")
+            .set_timer_paused(h, true)
+    }
+
+    /// Resume a paused timer.
+    ///
+    /// The timer continues with the remaining time. A repeating
+    /// timer doesn't send the ticks missed while paused.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no timer support is configured.
+    #[inline]
+    pub fn resume_timer(&self, h: TimerHandle) {
+        self.timers
+            .as_ref()
+            .expect("No timers configured. In main() add RunConfig::default()?.poll(PollTimers)")
+            .set_timer_paused(h, false)
+    }
+
+    /// Pause all timers, including the ones added later.
+    ///
+    /// Timers paused with [pause_timer](Self::pause_timer) stay
+    /// paused after [resume_all_timers](Self::resume_all_timers).
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no timer support is configured.
+    #[inline]
+    pub fn pause_all_timers(&self) {
+        self.timers
+            .as_ref()
+            .expect("No timers configured. In main() add RunConfig::default()?.poll(PollTimers)")
+            .set_paused_all(true)
+    }
+
+    /// Resume all timers.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no timer support is configured.
+    #[inline]
+    pub fn resume_all_timers(&self) {
+        self.timers
+            .as_ref()
+            .expect("No timers configured. In main() add RunConfig::default()?.poll(PollTimers)")
+            .set_paused_all(false)
+    }
+
+    /// Time until the timer fires next. A paused timer reports the
+    /// frozen remaining time. None if the timer doesn't exist.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no timer support is configured.
+    #[inline]
+    pub fn timer_remaining(&self, h: TimerHandle) -> Option<Duration> {
+        self.timers
+            .as_ref()
+            .expect("No timers configured. In main() add RunConfig::default()?.poll(PollTimers)")
+            .remaining(h)
+    }
+
+    /// Create an observable value.
+    ///
+    /// Each change via the [ObservableWriter] queues a
+    /// [Control::Changed].
+    ///
+    /// See [observable](crate::observable).
+    pub fn observable<T: 'static>(&self, initial: T) -> (ObservableWriter<T>, ObservableReader<T>) {
+        let push = self.queue.weak_push();
+        observable::observable(initial, move |_| push(Control::Changed))
+    }
+
+    /// Create an observable value.
+    ///
+    /// Each change via the [ObservableWriter] queues the
+    /// application event created by `event`.
+    ///
+    /// See [observable](crate::observable).
+    pub fn observable_event<T: 'static>(
+        &self,
+        initial: T,
+        event: impl Fn(&T) -> Event + 'static,
+    ) -> (ObservableWriter<T>, ObservableReader<T>) {
+        let push = self.queue.weak_push();
+        observable::observable(initial, move |v| push(Control::Event(event(v))))
     }
 
     /// Add a background worker task.
@@ -350,12 +636,134 @@ where
         Event: 'static + Send,
         Error: 'static + Send,
     {
+        self.spawn_task(TaskMeta::new(None), task)
+    }
+
+    /// Add a named background worker task.
+    ///
+    /// The name is available via [last_error_meta](Self::last_error_meta)
+    /// if the task fails.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no worker-thread support is configured.
+    #[inline]
+    pub fn spawn_named(
+        &self,
+        name: &str,
+        task: impl FnOnce(Cancel, &Sender<Result<Control<Event>, Error>>) -> Result<Control<Event>, Error>
+            + Send
+            + 'static,
+    ) -> Result<Cancel, SendError<()>>
+    where
+        Event: 'static + Send,
+        Error: 'static + Send,
+    {
+        self.spawn_task(TaskMeta::new(Some(name)), task)
+    }
+
+    fn spawn_task(
+        &self,
+        meta: TaskMeta,
+        task: impl FnOnce(Cancel, &Sender<Result<Control<Event>, Error>>) -> Result<Control<Event>, Error>
+            + Send
+            + 'static,
+    ) -> Result<Cancel, SendError<()>> {
         self.tasks
             .as_ref()
             .expect(
                 "No thread-pool configured. In main() add RunConfig::default()?.poll(PollTasks)",
             )
-            .spawn(Box::new(task))
+            .spawn(meta, Box::new(task))
+    }
+
+    /// Run a background task periodically.
+    ///
+    /// The `factory` creates the task for each run. The first run
+    /// starts after one interval. If a run is due while the previous
+    /// one is still going, `overlap` decides what happens.
+    ///
+    /// ```rust ignore
+    /// let client = self.client.clone();
+    /// self.refresh = Some(ctx.spawn_periodic(
+    ///     Duration::from_secs(30),
+    ///     Overlap::Skip,
+    ///     move || {
+    ///         let client = client.clone();
+    ///         move |cancel, _send| {
+    ///             let data = client.fetch(&cancel)?;
+    ///             Ok(Control::Event(AppEvent::Refreshed(data)))
+    ///         }
+    ///     },
+    /// ));
+    ///
+    /// // later
+    /// if let Some(refresh) = self.refresh.take() {
+    ///     refresh.stop();
+    /// }
+    /// ```
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no worker-thread support is configured.
+    pub fn spawn_periodic<T>(
+        &self,
+        interval: Duration,
+        overlap: Overlap,
+        factory: impl FnMut() -> T + 'static,
+    ) -> PeriodicHandle
+    where
+        T: FnOnce(Cancel, &Sender<Result<Control<Event>, Error>>) -> Result<Control<Event>, Error>
+            + Send
+            + 'static,
+    {
+        self.spawn_periodic_task(None, interval, overlap, factory)
+    }
+
+    /// Run a named background task periodically.
+    ///
+    /// Each run gets the name as its [TaskMeta].
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no worker-thread support is configured.
+    pub fn spawn_periodic_named<T>(
+        &self,
+        name: &str,
+        interval: Duration,
+        overlap: Overlap,
+        factory: impl FnMut() -> T + 'static,
+    ) -> PeriodicHandle
+    where
+        T: FnOnce(Cancel, &Sender<Result<Control<Event>, Error>>) -> Result<Control<Event>, Error>
+            + Send
+            + 'static,
+    {
+        self.spawn_periodic_task(Some(name), interval, overlap, factory)
+    }
+
+    fn spawn_periodic_task<T>(
+        &self,
+        name: Option<&str>,
+        interval: Duration,
+        overlap: Overlap,
+        mut factory: impl FnMut() -> T + 'static,
+    ) -> PeriodicHandle
+    where
+        T: FnOnce(Cancel, &Sender<Result<Control<Event>, Error>>) -> Result<Control<Event>, Error>
+            + Send
+            + 'static,
+    {
+        assert!(
+            self.tasks.is_some(),
+            "No thread-pool configured. In main() add RunConfig::default()?.poll(PollTasks)"
+        );
+        self.periodic.push(
+            name,
+            interval,
+            overlap,
+            Box::new(move || Box::new(factory())),
+        )
     }
 
     /// Spawn a future in the executor.
@@ -366,7 +774,21 @@ where
         F: Future<Output = Result<Control<Event>, Error>> + Send + 'static,
     {
         self.tokio.as_ref().expect("No tokio runtime is configured. In main() add RunConfig::default()?.poll(PollTokio::new(rt))")
-            .spawn(Box::new(future))
+            .spawn(TaskMeta::new(None), Box::new(future))
+    }
+
+    /// Spawn a named future in the executor.
+    ///
+    /// The name is available via [last_error_meta](Self::last_error_meta)
+    /// if the task fails.
+    #[inline]
+    #[cfg(feature = "async")]
+    pub fn spawn_async_named<F>(&self, name: &str, future: F) -> AbortHandle
+    where
+        F: Future<Output = Result<Control<Event>, Error>> + Send + 'static,
+    {
+        self.tokio.as_ref().expect("No tokio runtime is configured. In main() add RunConfig::default()?.poll(PollTokio::new(rt))")
+            .spawn(TaskMeta::new(Some(name)), Box::new(future))
     }
 
     /// Spawn a future in the executor.
@@ -379,8 +801,134 @@ where
         F: Future<Output = Result<Control<Event>, Error>> + Send + 'static,
     {
         let rt = self.tokio.as_ref().expect("No tokio runtime is configured. In main() add RunConfig::default()?.poll(PollTokio::new(rt))");
-        let future = cr_future(rt.sender());
-        rt.spawn(Box::new(future))
+        let meta = TaskMeta::new(None);
+        let future = cr_future(rt.sender(meta.clone()));
+        rt.spawn(meta, Box::new(future))
+    }
+
+    /// Answer an interaction with some background work.
+    ///
+    /// Runs `work` with the thread-pool. When it is done,
+    /// `done` is called with the result in the event-loop, and
+    /// its [Control] is processed like any other result. Errors
+    /// of the work go to [AppState::error].
+    ///
+    /// This happens only if the `token` is still current. If the
+    /// [PendingOp](rat_widget::event::util::PendingOp) has started
+    /// another operation, was canceled or has been dropped, the
+    /// work is canceled and its result is dropped.
+    ///
+    /// If the work ends without a result, e.g. because it
+    /// panicked, an error goes to [AppState::error].
+    ///
+    /// The file-path autocomplete:
+    ///
+    /// ```rust ignore
+    /// // in the state
+    /// pub completion: PendingOp,
+    ///
+    /// // each change of the path input starts a new lookup.
+    /// // any lookup still running becomes stale.
+    /// let token = self.completion.start();
+    /// let prefix = PathBuf::from(self.path.text());
+    /// ctx.defer(
+    ///     token,
+    ///     move |cancel| list_matching(&prefix, &cancel),
+    ///     |names| Control::Event(AppEvent::Completions(names)),
+    /// )?;
+    ///
+    /// // only the answer for the current input arrives.
+    /// AppEvent::Completions(names) => {
+    ///     self.popup.set_items(names);
+    ///     Control::Changed
+    /// }
+    ///
+    /// // the popup is closed, nobody wants the answer.
+    /// self.completion.cancel();
+    /// ```
+    ///
+    /// __Panic__
+    ///
+    /// Panics if no worker-thread support is configured.
+    pub fn defer<T>(
+        &self,
+        token: OpToken,
+        work: impl FnOnce(Cancel) -> Result<T, Error> + Send + 'static,
+        done: impl FnOnce(T) -> Control<Event> + 'static,
+    ) -> Result<Cancel, SendError<()>>
+    where
+        T: Send + 'static,
+        Error: From<std::io::Error>,
+    {
+        let (send, result) = bounded(1);
+        let cancel = self.spawn(move |cancel, _| {
+            _ = send.send(work(cancel));
+            // wakes up the event-loop
+            Ok(Control::Continue)
+        })?;
+        let stale = cancel.clone();
+        self.push_deferred(token, result, move || stale.cancel(), done);
+        Ok(cancel)
+    }
+
+    /// Answer an interaction with a future.
+    ///
+    /// Same as [defer](AppContext::defer), but runs the future
+    /// in the executor. A stale future is aborted.
+    #[cfg(feature = "async")]
+    pub fn defer_async<T, F>(
+        &self,
+        token: OpToken,
+        future: F,
+        done: impl FnOnce(T) -> Control<Event> + 'static,
+    ) -> AbortHandle
+    where
+        T: Send + 'static,
+        F: Future<Output = Result<T, Error>> + Send + 'static,
+        Error: From<std::io::Error>,
+    {
+        let (send, result) = bounded(1);
+        let abort = self.spawn_async(async move {
+            _ = send.send(future.await);
+            // wakes up the event-loop
+            Ok(Control::Continue)
+        });
+        let stale = abort.clone();
+        self.push_deferred(token, result, move || stale.abort(), done);
+        abort
+    }
+
+    fn push_deferred<T: 'static>(
+        &self,
+        token: OpToken,
+        result: Receiver<Result<T, Error>>,
+        stale: impl Fn() + 'static,
+        done: impl FnOnce(T) -> Control<Event> + 'static,
+    ) where
+        Error: From<std::io::Error>,
+    {
+        let mut done = Some(done);
+        self.deferred.push(Box::new(move || {
+            if !token.is_current() {
+                stale();
+                return Some(Ok(Control::Continue));
+            }
+            let r = match result.try_recv() {
+                Ok(r) => r,
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => {
+                    // the work is gone without a result.
+                    token.finish();
+                    return Some(Err(std::io::Error::other(
+                        "deferred work ended without a result",
+                    )
+                    .into()));
+                }
+            };
+            token.finish();
+            let done = done.take().expect("done");
+            Some(r.map(done))
+        }));
     }
 
     /// Queue additional results.
@@ -390,11 +938,217 @@ where
     }
 
     /// Queue an error.
+    ///
+    /// Errors queued here have no [TaskMeta].
     #[inline]
     pub fn queue_err(&self, err: Error) {
         self.queue.push(Err(err));
     }
 
+    /// The background task that produced the error currently
+    /// handled by [AppState::error].
+    ///
+    /// This is None for errors from any other source.
+    #[inline]
+    pub fn last_error_meta(&self) -> Option<&TaskMeta> {
+        self.last_error_meta.as_ref()
+    }
+
+    /// The error currently handled by [AppState::error] is a
+    /// [recoverable](PollError::Recoverable) failure of an event-source.
+    /// Returns the name of the source, see [PollStats::name].
+    ///
+    /// The source is polled again after a short backoff.
+    /// This is None for errors from any other source.
+    #[inline]
+    pub fn poll_warning(&self) -> Option<&'static str> {
+        self.poll_warning
+    }
+
+    /// Take the [feedback](rat_widget::event::feedback) messages
+    /// sent by event-handlers, oldest first.
+    ///
+    /// The framework collects the messages after each event.
+    /// This also takes the messages of the event currently handled,
+    /// so it can be called at the end of [AppState::event] to
+    /// show them in a status line.
+    pub fn take_feedback(&mut self) -> Vec<Feedback> {
+        self.collect_feedback();
+        mem::take(&mut self.feedback)
+    }
+
+    /// Move the pending feedback messages to the context.
+    pub(crate) fn collect_feedback(&mut self) {
+        if self.feedback_capacity == 0 {
+            return;
+        }
+        self.feedback.extend(rat_widget::event::take_feedback());
+        if self.feedback.len() > self.feedback_capacity {
+            let n = self.feedback.len() - self.feedback_capacity;
+            self.feedback.drain(..n);
+        }
+    }
+
+    /// Terminal window has the focus.
+    ///
+    /// This is always true, unless
+    /// [RunConfig::track_terminal_focus] is set.
+    #[inline]
+    pub fn terminal_focused(&self) -> bool {
+        self.terminal_focused
+    }
+
+    /// Block all keyboard and mouse input.
+    ///
+    /// While set, [PollCrossterm](crate::poll::PollCrossterm) drops
+    /// key, mouse and paste events. Timers, task results and
+    /// app events are still delivered, and rendering continues.
+    ///
+    /// Ctrl+C and the keys set with [RunConfig::abort_key] are
+    /// never blocked, so the user can always get out.
+    #[inline]
+    pub fn set_input_blocked(&mut self, blocked: bool) {
+        crate::poll::crossterm::set_input_blocked(blocked);
+    }
+
+    /// Input is blocked.
+    #[inline]
+    pub fn input_blocked(&self) -> bool {
+        crate::poll::crossterm::is_input_blocked()
+    }
+
+    /// Color capabilities of the terminal.
+    ///
+    /// Detected by the [Terminal](crate::terminal::Terminal) or set with
+    /// [RunConfig::color_depth].
+    #[inline]
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    /// Size of the terminal as of the last render.
+    ///
+    /// Zero before the first render. See also [RunConfig::min_size].
+    #[inline]
+    pub fn terminal_size(&self) -> Size {
+        self.terminal_size
+    }
+
+    /// Run a nested event-loop for a modal prompt.
+    ///
+    /// This renders the last frame of the application dimmed and
+    /// calls `render` for the modal content on top of it. Then
+    /// it waits for crossterm events and calls `event` for each
+    /// of them until it returns a value.
+    ///
+    /// ```rust ignore
+    /// let overwrite = ctx.run_modal(
+    ///     &mut prompt,
+    ///     |area, buf, prompt, _ctx| {
+    ///         Prompt::new("Overwrite file? y/n").render(area, buf, prompt);
+    ///         Ok(())
+    ///     },
+    ///     |event, _prompt, _ctx| {
+    ///         Ok(match event {
+    ///             ct_event!(key press 'y') => Some(true),
+    ///             ct_event!(key press 'n') | ct_event!(keycode press Esc) => Some(false),
+    ///             _ => None,
+    ///         })
+    ///     },
+    /// )?;
+    /// ```
+    ///
+    /// Only crossterm events are delivered while the prompt is open.
+    /// Timers, task results and any [queued](AppContext::queue) controls
+    /// are processed by the main loop after the prompt returns.
+    /// The application state stays borrowed by the running handler,
+    /// so the prompt can only use its own state and the context.
+    pub fn run_modal<S, T>(
+        &mut self,
+        state: &mut S,
+        mut render: impl FnMut(
+            Rect,
+            &mut Buffer,
+            &mut S,
+            &mut RenderContext<'_, Global>,
+        ) -> Result<(), Error>,
+        mut event: impl FnMut(
+            &crossterm::event::Event,
+            &mut S,
+            &mut AppContext<'_, Global, Event, Error>,
+        ) -> Result<Option<T>, Error>,
+    ) -> Result<T, Error>
+    where
+        Error: From<std::io::Error>,
+    {
+        framework::modal::run_modal(self, state, &mut render, &mut event)
+    }
+
+    /// Insert lines above an inline viewport.
+    ///
+    /// The lines are rendered into a buffer of the given height
+    /// and the width of the viewport, and then end up in the
+    /// scrollback of the terminal. Use this for log output of
+    /// an inline TUI. Does nothing for a fullscreen terminal.
+    ///
+    /// This clears the viewport, return [Control::Changed]
+    /// to render it again.
+    ///
+    /// See [RunConfig::inline](crate::RunConfig::inline).
+    pub fn insert_before(
+        &mut self,
+        height: u16,
+        mut render: impl FnMut(&mut Buffer),
+    ) -> Result<(), Error>
+    where
+        Error: From<std::io::Error>,
+    {
+        self.modal
+            .term
+            .borrow_mut()
+            .insert_before(height, &mut render)
+    }
+
+    /// Insert some text above an inline viewport.
+    ///
+    /// See [AppContext::insert_before].
+    pub fn insert_text<'t>(&mut self, text: impl Into<Text<'t>>) -> Result<(), Error>
+    where
+        Error: From<std::io::Error>,
+    {
+        let text = text.into();
+        self.insert_before(text.height() as u16, |buf| {
+            (&text).render(buf.area, buf);
+        })
+    }
+
+    /// Diagnostics for the event-sources, in registration order.
+    pub fn poll_stats(&self) -> Vec<PollStats> {
+        self.poll_stats.borrow().clone()
+    }
+
+    /// Number of results waiting in the control-queue.
+    pub fn queue_len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Number of background tasks that are queued or running.
+    ///
+    /// Counts both the thread-pool and the async tasks.
+    pub fn task_count(&self) -> usize {
+        let n = self.tasks.as_ref().map(|v| v.len()).unwrap_or_default();
+        #[cfg(feature = "async")]
+        let n = n + self.tokio.as_ref().map(|v| v.len()).unwrap_or_default();
+        n
+    }
+
+    /// Timing and load of the event-loop.
+    pub fn frame_stats(&self) -> FrameStats {
+        self.frame_times
+            .borrow()
+            .stats(self.count, self.queue_len(), self.task_count())
+    }
+
     /// Access the focus-field.
     ///
     /// __Panic__
@@ -0,0 +1,254 @@
+//!
+//! Compose an application from independent modules.
+//!
+
+use crate::{AppContext, Control, RenderContext};
+use rat_widget::event::ConsumedEvent;
+use rat_widget::focus::{Focus, FocusBuilder};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::fmt::{Debug, Formatter};
+
+/// One part of an application.
+///
+/// A module owns its widget states and does its own rendering
+/// and event-handling. Add it to a [ModuleHost] to get it
+/// wired into the application.
+#[allow(unused_variables)]
+pub trait Module<Global, Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    /// Initialize the module. Called by [ModuleHost::init].
+    fn init(&mut self, ctx: &mut AppContext<'_, Global, Event, Error>) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Render the module.
+    fn render(
+        &mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        ctx: &mut RenderContext<'_, Global>,
+    ) -> Result<(), Error>;
+
+    /// Handle an event.
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, Global, Event, Error>,
+    ) -> Result<Control<Event>, Error> {
+        Ok(Control::Continue)
+    }
+
+    /// Add the widgets of the module to the focus.
+    fn build(&self, builder: &mut FocusBuilder) {}
+}
+
+type Filter<Event> = Box<dyn Fn(&Event) -> bool>;
+
+struct Mounted<Global, Event, Error> {
+    module: Box<dyn Module<Global, Event, Error>>,
+    enabled: bool,
+    filter: Option<Filter<Event>>,
+}
+
+/// Owns a list of [Module]s and forwards everything to them.
+///
+/// * Events go to the modules in order, until one of them
+///   consumes the event.
+/// * A module can be mounted with a filter. Events that don't
+///   pass the filter skip the module.
+/// * A disabled module neither renders nor handles events
+///   and is not part of the focus.
+///
+/// ```rust ignore
+/// // construct
+/// let mut modules = ModuleHost::new();
+/// let edit = modules.add(EditModule::new());
+/// let detail = modules.add_filtered(DetailModule::new(), |e| matches!(e, AppEvent::Detail(_)));
+/// modules.set_enabled(detail, false);
+///
+/// // AppState::init
+/// modules.init(ctx)?;
+/// ctx.focus = Some(modules.focus(ctx.focus.take()));
+///
+/// // AppState::event
+/// flow!(state.modules.event(event, ctx)?);
+///
+/// // AppWidget::render
+/// state.modules.render(area, buf, ctx)?;
+/// ```
+pub struct ModuleHost<Global, Event, Error> {
+    modules: Vec<Mounted<Global, Event, Error>>,
+}
+
+impl<Global, Event, Error> Default for ModuleHost<Global, Event, Error> {
+    fn default() -> Self {
+        Self {
+            modules: Default::default(),
+        }
+    }
+}
+
+impl<Global, Event, Error> Debug for ModuleHost<Global, Event, Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModuleHost")
+            .field(
+                "enabled",
+                &self.modules.iter().map(|v| v.enabled).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<Global, Event, Error> ModuleHost<Global, Event, Error>
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a module. Returns the index of the module.
+    pub fn add(&mut self, module: impl Module<Global, Event, Error> + 'static) -> usize {
+        self.modules.push(Mounted {
+            module: Box::new(module),
+            enabled: true,
+            filter: None,
+        });
+        self.modules.len() - 1
+    }
+
+    /// Add a module that only sees the events that pass the filter.
+    /// Returns the index of the module.
+    pub fn add_filtered(
+        &mut self,
+        module: impl Module<Global, Event, Error> + 'static,
+        filter: impl Fn(&Event) -> bool + 'static,
+    ) -> usize {
+        self.modules.push(Mounted {
+            module: Box::new(module),
+            enabled: true,
+            filter: Some(Box::new(filter)),
+        });
+        self.modules.len() - 1
+    }
+
+    /// Number of modules.
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Any modules.
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// Enable/disable a module.
+    ///
+    /// The focus must be rebuilt after this.
+    pub fn set_enabled(&mut self, n: usize, enabled: bool) {
+        self.modules[n].enabled = enabled;
+    }
+
+    /// Module is enabled.
+    pub fn is_enabled(&self, n: usize) -> bool {
+        self.modules[n].enabled
+    }
+
+    /// Access a module.
+    pub fn module(&self, n: usize) -> &dyn Module<Global, Event, Error> {
+        self.modules[n].module.as_ref()
+    }
+
+    /// Access a module.
+    pub fn module_mut(&mut self, n: usize) -> &mut dyn Module<Global, Event, Error> {
+        self.modules[n].module.as_mut()
+    }
+
+    /// Initialize all modules, enabled or not.
+    pub fn init(&mut self, ctx: &mut AppContext<'_, Global, Event, Error>) -> Result<(), Error> {
+        for v in self.modules.iter_mut() {
+            v.module.init(ctx)?;
+        }
+        Ok(())
+    }
+
+    /// Render all enabled modules to the same area.
+    ///
+    /// Use [render_module](Self::render_module) if the modules
+    /// need different areas.
+    pub fn render(
+        &mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        ctx: &mut RenderContext<'_, Global>,
+    ) -> Result<(), Error> {
+        for v in self.modules.iter_mut() {
+            if v.enabled {
+                v.module.render(area, buf, ctx)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Render one module, if it is enabled.
+    pub fn render_module(
+        &mut self,
+        n: usize,
+        area: Rect,
+        buf: &mut Buffer,
+        ctx: &mut RenderContext<'_, Global>,
+    ) -> Result<(), Error> {
+        let v = &mut self.modules[n];
+        if v.enabled {
+            v.module.render(area, buf, ctx)?;
+        }
+        Ok(())
+    }
+
+    /// Forward the event to the enabled modules.
+    ///
+    /// Returns the result of the module that consumed the event,
+    /// or Control::Continue.
+    pub fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, Global, Event, Error>,
+    ) -> Result<Control<Event>, Error> {
+        for v in self.modules.iter_mut() {
+            if !v.enabled {
+                continue;
+            }
+            if let Some(filter) = &v.filter {
+                if !filter(event) {
+                    continue;
+                }
+            }
+            let r = v.module.event(event, ctx)?;
+            if r.is_consumed() {
+                return Ok(r);
+            }
+        }
+        Ok(Control::Continue)
+    }
+
+    /// Add the widgets of all enabled modules to the focus.
+    pub fn build(&self, builder: &mut FocusBuilder) {
+        for v in self.modules.iter() {
+            if v.enabled {
+                v.module.build(builder);
+            }
+        }
+    }
+
+    /// Build the focus for all enabled modules.
+    pub fn focus(&self, old: Option<Focus>) -> Focus {
+        let mut builder = FocusBuilder::new(old);
+        self.build(&mut builder);
+        builder.build()
+    }
+}
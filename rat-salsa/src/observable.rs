@@ -0,0 +1,231 @@
+//!
+//! Observable values shared between parts of the application.
+//!
+//! Create one with [AppContext::observable](crate::AppContext::observable).
+//! The [ObservableWriter] sets the value and queues a
+//! [Control::Changed], or an application event if created with
+//! [AppContext::observable_event](crate::AppContext::observable_event).
+//! Any number of [ObservableReader]s can read the value during
+//! event-handling and rendering, and check if it changed since
+//! they last looked.
+//!
+//! ```rust ignore
+//! let (project, project_reader) = ctx.observable(PathBuf::new());
+//! state.sidebar.project = project_reader.clone();
+//! state.statusline.project = project_reader;
+//!
+//! // somewhere else
+//! project.set(path);
+//!
+//! // in the sidebar
+//! if self.project.changed_since(self.project_gen) {
+//!     self.project_gen = self.project.generation();
+//!     self.reload(&self.project.get());
+//! }
+//! ```
+//!
+//! Background tasks use a [SendWriter]. It hands the value over to
+//! the event-loop and sends a [Control::Changed] via the result
+//! channel of the task. The readers see the value with the
+//! next access.
+//!
+use crate::Control;
+use crossbeam::channel::{SendError, Sender};
+use std::cell::{Cell, Ref, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+struct ObservableInner<T> {
+    value: RefCell<T>,
+    generation: Cell<u64>,
+    notify: Box<dyn Fn(&T)>,
+    /// Value set by a SendWriter.
+    remote: RefCell<Option<Arc<Mutex<Option<T>>>>>,
+}
+
+impl<T> ObservableInner<T> {
+    /// Take over a value set by a SendWriter.
+    fn sync(&self) {
+        let remote = self.remote.borrow();
+        let Some(remote) = remote.as_ref() else {
+            return;
+        };
+        let Ok(mut value) = self.value.try_borrow_mut() else {
+            // currently borrowed. next time.
+            return;
+        };
+        let remote = remote.lock().expect("observable").take();
+        if let Some(v) = remote {
+            *value = v;
+            self.generation.set(self.generation.get() + 1);
+        }
+    }
+}
+
+/// Sets the value of an observable.
+pub struct ObservableWriter<T> {
+    inner: Rc<ObservableInner<T>>,
+}
+
+/// Reads the value of an observable.
+pub struct ObservableReader<T> {
+    inner: Rc<ObservableInner<T>>,
+}
+
+/// Sets the value of an observable from a background task.
+pub struct SendWriter<T> {
+    remote: Arc<Mutex<Option<T>>>,
+}
+
+pub(crate) fn observable<T: 'static>(
+    initial: T,
+    notify: impl Fn(&T) + 'static,
+) -> (ObservableWriter<T>, ObservableReader<T>) {
+    let inner = Rc::new(ObservableInner {
+        value: RefCell::new(initial),
+        generation: Cell::new(0),
+        notify: Box::new(notify),
+        remote: RefCell::new(None),
+    });
+    (
+        ObservableWriter {
+            inner: inner.clone(),
+        },
+        ObservableReader { inner },
+    )
+}
+
+impl<T> ObservableWriter<T> {
+    /// Set a new value and notify the event-loop.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if a reader holds a [borrow](ObservableReader::borrow).
+    pub fn set(&self, value: T) {
+        self.update(|v| *v = value);
+    }
+
+    /// Modify the value and notify the event-loop.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if a reader holds a [borrow](ObservableReader::borrow).
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        self.inner.sync();
+        f(&mut self.inner.value.borrow_mut());
+        self.inner.generation.set(self.inner.generation.get() + 1);
+        (self.inner.notify)(&self.inner.value.borrow());
+    }
+
+    /// Create another reader.
+    pub fn reader(&self) -> ObservableReader<T> {
+        ObservableReader {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Create a writer for background tasks.
+    pub fn sender(&self) -> SendWriter<T>
+    where
+        T: Send,
+    {
+        let mut remote = self.inner.remote.borrow_mut();
+        let remote = remote.get_or_insert_with(|| Arc::new(Mutex::new(None)));
+        SendWriter {
+            remote: remote.clone(),
+        }
+    }
+}
+
+impl<T> ObservableReader<T> {
+    /// Borrow the current value.
+    ///
+    /// Don't hold on to it while setting a new value.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        self.inner.sync();
+        self.inner.value.borrow()
+    }
+
+    /// Copy of the current value.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.borrow().clone()
+    }
+
+    /// Generation of the value. Starts with 0 and counts
+    /// up with each change.
+    pub fn generation(&self) -> u64 {
+        self.inner.sync();
+        self.inner.generation.get()
+    }
+
+    /// The value changed after the given generation.
+    pub fn changed_since(&self, generation: u64) -> bool {
+        self.generation() > generation
+    }
+}
+
+impl<T: Send> SendWriter<T> {
+    /// Set a new value.
+    ///
+    /// The value is handed over to the event-loop, and a
+    /// [Control::Changed] is sent with the result channel of
+    /// the background task. If the task sets more than one
+    /// value before the event-loop looks, only the last one
+    /// is seen.
+    pub fn set<Event, Error>(
+        &self,
+        value: T,
+        channel: &Sender<Result<Control<Event>, Error>>,
+    ) -> Result<(), SendError<Result<Control<Event>, Error>>>
+    where
+        Event: 'static + Send,
+        Error: 'static + Send,
+    {
+        *self.remote.lock().expect("observable") = Some(value);
+        channel.send(Ok(Control::Changed))
+    }
+}
+
+impl<T> Clone for ObservableReader<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Clone for SendWriter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            remote: self.remote.clone(),
+        }
+    }
+}
+
+impl<T: Debug> Debug for ObservableWriter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObservableWriter")
+            .field("value", &self.inner.value)
+            .field("generation", &self.inner.generation)
+            .finish()
+    }
+}
+
+impl<T: Debug> Debug for ObservableReader<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObservableReader")
+            .field("value", &self.inner.value)
+            .field("generation", &self.inner.generation)
+            .finish()
+    }
+}
+
+impl<T> Debug for SendWriter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SendWriter").finish()
+    }
+}
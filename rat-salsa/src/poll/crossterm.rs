@@ -1,11 +1,121 @@
-use crate::{Control, PollEvents};
+use crate::{Control, PollError, PollEvents};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::io;
 use std::time::Duration;
 
 /// Processes crossterm events.
+///
+/// A pending resize is delivered before the next render,
+/// even if other events are queued.
+///
+/// While the input is blocked with
+/// [AppContext::set_input_blocked](crate::AppContext::set_input_blocked)
+/// all key, mouse and paste events are dropped. Ctrl+C and the
+/// keys set with [RunConfig::abort_key](crate::RunConfig::abort_key)
+/// are still delivered. The same happens while the terminal is below
+/// the [minimum size](crate::RunConfig::min_size).
+///
+/// If reading from the terminal fails, the error is reported
+/// by the next poll(). Interrupts and timeouts are
+/// [recoverable](PollError::Recoverable), anything else
+/// (the terminal has been closed, ssh dropped the connection)
+/// is [fatal](PollError::Fatal) and ends the event-loop.
 #[derive(Debug)]
 pub struct PollCrossterm;
 
+thread_local! {
+    /// Events read ahead while looking for a resize.
+    static PENDING: RefCell<VecDeque<crossterm::event::Event>> = const { RefCell::new(VecDeque::new()) };
+    /// Last terminal focus change.
+    static FOCUS_CHANGE: Cell<Option<bool>> = const { Cell::new(None) };
+    /// Drop input events.
+    static INPUT_BLOCKED: Cell<bool> = const { Cell::new(false) };
+    /// Terminal is below the minimum size.
+    static TOO_SMALL: Cell<bool> = const { Cell::new(false) };
+    /// A resize has been read.
+    static RESIZED: Cell<bool> = const { Cell::new(false) };
+    /// Keys that pass while the input is blocked.
+    static ABORT_KEYS: RefCell<Vec<(KeyCode, KeyModifiers)>> = const { RefCell::new(Vec::new()) };
+    /// Error from reading, reported by the next poll.
+    static FAILED: RefCell<Option<io::Error>> = const { RefCell::new(None) };
+}
+
+pub(crate) fn set_input_blocked(blocked: bool) {
+    INPUT_BLOCKED.set(blocked);
+}
+
+pub(crate) fn is_input_blocked() -> bool {
+    INPUT_BLOCKED.get()
+}
+
+pub(crate) fn set_too_small(too_small: bool) {
+    TOO_SMALL.set(too_small);
+}
+
+/// Returns if a resize has been read since the last call.
+pub(crate) fn take_resized() -> bool {
+    RESIZED.take()
+}
+
+/// Forget a read error of a previous event-loop.
+pub(crate) fn clear_failed() {
+    FAILED.set(None);
+}
+
+pub(crate) fn set_abort_keys(keys: Vec<(KeyCode, KeyModifiers)>) {
+    ABORT_KEYS.set(keys);
+}
+
+/// Read the next crossterm event, waiting at most `timeout`.
+///
+/// Used by nested event-loops. Takes events that have been
+/// read ahead first.
+pub(crate) fn read_timeout(timeout: Duration) -> std::io::Result<Option<crossterm::event::Event>> {
+    let event = if let Some(event) = PENDING.with_borrow_mut(|v| v.pop_front()) {
+        event
+    } else if crossterm::event::poll(timeout)? {
+        crossterm::event::read()?
+    } else {
+        return Ok(None);
+    };
+    track_focus(&event);
+    Ok(Some(event))
+}
+
+/// Is this event dropped while the input is blocked?
+fn is_blocked(event: &crossterm::event::Event) -> bool {
+    match event {
+        crossterm::event::Event::Key(KeyEvent {
+            code, modifiers, ..
+        }) => {
+            if *code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL) {
+                false
+            } else {
+                ABORT_KEYS.with_borrow(|v| !v.contains(&(*code, *modifiers)))
+            }
+        }
+        crossterm::event::Event::Mouse(_) => true,
+        crossterm::event::Event::Paste(_) => true,
+        _ => false,
+    }
+}
+
+/// Add some context to the io-error.
+fn terminal_error(err: io::Error) -> io::Error {
+    io::Error::new(err.kind(), format!("terminal input failed: {}", err))
+}
+
+fn track_focus(event: &crossterm::event::Event) {
+    match event {
+        crossterm::event::Event::FocusGained => FOCUS_CHANGE.set(Some(true)),
+        crossterm::event::Event::FocusLost => FOCUS_CHANGE.set(Some(false)),
+        _ => {}
+    }
+}
+
 impl<Event, Error> PollEvents<Event, Error> for PollCrossterm
 where
     Event: 'static + Send + From<crossterm::event::Event>,
@@ -15,11 +125,73 @@ where
         self
     }
 
-    fn poll(&mut self) -> Result<bool, Error> {
-        Ok(crossterm::event::poll(Duration::from_millis(0))?)
+    fn poll(&mut self) -> Result<bool, PollError<Error>> {
+        if PENDING.with_borrow(|v| !v.is_empty()) {
+            return Ok(true);
+        }
+        if let Some(err) = FAILED.take() {
+            return Err(PollError::from_io(err));
+        }
+        crossterm::event::poll(Duration::from_millis(0))
+            .map_err(|e| PollError::from_io(terminal_error(e)))
     }
 
     fn read(&mut self) -> Result<Control<Event>, Error> {
-        Ok(crossterm::event::read().map(|v| Control::Event(v.into()))?)
+        let event = if let Some(event) = PENDING.with_borrow_mut(|v| v.pop_front()) {
+            event
+        } else {
+            match crossterm::event::read() {
+                Ok(v) => v,
+                Err(e) => {
+                    FAILED.set(Some(terminal_error(e)));
+                    return Ok(Control::Continue);
+                }
+            }
+        };
+        track_focus(&event);
+        if matches!(event, crossterm::event::Event::Resize(_, _)) {
+            RESIZED.set(true);
+        }
+        if (is_input_blocked() || TOO_SMALL.get()) && is_blocked(&event) {
+            return Ok(Control::Continue);
+        }
+        Ok(Control::Event(event.into()))
+    }
+
+    fn is_input(&self) -> bool {
+        true
+    }
+
+    /// Returns the last FocusGained/FocusLost read, if any.
+    fn read_focus(&mut self) -> Option<bool> {
+        FOCUS_CHANGE.take()
+    }
+
+    fn read_urgent(&mut self) -> Result<Option<Control<Event>>, Error> {
+        loop {
+            let event = match crossterm::event::poll(Duration::from_millis(0)) {
+                Ok(true) => crossterm::event::read(),
+                Ok(false) => break,
+                Err(e) => Err(e),
+            };
+            match event {
+                Ok(event) => PENDING.with_borrow_mut(|v| v.push_back(event)),
+                Err(e) => {
+                    FAILED.set(Some(terminal_error(e)));
+                    break;
+                }
+            }
+        }
+        // only the last resize counts.
+        let resize = PENDING.with_borrow_mut(|v| {
+            let resize = v
+                .iter()
+                .rev()
+                .find(|e| matches!(e, crossterm::event::Event::Resize(_, _)))
+                .cloned();
+            v.retain(|e| !matches!(e, crossterm::event::Event::Resize(_, _)));
+            resize
+        });
+        Ok(resize.map(|v| Control::Event(v.into())))
     }
 }
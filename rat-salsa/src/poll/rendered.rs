@@ -1,6 +1,23 @@
 use crate::rendered::RenderedEvent;
-use crate::{Control, PollEvents};
+use crate::{Control, PollError, PollEvents};
 use std::any::Any;
+use std::cell::Cell;
+
+thread_local! {
+    /// Info about the last render.
+    static RENDERED: Cell<RenderedEvent> = const {
+        Cell::new(RenderedEvent {
+            count: 0,
+            duration: std::time::Duration::ZERO,
+            full_redraw: false,
+        })
+    };
+}
+
+/// Set by the framework after each render.
+pub(crate) fn set_rendered(rendered: RenderedEvent) {
+    RENDERED.set(rendered);
+}
 
 /// Sends an event after a render of the UI.
 #[derive(Debug, Default)]
@@ -15,12 +32,12 @@ where
         self
     }
 
-    fn poll(&mut self) -> Result<bool, Error> {
+    fn poll(&mut self) -> Result<bool, PollError<Error>> {
         // doesn't poll. it's triggered by a repaint.
         Ok(false)
     }
 
     fn read(&mut self) -> Result<Control<Event>, Error> {
-        Ok(Control::Event(RenderedEvent.into()))
+        Ok(Control::Event(RENDERED.get().into()))
     }
 }
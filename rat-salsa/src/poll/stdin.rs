@@ -0,0 +1,196 @@
+use crate::{Control, PollError, PollEvents};
+use crossbeam::channel::{bounded, Receiver, TryRecvError};
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::io::{BufRead, BufReader, IsTerminal, Read};
+use std::{io, thread};
+
+type ConvertFn<Event> = Box<dyn FnMut(String) -> Event + Send>;
+
+/// Reads piped input from stdin.
+///
+/// For something like `mycmd --follow | mytui`. A background
+/// thread reads stdin record by record and converts each record
+/// to an application event.
+///
+/// ```rust ignore
+/// RunConfig::default()?
+///     .poll(PollCrossterm)
+///     .poll(PollStdin::new(|line| MyEvent::Line(line)).eof(MyEvent::Eof))
+/// ```
+///
+/// Records are lines by default, the line-break is removed.
+/// Use [delimiter](PollStdin::delimiter) for other records.
+/// Invalid UTF-8 is replaced.
+///
+/// Only a limited number of records is buffered. If the
+/// application is slower than the input, the reader thread
+/// blocks until there is room again, nothing is dropped.
+///
+/// The keyboard is read from the controlling terminal in that
+/// case (`/dev/tty` or `CONIN$`), crossterm does this by itself
+/// if stdin is not a terminal. If stdin is a terminal, there is
+/// no piped input and this source only sends the eof event.
+///
+/// The reader thread starts with the first poll.
+pub struct PollStdin<Event>
+where
+    Event: 'static + Send,
+{
+    reader: Option<Box<dyn Read + Send>>,
+    convert: Option<ConvertFn<Event>>,
+    delimiter: u8,
+    capacity: usize,
+    eof: Option<Event>,
+
+    recv: Option<Receiver<io::Result<Event>>>,
+}
+
+impl<Event> Debug for PollStdin<Event>
+where
+    Event: 'static + Send,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollStdin")
+            .field("delimiter", &self.delimiter)
+            .field("capacity", &self.capacity)
+            .field("eof", &self.eof.is_some())
+            .field("started", &self.recv.is_some())
+            .finish()
+    }
+}
+
+impl<Event> PollStdin<Event>
+where
+    Event: 'static + Send,
+{
+    /// Read stdin and convert each line with the given function.
+    pub fn new(convert: impl FnMut(String) -> Event + Send + 'static) -> Self {
+        let stdin = io::stdin();
+        let reader: Option<Box<dyn Read + Send>> = if stdin.is_terminal() {
+            None
+        } else {
+            Some(Box::new(stdin))
+        };
+        Self {
+            reader,
+            convert: Some(Box::new(convert)),
+            delimiter: b'\n',
+            capacity: 64,
+            eof: None,
+            recv: None,
+        }
+    }
+
+    /// Read from something else than stdin.
+    pub fn from_reader(
+        reader: impl Read + Send + 'static,
+        convert: impl FnMut(String) -> Event + Send + 'static,
+    ) -> Self {
+        Self {
+            reader: Some(Box::new(reader)),
+            ..Self::new(convert)
+        }
+    }
+
+    /// Record delimiter. Defaults to `\n`.
+    ///
+    /// The delimiter is removed. For `\n` a preceding `\r`
+    /// is removed too.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Number of records buffered before the reader
+    /// thread blocks. Defaults to 64.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Event that is sent after the last record.
+    pub fn eof(mut self, eof: Event) -> Self {
+        self.eof = Some(eof);
+        self
+    }
+
+    /// Start the reader thread.
+    fn start(&mut self) {
+        let (send, recv) = bounded(self.capacity);
+        self.recv = Some(recv);
+
+        let Some(mut convert) = self.convert.take() else {
+            return;
+        };
+        let eof = self.eof.take();
+        let Some(reader) = self.reader.take() else {
+            if let Some(eof) = eof {
+                _ = send.send(Ok(eof));
+            }
+            return;
+        };
+        let delimiter = self.delimiter;
+
+        thread::spawn(move || {
+            let mut reader = BufReader::new(reader);
+            let mut buf = Vec::new();
+            loop {
+                buf.clear();
+                match reader.read_until(delimiter, &mut buf) {
+                    Ok(0) => break,
+                    Ok(_) => {
+                        if buf.last() == Some(&delimiter) {
+                            buf.pop();
+                            if delimiter == b'\n' && buf.last() == Some(&b'\r') {
+                                buf.pop();
+                            }
+                        }
+                        let record = String::from_utf8_lossy(&buf).into_owned();
+                        // blocks if the channel is full.
+                        if send.send(Ok(convert(record))).is_err() {
+                            // receiver is gone.
+                            return;
+                        }
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => {
+                        _ = send.send(Err(e));
+                        return;
+                    }
+                }
+            }
+            if let Some(eof) = eof {
+                _ = send.send(Ok(eof));
+            }
+        });
+    }
+}
+
+impl<Event, Error> PollEvents<Event, Error> for PollStdin<Event>
+where
+    Event: 'static + Send,
+    Error: 'static + Send + From<io::Error>,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<Error>> {
+        if self.recv.is_none() {
+            self.start();
+        }
+        Ok(self.recv.as_ref().is_some_and(|v| !v.is_empty()))
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, Error> {
+        let Some(recv) = &self.recv else {
+            return Ok(Control::Continue);
+        };
+        match recv.try_recv() {
+            Ok(Ok(event)) => Ok(Control::Event(event)),
+            Ok(Err(e)) => Err(e.into()),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => Ok(Control::Continue),
+        }
+    }
+}
@@ -1,10 +1,15 @@
 use crate::thread_pool::ThreadPool;
-use crate::{Control, PollEvents};
+use crate::{Control, PollError, PollEvents, TaskMeta};
 use crossbeam::channel::TryRecvError;
 use std::any::Any;
+use std::io;
 use std::rc::Rc;
 
 /// Processes results from background tasks.
+///
+/// A panic in a task kills its worker thread. The next poll()
+/// returns a [fatal](PollError::Fatal) error, which ends the
+/// event-loop.
 #[derive(Debug)]
 pub struct PollTasks<Event, Error>
 where
@@ -12,6 +17,7 @@ where
     Error: 'static + Send,
 {
     tasks: Rc<ThreadPool<Event, Error>>,
+    last_meta: Option<TaskMeta>,
 }
 
 impl<Event, Error> Default for PollTasks<Event, Error>
@@ -32,6 +38,7 @@ where
     pub fn new(num_workers: usize) -> Self {
         Self {
             tasks: Rc::new(ThreadPool::new(num_workers)),
+            last_meta: None,
         }
     }
 
@@ -43,17 +50,28 @@ where
 impl<Event, Error> PollEvents<Event, Error> for PollTasks<Event, Error>
 where
     Event: 'static + Send,
-    Error: 'static + Send + From<TryRecvError>,
+    Error: 'static + Send + From<TryRecvError> + From<io::Error>,
 {
     fn as_any(&self) -> &dyn Any {
         self
     }
 
-    fn poll(&mut self) -> Result<bool, Error> {
+    fn poll(&mut self) -> Result<bool, PollError<Error>> {
+        if !self.tasks.check_liveness() {
+            return Err(PollError::Fatal(
+                io::Error::other("background task panicked, worker thread is gone").into(),
+            ));
+        }
         Ok(!self.tasks.is_empty())
     }
 
     fn read(&mut self) -> Result<Control<Event>, Error> {
-        self.tasks.try_recv()
+        let (meta, r) = self.tasks.try_recv();
+        self.last_meta = meta;
+        r
+    }
+
+    fn read_meta(&mut self) -> Option<TaskMeta> {
+        self.last_meta.take()
     }
 }
@@ -1,6 +1,7 @@
 use crate::timer::{TimeOut, Timers};
-use crate::{Control, PollEvents};
+use crate::{Control, PollError, PollEvents};
 use std::any::Any;
+use std::io;
 use std::rc::Rc;
 
 /// Processes timers.
@@ -30,15 +31,23 @@ where
         self
     }
 
-    fn poll(&mut self) -> Result<bool, Error> {
+    fn poll(&mut self) -> Result<bool, PollError<Error>> {
         Ok(self.timers.poll())
     }
 
     fn read(&mut self) -> Result<Control<Event>, Error> {
-        Ok(self
-            .timers
-            .read()
-            .map(|v| Control::Event(v.0.into()))
-            .unwrap_or(Control::Continue))
+        match self.timers.read() {
+            Some((_, Some(payload))) => match payload.downcast::<Event>() {
+                Ok(event) => Ok(Control::Event(*event)),
+                // TimerDef has the Event type, this can't happen.
+                Err(_) => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "timer event is not the application Event type",
+                )
+                .into()),
+            },
+            Some((timeout, None)) => Ok(Control::Event(timeout.0.into())),
+            None => Ok(Control::Continue),
+        }
     }
 }
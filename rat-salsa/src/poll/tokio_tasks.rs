@@ -1,9 +1,9 @@
 use crate::tokio_tasks::TokioTasks;
-use crate::{Control, PollEvents};
+use crate::{Control, PollError, PollEvents, TaskMeta};
 use std::any::Any;
+use std::io;
 use std::rc::Rc;
 use tokio::runtime::Runtime;
-use tokio::sync::mpsc::Receiver;
 
 /// Add PollTokio to the configuration to enable spawning
 /// async operations from the application.
@@ -11,6 +11,10 @@ use tokio::sync::mpsc::Receiver;
 /// You cannot work with `tokio-main` but need to initialize
 /// the runtime manually.
 ///
+/// A panic in an async task is reported as a
+/// [recoverable](PollError::Recoverable) error. Aborted tasks
+/// are ignored.
+///
 #[derive(Debug)]
 pub struct PollTokio<Event, Error>
 where
@@ -18,7 +22,7 @@ where
     Error: 'static + Send,
 {
     tasks: Rc<TokioTasks<Event, Error>>,
-    recv_queue: Receiver<Result<Control<Event>, Error>>,
+    last_meta: Option<TaskMeta>,
 }
 
 impl<Event, Error> PollTokio<Event, Error>
@@ -27,10 +31,9 @@ where
     Error: 'static + Send,
 {
     pub fn new(rt: Runtime) -> Self {
-        let (tokio, recv) = TokioTasks::new(rt);
         Self {
-            tasks: Rc::new(tokio),
-            recv_queue: recv,
+            tasks: Rc::new(TokioTasks::new(rt)),
+            last_meta: None,
         }
     }
 
@@ -42,20 +45,30 @@ where
 impl<Event, Error> PollEvents<Event, Error> for PollTokio<Event, Error>
 where
     Event: 'static + Send,
-    Error: 'static + Send,
+    Error: 'static + Send + From<io::Error>,
 {
     fn as_any(&self) -> &dyn Any {
         self
     }
 
-    fn poll(&mut self) -> Result<bool, Error> {
-        self.tasks.poll_finished()?;
-        Ok(!self.recv_queue.is_empty())
+    fn poll(&mut self) -> Result<bool, PollError<Error>> {
+        self.tasks
+            .poll_finished()
+            .map_err(|e| PollError::Recoverable(e.into()))?;
+        Ok(!self.tasks.is_empty())
     }
 
     fn read(&mut self) -> Result<Control<Event>, Error> {
-        self.recv_queue
-            .blocking_recv()
-            .unwrap_or_else(|| Ok(Control::Continue))
+        if let Some((meta, r)) = self.tasks.take() {
+            self.last_meta = Some(meta);
+            r
+        } else {
+            self.last_meta = None;
+            Ok(Control::Continue)
+        }
+    }
+
+    fn read_meta(&mut self) -> Option<TaskMeta> {
+        self.last_meta.take()
     }
 }
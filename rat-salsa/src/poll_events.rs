@@ -2,8 +2,10 @@
 //! Defines the trait for event-sources.
 //!
 
-use crate::Control;
+use crate::{Control, TaskMeta};
 use std::any::Any;
+use std::io;
+use std::time::Instant;
 
 /// Trait for an event-source.
 ///
@@ -26,11 +28,121 @@ where
     ///
     /// This prevents issues with poll-ordering of multiple sources, and
     /// one source cannot just flood the app with events.
-    fn poll(&mut self) -> Result<bool, Error>;
+    ///
+    /// Failures of the source itself are reported here, see [PollError].
+    /// Errors returned by read() are results like any other and
+    /// go to [AppState::error](crate::AppState::error).
+    fn poll(&mut self) -> Result<bool, PollError<Error>>;
 
     /// Read the event and distribute it.
     ///
     /// If you add a new event, that doesn't fit into AppEvents, you'll
     /// have to define a new trait for your AppState and use that.
     fn read(&mut self) -> Result<Control<Event>, Error>;
+
+    /// Called before rendering.
+    ///
+    /// Returns an event that must be processed before the next
+    /// render, e.g. a terminal resize. Other events must stay
+    /// queued for the regular read().
+    fn read_urgent(&mut self) -> Result<Option<Control<Event>>, Error> {
+        Ok(None)
+    }
+
+    /// This source delivers user input.
+    ///
+    /// Input sources are polled in between a long chain of
+    /// queued results, so the application stays responsive.
+    /// See [RunConfig::max_queue_burst](crate::RunConfig::max_queue_burst).
+    fn is_input(&self) -> bool {
+        false
+    }
+
+    /// Provenance of the result of the last read().
+    ///
+    /// Event-sources that run background tasks return the
+    /// [TaskMeta] of the task that produced the result.
+    fn read_meta(&mut self) -> Option<TaskMeta> {
+        None
+    }
+
+    /// Terminal focus change with the result of the last read().
+    ///
+    /// A terminal event-source returns Some(true) for FocusGained
+    /// and Some(false) for FocusLost. Used with
+    /// [RunConfig::track_terminal_focus](crate::RunConfig::track_terminal_focus).
+    fn read_focus(&mut self) -> Option<bool> {
+        None
+    }
+}
+
+/// Diagnostics for one event-source.
+#[derive(Debug, Clone)]
+pub struct PollStats {
+    /// Type name of the event-source.
+    pub name: &'static str,
+    /// Priority. See [RunConfig::poll_with_priority](crate::RunConfig::poll_with_priority).
+    pub priority: u8,
+    /// Number of events delivered.
+    pub events: usize,
+    /// Last time the source was polled.
+    pub last_poll: Option<Instant>,
+}
+
+/// Failure of an event-source.
+///
+/// Returned by [PollEvents::poll]. Any other error converts
+/// to `Recoverable`, so `?` works as before.
+#[derive(Debug)]
+pub enum PollError<Error> {
+    /// A transient failure.
+    ///
+    /// The source is polled again after a backoff, that doubles
+    /// with each consecutive failure. The error goes to
+    /// [AppState::error](crate::AppState::error), and
+    /// [AppContext::poll_warning](crate::AppContext::poll_warning)
+    /// names the source.
+    Recoverable(Error),
+    /// The source is gone for good.
+    ///
+    /// Ends the event-loop. The regular shutdown still runs,
+    /// and [run_tui](crate::run_tui) returns the error.
+    Fatal(Error),
+}
+
+impl<Error> PollError<Error> {
+    /// Classify an io-error.
+    ///
+    /// Interrupted, WouldBlock and TimedOut are recoverable,
+    /// everything else is fatal.
+    pub fn from_io(err: io::Error) -> Self
+    where
+        Error: From<io::Error>,
+    {
+        match err.kind() {
+            io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => {
+                PollError::Recoverable(err.into())
+            }
+            _ => PollError::Fatal(err.into()),
+        }
+    }
+
+    /// Ends the event-loop?
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, PollError::Fatal(_))
+    }
+
+    /// The error.
+    pub fn into_inner(self) -> Error {
+        match self {
+            PollError::Recoverable(e) => e,
+            PollError::Fatal(e) => e,
+        }
+    }
+}
+
+impl<Error> From<Error> for PollError<Error> {
+    fn from(value: Error) -> Self {
+        PollError::Recoverable(value)
+    }
 }
@@ -2,5 +2,21 @@
 //! Rendered event.
 //!
 
+use std::time::Duration;
+
 /// Event sent immediately after rendering.
-pub struct RenderedEvent;
+///
+/// If you only need the signal, ignore the fields
+/// in your `From<RenderedEvent>` impl.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RenderedEvent {
+    /// Frame number.
+    pub count: usize,
+    /// Time taken for rendering and drawing the frame.
+    pub duration: Duration,
+    /// The whole screen has been drawn anew.
+    /// This is true for the first frame and after a resize.
+    ///
+    /// Can be used to skip rebuilding caches on ordinary frames.
+    pub full_redraw: bool,
+}
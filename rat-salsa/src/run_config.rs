@@ -1,11 +1,35 @@
-use crate::poll_events::PollEvents;
-use crate::terminal::{CrosstermTerminal, Terminal};
+#[cfg(feature = "async")]
+use crate::poll::PollTokio;
+use crate::poll::{PollCrossterm, PollRendered, PollTasks, PollTimers};
+use crate::poll_events::{PollEvents, PollStats};
+use crate::terminal::{ColorDepth, CrosstermTerminal, Terminal};
+use crate::{AppContext, Control, Intercept};
 use crossbeam::channel::TryRecvError;
-use std::fmt::{Debug, Formatter};
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
 use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Event interceptor.
+pub(crate) type InterceptFn<Global, Event, Error> =
+    Box<dyn FnMut(&Event, &mut AppContext<'_, Global, Event, Error>) -> Intercept<Event>>;
+
+/// Result interceptor.
+pub(crate) type InterceptResultFn<Global, Event, Error> =
+    Box<dyn FnMut(Control<Event>, &mut AppContext<'_, Global, Event, Error>) -> Control<Event>>;
+
+/// Renders the screen for a terminal below the minimum size.
+pub(crate) type TooSmallFn = Box<dyn FnMut(Size, Rect, &mut Buffer)>;
 
 /// Captures some parameters for [crate::run_tui()].
-pub struct RunConfig<Event, Error>
+///
+/// The Global type is only needed for the interceptors,
+/// and is usually inferred from the call to run_tui().
+pub struct RunConfig<Event, Error, Global = ()>
 where
     Event: 'static + Send,
     Error: 'static + Send,
@@ -19,9 +43,35 @@ where
     ///
     /// Defaults to PollTimers, PollCrossterm, PollTasks. Add yours here.
     pub(crate) poll: Vec<Box<dyn PollEvents<Event, Error>>>,
+    /// Priority and counters for each event-handler.
+    pub(crate) stats: Vec<PollStats>,
+    /// Autosave the application state.
+    pub(crate) autosave: Option<(PathBuf, Duration)>,
+    /// Track FocusGained/FocusLost of the terminal.
+    pub(crate) track_terminal_focus: bool,
+    /// Keys that are delivered while the input is blocked.
+    pub(crate) abort_keys: Vec<(KeyCode, KeyModifiers)>,
+    /// Override the detected color depth.
+    pub(crate) color_depth: Option<ColorDepth>,
+    /// Call AppState::idle() after this time without events.
+    pub(crate) idle: Option<Duration>,
+    /// Interceptors for events.
+    pub(crate) intercept: Vec<InterceptFn<Global, Event, Error>>,
+    /// Interceptors for results.
+    pub(crate) intercept_result: Vec<InterceptResultFn<Global, Event, Error>>,
+    /// Poll the input after this many queued results.
+    pub(crate) max_queue_burst: usize,
+    /// Capacity of the feedback sink.
+    pub(crate) feedback: usize,
+    /// Minimum terminal size for the application.
+    pub(crate) min_size: Option<Size>,
+    /// Screen shown below the minimum size.
+    pub(crate) too_small: Option<TooSmallFn>,
+    /// Services the application uses.
+    pub(crate) needs: Vec<Service>,
 }
 
-impl<Event, Error> Debug for RunConfig<Event, Error>
+impl<Event, Error, Global> Debug for RunConfig<Event, Error, Global>
 where
     Event: 'static + Send,
     Error: 'static + Send,
@@ -30,11 +80,23 @@ where
         f.debug_struct("RunConfig")
             .field("render", &"...")
             .field("events", &"...")
+            .field("autosave", &self.autosave)
+            .field("track_terminal_focus", &self.track_terminal_focus)
+            .field("abort_keys", &self.abort_keys)
+            .field("color_depth", &self.color_depth)
+            .field("idle", &self.idle)
+            .field("intercept", &self.intercept.len())
+            .field("intercept_result", &self.intercept_result.len())
+            .field("max_queue_burst", &self.max_queue_burst)
+            .field("feedback", &self.feedback)
+            .field("min_size", &self.min_size)
+            .field("too_small", &self.too_small.is_some())
+            .field("needs", &self.needs)
             .finish()
     }
 }
 
-impl<Event, Error> RunConfig<Event, Error>
+impl<Event, Error, Global> RunConfig<Event, Error, Global>
 where
     Event: 'static + Send,
     Error: 'static + Send + From<io::Error> + From<TryRecvError>,
@@ -45,20 +107,486 @@ where
         Ok(Self {
             term: Box::new(CrosstermTerminal::new()?),
             poll: Default::default(),
+            stats: Default::default(),
+            autosave: None,
+            track_terminal_focus: false,
+            abort_keys: Default::default(),
+            color_depth: None,
+            idle: None,
+            intercept: Default::default(),
+            intercept_result: Default::default(),
+            max_queue_burst: 64,
+            feedback: 32,
+            min_size: None,
+            too_small: None,
+            needs: Default::default(),
         })
     }
 
+    /// Configuration for an inline TUI with the given height.
+    ///
+    /// Uses [CrosstermTerminal::inline] without the alternate screen.
+    /// The viewport is placed at the cursor position and is
+    /// re-anchored when the terminal is resized. Mouse capture is off,
+    /// and the last frame stays visible after quitting. Use
+    /// [RunConfig::new] with a configured [CrosstermTerminal] to change this.
+    ///
+    /// Output above the viewport can be added with
+    /// [AppContext::insert_before](crate::AppContext::insert_before).
+    pub fn inline(height: u16) -> Result<Self, Error> {
+        Ok(Self::new(CrosstermTerminal::inline(height)?))
+    }
+
     /// Terminal is a rat-salsa::terminal::Terminal not a ratatui::Terminal.
     pub fn new(term: impl Terminal<Error> + 'static) -> Self {
         Self {
             term: Box::new(term),
             poll: Default::default(),
+            stats: Default::default(),
+            autosave: None,
+            track_terminal_focus: false,
+            abort_keys: Default::default(),
+            color_depth: None,
+            idle: None,
+            intercept: Default::default(),
+            intercept_result: Default::default(),
+            max_queue_burst: 64,
+            feedback: 32,
+            min_size: None,
+            too_small: None,
+            needs: Default::default(),
         }
     }
 
     /// Add one more poll impl.
-    pub fn poll(mut self, poll: impl PollEvents<Event, Error> + 'static) -> Self {
+    ///
+    /// This uses priority 0.
+    pub fn poll(self, poll: impl PollEvents<Event, Error> + 'static) -> Self {
+        self.poll_with_priority(poll, 0)
+    }
+
+    /// Add one more poll impl with a priority.
+    ///
+    /// All sources are polled once per round, in order of
+    /// their priority. Each source that has an event gets to
+    /// deliver one. Sources with a priority greater than 0 are
+    /// drained first, up to a fairness cap of `8 * priority`
+    /// events per round. After that the lower priority sources
+    /// get their turn.
+    ///
+    /// Sources with the same priority keep the registration
+    /// order.
+    pub fn poll_with_priority<P>(mut self, poll: P, priority: u8) -> Self
+    where
+        P: PollEvents<Event, Error> + 'static,
+    {
         self.poll.push(Box::new(poll));
+        self.stats.push(PollStats {
+            name: std::any::type_name::<P>(),
+            priority,
+            events: 0,
+            last_poll: None,
+        });
+        self
+    }
+
+    /// Declare a service of the [AppContext] the application uses.
+    ///
+    /// Calling e.g. [AppContext::add_timer] without a [PollTimers]
+    /// panics at the first use. With the service declared, the
+    /// missing event-source is reported when
+    /// [run_tui](crate::run_tui) starts.
+    /// See [RunConfig::dry_check].
+    pub fn needs(mut self, service: Service) -> Self {
+        if !self.needs.contains(&service) {
+            self.needs.push(service);
+        }
+        self
+    }
+
+    /// Track the focus of the terminal window.
+    ///
+    /// Enables focus change reporting of the terminal and calls
+    /// [AppState::focus_lost](crate::AppState::focus_lost) and
+    /// [AppState::focus_gained](crate::AppState::focus_gained).
+    /// Needs [PollCrossterm](crate::poll::PollCrossterm).
+    pub fn track_terminal_focus(mut self, track: bool) -> Self {
+        self.track_terminal_focus = track;
+        self
+    }
+
+    /// Add a key that is still delivered while the input
+    /// is blocked with [AppContext::set_input_blocked](crate::AppContext::set_input_blocked).
+    ///
+    /// Use this to let the user abort a long-running operation.
+    /// Ctrl+C is always delivered.
+    pub fn abort_key(mut self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        self.abort_keys.push((code, modifiers));
+        self
+    }
+
+    /// Override the color depth detected by the terminal.
+    ///
+    /// The depth is available as [AppContext::color_depth](crate::AppContext::color_depth)
+    /// and [RenderContext::color_depth](crate::RenderContext::color_depth).
+    pub fn color_depth(mut self, depth: ColorDepth) -> Self {
+        self.color_depth = Some(depth);
         self
     }
+
+    /// Call [AppState::idle](crate::AppState::idle) when there
+    /// have been no events for the given duration.
+    ///
+    /// After each call it waits for the full duration again,
+    /// so the idle work is done in small steps.
+    pub fn idle(mut self, after: Duration) -> Self {
+        self.idle = Some(after);
+        self
+    }
+
+    /// Add an event interceptor.
+    ///
+    /// Interceptors run in registration order before each
+    /// call to [AppState::event](crate::AppState::event). Each can pass
+    /// the event on, replace it for the rest of the chain or consume
+    /// it with a result.
+    ///
+    /// Everything the application does itself in event(), like
+    /// dialog stacks or focus handling, comes after the interceptors.
+    /// Use this for logging, translating legacy events and such.
+    pub fn intercept(
+        mut self,
+        intercept: impl FnMut(&Event, &mut AppContext<'_, Global, Event, Error>) -> Intercept<Event>
+            + 'static,
+    ) -> Self {
+        self.intercept.push(Box::new(intercept));
+        self
+    }
+
+    /// Add a result interceptor.
+    ///
+    /// Result interceptors run in registration order for each
+    /// [Control] before the event-loop acts on it. This includes
+    /// the results of event(), the queue and background tasks.
+    /// A [Control::Multiple] is split up first, and each part is
+    /// intercepted separately. Errors go to
+    /// [AppState::error](crate::AppState::error) directly.
+    ///
+    /// As the results are those of event(), they come after
+    /// anything the application does itself, including dialogs.
+    /// E.g. turn [Control::Quit] into an event that shows a
+    /// confirmation dialog.
+    pub fn intercept_result(
+        mut self,
+        intercept: impl FnMut(Control<Event>, &mut AppContext<'_, Global, Event, Error>) -> Control<Event>
+            + 'static,
+    ) -> Self {
+        self.intercept_result.push(Box::new(intercept));
+        self
+    }
+
+    /// Guard against a starving input.
+    ///
+    /// Results of event-handling are queued, and the queue is
+    /// worked off before the event-sources are polled again.
+    /// A chain of [Control::Event]s that keeps producing new
+    /// events would block any input, even Ctrl+C.
+    ///
+    /// After `burst` queued results the input sources
+    /// (see [PollEvents::is_input]) are polled once, and each
+    /// one that has an event delivers it. The result is appended
+    /// to the queue. Other event-sources wait until the queue
+    /// is empty.
+    ///
+    /// What still holds:
+    /// * Queued results are processed in order, and everything
+    ///   queued before the input is processed before it.
+    /// * Input events are processed in the order they arrive.
+    /// * A [Control::Quit] or an error that is next in the queue
+    ///   is never delayed by the guard.
+    ///
+    /// What doesn't: an input event may be handled in the middle
+    /// of a chain of events, before the chain has run its course.
+    ///
+    /// Defaults to 64. 0 disables the guard.
+    pub fn max_queue_burst(mut self, burst: usize) -> Self {
+        self.max_queue_burst = burst;
+        self
+    }
+
+    /// Capacity for [feedback](rat_widget::event::feedback) messages.
+    ///
+    /// The framework installs a feedback sink for the event-loop
+    /// and collects the messages after each event. They can be
+    /// retrieved with [AppContext::take_feedback](crate::AppContext::take_feedback).
+    /// If there are more messages the oldest are dropped.
+    ///
+    /// Defaults to 32. 0 disables the sink.
+    pub fn feedback(mut self, capacity: usize) -> Self {
+        self.feedback = capacity;
+        self
+    }
+
+    /// Minimum terminal size for the application.
+    ///
+    /// While the terminal is smaller, the application is not
+    /// rendered. Instead a screen with 'Terminal too small: need 80x24,
+    /// have 62x18' is shown, or whatever is set with
+    /// [RunConfig::too_small_screen].
+    ///
+    /// Key, mouse and paste events are dropped as long as the
+    /// terminal is too small. Resize events, Ctrl+C and the keys set
+    /// with [RunConfig::abort_key] are still delivered. Timers,
+    /// task results and app events are not affected.
+    ///
+    /// Each resize renders, and when the size is sufficient again
+    /// the application renders as usual.
+    pub fn min_size(mut self, width: u16, height: u16) -> Self {
+        self.min_size = Some(Size::new(width, height));
+        self
+    }
+
+    /// Render a custom screen while the terminal is below the
+    /// [minimum size](RunConfig::min_size).
+    ///
+    /// The closure gets the required size, the area of the
+    /// terminal and the buffer. Use this to style the screen
+    /// with your theme.
+    pub fn too_small_screen(mut self, f: impl FnMut(Size, Rect, &mut Buffer) + 'static) -> Self {
+        self.too_small = Some(Box::new(f));
+        self
+    }
+
+    /// Persist the application state.
+    ///
+    /// On startup the file is read and given to
+    /// [AppState::restore_state](crate::AppState::restore_state)
+    /// before init() runs.
+    ///
+    /// While running [AppState::save_state](crate::AppState::save_state)
+    /// is called every `interval` and once more before shutdown().
+    /// The file is only written if the state changed, and it is
+    /// written to a temp file first and then renamed.
+    ///
+    /// The interval runs as a timer, this needs
+    /// [PollTimers]. The interval must not be zero.
+    pub fn autosave(mut self, path: impl Into<PathBuf>, interval: Duration) -> Self {
+        self.autosave = Some((path.into(), interval));
+        self
+    }
+
+    /// Check the configuration without starting the UI.
+    ///
+    /// [run_tui](crate::run_tui) runs the same check before it
+    /// initializes the terminal. Errors stop it from starting,
+    /// warnings are logged.
+    ///
+    /// Checks for
+    /// * no event-sources at all.
+    /// * an event-source type that is registered more than once.
+    ///   The builtin sources are an error, others a warning.
+    /// * a [service](RunConfig::needs) without its event-source.
+    /// * [track_terminal_focus](RunConfig::track_terminal_focus)
+    ///   without [PollCrossterm].
+    /// * a terminal below the [min_size](RunConfig::min_size).
+    ///   This is a warning, the too-small screen is shown.
+    /// * [autosave](RunConfig::autosave) without [PollTimers] or
+    ///   with a zero interval.
+    /// * PollTokio with a current-thread runtime or a runtime
+    ///   that has been shut down. This is checked for each
+    ///   PollTokio, not only for [Service::Tokio].
+    ///
+    /// Returns the warnings, or all problems as one [ConfigError].
+    pub fn dry_check(&self) -> Result<Vec<String>, ConfigError> {
+        let mut problems = ConfigError::default();
+
+        if self.poll.is_empty() {
+            problems.errors.push(
+                "No event-sources configured. Add RunConfig::poll(PollCrossterm) for terminal input."
+                    .to_string(),
+            );
+        }
+
+        // duplicates
+        let mut seen = Vec::new();
+        for (n, stats) in self.stats.iter().enumerate() {
+            if seen.contains(&stats.name) {
+                continue;
+            }
+            seen.push(stats.name);
+            let count = self.stats.iter().filter(|v| v.name == stats.name).count();
+            if count > 1 {
+                let name = short_name(stats.name);
+                if is_builtin::<Event, Error>(self.poll[n].as_any()) {
+                    problems.errors.push(format!(
+                        "{} is registered {} times. Keep only one RunConfig::poll({}).",
+                        name, count, name
+                    ));
+                } else {
+                    problems
+                        .warnings
+                        .push(format!("{} is registered {} times.", name, count));
+                }
+            }
+        }
+
+        for service in &self.needs {
+            let (missing, what, call) = match service {
+                Service::Timers => (
+                    self.count::<PollTimers>() == 0,
+                    "Timers",
+                    "PollTimers::default()",
+                ),
+                Service::Tasks => (
+                    self.count::<PollTasks<Event, Error>>() == 0,
+                    "Background tasks",
+                    "PollTasks::default()",
+                ),
+                #[cfg(feature = "async")]
+                Service::Tokio => (
+                    self.count::<PollTokio<Event, Error>>() == 0,
+                    "Async tasks",
+                    "PollTokio::new(rt)",
+                ),
+                Service::Terminal => (
+                    self.count::<PollCrossterm>() == 0,
+                    "Terminal events",
+                    "PollCrossterm",
+                ),
+            };
+            if missing {
+                problems.errors.push(format!(
+                    "{} are needed, but not configured. Add RunConfig::poll({}).",
+                    what, call
+                ));
+            }
+        }
+
+        if self.track_terminal_focus && self.count::<PollCrossterm>() == 0 {
+            problems.warnings.push(
+                "track_terminal_focus() has no effect without PollCrossterm. Add RunConfig::poll(PollCrossterm)."
+                    .to_string(),
+            );
+        }
+
+        if let Some(min_size) = self.min_size {
+            if let Some(size) = self.term.size() {
+                if size.width < min_size.width || size.height < min_size.height {
+                    problems.warnings.push(format!(
+                        "Terminal is {}x{}, below the min_size {}x{}. The too-small screen is shown until it is resized.",
+                        size.width, size.height, min_size.width, min_size.height
+                    ));
+                }
+            }
+        }
+
+        if let Some((_, interval)) = &self.autosave {
+            if self.count::<PollTimers>() == 0 {
+                problems.errors.push(
+                    "autosave() needs timers, but they are not configured. Add RunConfig::poll(PollTimers::default())."
+                        .to_string(),
+                );
+            }
+            if interval.is_zero() {
+                problems
+                    .errors
+                    .push("autosave() with a zero interval. Use a longer interval.".to_string());
+            }
+        }
+
+        #[cfg(feature = "async")]
+        for poll in &self.poll {
+            if let Some(tokio) = poll.as_any().downcast_ref::<PollTokio<Event, Error>>() {
+                if tokio.get_tasks().is_current_thread() {
+                    problems.errors.push(
+                        "PollTokio has a current-thread runtime, spawned tasks would never run. Use tokio::runtime::Builder::new_multi_thread()."
+                            .to_string(),
+                    );
+                }
+                if !tokio.get_tasks().is_alive() {
+                    problems.errors.push(
+                        "PollTokio has a runtime that has been shut down, spawned tasks would never run. Keep the runtime running while the event-loop runs."
+                            .to_string(),
+                    );
+                }
+            }
+        }
+
+        if problems.errors.is_empty() {
+            Ok(problems.warnings)
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Number of event-sources of this type.
+    fn count<T: 'static>(&self) -> usize {
+        self.poll.iter().filter(|v| v.as_any().is::<T>()).count()
+    }
+}
+
+/// Builtin event-sources. The framework uses only the first one of each.
+fn is_builtin<Event, Error>(poll: &dyn Any) -> bool
+where
+    Event: 'static + Send,
+    Error: 'static + Send,
+{
+    #[cfg(feature = "async")]
+    if poll.is::<PollTokio<Event, Error>>() {
+        return true;
+    }
+    poll.is::<PollCrossterm>()
+        || poll.is::<PollTimers>()
+        || poll.is::<PollTasks<Event, Error>>()
+        || poll.is::<PollRendered>()
+}
+
+/// Type name without path and generics.
+fn short_name(name: &str) -> &str {
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Services of the [AppContext] that need an event-source.
+///
+/// See [RunConfig::needs].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Service {
+    /// Timers need [PollTimers].
+    Timers,
+    /// Background tasks with [AppContext::spawn] need [PollTasks].
+    Tasks,
+    /// Async tasks need [PollTokio].
+    #[cfg(feature = "async")]
+    Tokio,
+    /// Terminal events need [PollCrossterm].
+    Terminal,
+}
+
+/// All problems found by [RunConfig::dry_check].
+///
+/// [run_tui](crate::run_tui) returns this wrapped in an
+/// [io::Error] with [io::ErrorKind::InvalidInput].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    /// Problems that stop the application from starting.
+    pub errors: Vec<String>,
+    /// Problems that are only logged.
+    pub warnings: Vec<String>,
 }
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid RunConfig")?;
+        for v in &self.errors {
+            write!(f, "\n  error: {}", v)?;
+        }
+        for v in &self.warnings {
+            write!(f, "\n  warning: {}", v)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
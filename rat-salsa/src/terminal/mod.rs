@@ -5,7 +5,8 @@
 //! and can be used as dyn Terminal to avoid adding more T's.
 //!
 
-use crossterm::cursor::{DisableBlinking, EnableBlinking, SetCursorStyle};
+use crate::poll::crossterm::read_timeout;
+use crossterm::cursor::{DisableBlinking, EnableBlinking, MoveTo, SetCursorStyle};
 use crossterm::event::{
     DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
 };
@@ -20,11 +21,16 @@ use crossterm::terminal::{
 };
 use crossterm::ExecutableCommand;
 use rat_widget::event::util::set_have_keyboard_enhancement;
+pub use rat_widget::util::ColorDepth;
 use ratatui::backend::CrosstermBackend;
-use ratatui::Frame;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Size;
+use ratatui::{Frame, TerminalOptions, Viewport};
+use std::cmp::min;
 use std::fmt::Debug;
-use std::io;
-use std::io::{stdout, Stdout};
+use std::io::{stdout, Stdout, Write};
+use std::time::Duration;
+use std::{env, io};
 
 /// Encapsulates Terminal and Backend.
 ///
@@ -46,6 +52,21 @@ where
     where
         Error: From<io::Error>;
 
+    /// Color capabilities of the terminal.
+    ///
+    /// Defaults to [detect_color_depth].
+    fn color_depth(&self) -> ColorDepth {
+        detect_color_depth()
+    }
+
+    /// Current size of the terminal.
+    ///
+    /// Used to check the [min_size](crate::RunConfig::min_size)
+    /// at startup. Defaults to None, which skips the check.
+    fn size(&self) -> Option<Size> {
+        None
+    }
+
     /// Render the app widget.
     ///
     /// Creates the render-context, fetches the frame and calls render.
@@ -58,20 +79,123 @@ where
     ) -> Result<usize, Error>
     where
         Error: From<io::Error>;
+
+    /// Insert lines above an inline viewport.
+    ///
+    /// The lines end up in the scrollback of the terminal.
+    /// Does nothing for a fullscreen terminal.
+    fn insert_before(&mut self, height: u16, f: &mut dyn FnMut(&mut Buffer)) -> Result<(), Error>
+    where
+        Error: From<io::Error>,
+    {
+        _ = height;
+        _ = f;
+        Ok(())
+    }
+
+    /// Read the next event for a nested event-loop, waiting
+    /// at most `timeout`.
+    ///
+    /// Used by [AppContext::run_modal](crate::AppContext::run_modal).
+    /// Defaults to reading crossterm events. Events the main loop
+    /// has already read ahead are delivered first.
+    fn read_event(&mut self, timeout: Duration) -> Result<Option<crossterm::event::Event>, Error>
+    where
+        Error: From<io::Error>,
+    {
+        Ok(read_timeout(timeout)?)
+    }
+}
+
+/// Guess the color capabilities of the terminal.
+///
+/// * COLORTERM `truecolor` or `24bit` or a TERM ending with
+///   `-direct` gives true-color.
+/// * A TERM containing `256color` gives 256 colors.
+/// * Any other TERM gives 16 colors.
+///
+/// Windows terminals are assumed to support true-color.
+pub fn detect_color_depth() -> ColorDepth {
+    let colorterm = env::var("COLORTERM").unwrap_or_default();
+    let term = env::var("TERM").unwrap_or_default();
+
+    if colorterm == "truecolor" || colorterm == "24bit" || term.ends_with("-direct") {
+        ColorDepth::TrueColor
+    } else if term.contains("256color") {
+        ColorDepth::Ansi256
+    } else if cfg!(windows) && term.is_empty() {
+        ColorDepth::TrueColor
+    } else {
+        ColorDepth::Ansi16
+    }
 }
 
 /// Default RenderUI for crossterm.
+///
+/// Uses the alternate screen, or an inline viewport
+/// of a fixed height at the cursor position.
+///
+/// If stdin is redirected, the keyboard is read from the
+/// controlling terminal (`/dev/tty` or `CONIN$`) instead.
+/// Use [PollStdin](crate::poll::PollStdin) to read the piped input.
 #[derive(Debug)]
 pub struct CrosstermTerminal {
     term: ratatui::Terminal<CrosstermBackend<Stdout>>,
+    /// Height of the inline viewport.
+    inline: Option<u16>,
+    /// Enable mouse capture.
+    mouse_capture: bool,
+    /// Clear the inline viewport on shutdown.
+    clear_on_exit: bool,
 }
 
 impl CrosstermTerminal {
+    /// Fullscreen terminal using the alternate screen.
     pub fn new() -> Result<Self, io::Error> {
         Ok(Self {
             term: ratatui::Terminal::new(CrosstermBackend::new(stdout()))?,
+            inline: None,
+            mouse_capture: true,
+            clear_on_exit: false,
+        })
+    }
+
+    /// Inline viewport with the given height.
+    ///
+    /// Doesn't use the alternate screen but renders the
+    /// given number of lines at the cursor position. The rest of
+    /// the screen and the scrollback stay as they are.
+    ///
+    /// Mouse capture is off by default, so selecting text
+    /// with the mouse still works.
+    pub fn inline(height: u16) -> Result<Self, io::Error> {
+        Ok(Self {
+            term: ratatui::Terminal::with_options(
+                CrosstermBackend::new(stdout()),
+                TerminalOptions {
+                    viewport: Viewport::Inline(height),
+                },
+            )?,
+            inline: Some(height),
+            mouse_capture: false,
+            clear_on_exit: false,
         })
     }
+
+    /// Enable mouse capture.
+    pub fn mouse_capture(mut self, capture: bool) -> Self {
+        self.mouse_capture = capture;
+        self
+    }
+
+    /// Clear the inline viewport on shutdown.
+    ///
+    /// Otherwise, the last frame stays visible and the cursor
+    /// is placed after it.
+    pub fn clear_on_exit(mut self, clear: bool) -> Self {
+        self.clear_on_exit = clear;
+        self
+    }
 }
 
 impl<Error> Terminal<Error> for CrosstermTerminal
@@ -82,8 +206,26 @@ where
     where
         Error: From<io::Error>,
     {
-        stdout().execute(EnterAlternateScreen)?;
-        stdout().execute(EnableMouseCapture)?;
+        #[cfg(unix)]
+        if !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+            // crossterm switches to /dev/tty, give a better error if that's missing.
+            if let Err(e) = std::fs::File::open("/dev/tty") {
+                return Err(io::Error::new(
+                    e.kind(),
+                    format!(
+                        "stdin is not a terminal and /dev/tty is not available: {}",
+                        e
+                    ),
+                )
+                .into());
+            }
+        }
+        if self.inline.is_none() {
+            stdout().execute(EnterAlternateScreen)?;
+        }
+        if self.mouse_capture {
+            stdout().execute(EnableMouseCapture)?;
+        }
         stdout().execute(EnableBracketedPaste)?;
         stdout().execute(EnableBlinking)?;
         stdout().execute(SetCursorStyle::BlinkingBar)?;
@@ -120,11 +262,35 @@ where
         stdout().execute(SetCursorStyle::DefaultUserShape)?;
         stdout().execute(DisableBlinking)?;
         stdout().execute(DisableBracketedPaste)?;
-        stdout().execute(DisableMouseCapture)?;
-        stdout().execute(LeaveAlternateScreen)?;
+        if self.mouse_capture {
+            stdout().execute(DisableMouseCapture)?;
+        }
+        if self.inline.is_some() {
+            let area = self.term.get_frame().area();
+            if self.clear_on_exit {
+                self.term.clear()?;
+                stdout().execute(MoveTo(0, area.top()))?;
+            } else {
+                // continue after the last frame.
+                stdout().execute(MoveTo(0, area.bottom().saturating_sub(1)))?;
+                stdout().write_all(b"\r\n")?;
+                stdout().flush()?;
+            }
+            self.term.show_cursor()?;
+        } else {
+            stdout().execute(LeaveAlternateScreen)?;
+        }
         Ok(())
     }
 
+    fn size(&self) -> Option<Size> {
+        let size = self.term.size().ok()?;
+        match self.inline {
+            Some(height) => Some(Size::new(size.width, min(height, size.height))),
+            None => Some(size),
+        }
+    }
+
     #[allow(clippy::needless_lifetimes)]
     fn render(
         &mut self,
@@ -138,4 +304,12 @@ where
         self.term.draw(|frame| res = f(frame))?;
         res
     }
+
+    fn insert_before(&mut self, height: u16, f: &mut dyn FnMut(&mut Buffer)) -> Result<(), Error>
+    where
+        Error: From<io::Error>,
+    {
+        self.term.insert_before(height, |buf| f(buf))?;
+        Ok(())
+    }
 }
@@ -1,15 +1,17 @@
 //! Thread pool.
 
-use crate::Control;
+use crate::{Control, TaskMeta};
 use crossbeam::channel::{bounded, unbounded, Receiver, SendError, Sender, TryRecvError};
 use log::warn;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::thread::JoinHandle;
 use std::{mem, thread};
 
 /// Type for a background task.
-type BoxTask<Event, Error> = Box<
+pub(crate) type BoxTask<Event, Error> = Box<
     dyn FnOnce(Cancel, &Sender<Result<Control<Event>, Error>>) -> Result<Control<Event>, Error>
         + Send,
 >;
@@ -32,18 +34,84 @@ impl Cancel {
     }
 }
 
-/// Basic thread-pool.
+/// What to do when a periodic task is due while
+/// the previous run is still going.
 ///
+/// See [AppContext::spawn_periodic](crate::AppContext::spawn_periodic).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Overlap {
+    /// Don't start a new run.
+    #[default]
+    Skip,
+    /// Start a new run as soon as the previous one is done.
+    /// Multiple missed ticks result in only one run.
+    Queue,
+    /// Cancel the previous run and start a new one.
+    ///
+    /// Cancellation is cooperative, the previous run needs to
+    /// check its [Cancel] token.
+    Cancel,
+}
+
+/// Handle for a periodic task.
 ///
+/// See [AppContext::spawn_periodic](crate::AppContext::spawn_periodic).
+#[derive(Debug, Default, Clone)]
+pub struct PeriodicHandle(Rc<PeriodicCore>);
+
+#[derive(Debug, Default)]
+struct PeriodicCore {
+    stopped: Cell<bool>,
+    running: Arc<AtomicUsize>,
+}
+
+impl PeriodicHandle {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop the schedule.
+    ///
+    /// A run that is currently going is not canceled.
+    pub fn stop(&self) {
+        self.0.stopped.set(true);
+    }
+
+    /// Schedule has been stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.0.stopped.get()
+    }
+
+    /// Is a run active right now?
+    pub fn is_running(&self) -> bool {
+        self.0.running.load(Ordering::Acquire) > 0
+    }
+
+    /// Counts the active runs.
+    pub(crate) fn running(&self) -> Arc<AtomicUsize> {
+        self.0.running.clone()
+    }
+}
+
+/// Type for the result-channel of a task.
+type TaskSender<Event, Error> = Sender<Result<Control<Event>, Error>>;
+type TaskReceiver<Event, Error> = Receiver<Result<Control<Event>, Error>>;
+/// Type for the work-queue.
+type WorkSender<Event, Error> = Sender<(Cancel, TaskSender<Event, Error>, BoxTask<Event, Error>)>;
+
+/// Basic thread-pool.
 ///
+/// Each task gets its own result-channel, so every result
+/// can be attributed to the task that produced it.
 #[derive(Debug)]
 pub(crate) struct ThreadPool<Event, Error>
 where
     Event: 'static + Send,
     Error: 'static + Send,
 {
-    send: Sender<(Cancel, BoxTask<Event, Error>)>,
-    recv: Receiver<Result<Control<Event>, Error>>,
+    send: WorkSender<Event, Error>,
+    running: RefCell<Vec<(TaskMeta, TaskReceiver<Event, Error>)>>,
+    active: Arc<AtomicUsize>,
     handles: Vec<JoinHandle<()>>,
 }
 
@@ -54,23 +122,26 @@ where
 {
     /// New thread-pool with the given task executor.
     pub(crate) fn new(n_worker: usize) -> Self {
-        let (send, t_recv) = unbounded::<(Cancel, BoxTask<Event, Error>)>();
-        let (t_send, recv) = unbounded::<Result<Control<Event>, Error>>();
+        let (send, t_recv) =
+            unbounded::<(Cancel, TaskSender<Event, Error>, BoxTask<Event, Error>)>();
 
+        let active = Arc::new(AtomicUsize::new(0));
         let mut handles = Vec::new();
 
         for _ in 0..n_worker {
             let t_recv = t_recv.clone();
-            let t_send = t_send.clone();
+            let active = active.clone();
 
             let handle = thread::spawn(move || {
                 let t_recv = t_recv;
 
                 'l: loop {
                     match t_recv.recv() {
-                        Ok((cancel, task)) => {
+                        Ok((cancel, t_send, task)) => {
                             let flow = task(cancel, &t_send);
+                            active.fetch_sub(1, Ordering::AcqRel);
                             if let Err(err) = t_send.send(flow) {
+                                // the pool is gone.
                                 warn!("{:?}", err);
                                 break 'l;
                             }
@@ -88,7 +159,8 @@ where
 
         Self {
             send,
-            recv,
+            running: Default::default(),
+            active,
             handles,
         }
     }
@@ -102,15 +174,27 @@ where
     ///
     /// If you need more, create an extra channel for communication to the background task.
     #[inline]
-    pub(crate) fn spawn(&self, task: BoxTask<Event, Error>) -> Result<Cancel, SendError<()>> {
+    pub(crate) fn spawn(
+        &self,
+        meta: TaskMeta,
+        task: BoxTask<Event, Error>,
+    ) -> Result<Cancel, SendError<()>> {
         if self.handles.is_empty() {
             return Err(SendError(()));
         }
 
         let cancel = Cancel::new();
-        match self.send.send((cancel.clone(), task)) {
-            Ok(_) => Ok(cancel),
-            Err(_) => Err(SendError(())),
+        let (t_send, recv) = unbounded();
+        self.active.fetch_add(1, Ordering::AcqRel);
+        match self.send.send((cancel.clone(), t_send, task)) {
+            Ok(_) => {
+                self.running.borrow_mut().push((meta, recv));
+                Ok(cancel)
+            }
+            Err(_) => {
+                self.active.fetch_sub(1, Ordering::AcqRel);
+                Err(SendError(()))
+            }
         }
     }
 
@@ -124,22 +208,40 @@ where
         true
     }
 
-    /// Is the receive-channel empty?
+    /// Number of tasks that are queued or running.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+
+    /// Are all the result-channels empty?
     #[inline]
     pub(crate) fn is_empty(&self) -> bool {
-        self.recv.is_empty()
+        self.running
+            .borrow()
+            .iter()
+            .all(|(_, recv)| recv.is_empty())
     }
 
-    /// Receive a result.
-    pub(crate) fn try_recv(&self) -> Result<Control<Event>, Error>
-    where
-        Error: From<TryRecvError>,
-    {
-        match self.recv.try_recv() {
-            Ok(v) => v,
-            Err(TryRecvError::Empty) => Ok(Control::Continue),
-            Err(e) => Err(e.into()),
-        }
+    /// Receive a result and the task that sent it.
+    ///
+    /// Removes the channels of finished tasks.
+    pub(crate) fn try_recv(&self) -> (Option<TaskMeta>, Result<Control<Event>, Error>) {
+        let mut result = None;
+        self.running.borrow_mut().retain(|(meta, recv)| {
+            if result.is_some() {
+                return true;
+            }
+            match recv.try_recv() {
+                Ok(v) => {
+                    result = Some((Some(meta.clone()), v));
+                    true
+                }
+                Err(TryRecvError::Empty) => true,
+                Err(TryRecvError::Disconnected) => false,
+            }
+        });
+        result.unwrap_or((None, Ok(Control::Continue)))
     }
 }
 
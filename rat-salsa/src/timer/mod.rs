@@ -1,7 +1,11 @@
 //!
 //! Support for timers.
 //!
+use std::any::Any;
 use std::cell::{Cell, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::rc::Rc;
 use std::time::{Duration, Instant};
 
 /// Holds all the timers.
@@ -9,6 +13,12 @@ use std::time::{Duration, Instant};
 pub(crate) struct Timers {
     tags: Cell<usize>,
     timers: RefCell<Vec<TimerImpl>>,
+    /// Terminal has no focus.
+    paused: Cell<bool>,
+    /// All timers are paused.
+    paused_all: Cell<bool>,
+    /// Paused timers, with the remaining time.
+    paused_timers: RefCell<Vec<(TimerImpl, Duration)>>,
 }
 
 /// Handle for a submitted timer.
@@ -21,7 +31,28 @@ struct TimerImpl {
     count: usize,
     repeat: Option<usize>,
     next: Instant,
+    /// Last tick or the start time.
+    last: Instant,
     timer: Duration,
+    /// Collapse missed ticks.
+    animation: bool,
+    pause_on_unfocus: bool,
+    /// Paused by the application.
+    paused: bool,
+    /// Used by the framework itself. Never sends an event
+    /// and is never paused.
+    internal: bool,
+    payload: Option<TimerPayload>,
+}
+
+/// Creates the app event for a timer.
+#[derive(Clone)]
+struct TimerPayload(Rc<dyn Fn() -> Box<dyn Any>>);
+
+impl Debug for TimerPayload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TimerPayload")
+    }
 }
 
 impl Timers {
@@ -43,7 +74,7 @@ impl Timers {
     /// Polls for the next timer event.
     pub(crate) fn poll(&self) -> bool {
         let timers = self.timers.borrow();
-        if let Some(timer) = timers.last() {
+        if let Some(timer) = timers.iter().rev().find(|v| !v.internal) {
             Instant::now() >= timer.next
         } else {
             false
@@ -52,36 +83,101 @@ impl Timers {
 
     /// Polls for the next timer event.
     /// Removes/recalculates the event and reorders the queue.
-    pub(crate) fn read(&self) -> Option<TimerEvent> {
+    ///
+    /// Returns the payload event if the timer has one.
+    ///
+    /// The framework calls this only when the previous event has
+    /// been processed completely. So a timer that has been removed
+    /// by the application can't fire afterwards.
+    pub(crate) fn read(&self) -> Option<(TimerEvent, Option<Box<dyn Any>>)> {
         let mut timers = self.timers.borrow_mut();
 
-        let timer = timers.pop();
-        if let Some(mut timer) = timer {
-            if Instant::now() >= timer.next {
-                let evt = TimerEvent(TimeOut {
-                    handle: TimerHandle(timer.tag),
-                    counter: timer.count,
-                });
-
-                // reschedule
-                if let Some(repeat) = timer.repeat {
-                    timer.count += 1;
-                    if timer.count < repeat {
-                        timer.next += timer.timer;
-                        Self::add_impl(timers.as_mut(), timer);
-                    }
-                }
-
-                Some(evt)
-            } else {
-                timers.push(timer);
-                None
-            }
+        let now = Instant::now();
+        let idx = timers.iter().rposition(|v| !v.internal)?;
+        if now >= timers[idx].next {
+            let timer = timers.remove(idx);
+            Some(Self::tick(timers.as_mut(), timer, now))
         } else {
             None
         }
     }
 
+    /// Creates the event for a due timer and reschedules it.
+    fn tick(
+        timers: &mut Vec<TimerImpl>,
+        mut timer: TimerImpl,
+        now: Instant,
+    ) -> (TimerEvent, Option<Box<dyn Any>>) {
+        // all ticks that are due by now are delivered as one.
+        let skipped = if timer.animation && !timer.timer.is_zero() {
+            (now.duration_since(timer.next).as_nanos() / timer.timer.as_nanos()) as usize
+        } else {
+            0
+        };
+
+        let evt = TimerEvent(TimeOut {
+            handle: TimerHandle(timer.tag),
+            counter: timer.count,
+            elapsed_since_last: now.saturating_duration_since(timer.last),
+            skipped,
+        });
+        let payload = timer.payload.as_ref().map(|v| (v.0)());
+        timer.last = now;
+
+        // reschedule
+        if let Some(repeat) = timer.repeat {
+            timer.count = timer.count.saturating_add(skipped + 1);
+            if timer.count < repeat {
+                timer.next += timer.timer * (skipped as u32 + 1);
+                Self::add_impl(timers, timer);
+            }
+        }
+
+        (evt, payload)
+    }
+
+    /// Add a repeating timer for the framework itself.
+    /// It doesn't send events, use [Timers::take_internal]
+    /// to check it.
+    pub(crate) fn add_internal(&self, interval: Duration) -> TimerHandle {
+        let tag = self.tags.get() + 1;
+        self.tags.set(tag);
+
+        let now = Instant::now();
+        let t = TimerImpl {
+            tag,
+            count: 0,
+            repeat: Some(usize::MAX),
+            next: now + interval,
+            last: now,
+            timer: interval,
+            animation: true,
+            pause_on_unfocus: false,
+            paused: false,
+            internal: true,
+            payload: None,
+        };
+        Self::add_impl(self.timers.borrow_mut().as_mut(), t);
+
+        TimerHandle(tag)
+    }
+
+    /// Is the internal timer due? Reschedules it if it is.
+    pub(crate) fn take_internal(&self, tag: TimerHandle) -> bool {
+        let mut timers = self.timers.borrow_mut();
+
+        let now = Instant::now();
+        let Some(idx) = timers
+            .iter()
+            .position(|v| v.internal && v.tag == tag.0 && now >= v.next)
+        else {
+            return false;
+        };
+        let timer = timers.remove(idx);
+        _ = Self::tick(timers.as_mut(), timer, now);
+        true
+    }
+
     fn add_impl(timers: &mut Vec<TimerImpl>, t: TimerImpl) {
         'f: {
             for i in 0..timers.len() {
@@ -96,10 +192,11 @@ impl Timers {
 
     /// Add a timer.
     #[must_use]
-    pub(crate) fn add(&self, t: TimerDef) -> TimerHandle {
+    pub(crate) fn add<Event>(&self, t: TimerDef<Event>) -> TimerHandle {
         let tag = self.tags.get() + 1;
         self.tags.set(tag);
 
+        let now = Instant::now();
         let t = TimerImpl {
             tag,
             count: 0,
@@ -107,13 +204,24 @@ impl Timers {
             next: if let Some(next) = t.next {
                 next
             } else {
-                Instant::now() + t.timer
+                now + t.timer
             },
+            last: now,
             timer: t.timer,
+            animation: t.animation,
+            pause_on_unfocus: t.pause_on_unfocus,
+            paused: false,
+            internal: false,
+            payload: t.payload,
         };
 
-        let mut timers = self.timers.borrow_mut();
-        Self::add_impl(timers.as_mut(), t);
+        if self.is_held(&t) {
+            let remaining = t.next.saturating_duration_since(now);
+            self.paused_timers.borrow_mut().push((t, remaining));
+        } else {
+            let mut timers = self.timers.borrow_mut();
+            Self::add_impl(timers.as_mut(), t);
+        }
 
         TimerHandle(tag)
     }
@@ -127,14 +235,149 @@ impl Timers {
                 break;
             }
         }
+        self.paused_timers
+            .borrow_mut()
+            .retain(|(t, _)| t.tag != tag.0);
+    }
+
+    /// Replace a timer.
+    ///
+    /// If the new timer has no payload it keeps the payload
+    /// of the old timer.
+    pub(crate) fn replace<Event>(
+        &self,
+        tag: Option<TimerHandle>,
+        mut t: TimerDef<Event>,
+    ) -> TimerHandle {
+        if let Some(tag) = tag {
+            if t.payload.is_none() {
+                t.payload = self.payload(tag);
+            }
+            self.remove(tag);
+        }
+        self.add(t)
+    }
+
+    fn payload(&self, tag: TimerHandle) -> Option<TimerPayload> {
+        if let Some(t) = self.timers.borrow().iter().find(|v| v.tag == tag.0) {
+            return t.payload.clone();
+        }
+        if let Some((t, _)) = self
+            .paused_timers
+            .borrow()
+            .iter()
+            .find(|(v, _)| v.tag == tag.0)
+        {
+            return t.payload.clone();
+        }
+        None
+    }
+
+    /// Pause/resume the timers marked with pause_on_unfocus.
+    pub(crate) fn set_paused(&self, paused: bool) {
+        if self.paused.replace(paused) != paused {
+            self.update_paused();
+        }
+    }
+
+    /// Pause/resume all timers.
+    pub(crate) fn set_paused_all(&self, paused: bool) {
+        if self.paused_all.replace(paused) != paused {
+            self.update_paused();
+        }
+    }
+
+    /// Pause/resume a single timer.
+    pub(crate) fn set_timer_paused(&self, tag: TimerHandle, paused: bool) {
+        for t in self.timers.borrow_mut().iter_mut() {
+            if t.tag == tag.0 {
+                t.paused = paused;
+            }
+        }
+        for (t, _) in self.paused_timers.borrow_mut().iter_mut() {
+            if t.tag == tag.0 {
+                t.paused = paused;
+            }
+        }
+        self.update_paused();
+    }
+
+    /// Time until the timer fires next.
+    /// For a paused timer this is the remaining time when it was paused.
+    pub(crate) fn remaining(&self, tag: TimerHandle) -> Option<Duration> {
+        if let Some(t) = self.timers.borrow().iter().find(|v| v.tag == tag.0) {
+            return Some(t.next.saturating_duration_since(Instant::now()));
+        }
+        if let Some((_, remaining)) = self
+            .paused_timers
+            .borrow()
+            .iter()
+            .find(|(v, _)| v.tag == tag.0)
+        {
+            return Some(*remaining);
+        }
+        None
+    }
+
+    fn is_held(&self, t: &TimerImpl) -> bool {
+        !t.internal
+            && (t.paused || self.paused_all.get() || (t.pause_on_unfocus && self.paused.get()))
+    }
+
+    /// Moves timers between the running and paused list.
+    ///
+    /// Paused timers keep their remaining time until the next
+    /// tick. They continue from there when resumed, missed ticks
+    /// are not delivered.
+    fn update_paused(&self) {
+        let now = Instant::now();
+        let mut timers = self.timers.borrow_mut();
+        let mut paused_timers = self.paused_timers.borrow_mut();
+
+        let mut i = 0;
+        while i < timers.len() {
+            if self.is_held(&timers[i]) {
+                let t = timers.remove(i);
+                let remaining = t.next.saturating_duration_since(now);
+                paused_timers.push((t, remaining));
+            } else {
+                i += 1;
+            }
+        }
+
+        let mut i = 0;
+        while i < paused_timers.len() {
+            if !self.is_held(&paused_timers[i].0) {
+                let (mut t, remaining) = paused_timers.remove(i);
+                t.next = now + remaining;
+                // the pause doesn't count as elapsed time.
+                t.last = (now + remaining)
+                    .checked_sub(t.timer)
+                    .unwrap_or(now)
+                    .min(now);
+                Self::add_impl(timers.as_mut(), t);
+            } else {
+                i += 1;
+            }
+        }
     }
 }
 
 /// Timing event data. Used by [TimerEvent].
+///
+/// This is created by the timers only, more fields may be
+/// added in the future.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct TimeOut {
     pub handle: TimerHandle,
+    /// Number of ticks so far. Includes skipped ticks.
     pub counter: usize,
+    /// Time since the last tick, or since the timer was started.
+    pub elapsed_since_last: Duration,
+    /// Number of ticks that have been collapsed into this one.
+    /// Only an [animation](TimerDef::animation) timer skips ticks.
+    pub skipped: usize,
 }
 
 /// Timer event.
@@ -142,21 +385,74 @@ pub struct TimeOut {
 pub struct TimerEvent(pub TimeOut);
 
 /// Holds the information to start a timer.
-#[derive(Debug, Default)]
-pub struct TimerDef {
+///
+/// The type parameter is the Event type of the application,
+/// it's used for [event](TimerDef::event).
+pub struct TimerDef<Event> {
     /// Optional repeat.
     repeat: Option<usize>,
     /// Duration
     timer: Duration,
     /// Specific time.
     next: Option<Instant>,
+    /// Collapse missed ticks.
+    animation: bool,
+    /// Pause while the terminal has no focus.
+    pause_on_unfocus: bool,
+    /// Event sent instead of the TimeOut.
+    payload: Option<TimerPayload>,
+    phantom: PhantomData<fn() -> Event>,
+}
+
+impl<Event> Debug for TimerDef<Event> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimerDef")
+            .field("repeat", &self.repeat)
+            .field("timer", &self.timer)
+            .field("next", &self.next)
+            .field("animation", &self.animation)
+            .field("pause_on_unfocus", &self.pause_on_unfocus)
+            .field("payload", &self.payload)
+            .finish()
+    }
+}
+
+impl<Event> Default for TimerDef<Event> {
+    fn default() -> Self {
+        Self {
+            repeat: None,
+            timer: Default::default(),
+            next: None,
+            animation: false,
+            pause_on_unfocus: false,
+            payload: None,
+            phantom: PhantomData,
+        }
+    }
 }
 
-impl TimerDef {
+impl<Event> TimerDef<Event> {
     pub fn new() -> Self {
         Default::default()
     }
 
+    /// Timer for driving an animation with the given frame-rate.
+    /// Repeats forever.
+    ///
+    /// A normal repeating timer delivers every tick, so if the
+    /// event-loop falls behind all the missed ticks arrive in
+    /// a burst. This timer delivers only one tick instead,
+    /// with [TimeOut::skipped] set to the number of missed ticks.
+    /// Use [TimeOut::elapsed_since_last] to advance the animation.
+    pub fn animation(fps: u32) -> Self {
+        Self {
+            repeat: Some(usize::MAX),
+            timer: Duration::from_secs(1) / fps.max(1),
+            animation: true,
+            ..Default::default()
+        }
+    }
+
     /// Repeat forever.
     pub fn repeat_forever(mut self) -> Self {
         self.repeat = Some(usize::MAX);
@@ -181,4 +477,22 @@ impl TimerDef {
         self.next = Some(next);
         self
     }
+
+    /// Send this event when the timer fires, instead of a
+    /// [TimeOut]. The event is cloned for each tick.
+    pub fn event(mut self, event: Event) -> Self
+    where
+        Event: Clone + 'static,
+    {
+        self.payload = Some(TimerPayload(Rc::new(move || Box::new(event.clone()))));
+        self
+    }
+
+    /// Pause the timer while the terminal window has no focus.
+    ///
+    /// Needs [RunConfig::track_terminal_focus](crate::RunConfig::track_terminal_focus).
+    pub fn pause_on_unfocus(mut self, pause: bool) -> Self {
+        self.pause_on_unfocus = pause;
+        self
+    }
 }
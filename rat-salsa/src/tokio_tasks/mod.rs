@@ -1,16 +1,27 @@
-use crate::Control;
-use log::error;
+use crate::{Control, TaskMeta};
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::future::Future;
-use tokio::runtime::Runtime;
+use std::io;
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
+use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio::task::{AbortHandle, JoinHandle};
 
+type TaskResult<Event, Error> = Result<Control<Event>, Error>;
+type Pending<Event, Error> = (TaskMeta, JoinHandle<TaskResult<Event, Error>>);
+type Channel<Event, Error> = (TaskMeta, Receiver<TaskResult<Event, Error>>);
+
+/// Runs async tasks.
+///
+/// Each task that wants to send extra results gets its own
+/// channel, so every result can be attributed to its task.
 #[derive(Debug)]
 pub(crate) struct TokioTasks<Event, Error> {
     rt: Runtime,
-    pending: RefCell<Vec<JoinHandle<Result<Control<Event>, Error>>>>,
-    send_queue: Sender<Result<Control<Event>, Error>>,
+    pending: RefCell<Vec<Pending<Event, Error>>>,
+    channels: RefCell<Vec<Channel<Event, Error>>>,
+    ready: RefCell<VecDeque<(TaskMeta, TaskResult<Event, Error>)>>,
 }
 
 impl<Event, Error> TokioTasks<Event, Error>
@@ -18,48 +29,103 @@ where
     Event: 'static + Send,
     Error: 'static + Send,
 {
-    pub fn new(rt: Runtime) -> (Self, Receiver<Result<Control<Event>, Error>>) {
-        let (send, recv) = channel(100);
-        (
-            Self {
-                rt,
-                pending: Default::default(),
-                send_queue: send,
-            },
-            recv,
-        )
+    pub fn new(rt: Runtime) -> Self {
+        Self {
+            rt,
+            pending: Default::default(),
+            channels: Default::default(),
+            ready: Default::default(),
+        }
+    }
+
+    /// Runs on a current-thread runtime.
+    pub(crate) fn is_current_thread(&self) -> bool {
+        self.rt.handle().runtime_flavor() == RuntimeFlavor::CurrentThread
+    }
+
+    /// The runtime still runs tasks.
+    ///
+    /// A task spawned on a runtime that has been shut down is
+    /// cancelled immediately. Can't block from inside a runtime,
+    /// this reports true there.
+    pub(crate) fn is_alive(&self) -> bool {
+        if Handle::try_current().is_ok() {
+            return true;
+        }
+        let h = self.rt.spawn(async {});
+        self.rt.block_on(h).is_ok()
     }
 
     pub(crate) fn spawn(
         &self,
+        meta: TaskMeta,
         future: Box<dyn Future<Output = Result<Control<Event>, Error>> + Send>,
     ) -> AbortHandle {
         let h = self.rt.spawn(Box::into_pin(future));
         let ah = h.abort_handle();
-        self.pending.borrow_mut().push(h);
+        self.pending.borrow_mut().push((meta, h));
         ah
     }
 
-    pub(crate) fn sender(&self) -> Sender<Result<Control<Event>, Error>> {
-        self.send_queue.clone()
+    /// Channel for extra results of the given task.
+    pub(crate) fn sender(&self, meta: TaskMeta) -> Sender<Result<Control<Event>, Error>> {
+        let (send, recv) = channel(100);
+        self.channels.borrow_mut().push((meta, recv));
+        send
     }
 
-    pub(crate) fn poll_finished(&self) -> Result<(), Error> {
-        self.pending.borrow_mut().retain_mut(|v| {
+    /// Collect the results of finished tasks.
+    ///
+    /// Returns an error if a task panicked.
+    pub(crate) fn poll_finished(&self) -> Result<(), io::Error> {
+        let mut panicked = None;
+        let mut ready = self.ready.borrow_mut();
+        self.channels.borrow_mut().retain_mut(|(meta, recv)| loop {
+            match recv.try_recv() {
+                Ok(r) => ready.push_back((meta.clone(), r)),
+                Err(TryRecvError::Empty) => break true,
+                Err(TryRecvError::Disconnected) => break false,
+            }
+        });
+        self.pending.borrow_mut().retain_mut(|(meta, v)| {
             if v.is_finished() {
                 match self.rt.block_on(v) {
-                    Ok(r) => {
-                        if let Err(e) = self.send_queue.try_send(r) {
-                            error!("{:?}", e);
-                        }
+                    Ok(r) => ready.push_back((meta.clone(), r)),
+                    Err(e) if e.is_panic() => {
+                        let name = meta.name.as_deref().unwrap_or("async task");
+                        panicked.get_or_insert(io::Error::other(format!("{} panicked", name)));
+                    }
+                    Err(_) => {
+                        // aborted
                     }
-                    Err(e) => error!("{:?}", e),
                 }
                 false
             } else {
                 true
             }
         });
-        Ok(())
+        match panicked {
+            None => Ok(()),
+            Some(e) => Err(e),
+        }
+    }
+
+    /// Number of tasks still running.
+    pub(crate) fn len(&self) -> usize {
+        self.pending
+            .borrow()
+            .iter()
+            .filter(|(_, v)| !v.is_finished())
+            .count()
+    }
+
+    /// Are there any results?
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ready.borrow().is_empty()
+    }
+
+    /// Next result and the task that sent it.
+    pub(crate) fn take(&self) -> Option<(TaskMeta, TaskResult<Event, Error>)> {
+        self.ready.borrow_mut().pop_front()
     }
 }
@@ -0,0 +1,139 @@
+//!
+//! Test harness for running the event-loop.
+//!
+#![allow(dead_code)]
+
+use rat_salsa::terminal::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Size;
+use ratatui::{Frame, TerminalOptions, Viewport};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::io;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// The TestBackend terminal, can be shared with the app state.
+pub type SharedTerminal = Rc<RefCell<ratatui::Terminal<TestBackend>>>;
+
+/// Events for a nested event-loop.
+pub type SharedInput = Rc<RefCell<VecDeque<crossterm::event::Event>>>;
+
+/// Renders to a [TestBackend].
+pub struct TestTerminal {
+    term: SharedTerminal,
+    init: Rc<Cell<bool>>,
+    input: SharedInput,
+}
+
+impl TestTerminal {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self::shared(Rc::new(RefCell::new(
+            ratatui::Terminal::new(TestBackend::new(width, height)).expect("terminal"),
+        )))
+    }
+
+    /// Inline viewport of the given height.
+    pub fn inline(width: u16, height: u16, viewport: u16) -> Self {
+        Self::shared(Rc::new(RefCell::new(
+            ratatui::Terminal::with_options(
+                TestBackend::new(width, height),
+                TerminalOptions {
+                    viewport: Viewport::Inline(viewport),
+                },
+            )
+            .expect("terminal"),
+        )))
+    }
+
+    pub fn shared(term: SharedTerminal) -> Self {
+        Self {
+            term,
+            init: Default::default(),
+            input: Default::default(),
+        }
+    }
+
+    /// The wrapped terminal.
+    pub fn terminal(&self) -> SharedTerminal {
+        self.term.clone()
+    }
+
+    /// Set when the event-loop calls init().
+    pub fn init_flag(&self) -> Rc<Cell<bool>> {
+        self.init.clone()
+    }
+
+    /// Events for read_event(). Reading past the last one
+    /// is an error.
+    pub fn input(&self) -> SharedInput {
+        self.input.clone()
+    }
+}
+
+impl Terminal<anyhow::Error> for TestTerminal {
+    fn init(&mut self) -> Result<(), anyhow::Error>
+    where
+        anyhow::Error: From<io::Error>,
+    {
+        self.init.set(true);
+        Ok(())
+    }
+
+    fn shutdown(&mut self) -> Result<(), anyhow::Error>
+    where
+        anyhow::Error: From<io::Error>,
+    {
+        Ok(())
+    }
+
+    fn size(&self) -> Option<Size> {
+        self.term.borrow().size().ok()
+    }
+
+    fn render(
+        &mut self,
+        f: &mut dyn FnMut(&mut Frame<'_>) -> Result<usize, anyhow::Error>,
+    ) -> Result<usize, anyhow::Error>
+    where
+        anyhow::Error: From<io::Error>,
+    {
+        let mut res = Ok(0);
+        self.term.borrow_mut().draw(|frame| res = f(frame))?;
+        res
+    }
+
+    fn insert_before(
+        &mut self,
+        height: u16,
+        f: &mut dyn FnMut(&mut Buffer),
+    ) -> Result<(), anyhow::Error> {
+        self.term.borrow_mut().insert_before(height, |buf| f(buf))?;
+        Ok(())
+    }
+
+    fn read_event(
+        &mut self,
+        _timeout: Duration,
+    ) -> Result<Option<crossterm::event::Event>, anyhow::Error> {
+        match self.input.borrow_mut().pop_front() {
+            Some(event) => Ok(Some(event)),
+            None => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "no more test input").into()),
+        }
+    }
+}
+
+/// Current content of the terminal, one line per row.
+pub fn screen(term: &SharedTerminal) -> String {
+    let term = term.borrow();
+    let buf = term.backend().buffer();
+    let mut s = String::new();
+    for y in 0..buf.area.height {
+        for x in 0..buf.area.width {
+            s.push_str(buf[(x, y)].symbol());
+        }
+        s.push('\n');
+    }
+    s
+}
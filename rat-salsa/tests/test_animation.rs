@@ -0,0 +1,106 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollTimers;
+use rat_salsa::timer::{TimeOut, TimerDef, TimerHandle};
+use rat_salsa::{run_tui, AppContext, AppState, AppWidget, Control, RenderContext, RunConfig};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+enum Event {
+    Timer(TimeOut),
+}
+
+impl From<TimeOut> for Event {
+    fn from(value: TimeOut) -> Self {
+        Self::Timer(value)
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    anim: TimerHandle,
+    ticks: Vec<(Instant, TimeOut)>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        self.anim = ctx.add_timer(TimerDef::animation(20));
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Timer(t) if t.handle == self.anim => {
+                self.ticks.push((Instant::now(), *t));
+                if self.ticks.len() == 1 {
+                    // a really slow frame.
+                    sleep(Duration::from_millis(500));
+                }
+            }
+            Event::Timer(_) => {}
+        }
+
+        if self.ticks.len() >= 4 {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+}
+
+#[test]
+fn test_animation() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term).poll(PollTimers::new()),
+    )?;
+
+    let ticks = &state.ticks;
+    assert_eq!(ticks[0].1.skipped, 0);
+
+    // exactly one compensating tick.
+    let (at, t) = ticks[1];
+    assert!(t.skipped >= 8 && t.skipped <= 10);
+    assert!(t.elapsed_since_last >= Duration::from_millis(500));
+    assert_eq!(t.counter, 1);
+    assert_eq!(ticks.iter().filter(|(_, t)| t.skipped > 0).count(), 1);
+
+    // and no burst afterwards.
+    let (at2, t2) = ticks[2];
+    assert_eq!(t2.counter, t.counter + t.skipped + 1);
+    assert!(at2.duration_since(at) >= Duration::from_millis(20));
+    assert!(t2.elapsed_since_last < Duration::from_millis(100));
+    Ok(())
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::{PollTasks, PollTimers};
+use rat_salsa::timer::{TimeOut, TimerDef};
+use rat_salsa::{run_tui, AppContext, AppState, AppWidget, Control, RenderContext, RunConfig};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::cell::Cell;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug)]
+enum Event {
+    Timer(TimeOut),
+}
+
+impl From<TimeOut> for Event {
+    fn from(value: TimeOut) -> Self {
+        Self::Timer(value)
+    }
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "rat-salsa-autosave-{}-{}",
+        std::process::id(),
+        name
+    ));
+    _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("temp dir");
+    dir
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    path: PathBuf,
+    data: String,
+    /// Order of restore/init.
+    calls: Vec<String>,
+    saves: Cell<usize>,
+    step: usize,
+    saves_at: usize,
+    skipped_unchanged: bool,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        self.calls.push(format!("init {}", self.data));
+        self.data = "v1".into();
+        ctx.add_timer(
+            TimerDef::new()
+                .timer(Duration::from_millis(5))
+                .repeat_forever(),
+        );
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        let Event::Timer(t) = event;
+        if t.counter > 2000 {
+            return Ok(Control::Quit);
+        }
+
+        match self.step {
+            0 if fs::read_to_string(&self.path).ok().as_deref() == Some("v1") => {
+                fs::remove_file(&self.path)?;
+                self.saves_at = self.saves.get();
+                self.step = 1;
+            }
+            // unchanged state is not written again.
+            1 if self.saves.get() >= self.saves_at + 2 => {
+                self.skipped_unchanged = !self.path.exists();
+                self.data = "v2".into();
+                self.step = 2;
+            }
+            2 if fs::read_to_string(&self.path).ok().as_deref() == Some("v2") => {
+                self.step = 3;
+                return Ok(Control::Quit);
+            }
+            _ => {}
+        }
+        Ok(Control::Continue)
+    }
+
+    fn save_state(&self) -> Option<Vec<u8>> {
+        self.saves.set(self.saves.get() + 1);
+        Some(self.data.clone().into_bytes())
+    }
+
+    fn restore_state(&mut self, bytes: &[u8]) {
+        self.data = String::from_utf8_lossy(bytes).into_owned();
+        self.calls.push(format!("restore {}", self.data));
+    }
+}
+
+#[test]
+fn test_autosave() -> Result<(), anyhow::Error> {
+    let dir = temp_dir("save");
+    let path = dir.join("state.bin");
+    fs::write(&path, "v0")?;
+
+    let mut state = State {
+        path: path.clone(),
+        ..Default::default()
+    };
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5))
+            .poll(PollTimers::default())
+            .autosave(&path, Duration::from_millis(10)),
+    )?;
+
+    // restore runs before init.
+    assert_eq!(state.calls, vec!["restore v0", "init v0"]);
+    assert_eq!(state.step, 3);
+    assert!(state.skipped_unchanged);
+
+    // only the final file, no temp file left behind.
+    assert_eq!(fs::read_to_string(&path)?, "v2");
+    let files = fs::read_dir(&dir)?
+        .map(|v| v.map(|v| v.file_name()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(files, vec!["state.bin"]);
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_autosave_config() {
+    let err = RunConfig::<Event, anyhow::Error>::new(TestTerminal::new(20, 5))
+        .poll(PollTimers::default())
+        .autosave("state.bin", Duration::ZERO)
+        .dry_check()
+        .expect_err("error");
+    assert_eq!(err.errors.len(), 1);
+    assert!(err.errors[0].contains("zero interval"));
+
+    // the interval runs as a timer.
+    let cfg = RunConfig::<Event, anyhow::Error>::new(TestTerminal::new(20, 5))
+        .poll(PollTimers::default())
+        .autosave("state.bin", Duration::from_secs(1));
+    assert_eq!(cfg.dry_check(), Ok(Vec::new()));
+
+    let err = RunConfig::<Event, anyhow::Error>::new(TestTerminal::new(20, 5))
+        .poll(PollTasks::default())
+        .autosave("state.bin", Duration::from_secs(1))
+        .dry_check()
+        .expect_err("error");
+    assert_eq!(err.errors.len(), 1);
+    assert!(err.errors[0].contains("PollTimers"));
+}
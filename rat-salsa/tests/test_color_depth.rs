@@ -0,0 +1,157 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::terminal::{detect_color_depth, ColorDepth, Terminal};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use rat_theme::dark_theme::DarkTheme;
+use rat_theme::scheme::IMPERIAL;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::Frame;
+use std::any::Any;
+use std::env;
+use std::io;
+
+#[derive(Debug)]
+struct Event;
+
+/// Quits right away.
+struct PollQuit;
+
+impl PollEvents<Event, anyhow::Error> for PollQuit {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        Ok(Control::Quit)
+    }
+}
+
+/// A terminal that knows its colors.
+struct Terminal256(TestTerminal);
+
+impl Terminal<anyhow::Error> for Terminal256 {
+    fn init(&mut self) -> Result<(), anyhow::Error>
+    where
+        anyhow::Error: From<io::Error>,
+    {
+        self.0.init()
+    }
+
+    fn shutdown(&mut self) -> Result<(), anyhow::Error>
+    where
+        anyhow::Error: From<io::Error>,
+    {
+        self.0.shutdown()
+    }
+
+    fn size(&self) -> Option<Size> {
+        self.0.size()
+    }
+
+    fn render(
+        &mut self,
+        f: &mut dyn FnMut(&mut Frame<'_>) -> Result<usize, anyhow::Error>,
+    ) -> Result<usize, anyhow::Error>
+    where
+        anyhow::Error: From<io::Error>,
+    {
+        self.0.render(f)
+    }
+
+    fn color_depth(&self) -> ColorDepth {
+        ColorDepth::Ansi256
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    init: Option<ColorDepth>,
+    render: Option<ColorDepth>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        state: &mut Self::State,
+        ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        state.render = Some(ctx.color_depth);
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        self.init = Some(ctx.color_depth());
+        Ok(())
+    }
+}
+
+fn run(cfg: RunConfig<Event, anyhow::Error>) -> Result<State, anyhow::Error> {
+    let mut state = State::default();
+    run_tui(App, &mut (), &mut state, cfg.poll(PollQuit))?;
+    Ok(state)
+}
+
+#[test]
+fn test_color_depth() -> Result<(), anyhow::Error> {
+    // detected by the terminal.
+    let state = run(RunConfig::new(Terminal256(TestTerminal::new(20, 5))))?;
+    assert_eq!(state.init, Some(ColorDepth::Ansi256));
+    assert_eq!(state.render, Some(ColorDepth::Ansi256));
+
+    // the override wins.
+    let state =
+        run(RunConfig::new(Terminal256(TestTerminal::new(20, 5))).color_depth(ColorDepth::Ansi16))?;
+    assert_eq!(state.init, Some(ColorDepth::Ansi16));
+    assert_eq!(state.render, Some(ColorDepth::Ansi16));
+
+    // the default detection.
+    let colorterm = env::var_os("COLORTERM");
+    let term = env::var_os("TERM");
+
+    env::set_var("COLORTERM", "truecolor");
+    env::set_var("TERM", "xterm-256color");
+    assert_eq!(detect_color_depth(), ColorDepth::TrueColor);
+    let state = run(RunConfig::new(TestTerminal::new(20, 5)))?;
+    assert_eq!(state.init, Some(ColorDepth::TrueColor));
+
+    env::remove_var("COLORTERM");
+    assert_eq!(detect_color_depth(), ColorDepth::Ansi256);
+    env::set_var("TERM", "xterm-direct");
+    assert_eq!(detect_color_depth(), ColorDepth::TrueColor);
+    env::set_var("TERM", "linux");
+    assert_eq!(detect_color_depth(), ColorDepth::Ansi16);
+
+    // rat-theme converts the depth.
+    let theme = DarkTheme::new("Imperial".into(), IMPERIAL).degrade_to(ColorDepth::Ansi256);
+    assert_eq!(theme.color_depth(), rat_theme::ColorDepth::Ansi256);
+
+    match colorterm {
+        Some(v) => env::set_var("COLORTERM", v),
+        None => env::remove_var("COLORTERM"),
+    }
+    match term {
+        Some(v) => env::set_var("TERM", v),
+        None => env::remove_var("TERM"),
+    }
+    Ok(())
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cmp::max;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    Start,
+    A,
+    B,
+    C,
+    Never,
+}
+
+#[test]
+fn test_and() {
+    let c = Control::<Event>::Changed.and(Control::Event(Event::A));
+    assert!(matches!(
+        c,
+        Control::Multiple(ref v)
+            if matches!(v[..], [Control::Changed, Control::Event(Event::A)])
+    ));
+
+    // appends to an existing Multiple.
+    let c = c.and(Control::Quit);
+    assert!(matches!(
+        c,
+        Control::Multiple(ref v)
+            if matches!(v[..], [Control::Changed, Control::Event(Event::A), Control::Quit])
+    ));
+}
+
+#[test]
+fn test_eq() {
+    let a = Control::Multiple(vec![Control::Changed, Control::<Event>::Unchanged]);
+    let b = Control::Multiple(vec![Control::Changed, Control::Quit]);
+    assert_ne!(a, b);
+    assert_eq!(a, a.clone());
+    assert_ne!(
+        Control::Multiple(vec![Control::<Event>::Changed]),
+        Control::Changed
+    );
+    // events only by discriminant.
+    assert_eq!(Control::Event(Event::A), Control::Event(Event::B));
+}
+
+#[test]
+fn test_ord() {
+    let m = Control::Multiple(vec![Control::Unchanged, Control::<Event>::Changed]);
+    assert_eq!(m.rank(), Control::<Event>::Changed.rank());
+    assert!(m > Control::Unchanged);
+    assert!(m < Control::Event(Event::A));
+
+    // consistent with eq.
+    assert!(m > Control::Changed);
+    assert_ne!(m, Control::Changed);
+    let n = Control::Multiple(vec![Control::Changed, Control::<Event>::Continue]);
+    assert!(n > m);
+    assert_eq!(m.cmp(&m.clone()), std::cmp::Ordering::Equal);
+
+    // the merge of results keeps the highest priority.
+    assert_eq!(max(m.clone(), Control::Quit), Control::Quit);
+    assert!(matches!(
+        max(m.clone(), Control::Continue),
+        Control::Multiple(_)
+    ));
+    assert_eq!(
+        max(Control::<Event>::Continue, Control::Multiple(vec![])).rank(),
+        Control::<Event>::Continue.rank()
+    );
+}
+
+/// Sends Start once.
+struct PollStart(bool);
+
+impl PollEvents<Event, anyhow::Error> for PollStart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(!self.0)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.0 = true;
+        Ok(Control::Event(Event::Start))
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    events: Vec<Event>,
+    renders: usize,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        state.renders += 1;
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.events.push(*event);
+        match event {
+            Event::Start => {
+                ctx.queue(Control::Event(Event::C));
+                Ok(Control::Event(Event::A)
+                    .and(Control::Changed)
+                    .and(Control::Event(Event::B)))
+            }
+            Event::B => Ok(Control::Multiple(vec![
+                Control::Quit,
+                Control::Event(Event::Never),
+            ])),
+            _ => Ok(Control::Continue),
+        }
+    }
+}
+
+#[test]
+fn test_flatten() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(PollStart(false)),
+    )?;
+
+    // queued results first, then the return value in order.
+    // nothing after the Quit.
+    assert_eq!(
+        state.events,
+        vec![Event::Start, Event::C, Event::A, Event::B]
+    );
+    // initial render and Changed.
+    assert_eq!(state.renders, 2);
+    Ok(())
+}
@@ -0,0 +1,151 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollTasks;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use rat_widget::event::util::PendingOp;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+    Done(&'static str),
+}
+
+/// Sends Tick forever.
+struct PollTick;
+
+impl PollEvents<Event, anyhow::Error> for PollTick {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        thread::sleep(Duration::from_millis(1));
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    ticks: usize,
+    op: PendingOp,
+    canceled: PendingOp,
+    done: Vec<&'static str>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Tick => {
+                self.ticks += 1;
+                match self.ticks {
+                    1 => {
+                        let token = self.op.start();
+                        _ = ctx.defer(
+                            token,
+                            |_| {
+                                thread::sleep(Duration::from_millis(100));
+                                Ok("slow")
+                            },
+                            |v| Control::Event(Event::Done(v)),
+                        )?;
+                    }
+                    2 => {
+                        // the slow one becomes stale.
+                        let token = self.op.start();
+                        _ = ctx.defer(token, |_| Ok("fast"), |v| Control::Event(Event::Done(v)))?;
+                        assert!(self.op.is_pending());
+
+                        let token = self.canceled.start();
+                        _ = ctx.defer(
+                            token,
+                            |_| Ok("canceled"),
+                            |v| Control::Event(Event::Done(v)),
+                        )?;
+                        self.canceled.cancel();
+                    }
+                    _ => {}
+                }
+            }
+            Event::Done(v) => {
+                self.done.push(v);
+            }
+        }
+
+        if self.ticks > 500 {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+}
+
+#[test]
+fn test_defer() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term)
+            .poll(PollTick)
+            .poll(PollTasks::default()),
+    )?;
+
+    assert_eq!(state.done, vec!["fast"]);
+    assert!(!state.op.is_pending());
+    assert!(!state.canceled.is_pending());
+    Ok(())
+}
+
+#[test]
+fn test_token() {
+    let op = PendingOp::new();
+    let t1 = op.start();
+    assert!(t1.is_current());
+    let t2 = op.start();
+    assert!(!op.is_current(&t1));
+    assert!(op.is_current(&t2));
+    assert!(!t1.finish());
+    assert!(op.is_pending());
+    assert!(t2.finish());
+    assert!(!op.is_pending());
+
+    let t3 = op.start();
+    drop(op);
+    assert!(!t3.is_current());
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollTokio;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use rat_widget::event::util::PendingOp;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cell::RefCell;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+    Done,
+}
+
+/// Sends Tick forever.
+struct PollTick;
+
+impl PollEvents<Event, anyhow::Error> for PollTick {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        thread::sleep(Duration::from_millis(1));
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    ticks: usize,
+    op: PendingOp,
+    done: bool,
+    errors: RefCell<Vec<String>>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Tick => {
+                self.ticks += 1;
+                if self.ticks == 1 {
+                    let token = self.op.start();
+                    ctx.defer_async(
+                        token,
+                        async {
+                            if true {
+                                panic!("worker failed");
+                            }
+                            Ok(())
+                        },
+                        |_| Control::Event(Event::Done),
+                    );
+                }
+            }
+            Event::Done => {
+                self.done = true;
+            }
+        }
+
+        if !self.op.is_pending() || self.ticks > 2000 {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+
+    fn error(
+        &self,
+        event: anyhow::Error,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.errors.borrow_mut().push(event.to_string());
+        Ok(Control::Continue)
+    }
+}
+
+#[test]
+fn test_defer_panic() -> Result<(), anyhow::Error> {
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .build()?;
+
+    let mut state = State::default();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5))
+            .poll(PollTick)
+            .poll(PollTokio::new(rt)),
+    )?;
+
+    // the entry is removed and reported.
+    assert!(!state.op.is_pending());
+    assert!(!state.done);
+    assert!(state
+        .errors
+        .borrow()
+        .iter()
+        .any(|v| v == "deferred work ended without a result"));
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use rat_salsa::{Control, EventDispatcher};
+use rat_widget::event::flow;
+
+#[derive(Debug)]
+enum AppEvent {
+    Saved(u32),
+    Tick,
+    Other,
+}
+
+#[derive(Default)]
+struct State {
+    calls: Vec<&'static str>,
+}
+
+fn dispatcher() -> EventDispatcher<State, AppEvent, AppEvent> {
+    let mut d = EventDispatcher::new();
+    d.broadcast(
+        |e| matches!(e, AppEvent::Saved(_) | AppEvent::Tick),
+        |s: &mut State, _| {
+            s.calls.push("status");
+            Control::Unchanged
+        },
+    );
+    d.on(
+        |e| matches!(e, AppEvent::Saved(_)),
+        |s: &mut State, e| {
+            s.calls.push("files");
+            match e {
+                AppEvent::Saved(0) => Control::Continue,
+                _ => Control::Changed,
+            }
+        },
+    );
+    d.on(
+        |e| matches!(e, AppEvent::Saved(_)),
+        |s: &mut State, _| {
+            s.calls.push("files2");
+            Control::Changed
+        },
+    );
+    d.fallback(|s: &mut State, _| {
+        s.calls.push("fallback");
+        Control::Continue
+    });
+    d
+}
+
+#[test]
+fn test_first_consumer() {
+    let d = dispatcher();
+    let mut state = State::default();
+
+    let r = d.dispatch(&mut state, &AppEvent::Saved(1));
+    assert_eq!(state.calls, vec!["status", "files"]);
+    // both results are kept.
+    assert!(matches!(
+        r,
+        Control::Multiple(ref v) if v.len() == 2
+    ));
+    assert!(r >= Control::Changed);
+}
+
+#[test]
+fn test_continue_to_next() {
+    let d = dispatcher();
+    let mut state = State::default();
+
+    _ = d.dispatch(&mut state, &AppEvent::Saved(0));
+    assert_eq!(state.calls, vec!["status", "files", "files2"]);
+}
+
+#[test]
+fn test_broadcast_only() {
+    let d = dispatcher();
+    let mut state = State::default();
+
+    let r = d.dispatch(&mut state, &AppEvent::Tick);
+    assert_eq!(state.calls, vec!["status"]);
+    assert_eq!(r, Control::Unchanged);
+}
+
+#[test]
+fn test_fallback() {
+    let mut d = dispatcher();
+    d.log_unhandled();
+    let mut state = State::default();
+
+    let r = d.dispatch(&mut state, &AppEvent::Other);
+    assert_eq!(state.calls, vec!["fallback"]);
+    assert_eq!(r, Control::Continue);
+}
+
+#[test]
+fn test_flow() {
+    fn handle(
+        d: &EventDispatcher<State, AppEvent, AppEvent>,
+        s: &mut State,
+        e: &AppEvent,
+    ) -> Control<AppEvent> {
+        flow!(d.dispatch(s, e));
+        s.calls.push("after");
+        Control::Continue
+    }
+
+    let d = dispatcher();
+    let mut state = State::default();
+    _ = handle(&d, &mut state, &AppEvent::Other);
+    assert_eq!(state.calls, vec!["fallback", "after"]);
+
+    let mut state = State::default();
+    _ = handle(&d, &mut state, &AppEvent::Tick);
+    assert_eq!(state.calls, vec!["status"]);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use rat_widget::event::{feedback, Feedback, Severity};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+}
+
+/// Sends Tick forever.
+struct PollTick;
+
+impl PollEvents<Event, anyhow::Error> for PollTick {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        thread::sleep(Duration::from_millis(1));
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+struct App;
+
+#[derive(Debug, Default)]
+struct State {
+    ticks: usize,
+    /// Taken in the same event.
+    direct: Vec<Feedback>,
+    /// Taken in a later event.
+    later: Vec<Feedback>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Tick => {
+                self.ticks += 1;
+                match self.ticks {
+                    1 => {
+                        feedback(Severity::Info, "direct");
+                        self.direct = ctx.take_feedback();
+                    }
+                    2 => {
+                        for n in 0..5 {
+                            feedback(Severity::Warning, format!("later {}", n));
+                        }
+                    }
+                    _ => {
+                        self.later = ctx.take_feedback();
+                        return Ok(Control::Quit);
+                    }
+                }
+            }
+        }
+        Ok(Control::Continue)
+    }
+}
+
+#[test]
+fn test_feedback() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term).poll(PollTick).feedback(3),
+    )?;
+
+    assert_eq!(state.direct.len(), 1);
+    assert_eq!(state.direct[0].msg, "direct");
+    // bounded
+    assert_eq!(state.later.len(), 3);
+    assert_eq!(state.later[0].severity, Severity::Warning);
+    assert_eq!(state.later[0].msg, "later 2");
+    assert_eq!(state.later[2].msg, "later 4");
+    Ok(())
+}
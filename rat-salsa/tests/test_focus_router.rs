@@ -0,0 +1,110 @@
+use rat_salsa::{Control, FocusRouter};
+use rat_widget::focus::FocusFlag;
+
+#[derive(Debug, Default)]
+struct State {
+    calls: Vec<&'static str>,
+}
+
+fn router(a: &FocusFlag, b: &FocusFlag) -> FocusRouter<State, char, ()> {
+    let mut router = FocusRouter::new();
+    router
+        .popup(|s: &mut State, e| {
+            s.calls.push("popup");
+            if *e == 'p' {
+                Control::Changed
+            } else {
+                Control::Continue
+            }
+        })
+        .widget(a, |s: &mut State, e| {
+            s.calls.push("a");
+            if *e == 'x' {
+                Control::Changed
+            } else {
+                Control::Continue
+            }
+        })
+        .widget(b, |s: &mut State, e| {
+            s.calls.push("b");
+            if *e == 'x' {
+                Control::Unchanged
+            } else {
+                Control::Continue
+            }
+        })
+        .fallback(|s: &mut State, e| {
+            s.calls.push("f1");
+            if *e == 'f' {
+                Control::Changed
+            } else {
+                Control::Continue
+            }
+        })
+        .fallback(|s: &mut State, _e| {
+            s.calls.push("f2");
+            Control::Continue
+        });
+    router
+}
+
+fn route(router: &FocusRouter<State, char, ()>, event: char) -> (Control<()>, Vec<&'static str>) {
+    let mut state = State::default();
+    let r = router.route(&mut state, &event);
+    (r, state.calls)
+}
+
+#[test]
+fn test_route() {
+    let a = FocusFlag::named("a");
+    let b = FocusFlag::named("b");
+    let router = router(&a, &b);
+
+    // only the focused widget.
+    b.set(true);
+    assert_eq!(
+        route(&router, 'x'),
+        (Control::Unchanged, vec!["popup", "b"])
+    );
+
+    // popups first.
+    assert_eq!(route(&router, 'p'), (Control::Changed, vec!["popup"]));
+
+    // fallbacks in order, until one consumes.
+    assert_eq!(
+        route(&router, 'f'),
+        (Control::Changed, vec!["popup", "b", "f1"])
+    );
+    assert_eq!(
+        route(&router, 'z'),
+        (Control::Continue, vec!["popup", "b", "f1", "f2"])
+    );
+
+    // focused widgets in registration order, like a container
+    // and its child.
+    a.set(true);
+    assert_eq!(route(&router, 'x'), (Control::Changed, vec!["popup", "a"]));
+
+    // nothing focused.
+    a.set(false);
+    b.set(false);
+    assert_eq!(
+        route(&router, 'x'),
+        (Control::Continue, vec!["popup", "f1", "f2"])
+    );
+}
+
+#[test]
+fn test_clear() {
+    let a = FocusFlag::named("a");
+    let b = FocusFlag::named("b");
+    a.set(true);
+
+    let mut router = router(&a, &b);
+    // a clone keeps the handlers.
+    let clone = router.clone();
+    router.clear();
+
+    assert_eq!(route(&router, 'x'), (Control::Continue, vec![]));
+    assert_eq!(route(&clone, 'x'), (Control::Changed, vec!["popup", "a"]));
+}
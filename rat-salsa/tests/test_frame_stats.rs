@@ -0,0 +1,147 @@
+mod common;
+
+use common::TestTerminal;
+use crossbeam::channel::{bounded, Receiver, Sender};
+use rat_salsa::poll::PollTasks;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, FrameStats, RenderContext, RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug)]
+enum Event {
+    Step(usize),
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    /// Keeps the background task alive.
+    stop: Option<Sender<()>>,
+    /// queue_len and task_count for each step.
+    load: Vec<(usize, usize)>,
+    /// frame_stats for each step.
+    stats: Vec<FrameStats>,
+    /// RenderContext::stats for each frame.
+    rendered: Vec<FrameStats>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        state: &mut Self::State,
+        ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        state.rendered.push(ctx.stats);
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        let (send, recv): (Sender<()>, Receiver<()>) = bounded(1);
+        self.stop = Some(send);
+        ctx.spawn(move |_, _| {
+            _ = recv.recv();
+            Ok(Control::Continue)
+        })?;
+        ctx.queue(Control::Event(Event::Step(0)));
+        ctx.queue(Control::Event(Event::Step(1)));
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.load.push((ctx.queue_len(), ctx.task_count()));
+        self.stats.push(ctx.frame_stats());
+        match event {
+            Event::Step(0) => {
+                thread::sleep(Duration::from_millis(5));
+                Ok(Control::Changed)
+            }
+            Event::Step(1) => {
+                // let the task finish.
+                self.stop = None;
+                thread::sleep(Duration::from_millis(50));
+                ctx.queue(Control::Event(Event::Step(2)));
+                Ok(Control::Changed)
+            }
+            _ => Ok(Control::Quit),
+        }
+    }
+}
+
+#[test]
+fn test_frame_stats() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(PollTasks::default()),
+    )?;
+
+    // Step(1) waits in the queue, the task is running.
+    assert_eq!(state.load[0], (1, 1));
+    // the Changed from Step(0) waits.
+    assert_eq!(state.load[1], (1, 1));
+    // the task has finished.
+    assert_eq!(state.load[2], (1, 0));
+    assert_eq!(state.stats[2].queue_len, 1);
+    assert_eq!(state.stats[2].tasks, 0);
+
+    // the time for the previous step.
+    assert_eq!(state.stats[0].event, Duration::ZERO);
+    assert!(state.stats[1].event >= Duration::from_millis(5));
+    assert!(state.stats[2].event >= Duration::from_millis(50));
+
+    // initial render and the one for Step(0).
+    assert_eq!(state.stats[2].count, 1);
+    let avg = (state.rendered[1].render + state.rendered[2].render) / 2;
+    assert_eq!(state.stats[2].render_avg, avg);
+
+    // stats as of the start of each frame.
+    assert_eq!(state.rendered.len(), 3);
+    assert_eq!(state.rendered[0].render, Duration::ZERO);
+    assert_eq!(state.rendered[2].count, 1);
+
+    Ok(())
+}
+
+#[cfg(feature = "debug-status")]
+#[test]
+fn test_debug_status() {
+    use rat_salsa::debug_status::DebugStatus;
+    use ratatui::widgets::Widget;
+
+    let stats = FrameStats {
+        count: 12,
+        render: Duration::from_micros(1500),
+        render_avg: Duration::from_micros(1000),
+        event: Duration::from_micros(250),
+        queue_len: 3,
+        tasks: 2,
+    };
+    let status = DebugStatus::new(stats);
+    let text = "#12 R 1.5ms ~1.0ms E 250.0µs Q 3 T 2";
+    assert_eq!(status.width() as usize, text.chars().count());
+
+    let area = Rect::new(0, 0, 40, 1);
+    let mut buf = Buffer::empty(area);
+    status.render(area, &mut buf);
+    assert_eq!(buf, Buffer::with_lines([format!("{:>40}", text)]));
+}
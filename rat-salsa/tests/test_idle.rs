@@ -0,0 +1,117 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+const IDLE: Duration = Duration::from_millis(20);
+
+#[derive(Debug)]
+enum Event {
+    Wake,
+}
+
+/// Sends Wake once at the given time.
+struct PollWake(Option<Instant>);
+
+impl PollEvents<Event, anyhow::Error> for PollWake {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(self.0.is_some_and(|v| Instant::now() >= v))
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.0 = None;
+        Ok(Control::Event(Event::Wake))
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    idle: Vec<Instant>,
+    wake: Option<Instant>,
+    renders: usize,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        state.renders += 1;
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        _event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.wake = Some(Instant::now());
+        Ok(Control::Continue)
+    }
+
+    fn idle(
+        &mut self,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.idle.push(Instant::now());
+        match self.idle.len() {
+            1 => Ok(Control::Changed),
+            n if n >= 4 && self.wake.is_some() => Ok(Control::Quit),
+            _ => Ok(Control::Continue),
+        }
+    }
+}
+
+#[test]
+fn test_idle() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    let start = Instant::now();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5))
+            .poll(PollWake(Some(start + Duration::from_millis(50))))
+            .idle(IDLE),
+    )?;
+
+    assert!(state.idle.len() >= 4);
+    assert!(state.idle[0].duration_since(start) >= IDLE);
+    // waits the full duration after each call.
+    for w in state.idle.windows(2) {
+        assert!(w[1].duration_since(w[0]) >= IDLE);
+    }
+    // and after each event. The wait starts just before the
+    // event is read.
+    let wake = state.wake.expect("wake");
+    let next = state
+        .idle
+        .iter()
+        .find(|v| **v > wake)
+        .expect("idle after wake");
+    assert!(next.duration_since(wake) >= IDLE - Duration::from_millis(1));
+
+    // Changed renders as usual.
+    assert_eq!(state.renders, 2);
+    Ok(())
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use common::{screen, SharedTerminal, TestTerminal};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use std::any::Any;
+
+#[derive(Debug)]
+enum Event {
+    Start,
+}
+
+/// Sends Start once.
+struct PollStart(bool);
+
+impl PollEvents<Event, anyhow::Error> for PollStart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(!self.0)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.0 = true;
+        Ok(Control::Event(Event::Start))
+    }
+}
+
+struct App;
+
+struct State;
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        "app".render(area, buf);
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        _event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        ctx.insert_text("log 1\nlog 2")?;
+        ctx.insert_before(1, |buf| "log 3".render(buf.area, buf))?;
+        Ok(Control::Changed.and(Control::Quit))
+    }
+}
+
+fn run(term: TestTerminal) -> Result<SharedTerminal, anyhow::Error> {
+    let shared = term.terminal();
+    run_tui(
+        App,
+        &mut (),
+        &mut State,
+        RunConfig::new(term).poll(PollStart(false)),
+    )?;
+    Ok(shared)
+}
+
+#[test]
+fn test_inline() -> Result<(), anyhow::Error> {
+    let term = run(TestTerminal::inline(8, 6, 2))?;
+
+    // the output goes above the viewport.
+    assert_eq!(
+        screen(&term),
+        "log 1   \nlog 2   \nlog 3   \napp     \n        \n        \n"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_fullscreen() -> Result<(), anyhow::Error> {
+    let term = run(TestTerminal::new(8, 3))?;
+
+    // does nothing.
+    assert_eq!(screen(&term), "app     \n        \n        \n");
+    Ok(())
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use common::TestTerminal;
+use crossterm::event::{KeyCode, KeyModifiers};
+use rat_salsa::poll::PollTimers;
+use rat_salsa::timer::{TimeOut, TimerDef};
+use rat_salsa::{run_tui, AppContext, AppState, AppWidget, Control, RenderContext, RunConfig};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::time::Duration;
+
+#[derive(Debug)]
+enum Event {
+    Timer(TimeOut),
+}
+
+impl From<TimeOut> for Event {
+    fn from(value: TimeOut) -> Self {
+        Self::Timer(value)
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    blocked_at_init: Option<bool>,
+    /// Ticks with the blocked flag.
+    ticks: Vec<bool>,
+    /// Renders with the blocked flag.
+    renders: Vec<bool>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        state: &mut Self::State,
+        ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        state.renders.push(ctx.input_blocked);
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        self.blocked_at_init = Some(ctx.input_blocked());
+        ctx.set_input_blocked(true);
+        ctx.add_timer(
+            TimerDef::new()
+                .timer(Duration::from_millis(5))
+                .repeat_forever(),
+        );
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        let Event::Timer(t) = event;
+        // timers are still delivered.
+        self.ticks.push(ctx.input_blocked());
+        match t.counter {
+            2 => {
+                ctx.set_input_blocked(false);
+                Ok(Control::Changed)
+            }
+            3 => {
+                ctx.set_input_blocked(true);
+                Ok(Control::Quit)
+            }
+            _ => Ok(Control::Changed),
+        }
+    }
+}
+
+fn run(state: &mut State) -> Result<(), anyhow::Error> {
+    run_tui(
+        App,
+        &mut (),
+        state,
+        RunConfig::new(TestTerminal::new(20, 5))
+            .poll(PollTimers::default())
+            .abort_key(KeyCode::Esc, KeyModifiers::NONE),
+    )
+}
+
+#[test]
+fn test_input_blocked() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    run(&mut state)?;
+
+    assert_eq!(state.blocked_at_init, Some(false));
+    assert_eq!(state.ticks, vec![true, true, true, false]);
+    // the busy state is visible while rendering.
+    assert_eq!(state.renders, vec![true, true, true, false]);
+
+    // each event-loop starts unblocked, even though the last
+    // one ended blocked.
+    let mut state = State::default();
+    run(&mut state)?;
+    assert_eq!(state.blocked_at_init, Some(false));
+    Ok(())
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, Intercept, PollError, PollEvents,
+    RenderContext, RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    Start,
+    Legacy,
+    New,
+    Secret,
+    Confirm,
+}
+
+/// Sends Start once.
+struct PollStart(bool);
+
+impl PollEvents<Event, anyhow::Error> for PollStart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(!self.0)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.0 = true;
+        Ok(Control::Event(Event::Start))
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    events: Vec<Event>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.events.push(*event);
+        match event {
+            Event::Start => Ok(Control::Event(Event::Legacy)
+                .and(Control::Event(Event::Secret))
+                .and(Control::Quit)),
+            // confirmed.
+            Event::Confirm => Ok(Control::Quit),
+            _ => Ok(Control::Continue),
+        }
+    }
+}
+
+#[test]
+fn test_intercept() -> Result<(), anyhow::Error> {
+    let first = Rc::new(RefCell::new(Vec::new()));
+    let last = Rc::new(RefCell::new(Vec::new()));
+    let results = Rc::new(RefCell::new(Vec::new()));
+
+    let mut state = State::default();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5))
+            .poll(PollStart(false))
+            .intercept({
+                let first = first.clone();
+                move |event, _ctx| {
+                    first.borrow_mut().push(*event);
+                    Intercept::Pass
+                }
+            })
+            .intercept(|event, _ctx| match event {
+                Event::Legacy => Intercept::Replace(Event::New),
+                Event::Secret => Intercept::Consume(Control::Continue),
+                _ => Intercept::Pass,
+            })
+            .intercept({
+                let last = last.clone();
+                move |event, _ctx| {
+                    last.borrow_mut().push(*event);
+                    Intercept::Pass
+                }
+            })
+            .intercept_result({
+                let mut confirmed = false;
+                move |ctrl, _ctx| match ctrl {
+                    Control::Quit if !confirmed => {
+                        confirmed = true;
+                        Control::Event(Event::Confirm)
+                    }
+                    ctrl => ctrl,
+                }
+            })
+            .intercept_result({
+                let results = results.clone();
+                move |ctrl, _ctx| {
+                    if !matches!(ctrl, Control::Continue) {
+                        results.borrow_mut().push(format!("{:?}", ctrl));
+                    }
+                    ctrl
+                }
+            }),
+    )?;
+
+    // the chain runs in registration order.
+    assert_eq!(
+        *first.borrow(),
+        vec![Event::Start, Event::Legacy, Event::Secret, Event::Confirm]
+    );
+    assert_eq!(
+        *last.borrow(),
+        vec![Event::Start, Event::New, Event::Confirm]
+    );
+    assert_eq!(state.events, vec![Event::Start, Event::New, Event::Confirm]);
+
+    // each part of the Multiple is intercepted, the second
+    // interceptor sees the replaced Quit.
+    assert_eq!(
+        *results.borrow(),
+        vec![
+            "Event(Start)",
+            "Event(Legacy)",
+            "Event(Secret)",
+            "Event(Confirm)",
+            "Quit"
+        ]
+    );
+    Ok(())
+}
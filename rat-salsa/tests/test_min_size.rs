@@ -0,0 +1,144 @@
+mod common;
+
+use common::{screen, SharedTerminal, TestTerminal};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::widgets::Widget;
+use std::any::Any;
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+}
+
+/// Sends Tick forever.
+struct PollTick;
+
+impl PollEvents<Event, anyhow::Error> for PollTick {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+struct App;
+
+struct State {
+    term: SharedTerminal,
+    ticks: usize,
+    renders: Vec<Rect>,
+    sizes: Vec<Size>,
+    screens: Vec<String>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        "app".render(area, buf);
+        state.renders.push(area);
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Tick => {
+                self.ticks += 1;
+                self.sizes.push(ctx.terminal_size());
+                self.screens.push(screen(&self.term));
+                match self.ticks {
+                    1 => {
+                        self.term.borrow_mut().backend_mut().resize(40, 10);
+                        Ok(Control::Changed)
+                    }
+                    2 => {
+                        self.term.borrow_mut().backend_mut().resize(80, 24);
+                        Ok(Control::Changed)
+                    }
+                    _ => Ok(Control::Quit),
+                }
+            }
+        }
+    }
+}
+
+fn run(
+    cfg: impl FnOnce(RunConfig<Event, anyhow::Error>) -> RunConfig<Event, anyhow::Error>,
+) -> Result<State, anyhow::Error> {
+    let term = TestTerminal::new(80, 24);
+    let mut state = State {
+        term: term.terminal(),
+        ticks: 0,
+        renders: Vec::new(),
+        sizes: Vec::new(),
+        screens: Vec::new(),
+    };
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        cfg(RunConfig::new(term).poll(PollTick)),
+    )?;
+    Ok(state)
+}
+
+#[test]
+fn test_min_size() -> Result<(), anyhow::Error> {
+    let state = run(|cfg| cfg.min_size(80, 24))?;
+
+    // no render while too small, and again after the size recovers.
+    assert_eq!(
+        state.renders,
+        vec![Rect::new(0, 0, 80, 24), Rect::new(0, 0, 80, 24)]
+    );
+    assert_eq!(
+        state.sizes,
+        vec![Size::new(80, 24), Size::new(40, 10), Size::new(80, 24)]
+    );
+    assert!(state.screens[0].starts_with("app"));
+    assert!(!state.screens[1].contains("app"));
+    let text = state.screens[1].split_whitespace().collect::<Vec<_>>();
+    assert_eq!(text.join(" "), "Terminal too small: need 80x24, have 40x10");
+    assert!(state.screens[2].starts_with("app"));
+    Ok(())
+}
+
+#[test]
+fn test_too_small_screen() -> Result<(), anyhow::Error> {
+    let state = run(|cfg| {
+        cfg.min_size(60, 20).too_small_screen(|need, area, buf| {
+            format!(
+                "{}x{} < {}x{}",
+                area.width, area.height, need.width, need.height
+            )
+            .render(area, buf);
+        })
+    })?;
+
+    assert_eq!(state.renders.len(), 2);
+    assert!(state.screens[1].starts_with("40x10 < 60x20"));
+    Ok(())
+}
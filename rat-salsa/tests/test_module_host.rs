@@ -0,0 +1,193 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, Module, ModuleHost, PollError, PollEvents,
+    RenderContext, RunConfig,
+};
+use rat_widget::focus::{FocusBuilder, FocusFlag, HasFocus};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    Edit,
+    Detail,
+    Other,
+}
+
+/// Counts what it sees and consumes its own event.
+struct Counting {
+    consume: Event,
+    seen: Rc<Cell<usize>>,
+    rendered: Rc<Cell<usize>>,
+    focus: FocusFlag,
+}
+
+impl Counting {
+    fn new(consume: Event) -> Self {
+        Self {
+            consume,
+            seen: Default::default(),
+            rendered: Default::default(),
+            focus: Default::default(),
+        }
+    }
+}
+
+impl HasFocus for Counting {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.leaf_widget(self);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    fn area(&self) -> Rect {
+        Rect::new(0, 0, 1, 1)
+    }
+}
+
+impl Module<(), Event, anyhow::Error> for Counting {
+    fn render(
+        &mut self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        self.rendered.set(self.rendered.get() + 1);
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.seen.set(self.seen.get() + 1);
+        if *event == self.consume {
+            Ok(Control::Changed)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.widget(self);
+    }
+}
+
+struct App;
+
+struct State {
+    modules: ModuleHost<(), Event, anyhow::Error>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut Self::State,
+        ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        state.modules.render(area, buf, ctx)
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.modules.event(event, ctx)
+    }
+}
+
+/// Sends the events, then quits.
+struct PollScript(VecDeque<Event>);
+
+impl PollEvents<Event, anyhow::Error> for PollScript {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        Ok(match self.0.pop_front() {
+            Some(v) => Control::Event(v),
+            None => Control::Quit,
+        })
+    }
+}
+
+#[test]
+fn test_event_scoping() -> Result<(), anyhow::Error> {
+    let edit = Counting::new(Event::Edit);
+    let detail = Counting::new(Event::Detail);
+    let hidden = Counting::new(Event::Other);
+    let (edit_seen, edit_rendered) = (edit.seen.clone(), edit.rendered.clone());
+    let (detail_seen, detail_rendered) = (detail.seen.clone(), detail.rendered.clone());
+    let (hidden_seen, hidden_rendered) = (hidden.seen.clone(), hidden.rendered.clone());
+
+    let mut modules = ModuleHost::new();
+    modules.add_filtered(edit, |e| *e == Event::Edit);
+    modules.add(detail);
+    let n = modules.add(hidden);
+    modules.set_enabled(n, false);
+
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        &mut State { modules },
+        RunConfig::new(term).poll(PollScript(
+            [Event::Edit, Event::Detail, Event::Other, Event::Edit].into(),
+        )),
+    )?;
+
+    // edit only sees its own events.
+    assert_eq!(edit_seen.get(), 2);
+    // detail sees everything not consumed by edit.
+    assert_eq!(detail_seen.get(), 2);
+    // disabled.
+    assert_eq!(hidden_seen.get(), 0);
+    assert_eq!(hidden_rendered.get(), 0);
+    assert!(edit_rendered.get() > 0);
+    assert_eq!(edit_rendered.get(), detail_rendered.get());
+    Ok(())
+}
+
+#[test]
+fn test_focus() {
+    let edit = Counting::new(Event::Edit);
+    let detail = Counting::new(Event::Detail);
+    let edit_focus = edit.focus.clone();
+    let detail_focus = detail.focus.clone();
+
+    let mut modules = ModuleHost::<(), Event, anyhow::Error>::new();
+    let e = modules.add(edit);
+    modules.add(detail);
+
+    let focus = modules.focus(None);
+    focus.first();
+    assert!(edit_focus.get());
+    focus.next();
+    assert!(detail_focus.get());
+
+    modules.set_enabled(e, false);
+    let focus = modules.focus(Some(focus));
+    focus.first();
+    assert!(detail_focus.get());
+}
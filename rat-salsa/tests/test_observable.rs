@@ -0,0 +1,145 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::observable::{ObservableReader, ObservableWriter};
+use rat_salsa::poll::PollTasks;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+    Path(String),
+}
+
+/// Sends Tick forever.
+struct PollTick;
+
+impl PollEvents<Event, anyhow::Error> for PollTick {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        thread::sleep(Duration::from_millis(1));
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    ticks: usize,
+    path: Option<(ObservableWriter<String>, ObservableReader<String>)>,
+    status: Option<(ObservableWriter<u32>, ObservableReader<u32>)>,
+    status_gen: u64,
+
+    path_events: Vec<String>,
+    rendered_status: Vec<u32>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        if let Some((_, status)) = &state.status {
+            if status.changed_since(state.status_gen) {
+                state.status_gen = status.generation();
+                state.rendered_status.push(status.get());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        self.path = Some(ctx.observable_event(String::new(), |v| Event::Path(v.clone())));
+        self.status = Some(ctx.observable(0));
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        let (path, path_reader) = self.path.as_ref().expect("path");
+        let (status, _) = self.status.as_ref().expect("status");
+
+        match event {
+            Event::Tick => {
+                self.ticks += 1;
+                match self.ticks {
+                    1 => {
+                        path.set("/home".into());
+                        path.update(|v| v.push_str("/user"));
+                        assert_eq!(path_reader.generation(), 2);
+                    }
+                    5 => {
+                        status.set(1);
+                    }
+                    10 => {
+                        let sender = status.sender();
+                        _ = ctx.spawn(move |_, send| {
+                            sender.set(2, send)?;
+                            Ok(Control::Continue)
+                        })?;
+                    }
+                    _ => {}
+                }
+            }
+            Event::Path(v) => {
+                assert_eq!(path_reader.borrow().as_str(), "/home/user");
+                self.path_events.push(v.clone());
+            }
+        }
+
+        if self.rendered_status.contains(&2) || self.ticks > 5000 {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+}
+
+#[test]
+fn test_observable() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term)
+            .poll(PollTick)
+            .poll(PollTasks::default()),
+    )?;
+
+    // one event per change, with the value at the time it was set.
+    assert_eq!(state.path_events, vec!["/home", "/home/user"]);
+    assert_eq!(state.rendered_status, vec![1, 2]);
+    Ok(())
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollTasks;
+use rat_salsa::thread_pool::{Overlap, PeriodicHandle};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+}
+
+/// Sends Tick forever.
+struct PollTick;
+
+impl PollEvents<Event, anyhow::Error> for PollTick {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(true)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        thread::sleep(Duration::from_millis(1));
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+struct App;
+
+#[derive(Debug, Default, Clone)]
+struct Counter {
+    runs: Arc<AtomicUsize>,
+    active: Arc<AtomicUsize>,
+    max_active: Arc<AtomicUsize>,
+    canceled: Arc<AtomicUsize>,
+}
+
+struct State {
+    overlap: Overlap,
+    /// Duration of each run.
+    work: Duration,
+    /// Stop the schedule after this many runs.
+    stop_after: Option<usize>,
+    /// Total runtime.
+    runtime: Duration,
+
+    start: Instant,
+    handle: Option<PeriodicHandle>,
+    counter: Counter,
+}
+
+impl State {
+    fn new(overlap: Overlap, work: u64) -> Self {
+        Self {
+            overlap,
+            work: Duration::from_millis(work),
+            stop_after: None,
+            runtime: Duration::from_millis(300),
+            start: Instant::now(),
+            handle: None,
+            counter: Default::default(),
+        }
+    }
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        let counter = self.counter.clone();
+        let work = self.work;
+        self.start = Instant::now();
+        self.handle =
+            Some(
+                ctx.spawn_periodic(Duration::from_millis(20), self.overlap, move || {
+                    let counter = counter.clone();
+                    move |cancel, _| {
+                        counter.runs.fetch_add(1, Ordering::AcqRel);
+                        let active = counter.active.fetch_add(1, Ordering::AcqRel) + 1;
+                        counter.max_active.fetch_max(active, Ordering::AcqRel);
+
+                        let start = Instant::now();
+                        while start.elapsed() < work {
+                            if cancel.is_canceled() {
+                                counter.canceled.fetch_add(1, Ordering::AcqRel);
+                                break;
+                            }
+                            thread::sleep(Duration::from_millis(1));
+                        }
+
+                        counter.active.fetch_sub(1, Ordering::AcqRel);
+                        Ok(Control::Continue)
+                    }
+                }),
+            );
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Tick => {
+                if let Some(stop_after) = self.stop_after {
+                    if self.counter.runs.load(Ordering::Acquire) >= stop_after {
+                        if let Some(handle) = &self.handle {
+                            handle.stop();
+                        }
+                    }
+                }
+            }
+        }
+
+        if self.start.elapsed() > self.runtime {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+}
+
+fn run(state: &mut State) -> Result<(), anyhow::Error> {
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        state,
+        RunConfig::new(term).poll(PollTick).poll(PollTasks::new(2)),
+    )
+}
+
+#[test]
+fn test_no_overlap() -> Result<(), anyhow::Error> {
+    let mut state = State::new(Overlap::Skip, 5);
+    run(&mut state)?;
+
+    // every 20ms in 300ms.
+    let runs = state.counter.runs.load(Ordering::Acquire);
+    assert!(runs >= 5, "runs {}", runs);
+    assert_eq!(state.counter.max_active.load(Ordering::Acquire), 1);
+    Ok(())
+}
+
+#[test]
+fn test_skip() -> Result<(), anyhow::Error> {
+    let mut state = State::new(Overlap::Skip, 50);
+    run(&mut state)?;
+
+    assert!(state.counter.runs.load(Ordering::Acquire) >= 2);
+    assert_eq!(state.counter.max_active.load(Ordering::Acquire), 1);
+    assert_eq!(state.counter.canceled.load(Ordering::Acquire), 0);
+    Ok(())
+}
+
+#[test]
+fn test_queue() -> Result<(), anyhow::Error> {
+    let mut state = State::new(Overlap::Queue, 50);
+    run(&mut state)?;
+
+    // back to back, but never at the same time.
+    assert!(state.counter.runs.load(Ordering::Acquire) >= 3);
+    assert_eq!(state.counter.max_active.load(Ordering::Acquire), 1);
+    assert_eq!(state.counter.canceled.load(Ordering::Acquire), 0);
+    Ok(())
+}
+
+#[test]
+fn test_cancel() -> Result<(), anyhow::Error> {
+    let mut state = State::new(Overlap::Cancel, 200);
+    run(&mut state)?;
+
+    // each tick cancels the previous run.
+    assert!(state.counter.runs.load(Ordering::Acquire) >= 3);
+    assert!(state.counter.canceled.load(Ordering::Acquire) >= 2);
+    Ok(())
+}
+
+#[test]
+fn test_stop() -> Result<(), anyhow::Error> {
+    let mut state = State::new(Overlap::Skip, 1);
+    state.stop_after = Some(2);
+    run(&mut state)?;
+
+    assert_eq!(state.counter.runs.load(Ordering::Acquire), 2);
+    assert!(state.handle.as_ref().expect("handle").is_stopped());
+    Ok(())
+}
@@ -0,0 +1,255 @@
+mod common;
+
+use anyhow::anyhow;
+use common::TestTerminal;
+use rat_salsa::poll::PollTasks;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+}
+
+enum Step {
+    Tick,
+    Recoverable,
+    Fatal,
+}
+
+/// Plays a script, then idles.
+struct PollScript {
+    steps: VecDeque<Step>,
+    /// Time each step was polled.
+    polled: Rc<RefCell<Vec<Instant>>>,
+}
+
+impl PollScript {
+    fn new(steps: impl IntoIterator<Item = Step>) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+            polled: Default::default(),
+        }
+    }
+}
+
+impl PollEvents<Event, anyhow::Error> for PollScript {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        if !self.steps.is_empty() {
+            self.polled.borrow_mut().push(Instant::now());
+        }
+        match self.steps.front() {
+            Some(Step::Tick) => Ok(true),
+            Some(Step::Recoverable) => {
+                self.steps.pop_front();
+                Err(PollError::from_io(io::Error::from(
+                    io::ErrorKind::Interrupted,
+                )))
+            }
+            Some(Step::Fatal) => {
+                self.steps.pop_front();
+                Err(PollError::Fatal(anyhow!("terminal is gone")))
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.steps.pop_front();
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+#[derive(Default)]
+struct Log {
+    ticks: usize,
+    warnings: Vec<Option<&'static str>>,
+    shutdown: bool,
+}
+
+struct App;
+
+struct State {
+    quit_after: usize,
+    spawn_panic: bool,
+}
+
+impl AppWidget<Log, Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, Log>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<Log, Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, Log, Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        if self.spawn_panic {
+            ctx.spawn(|_, _| panic!("task panic"))?;
+        }
+        Ok(())
+    }
+
+    fn shutdown(
+        &mut self,
+        ctx: &mut AppContext<'_, Log, Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        ctx.g.shutdown = true;
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        _event: &Event,
+        ctx: &mut AppContext<'_, Log, Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        ctx.g.ticks += 1;
+        if ctx.g.ticks == self.quit_after {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+
+    fn error(
+        &self,
+        _event: anyhow::Error,
+        ctx: &mut AppContext<'_, Log, Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        ctx.g.warnings.push(ctx.poll_warning());
+        Ok(Control::Continue)
+    }
+}
+
+#[test]
+fn test_recoverable_then_fatal() -> Result<(), anyhow::Error> {
+    let mut log = Log::default();
+    let mut state = State {
+        quit_after: 0,
+        spawn_panic: false,
+    };
+    let r = run_tui(
+        App,
+        &mut log,
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(PollScript::new([
+            Step::Tick,
+            Step::Recoverable,
+            Step::Recoverable,
+            Step::Tick,
+            Step::Fatal,
+            Step::Tick,
+        ])),
+    );
+
+    let err = r.expect_err("fatal");
+    assert_eq!(err.to_string(), "terminal is gone");
+    assert!(log.shutdown);
+    // the tick after the fatal error is never read.
+    assert_eq!(log.ticks, 2);
+    assert_eq!(log.warnings.len(), 2);
+    assert!(log.warnings[0].expect("warning").contains("PollScript"));
+
+    Ok(())
+}
+
+#[test]
+fn test_backoff() -> Result<(), anyhow::Error> {
+    let mut log = Log::default();
+    let mut state = State {
+        quit_after: 1,
+        spawn_panic: false,
+    };
+    let source = PollScript::new([Step::Recoverable, Step::Recoverable, Step::Tick]);
+    let polled = source.polled.clone();
+
+    run_tui(
+        App,
+        &mut log,
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(source),
+    )?;
+
+    assert_eq!(log.warnings.len(), 2);
+    assert_eq!(log.ticks, 1);
+
+    // the backoff doubles.
+    let polled = polled.borrow();
+    assert_eq!(polled.len(), 3);
+    assert!(polled[1] - polled[0] >= Duration::from_millis(10));
+    assert!(polled[2] - polled[1] >= Duration::from_millis(20));
+    Ok(())
+}
+
+#[test]
+fn test_quit_first() -> Result<(), anyhow::Error> {
+    let mut log = Log::default();
+    let mut state = State {
+        quit_after: 1,
+        spawn_panic: false,
+    };
+    run_tui(
+        App,
+        &mut log,
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(PollScript::new([Step::Tick, Step::Fatal])),
+    )?;
+    assert!(log.shutdown);
+    Ok(())
+}
+
+#[test]
+fn test_worker_panic() -> Result<(), anyhow::Error> {
+    let mut log = Log::default();
+    let mut state = State {
+        quit_after: 0,
+        spawn_panic: true,
+    };
+    let start = Instant::now();
+    let r = run_tui(
+        App,
+        &mut log,
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(PollTasks::default()),
+    );
+    let err = r.expect_err("fatal");
+    assert!(err.to_string().contains("panicked"));
+    assert!(log.shutdown);
+    assert!(start.elapsed() < Duration::from_secs(5));
+    Ok(())
+}
+
+#[test]
+fn test_from_io() {
+    let err = PollError::<anyhow::Error>::from_io(io::ErrorKind::Interrupted.into());
+    assert!(!err.is_fatal());
+    let err = PollError::<anyhow::Error>::from_io(io::ErrorKind::WouldBlock.into());
+    assert!(!err.is_fatal());
+    let err = PollError::<anyhow::Error>::from_io(io::ErrorKind::BrokenPipe.into());
+    assert!(err.is_fatal());
+    let err = PollError::<anyhow::Error>::from_io(io::ErrorKind::UnexpectedEof.into());
+    assert!(err.is_fatal());
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollStdin;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollEvents, RenderContext, RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::io::{self, Cursor, Read};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Line(String),
+    Eof,
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    lines: Vec<String>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Line(line) => {
+                self.lines.push(line.clone());
+                Ok(Control::Continue)
+            }
+            Event::Eof => Ok(Control::Quit),
+        }
+    }
+}
+
+fn run(poll: PollStdin<Event>) -> Result<State, anyhow::Error> {
+    let mut state = State::default();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(poll),
+    )?;
+    Ok(state)
+}
+
+#[test]
+fn test_lines() -> Result<(), anyhow::Error> {
+    let input = Cursor::new("first\r\nsecond\n\nlast");
+    let state = run(PollStdin::from_reader(input, Event::Line).eof(Event::Eof))?;
+    assert_eq!(state.lines, vec!["first", "second", "", "last"]);
+    Ok(())
+}
+
+#[test]
+fn test_delimiter() -> Result<(), anyhow::Error> {
+    let input = Cursor::new("a\nb\0c\0");
+    let state = run(PollStdin::from_reader(input, Event::Line)
+        .delimiter(0)
+        .eof(Event::Eof))?;
+    assert_eq!(state.lines, vec!["a\nb", "c"]);
+    Ok(())
+}
+
+/// Endless input.
+struct Firehose;
+
+impl Read for Firehose {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        buf.fill(b'\n');
+        Ok(buf.len())
+    }
+}
+
+#[test]
+fn test_backpressure() {
+    let count = Arc::new(AtomicUsize::new(0));
+    let count2 = count.clone();
+    let mut poll = PollStdin::from_reader(Firehose, move |line| {
+        count2.fetch_add(1, Ordering::SeqCst);
+        Event::Line(line)
+    })
+    .capacity(4);
+
+    // starts the reader.
+    _ = PollEvents::<Event, anyhow::Error>::poll(&mut poll);
+    thread::sleep(Duration::from_millis(100));
+    // 4 buffered, one waiting to be sent.
+    assert!(count.load(Ordering::SeqCst) <= 5);
+
+    // reading makes room.
+    for _ in 0..3 {
+        let r = PollEvents::<Event, anyhow::Error>::read(&mut poll).expect("read");
+        assert_eq!(r, Control::Event(Event::Line(String::new())));
+    }
+    thread::sleep(Duration::from_millis(100));
+    let n = count.load(Ordering::SeqCst);
+    assert!(n > 4 && n <= 8);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+
+const LIMIT: usize = 10_000;
+
+#[derive(Debug)]
+enum Event {
+    Tick,
+}
+
+struct App;
+
+struct State {
+    ticks: Rc<Cell<usize>>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        _event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        // feeds itself forever.
+        self.ticks.set(self.ticks.get() + 1);
+        if self.ticks.get() >= LIMIT {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Event(Event::Tick))
+        }
+    }
+}
+
+/// Starts the chain.
+struct PollStart(bool);
+
+impl PollEvents<Event, anyhow::Error> for PollStart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(!self.0)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.0 = true;
+        Ok(Control::Event(Event::Tick))
+    }
+}
+
+/// Stands in for the terminal input. Quits as soon as
+/// the chain is running.
+struct PollInput(Rc<Cell<usize>>);
+
+impl PollEvents<Event, anyhow::Error> for PollInput {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(self.0.get() > 0)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        Ok(Control::Quit)
+    }
+
+    fn is_input(&self) -> bool {
+        true
+    }
+}
+
+fn run(burst: usize) -> Result<usize, anyhow::Error> {
+    let ticks = Rc::new(Cell::new(0));
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        &mut State {
+            ticks: ticks.clone(),
+        },
+        RunConfig::new(term)
+            .poll(PollStart(false))
+            .poll(PollInput(ticks.clone()))
+            .max_queue_burst(burst),
+    )?;
+    Ok(ticks.get())
+}
+
+#[test]
+fn test_burst() -> Result<(), anyhow::Error> {
+    let ticks = run(16)?;
+    assert!(ticks <= 16 + 1, "ticks {}", ticks);
+    Ok(())
+}
+
+#[test]
+fn test_burst_disabled() -> Result<(), anyhow::Error> {
+    let ticks = run(0)?;
+    assert_eq!(ticks, LIMIT);
+    Ok(())
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use common::{SharedTerminal, TestTerminal};
+use rat_salsa::poll::PollRendered;
+use rat_salsa::rendered::RenderedEvent;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    Rendered(RenderedEvent),
+    Key,
+    Resize(u16, u16),
+}
+
+impl From<RenderedEvent> for Event {
+    fn from(value: RenderedEvent) -> Self {
+        Self::Rendered(value)
+    }
+}
+
+/// Sends the events in order.
+struct PollScript(VecDeque<Event>);
+
+impl PollEvents<Event, anyhow::Error> for PollScript {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(!self.0.is_empty())
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        Ok(self
+            .0
+            .pop_front()
+            .map(Control::Event)
+            .unwrap_or(Control::Continue))
+    }
+}
+
+struct App;
+
+struct State {
+    term: SharedTerminal,
+    rendered: Vec<RenderedEvent>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Rendered(r) => {
+                self.rendered.push(*r);
+                if self.rendered.len() == 4 {
+                    Ok(Control::Quit)
+                } else {
+                    Ok(Control::Continue)
+                }
+            }
+            Event::Key => Ok(Control::Changed),
+            Event::Resize(w, h) => {
+                self.term.borrow_mut().backend_mut().resize(*w, *h);
+                Ok(Control::Changed)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rendered() -> Result<(), anyhow::Error> {
+    let term = TestTerminal::new(20, 5);
+    let mut state = State {
+        term: term.terminal(),
+        rendered: Vec::new(),
+    };
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term)
+            .poll(PollRendered)
+            .poll(PollScript(VecDeque::from([
+                Event::Key,
+                Event::Resize(30, 8),
+                Event::Key,
+            ]))),
+    )?;
+
+    // one event per frame, in order.
+    let count = state.rendered.iter().map(|v| v.count).collect::<Vec<_>>();
+    let first = count[0];
+    assert_eq!(count, vec![first, first + 1, first + 2, first + 3]);
+
+    // the first frame and after the resize.
+    let full_redraw = state
+        .rendered
+        .iter()
+        .map(|v| v.full_redraw)
+        .collect::<Vec<_>>();
+    assert_eq!(full_redraw, vec![true, false, true, false]);
+    Ok(())
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::{SharedTerminal, TestTerminal};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use std::any::Any;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    Key(char),
+    Resize(u16, u16),
+}
+
+/// Works like PollCrossterm: a pending resize is delivered
+/// by read_urgent(), the other events stay queued.
+struct PollTerm(VecDeque<Event>);
+
+impl PollEvents<Event, anyhow::Error> for PollTerm {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(!self.0.is_empty())
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        Ok(self
+            .0
+            .pop_front()
+            .map(Control::Event)
+            .unwrap_or(Control::Continue))
+    }
+
+    fn read_urgent(&mut self) -> Result<Option<Control<Event>>, anyhow::Error> {
+        let resize = self
+            .0
+            .iter()
+            .rev()
+            .find(|v| matches!(v, Event::Resize(_, _)))
+            .copied();
+        self.0.retain(|v| !matches!(v, Event::Resize(_, _)));
+        Ok(resize.map(Control::Event))
+    }
+}
+
+struct App;
+
+struct State {
+    term: SharedTerminal,
+    events: Vec<Event>,
+    renders: Vec<Size>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        area: Rect,
+        _buf: &mut Buffer,
+        state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        state.renders.push(area.as_size());
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        event: &Event,
+        _ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.events.push(*event);
+        match event {
+            Event::Resize(w, h) => {
+                self.term.borrow_mut().backend_mut().resize(*w, *h);
+                Ok(Control::Changed)
+            }
+            Event::Key('q') => Ok(Control::Quit),
+            Event::Key(_) => Ok(Control::Changed),
+        }
+    }
+}
+
+#[test]
+fn test_resize_before_render() -> Result<(), anyhow::Error> {
+    let term = TestTerminal::new(20, 5);
+    let mut state = State {
+        term: term.terminal(),
+        events: Vec::new(),
+        renders: Vec::new(),
+    };
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term).poll(PollTerm(VecDeque::from([
+            Event::Key('a'),
+            Event::Key('b'),
+            Event::Resize(30, 8),
+            Event::Key('q'),
+        ]))),
+    )?;
+
+    // the resize jumps ahead of 'b'.
+    assert_eq!(
+        state.events,
+        vec![
+            Event::Key('a'),
+            Event::Resize(30, 8),
+            Event::Key('b'),
+            Event::Key('q')
+        ]
+    );
+    // only the initial render sees the old size.
+    assert_eq!(state.renders[0], Size::new(20, 5));
+    assert!(state.renders.len() > 1);
+    assert!(state.renders[1..].iter().all(|v| *v == Size::new(30, 8)));
+    Ok(())
+}
@@ -0,0 +1,221 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::{PollCrossterm, PollTasks, PollTimers};
+use rat_salsa::timer::TimeOut;
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, ConfigError, Control, PollError, PollEvents,
+    RenderContext, RunConfig, Service,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::io;
+
+#[derive(Debug)]
+enum Event {
+    #[allow(dead_code)]
+    Crossterm(crossterm::event::Event),
+    #[allow(dead_code)]
+    TimeOut(TimeOut),
+}
+
+impl From<TimeOut> for Event {
+    fn from(value: TimeOut) -> Self {
+        Self::TimeOut(value)
+    }
+}
+
+impl From<crossterm::event::Event> for Event {
+    fn from(value: crossterm::event::Event) -> Self {
+        Self::Crossterm(value)
+    }
+}
+
+/// Never has an event.
+struct PollNothing;
+
+impl PollEvents<Event, anyhow::Error> for PollNothing {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(false)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        Ok(Control::Continue)
+    }
+}
+
+type Config = RunConfig<Event, anyhow::Error>;
+
+#[test]
+fn test_ok() {
+    let cfg = Config::new(TestTerminal::new(20, 5))
+        .poll(PollCrossterm)
+        .poll(PollTimers::default())
+        .needs(Service::Timers)
+        .needs(Service::Terminal)
+        .min_size(20, 5);
+    assert_eq!(cfg.dry_check(), Ok(Vec::new()));
+}
+
+#[test]
+fn test_no_sources() {
+    let err = Config::new(TestTerminal::new(20, 5))
+        .dry_check()
+        .expect_err("error");
+    assert_eq!(err.errors.len(), 1);
+    assert!(err.errors[0].contains("PollCrossterm"));
+}
+
+#[test]
+fn test_duplicates() {
+    let err = Config::new(TestTerminal::new(20, 5))
+        .poll(PollCrossterm)
+        .poll(PollNothing)
+        .poll(PollCrossterm)
+        .poll(PollNothing)
+        .dry_check()
+        .expect_err("error");
+    assert_eq!(
+        err.errors,
+        vec!["PollCrossterm is registered 2 times. Keep only one RunConfig::poll(PollCrossterm)."]
+    );
+    assert_eq!(err.warnings, vec!["PollNothing is registered 2 times."]);
+
+    // a custom source alone is only a warning.
+    let warnings = Config::new(TestTerminal::new(20, 5))
+        .poll(PollNothing)
+        .poll(PollNothing)
+        .dry_check()
+        .expect("ok");
+    assert_eq!(warnings.len(), 1);
+}
+
+#[test]
+fn test_needs() {
+    let err = Config::new(TestTerminal::new(20, 5))
+        .poll(PollCrossterm)
+        .needs(Service::Timers)
+        .needs(Service::Tasks)
+        .needs(Service::Timers)
+        .dry_check()
+        .expect_err("error");
+    assert_eq!(
+        err.errors,
+        vec![
+            "Timers are needed, but not configured. Add RunConfig::poll(PollTimers::default()).",
+            "Background tasks are needed, but not configured. Add RunConfig::poll(PollTasks::default()).",
+        ]
+    );
+
+    let cfg = Config::new(TestTerminal::new(20, 5))
+        .poll(PollCrossterm)
+        .poll(PollTasks::default())
+        .needs(Service::Tasks);
+    assert!(cfg.dry_check().is_ok());
+}
+
+#[test]
+fn test_warnings() {
+    let warnings = Config::new(TestTerminal::new(20, 5))
+        .poll(PollNothing)
+        .track_terminal_focus(true)
+        .min_size(80, 24)
+        .dry_check()
+        .expect("ok");
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].contains("track_terminal_focus"));
+    assert!(warnings[1].contains("Terminal is 20x5, below the min_size 80x24"));
+}
+
+#[test]
+fn test_display() {
+    let err = ConfigError {
+        errors: vec!["one".into(), "two".into()],
+        warnings: vec!["three".into()],
+    };
+    assert_eq!(
+        err.to_string(),
+        "invalid RunConfig\n  error: one\n  error: two\n  warning: three"
+    );
+}
+
+struct App;
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+struct State;
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        ctx.add_timer(Default::default());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_run_tui() {
+    let term = TestTerminal::new(20, 5);
+    let init = term.init_flag();
+
+    let r = run_tui(
+        App,
+        &mut (),
+        &mut State,
+        Config::new(term).poll(PollNothing).needs(Service::Timers),
+    );
+
+    let err = r.expect_err("error");
+    let err = err.downcast_ref::<io::Error>().expect("io::Error");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    let err = err
+        .get_ref()
+        .and_then(|v| v.downcast_ref::<ConfigError>())
+        .expect("ConfigError");
+    assert_eq!(err.errors.len(), 1);
+    // the terminal is never touched.
+    assert!(!init.get());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn test_tokio() {
+    use rat_salsa::poll::PollTokio;
+
+    // checked without needs(Service::Tokio).
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .build()
+        .expect("runtime");
+    let cfg = Config::new(TestTerminal::new(20, 5)).poll(PollTokio::new(rt));
+    assert_eq!(cfg.dry_check(), Ok(Vec::new()));
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .expect("runtime");
+    let err = Config::new(TestTerminal::new(20, 5))
+        .poll(PollTokio::new(rt))
+        .dry_check()
+        .expect_err("error");
+    assert_eq!(err.errors.len(), 1);
+    assert!(err.errors[0].contains("current-thread"));
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::{screen, SharedTerminal, TestTerminal};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::Widget;
+use std::any::Any;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    Start,
+}
+
+/// Sends Start once.
+struct PollStart(bool);
+
+impl PollEvents<Event, anyhow::Error> for PollStart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(!self.0)
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.0 = true;
+        Ok(Control::Event(Event::Start))
+    }
+}
+
+fn key(c: char) -> crossterm::event::Event {
+    crossterm::event::Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+}
+
+#[derive(Debug, Default)]
+struct Prompt {
+    keys: Vec<char>,
+    renders: usize,
+}
+
+struct App;
+
+struct State {
+    term: SharedTerminal,
+    answer: Option<bool>,
+    prompt: Prompt,
+    modal_screen: String,
+    parent_dimmed: bool,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        "main".render(area, buf);
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn event(
+        &mut self,
+        _event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        let answer = ctx.run_modal(
+            &mut self.prompt,
+            |area, buf, prompt, _ctx| {
+                prompt.renders += 1;
+                "y/n?".render(Rect::new(area.x, area.y + 1, area.width, 1), buf);
+                Ok(())
+            },
+            |event, prompt, _ctx| {
+                let crossterm::event::Event::Key(key) = event else {
+                    return Ok(None);
+                };
+                let KeyCode::Char(c) = key.code else {
+                    return Ok(None);
+                };
+                prompt.keys.push(c);
+                Ok(match c {
+                    'y' => Some(true),
+                    'n' => Some(false),
+                    _ => None,
+                })
+            },
+        )?;
+        self.answer = Some(answer);
+
+        // the last modal frame.
+        self.modal_screen = screen(&self.term);
+        self.parent_dimmed = self.term.borrow().backend().buffer()[(0, 0)]
+            .modifier
+            .contains(Modifier::DIM);
+        Ok(Control::Quit)
+    }
+}
+
+#[test]
+fn test_run_modal() -> Result<(), anyhow::Error> {
+    let term = TestTerminal::new(10, 3);
+    let input = term.input();
+    input.borrow_mut().extend([key('x'), key('y'), key('n')]);
+
+    let mut state = State {
+        term: term.terminal(),
+        answer: None,
+        prompt: Prompt::default(),
+        modal_screen: String::new(),
+        parent_dimmed: false,
+    };
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term).poll(PollStart(false)),
+    )?;
+
+    assert_eq!(state.answer, Some(true));
+    // stops with the answer, the rest stays queued.
+    assert_eq!(state.prompt.keys, vec!['x', 'y']);
+    assert_eq!(input.borrow().len(), 1);
+    // rendered before each event.
+    assert_eq!(state.prompt.renders, 2);
+
+    // the prompt above the dimmed main ui.
+    assert_eq!(state.modal_screen, "main      \ny/n?      \n          \n");
+    assert!(state.parent_dimmed);
+    Ok(())
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use anyhow::anyhow;
+use common::TestTerminal;
+use rat_salsa::poll::PollTasks;
+use rat_salsa::{run_tui, AppContext, AppState, AppWidget, Control, RenderContext, RunConfig};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::cell::RefCell;
+
+#[derive(Debug)]
+struct Event;
+
+/// Error message, task name and id.
+type ErrorMeta = (String, Option<(Option<String>, u64)>);
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    errors: RefCell<Vec<ErrorMeta>>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        ctx.spawn_named("load", |_cancel, send| {
+            // extra results of the task are attributed too.
+            _ = send.send(Err(anyhow!("progress failed")));
+            Err(anyhow!("load failed"))
+        })?;
+        ctx.spawn(|_cancel, _send| Err(anyhow!("unnamed failed")))?;
+        ctx.queue_err(anyhow!("queued"));
+        Ok(())
+    }
+
+    fn error(
+        &self,
+        event: anyhow::Error,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        let meta = ctx.last_error_meta().map(|v| (v.name.clone(), v.id));
+        let mut errors = self.errors.borrow_mut();
+        errors.push((event.to_string(), meta));
+        if errors.len() == 4 {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+}
+
+#[test]
+fn test_task_meta() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(PollTasks::default()),
+    )?;
+
+    let errors = state.errors.into_inner();
+    let meta = |msg: &str| {
+        errors
+            .iter()
+            .find(|(v, _)| v == msg)
+            .unwrap_or_else(|| panic!("{}", msg))
+            .1
+            .clone()
+    };
+
+    // queued directly.
+    assert_eq!(meta("queued"), None);
+
+    let (name, load_id) = meta("load failed").expect("meta");
+    assert_eq!(name.as_deref(), Some("load"));
+    assert_eq!(meta("progress failed"), Some((name, load_id)));
+
+    let (name, id) = meta("unnamed failed").expect("meta");
+    assert_eq!(name, None);
+    assert_ne!(id, load_id);
+    Ok(())
+}
@@ -0,0 +1,192 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollTimers;
+use rat_salsa::timer::{TimeOut, TimerDef};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Event {
+    Timer(TimeOut),
+    Lost,
+    Gained,
+    Other,
+}
+
+impl From<TimeOut> for Event {
+    fn from(value: TimeOut) -> Self {
+        Self::Timer(value)
+    }
+}
+
+/// Sends the events at the given offsets, and reports the
+/// focus change like PollCrossterm.
+struct PollFocus {
+    start: Instant,
+    events: VecDeque<(Duration, Event, Option<bool>)>,
+    focus: Option<bool>,
+    /// Time the focus came back.
+    gained: Rc<Cell<Option<Instant>>>,
+}
+
+impl PollEvents<Event, anyhow::Error> for PollFocus {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(self
+            .events
+            .front()
+            .is_some_and(|(due, _, _)| self.start.elapsed() >= *due))
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        let (_, event, focus) = self.events.pop_front().expect("event");
+        self.focus = focus;
+        if focus == Some(true) {
+            self.gained.set(Some(Instant::now()));
+        }
+        Ok(Control::Event(event))
+    }
+
+    fn read_focus(&mut self) -> Option<bool> {
+        self.focus.take()
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    hooks: Vec<String>,
+    /// Ticks of the pause_on_unfocus timer, with the focus.
+    ticks: Vec<bool>,
+    ticks_after_resume: usize,
+    gained: Rc<Cell<Option<Instant>>>,
+    resumed: bool,
+    resumed_for: Duration,
+    other_ticks: usize,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        ctx.add_timer(
+            TimerDef::new()
+                .timer(Duration::from_millis(5))
+                .repeat_forever()
+                .pause_on_unfocus(true),
+        );
+        ctx.add_timer(
+            TimerDef::new()
+                .timer(Duration::from_millis(5))
+                .repeat_forever()
+                .event(Event::Other),
+        );
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Timer(_) => {
+                self.ticks.push(ctx.terminal_focused());
+                if self.resumed {
+                    self.ticks_after_resume += 1;
+                    if self.ticks_after_resume == 3 {
+                        self.resumed_for = self.gained.get().expect("gained").elapsed();
+                        return Ok(Control::Quit);
+                    }
+                }
+            }
+            // the other timer keeps running while unfocused.
+            Event::Other if !ctx.terminal_focused() => {
+                self.other_ticks += 1;
+            }
+            _ => {}
+        }
+        Ok(Control::Continue)
+    }
+
+    fn focus_lost(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.hooks.push(format!("lost {}", ctx.terminal_focused()));
+        Ok(Control::Continue)
+    }
+
+    fn focus_gained(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.hooks
+            .push(format!("gained {}", ctx.terminal_focused()));
+        self.resumed = true;
+        Ok(Control::Continue)
+    }
+}
+
+#[test]
+fn test_terminal_focus() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    let gained = state.gained.clone();
+    let started = Instant::now();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5))
+            .poll(PollTimers::default())
+            .poll(PollFocus {
+                start: started,
+                events: VecDeque::from([
+                    (Duration::from_millis(12), Event::Lost, Some(false)),
+                    (Duration::from_millis(60), Event::Gained, Some(true)),
+                ]),
+                focus: None,
+                gained,
+            })
+            .track_terminal_focus(true),
+    )?;
+
+    assert_eq!(state.hooks, vec!["lost false", "gained true"]);
+    // the marked timer doesn't tick while unfocused.
+    assert!(state.ticks.iter().all(|v| *v));
+    assert!(state.other_ticks > 0);
+    // and continues after the focus returns, without a burst
+    // of the missed ticks.
+    assert_eq!(state.ticks_after_resume, 3);
+    assert!(state.resumed_for >= Duration::from_millis(10));
+    Ok(())
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollTimers;
+use rat_salsa::timer::{TimeOut, TimerDef, TimerHandle};
+use rat_salsa::{run_tui, AppContext, AppState, AppWidget, Control, RenderContext, RunConfig};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Timer(TimeOut),
+    Blink,
+    Remove,
+    Stale,
+    Done,
+}
+
+impl From<TimeOut> for Event {
+    fn from(value: TimeOut) -> Self {
+        Self::Timer(value)
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    blink: TimerHandle,
+    stale: TimerHandle,
+    events: Vec<Event>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        self.blink = ctx.add_timer(
+            TimerDef::new()
+                .timer(Duration::from_millis(5))
+                .repeat_forever()
+                .event(Event::Blink),
+        );
+
+        // both are due at the same time.
+        let due = Instant::now() + Duration::from_millis(30);
+        ctx.add_timer(TimerDef::new().next(due).event(Event::Remove));
+        self.stale = ctx.add_timer(TimerDef::new().next(due).event(Event::Stale));
+        ctx.add_timer(
+            TimerDef::new()
+                .next(due + Duration::from_millis(20))
+                .event(Event::Done),
+        );
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        self.events.push(event.clone());
+        match event {
+            Event::Blink => {
+                // no new event, keeps the old one.
+                self.blink = ctx.replace_timer(
+                    Some(self.blink),
+                    TimerDef::new()
+                        .timer(Duration::from_millis(5))
+                        .repeat_forever(),
+                );
+                Ok(Control::Continue)
+            }
+            Event::Remove => {
+                ctx.remove_timer(self.stale);
+                ctx.remove_timer(self.blink);
+                Ok(Control::Continue)
+            }
+            Event::Done => Ok(Control::Quit),
+            _ => Ok(Control::Continue),
+        }
+    }
+}
+
+#[test]
+fn test_timer_event() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(TestTerminal::new(20, 5)).poll(PollTimers::new()),
+    )?;
+
+    let events = &state.events;
+    // the replaced timer still sends Blink.
+    assert!(events.iter().filter(|v| **v == Event::Blink).count() >= 2);
+    assert!(!events.iter().any(|v| matches!(v, Event::Timer(_))));
+
+    // nothing after remove, only Done.
+    let remove = events
+        .iter()
+        .position(|v| *v == Event::Remove)
+        .expect("remove");
+    assert_eq!(&events[remove..], &[Event::Remove, Event::Done]);
+    Ok(())
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use common::TestTerminal;
+use rat_salsa::poll::PollTimers;
+use rat_salsa::timer::{TimeOut, TimerDef, TimerHandle};
+use rat_salsa::{
+    run_tui, AppContext, AppState, AppWidget, Control, PollError, PollEvents, RenderContext,
+    RunConfig,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::any::Any;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+enum Event {
+    Timer(TimeOut),
+    Wake,
+}
+
+impl From<TimeOut> for Event {
+    fn from(value: TimeOut) -> Self {
+        Self::Timer(value)
+    }
+}
+
+/// Sends Wake at the given time.
+struct PollWake(Rc<Cell<Option<Instant>>>);
+
+impl PollEvents<Event, anyhow::Error> for PollWake {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn poll(&mut self) -> Result<bool, PollError<anyhow::Error>> {
+        Ok(self.0.get().is_some_and(|v| Instant::now() >= v))
+    }
+
+    fn read(&mut self) -> Result<Control<Event>, anyhow::Error> {
+        self.0.set(None);
+        Ok(Control::Event(Event::Wake))
+    }
+}
+
+struct App;
+
+#[derive(Default)]
+struct State {
+    wake: Rc<Cell<Option<Instant>>>,
+
+    tick: TimerHandle,
+    ticks: usize,
+    tick_remaining: Option<Duration>,
+    oneshot: TimerHandle,
+    oneshot_remaining: Option<Duration>,
+    oneshot_fired: bool,
+
+    woken: bool,
+    resumed_at: Option<Instant>,
+    ticks_after_resume: Vec<Duration>,
+}
+
+impl AppWidget<(), Event, anyhow::Error> for App {
+    type State = State;
+
+    fn render(
+        &self,
+        _area: Rect,
+        _buf: &mut Buffer,
+        _state: &mut Self::State,
+        _ctx: &mut RenderContext<'_, ()>,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
+}
+
+impl AppState<(), Event, anyhow::Error> for State {
+    fn init(
+        &mut self,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<(), anyhow::Error> {
+        self.tick = ctx.add_timer(
+            TimerDef::new()
+                .timer(Duration::from_millis(5))
+                .repeat_forever(),
+        );
+        self.oneshot = ctx.add_timer(TimerDef::new().timer(Duration::from_millis(20)));
+        ctx.pause_timer(self.oneshot);
+        self.oneshot_remaining = ctx.timer_remaining(self.oneshot);
+        Ok(())
+    }
+
+    fn event(
+        &mut self,
+        event: &Event,
+        ctx: &mut AppContext<'_, (), Event, anyhow::Error>,
+    ) -> Result<Control<Event>, anyhow::Error> {
+        match event {
+            Event::Timer(t) if t.handle == self.tick => {
+                self.ticks += 1;
+                if self.ticks == 3 {
+                    ctx.pause_all_timers();
+                    self.tick_remaining = ctx.timer_remaining(self.tick);
+                    self.wake
+                        .set(Some(Instant::now() + Duration::from_millis(50)));
+                }
+                if let Some(resumed_at) = self.resumed_at {
+                    self.ticks_after_resume.push(resumed_at.elapsed());
+                }
+            }
+            Event::Timer(t) if t.handle == self.oneshot => {
+                self.oneshot_fired = true;
+            }
+            Event::Timer(_) => {}
+            Event::Wake => {
+                self.woken = true;
+                // nothing moved while paused.
+                assert_eq!(self.ticks, 3);
+                assert_eq!(ctx.timer_remaining(self.tick), self.tick_remaining);
+                assert_eq!(ctx.timer_remaining(self.oneshot), self.oneshot_remaining);
+
+                ctx.resume_all_timers();
+                // still paused on its own.
+                assert_eq!(ctx.timer_remaining(self.oneshot), self.oneshot_remaining);
+                ctx.resume_timer(self.oneshot);
+                self.resumed_at = Some(Instant::now());
+            }
+        }
+
+        if self.oneshot_fired && self.ticks_after_resume.len() >= 2 {
+            Ok(Control::Quit)
+        } else {
+            Ok(Control::Continue)
+        }
+    }
+}
+
+#[test]
+fn test_pause() -> Result<(), anyhow::Error> {
+    let mut state = State::default();
+    let wake = state.wake.clone();
+
+    let term = TestTerminal::new(20, 5);
+    run_tui(
+        App,
+        &mut (),
+        &mut state,
+        RunConfig::new(term)
+            .poll(PollTimers::new())
+            .poll(PollWake(wake)),
+    )?;
+
+    assert!(state.woken);
+    let rem = state.oneshot_remaining.expect("oneshot");
+    assert!(rem > Duration::from_millis(15) && rem <= Duration::from_millis(20));
+    // no burst of missed ticks after resume.
+    assert!(state.ticks_after_resume[1] >= Duration::from_millis(4));
+    Ok(())
+}
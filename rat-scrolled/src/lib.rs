@@ -4,12 +4,14 @@
 
 mod scroll;
 mod scroll_area;
+mod scroll_sync;
 
 pub use scroll::{
-    Scroll, ScrollState, ScrollStyle, ScrollSymbols, SCROLLBAR_DOUBLE_HORIZONTAL,
+    horizontal_wheel, Scroll, ScrollState, ScrollStyle, ScrollSymbols, SCROLLBAR_DOUBLE_HORIZONTAL,
     SCROLLBAR_DOUBLE_VERTICAL, SCROLLBAR_HORIZONTAL, SCROLLBAR_VERTICAL,
 };
 pub use scroll_area::{ScrollArea, ScrollAreaState};
+pub use scroll_sync::ScrollSync;
 
 pub mod event {
     use rat_event::*;
@@ -93,6 +95,21 @@ pub enum ScrollbarPolicy {
     /// If the scrollbar is rendered combined with a block,
     /// the block still might reserve the same space for itself.
     Collapse,
+
+    /// The scrollbar is only shown if `max_offset > 0`.
+    /// Otherwise, the column/row is given back to the widget.
+    ///
+    /// Whether the scrollbar is needed depends on the inner area,
+    /// which depends on whether the scrollbar is shown. Widgets
+    /// resolve this with [ScrollArea::measure](crate::ScrollArea::measure):
+    /// the content is measured without the scrollbars first, and
+    /// the layout is finalized with the result.
+    ///
+    /// If the visibility flips outside of rendering, e.g. because
+    /// max_offset is changed by the application, the next event
+    /// for the [ScrollState](crate::ScrollState) reports
+    /// [ScrollOutcome::Changed](crate::event::ScrollOutcome::Changed).
+    Auto,
 }
 
 mod _private {
@@ -1,7 +1,7 @@
 use crate::_private::NonExhaustive;
 use crate::event::ScrollOutcome;
 use crate::ScrollbarPolicy;
-use rat_event::util::MouseFlags;
+use rat_event::util::{DragOutcome, DragTracker};
 use rat_event::{ct_event, HandleEvent, MouseOnly};
 use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
@@ -64,6 +64,12 @@ pub struct ScrollState {
     /// Vertical/Horizontal scroll?
     /// __readonly__. renewed for each render.
     pub orientation: ScrollbarOrientation,
+    /// Scrollbar policy.
+    /// __readonly__. renewed for each render.
+    pub policy: ScrollbarPolicy,
+    /// Scrollbar has been rendered.
+    /// __readonly__. renewed for each render.
+    pub visible: bool,
 
     /// Current offset.
     /// __read+write__
@@ -91,9 +97,9 @@ pub struct ScrollState {
     /// __read+write__
     pub overscroll_by: Option<usize>,
 
-    /// Mouse support.
+    /// Dragging the scrollbar.
     /// __read+write__
-    pub mouse: MouseFlags,
+    pub drag: DragTracker,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -482,6 +488,8 @@ impl StatefulWidgetRef for Scroll<'_> {
 
 fn render_scroll(scroll: &Scroll<'_>, area: Rect, buf: &mut Buffer, state: &mut ScrollState) {
     state.set_orientation(scroll.orientation.clone());
+    state.policy = scroll.policy;
+    state.visible = false;
     if scroll.overscroll_by.is_some() {
         state.set_overscroll_by(scroll.overscroll_by);
     }
@@ -497,6 +505,7 @@ fn render_scroll(scroll: &Scroll<'_>, area: Rect, buf: &mut Buffer, state: &mut
     if state.max_offset() == 0 {
         match scroll.policy {
             ScrollbarPolicy::Always => {
+                state.visible = true;
                 scroll.scrollbar().render(
                     area,
                     buf,
@@ -506,13 +515,15 @@ fn render_scroll(scroll: &Scroll<'_>, area: Rect, buf: &mut Buffer, state: &mut
                 );
             }
             ScrollbarPolicy::Minimize => {
+                state.visible = true;
                 fill(scroll.min_symbol, scroll.min_style, area, buf);
             }
-            ScrollbarPolicy::Collapse => {
+            ScrollbarPolicy::Collapse | ScrollbarPolicy::Auto => {
                 // widget renders
             }
         }
     } else {
+        state.visible = true;
         scroll.scrollbar().render(
             area,
             buf,
@@ -567,12 +578,14 @@ impl Default for ScrollState {
         Self {
             area: Default::default(),
             orientation: Default::default(),
+            policy: Default::default(),
+            visible: false,
             offset: 0,
             max_offset: 0,
             page_len: 0,
             scroll_by: None,
             overscroll_by: None,
-            mouse: Default::default(),
+            drag: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -594,6 +607,13 @@ impl ScrollState {
         self.orientation = orientation;
     }
 
+    /// With [ScrollbarPolicy::Auto] the scrollbar should be
+    /// shown/hidden according to the current max_offset, but
+    /// the last render did otherwise.
+    pub fn visibility_changed(&self) -> bool {
+        self.policy == ScrollbarPolicy::Auto && self.visible != (self.max_offset > 0)
+    }
+
     /// Vertical scroll?
     #[inline]
     pub fn is_vertical(&self) -> bool {
@@ -802,43 +822,147 @@ impl ScrollState {
 
         (self.max_offset.saturating_mul(pos)) / span
     }
+
+    /// Position of the thumb relative to the start of the scrollbar.
+    /// length - width/height of the scrollbar.
+    ///
+    /// This follows the calculation of the ratatui Scrollbar and
+    /// corrects for the arrows.
+    pub fn thumb_range(&self, length: u16) -> Range<u16> {
+        let track_len = length.saturating_sub(2) as f64;
+        if track_len < 1.0 {
+            return 1..1;
+        }
+        let viewport_len = if self.page_len != 0 {
+            self.page_len as f64
+        } else {
+            length as f64
+        };
+
+        let max_position = self.max_offset.saturating_sub(1) as f64;
+        let start_position = (self.offset as f64).clamp(0.0, max_position);
+        let max_viewport_position = max_position + viewport_len;
+        let end_position = start_position + viewport_len;
+
+        let thumb_start = start_position * track_len / max_viewport_position;
+        let thumb_end = end_position * track_len / max_viewport_position;
+        let thumb_start = thumb_start.round().clamp(0.0, track_len - 1.0) as u16;
+        let thumb_end = thumb_end.round().clamp(0.0, track_len) as u16;
+        let thumb_len = max(thumb_end.saturating_sub(thumb_start), 1);
+
+        1 + thumb_start..1 + thumb_start + thumb_len
+    }
+
+    /// Map the drag delta to a new offset.
+    ///
+    /// The thumb moves with the mouse, relative to where it was grabbed.
+    fn drag_pos(&self) -> ScrollOutcome {
+        let (dx, dy) = self.drag.delta();
+        let (delta, length) = if self.is_vertical() {
+            (dy, self.area.height)
+        } else {
+            (dx, self.area.width)
+        };
+        // correct for the arrows.
+        let span = max(length.saturating_sub(2), 1) as i64;
+        let offset =
+            self.drag.start_value.get() as i64 + delta as i64 * self.max_offset as i64 / span;
+        let offset = offset.clamp(0, self.max_offset as i64) as usize;
+
+        if self.is_vertical() {
+            ScrollOutcome::VPos(offset)
+        } else {
+            ScrollOutcome::HPos(offset)
+        }
+    }
+
+    /// Map a click on the scrollbar.
+    ///
+    /// The arrows scroll by scroll_by, the track before and after
+    /// the thumb scroll by a page. A click on the thumb doesn't change
+    /// anything, but starts dragging.
+    fn map_click(&self, pos: u16, base: u16, length: u16) -> (bool, usize) {
+        let pos = pos.saturating_sub(base);
+        let thumb = self.thumb_range(length);
+        let page = max(self.page_len, 1);
+        if pos == 0 {
+            (false, self.scroll_by())
+        } else if pos + 1 >= length {
+            (true, self.scroll_by())
+        } else if pos < thumb.start {
+            (false, page)
+        } else if pos >= thumb.end {
+            (true, page)
+        } else {
+            (false, 0)
+        }
+    }
+}
+
+/// Shift+wheel and Alt+wheel scroll horizontally, as do the
+/// left/right wheel events some terminals send.
+///
+/// All widgets with horizontal scrolling use this convention,
+/// it's applied by ScrollState and ScrollAreaState.
+pub fn horizontal_wheel(
+    event: &crossterm::event::Event,
+    area: Rect,
+    scroll_by: usize,
+) -> ScrollOutcome {
+    match event {
+        ct_event!(scroll SHIFT down for col, row)
+        | ct_event!(scroll ALT down for col, row)
+        | ct_event!(scroll right for col, row)
+            if area.contains((*col, *row).into()) =>
+        {
+            ScrollOutcome::Right(scroll_by)
+        }
+        ct_event!(scroll SHIFT up for col, row)
+        | ct_event!(scroll ALT up for col, row)
+        | ct_event!(scroll left for col, row)
+            if area.contains((*col, *row).into()) =>
+        {
+            ScrollOutcome::Left(scroll_by)
+        }
+        _ => ScrollOutcome::Continue,
+    }
 }
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, ScrollOutcome> for ScrollState {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> ScrollOutcome {
-        match event {
-            ct_event!(mouse any for m) if self.mouse.drag(self.area, m) => {
+        let r = match self.drag.handle(self.area, event) {
+            DragOutcome::Started(_) => {
+                self.drag.start_value.set(self.offset);
+                self.drag_pos()
+            }
+            DragOutcome::Moved(_, _) => self.drag_pos(),
+            DragOutcome::Canceled => {
                 if self.is_vertical() {
-                    if m.row >= self.area.y {
-                        ScrollOutcome::VPos(self.map_position_index(
-                            m.row,
-                            self.area.y,
-                            self.area.height,
-                        ))
-                    } else {
-                        ScrollOutcome::Unchanged
-                    }
+                    ScrollOutcome::VPos(self.drag.start_value.get())
                 } else {
-                    if m.column >= self.area.x {
-                        ScrollOutcome::HPos(self.map_position_index(
-                            m.column,
-                            self.area.x,
-                            self.area.width,
-                        ))
-                    } else {
-                        ScrollOutcome::Unchanged
-                    }
+                    ScrollOutcome::HPos(self.drag.start_value.get())
                 }
             }
+            _ => ScrollOutcome::Continue,
+        };
+        if r != ScrollOutcome::Continue {
+            return r;
+        }
+
+        let r = match event {
             ct_event!(mouse down Left for col, row) if self.area.contains((*col, *row).into()) => {
                 if self.is_vertical() {
-                    ScrollOutcome::VPos(self.map_position_index(
-                        *row,
-                        self.area.y,
-                        self.area.height,
-                    ))
+                    match self.map_click(*row, self.area.y, self.area.height) {
+                        (_, 0) => ScrollOutcome::Unchanged,
+                        (false, n) => ScrollOutcome::Up(n),
+                        (true, n) => ScrollOutcome::Down(n),
+                    }
                 } else {
-                    ScrollOutcome::HPos(self.map_position_index(*col, self.area.x, self.area.width))
+                    match self.map_click(*col, self.area.x, self.area.width) {
+                        (_, 0) => ScrollOutcome::Unchanged,
+                        (false, n) => ScrollOutcome::Left(n),
+                        (true, n) => ScrollOutcome::Right(n),
+                    }
                 }
             }
             ct_event!(scroll down for col, row)
@@ -851,19 +975,14 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, ScrollOutcome> for ScrollSt
             {
                 ScrollOutcome::Up(self.scroll_by())
             }
-            // right scroll with ALT down. shift doesn't work?
-            ct_event!(scroll ALT down for col, row)
-                if self.is_horizontal() && self.area.contains((*col, *row).into()) =>
-            {
-                ScrollOutcome::Right(self.scroll_by())
-            }
-            // left scroll with ALT up. shift doesn't work?
-            ct_event!(scroll ALT up for col, row)
-                if self.is_horizontal() && self.area.contains((*col, *row).into()) =>
-            {
-                ScrollOutcome::Left(self.scroll_by())
-            }
+            _ if self.is_horizontal() => horizontal_wheel(event, self.area, self.scroll_by()),
             _ => ScrollOutcome::Continue,
+        };
+
+        if r == ScrollOutcome::Continue && self.visibility_changed() {
+            ScrollOutcome::Changed
+        } else {
+            r
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::event::ScrollOutcome;
-use crate::{Scroll, ScrollState, ScrollbarPolicy};
+use crate::{horizontal_wheel, Scroll, ScrollState, ScrollbarPolicy};
 use rat_event::{ct_event, flow, HandleEvent, MouseOnly};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Position, Rect};
@@ -98,6 +98,68 @@ impl<'a> ScrollArea<'a> {
         )
         .0
     }
+
+    /// Measure the content before calculating the layout.
+    ///
+    /// Sets a preliminary max_offset for the scroll-states,
+    /// that is enough for [ScrollArea::inner] to decide about
+    /// [ScrollbarPolicy::Auto] and [ScrollbarPolicy::Collapse].
+    /// The widget still calculates the correct max_offset afterward.
+    ///
+    /// The content is measured without these scrollbars first.
+    /// Each scrollbar whose content doesn't fit is shown,
+    /// which in turn can make the other one necessary.
+    ///
+    /// * h_content, v_content: Width and height of the content.
+    ///   None leaves the max_offset of this state as is.
+    pub fn measure(
+        &self,
+        area: Rect,
+        h_content: Option<usize>,
+        v_content: Option<usize>,
+        hscroll_state: Option<&mut ScrollState>,
+        vscroll_state: Option<&mut ScrollState>,
+    ) {
+        let mut h = ScrollState::default();
+        let mut v = ScrollState::default();
+        if let Some(hscroll_state) = hscroll_state.as_deref() {
+            if h_content.is_none() {
+                h.max_offset = hscroll_state.max_offset;
+            }
+        }
+        if let Some(vscroll_state) = vscroll_state.as_deref() {
+            if v_content.is_none() {
+                v.max_offset = vscroll_state.max_offset;
+            }
+        }
+
+        // showing a scrollbar only ever makes the inner area smaller,
+        // so this settles after the second round.
+        for _ in 0..2 {
+            let inner = layout(
+                self.block,
+                self.h_scroll,
+                self.v_scroll,
+                area,
+                Some(&h),
+                Some(&v),
+            )
+            .0;
+            if let Some(h_content) = h_content {
+                h.max_offset = h_content.saturating_sub(inner.width as usize);
+            }
+            if let Some(v_content) = v_content {
+                v.max_offset = v_content.saturating_sub(inner.height as usize);
+            }
+        }
+
+        if let Some(hscroll_state) = hscroll_state {
+            hscroll_state.set_max_offset(h.max_offset);
+        }
+        if let Some(vscroll_state) = vscroll_state {
+            vscroll_state.set_max_offset(v.max_offset);
+        }
+    }
 }
 
 /// Get the padding the block imposes as Padding.
@@ -203,7 +265,7 @@ fn layout<'a>(
             let show = match hscroll.get_policy() {
                 ScrollbarPolicy::Always => true,
                 ScrollbarPolicy::Minimize => true,
-                ScrollbarPolicy::Collapse => hscroll_state.max_offset > 0,
+                ScrollbarPolicy::Collapse | ScrollbarPolicy::Auto => hscroll_state.max_offset > 0,
             };
             if show {
                 match hscroll.get_orientation() {
@@ -240,7 +302,7 @@ fn layout<'a>(
             let show = match vscroll.get_policy() {
                 ScrollbarPolicy::Always => true,
                 ScrollbarPolicy::Minimize => true,
-                ScrollbarPolicy::Collapse => vscroll_state.max_offset > 0,
+                ScrollbarPolicy::Collapse | ScrollbarPolicy::Auto => vscroll_state.max_offset > 0,
             };
             if show {
                 match vscroll.get_orientation() {
@@ -278,7 +340,7 @@ fn layout<'a>(
             let show = match hscroll.get_policy() {
                 ScrollbarPolicy::Always => true,
                 ScrollbarPolicy::Minimize => true,
-                ScrollbarPolicy::Collapse => hscroll_state.max_offset > 0,
+                ScrollbarPolicy::Collapse | ScrollbarPolicy::Auto => hscroll_state.max_offset > 0,
             };
             if show {
                 match hscroll.get_orientation() {
@@ -316,7 +378,7 @@ fn layout<'a>(
             let show = match vscroll.get_policy() {
                 ScrollbarPolicy::Always => true,
                 ScrollbarPolicy::Minimize => true,
-                ScrollbarPolicy::Collapse => vscroll_state.max_offset > 0,
+                ScrollbarPolicy::Collapse | ScrollbarPolicy::Auto => vscroll_state.max_offset > 0,
             };
             if show {
                 match vscroll.get_orientation() {
@@ -388,25 +450,7 @@ impl<'a> ScrollAreaState<'a> {
 impl HandleEvent<crossterm::event::Event, MouseOnly, ScrollOutcome> for ScrollAreaState<'_> {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> ScrollOutcome {
         if let Some(h_scroll) = &mut self.h_scroll {
-            flow!(match event {
-                // right scroll with ALT down. shift doesn't work?
-                ct_event!(scroll ALT down for column, row) => {
-                    if self.area.contains(Position::new(*column, *row)) {
-                        ScrollOutcome::Right(h_scroll.scroll_by())
-                    } else {
-                        ScrollOutcome::Continue
-                    }
-                }
-                // left scroll with ALT up. shift doesn't work?
-                ct_event!(scroll ALT up for column, row) => {
-                    if self.area.contains(Position::new(*column, *row)) {
-                        ScrollOutcome::Left(h_scroll.scroll_by())
-                    } else {
-                        ScrollOutcome::Continue
-                    }
-                }
-                _ => ScrollOutcome::Continue,
-            });
+            flow!(horizontal_wheel(event, self.area, h_scroll.scroll_by()));
             flow!(h_scroll.handle(event, MouseOnly));
         }
         if let Some(v_scroll) = &mut self.v_scroll {
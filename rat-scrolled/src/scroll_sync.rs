@@ -0,0 +1,176 @@
+use crate::_private::NonExhaustive;
+use crate::ScrollState;
+use rat_event::Outcome;
+use std::fmt::{Debug, Formatter};
+
+/// Maps an offset from one pane to another.
+type MapFn = Box<dyn Fn(usize, usize, usize) -> usize>;
+
+/// Keeps the offsets of two or more [ScrollState]s in sync.
+///
+/// The ScrollStates stay where they are, in the states of their
+/// widgets. Call [ScrollSync::sync] after the event-handling
+/// of all the panes. It finds the one whose offset has changed
+/// since the last call, by whatever means, and moves the others
+/// to the same offset.
+///
+/// ```rust ignore
+/// let r = r.or_else(|| state.left.handle(event, Regular));
+/// let r = r.or_else(|| state.right.handle(event, Regular));
+/// let r = r.max(
+///     state
+///         .sync
+///         .sync(&mut [&mut state.left.vscroll, &mut state.right.vscroll]),
+/// );
+/// ```
+///
+/// The offsets are copied unchanged, unless there is a
+/// [mapping](ScrollSync::map). Each offset is limited by the
+/// max_offset of its ScrollState.
+pub struct ScrollSync {
+    /// Sync is active.
+    /// __read+write__
+    pub locked: bool,
+    /// Offsets after the last sync.
+    /// __internal__
+    pub synced: Vec<usize>,
+    /// Counts the syncs that changed something.
+    /// __read only__
+    pub generation: usize,
+    /// Offset mapping.
+    map: Option<MapFn>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Debug for ScrollSync {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScrollSync")
+            .field("locked", &self.locked)
+            .field("synced", &self.synced)
+            .field("generation", &self.generation)
+            .field("map", &self.map.is_some())
+            .finish()
+    }
+}
+
+impl Default for ScrollSync {
+    fn default() -> Self {
+        Self {
+            locked: true,
+            synced: Default::default(),
+            generation: 0,
+            map: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl ScrollSync {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map the offset from one pane to another.
+    ///
+    /// The function gets the index of the pane that changed,
+    /// the index of the pane to update and the new offset.
+    /// It returns the offset for the pane to update.
+    ///
+    /// A side-by-side diff can use this to skip the gaps
+    /// that align the two texts.
+    pub fn map(mut self, map: impl Fn(usize, usize, usize) -> usize + 'static) -> Self {
+        self.map = Some(Box::new(map));
+        self
+    }
+
+    /// Sync is active.
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Activate/deactivate the sync.
+    ///
+    /// While unlocked, the panes scroll independently. After
+    /// locking again, they stay where they are until the next
+    /// change. Use [ScrollSync::sync_from] to align them at once.
+    pub fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+    }
+
+    /// Toggle the sync. Returns the new state.
+    pub fn toggle_locked(&mut self) -> bool {
+        self.locked = !self.locked;
+        self.locked
+    }
+
+    /// Counts the syncs that changed something.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Sync the panes.
+    ///
+    /// Uses the first pane whose offset changed since the last
+    /// call and updates all the others. The offsets written by
+    /// the sync count as synced, so they are never picked up
+    /// as changes on the next call. Even if a pane limits the
+    /// offset, nothing bounces back.
+    ///
+    /// The first call and any call with a different number of
+    /// panes only records the current offsets.
+    ///
+    /// Returns [Outcome::Changed] if any pane has been moved.
+    pub fn sync(&mut self, scroll: &mut [&mut ScrollState]) -> Outcome {
+        if self.synced.len() != scroll.len() {
+            self.record(scroll);
+            return Outcome::Continue;
+        }
+        if !self.locked {
+            self.record(scroll);
+            return Outcome::Continue;
+        }
+
+        let changed = scroll
+            .iter()
+            .zip(self.synced.iter())
+            .position(|(s, v)| s.offset != *v);
+        match changed {
+            Some(n) => self.sync_from(n, scroll),
+            None => Outcome::Continue,
+        }
+    }
+
+    /// Move all panes to the offset of pane `n`.
+    ///
+    /// This works even if the sync is not locked.
+    ///
+    /// Returns [Outcome::Changed] if any pane has been moved.
+    pub fn sync_from(&mut self, n: usize, scroll: &mut [&mut ScrollState]) -> Outcome {
+        let offset = scroll[n].offset;
+        let mut changed = false;
+        for (i, s) in scroll.iter_mut().enumerate() {
+            if i == n {
+                continue;
+            }
+            let target = match &self.map {
+                Some(map) => map(n, i, offset),
+                None => offset,
+            };
+            changed |= s.set_offset(target);
+        }
+        self.record(scroll);
+
+        if changed {
+            self.generation = self.generation.wrapping_add(1);
+            Outcome::Changed
+        } else {
+            Outcome::Unchanged
+        }
+    }
+
+    fn record(&mut self, scroll: &[&mut ScrollState]) {
+        self.synced.clear();
+        self.synced.extend(scroll.iter().map(|v| v.offset));
+    }
+}
@@ -0,0 +1,182 @@
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, MouseOnly};
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::{
+    horizontal_wheel, Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollbarPolicy,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{ScrollbarOrientation, StatefulWidget};
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16, modifiers: KeyModifiers) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers,
+    })
+}
+
+fn h_state() -> ScrollState {
+    let mut state = ScrollState::new();
+    state.orientation = ScrollbarOrientation::HorizontalBottom;
+    state.area = Rect::new(0, 10, 22, 1);
+    state.set_max_offset(100);
+    state.set_page_len(20);
+    state
+}
+
+#[test]
+fn test_wheel() {
+    let area = Rect::new(0, 0, 20, 10);
+
+    let e = mouse(MouseEventKind::ScrollDown, 5, 5, KeyModifiers::SHIFT);
+    assert_eq!(horizontal_wheel(&e, area, 3), ScrollOutcome::Right(3));
+    let e = mouse(MouseEventKind::ScrollUp, 5, 5, KeyModifiers::SHIFT);
+    assert_eq!(horizontal_wheel(&e, area, 3), ScrollOutcome::Left(3));
+    let e = mouse(MouseEventKind::ScrollDown, 5, 5, KeyModifiers::ALT);
+    assert_eq!(horizontal_wheel(&e, area, 3), ScrollOutcome::Right(3));
+    let e = mouse(MouseEventKind::ScrollLeft, 5, 5, KeyModifiers::NONE);
+    assert_eq!(horizontal_wheel(&e, area, 3), ScrollOutcome::Left(3));
+
+    // plain wheel is vertical
+    let e = mouse(MouseEventKind::ScrollDown, 5, 5, KeyModifiers::NONE);
+    assert_eq!(horizontal_wheel(&e, area, 3), ScrollOutcome::Continue);
+    // outside
+    let e = mouse(MouseEventKind::ScrollDown, 25, 5, KeyModifiers::SHIFT);
+    assert_eq!(horizontal_wheel(&e, area, 3), ScrollOutcome::Continue);
+}
+
+#[test]
+fn test_thumb() {
+    let mut state = h_state();
+    // track is 20 wide, viewport 20 of 119
+    assert_eq!(state.thumb_range(22), 1..4);
+
+    state.set_offset(100);
+    assert_eq!(state.thumb_range(22).end, 21);
+}
+
+#[test]
+fn test_click() {
+    let mut state = h_state();
+    state.set_offset(50);
+    let thumb = state.thumb_range(22);
+
+    // arrows
+    let e = mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        0,
+        10,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&e, MouseOnly), ScrollOutcome::Left(2));
+    let e = mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        21,
+        10,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&e, MouseOnly), ScrollOutcome::Right(2));
+
+    // track
+    let e = mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        1,
+        10,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&e, MouseOnly), ScrollOutcome::Left(20));
+    let e = mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        20,
+        10,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&e, MouseOnly), ScrollOutcome::Right(20));
+
+    // thumb
+    let e = mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        thumb.start,
+        10,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&e, MouseOnly), ScrollOutcome::Unchanged);
+
+    // drag moves relative to where the thumb was grabbed.
+    let e = mouse(
+        MouseEventKind::Drag(MouseButton::Left),
+        thumb.start + 1,
+        10,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&e, MouseOnly), ScrollOutcome::HPos(55));
+    let e = mouse(
+        MouseEventKind::Drag(MouseButton::Left),
+        thumb.start,
+        10,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&e, MouseOnly), ScrollOutcome::HPos(50));
+}
+
+#[test]
+fn test_auto_measure() {
+    let area = Rect::new(0, 0, 20, 10);
+    let hscroll = Scroll::new()
+        .orientation(ScrollbarOrientation::HorizontalBottom)
+        .policy(ScrollbarPolicy::Auto);
+    let vscroll = Scroll::new()
+        .orientation(ScrollbarOrientation::VerticalRight)
+        .policy(ScrollbarPolicy::Auto);
+    let sa = ScrollArea::new()
+        .h_scroll(Some(&hscroll))
+        .v_scroll(Some(&vscroll));
+
+    let mut h = ScrollState::new();
+    let mut v = ScrollState::new();
+
+    // fits, the whole area is given to the widget.
+    sa.measure(area, Some(20), Some(10), Some(&mut h), Some(&mut v));
+    assert_eq!(sa.inner(area, Some(&h), Some(&v)), Rect::new(0, 0, 20, 10));
+
+    // too high, only the vertical bar is needed.
+    sa.measure(area, Some(19), Some(11), Some(&mut h), Some(&mut v));
+    assert_eq!(sa.inner(area, Some(&h), Some(&v)), Rect::new(0, 0, 19, 10));
+
+    // too high, and the vertical bar makes it too wide.
+    sa.measure(area, Some(20), Some(11), Some(&mut h), Some(&mut v));
+    assert_eq!(sa.inner(area, Some(&h), Some(&v)), Rect::new(0, 0, 19, 9));
+
+    // None keeps the state.
+    sa.measure(area, None, Some(5), Some(&mut h), Some(&mut v));
+    assert_eq!(sa.inner(area, Some(&h), Some(&v)), Rect::new(0, 0, 20, 9));
+}
+
+#[test]
+fn test_auto_visibility() {
+    let area = Rect::new(0, 0, 20, 10);
+    let mut buf = Buffer::empty(area);
+    let vscroll = Scroll::new()
+        .orientation(ScrollbarOrientation::VerticalRight)
+        .policy(ScrollbarPolicy::Auto);
+    let sa = ScrollArea::new().v_scroll(Some(&vscroll));
+
+    let mut v = ScrollState::new();
+    sa.clone()
+        .render(area, &mut buf, &mut ScrollAreaState::new().v_scroll(&mut v));
+    assert!(!v.visible);
+    assert!(!v.visibility_changed());
+
+    // content grows outside of rendering.
+    v.set_max_offset(5);
+    assert!(v.visibility_changed());
+    let e = mouse(MouseEventKind::Moved, 5, 5, KeyModifiers::NONE);
+    assert_eq!(v.handle(&e, MouseOnly), ScrollOutcome::Changed);
+
+    sa.render(area, &mut buf, &mut ScrollAreaState::new().v_scroll(&mut v));
+    assert!(v.visible);
+    assert_eq!(v.area, Rect::new(19, 0, 1, 10));
+    assert_eq!(v.handle(&e, MouseOnly), ScrollOutcome::Continue);
+}
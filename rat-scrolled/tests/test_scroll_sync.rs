@@ -0,0 +1,125 @@
+use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, MouseOnly, Outcome};
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::{ScrollState, ScrollSync};
+use ratatui::layout::Rect;
+
+fn state(max_offset: usize) -> ScrollState {
+    let mut state = ScrollState::new();
+    state.area = Rect::new(0, 0, 1, 20);
+    state.set_max_offset(max_offset);
+    state.set_page_len(20);
+    state
+}
+
+#[test]
+fn test_sync() {
+    let mut sync = ScrollSync::new();
+    let mut a = state(100);
+    let mut b = state(100);
+    let mut c = state(100);
+
+    // first call records.
+    assert_eq!(sync.sync(&mut [&mut a, &mut b, &mut c]), Outcome::Continue);
+
+    b.set_offset(10);
+    assert_eq!(sync.sync(&mut [&mut a, &mut b, &mut c]), Outcome::Changed);
+    assert_eq!((a.offset, b.offset, c.offset), (10, 10, 10));
+    assert_eq!(sync.generation(), 1);
+
+    // settled.
+    assert_eq!(sync.sync(&mut [&mut a, &mut b, &mut c]), Outcome::Continue);
+    assert_eq!(sync.generation(), 1);
+}
+
+#[test]
+fn test_limited() {
+    let mut sync = ScrollSync::new();
+    let mut a = state(100);
+    let mut b = state(30);
+    sync.sync(&mut [&mut a, &mut b]);
+
+    a.set_offset(50);
+    assert_eq!(sync.sync(&mut [&mut a, &mut b]), Outcome::Changed);
+    assert_eq!((a.offset, b.offset), (50, 30));
+
+    // b didn't reach 50, but nothing bounces back.
+    assert_eq!(sync.sync(&mut [&mut a, &mut b]), Outcome::Continue);
+    assert_eq!((a.offset, b.offset), (50, 30));
+}
+
+#[test]
+fn test_map() {
+    // b has a gap of 5 lines after line 10.
+    let mut sync = ScrollSync::new().map(|from, _to, offset| {
+        if from == 0 {
+            if offset > 10 {
+                offset + 5
+            } else {
+                offset
+            }
+        } else {
+            if offset > 15 {
+                offset - 5
+            } else {
+                offset.min(10)
+            }
+        }
+    });
+    let mut a = state(100);
+    let mut b = state(100);
+    sync.sync(&mut [&mut a, &mut b]);
+
+    a.set_offset(20);
+    sync.sync(&mut [&mut a, &mut b]);
+    assert_eq!(b.offset, 25);
+
+    b.set_offset(12);
+    sync.sync(&mut [&mut a, &mut b]);
+    assert_eq!(a.offset, 10);
+}
+
+#[test]
+fn test_unlocked() {
+    let mut sync = ScrollSync::new();
+    let mut a = state(100);
+    let mut b = state(100);
+    sync.sync(&mut [&mut a, &mut b]);
+
+    assert!(!sync.toggle_locked());
+    a.set_offset(20);
+    assert_eq!(sync.sync(&mut [&mut a, &mut b]), Outcome::Continue);
+    assert_eq!(b.offset, 0);
+
+    // no jump when locking again.
+    sync.set_locked(true);
+    assert_eq!(sync.sync(&mut [&mut a, &mut b]), Outcome::Continue);
+    assert_eq!(b.offset, 0);
+
+    assert_eq!(sync.sync_from(0, &mut [&mut a, &mut b]), Outcome::Changed);
+    assert_eq!(b.offset, 20);
+    assert_eq!(sync.sync_from(0, &mut [&mut a, &mut b]), Outcome::Unchanged);
+}
+
+#[test]
+fn test_wheel() {
+    let mut sync = ScrollSync::new();
+    let mut a = state(100);
+    let mut b = state(100);
+    sync.sync(&mut [&mut a, &mut b]);
+
+    let event = Event::Mouse(MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: 0,
+        row: 5,
+        modifiers: KeyModifiers::NONE,
+    });
+    let r = b.handle(&event, MouseOnly);
+    assert!(matches!(r, ScrollOutcome::Down(_)));
+    if let ScrollOutcome::Down(n) = r {
+        b.scroll_down(n);
+    }
+    assert_eq!(sync.sync(&mut [&mut a, &mut b]), Outcome::Changed);
+    assert_eq!(a.offset, b.offset);
+    assert!(a.offset > 0);
+}
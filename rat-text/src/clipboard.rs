@@ -32,6 +32,22 @@ pub trait Clipboard: DynClone + Debug {
 
     /// Set text from the clipboard.
     fn set_string(&self, s: &str) -> Result<(), ClipboardError>;
+
+    /// Get text from the primary selection.
+    ///
+    /// Only X11/Wayland know a primary selection. The default
+    /// returns an empty string.
+    fn get_primary(&self) -> Result<String, ClipboardError> {
+        Ok(String::new())
+    }
+
+    /// Set the primary selection.
+    ///
+    /// Only X11/Wayland know a primary selection. The default
+    /// does nothing.
+    fn set_primary(&self, _s: &str) -> Result<(), ClipboardError> {
+        Ok(())
+    }
 }
 
 static GLOBAL_CLIPBOARD: OnceLock<StaticClipboard> = OnceLock::new();
@@ -80,13 +96,24 @@ impl Clipboard for StaticClipboard {
     fn set_string(&self, s: &str) -> Result<(), ClipboardError> {
         self.clip.lock().expect("clipboard-lock").set_string(s)
     }
+
+    fn get_primary(&self) -> Result<String, ClipboardError> {
+        self.clip.lock().expect("clipboard-lock").get_primary()
+    }
+
+    fn set_primary(&self, s: &str) -> Result<(), ClipboardError> {
+        self.clip.lock().expect("clipboard-lock").set_primary(s)
+    }
 }
 
 /// Local clipboard.
 /// A string in disguise.
+///
+/// Keeps a second string for the primary selection.
 #[derive(Debug, Default, Clone)]
 pub struct LocalClipboard {
     text: Arc<Mutex<String>>,
+    primary: Arc<Mutex<String>>,
 }
 
 impl LocalClipboard {
@@ -112,6 +139,23 @@ impl Clipboard for LocalClipboard {
             Err(_) => Err(ClipboardError),
         }
     }
+
+    fn get_primary(&self) -> Result<String, ClipboardError> {
+        match self.primary.lock() {
+            Ok(v) => Ok(v.clone()),
+            Err(_) => Err(ClipboardError),
+        }
+    }
+
+    fn set_primary(&self, s: &str) -> Result<(), ClipboardError> {
+        match self.primary.lock() {
+            Ok(mut v) => {
+                *v = s.to_string();
+                Ok(())
+            }
+            Err(_) => Err(ClipboardError),
+        }
+    }
 }
 
 impl Clone for Box<dyn Clipboard> {
@@ -128,4 +172,12 @@ impl Clipboard for Box<dyn Clipboard> {
     fn set_string(&self, s: &str) -> Result<(), ClipboardError> {
         self.as_ref().set_string(s)
     }
+
+    fn get_primary(&self) -> Result<String, ClipboardError> {
+        self.as_ref().get_primary()
+    }
+
+    fn set_primary(&self, s: &str) -> Result<(), ClipboardError> {
+        self.as_ref().set_primary(s)
+    }
 }
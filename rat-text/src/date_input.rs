@@ -5,14 +5,19 @@
 use crate::_private::NonExhaustive;
 use crate::clipboard::Clipboard;
 use crate::event::{ReadOnly, TextOutcome};
+use crate::spin::{layout_spin, render_spin, spin_event, SpinEvent};
 use crate::text_input_mask::{MaskedInput, MaskedInputState};
 use crate::undo_buffer::{UndoBuffer, UndoEntry};
-use crate::{upos_type, HasScreenCursor, TextError, TextFocusGained, TextFocusLost, TextStyle};
-use chrono::format::{Fixed, Item, Numeric, Pad, StrftimeItems};
-use chrono::NaiveDate;
+use crate::{
+    upos_type, HasScreenCursor, Spin, TextError, TextFocusGained, TextFocusLost, TextStyle,
+    ValueError, ValueErrorKind,
+};
+use chrono::format::{Fixed, Item, Numeric, Pad, ParseErrorKind, StrftimeItems};
+use chrono::{Days, Local, Months, NaiveDate};
+use crossterm::event::KeyModifiers;
 use rat_event::{HandleEvent, MouseOnly, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
-use rat_reloc::RelocatableState;
+use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::{StatefulWidget, Style};
@@ -20,7 +25,8 @@ use ratatui::widgets::Block;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
 use std::fmt;
-use std::ops::Range;
+use std::mem;
+use std::ops::{Range, RangeInclusive};
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Widget for dates.
@@ -31,6 +37,9 @@ use unicode_segmentation::UnicodeSegmentation;
 #[derive(Debug, Default, Clone)]
 pub struct DateInput<'a> {
     widget: MaskedInput<'a>,
+    spin: bool,
+    style: Style,
+    spin_style: Option<Style>,
 }
 
 /// State & event-handling.
@@ -43,10 +52,44 @@ pub struct DateInputState {
     pattern: String,
     /// Locale
     locale: chrono::Locale,
+    /// Sections of the pattern.
+    sections: Vec<DateSection>,
+
+    /// Area of the increment button.
+    /// __readonly__ renewed with each render.
+    pub spin_up: Rect,
+    /// Area of the decrement button.
+    /// __readonly__ renewed with each render.
+    pub spin_down: Rect,
+    /// Spin button currently held down.
+    /// Use [spin_repeat](DateInputState::spin_repeat) to
+    /// auto-repeat while it is pressed.
+    /// __read+write__
+    pub spin_pressed: Option<Spin>,
+    /// Spin button was pressed with Ctrl.
+    spin_month: bool,
+
+    /// Step in days for the spin buttons and the mouse-wheel.
+    step: u64,
+    /// Lower bound.
+    min: Option<NaiveDate>,
+    /// Upper bound.
+    max: Option<NaiveDate>,
+    /// Wrap around at the bounds.
+    wrap: bool,
 
     pub non_exhaustive: NonExhaustive,
 }
 
+/// One field of the chrono pattern and its place in the mask.
+#[derive(Debug, Clone)]
+struct DateSection {
+    range: Range<upos_type>,
+    field: &'static str,
+    /// Valid values for numeric fields.
+    valid: Option<RangeInclusive<u32>>,
+}
+
 impl<'a> DateInput<'a> {
     pub fn new() -> Self {
         Self::default()
@@ -59,9 +102,23 @@ impl<'a> DateInput<'a> {
         self
     }
 
+    /// Show spin buttons at the right edge.
+    ///
+    /// This enables the mouse-wheel too. The step is one day,
+    /// or one month if Ctrl is pressed.
+    #[inline]
+    pub fn spin(mut self, spin: bool) -> Self {
+        self.spin = spin;
+        self
+    }
+
     /// Set the combined style.
     #[inline]
     pub fn styles(mut self, style: TextStyle) -> Self {
+        self.style = style.style;
+        if style.spin.is_some() {
+            self.spin_style = style.spin;
+        }
         self.widget = self.widget.styles(style);
         self
     }
@@ -69,6 +126,8 @@ impl<'a> DateInput<'a> {
     /// Base text style.
     #[inline]
     pub fn style(mut self, style: impl Into<Style>) -> Self {
+        let style = style.into();
+        self.style = style;
         self.widget = self.widget.style(style);
         self
     }
@@ -94,6 +153,13 @@ impl<'a> DateInput<'a> {
         self
     }
 
+    /// Style for the spin buttons.
+    #[inline]
+    pub fn spin_style(mut self, style: impl Into<Style>) -> Self {
+        self.spin_style = Some(style.into());
+        self
+    }
+
     /// Block
     #[inline]
     pub fn block(mut self, block: Block<'a>) -> Self {
@@ -121,24 +187,60 @@ impl<'a> StatefulWidgetRef for DateInput<'a> {
     type State = DateInputState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        self.widget.render_ref(area, buf, &mut state.widget);
+        self.widget
+            .clone()
+            .spin(self.spin)
+            .render(area, buf, &mut state.widget);
+        render_spin_buttons(self, buf, state);
     }
 }
 
 impl StatefulWidget for DateInput<'_> {
     type State = DateInputState;
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        self.widget.render(area, buf, &mut state.widget);
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        mem::take(&mut self.widget)
+            .spin(self.spin)
+            .render(area, buf, &mut state.widget);
+        render_spin_buttons(&self, buf, state);
     }
 }
 
+fn render_spin_buttons(widget: &DateInput<'_>, buf: &mut Buffer, state: &mut DateInputState) {
+    (state.spin_up, state.spin_down) = layout_spin(state.widget.spin_area);
+    if !widget.spin {
+        state.spin_pressed = None;
+        return;
+    }
+    let style = if let Some(spin_style) = widget.spin_style {
+        widget.style.patch(spin_style)
+    } else {
+        widget.style
+    };
+    render_spin(
+        state.spin_up,
+        state.spin_down,
+        style,
+        state.spin_pressed,
+        buf,
+    );
+}
+
 impl Default for DateInputState {
     fn default() -> Self {
         Self {
             widget: Default::default(),
             pattern: Default::default(),
             locale: Default::default(),
+            sections: Default::default(),
+            spin_up: Default::default(),
+            spin_down: Default::default(),
+            spin_pressed: None,
+            spin_month: false,
+            step: 1,
+            min: None,
+            max: None,
+            wrap: false,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -227,6 +329,19 @@ impl DateInputState {
         locale: chrono::Locale,
     ) -> Result<(), fmt::Error> {
         let mut mask = String::new();
+        let mut sections = Vec::new();
+        let mut pos = 0;
+        let mut section = |pos: &mut upos_type, mask_str: &str, field: &'static str, valid| {
+            let len = mask_str.graphemes(true).count() as upos_type;
+            sections.push(DateSection {
+                range: *pos..*pos + len,
+                field,
+                valid,
+            });
+            *pos += len;
+            mask_str.to_string()
+        };
+
         let items = StrftimeItems::new_with_locale(pattern.as_ref(), locale)
             .parse()
             .map_err(|_| fmt::Error)?;
@@ -236,98 +351,78 @@ impl DateInputState {
                     for c in s.graphemes(true) {
                         mask.push('\\');
                         mask.push_str(c);
+                        pos += 1;
                     }
                 }
                 Item::OwnedLiteral(s) => {
                     for c in s.graphemes(true) {
                         mask.push('\\');
                         mask.push_str(c);
+                        pos += 1;
                     }
                 }
                 Item::Space(s) => {
                     for c in s.graphemes(true) {
                         mask.push_str(c);
+                        pos += 1;
                     }
                 }
                 Item::OwnedSpace(s) => {
                     for c in s.graphemes(true) {
                         mask.push_str(c);
+                        pos += 1;
                     }
                 }
-                Item::Numeric(v, Pad::None | Pad::Space) => match v {
-                    Numeric::Year | Numeric::IsoYear => mask.push_str("9999"),
-                    Numeric::YearDiv100
-                    | Numeric::YearMod100
-                    | Numeric::IsoYearDiv100
-                    | Numeric::IsoYearMod100
-                    | Numeric::Month
-                    | Numeric::Day
-                    | Numeric::WeekFromSun
-                    | Numeric::WeekFromMon
-                    | Numeric::IsoWeek
-                    | Numeric::Hour
-                    | Numeric::Hour12
-                    | Numeric::Minute
-                    | Numeric::Second => mask.push_str("99"),
-                    Numeric::NumDaysFromSun | Numeric::WeekdayFromMon => mask.push('9'),
-                    Numeric::Ordinal => mask.push_str("999"),
-                    Numeric::Nanosecond => mask.push_str("999999999"),
-                    Numeric::Timestamp => mask.push_str("###########"),
-                    _ => return Err(fmt::Error),
-                },
-                Item::Numeric(v, Pad::Zero) => match v {
-                    Numeric::Year | Numeric::IsoYear => mask.push_str("0000"),
-                    Numeric::YearDiv100
-                    | Numeric::YearMod100
-                    | Numeric::IsoYearDiv100
-                    | Numeric::IsoYearMod100
-                    | Numeric::Month
-                    | Numeric::Day
-                    | Numeric::WeekFromSun
-                    | Numeric::WeekFromMon
-                    | Numeric::IsoWeek
-                    | Numeric::Hour
-                    | Numeric::Hour12
-                    | Numeric::Minute
-                    | Numeric::Second => mask.push_str("00"),
-                    Numeric::NumDaysFromSun | Numeric::WeekdayFromMon => mask.push('0'),
-                    Numeric::Ordinal => mask.push_str("000"),
-                    Numeric::Nanosecond => mask.push_str("000000000"),
-                    Numeric::Timestamp => mask.push_str("#0000000000"),
-                    _ => return Err(fmt::Error),
-                },
-                Item::Fixed(v) => match v {
-                    Fixed::ShortMonthName => mask.push_str("___"),
-                    Fixed::LongMonthName => mask.push_str("_________"),
-                    Fixed::ShortWeekdayName => mask.push_str("___"),
-                    Fixed::LongWeekdayName => mask.push_str("________"),
-                    Fixed::LowerAmPm => mask.push_str("__"),
-                    Fixed::UpperAmPm => mask.push_str("__"),
-                    Fixed::Nanosecond => mask.push_str(".#########"),
-                    Fixed::Nanosecond3 => mask.push_str(".###"),
-                    Fixed::Nanosecond6 => mask.push_str(".######"),
-                    Fixed::Nanosecond9 => mask.push_str(".#########"),
-                    Fixed::TimezoneName => mask.push_str("__________"),
-                    Fixed::TimezoneOffsetColon | Fixed::TimezoneOffset => mask.push_str("+##:##"),
-                    Fixed::TimezoneOffsetDoubleColon => mask.push_str("+##:##:##"),
-                    Fixed::TimezoneOffsetTripleColon => mask.push_str("+##"),
-                    Fixed::TimezoneOffsetColonZ | Fixed::TimezoneOffsetZ => return Err(fmt::Error),
-                    Fixed::RFC2822 => {
-                        // 01 Jun 2016 14:31:46 -0700
-                        return Err(fmt::Error);
-                    }
-                    Fixed::RFC3339 => {
-                        // not supported, for now
-                        return Err(fmt::Error);
-                    }
-                    _ => return Err(fmt::Error),
-                },
+                Item::Numeric(v, pad) => {
+                    let (width, field, valid) = numeric_field(v)?;
+                    let m = match (v, pad) {
+                        (Numeric::Timestamp, Pad::Zero) => "#0000000000".to_string(),
+                        (Numeric::Timestamp, _) => "###########".to_string(),
+                        (_, Pad::Zero) => "0".repeat(width),
+                        (_, _) => "9".repeat(width),
+                    };
+                    mask.push_str(&section(&mut pos, &m, field, valid));
+                }
+                Item::Fixed(v) => {
+                    let (m, field) = match v {
+                        Fixed::ShortMonthName => ("___", "month"),
+                        Fixed::LongMonthName => ("_________", "month"),
+                        Fixed::ShortWeekdayName => ("___", "weekday"),
+                        Fixed::LongWeekdayName => ("________", "weekday"),
+                        Fixed::LowerAmPm => ("__", "am/pm"),
+                        Fixed::UpperAmPm => ("__", "am/pm"),
+                        Fixed::Nanosecond => (".#########", "nanosecond"),
+                        Fixed::Nanosecond3 => (".###", "nanosecond"),
+                        Fixed::Nanosecond6 => (".######", "nanosecond"),
+                        Fixed::Nanosecond9 => (".#########", "nanosecond"),
+                        Fixed::TimezoneName => ("__________", "timezone"),
+                        Fixed::TimezoneOffsetColon | Fixed::TimezoneOffset => {
+                            ("+##:##", "timezone")
+                        }
+                        Fixed::TimezoneOffsetDoubleColon => ("+##:##:##", "timezone"),
+                        Fixed::TimezoneOffsetTripleColon => ("+##", "timezone"),
+                        Fixed::TimezoneOffsetColonZ | Fixed::TimezoneOffsetZ => {
+                            return Err(fmt::Error)
+                        }
+                        Fixed::RFC2822 => {
+                            // 01 Jun 2016 14:31:46 -0700
+                            return Err(fmt::Error);
+                        }
+                        Fixed::RFC3339 => {
+                            // not supported, for now
+                            return Err(fmt::Error);
+                        }
+                        _ => return Err(fmt::Error),
+                    };
+                    mask.push_str(&section(&mut pos, m, field, None));
+                }
                 Item::Error => return Err(fmt::Error),
             }
         }
 
         self.locale = locale;
         self.pattern = pattern.as_ref().to_string();
+        self.sections = sections;
         self.widget.set_mask(mask)?;
         Ok(())
     }
@@ -344,6 +439,14 @@ impl DateInputState {
         self.widget.invalid
     }
 
+    /// Renders the widget in invalid style, but only the part
+    /// that caused the error. None resets the invalid state.
+    #[inline]
+    pub fn set_value_error(&mut self, err: Option<&ValueError>) {
+        self.widget.invalid = err.is_some();
+        self.widget.invalid_range = err.map(|v| v.range.clone());
+    }
+
     /// The next edit operation will overwrite the current content
     /// instead of adding text. Any move operations will cancel
     /// this overwrite.
@@ -357,6 +460,47 @@ impl DateInputState {
     pub fn overwrite(&self) -> bool {
         self.widget.overwrite
     }
+
+    /// Step in days for the spin buttons and the mouse-wheel.
+    /// Default is 1.
+    #[inline]
+    pub fn set_step(&mut self, days: u64) {
+        self.step = days;
+    }
+
+    /// Step in days for the spin buttons and the mouse-wheel.
+    #[inline]
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Bounds for the spin buttons and the mouse-wheel.
+    ///
+    /// The bounds are not enforced for text input.
+    #[inline]
+    pub fn set_range(&mut self, min: Option<NaiveDate>, max: Option<NaiveDate>) {
+        self.min = min;
+        self.max = max;
+    }
+
+    /// Bounds for the spin buttons and the mouse-wheel.
+    #[inline]
+    pub fn range(&self) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        (self.min, self.max)
+    }
+
+    /// Wrap around at the bounds instead of clamping.
+    /// This needs both bounds.
+    #[inline]
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Wrap around at the bounds instead of clamping.
+    #[inline]
+    pub fn wrap(&self) -> bool {
+        self.wrap
+    }
 }
 
 impl DateInputState {
@@ -578,9 +722,66 @@ impl DateInputState {
     }
 
     /// Parses the text according to the given pattern.
+    ///
+    /// The error contains the section of the text that is wrong.
     #[inline]
-    pub fn value(&self) -> Result<NaiveDate, chrono::ParseError> {
+    pub fn value(&self) -> Result<NaiveDate, ValueError> {
         NaiveDate::parse_from_str(self.widget.text(), self.pattern.as_str())
+            .map_err(|e| self.value_error(e))
+    }
+
+    /// Find the section responsible for the error.
+    fn value_error(&self, err: chrono::ParseError) -> ValueError {
+        let section_text = |s: &DateSection| {
+            self.widget
+                .try_str_slice(s.range.clone())
+                .map(|v| v.trim().to_string())
+                .unwrap_or_default()
+        };
+
+        match err.kind() {
+            ParseErrorKind::OutOfRange | ParseErrorKind::Impossible => {
+                for s in &self.sections {
+                    if let Some(valid) = &s.valid {
+                        if let Ok(v) = section_text(s).parse::<u32>() {
+                            if !valid.contains(&v) {
+                                return ValueError::new(
+                                    ValueErrorKind::OutOfRange { field: s.field },
+                                    s.range.clone(),
+                                );
+                            }
+                        }
+                    }
+                }
+                // each field is fine, but not the combination.
+                let s = self
+                    .sections
+                    .iter()
+                    .find(|v| v.field == "day")
+                    .or(self.sections.first());
+                ValueError::new(
+                    ValueErrorKind::OutOfRange { field: "day" },
+                    s.map(|v| v.range.clone()).unwrap_or(0..self.len()),
+                )
+            }
+            ParseErrorKind::TooLong => {
+                let end = self.sections.last().map(|v| v.range.end).unwrap_or(0);
+                ValueError::new(ValueErrorKind::TrailingInput, end..self.len())
+            }
+            _ => {
+                for s in &self.sections {
+                    if section_text(s).is_empty() {
+                        return ValueError::new(ValueErrorKind::IncompleteSection, s.range.clone());
+                    }
+                }
+                for s in &self.sections {
+                    if s.valid.is_some() && section_text(s).parse::<u32>().is_err() {
+                        return ValueError::new(ValueErrorKind::Invalid, s.range.clone());
+                    }
+                }
+                ValueError::new(ValueErrorKind::Invalid, 0..self.len())
+            }
+        }
     }
 
     /// Length in grapheme count.
@@ -610,6 +811,74 @@ impl DateInputState {
         self.widget.set_text(v);
     }
 
+    /// Increment/decrement the date by one step, or by one month.
+    ///
+    /// An empty input starts with today. Returns false if the value
+    /// can't be parsed or doesn't change due to the bounds.
+    pub fn spin(&mut self, spin: Spin, month: bool) -> bool {
+        let value = if self.widget.is_empty() {
+            None
+        } else {
+            match self.value() {
+                Ok(v) => Some(v),
+                Err(_) => return false,
+            }
+        };
+
+        let mut new_value = if let Some(value) = value {
+            let new_value = match (spin, month) {
+                (Spin::Up, false) => value.checked_add_days(Days::new(self.step)),
+                (Spin::Down, false) => value.checked_sub_days(Days::new(self.step)),
+                (Spin::Up, true) => value.checked_add_months(Months::new(1)),
+                (Spin::Down, true) => value.checked_sub_months(Months::new(1)),
+            };
+            let Some(new_value) = new_value else {
+                return false;
+            };
+            new_value
+        } else {
+            Local::now().date_naive()
+        };
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if self.wrap && value.is_some() => {
+                if new_value > max {
+                    new_value = min;
+                } else if new_value < min {
+                    new_value = max;
+                }
+            }
+            (min, max) => {
+                if let Some(max) = max {
+                    new_value = new_value.min(max);
+                }
+                if let Some(min) = min {
+                    new_value = new_value.max(min);
+                }
+            }
+        }
+        if value == Some(new_value) {
+            return false;
+        }
+        self.set_value(new_value);
+        true
+    }
+
+    /// Repeat the step of the currently pressed spin button.
+    ///
+    /// Call this from a timer while [spin_pressed](DateInputState::spin_pressed)
+    /// is set to get auto-repeat.
+    pub fn spin_repeat(&mut self) -> TextOutcome {
+        if let Some(spin) = self.spin_pressed {
+            if self.spin(spin, self.spin_month) {
+                TextOutcome::TextChanged
+            } else {
+                TextOutcome::Unchanged
+            }
+        } else {
+            TextOutcome::Continue
+        }
+    }
+
     /// Insert a char at the current position.
     #[inline]
     pub fn insert_char(&mut self, c: char) -> bool {
@@ -680,6 +949,8 @@ impl HasScreenCursor for DateInputState {
 impl RelocatableState for DateInputState {
     fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
         self.widget.relocate(shift, clip);
+        self.spin_up = relocate_area(self.spin_up, shift, clip);
+        self.spin_down = relocate_area(self.spin_down, shift, clip);
     }
 }
 
@@ -707,9 +978,38 @@ impl DateInputState {
     }
 }
 
+impl DateInputState {
+    fn handle_spin(&mut self, event: &crossterm::event::Event) -> TextOutcome {
+        match spin_event(
+            event,
+            self.widget.area,
+            self.spin_up,
+            self.spin_down,
+            &mut self.spin_pressed,
+        ) {
+            SpinEvent::Continue => TextOutcome::Continue,
+            SpinEvent::Released => TextOutcome::Changed,
+            SpinEvent::Step(spin, modifiers) => {
+                let month = modifiers.contains(KeyModifiers::CONTROL);
+                if self.spin_pressed.is_some() {
+                    self.spin_month = month;
+                }
+                if self.spin(spin, month) {
+                    TextOutcome::TextChanged
+                } else {
+                    TextOutcome::Unchanged
+                }
+            }
+        }
+    }
+}
+
 impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for DateInputState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> TextOutcome {
-        self.widget.handle(event, Regular)
+        match self.handle_spin(event) {
+            TextOutcome::Continue => self.widget.handle(event, Regular),
+            r => r,
+        }
     }
 }
 
@@ -721,7 +1021,10 @@ impl HandleEvent<crossterm::event::Event, ReadOnly, TextOutcome> for DateInputSt
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, TextOutcome> for DateInputState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> TextOutcome {
-        self.widget.handle(event, MouseOnly)
+        match self.handle_spin(event) {
+            TextOutcome::Continue => self.widget.handle(event, MouseOnly),
+            r => r,
+        }
     }
 }
 
@@ -756,3 +1059,31 @@ pub fn handle_mouse_events(
 ) -> TextOutcome {
     HandleEvent::handle(state, event, MouseOnly)
 }
+
+/// Mask width, name and valid values of a numeric field.
+#[allow(clippy::type_complexity)]
+fn numeric_field(
+    v: &Numeric,
+) -> Result<(usize, &'static str, Option<RangeInclusive<u32>>), fmt::Error> {
+    Ok(match v {
+        Numeric::Year | Numeric::IsoYear => (4, "year", None),
+        Numeric::YearDiv100
+        | Numeric::YearMod100
+        | Numeric::IsoYearDiv100
+        | Numeric::IsoYearMod100 => (2, "year", None),
+        Numeric::Month => (2, "month", Some(1..=12)),
+        Numeric::Day => (2, "day", Some(1..=31)),
+        Numeric::WeekFromSun | Numeric::WeekFromMon => (2, "week", Some(0..=53)),
+        Numeric::IsoWeek => (2, "week", Some(1..=53)),
+        Numeric::Hour => (2, "hour", Some(0..=23)),
+        Numeric::Hour12 => (2, "hour", Some(1..=12)),
+        Numeric::Minute => (2, "minute", Some(0..=59)),
+        Numeric::Second => (2, "second", Some(0..=60)),
+        Numeric::NumDaysFromSun => (1, "weekday", Some(0..=6)),
+        Numeric::WeekdayFromMon => (1, "weekday", Some(1..=7)),
+        Numeric::Ordinal => (3, "day of year", Some(1..=366)),
+        Numeric::Nanosecond => (9, "nanosecond", None),
+        Numeric::Timestamp => (11, "timestamp", None),
+        _ => return Err(fmt::Error),
+    })
+}
@@ -3,8 +3,10 @@ use ropey::iter::Chunks;
 use ropey::RopeSlice;
 use std::borrow::Cow;
 use std::cmp;
+use std::io;
 use std::ops::Range;
 use unicode_segmentation::{GraphemeCursor, GraphemeIncomplete};
+use unicode_width::UnicodeWidthStr;
 
 /// One grapheme.
 #[derive(Debug, PartialEq)]
@@ -54,6 +56,109 @@ impl<'a> Grapheme<'a> {
     }
 }
 
+/// Policy for the display width of graphemes.
+///
+/// Terminals don't agree on the width of some characters.
+/// This is used consistently for rendering, cursor positioning,
+/// scrolling and selection, so it must match the terminal.
+/// Use [WidthPolicy::probe_terminal] to find out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidthPolicy {
+    /// Characters with East Asian Width 'Ambiguous' are
+    /// rendered two columns wide.
+    ///
+    /// Default is false.
+    pub ambiguous_wide: bool,
+    /// Emoji presentation sequences (a text-default character
+    /// followed by U+FE0F) are rendered two columns wide.
+    /// Characters that are wide by default are always wide.
+    ///
+    /// Default is true.
+    pub emoji_wide: bool,
+    /// Tab width.
+    ///
+    /// Default is 8.
+    pub tab_width: u16,
+}
+
+impl Default for WidthPolicy {
+    fn default() -> Self {
+        Self {
+            ambiguous_wide: false,
+            emoji_wide: true,
+            tab_width: 8,
+        }
+    }
+}
+
+impl WidthPolicy {
+    /// Display width of a single grapheme cluster.
+    ///
+    /// Tabs and control characters are not handled here.
+    pub fn width(&self, grapheme: &str) -> u16 {
+        if grapheme.contains('\u{FE0F}') && !self.emoji_wide {
+            let base = grapheme.replace('\u{FE0F}', "");
+            return self.width(&base);
+        }
+
+        let width = unicode_display_width::width(grapheme) as u16;
+        if self.ambiguous_wide {
+            width.max(grapheme.width_cjk() as u16)
+        } else {
+            width
+        }
+    }
+
+    /// Probe the terminal by printing some test characters
+    /// and reading back the cursor position.
+    ///
+    /// This should be run at startup before anything else is
+    /// rendered. It prints on the current line and clears it
+    /// afterward.
+    ///
+    /// Reading the cursor position needs raw mode. If the
+    /// terminal is not in raw mode it is switched for the
+    /// probe and restored afterward.
+    pub fn probe_terminal(out: &mut impl io::Write) -> io::Result<WidthPolicy> {
+        use crossterm::cursor::{position, MoveToColumn};
+        use crossterm::execute;
+        use crossterm::style::Print;
+        use crossterm::terminal::{
+            disable_raw_mode, enable_raw_mode, is_raw_mode_enabled, Clear, ClearType,
+        };
+
+        let mut probe = |txt: &str| -> io::Result<u16> {
+            execute!(out, MoveToColumn(0), Print(txt))?;
+            let (x, _) = position()?;
+            execute!(out, MoveToColumn(0), Clear(ClearType::CurrentLine))?;
+            Ok(x)
+        };
+        let mut probe_all = || -> io::Result<(u16, u16)> {
+            // circled digit one, ambiguous
+            let ambiguous = probe("\u{2460}")?;
+            // heavy black heart + VS16
+            let emoji = probe("\u{2764}\u{FE0F}")?;
+            Ok((ambiguous, emoji))
+        };
+
+        let raw = is_raw_mode_enabled()?;
+        if !raw {
+            enable_raw_mode()?;
+        }
+        let r = probe_all();
+        if !raw {
+            disable_raw_mode()?;
+        }
+        let (ambiguous, emoji) = r?;
+
+        Ok(WidthPolicy {
+            ambiguous_wide: ambiguous >= 2,
+            emoji_wide: emoji >= 2,
+            ..Default::default()
+        })
+    }
+}
+
 /// Data for rendering/mapping graphemes to screen coordinates.
 #[derive(Debug)]
 pub struct Glyph<'a> {
@@ -459,7 +564,7 @@ pub(crate) struct GlyphIter<Iter> {
     screen_width: u16,
     screen_pos: (u16, u16),
 
-    tabs: u16,
+    width_policy: WidthPolicy,
     show_ctrl: bool,
     line_break: bool,
 }
@@ -476,7 +581,7 @@ where
             screen_offset: 0,
             screen_width: u16::MAX,
             screen_pos: Default::default(),
-            tabs: 8,
+            width_policy: Default::default(),
             show_ctrl: false,
             line_break: true,
         }
@@ -492,9 +597,9 @@ where
         self.screen_width = width;
     }
 
-    /// Tab width
-    pub(crate) fn set_tabs(&mut self, tabs: u16) {
-        self.tabs = tabs;
+    /// Width policy, includes the tab width.
+    pub(crate) fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        self.width_policy = width_policy;
     }
 
     /// Handle line-breaks. If false everything is treated as one line.
@@ -534,7 +639,8 @@ where
                     glyph = Cow::Borrowed("\u{2424}");
                 }
                 "\t" => {
-                    len = self.tabs - (self.screen_pos.0 % self.tabs);
+                    let tabs = self.width_policy.tab_width.max(1);
+                    len = tabs - (self.screen_pos.0 % tabs);
                     glyph = Cow::Borrowed(if self.show_ctrl { "\u{2409}" } else { " " });
                 }
                 c if ("\x00".."\x20").contains(&c) => {
@@ -555,7 +661,7 @@ where
                     });
                 }
                 c => {
-                    len = unicode_display_width::width(c) as u16;
+                    len = self.width_policy.width(c);
                     glyph = grapheme.grapheme;
                 }
             }
@@ -1039,7 +1145,7 @@ mod test_rope {
 
 #[cfg(test)]
 mod test_glyph {
-    use crate::grapheme::{GlyphIter, RopeGraphemes};
+    use crate::grapheme::{GlyphIter, RopeGraphemes, WidthPolicy};
     use crate::TextPosition;
     use ropey::Rope;
 
@@ -1267,4 +1373,63 @@ uiopü+uiop",
         assert_eq!(n.pos(), TextPosition::new(2, 1));
         assert_eq!(n.screen_width(), 1);
     }
+
+    #[test]
+    fn test_glyph_zwj() {
+        // family emoji is one grapheme, the cursor moves past all of it.
+        let s = Rope::from("a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}b");
+        let r = RopeGraphemes::new(0, s.byte_slice(..));
+        let mut glyphs = GlyphIter::new(TextPosition::new(0, 0), r);
+
+        let n = glyphs.nth(1).unwrap();
+        assert_eq!(n.text_bytes(), 1..19);
+        assert_eq!(n.pos(), TextPosition::new(1, 0));
+        assert_eq!(n.screen_width(), 2);
+
+        let n = glyphs.next().unwrap();
+        assert_eq!(n.glyph(), "b");
+        assert_eq!(n.pos(), TextPosition::new(2, 0));
+        assert_eq!(n.screen_pos(), (3, 0));
+    }
+
+    #[test]
+    fn test_glyph_combining() {
+        // e + combining acute, a + combining ring + combining dot below
+        let s = Rope::from("e\u{301}a\u{30A}\u{323}x");
+        let r = RopeGraphemes::new(0, s.byte_slice(..));
+        let mut glyphs = GlyphIter::new(TextPosition::new(0, 0), r);
+
+        let n = glyphs.next().unwrap();
+        assert_eq!(n.glyph(), "e\u{301}");
+        assert_eq!(n.screen_width(), 1);
+        let n = glyphs.next().unwrap();
+        assert_eq!(n.glyph(), "a\u{30A}\u{323}");
+        assert_eq!(n.screen_pos(), (1, 0));
+        assert_eq!(n.screen_width(), 1);
+        let n = glyphs.next().unwrap();
+        assert_eq!(n.glyph(), "x");
+        assert_eq!(n.pos(), TextPosition::new(2, 0));
+        assert_eq!(n.screen_pos(), (2, 0));
+    }
+
+    #[test]
+    fn test_glyph_policy() {
+        // circled digit one (ambiguous), heart + VS16, tab
+        let s = Rope::from("\u{2460}\u{2764}\u{FE0F}\tx");
+
+        let r = RopeGraphemes::new(0, s.byte_slice(..));
+        let glyphs = GlyphIter::new(TextPosition::new(0, 0), r);
+        let w = glyphs.map(|v| v.screen_width()).collect::<Vec<_>>();
+        assert_eq!(w, vec![1, 2, 5, 1]);
+
+        let r = RopeGraphemes::new(0, s.byte_slice(..));
+        let mut glyphs = GlyphIter::new(TextPosition::new(0, 0), r);
+        glyphs.set_width_policy(WidthPolicy {
+            ambiguous_wide: true,
+            emoji_wide: false,
+            tab_width: 4,
+        });
+        let w = glyphs.map(|v| v.screen_width()).collect::<Vec<_>>();
+        assert_eq!(w, vec![2, 1, 1, 1]);
+    }
 }
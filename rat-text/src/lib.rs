@@ -1,5 +1,6 @@
 #![doc = include_str!("../readme.md")]
 
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
@@ -8,6 +9,7 @@ pub mod clipboard;
 pub mod date_input;
 pub mod line_number;
 pub mod number_input;
+pub mod style_provider;
 pub mod text_area;
 pub mod text_input;
 pub mod text_input_mask;
@@ -15,11 +17,13 @@ pub mod undo_buffer;
 
 mod grapheme;
 mod range_map;
+mod spin;
 mod text_core;
 mod text_mask_core;
 mod text_store;
 
-pub use grapheme::{Glyph, Grapheme};
+pub use grapheme::{Glyph, Grapheme, WidthPolicy};
+pub use spin::Spin;
 
 use crate::_private::NonExhaustive;
 pub use pure_rust_locales::Locale;
@@ -117,6 +121,18 @@ pub enum TextFocusLost {
     Position0,
 }
 
+/// Input mode for typed characters.
+///
+/// Currently only used by the masked input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    /// Insert at the cursor and shift the rest of the section.
+    #[default]
+    Insert,
+    /// Replace the character at the cursor.
+    Overtype,
+}
+
 /// Combined style for the widget.
 #[derive(Debug, Clone)]
 pub struct TextStyle {
@@ -124,6 +140,8 @@ pub struct TextStyle {
     pub focus: Option<Style>,
     pub select: Option<Style>,
     pub invalid: Option<Style>,
+    /// Spin buttons for number and date inputs.
+    pub spin: Option<Style>,
 
     /// Focus behaviour.
     pub on_focus_gained: Option<TextFocusGained>,
@@ -144,6 +162,7 @@ impl Default for TextStyle {
             focus: None,
             select: None,
             invalid: None,
+            spin: None,
             on_focus_gained: None,
             on_focus_lost: None,
             scroll: None,
@@ -160,7 +179,7 @@ pub mod core {
     //! Used to implement the widgets.
     //!
 
-    pub use crate::text_core::TextCore;
+    pub use crate::text_core::{TextChangeHook, TextCore};
     pub use crate::text_mask_core::MaskedCore;
     pub use crate::text_store::text_rope::TextRope;
     pub use crate::text_store::text_string::TextString;
@@ -237,6 +256,8 @@ pub enum TextError {
         usize, // Start.
         usize, // End.
     ),
+    /// Indicates that two text-ranges overlap where this is not allowed.
+    TextRangeOverlap(TextRange, TextRange),
 }
 
 impl Display for TextError {
@@ -247,6 +268,72 @@ impl Display for TextError {
 
 impl Error for TextError {}
 
+/// Kind of [ValueError].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueErrorKind {
+    /// A section of the input is empty or not complete.
+    IncompleteSection,
+    /// The value of a field is out of range.
+    OutOfRange {
+        /// Name of the field, e.g. `"month"`.
+        field: &'static str,
+    },
+    /// There is more input than expected.
+    TrailingInput,
+    /// The input can't be parsed.
+    Invalid,
+}
+
+/// The text of an input widget could not be converted to a value.
+///
+/// Contains the part of the text that is wrong. Can be used
+/// to mark this part with
+/// [set_value_error](crate::date_input::DateInputState::set_value_error).
+///
+/// The Display impl gives a short message for a status line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueError {
+    /// What is wrong.
+    pub kind: ValueErrorKind,
+    /// Offending range as grapheme positions in the text.
+    /// Usually the range of a section of the input mask.
+    pub range: Range<upos_type>,
+}
+
+impl ValueError {
+    /// New error.
+    pub fn new(kind: ValueErrorKind, range: Range<upos_type>) -> Self {
+        Self { kind, range }
+    }
+
+    /// Position of the error as grapheme position.
+    pub fn pos(&self) -> upos_type {
+        self.range.start
+    }
+}
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ValueErrorKind::IncompleteSection => {
+                write!(f, "incomplete input at column {}", self.pos() + 1)
+            }
+            ValueErrorKind::OutOfRange { field } => {
+                write!(f, "{} out of range", field)
+            }
+            ValueErrorKind::TrailingInput => {
+                write!(f, "unexpected input at column {}", self.pos() + 1)
+            }
+            ValueErrorKind::Invalid => {
+                write!(f, "invalid input at column {}", self.pos() + 1)
+            }
+        }
+    }
+}
+
+impl Error for ValueError {}
+
 /// Row/Column type.
 #[allow(non_camel_case_types)]
 pub type upos_type = u32;
@@ -289,6 +376,25 @@ impl From<TextPosition> for (upos_type, upos_type) {
     }
 }
 
+/// One edit for [TextCore::apply_edits](crate::core::TextCore::apply_edits).
+///
+/// Replaces the range with the new text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit<'a> {
+    pub range: TextRange,
+    pub new_text: Cow<'a, str>,
+}
+
+impl<'a> TextEdit<'a> {
+    /// New edit.
+    pub fn new(range: impl Into<TextRange>, new_text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            range: range.into(),
+            new_text: new_text.into(),
+        }
+    }
+}
+
 /// Exclusive range for text ranges.
 #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct TextRange {
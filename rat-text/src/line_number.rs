@@ -1,16 +1,28 @@
 //!
 //! Line numbers widget.
 //!
+//! Can show a margin with [LineMarker]s for diff state, breakpoints
+//! or bookmarks. Clicks are reported as [LineNumberOutcome::Clicked].
+//!
+//! TextArea has no word-wrap, each row is one line of text.
+//! Use the [offset](crate::text_area::TextAreaState::offset) of
+//! the TextArea as start and everything stays aligned.
+//!
 
 use crate::_private::NonExhaustive;
 use crate::upos_type;
 use format_num_pattern::NumberFormat;
 use rat_event::util::MouseFlags;
+use rat_event::{ct_event, HandleEvent, MouseOnly};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::{BlockExt, StatefulWidget, Style};
+use ratatui::style::Color;
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Widget};
+use std::collections::HashMap;
+
+pub use event::LineNumberOutcome;
 
 /// Renders line-numbers.
 ///
@@ -28,21 +40,78 @@ pub struct LineNumbers<'a> {
     flag_width: Option<u16>,
     margin: (u16, u16),
 
+    markers: Option<&'a HashMap<upos_type, LineMarker>>,
+    marker_placement: MarkerPlacement,
+
     format: Option<NumberFormat>,
     style: Style,
     cursor_style: Option<Style>,
+    marker_styles: MarkerStyles,
 
     block: Option<Block<'a>>,
 }
 
+/// Marker for a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineMarker {
+    /// Line was added.
+    Added,
+    /// Line was modified.
+    Modified,
+    /// Lines have been removed below this one.
+    RemovedBelow,
+    /// Breakpoint.
+    Breakpoint,
+    /// Bookmark.
+    Bookmark,
+    /// Something else.
+    Custom(char, Style),
+}
+
+/// Position of the marker column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerPlacement {
+    /// Before the line numbers.
+    #[default]
+    Before,
+    /// After the line numbers.
+    After,
+}
+
+/// Part of the widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineNumberPart {
+    /// Marker column.
+    Marker,
+    /// Line number.
+    Number,
+    /// Flags.
+    Flags,
+}
+
+#[derive(Debug, Default, Clone)]
+struct MarkerStyles {
+    added: Option<Style>,
+    modified: Option<Style>,
+    removed: Option<Style>,
+    breakpoint: Option<Style>,
+    bookmark: Option<Style>,
+}
+
 /// Styles as a package.
 #[derive(Debug, Clone)]
 pub struct LineNumberStyle {
     pub flag_width: Option<u16>,
     pub margin: Option<(u16, u16)>,
     pub format: Option<NumberFormat>,
+    pub marker_placement: Option<MarkerPlacement>,
     pub style: Style,
     pub cursor: Option<Style>,
+    pub added: Option<Style>,
+    pub modified: Option<Style>,
+    pub removed: Option<Style>,
+    pub breakpoint: Option<Style>,
+    pub bookmark: Option<Style>,
     pub block: Option<Block<'static>>,
 
     pub non_exhaustive: NonExhaustive,
@@ -51,10 +120,25 @@ pub struct LineNumberStyle {
 /// State
 #[derive(Debug, Clone)]
 pub struct LineNumberState {
+    /// Full area.
+    /// __readonly__. renewed for each render.
     pub area: Rect,
+    /// Area inside the block.
+    /// __readonly__. renewed for each render.
     pub inner: Rect,
-
+    /// Column with the markers. Empty if there are no markers.
+    /// __readonly__. renewed for each render.
+    pub marker_area: Rect,
+    /// Column with the flags.
+    /// __readonly__. renewed for each render.
+    pub flag_area: Rect,
+
+    /// First line.
+    /// __readonly__. renewed for each render.
     pub start: upos_type,
+    /// End of the text.
+    /// __readonly__. renewed for each render.
+    pub end: upos_type,
 
     /// Helper for mouse.
     pub mouse: MouseFlags,
@@ -109,6 +193,21 @@ impl<'a> LineNumbers<'a> {
         self
     }
 
+    /// Markers by line.
+    ///
+    /// Setting this adds a column for the markers,
+    /// even if the map is empty.
+    pub fn markers(mut self, markers: &'a HashMap<upos_type, LineMarker>) -> Self {
+        self.markers = Some(markers);
+        self
+    }
+
+    /// Marker column before or after the line numbers.
+    pub fn marker_placement(mut self, placement: MarkerPlacement) -> Self {
+        self.marker_placement = placement;
+        self
+    }
+
     /// Line number format.
     pub fn format(mut self, format: NumberFormat) -> Self {
         self.format = Some(format);
@@ -130,6 +229,24 @@ impl<'a> LineNumbers<'a> {
         if let Some(cursor_style) = styles.cursor {
             self.cursor_style = Some(cursor_style);
         }
+        if let Some(placement) = styles.marker_placement {
+            self.marker_placement = placement;
+        }
+        if styles.added.is_some() {
+            self.marker_styles.added = styles.added;
+        }
+        if styles.modified.is_some() {
+            self.marker_styles.modified = styles.modified;
+        }
+        if styles.removed.is_some() {
+            self.marker_styles.removed = styles.removed;
+        }
+        if styles.breakpoint.is_some() {
+            self.marker_styles.breakpoint = styles.breakpoint;
+        }
+        if styles.bookmark.is_some() {
+            self.marker_styles.bookmark = styles.bookmark;
+        }
         if let Some(block) = styles.block {
             self.block = Some(block);
         }
@@ -176,8 +293,22 @@ impl<'a> LineNumbers<'a> {
             let area = self.block.inner_if_some(Rect::new(0, 0, 2, 2));
             2 - area.width
         };
+        let marker_width = if self.markers.is_some() { 1 } else { 0 };
 
-        nr_width + flag_width + self.margin.0 + self.margin.1 + block_width + 1
+        nr_width + flag_width + marker_width + self.margin.0 + self.margin.1 + block_width + 1
+    }
+
+    fn marker(&self, marker: LineMarker) -> (char, Style) {
+        let styles = &self.marker_styles;
+        let fallback = |fg: Color| self.style.fg(fg);
+        match marker {
+            LineMarker::Added => ('▎', styles.added.unwrap_or(fallback(Color::Green))),
+            LineMarker::Modified => ('▎', styles.modified.unwrap_or(fallback(Color::Yellow))),
+            LineMarker::RemovedBelow => ('▁', styles.removed.unwrap_or(fallback(Color::Red))),
+            LineMarker::Breakpoint => ('●', styles.breakpoint.unwrap_or(fallback(Color::Red))),
+            LineMarker::Bookmark => ('◆', styles.bookmark.unwrap_or(fallback(Color::Cyan))),
+            LineMarker::Custom(c, style) => (c, self.style.patch(style)),
+        }
     }
 }
 
@@ -189,6 +320,12 @@ impl Default for LineNumberStyle {
             format: None,
             style: Default::default(),
             cursor: None,
+            marker_placement: None,
+            added: None,
+            modified: None,
+            removed: None,
+            breakpoint: None,
+            bookmark: None,
             block: None,
             non_exhaustive: NonExhaustive,
         }
@@ -198,11 +335,12 @@ impl Default for LineNumberStyle {
 impl StatefulWidget for LineNumbers<'_> {
     type State = LineNumberState;
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         state.area = area;
         state.inner = self.block.inner_if_some(area);
         state.start = self.start;
         let end = self.end.unwrap_or(upos_type::MAX);
+        state.end = end;
 
         let nr_width = if let Some(end) = self.end {
             end.ilog10() as u16 + 1
@@ -220,7 +358,7 @@ impl StatefulWidget for LineNumbers<'_> {
                 .unwrap_or_default()
         };
 
-        let format = if let Some(format) = self.format {
+        let format = if let Some(format) = self.format.take() {
             format
         } else {
             let mut f = "#".repeat(nr_width.saturating_sub(1) as usize);
@@ -234,7 +372,27 @@ impl StatefulWidget for LineNumbers<'_> {
             self.style
         };
 
-        if let Some(block) = self.block {
+        // column layout
+        let marker_width = if self.markers.is_some() { 1 } else { 0 };
+        let mut x = state.inner.x + self.margin.0;
+        let mut marker_x = x;
+        if self.marker_placement == MarkerPlacement::Before {
+            x += marker_width;
+        }
+        let nr_x = x;
+        x += nr_width;
+        if self.marker_placement == MarkerPlacement::After {
+            marker_x = x;
+            x += marker_width;
+        }
+        let flag_x = x + 1;
+
+        state.marker_area = Rect::new(marker_x, state.inner.y, marker_width, state.inner.height)
+            .intersection(state.inner);
+        state.flag_area = Rect::new(flag_x, state.inner.y, flag_width, state.inner.height)
+            .intersection(state.inner);
+
+        if let Some(block) = &self.block {
             block.render(area, buf);
         } else {
             buf.set_style(area, self.style);
@@ -242,37 +400,44 @@ impl StatefulWidget for LineNumbers<'_> {
 
         let mut tmp = String::new();
         for y in state.inner.top()..state.inner.bottom() {
+            let pos = self.start + (y - state.inner.y) as upos_type;
             let (nr, is_cursor) = if self.relative {
-                let pos = self.start + (y - state.inner.y) as upos_type;
                 (pos.abs_diff(self.cursor), pos == self.cursor)
             } else {
-                let pos = self.start + (y - state.inner.y) as upos_type;
                 (pos, pos == self.cursor)
             };
 
             tmp.clear();
-            if nr < end {
+            if pos < end {
                 _ = format.fmt_to(nr, &mut tmp);
             }
 
             let style = if is_cursor { cursor_style } else { self.style };
 
             let nr_area = Rect::new(
-                state.inner.x + self.margin.0, //
-                y,
-                nr_width,
-                1,
+                nr_x, //
+                y, nr_width, 1,
             )
             .intersection(area);
             buf.set_stringn(nr_area.x, nr_area.y, &tmp, nr_area.width as usize, style);
 
+            if let Some(markers) = self.markers {
+                if let Some(marker) = markers.get(&pos) {
+                    let (c, style) = self.marker(*marker);
+                    if let Some(cell) = buf.cell_mut((marker_x, y)) {
+                        if state.marker_area.width > 0 {
+                            cell.set_char(c);
+                            cell.set_style(style);
+                        }
+                    }
+                }
+            }
+
             if let Some(flags) = self.flags.get((y - state.inner.y) as usize) {
                 flags.render(
                     Rect::new(
-                        state.inner.x + self.margin.0 + nr_width + 1,
-                        y,
-                        flag_width,
-                        1,
+                        flag_x, //
+                        y, flag_width, 1,
                     ),
                     buf,
                 );
@@ -286,7 +451,10 @@ impl Default for LineNumberState {
         Self {
             area: Default::default(),
             inner: Default::default(),
+            marker_area: Default::default(),
+            flag_area: Default::default(),
             start: 0,
+            end: 0,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -297,4 +465,100 @@ impl LineNumberState {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Line and part of the widget at the given position.
+    pub fn line_at(&self, pos: (u16, u16)) -> Option<(upos_type, LineNumberPart)> {
+        if !self.inner.contains(pos.into()) {
+            return None;
+        }
+        let line = self.start + (pos.1 - self.inner.y) as upos_type;
+        if line >= self.end {
+            return None;
+        }
+        let part = if self.marker_area.contains(pos.into()) {
+            LineNumberPart::Marker
+        } else if self.flag_area.contains(pos.into()) {
+            LineNumberPart::Flags
+        } else {
+            LineNumberPart::Number
+        };
+        Some((line, part))
+    }
+}
+
+pub mod event {
+    use crate::line_number::LineNumberPart;
+    use crate::upos_type;
+    use rat_event::{ConsumedEvent, Outcome};
+
+    /// Result of event handling.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LineNumberOutcome {
+        /// The given event has not been used at all.
+        Continue,
+        /// The event has been recognized, but the result was nil.
+        /// Further processing for this event may stop.
+        Unchanged,
+        /// The event has been recognized and there is some change
+        /// due to it.
+        /// Further processing for this event may stop.
+        /// Rendering the ui is advised.
+        Changed,
+        /// A line was clicked.
+        Clicked(upos_type, LineNumberPart),
+    }
+
+    impl ConsumedEvent for LineNumberOutcome {
+        fn is_consumed(&self) -> bool {
+            *self != LineNumberOutcome::Continue
+        }
+    }
+
+    impl From<Outcome> for LineNumberOutcome {
+        fn from(value: Outcome) -> Self {
+            match value {
+                Outcome::Continue => LineNumberOutcome::Continue,
+                Outcome::Unchanged => LineNumberOutcome::Unchanged,
+                Outcome::Changed => LineNumberOutcome::Changed,
+            }
+        }
+    }
+
+    impl From<LineNumberOutcome> for Outcome {
+        fn from(value: LineNumberOutcome) -> Self {
+            match value {
+                LineNumberOutcome::Continue => Outcome::Continue,
+                LineNumberOutcome::Unchanged => Outcome::Unchanged,
+                LineNumberOutcome::Changed => Outcome::Changed,
+                LineNumberOutcome::Clicked(_, _) => Outcome::Changed,
+            }
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, LineNumberOutcome> for LineNumberState {
+    fn handle(
+        &mut self,
+        event: &crossterm::event::Event,
+        _qualifier: MouseOnly,
+    ) -> LineNumberOutcome {
+        match event {
+            ct_event!(mouse down Left for x,y) if self.inner.contains((*x, *y).into()) => {
+                if let Some((line, part)) = self.line_at((*x, *y)) {
+                    LineNumberOutcome::Clicked(line, part)
+                } else {
+                    LineNumberOutcome::Unchanged
+                }
+            }
+            _ => LineNumberOutcome::Continue,
+        }
+    }
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(
+    state: &mut LineNumberState,
+    event: &crossterm::event::Event,
+) -> LineNumberOutcome {
+    HandleEvent::handle(state, event, MouseOnly)
 }
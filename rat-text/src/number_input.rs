@@ -5,13 +5,17 @@
 use crate::_private::NonExhaustive;
 use crate::clipboard::Clipboard;
 use crate::event::{ReadOnly, TextOutcome};
+use crate::spin::{layout_spin, render_spin, spin_event, SpinEvent};
 use crate::text_input_mask::{MaskedInput, MaskedInputState};
 use crate::undo_buffer::{UndoBuffer, UndoEntry};
-use crate::{upos_type, HasScreenCursor, TextError, TextFocusGained, TextFocusLost, TextStyle};
+use crate::{
+    upos_type, HasScreenCursor, Spin, TextError, TextFocusGained, TextFocusLost, TextStyle,
+    ValueError, ValueErrorKind,
+};
 use format_num_pattern::{NumberFmtError, NumberFormat, NumberSymbols};
 use rat_event::{HandleEvent, MouseOnly, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
-use rat_reloc::RelocatableState;
+use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::{StatefulWidget, Style};
@@ -19,8 +23,10 @@ use ratatui::widgets::Block;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
 use std::fmt::{Debug, Display, LowerExp};
+use std::mem;
 use std::ops::Range;
 use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// NumberInput with [format_num_pattern][refFormatNumPattern] backend. A bit
 /// similar to javas DecimalFormat.
@@ -33,6 +39,9 @@ use std::str::FromStr;
 #[derive(Debug, Default, Clone)]
 pub struct NumberInput<'a> {
     widget: MaskedInput<'a>,
+    spin: bool,
+    style: Style,
+    spin_style: Option<Style>,
 }
 
 /// State & event handling.
@@ -49,6 +58,27 @@ pub struct NumberInputState {
     // paramter locale used here.
     format: NumberFormat,
 
+    /// Area of the increment button.
+    /// __readonly__ renewed with each render.
+    pub spin_up: Rect,
+    /// Area of the decrement button.
+    /// __readonly__ renewed with each render.
+    pub spin_down: Rect,
+    /// Spin button currently held down.
+    /// Use [spin_repeat](NumberInputState::spin_repeat) to
+    /// auto-repeat while it is pressed.
+    /// __read+write__
+    pub spin_pressed: Option<Spin>,
+
+    /// Step for spin buttons and mouse-wheel.
+    step: f64,
+    /// Lower bound.
+    min: Option<f64>,
+    /// Upper bound.
+    max: Option<f64>,
+    /// Wrap around at the bounds.
+    wrap: bool,
+
     pub non_exhaustive: NonExhaustive,
 }
 
@@ -64,9 +94,22 @@ impl<'a> NumberInput<'a> {
         self
     }
 
+    /// Show spin buttons at the right edge.
+    ///
+    /// This enables the mouse-wheel too.
+    #[inline]
+    pub fn spin(mut self, spin: bool) -> Self {
+        self.spin = spin;
+        self
+    }
+
     /// Set the combined style.
     #[inline]
     pub fn styles(mut self, style: TextStyle) -> Self {
+        self.style = style.style;
+        if style.spin.is_some() {
+            self.spin_style = style.spin;
+        }
         self.widget = self.widget.styles(style);
         self
     }
@@ -74,6 +117,8 @@ impl<'a> NumberInput<'a> {
     /// Base text style.
     #[inline]
     pub fn style(mut self, style: impl Into<Style>) -> Self {
+        let style = style.into();
+        self.style = style;
         self.widget = self.widget.style(style);
         self
     }
@@ -99,6 +144,13 @@ impl<'a> NumberInput<'a> {
         self
     }
 
+    /// Style for the spin buttons.
+    #[inline]
+    pub fn spin_style(mut self, style: impl Into<Style>) -> Self {
+        self.spin_style = Some(style.into());
+        self
+    }
+
     #[inline]
     pub fn block(mut self, block: Block<'a>) -> Self {
         self.widget = self.widget.block(block);
@@ -125,18 +177,45 @@ impl<'a> StatefulWidgetRef for NumberInput<'a> {
     type State = NumberInputState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        self.widget.render_ref(area, buf, &mut state.widget);
+        self.widget
+            .clone()
+            .spin(self.spin)
+            .render(area, buf, &mut state.widget);
+        render_spin_buttons(self, buf, state);
     }
 }
 
 impl StatefulWidget for NumberInput<'_> {
     type State = NumberInputState;
 
-    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        self.widget.render(area, buf, &mut state.widget);
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        mem::take(&mut self.widget)
+            .spin(self.spin)
+            .render(area, buf, &mut state.widget);
+        render_spin_buttons(&self, buf, state);
     }
 }
 
+fn render_spin_buttons(widget: &NumberInput<'_>, buf: &mut Buffer, state: &mut NumberInputState) {
+    (state.spin_up, state.spin_down) = layout_spin(state.widget.spin_area);
+    if !widget.spin {
+        state.spin_pressed = None;
+        return;
+    }
+    let style = if let Some(spin_style) = widget.spin_style {
+        widget.style.patch(spin_style)
+    } else {
+        widget.style
+    };
+    render_spin(
+        state.spin_up,
+        state.spin_down,
+        style,
+        state.spin_pressed,
+        buf,
+    );
+}
+
 impl Default for NumberInputState {
     fn default() -> Self {
         let mut s = Self {
@@ -144,6 +223,13 @@ impl Default for NumberInputState {
             pattern: "".to_string(),
             locale: Default::default(),
             format: Default::default(),
+            spin_up: Default::default(),
+            spin_down: Default::default(),
+            spin_pressed: None,
+            step: 1.0,
+            min: None,
+            max: None,
+            wrap: false,
             non_exhaustive: NonExhaustive,
         };
         _ = s.set_format("#####");
@@ -256,6 +342,14 @@ impl NumberInputState {
         self.widget.invalid
     }
 
+    /// Renders the widget in invalid style, but only the part
+    /// that caused the error. None resets the invalid state.
+    #[inline]
+    pub fn set_value_error(&mut self, err: Option<&ValueError>) {
+        self.widget.invalid = err.is_some();
+        self.widget.invalid_range = err.map(|v| v.range.clone());
+    }
+
     /// The next edit operation will overwrite the current content
     /// instead of adding text. Any move operations will cancel
     /// this overwrite.
@@ -269,6 +363,47 @@ impl NumberInputState {
     pub fn overwrite(&self) -> bool {
         self.widget.overwrite
     }
+
+    /// Step for the spin buttons and the mouse-wheel.
+    /// Default is 1.
+    #[inline]
+    pub fn set_step(&mut self, step: f64) {
+        self.step = step;
+    }
+
+    /// Step for the spin buttons and the mouse-wheel.
+    #[inline]
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Bounds for the spin buttons and the mouse-wheel.
+    ///
+    /// The bounds are not enforced for text input.
+    #[inline]
+    pub fn set_range(&mut self, min: Option<f64>, max: Option<f64>) {
+        self.min = min;
+        self.max = max;
+    }
+
+    /// Bounds for the spin buttons and the mouse-wheel.
+    #[inline]
+    pub fn range(&self) -> (Option<f64>, Option<f64>) {
+        (self.min, self.max)
+    }
+
+    /// Wrap around at the bounds instead of clamping.
+    /// This needs both bounds.
+    #[inline]
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Wrap around at the bounds instead of clamping.
+    #[inline]
+    pub fn wrap(&self) -> bool {
+        self.wrap
+    }
 }
 
 impl NumberInputState {
@@ -492,19 +627,56 @@ impl NumberInputState {
 
     /// Parses the text as the desired value type.
     /// If the text content is empty returns None.
-    pub fn value_opt<T: FromStr>(&self) -> Result<Option<T>, NumberFmtError> {
+    pub fn value_opt<T: FromStr>(&self) -> Result<Option<T>, ValueError> {
         let s = self.widget.text();
         if s.trim().is_empty() {
             Ok(None)
         } else {
-            self.format.parse(s).map(|v| Some(v))
+            self.format
+                .parse(s)
+                .map(|v| Some(v))
+                .map_err(|e| self.value_error(e))
         }
     }
 
     /// Parses the text as the desired value type.
-    pub fn value<T: FromStr>(&self) -> Result<T, NumberFmtError> {
+    ///
+    /// The error contains the section of the text that is wrong.
+    pub fn value<T: FromStr>(&self) -> Result<T, ValueError> {
         let s = self.widget.text();
-        self.format.parse(s)
+        self.format.parse(s).map_err(|e| self.value_error(e))
+    }
+
+    /// Find the section responsible for the error.
+    fn value_error(&self, err: NumberFmtError) -> ValueError {
+        let first_digit = self
+            .widget
+            .text()
+            .graphemes(true)
+            .position(|v| v.chars().all(|c| c.is_ascii_digit()));
+
+        match (first_digit, err) {
+            (None, _) => {
+                // no digits at all. mark the integer part.
+                let range = self
+                    .widget
+                    .value
+                    .section_range(0)
+                    .or_else(|| self.widget.value.next_section_range(0))
+                    .unwrap_or(0..self.len());
+                ValueError::new(ValueErrorKind::IncompleteSection, range)
+            }
+            (Some(pos), NumberFmtError::Parse) => {
+                // all chars are fine, but the value doesn't fit the type.
+                let range = self
+                    .widget
+                    .value
+                    .section_range(pos as upos_type)
+                    .unwrap_or(0..self.len());
+                ValueError::new(ValueErrorKind::OutOfRange { field: "number" }, range)
+            }
+            (Some(_), _) => ValueError::new(ValueErrorKind::Invalid, 0..self.len()),
+        }
     }
 
     /// Length in grapheme count.
@@ -537,6 +709,60 @@ impl NumberInputState {
         Ok(())
     }
 
+    /// Increment/decrement the value by one step.
+    ///
+    /// An empty input counts as 0. Returns false if the value
+    /// can't be parsed or doesn't change due to the bounds.
+    pub fn spin(&mut self, spin: Spin) -> bool {
+        let value = match self.value_opt::<f64>() {
+            Ok(Some(v)) => Some(v),
+            Ok(None) => None,
+            Err(_) => return false,
+        };
+        let base = value.unwrap_or_default();
+        let mut new_value = match spin {
+            Spin::Up => base + self.step,
+            Spin::Down => base - self.step,
+        };
+        match (self.min, self.max) {
+            (Some(min), Some(max)) if self.wrap => {
+                if new_value > max {
+                    new_value = min;
+                } else if new_value < min {
+                    new_value = max;
+                }
+            }
+            (min, max) => {
+                if let Some(max) = max {
+                    new_value = new_value.min(max);
+                }
+                if let Some(min) = min {
+                    new_value = new_value.max(min);
+                }
+            }
+        }
+        if value == Some(new_value) {
+            return false;
+        }
+        self.set_value(new_value).is_ok()
+    }
+
+    /// Repeat the step of the currently pressed spin button.
+    ///
+    /// Call this from a timer while [spin_pressed](NumberInputState::spin_pressed)
+    /// is set to get auto-repeat.
+    pub fn spin_repeat(&mut self) -> TextOutcome {
+        if let Some(spin) = self.spin_pressed {
+            if self.spin(spin) {
+                TextOutcome::TextChanged
+            } else {
+                TextOutcome::Unchanged
+            }
+        } else {
+            TextOutcome::Continue
+        }
+    }
+
     /// Insert a char at the current position.
     #[inline]
     pub fn insert_char(&mut self, c: char) -> bool {
@@ -607,6 +833,8 @@ impl HasScreenCursor for NumberInputState {
 impl RelocatableState for NumberInputState {
     fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
         self.widget.relocate(shift, clip);
+        self.spin_up = relocate_area(self.spin_up, shift, clip);
+        self.spin_down = relocate_area(self.spin_down, shift, clip);
     }
 }
 
@@ -634,9 +862,34 @@ impl NumberInputState {
     }
 }
 
+impl NumberInputState {
+    fn handle_spin(&mut self, event: &crossterm::event::Event) -> TextOutcome {
+        match spin_event(
+            event,
+            self.widget.area,
+            self.spin_up,
+            self.spin_down,
+            &mut self.spin_pressed,
+        ) {
+            SpinEvent::Continue => TextOutcome::Continue,
+            SpinEvent::Released => TextOutcome::Changed,
+            SpinEvent::Step(spin, _) => {
+                if self.spin(spin) {
+                    TextOutcome::TextChanged
+                } else {
+                    TextOutcome::Unchanged
+                }
+            }
+        }
+    }
+}
+
 impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for NumberInputState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> TextOutcome {
-        self.widget.handle(event, Regular)
+        match self.handle_spin(event) {
+            TextOutcome::Continue => self.widget.handle(event, Regular),
+            r => r,
+        }
     }
 }
 
@@ -648,7 +901,10 @@ impl HandleEvent<crossterm::event::Event, ReadOnly, TextOutcome> for NumberInput
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, TextOutcome> for NumberInputState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> TextOutcome {
-        self.widget.handle(event, MouseOnly)
+        match self.handle_spin(event) {
+            TextOutcome::Continue => self.widget.handle(event, MouseOnly),
+            r => r,
+        }
     }
 }
 
@@ -0,0 +1,110 @@
+//!
+//! Spin buttons for number and date inputs.
+//!
+
+use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+
+/// Direction of a spin step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spin {
+    /// Increment
+    Up,
+    /// Decrement
+    Down,
+}
+
+/// Result of the spin event-handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpinEvent {
+    /// Not a spin event.
+    Continue,
+    /// Spin button released.
+    Released,
+    /// Do one step. The modifiers are those of the mouse-event.
+    Step(Spin, KeyModifiers),
+}
+
+/// Splits the last two columns of the first line for the spin buttons.
+/// Returns (up, down).
+pub(crate) fn layout_spin(spin_area: Rect) -> (Rect, Rect) {
+    if spin_area.is_empty() {
+        (Rect::default(), Rect::default())
+    } else {
+        (
+            Rect::new(spin_area.x, spin_area.y, 1, 1),
+            Rect::new(spin_area.x + 1, spin_area.y, 1, 1),
+        )
+    }
+}
+
+/// Render the spin buttons.
+pub(crate) fn render_spin(
+    up: Rect,
+    down: Rect,
+    style: Style,
+    pressed: Option<Spin>,
+    buf: &mut Buffer,
+) {
+    for (area, spin, symbol) in [(up, Spin::Up, "▲"), (down, Spin::Down, "▼")] {
+        if area.is_empty() {
+            continue;
+        }
+        if let Some(cell) = buf.cell_mut(area.as_position()) {
+            cell.reset();
+            cell.set_symbol(symbol);
+            if pressed == Some(spin) {
+                cell.set_style(style.add_modifier(Modifier::REVERSED));
+            } else {
+                cell.set_style(style);
+            }
+        }
+    }
+}
+
+/// Mouse handling for the spin buttons and the mouse-wheel.
+///
+/// Only active if there are spin buttons.
+pub(crate) fn spin_event(
+    event: &crossterm::event::Event,
+    area: Rect,
+    up: Rect,
+    down: Rect,
+    pressed: &mut Option<Spin>,
+) -> SpinEvent {
+    if up.is_empty() || down.is_empty() {
+        return SpinEvent::Continue;
+    }
+
+    let crossterm::event::Event::Mouse(m) = event else {
+        return SpinEvent::Continue;
+    };
+    let pos = (m.column, m.row).into();
+    match m.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if up.contains(pos) {
+                *pressed = Some(Spin::Up);
+                SpinEvent::Step(Spin::Up, m.modifiers)
+            } else if down.contains(pos) {
+                *pressed = Some(Spin::Down);
+                SpinEvent::Step(Spin::Down, m.modifiers)
+            } else {
+                SpinEvent::Continue
+            }
+        }
+        MouseEventKind::Up(MouseButton::Left) => {
+            if pressed.take().is_some() {
+                SpinEvent::Released
+            } else {
+                SpinEvent::Continue
+            }
+        }
+        MouseEventKind::ScrollUp if area.contains(pos) => SpinEvent::Step(Spin::Up, m.modifiers),
+        MouseEventKind::ScrollDown if area.contains(pos) => {
+            SpinEvent::Step(Spin::Down, m.modifiers)
+        }
+        _ => SpinEvent::Continue,
+    }
+}
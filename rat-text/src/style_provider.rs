@@ -0,0 +1,206 @@
+//!
+//! Lazy styling of the text.
+//!
+//! A [StyleProvider] is asked for the styles of the visible lines
+//! during rendering. The results are cached per line and only
+//! the lines touched by an edit are requested again.
+//!
+
+use crate::core::TextRope;
+use crate::text_store::TextStore;
+use crate::{upos_type, TextPosition, TextRange};
+use dyn_clone::DynClone;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Provides the styles for a range of lines.
+///
+/// This is the integration point for syntax highlighting.
+pub trait StyleProvider: DynClone + Debug {
+    /// Styles for the given lines.
+    ///
+    /// Returns text-ranges and the index of the style in
+    /// TextArea::text_style. Ranges may extend beyond the
+    /// requested lines, everything outside is ignored.
+    fn styles_for(&self, lines: Range<upos_type>, text: &TextRope) -> Vec<(TextRange, usize)>;
+}
+
+/// Default number of cached lines.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Per line cache for the styles of a [StyleProvider].
+///
+/// The ranges are stored as columns relative to the line,
+/// so inserting or removing lines only shifts the keys.
+#[derive(Debug, Clone)]
+pub struct LineStyleCache {
+    lines: HashMap<upos_type, Vec<(Range<upos_type>, usize)>>,
+    capacity: usize,
+}
+
+impl Default for LineStyleCache {
+    fn default() -> Self {
+        Self {
+            lines: Default::default(),
+            capacity: DEFAULT_CAPACITY,
+        }
+    }
+}
+
+impl LineStyleCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache with a maximum number of lines.
+    ///
+    /// This is a soft limit, the lines that are currently
+    /// requested are always kept.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            lines: Default::default(),
+            capacity,
+        }
+    }
+
+    /// Maximum number of cached lines.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached lines.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Empty cache.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Is this line cached.
+    pub fn contains(&self, row: upos_type) -> bool {
+        self.lines.contains_key(&row)
+    }
+
+    /// Clear the cache.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Invalidate the lines touched by a change.
+    ///
+    /// The range is the replaced range as it was before the change,
+    /// txt is the replacement. All following lines are shifted
+    /// by the number of added/removed lines.
+    pub fn invalidate(&mut self, range: TextRange, txt: &str) {
+        if self.lines.is_empty() {
+            return;
+        }
+
+        let removed = (range.end.y - range.start.y) as i64;
+        let inserted = txt.split('\n').count() as i64 - 1;
+        let delta = inserted - removed;
+
+        let old = std::mem::take(&mut self.lines);
+        for (row, styles) in old {
+            if row < range.start.y {
+                self.lines.insert(row, styles);
+            } else if row > range.end.y {
+                self.lines.insert((row as i64 + delta) as upos_type, styles);
+            }
+        }
+    }
+
+    /// Query the styles for the given position.
+    pub fn styles_at(&self, pos: TextPosition, buf: &mut Vec<usize>) {
+        if let Some(styles) = self.lines.get(&pos.y) {
+            for (r, style) in styles {
+                if r.contains(&pos.x) {
+                    buf.push(*style);
+                }
+            }
+        }
+    }
+
+    /// Fill in all missing lines of the given range.
+    ///
+    /// The provider is called once for each continuous run
+    /// of missing lines.
+    pub fn fill<F>(&mut self, rows: Range<upos_type>, mut provider: F)
+    where
+        F: FnMut(Range<upos_type>) -> Vec<(TextRange, usize)>,
+    {
+        let mut row = rows.start;
+        while row < rows.end {
+            if self.lines.contains_key(&row) {
+                row += 1;
+                continue;
+            }
+            let start = row;
+            while row < rows.end && !self.lines.contains_key(&row) {
+                row += 1;
+            }
+            let missing = start..row;
+
+            for y in missing.clone() {
+                self.lines.insert(y, Vec::new());
+            }
+            for (range, style) in provider(missing.clone()) {
+                let first = range.start.y.max(missing.start);
+                let last = range.end.y.min(missing.end.saturating_sub(1));
+                for y in first..=last {
+                    let sx = if y == range.start.y { range.start.x } else { 0 };
+                    let ex = if y == range.end.y {
+                        range.end.x
+                    } else {
+                        upos_type::MAX
+                    };
+                    if sx < ex {
+                        self.lines.get_mut(&y).expect("line").push((sx..ex, style));
+                    }
+                }
+            }
+        }
+
+        if self.lines.len() > self.capacity {
+            self.lines.retain(|y, _| rows.contains(y));
+        }
+    }
+}
+
+/// Demo provider that marks TODO and FIXME.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TodoStyleProvider {
+    /// Index into TextArea::text_style
+    pub style: usize,
+}
+
+impl TodoStyleProvider {
+    pub fn new(style: usize) -> Self {
+        Self { style }
+    }
+}
+
+impl StyleProvider for TodoStyleProvider {
+    fn styles_for(&self, lines: Range<upos_type>, text: &TextRope) -> Vec<(TextRange, usize)> {
+        let mut styles = Vec::new();
+        for y in lines {
+            let Ok(line) = text.line_at(y) else {
+                break;
+            };
+            for (idx, (byte, g)) in line.grapheme_indices(true).enumerate() {
+                for marker in ["TODO", "FIXME"] {
+                    if g == &marker[..1] && line[byte..].starts_with(marker) {
+                        let x = idx as upos_type;
+                        let len = marker.len() as upos_type;
+                        styles.push((TextRange::new((x, y), (x + len, y)), self.style));
+                    }
+                }
+            }
+        }
+        styles
+    }
+}
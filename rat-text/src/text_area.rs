@@ -7,16 +7,19 @@ use crate::_private::NonExhaustive;
 use crate::clipboard::{global_clipboard, Clipboard};
 use crate::event::{ReadOnly, TextOutcome};
 use crate::grapheme::{Glyph, Grapheme};
+use crate::style_provider::{LineStyleCache, StyleProvider};
 use crate::text_core::TextCore;
 use crate::text_store::text_rope::TextRope;
 use crate::text_store::TextStore;
 use crate::undo_buffer::{UndoBuffer, UndoEntry, UndoVec};
 use crate::{
-    ipos_type, upos_type, Cursor, HasScreenCursor, TextError, TextPosition, TextRange, TextStyle,
+    ipos_type, upos_type, Cursor, HasScreenCursor, TextEdit, TextError, TextPosition, TextRange,
+    TextStyle, WidthPolicy,
 };
 use crossterm::event::KeyModifiers;
+use dyn_clone::clone_box;
 use rat_event::util::MouseFlags;
-use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Regular};
+use rat_event::{ct_event, feedback, flow, HandleEvent, MouseOnly, Regular, Severity};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_reloc::{relocate_area, relocate_dark_offset, RelocatableState};
 use rat_scrolled::event::ScrollOutcome;
@@ -29,8 +32,10 @@ use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{Block, StatefulWidget};
 use ropey::Rope;
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::cmp::{max, min};
 use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Text area widget.
 ///
@@ -52,6 +57,11 @@ use std::ops::Range;
 /// Scrolling doesn't depend on the cursor, but the editing and move
 /// functions take care that the cursor stays visible.
 ///
+/// For displaying logs, diffs and the like use a
+/// [read-only](TextAreaState::set_read_only) TextArea. It keeps
+/// selection, copy and scrolling, but rejects all edits from
+/// the keyboard.
+///
 /// Wordwrap is not available. For display only use
 /// [Paragraph](https://docs.rs/ratatui/latest/ratatui/widgets/struct.Paragraph.html), as
 /// for editing: why?
@@ -67,6 +77,10 @@ use std::ops::Range;
 /// and [try_col_to_screen](TextAreaState::try_col_to_screen). They calculate everything,
 /// even in the presence of more complex graphemes and those double-width emojis.
 ///
+/// Alt+mouse-drag or Alt+B switch to a
+/// [block selection](SelectionKind::Block), a rectangle between
+/// anchor and cursor. Copy, cut, delete and paste work blockwise.
+///
 /// # Stateful
 /// This widget implements [`StatefulWidget`], you can use it with
 /// [`TextAreaState`] to handle common actions.
@@ -81,7 +95,10 @@ pub struct TextArea<'a> {
     style: Style,
     focus_style: Option<Style>,
     select_style: Option<Style>,
+    cursor_style: Option<Style>,
     text_style: Vec<Style>,
+
+    read_only: Option<bool>,
 }
 
 /// State & event handling.
@@ -103,9 +120,19 @@ pub struct TextAreaState {
     /// Dark offset due to clipping.
     /// __read only__ secondary offset due to clipping.
     pub dark_offset: (u16, u16),
+    /// Keep the view at the end of the text while it grows.
+    /// __read+write__
+    pub follow_tail: bool,
+    /// Follow-tail is engaged. Scrolling up disengages,
+    /// scrolling back to the end engages again.
+    /// __read only__
+    pub following: bool,
 
     /// Text edit core
     pub value: TextCore<TextRope>,
+    /// Lazy styles for the visible lines.
+    /// The results are cached in value.
+    pub style_provider: Option<Box<dyn StyleProvider>>,
 
     /// movement column
     pub move_col: Option<upos_type>,
@@ -113,6 +140,20 @@ pub struct TextAreaState {
     pub auto_indent: bool,
     /// quote selection active
     pub auto_quote: bool,
+    /// Read-only view. Navigation, selection and copy work,
+    /// but any edit from the keyboard is rejected.
+    /// __read+write__
+    pub read_only: bool,
+    /// How anchor and cursor define the selection.
+    /// __read+write__
+    pub selection_kind: SelectionKind,
+    /// Use the primary selection of the clipboard.
+    /// A finished selection is copied to the primary selection,
+    /// and a middle click pastes it.
+    /// __read+write__
+    pub primary_selection: bool,
+    /// Last selection copied to the primary selection.
+    primary_range: Option<TextRange>,
 
     /// Current focus state.
     pub focus: FocusFlag,
@@ -124,6 +165,26 @@ pub struct TextAreaState {
     pub non_exhaustive: NonExhaustive,
 }
 
+/// Kind of selection.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionKind {
+    /// Everything between anchor and cursor.
+    #[default]
+    Stream,
+    /// The rectangle between anchor and cursor.
+    ///
+    /// The columns are display columns, so the block lines up
+    /// visually over tabs and wide graphemes. A grapheme belongs
+    /// to the block if it starts inside the columns of the block.
+    Block,
+}
+
+thread_local! {
+    /// Text of the last block copied to the clipboard.
+    /// Paste of the same text inserts it blockwise.
+    static BLOCK_CLIP: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
 impl Clone for TextAreaState {
     fn clone(&self) -> Self {
         Self {
@@ -131,14 +192,21 @@ impl Clone for TextAreaState {
             area: self.area,
             inner: self.inner,
             value: self.value.clone(),
+            style_provider: self.style_provider.as_ref().map(|v| clone_box(v.as_ref())),
             hscroll: self.hscroll.clone(),
             vscroll: self.vscroll.clone(),
             move_col: None,
             auto_indent: self.auto_indent,
             auto_quote: self.auto_quote,
+            read_only: self.read_only,
+            selection_kind: self.selection_kind,
+            primary_selection: self.primary_selection,
+            primary_range: None,
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
             dark_offset: (0, 0),
+            follow_tail: self.follow_tail,
+            following: self.following,
         }
     }
 }
@@ -202,6 +270,22 @@ impl<'a> TextArea<'a> {
         self
     }
 
+    /// Style for the cursor in read-only mode.
+    ///
+    /// A read-only TextArea doesn't show the terminal cursor,
+    /// instead the cell at the cursor is patched with this style.
+    /// Defaults to underlined. Use `Style::new()` to hide the cursor.
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = Some(style);
+        self
+    }
+
+    /// Sets [TextAreaState::read_only] when rendering.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
     /// List of text-styles.
     ///
     /// Use [TextAreaState::add_style()] to refer a text range to
@@ -283,16 +367,26 @@ fn render_text_area(
     state: &mut TextAreaState,
 ) {
     state.area = area;
+    if let Some(read_only) = widget.read_only {
+        state.read_only = read_only;
+    }
 
     let sa = ScrollArea::new()
         .block(widget.block.as_ref())
         .h_scroll(widget.hscroll.as_ref())
         .v_scroll(widget.vscroll.as_ref());
-    state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
-
     if let Some(h_max_offset) = widget.h_max_offset {
         state.hscroll.set_max_offset(h_max_offset);
     }
+    sa.measure(
+        area,
+        None,
+        Some(state.len_lines() as usize),
+        Some(&mut state.hscroll),
+        Some(&mut state.vscroll),
+    );
+    state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
+
     if let Some(h_overscroll) = widget.h_overscroll {
         state.hscroll.set_overscroll_by(Some(h_overscroll));
     }
@@ -303,6 +397,9 @@ fn render_text_area(
             .saturating_sub(state.inner.height as upos_type) as usize,
     );
     state.vscroll.set_page_len(state.inner.height as usize);
+    if state.is_following() {
+        state.vscroll.set_offset(state.vscroll.max_offset());
+    }
 
     let inner = state.inner;
 
@@ -350,8 +447,21 @@ fn render_text_area(
         .try_bytes_at_range(TextRange::new((0, page_rows.start), (0, page_rows.end)))
         .expect("valid_rows");
     let selection = state.selection();
+    let block_selection = if state.is_block_selection() {
+        Some(state.block_ranges())
+    } else {
+        None
+    };
     let mut styles = Vec::new();
 
+    if let Some(provider) = &state.style_provider {
+        state
+            .value
+            .fill_line_styles(page_rows.clone(), |rows, text| {
+                provider.styles_for(rows, text)
+            });
+    }
+
     let glyph_iter = state
         .value
         .glyphs(page_rows.clone(), ox as u16, inner.width)
@@ -365,13 +475,21 @@ fn render_text_area(
             state
                 .value
                 .styles_at_page(page_bytes.clone(), g.text_bytes().start, &mut styles);
+            state.value.line_styles_at(g.pos(), &mut styles);
             for style_nr in &styles {
                 if let Some(s) = widget.text_style.get(*style_nr) {
                     style = style.patch(*s);
                 }
             }
             // selection
-            if selection.contains_pos(g.pos()) {
+            let selected = if let Some(block_selection) = &block_selection {
+                block_selection.iter().any(|v| {
+                    v.start.y == g.pos().y && v.start.x <= g.pos().x && g.pos().x < v.end.x
+                })
+            } else {
+                selection.contains_pos(g.pos())
+            };
+            if selected {
                 style = style.patch(select_style);
             };
 
@@ -394,6 +512,20 @@ fn render_text_area(
             }
         }
     }
+
+    // no terminal cursor when read-only.
+    if state.read_only && state.is_focused() {
+        let cursor_style = if let Some(cursor_style) = widget.cursor_style {
+            cursor_style
+        } else {
+            Style::new().underlined()
+        };
+        if let Some(pos) = state.cursor_screen_pos() {
+            if let Some(cell) = buf.cell_mut(pos) {
+                cell.set_style(cell.style().patch(cursor_style));
+            }
+        }
+    }
 }
 
 impl Default for TextAreaState {
@@ -404,13 +536,20 @@ impl Default for TextAreaState {
             inner: Default::default(),
             mouse: Default::default(),
             value: TextCore::new(Some(Box::new(UndoVec::new(99))), Some(global_clipboard())),
+            style_provider: None,
             hscroll: Default::default(),
             non_exhaustive: NonExhaustive,
             vscroll: Default::default(),
             move_col: None,
             auto_indent: true,
             auto_quote: true,
+            read_only: false,
+            selection_kind: SelectionKind::Stream,
+            primary_selection: false,
+            primary_range: None,
             dark_offset: (0, 0),
+            follow_tail: false,
+            following: true,
         };
         s.hscroll.set_max_offset(255);
         s.hscroll.set_overscroll_by(Some(16384));
@@ -474,6 +613,23 @@ impl TextAreaState {
         self.auto_indent = indent;
     }
 
+    /// Read-only view. Navigation, selection and copy work,
+    /// but any edit from the keyboard is rejected with
+    /// [TextOutcome::Unchanged].
+    ///
+    /// Changing the text via the api still works, so
+    /// the application can append to a log.
+    #[inline]
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Read-only view.
+    #[inline]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
     /// Activates 'add quotes to selection'.
     #[inline]
     pub fn set_auto_quote(&mut self, quote: bool) {
@@ -492,6 +648,19 @@ impl TextAreaState {
         self.value.tab_width()
     }
 
+    /// Set the policy for the display width of graphemes.
+    /// This includes the tab-width.
+    #[inline]
+    pub fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        self.value.set_width_policy(width_policy);
+    }
+
+    /// Policy for the display width of graphemes.
+    #[inline]
+    pub fn width_policy(&self) -> WidthPolicy {
+        self.value.width_policy()
+    }
+
     /// Expand tabs to spaces. Only for new inputs.
     #[inline]
     pub fn set_expand_tabs(&mut self, expand: bool) {
@@ -558,7 +727,11 @@ impl TextAreaState {
             return false;
         };
 
-        _ = clip.set_string(self.selected_text().as_ref());
+        let text = self.selected_text();
+        match clip.set_string(text.as_ref()) {
+            Ok(_) => self.remember_block_clip(text.as_ref()),
+            Err(_) => feedback(Severity::Warning, "Clipboard not available."),
+        }
         false
     }
 
@@ -569,13 +742,27 @@ impl TextAreaState {
             return false;
         };
 
-        match clip.set_string(self.selected_text().as_ref()) {
-            Ok(_) => self.delete_range(self.selection()),
-            Err(_) => false,
+        let text = self.selected_text();
+        match clip.set_string(text.as_ref()) {
+            Ok(_) => {
+                self.remember_block_clip(text.as_ref());
+                if self.is_block_selection() {
+                    self.delete_block_selection()
+                } else {
+                    self.delete_range(self.selection())
+                }
+            }
+            Err(_) => {
+                feedback(Severity::Warning, "Clipboard not available.");
+                false
+            }
         }
     }
 
     /// Paste from internal buffer.
+    ///
+    /// Text that has been copied from a block selection is
+    /// inserted blockwise.
     #[inline]
     pub fn paste_from_clip(&mut self) -> bool {
         let Some(clip) = self.value.clipboard() else {
@@ -583,11 +770,104 @@ impl TextAreaState {
         };
 
         if let Ok(text) = clip.get_string() {
-            self.insert_str(text)
+            let is_block = BLOCK_CLIP.with_borrow(|v| v.as_deref() == Some(text.as_str()));
+            if is_block {
+                self.insert_block(text)
+            } else {
+                self.insert_str(text)
+            }
         } else {
+            feedback(Severity::Warning, "Clipboard not available.");
             false
         }
     }
+
+    /// Paste the primary selection at the cursor.
+    #[inline]
+    pub fn paste_from_primary(&mut self) -> bool {
+        let Some(clip) = self.value.clipboard() else {
+            return false;
+        };
+
+        match clip.get_primary() {
+            Ok(text) if !text.is_empty() => self.insert_str(text),
+            Ok(_) => false,
+            Err(_) => {
+                feedback(Severity::Warning, "Clipboard not available.");
+                false
+            }
+        }
+    }
+
+    /// Copy a changed selection to the primary selection.
+    fn update_primary(&mut self) {
+        if !self.has_selection() {
+            self.primary_range = None;
+            return;
+        }
+        let selection = self.selection();
+        if self.primary_range == Some(selection) {
+            return;
+        }
+        let Some(clip) = self.value.clipboard() else {
+            return;
+        };
+        if clip.set_primary(self.selected_text().as_ref()).is_ok() {
+            self.primary_range = Some(selection);
+        }
+    }
+
+    fn remember_block_clip(&self, text: &str) {
+        let block = if self.is_block_selection() {
+            Some(text.to_string())
+        } else {
+            None
+        };
+        BLOCK_CLIP.set(block);
+    }
+}
+
+impl TextAreaState {
+    /// Set a callback that is notified of every change to the text.
+    ///
+    /// Gets the changed range, as it was before the change, and
+    /// the text that replaces it. This includes undo/redo.
+    #[inline]
+    pub fn set_change_hook(&mut self, hook: Option<impl FnMut(TextRange, &str) + 'static>) {
+        match hook {
+            None => self.value.set_change_hook(None),
+            Some(v) => self.value.set_change_hook(Some(Box::new(v))),
+        }
+    }
+
+    /// Set a style provider.
+    ///
+    /// The provider is called during rendering for the visible lines.
+    /// Its results are cached per line, and the cache is invalidated
+    /// for each line touched by an edit.
+    pub fn set_style_provider(&mut self, provider: Option<impl StyleProvider + 'static>) {
+        match provider {
+            None => {
+                self.style_provider = None;
+                self.value.set_line_style_cache(None);
+            }
+            Some(v) => {
+                self.style_provider = Some(Box::new(v));
+                self.value.set_line_style_cache(Some(LineStyleCache::new()));
+            }
+        }
+    }
+
+    /// Style provider.
+    pub fn style_provider(&self) -> Option<&dyn StyleProvider> {
+        self.style_provider.as_deref()
+    }
+
+    /// Throw away the cached styles of the style provider.
+    /// Use this if the provider changes its mind.
+    pub fn clear_provided_styles(&mut self) {
+        self.value.clear_line_styles();
+    }
 }
 
 impl TextAreaState {
@@ -647,6 +927,24 @@ impl TextAreaState {
     pub fn redo(&mut self) -> bool {
         self.value.redo()
     }
+
+    /// Undo until the undo position is `pos`.
+    #[inline]
+    pub fn undo_to(&mut self, pos: usize) -> bool {
+        self.value.undo_to(pos)
+    }
+
+    /// Redo until the undo position is `pos`.
+    #[inline]
+    pub fn redo_to(&mut self, pos: usize) -> bool {
+        self.value.redo_to(pos)
+    }
+
+    /// Restore a redo branch that was dropped by a later change.
+    #[inline]
+    pub fn restore_redo_branch(&mut self, n: usize) -> bool {
+        self.value.restore_redo_branch(n)
+    }
 }
 
 impl TextAreaState {
@@ -735,6 +1033,7 @@ impl TextAreaState {
     pub fn set_offset(&mut self, offset: (usize, usize)) -> bool {
         let c = self.hscroll.set_offset(offset.0);
         let r = self.vscroll.set_offset(offset.1);
+        self.update_following();
         r || c
     }
 
@@ -783,15 +1082,113 @@ impl TextAreaState {
     /// Select all.
     #[inline]
     pub fn select_all(&mut self) -> bool {
+        self.selection_kind = SelectionKind::Stream;
         self.value.select_all()
     }
 
     /// Selection.
+    ///
+    /// A block selection is returned line by line, joined
+    /// with newlines.
     #[inline]
     pub fn selected_text(&self) -> Cow<'_, str> {
-        self.value
-            .str_slice(self.value.selection())
-            .expect("valid_selection")
+        if self.is_block_selection() {
+            let mut text = String::new();
+            for (n, range) in self.block_ranges().into_iter().enumerate() {
+                if n > 0 {
+                    text.push('\n');
+                }
+                text.push_str(self.str_slice(range).as_ref());
+            }
+            Cow::Owned(text)
+        } else {
+            self.value
+                .str_slice(self.value.selection())
+                .expect("valid_selection")
+        }
+    }
+
+    /// Kind of selection.
+    #[inline]
+    pub fn selection_kind(&self) -> SelectionKind {
+        self.selection_kind
+    }
+
+    /// Kind of selection.
+    ///
+    /// This keeps anchor and cursor, only the selected text changes.
+    #[inline]
+    pub fn set_selection_kind(&mut self, kind: SelectionKind) -> bool {
+        let old_kind = self.selection_kind;
+        self.selection_kind = kind;
+        old_kind != kind
+    }
+
+    /// Switch between stream and block selection.
+    pub fn toggle_block_selection(&mut self) -> bool {
+        match self.selection_kind {
+            SelectionKind::Stream => self.set_selection_kind(SelectionKind::Block),
+            SelectionKind::Block => self.set_selection_kind(SelectionKind::Stream),
+        }
+    }
+
+    /// There is a block selection.
+    #[inline]
+    pub fn is_block_selection(&self) -> bool {
+        self.selection_kind == SelectionKind::Block && self.has_selection()
+    }
+
+    /// Display column of the position.
+    ///
+    /// Tabs and wide graphemes count with their width on the screen.
+    pub fn display_col(&self, pos: impl Into<TextPosition>) -> upos_type {
+        let pos = pos.into();
+        let mut col = 0;
+        for g in self.glyphs(pos.y..pos.y + 1, 0, u16::MAX) {
+            if g.pos().x >= pos.x {
+                return g.screen_pos().0 as upos_type;
+            }
+            col = (g.screen_pos().0 + g.screen_width()) as upos_type;
+        }
+        col
+    }
+
+    /// First grapheme in the row that starts at or after the
+    /// display column.
+    ///
+    /// Returns the grapheme index and its display column. If the
+    /// line is shorter, this is the end of the line and its width.
+    fn display_col_to_col(&self, row: upos_type, display_col: upos_type) -> (upos_type, upos_type) {
+        let mut end = (0, 0);
+        for g in self.glyphs(row..row + 1, 0, u16::MAX) {
+            let start = g.screen_pos().0 as upos_type;
+            if start >= display_col {
+                return (g.pos().x, start);
+            }
+            end = (g.pos().x + 1, start + g.screen_width() as upos_type);
+        }
+        // the line break is not part of the line.
+        (min(end.0, self.line_width(row)), end.1)
+    }
+
+    /// Ranges of the block selection, one per row.
+    ///
+    /// Rows shorter than the block give an empty range at the
+    /// end of the row.
+    pub fn block_ranges(&self) -> Vec<TextRange> {
+        let anchor = self.anchor();
+        let cursor = self.cursor();
+        let anchor_col = self.display_col(anchor);
+        let cursor_col = self.display_col(cursor);
+        let cols = min(anchor_col, cursor_col)..max(anchor_col, cursor_col);
+
+        let mut ranges = Vec::new();
+        for row in min(anchor.y, cursor.y)..=max(anchor.y, cursor.y) {
+            let (start, _) = self.display_col_to_col(row, cols.start);
+            let (end, _) = self.display_col_to_col(row, cols.end);
+            ranges.push(TextRange::new((start, row), (end, row)));
+        }
+        ranges
     }
 }
 
@@ -1038,6 +1435,24 @@ impl TextAreaState {
         self.value.set_text(TextRope::new_text(s.as_ref()));
     }
 
+    /// Append text at the end.
+    ///
+    /// This doesn't touch existing styles, the cursor or the undo buffer,
+    /// and is meant for log views and the like. If follow-tail is
+    /// engaged the view scrolls to the new end.
+    pub fn append_lines(&mut self, t: impl AsRef<str>) -> bool {
+        self.value.append_str(t.as_ref()).expect("valid_text");
+
+        self.vscroll.set_max_offset(
+            self.len_lines()
+                .saturating_sub(self.vscroll.page_len() as upos_type) as usize,
+        );
+        if self.is_following() {
+            self.vscroll.set_offset(self.vscroll.max_offset());
+        }
+        true
+    }
+
     /// Set the text value as a Rope.
     /// Resets all internal state.
     #[inline]
@@ -1055,6 +1470,7 @@ impl TextAreaState {
     /// a new-line or tab. Use insert_newline and insert_tab for
     /// this.
     pub fn insert_char(&mut self, c: char) -> bool {
+        self.delete_block_selection();
         let mut insert = true;
         if self.has_selection() {
             if self.auto_quote
@@ -1162,6 +1578,7 @@ impl TextAreaState {
     /// Removes the selection and inserts the text.
     pub fn insert_str(&mut self, t: impl AsRef<str>) -> bool {
         let t = t.as_ref();
+        self.delete_block_selection();
         if self.has_selection() {
             self.value
                 .remove_str_range(self.selection())
@@ -1179,6 +1596,7 @@ impl TextAreaState {
     /// If auto_indent is set the new line starts with the same
     /// indent as the current.
     pub fn insert_newline(&mut self) -> bool {
+        self.delete_block_selection();
         if self.has_selection() {
             self.value
                 .remove_str_range(self.selection())
@@ -1218,6 +1636,78 @@ impl TextAreaState {
         self.try_delete_range(range).expect("valid_range")
     }
 
+    /// Deletes the block selection as one undo sequence.
+    ///
+    /// The cursor goes to the top-left corner of the block, and
+    /// the selection switches back to a stream selection.
+    pub fn delete_block_selection(&mut self) -> bool {
+        if !self.is_block_selection() {
+            return false;
+        }
+        let ranges = self.block_ranges();
+
+        self.value.begin_undo_seq();
+        for range in ranges.iter().rev() {
+            if !range.is_empty() {
+                self.value.remove_str_range(*range).expect("valid_range");
+            }
+        }
+        self.value.end_undo_seq();
+
+        self.selection_kind = SelectionKind::Stream;
+        self.set_cursor(ranges[0].start, false);
+        self.scroll_cursor_to_visible();
+        true
+    }
+
+    /// Insert the text blockwise.
+    ///
+    /// Each line of the text is inserted in the next row at the
+    /// display column of the cursor. Rows that are too short are
+    /// padded with spaces, missing rows are appended.
+    pub fn insert_block(&mut self, t: impl AsRef<str>) -> bool {
+        let t = t.as_ref();
+        if self.is_block_selection() {
+            self.delete_block_selection();
+        } else if self.has_selection() {
+            self.value
+                .remove_str_range(self.selection())
+                .expect("valid_selection");
+        }
+
+        let cursor = self.cursor();
+        let display_col = self.display_col(cursor);
+
+        self.value.begin_undo_seq();
+        for (n, line) in t.split('\n').enumerate() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let row = cursor.y + n as upos_type;
+            if row >= self.len_lines() {
+                let last = self.len_lines() - 1;
+                let newline = self.newline().to_string();
+                self.value
+                    .insert_str(TextPosition::new(self.line_width(last), last), &newline)
+                    .expect("valid_pos");
+            }
+            let (mut col, line_col) = self.display_col_to_col(row, display_col);
+            if line_col < display_col {
+                let pad = " ".repeat((display_col - line_col) as usize);
+                self.value
+                    .insert_str(TextPosition::new(col, row), &pad)
+                    .expect("valid_pos");
+                col += pad.len() as upos_type;
+            }
+            self.value
+                .insert_str(TextPosition::new(col, row), line)
+                .expect("valid_pos");
+        }
+        self.value.end_undo_seq();
+
+        self.set_cursor(cursor, false);
+        self.scroll_cursor_to_visible();
+        true
+    }
+
     /// Deletes the given range.
     #[inline]
     pub fn try_delete_range(&mut self, range: impl Into<TextRange>) -> Result<bool, TextError> {
@@ -1233,6 +1723,19 @@ impl TextAreaState {
 }
 
 impl TextAreaState {
+    /// Apply a batch of edits.
+    ///
+    /// All ranges refer to the text before any of the edits
+    /// is applied, and they must not overlap.
+    ///
+    /// The edits are applied as one undo sequence. If any of the
+    /// ranges is invalid nothing is changed and an error is returned.
+    pub fn apply_edits(&mut self, edits: &[TextEdit<'_>]) -> Result<bool, TextError> {
+        let r = self.value.apply_edits(edits)?;
+        self.scroll_cursor_to_visible();
+        Ok(r)
+    }
+
     /// Duplicates the selection or the current line.
     /// Returns true if there was any real change.
     pub fn duplicate_text(&mut self) -> bool {
@@ -1270,10 +1773,272 @@ impl TextAreaState {
         }
     }
 
+    /// Moves the current line or all lines touched by the
+    /// selection up by one line.
+    ///
+    /// This is one undo sequence, the selection moves with the lines.
+    /// Returns true if there was any real change.
+    pub fn move_lines_up(&mut self) -> bool {
+        let (first, last) = self.line_block();
+        if first == 0 {
+            return false;
+        }
+
+        self.value.begin_undo_seq();
+        let anchor = self.anchor();
+        let cursor = self.cursor();
+        self.swap_rows(first - 1, first - 1, last);
+        self.value.set_selection(
+            TextPosition::new(anchor.x, anchor.y - 1),
+            TextPosition::new(cursor.x, cursor.y - 1),
+        );
+        self.value.end_undo_seq();
+
+        self.scroll_cursor_to_visible();
+        true
+    }
+
+    /// Moves the current line or all lines touched by the
+    /// selection down by one line.
+    ///
+    /// This is one undo sequence, the selection moves with the lines.
+    /// Returns true if there was any real change.
+    pub fn move_lines_down(&mut self) -> bool {
+        let (first, last) = self.line_block();
+        if last + 1 >= self.len_lines() {
+            return false;
+        }
+
+        self.value.begin_undo_seq();
+        let anchor = self.anchor();
+        let cursor = self.cursor();
+        self.swap_rows(first, last, last + 1);
+        self.value.set_selection(
+            TextPosition::new(anchor.x, anchor.y + 1),
+            TextPosition::new(cursor.x, cursor.y + 1),
+        );
+        self.value.end_undo_seq();
+
+        self.scroll_cursor_to_visible();
+        true
+    }
+
+    /// Duplicates the current line or all lines touched by the
+    /// selection. The copy is inserted below and the selection
+    /// moves to the copy.
+    ///
+    /// This is one undo sequence.
+    /// Returns true if there was any real change.
+    pub fn duplicate_lines(&mut self) -> bool {
+        let (first, last) = self.line_block();
+        let n = last - first + 1;
+        let block_range = self.rows_range(first, last);
+        let block_bytes = self.bytes_at_range(block_range);
+        let block = self.str_slice(block_range).to_string();
+
+        // the last line has no line-break.
+        let (pos, txt, offset) = if last + 1 < self.len_lines() {
+            (block_range.end, block.clone(), 0)
+        } else {
+            let nl = self.newline().to_string();
+            (block_range.end, format!("{}{}", nl, block), nl.len())
+        };
+
+        let mut styles = Vec::new();
+        self.value.styles_in(block_bytes.clone(), &mut styles);
+
+        self.value.begin_undo_seq();
+        let anchor = self.anchor();
+        let cursor = self.cursor();
+        let insert_byte = self.byte_at(pos).start;
+        self.insert_keep_styles(pos, &txt);
+        for (r, s) in styles {
+            if r.start >= block_bytes.start && r.end <= block_bytes.end {
+                let start = insert_byte + offset + (r.start - block_bytes.start);
+                let end = insert_byte + offset + (r.end - block_bytes.start);
+                self.value.add_style(start..end, s);
+            }
+        }
+        self.value.set_selection(
+            TextPosition::new(anchor.x, anchor.y + n),
+            TextPosition::new(cursor.x, cursor.y + n),
+        );
+        self.value.end_undo_seq();
+
+        self.scroll_cursor_to_visible();
+        true
+    }
+
+    /// Toggles a line comment for the current line or all lines
+    /// touched by the selection.
+    ///
+    /// If all non-blank lines start with the prefix it is removed,
+    /// otherwise all non-blank lines are commented at the smallest
+    /// indent of the block. When removing, a prefix with a trailing
+    /// space also matches without the space.
+    ///
+    /// This is one undo sequence.
+    /// Returns true if there was any real change.
+    pub fn toggle_line_comment(&mut self, prefix: &str) -> bool {
+        if prefix.is_empty() {
+            return false;
+        }
+        let short_prefix = prefix.trim_end();
+
+        let (first, last) = self.line_block();
+        let mut lines = Vec::new();
+        for row in first..=last {
+            let line = self.line_at(row);
+            let text = line.trim_start();
+            if text.trim_end().is_empty() {
+                continue;
+            }
+            let indent = self
+                .line_graphemes(row)
+                .take_while(|g| *g == " " || *g == "\t")
+                .count() as upos_type;
+            let commented = if text.starts_with(prefix) {
+                Some(prefix.graphemes(true).count() as upos_type)
+            } else if !short_prefix.is_empty() && text.starts_with(short_prefix) {
+                Some(short_prefix.graphemes(true).count() as upos_type)
+            } else {
+                None
+            };
+            lines.push((row, indent, commented));
+        }
+        if lines.is_empty() {
+            return false;
+        }
+
+        let anchor = self.anchor();
+        let cursor = self.cursor();
+
+        self.value.begin_undo_seq();
+        if lines.iter().all(|(_, _, c)| c.is_some()) {
+            for (row, indent, len) in lines {
+                let len = len.expect("commented");
+                self.value
+                    .remove_str_range(TextRange::new((indent, row), (indent + len, row)))
+                    .expect("valid_range");
+            }
+        } else {
+            let indent = lines.iter().map(|(_, i, _)| *i).min().expect("lines");
+            for (row, _, _) in lines {
+                self.insert_keep_styles(TextPosition::new(indent, row), prefix);
+            }
+        }
+        // keep complete lines selected.
+        let mut new_anchor = self.anchor();
+        let mut new_cursor = self.cursor();
+        if anchor.x == 0 {
+            new_anchor.x = 0;
+        }
+        if cursor.x == 0 {
+            new_cursor.x = 0;
+        }
+        self.value.set_selection(new_anchor, new_cursor);
+        self.value.end_undo_seq();
+
+        self.scroll_cursor_to_visible();
+        true
+    }
+
+    /// First and last row for the line operations.
+    /// A selection ending at the start of a line doesn't include that line.
+    fn line_block(&self) -> (upos_type, upos_type) {
+        let sel = self.selection();
+        if sel.end.x == 0 && sel.end.y > sel.start.y {
+            (sel.start.y, sel.end.y - 1)
+        } else {
+            (sel.start.y, sel.end.y)
+        }
+    }
+
+    /// Range for the given rows including the last line-break.
+    fn rows_range(&self, first: upos_type, last: upos_type) -> TextRange {
+        if last + 1 < self.len_lines() {
+            TextRange::new((0, first), (0, last + 1))
+        } else {
+            TextRange::new((0, first), (self.line_width(last), last))
+        }
+    }
+
+    /// Swaps the rows upper..=mid with mid+1..=lower.
+    /// Styles are moved with the rows.
+    fn swap_rows(&mut self, upper: upos_type, mid: upos_type, lower: upos_type) {
+        let region = self.rows_range(upper, lower);
+        let region_bytes = self.bytes_at_range(region);
+        let upper_txt = self.str_slice(self.rows_range(upper, mid)).to_string();
+        let lower_txt = self.str_slice(self.rows_range(mid + 1, lower)).to_string();
+
+        // the last line has no line-break.
+        let (new_lower, new_upper) = if lower + 1 < self.len_lines() {
+            (lower_txt, upper_txt.clone())
+        } else {
+            let nl = self.newline();
+            (
+                format!("{}{}", lower_txt, nl),
+                upper_txt
+                    .strip_suffix(nl)
+                    .unwrap_or(upper_txt.as_str())
+                    .to_string(),
+            )
+        };
+        let new_txt = format!("{}{}", new_lower, new_upper);
+
+        let mut styles = Vec::new();
+        self.value.styles_in(region_bytes.clone(), &mut styles);
+
+        self.value.remove_str_range(region).expect("valid_range");
+        self.insert_keep_styles(region.start, &new_txt);
+
+        let start = region_bytes.start;
+        let end = start + new_txt.len();
+        for (r, s) in styles {
+            if r.start < region_bytes.start || r.end > region_bytes.end {
+                continue;
+            }
+            let (r_start, r_end) = (r.start - start, r.end - start);
+            let new_range = if r_end <= upper_txt.len() {
+                // upper block moves down
+                start + new_lower.len() + r_start..start + new_lower.len() + r_end
+            } else if r_start >= upper_txt.len() {
+                // lower block moves up
+                start + r_start - upper_txt.len()..start + r_end - upper_txt.len()
+            } else {
+                continue;
+            };
+            let new_range = min(new_range.start, end)..min(new_range.end, end);
+            if !new_range.is_empty() {
+                self.value.add_style(new_range, s);
+            }
+        }
+    }
+
+    /// Insert text without extending the styles that end at the position.
+    fn insert_keep_styles(&mut self, pos: TextPosition, txt: &str) {
+        let byte_pos = self.byte_at(pos).start;
+        let mut styles = Vec::new();
+        if byte_pos > 0 {
+            self.value.styles_in(byte_pos - 1..byte_pos, &mut styles);
+        }
+
+        self.value.insert_str(pos, txt).expect("valid_pos");
+
+        for (r, s) in styles {
+            if r.start < byte_pos && r.end == byte_pos {
+                self.value.remove_style(r.start..r.end + txt.len(), s);
+                self.value.add_style(r, s);
+            }
+        }
+    }
+
     /// Deletes the next char or the current selection.
     /// Returns true if there was any real change.
     pub fn delete_next_char(&mut self) -> bool {
-        if self.has_selection() {
+        if self.is_block_selection() {
+            self.delete_block_selection()
+        } else if self.has_selection() {
             self.delete_range(self.selection())
         } else {
             let r = self
@@ -1289,7 +2054,9 @@ impl TextAreaState {
     /// Deletes the previous char or the selection.
     /// Returns true if there was any real change.
     pub fn delete_prev_char(&mut self) -> bool {
-        if self.has_selection() {
+        if self.is_block_selection() {
+            self.delete_block_selection()
+        } else if self.has_selection() {
             self.delete_range(self.selection())
         } else {
             let r = self
@@ -1640,33 +2407,38 @@ impl TextAreaState {
 impl HasScreenCursor for TextAreaState {
     /// Cursor position on the screen.
     fn screen_cursor(&self) -> Option<(u16, u16)> {
-        if self.is_focused() {
-            let cursor = self.cursor();
-            let (ox, oy) = self.offset();
-            let (ox, oy) = (ox as upos_type, oy as upos_type);
+        if self.is_focused() && !self.read_only {
+            self.cursor_screen_pos()
+        } else {
+            None
+        }
+    }
+}
 
-            if cursor.y < oy {
-                None
-            } else if cursor.y >= oy + (self.inner.height + self.dark_offset.1) as upos_type {
-                None
-            } else {
-                if cursor.x < ox {
-                    None
-                } else if cursor.x > ox + (self.inner.width + self.dark_offset.0) as upos_type {
-                    None
-                } else {
-                    let sy = self.row_to_screen(cursor);
-                    let sx = self.col_to_screen(cursor);
+impl TextAreaState {
+    /// Screen position of the cursor, if visible.
+    fn cursor_screen_pos(&self) -> Option<(u16, u16)> {
+        let cursor = self.cursor();
+        let (ox, oy) = self.offset();
+        let (ox, oy) = (ox as upos_type, oy as upos_type);
 
-                    if let Some((sx, sy)) = sx.iter().zip(sy.iter()).next() {
-                        Some((self.inner.x + *sx, self.inner.y + *sy))
-                    } else {
-                        None
-                    }
-                }
-            }
-        } else {
+        if cursor.y < oy {
+            None
+        } else if cursor.y >= oy + (self.inner.height + self.dark_offset.1) as upos_type {
             None
+        } else if cursor.x < ox {
+            None
+        } else if cursor.x > ox + (self.inner.width + self.dark_offset.0) as upos_type {
+            None
+        } else {
+            let sy = self.row_to_screen(cursor);
+            let sx = self.col_to_screen(cursor);
+
+            if let Some((sx, sy)) = sx.iter().zip(sy.iter()).next() {
+                Some((self.inner.x + *sx, self.inner.y + *sy))
+            } else {
+                None
+            }
         }
     }
 }
@@ -1910,7 +2682,9 @@ impl TextAreaState {
     /// The widget returns true if the offset changed at all.
     #[allow(unused_assignments)]
     pub fn set_vertical_offset(&mut self, row_offset: usize) -> bool {
-        self.vscroll.set_offset(row_offset)
+        let r = self.vscroll.set_offset(row_offset);
+        self.update_following();
+        r
     }
 
     /// Change the horizontal offset.
@@ -1926,7 +2700,9 @@ impl TextAreaState {
 
     /// Scroll to position.
     pub fn scroll_to_row(&mut self, pos: usize) -> bool {
-        self.vscroll.set_offset(pos)
+        let r = self.vscroll.set_offset(pos);
+        self.update_following();
+        r
     }
 
     /// Scroll to position.
@@ -1936,12 +2712,39 @@ impl TextAreaState {
 
     /// Scrolling
     pub fn scroll_up(&mut self, delta: usize) -> bool {
-        self.vscroll.scroll_up(delta)
+        let r = self.vscroll.scroll_up(delta);
+        self.update_following();
+        r
     }
 
     /// Scrolling
     pub fn scroll_down(&mut self, delta: usize) -> bool {
-        self.vscroll.scroll_down(delta)
+        let r = self.vscroll.scroll_down(delta);
+        self.update_following();
+        r
+    }
+
+    /// Keep the view at the end of the text while it grows.
+    ///
+    /// Scrolling up disengages, scrolling back to the end
+    /// (e.g. with Ctrl+End) engages again.
+    pub fn set_follow_tail(&mut self, follow_tail: bool) {
+        self.follow_tail = follow_tail;
+        self.following = true;
+    }
+
+    /// Keep the view at the end of the text while it grows.
+    pub fn follow_tail(&self) -> bool {
+        self.follow_tail
+    }
+
+    /// Is follow-tail active and currently engaged.
+    pub fn is_following(&self) -> bool {
+        self.follow_tail && self.following
+    }
+
+    fn update_following(&mut self) {
+        self.following = self.vscroll.offset() >= self.vscroll.max_offset();
     }
 
     /// Scrolling
@@ -2002,14 +2805,17 @@ impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for TextAreaStat
             }
         }
 
+        // edits are rejected, but the keys are still consumed.
+        let edit = !self.read_only;
+
         let mut r = if self.is_focused() {
             match event {
                 ct_event!(key press c)
                 | ct_event!(key press SHIFT-c)
-                | ct_event!(key press CONTROL_ALT-c) => tc(self.insert_char(*c)),
+                | ct_event!(key press CONTROL_ALT-c) => tc(edit && self.insert_char(*c)),
                 ct_event!(keycode press Tab) => {
                     // ignore tab from focus
-                    tc(if !self.focus.gained() {
+                    tc(if edit && !self.focus.gained() {
                         self.insert_tab()
                     } else {
                         false
@@ -2017,26 +2823,26 @@ impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for TextAreaStat
                 }
                 ct_event!(keycode press SHIFT-BackTab) => {
                     // ignore tab from focus
-                    tc(if !self.focus.gained() {
+                    tc(if edit && !self.focus.gained() {
                         self.insert_backtab()
                     } else {
                         false
                     })
                 }
-                ct_event!(keycode press Enter) => tc(self.insert_newline()),
-                ct_event!(keycode press Backspace) => tc(self.delete_prev_char()),
-                ct_event!(keycode press Delete) => tc(self.delete_next_char()),
+                ct_event!(keycode press Enter) => tc(edit && self.insert_newline()),
+                ct_event!(keycode press Backspace) => tc(edit && self.delete_prev_char()),
+                ct_event!(keycode press Delete) => tc(edit && self.delete_next_char()),
                 ct_event!(keycode press CONTROL-Backspace)
-                | ct_event!(keycode press ALT-Backspace) => tc(self.delete_prev_word()),
+                | ct_event!(keycode press ALT-Backspace) => tc(edit && self.delete_prev_word()),
                 ct_event!(keycode press CONTROL-Delete) | ct_event!(keycode press ALT-Delete) => {
-                    tc(self.delete_next_word())
+                    tc(edit && self.delete_next_word())
                 }
-                ct_event!(key press CONTROL-'x') => tc(self.cut_to_clip()),
-                ct_event!(key press CONTROL-'v') => tc(self.paste_from_clip()),
-                ct_event!(key press CONTROL-'d') => tc(self.duplicate_text()),
-                ct_event!(key press CONTROL-'y') => tc(self.delete_line()),
-                ct_event!(key press CONTROL-'z') => tc(self.undo()),
-                ct_event!(key press CONTROL_SHIFT-'Z') => tc(self.redo()),
+                ct_event!(key press CONTROL-'x') => tc(edit && self.cut_to_clip()),
+                ct_event!(key press CONTROL-'v') => tc(edit && self.paste_from_clip()),
+                ct_event!(key press CONTROL-'d') => tc(edit && self.duplicate_text()),
+                ct_event!(key press CONTROL-'y') => tc(edit && self.delete_line()),
+                ct_event!(key press CONTROL-'z') => tc(edit && self.undo()),
+                ct_event!(key press CONTROL_SHIFT-'Z') => tc(edit && self.redo()),
 
                 ct_event!(key release _)
                 | ct_event!(key release SHIFT-_)
@@ -2059,6 +2865,23 @@ impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for TextAreaStat
         } else {
             TextOutcome::Continue
         };
+        if r == TextOutcome::Continue && self.primary_selection {
+            r = match event {
+                ct_event!(mouse down Middle for column,row)
+                    if self.inner.contains((*column, *row).into()) =>
+                {
+                    let cx = (column - self.inner.x) as i16;
+                    let cy = (row - self.inner.y) as i16;
+                    self.set_screen_cursor((cx, cy), false);
+                    if edit && self.paste_from_primary() {
+                        TextOutcome::TextChanged
+                    } else {
+                        TextOutcome::Changed
+                    }
+                }
+                _ => TextOutcome::Continue,
+            };
+        }
         if r == TextOutcome::Continue {
             r = self.handle(event, ReadOnly);
         }
@@ -2126,6 +2949,7 @@ impl HandleEvent<crossterm::event::Event, ReadOnly, TextOutcome> for TextAreaSta
                 ct_event!(keycode press CONTROL_SHIFT-End) => self.move_to_end(true).into(),
                 ct_event!(key press CONTROL-'a') => self.select_all().into(),
                 ct_event!(key press CONTROL-'c') => self.copy_to_clip().into(),
+                ct_event!(key press ALT-'b') => self.toggle_block_selection().into(),
 
                 ct_event!(keycode release Left)
                 | ct_event!(keycode release Right)
@@ -2164,7 +2988,8 @@ impl HandleEvent<crossterm::event::Event, ReadOnly, TextOutcome> for TextAreaSta
                 | ct_event!(keycode release CONTROL_SHIFT-Home)
                 | ct_event!(keycode release CONTROL_SHIFT-End)
                 | ct_event!(key release CONTROL-'a')
-                | ct_event!(key release CONTROL-'c') => TextOutcome::Unchanged,
+                | ct_event!(key release CONTROL-'c')
+                | ct_event!(key release ALT-'b') => TextOutcome::Unchanged,
                 _ => TextOutcome::Continue,
             }
         } else {
@@ -2174,6 +2999,10 @@ impl HandleEvent<crossterm::event::Event, ReadOnly, TextOutcome> for TextAreaSta
         if r == TextOutcome::Continue {
             r = self.handle(event, MouseOnly);
         }
+        // only a finished selection goes to the primary selection.
+        if self.primary_selection && !self.mouse.drag.get() {
+            self.update_primary();
+        }
         r
     }
 }
@@ -2189,7 +3018,16 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, TextOutcome> for TextAreaSt
             ct_event!(mouse any for m) if self.mouse.drag2(self.inner, m, KeyModifiers::ALT) => {
                 let cx = m.column as i16 - self.inner.x as i16;
                 let cy = m.row as i16 - self.inner.y as i16;
-                self.set_screen_cursor_words((cx, cy), true).into()
+                self.set_screen_cursor((cx, cy), true).into()
+            }
+            ct_event!(mouse any for m) if self.mouse.tripleclick(self.inner, m) => {
+                let ty = self.screen_to_row(m.row as i16 - self.inner.y as i16);
+                if ty + 1 < self.len_lines() {
+                    self.set_selection((0, ty), (0, ty + 1)).into()
+                } else {
+                    let width = self.line_width(ty);
+                    self.set_selection((0, ty), (width, ty)).into()
+                }
             }
             ct_event!(mouse any for m) if self.mouse.doubleclick(self.inner, m) => {
                 let ty = self.screen_to_row(m.row as i16 - self.inner.y as i16);
@@ -2203,6 +3041,7 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, TextOutcome> for TextAreaSt
                 if self.inner.contains((*column, *row).into()) {
                     let cx = (column - self.inner.x) as i16;
                     let cy = (row - self.inner.y) as i16;
+                    self.selection_kind = SelectionKind::Stream;
                     self.set_screen_cursor((cx, cy), false).into()
                 } else {
                     TextOutcome::Continue
@@ -2221,7 +3060,9 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, TextOutcome> for TextAreaSt
                 if self.inner.contains((*column, *row).into()) {
                     let cx = (column - self.inner.x) as i16;
                     let cy = (row - self.inner.y) as i16;
-                    self.set_screen_cursor_words((cx, cy), true).into()
+                    self.selection_kind = SelectionKind::Block;
+                    self.set_screen_cursor((cx, cy), false);
+                    TextOutcome::Changed
                 } else {
                     TextOutcome::Continue
                 }
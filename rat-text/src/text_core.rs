@@ -1,14 +1,31 @@
 use crate::clipboard::Clipboard;
-use crate::grapheme::{Glyph, GlyphIter, Grapheme};
+use crate::grapheme::{Glyph, GlyphIter, Grapheme, WidthPolicy};
 use crate::range_map::{expand_range_by, ranges_intersect, shrink_range_by, RangeMap};
+use crate::style_provider::LineStyleCache;
 use crate::text_store::TextStore;
 use crate::undo_buffer::{StyleChange, TextPositionChange, UndoBuffer, UndoEntry, UndoOp};
-use crate::{upos_type, Cursor, TextError, TextPosition, TextRange};
+use crate::{upos_type, Cursor, TextEdit, TextError, TextPosition, TextRange};
 use dyn_clone::clone_box;
 use std::borrow::Cow;
 use std::cmp::min;
+use std::fmt::{Debug, Formatter};
 use std::ops::Range;
 
+/// Callback for text changes.
+///
+/// Gets the changed range, as it was before the change,
+/// and the text that replaces it.
+pub type TextChangeHook = dyn FnMut(TextRange, &str) + 'static;
+
+/// Wrapper for Debug.
+struct ChangeHook(Box<TextChangeHook>);
+
+impl Debug for ChangeHook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangeHook").finish()
+    }
+}
+
 /// Core for text editing.
 #[derive(Debug)]
 pub struct TextCore<Store> {
@@ -26,11 +43,15 @@ pub struct TextCore<Store> {
     undo: Option<Box<dyn UndoBuffer>>,
     /// clipboard
     clip: Option<Box<dyn Clipboard>>,
+    /// change notification
+    change_hook: Option<ChangeHook>,
+    /// cached styles of a style-provider
+    line_styles: Option<Box<LineStyleCache>>,
 
     /// line-break
     newline: String,
-    /// tab-width
-    tabs: u16,
+    /// display width of graphemes and tab-width
+    width_policy: WidthPolicy,
     /// expand tabs
     expand_tabs: bool,
     /// show ctrl chars in glyphs
@@ -48,8 +69,10 @@ impl<Store: Clone> Clone for TextCore<Store> {
             styles: self.styles.clone(),
             undo: self.undo.as_ref().map(|v| clone_box(v.as_ref())),
             clip: self.clip.as_ref().map(|v| clone_box(v.as_ref())),
+            change_hook: None,
+            line_styles: self.line_styles.clone(),
             newline: self.newline.clone(),
-            tabs: self.tabs,
+            width_policy: self.width_policy,
             expand_tabs: self.expand_tabs,
             glyph_ctrl: self.glyph_ctrl,
             glyph_line_break: self.glyph_line_break,
@@ -66,8 +89,10 @@ impl<Store: TextStore + Default> TextCore<Store> {
             styles: Default::default(),
             undo,
             clip,
+            change_hook: None,
+            line_styles: None,
             newline: "\n".to_string(),
-            tabs: 8,
+            width_policy: Default::default(),
             expand_tabs: true,
             glyph_ctrl: false,
             glyph_line_break: true,
@@ -94,13 +119,26 @@ impl<Store: TextStore + Default> TextCore<Store> {
     /// Default is 8.
     #[inline]
     pub fn set_tab_width(&mut self, tabs: u16) {
-        self.tabs = tabs;
+        self.width_policy.tab_width = tabs;
     }
 
     /// Tab-width
     #[inline]
     pub fn tab_width(&self) -> u16 {
-        self.tabs
+        self.width_policy.tab_width
+    }
+
+    /// Set the policy for the display width of graphemes.
+    /// This includes the tab-width.
+    #[inline]
+    pub fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        self.width_policy = width_policy;
+    }
+
+    /// Policy for the display width of graphemes.
+    #[inline]
+    pub fn width_policy(&self) -> WidthPolicy {
+        self.width_policy
     }
 
     /// Expand tabs to spaces. Only for new inputs.
@@ -154,6 +192,84 @@ impl<Store: TextStore + Default> TextCore<Store> {
     }
 }
 
+impl<Store: TextStore + Default> TextCore<Store> {
+    /// Set a callback that is notified of every change to the text.
+    ///
+    /// This includes changes due to undo/redo and replay.
+    /// The hook is not cloned with the TextCore.
+    pub fn set_change_hook(&mut self, hook: Option<Box<TextChangeHook>>) {
+        self.change_hook = hook.map(ChangeHook);
+    }
+
+    /// Has a change hook.
+    pub fn has_change_hook(&self) -> bool {
+        self.change_hook.is_some()
+    }
+
+    /// Set a cache for the styles of a style-provider.
+    ///
+    /// The cache is invalidated for all lines touched by a change.
+    pub fn set_line_style_cache(&mut self, cache: Option<LineStyleCache>) {
+        self.line_styles = cache.map(Box::new);
+    }
+
+    /// Cache for the styles of a style-provider.
+    pub fn line_style_cache(&self) -> Option<&LineStyleCache> {
+        self.line_styles.as_deref()
+    }
+
+    /// Clear the cache for the styles of a style-provider.
+    pub fn clear_line_styles(&mut self) {
+        if let Some(cache) = &mut self.line_styles {
+            cache.clear();
+        }
+    }
+
+    /// Fill the style cache for the given rows.
+    ///
+    /// The provider is only called for rows that are not cached.
+    pub fn fill_line_styles<F>(&mut self, rows: Range<upos_type>, mut provider: F)
+    where
+        F: FnMut(Range<upos_type>, &Store) -> Vec<(TextRange, usize)>,
+    {
+        if let Some(cache) = &mut self.line_styles {
+            let text = &self.text;
+            cache.fill(rows, |rows| provider(rows, text));
+        }
+    }
+
+    /// Styles of the style-provider at the given position.
+    #[inline]
+    pub fn line_styles_at(&self, pos: TextPosition, buf: &mut Vec<usize>) {
+        if let Some(cache) = &self.line_styles {
+            cache.styles_at(pos, buf);
+        }
+    }
+
+    /// Someone wants to know about changes.
+    #[inline]
+    fn has_change_listener(&self) -> bool {
+        self.change_hook.is_some() || self.line_styles.is_some()
+    }
+
+    /// Notify the change hook.
+    fn notify_change(&mut self, range: TextRange, txt: &str) {
+        if let Some(hook) = &mut self.change_hook {
+            (hook.0)(range, txt);
+        }
+        if let Some(cache) = &mut self.line_styles {
+            cache.invalidate(range, txt);
+        }
+    }
+
+    /// Range covering the complete text.
+    fn full_range(&self) -> TextRange {
+        let last = self.len_lines().saturating_sub(1);
+        let last_width = self.line_width(last).expect("valid_line");
+        TextRange::new((0, 0), (last_width, last))
+    }
+}
+
 impl<Store: TextStore + Default> TextCore<Store> {
     /// Undo
     #[inline]
@@ -235,7 +351,17 @@ impl<Store: TextStore + Default> TextCore<Store> {
                     anchor,
                     ..
                 } => {
+                    let range = self
+                        .text
+                        .bytes_to_range(bytes.clone())
+                        .expect("valid_bytes");
                     self.text.remove_b(bytes.clone()).expect("valid_bytes");
+                    if let Some(hook) = &mut self.change_hook {
+                        (hook.0)(range, "");
+                    }
+                    if let Some(cache) = &mut self.line_styles {
+                        cache.invalidate(range, "");
+                    }
 
                     if let Some(sty) = &mut self.styles {
                         sty.remap(|r, _| Some(shrink_range_by(bytes.clone(), r)));
@@ -257,7 +383,14 @@ impl<Store: TextStore + Default> TextCore<Store> {
                     txt,
                     styles,
                 } => {
+                    let pos = self.text.byte_to_pos(bytes.start).expect("valid_bytes");
                     self.text.insert_b(bytes.start, txt).expect("valid_bytes");
+                    if let Some(hook) = &mut self.change_hook {
+                        (hook.0)(TextRange::new(pos, pos), txt);
+                    }
+                    if let Some(cache) = &mut self.line_styles {
+                        cache.invalidate(TextRange::new(pos, pos), txt);
+                    }
 
                     if let Some(sty) = &mut self.styles {
                         for s in styles {
@@ -315,6 +448,64 @@ impl<Store: TextStore + Default> TextCore<Store> {
         self._redo()
     }
 
+    /// Undo until the undo position is `pos`.
+    ///
+    /// See [UndoBuffer::entries] and [UndoBuffer::position].
+    pub fn undo_to(&mut self, pos: usize) -> bool {
+        let mut changed = false;
+        while let Some(position) = self.undo.as_ref().map(|v| v.position()) {
+            if position <= pos {
+                break;
+            }
+            changed |= self.undo();
+            if self.undo.as_ref().map(|v| v.position()) == Some(position) {
+                break;
+            }
+        }
+        changed
+    }
+
+    /// Redo until the undo position is `pos`.
+    ///
+    /// See [UndoBuffer::entries] and [UndoBuffer::position].
+    pub fn redo_to(&mut self, pos: usize) -> bool {
+        let mut changed = false;
+        while let Some(position) = self.undo.as_ref().map(|v| v.position()) {
+            if position >= pos {
+                break;
+            }
+            changed |= self.redo();
+            if self.undo.as_ref().map(|v| v.position()) == Some(position) {
+                break;
+            }
+        }
+        changed
+    }
+
+    /// Restore a redo branch that was dropped by a later change.
+    ///
+    /// This undoes everything up to the start of the branch and
+    /// makes the branch the current redo. Use [redo_to](Self::redo_to)
+    /// to apply it afterward.
+    ///
+    /// __Attention__:
+    /// Restoring a branch is not recorded in the replay-log.
+    ///
+    /// See [UndoBuffer::branches].
+    pub fn restore_redo_branch(&mut self, n: usize) -> bool {
+        let Some(undo) = self.undo.as_ref() else {
+            return false;
+        };
+        let Some(branch) = undo.branches().into_iter().nth(n) else {
+            return false;
+        };
+        self.undo_to(branch.position);
+        match self.undo.as_mut() {
+            Some(undo) => undo.restore_branch(n),
+            None => false,
+        }
+    }
+
     fn _redo(&mut self) -> bool {
         let Some(undo) = self.undo.as_mut() else {
             return false;
@@ -335,7 +526,14 @@ impl<Store: TextStore + Default> TextCore<Store> {
                     anchor,
                     txt,
                 } => {
+                    let pos = self.text.byte_to_pos(bytes.start).expect("valid_bytes");
                     self.text.insert_b(bytes.start, txt).expect("valid_bytes");
+                    if let Some(hook) = &mut self.change_hook {
+                        (hook.0)(TextRange::new(pos, pos), txt);
+                    }
+                    if let Some(cache) = &mut self.line_styles {
+                        cache.invalidate(TextRange::new(pos, pos), txt);
+                    }
                     if let Some(sty) = &mut self.styles {
                         sty.remap(|r, _| Some(expand_range_by(bytes.clone(), r)));
                     }
@@ -356,7 +554,17 @@ impl<Store: TextStore + Default> TextCore<Store> {
                     styles,
                     ..
                 } => {
+                    let range = self
+                        .text
+                        .bytes_to_range(bytes.clone())
+                        .expect("valid_bytes");
                     self.text.remove_b(bytes.clone()).expect("valid_bytes");
+                    if let Some(hook) = &mut self.change_hook {
+                        (hook.0)(range, "");
+                    }
+                    if let Some(cache) = &mut self.line_styles {
+                        cache.invalidate(range, "");
+                    }
 
                     if let Some(sty) = &mut self.styles {
                         sty.remap(|r, _| {
@@ -419,6 +627,10 @@ impl<Store: TextStore + Default> TextCore<Store> {
         for replay_entry in replay {
             match &replay_entry.operation {
                 UndoOp::SetText { txt } => {
+                    if self.has_change_listener() {
+                        let range = self.full_range();
+                        self.notify_change(range, txt);
+                    }
                     self.text.set_string(txt);
                     if let Some(sty) = &mut self.styles {
                         sty.clear();
@@ -428,6 +640,10 @@ impl<Store: TextStore + Default> TextCore<Store> {
                     };
                 }
                 UndoOp::InsertChar { bytes, txt, .. } | UndoOp::InsertStr { bytes, txt, .. } => {
+                    if self.has_change_listener() {
+                        let pos = self.text.byte_to_pos(bytes.start).expect("valid_range");
+                        self.notify_change(TextRange::new(pos, pos), txt);
+                    }
                     self.text.insert_b(bytes.start, txt).expect("valid_range");
                     if let Some(sty) = &mut self.styles {
                         sty.remap(|r, _| Some(expand_range_by(bytes.clone(), r)));
@@ -435,6 +651,13 @@ impl<Store: TextStore + Default> TextCore<Store> {
                 }
                 UndoOp::RemoveChar { bytes, styles, .. }
                 | UndoOp::RemoveStr { bytes, styles, .. } => {
+                    if self.has_change_listener() {
+                        let range = self
+                            .text
+                            .bytes_to_range(bytes.clone())
+                            .expect("valid_range");
+                        self.notify_change(range, "");
+                    }
                     self.text.remove_b(bytes.clone()).expect("valid_range");
                     if let Some(sty) = &mut self.styles {
                         sty.remap(|r, _| {
@@ -762,7 +985,7 @@ impl<Store: TextStore + Default> TextCore<Store> {
         let mut it = GlyphIter::new(TextPosition::new(0, rows.start), iter);
         it.set_screen_offset(screen_offset);
         it.set_screen_width(screen_width);
-        it.set_tabs(self.tabs);
+        it.set_width_policy(self.width_policy);
         it.set_show_ctrl(self.glyph_ctrl);
         it.set_line_break(self.glyph_line_break);
         Ok(it)
@@ -843,6 +1066,10 @@ impl<Store: TextStore + Default> TextCore<Store> {
 impl<Store: TextStore + Default> TextCore<Store> {
     /// Clear the internal state.
     pub fn clear(&mut self) {
+        if self.has_change_listener() {
+            let range = self.full_range();
+            self.notify_change(range, "");
+        }
         self.text.set_string("");
         self.cursor = TextPosition::default();
         self.anchor = TextPosition::default();
@@ -869,6 +1096,10 @@ impl<Store: TextStore + Default> TextCore<Store> {
     /// Clears the styles.
     /// Caps cursor and anchor.
     pub fn set_text(&mut self, t: Store) -> bool {
+        if self.has_change_listener() {
+            let range = self.full_range();
+            self.notify_change(range, &t.string());
+        }
         self.text = t;
         if let Some(sty) = &mut self.styles {
             sty.clear();
@@ -962,7 +1193,8 @@ impl<Store: TextStore + Default> TextCore<Store> {
     /// Insert a tab, either expanded or literally.
     pub fn insert_tab(&mut self, mut pos: TextPosition) -> Result<bool, TextError> {
         if self.expand_tabs {
-            let n = self.tabs as upos_type - (pos.x % self.tabs as upos_type);
+            let tabs = self.width_policy.tab_width as upos_type;
+            let n = tabs - (pos.x % tabs);
             for _ in 0..n {
                 self.insert_char(pos, ' ')?;
                 pos.x += 1;
@@ -989,6 +1221,9 @@ impl<Store: TextStore + Default> TextCore<Store> {
     /// Insert a character.
     pub fn insert_char(&mut self, pos: TextPosition, c: char) -> Result<bool, TextError> {
         let (inserted_range, inserted_bytes) = self.text.insert_char(pos, c)?;
+        if self.has_change_listener() {
+            self.notify_change(TextRange::new(pos, pos), c.encode_utf8(&mut [0u8; 4]));
+        }
 
         let old_cursor = self.cursor;
         let old_anchor = self.anchor;
@@ -1023,6 +1258,7 @@ impl<Store: TextStore + Default> TextCore<Store> {
         let old_anchor = self.anchor;
 
         let (inserted_range, inserted_bytes) = self.text.insert_str(pos, t)?;
+        self.notify_change(TextRange::new(pos, pos), t);
 
         if let Some(sty) = &mut self.styles {
             sty.remap(|r, _| Some(expand_range_by(inserted_bytes.clone(), r)));
@@ -1048,6 +1284,69 @@ impl<Store: TextStore + Default> TextCore<Store> {
         Ok(true)
     }
 
+    /// Append a string at the end of the text.
+    ///
+    /// Other than insert_str this leaves the existing styles,
+    /// cursor and selection alone and doesn't record an undo.
+    pub fn append_str(&mut self, t: &str) -> Result<bool, TextError> {
+        let row = self.text.len_lines().saturating_sub(1);
+        let pos = TextPosition::new(self.text.line_width(row)?, row);
+
+        self.text.insert_str(pos, t)?;
+        self.notify_change(TextRange::new(pos, pos), t);
+
+        Ok(true)
+    }
+
+    /// Apply a batch of edits.
+    ///
+    /// All ranges refer to the text before any of the edits
+    /// is applied, and they must not overlap. Inserts at the same
+    /// position end up in the order given.
+    ///
+    /// The edits are applied as one undo sequence. If any of the
+    /// ranges is invalid nothing is changed and an error is returned.
+    /// The change hook is notified once per edit.
+    pub fn apply_edits(&mut self, edits: &[TextEdit<'_>]) -> Result<bool, TextError> {
+        let mut sorted = Vec::with_capacity(edits.len());
+        for edit in edits {
+            let bytes = self.text.byte_range(edit.range)?;
+            sorted.push((bytes, edit));
+        }
+        sorted.sort_by(|a, b| {
+            a.0.start
+                .cmp(&b.0.start)
+                .then_with(|| a.0.end.cmp(&b.0.end))
+        });
+        for w in sorted.windows(2) {
+            if w[0].0.end > w[1].0.start {
+                return Err(TextError::TextRangeOverlap(w[0].1.range, w[1].1.range));
+            }
+        }
+
+        // report complete edits, not the parts.
+        let mut hook = self.change_hook.take();
+
+        let mut changed = false;
+        self.begin_undo_seq();
+        for (_, edit) in sorted.into_iter().rev() {
+            changed |= self._remove_range(edit.range, false).expect("valid_range");
+            if !edit.new_text.is_empty() {
+                changed |= self
+                    .insert_str(edit.range.start, edit.new_text.as_ref())
+                    .expect("valid_pos");
+            }
+            if let Some(hook) = &mut hook {
+                (hook.0)(edit.range, edit.new_text.as_ref());
+            }
+        }
+        self.end_undo_seq();
+
+        self.change_hook = hook;
+
+        Ok(changed)
+    }
+
     /// Remove the previous character
     pub fn remove_prev_char(&mut self, pos: TextPosition) -> Result<bool, TextError> {
         let (sx, sy) = if pos.y == 0 && pos.x == 0 {
@@ -1101,6 +1400,7 @@ impl<Store: TextStore + Default> TextCore<Store> {
         }
 
         let (old_text, (_removed_range, removed_bytes)) = self.text.remove(range)?;
+        self.notify_change(range, "");
 
         // remove deleted styles.
         let mut changed_style = Vec::new();
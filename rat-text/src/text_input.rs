@@ -20,11 +20,11 @@ use crate::event::{ReadOnly, TextOutcome};
 use crate::undo_buffer::{UndoBuffer, UndoEntry, UndoVec};
 use crate::{
     ipos_type, upos_type, Cursor, Glyph, Grapheme, HasScreenCursor, TextError, TextFocusGained,
-    TextFocusLost, TextPosition, TextRange, TextStyle,
+    TextFocusLost, TextPosition, TextRange, TextStyle, WidthPolicy,
 };
 use crossterm::event::KeyModifiers;
 use rat_event::util::MouseFlags;
-use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
+use rat_event::{ct_event, feedback, HandleEvent, MouseOnly, Regular, Severity};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
 use rat_reloc::{relocate_area, relocate_dark_offset, RelocatableState};
 use ratatui::buffer::Buffer;
@@ -90,6 +90,13 @@ pub struct TextInputState {
     /// Focus behaviour.
     /// __read only__
     pub on_focus_lost: TextFocusLost,
+    /// Use the primary selection of the clipboard.
+    /// A finished selection is copied to the primary selection,
+    /// and a middle click pastes it. Never used for passwords.
+    /// __read+write__
+    pub primary_selection: bool,
+    /// Last selection copied to the primary selection.
+    primary_range: Option<Range<upos_type>>,
 
     /// Current focus state.
     /// __read+write__
@@ -397,6 +404,8 @@ impl Clone for TextInputState {
             overwrite: Default::default(),
             on_focus_gained: Default::default(),
             on_focus_lost: Default::default(),
+            primary_selection: self.primary_selection,
+            primary_range: None,
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -420,6 +429,8 @@ impl Default for TextInputState {
             overwrite: Default::default(),
             on_focus_gained: Default::default(),
             on_focus_lost: Default::default(),
+            primary_selection: false,
+            primary_range: None,
             focus: Default::default(),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -478,6 +489,18 @@ impl TextInputState {
     pub fn overwrite(&self) -> bool {
         self.overwrite
     }
+
+    /// Set the policy for the display width of graphemes.
+    #[inline]
+    pub fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        self.value.set_width_policy(width_policy);
+    }
+
+    /// Policy for the display width of graphemes.
+    #[inline]
+    pub fn width_policy(&self) -> WidthPolicy {
+        self.value.width_policy()
+    }
 }
 
 impl TextInputState {
@@ -508,7 +531,9 @@ impl TextInputState {
             return false;
         }
 
-        _ = clip.set_string(self.selected_text().as_ref());
+        if clip.set_string(self.selected_text().as_ref()).is_err() {
+            feedback(Severity::Warning, "Clipboard not available.");
+        }
         false
     }
 
@@ -524,7 +549,10 @@ impl TextInputState {
 
         match clip.set_string(self.selected_text().as_ref()) {
             Ok(_) => self.delete_range(self.selection()),
-            Err(_) => false,
+            Err(_) => {
+                feedback(Severity::Warning, "Clipboard not available.");
+                false
+            }
         }
     }
 
@@ -538,9 +566,45 @@ impl TextInputState {
         if let Ok(text) = clip.get_string() {
             self.insert_str(text)
         } else {
+            feedback(Severity::Warning, "Clipboard not available.");
             false
         }
     }
+
+    /// Paste the primary selection at the cursor.
+    #[inline]
+    pub fn paste_from_primary(&mut self) -> bool {
+        let Some(clip) = self.value.clipboard() else {
+            return false;
+        };
+
+        match clip.get_primary() {
+            Ok(text) if !text.is_empty() => self.insert_str(text),
+            Ok(_) => false,
+            Err(_) => {
+                feedback(Severity::Warning, "Clipboard not available.");
+                false
+            }
+        }
+    }
+
+    /// Copy a changed selection to the primary selection.
+    fn update_primary(&mut self) {
+        if self.passwd || !self.has_selection() {
+            self.primary_range = None;
+            return;
+        }
+        let selection = self.selection();
+        if self.primary_range.as_ref() == Some(&selection) {
+            return;
+        }
+        let Some(clip) = self.value.clipboard() else {
+            return;
+        };
+        if clip.set_primary(self.selected_text()).is_ok() {
+            self.primary_range = Some(selection);
+        }
+    }
 }
 
 impl TextInputState {
@@ -1518,6 +1582,23 @@ impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for TextInputSta
         } else {
             TextOutcome::Continue
         };
+        if r == TextOutcome::Continue && self.primary_selection {
+            r = match event {
+                ct_event!(mouse down Middle for column,row)
+                    if self.inner.contains((*column, *row).into()) =>
+                {
+                    let c = (column - self.inner.x) as i16;
+                    clear_overwrite(self);
+                    self.set_screen_cursor(c, false);
+                    if self.paste_from_primary() {
+                        TextOutcome::TextChanged
+                    } else {
+                        TextOutcome::Changed
+                    }
+                }
+                _ => TextOutcome::Continue,
+            };
+        }
         if r == TextOutcome::Continue {
             r = self.handle(event, ReadOnly);
         }
@@ -1622,6 +1703,10 @@ impl HandleEvent<crossterm::event::Event, ReadOnly, TextOutcome> for TextInputSt
         if r == TextOutcome::Continue {
             r = self.handle(event, MouseOnly);
         }
+        // only a finished selection goes to the primary selection.
+        if self.primary_selection && !self.mouse.drag.get() {
+            self.update_primary();
+        }
         r
     }
 }
@@ -4,6 +4,7 @@
 //! * Text selection with keyboard + mouse
 //! * Scrolls with the cursor.
 //! * Modes for focus and valid.
+//! * Insert/overtype mode, toggled with the Insert key.
 //! * Localization with [format_num_pattern::NumberSymbols]
 //!
 //! * Accepts an input mask:
@@ -76,13 +77,13 @@ use crate::text_input::TextInputState;
 use crate::text_mask_core::MaskedCore;
 use crate::undo_buffer::{UndoBuffer, UndoEntry};
 use crate::{
-    ipos_type, upos_type, Cursor, Glyph, Grapheme, HasScreenCursor, TextError, TextFocusGained,
-    TextFocusLost, TextStyle,
+    ipos_type, upos_type, Cursor, Glyph, Grapheme, HasScreenCursor, InputMode, TextError,
+    TextFocusGained, TextFocusLost, TextStyle, WidthPolicy,
 };
 use crossterm::event::KeyModifiers;
 use format_num_pattern::NumberSymbols;
 use rat_event::util::MouseFlags;
-use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
+use rat_event::{ct_event, feedback, HandleEvent, MouseOnly, Regular, Severity};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_reloc::{relocate_area, relocate_dark_offset, RelocatableState};
 use ratatui::buffer::Buffer;
@@ -113,6 +114,7 @@ pub struct MaskedInput<'a> {
     text_style: Vec<Style>,
     on_focus_gained: TextFocusGained,
     on_focus_lost: TextFocusLost,
+    spin: bool,
 }
 
 /// State & event-handling.
@@ -137,6 +139,9 @@ pub struct MaskedInputState {
     /// Display as invalid.
     /// __read+write__
     pub invalid: bool,
+    /// Only this range of the text is displayed as invalid.
+    /// __read+write__
+    pub invalid_range: Option<Range<upos_type>>,
     /// The next user edit clears the text for doing any edit.
     /// It will reset this flag. Other interactions may reset this flag too.
     pub overwrite: bool,
@@ -155,6 +160,9 @@ pub struct MaskedInputState {
     /// __read+write__
     pub mouse: MouseFlags,
 
+    /// Area reserved for spin buttons.
+    pub(crate) spin_area: Rect,
+
     /// Construct with `..Default::default()`
     pub non_exhaustive: NonExhaustive,
 }
@@ -173,6 +181,13 @@ impl<'a> MaskedInput<'a> {
         self
     }
 
+    /// Reserve space for spin buttons at the right edge.
+    #[inline]
+    pub(crate) fn spin(mut self, spin: bool) -> Self {
+        self.spin = spin;
+        self
+    }
+
     /// Set the combined style.
     #[inline]
     pub fn styles_opt(self, styles: Option<TextStyle>) -> Self {
@@ -301,6 +316,12 @@ fn render_ref(
 ) {
     state.area = area;
     state.inner = widget.block.inner_if_some(area);
+    if widget.spin && state.inner.width > 2 && state.inner.height > 0 {
+        state.inner.width -= 2;
+        state.spin_area = Rect::new(state.inner.right(), state.inner.y, 2, 1);
+    } else {
+        state.spin_area = Rect::default();
+    }
     state.on_focus_gained = widget.on_focus_gained;
     state.on_focus_lost = widget.on_focus_lost;
 
@@ -329,8 +350,16 @@ fn render_ref(
         Style::default().red()
     };
 
+    // invalid style for the whole text or for a range.
+    let invalid_all = state.invalid && state.invalid_range.is_none();
+    let invalid_range = if state.invalid {
+        state.invalid_range.clone()
+    } else {
+        None
+    };
+
     let (style, select_style) = if state.focus.get() {
-        if state.invalid {
+        if invalid_all {
             (
                 focus_style.patch(invalid_style),
                 select_style.patch(invalid_style),
@@ -339,7 +368,7 @@ fn render_ref(
             (focus_style, select_style)
         }
     } else {
-        if state.invalid {
+        if invalid_all {
             (
                 widget.style.patch(invalid_style),
                 widget.style.patch(invalid_style),
@@ -398,6 +427,11 @@ fn render_ref(
                     style = style.patch(*s);
                 }
             }
+            if let Some(invalid_range) = &invalid_range {
+                if invalid_range.contains(&g.pos().x) {
+                    style = style.patch(invalid_style);
+                }
+            }
             // selection
             if selection.contains(&g.pos().x) {
                 style = style.patch(select_style);
@@ -433,11 +467,13 @@ impl Clone for MaskedInputState {
             dark_offset: self.dark_offset,
             value: self.value.clone(),
             invalid: self.invalid,
+            invalid_range: self.invalid_range.clone(),
             overwrite: Default::default(),
             on_focus_gained: Default::default(),
             on_focus_lost: Default::default(),
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
+            spin_area: self.spin_area,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -452,11 +488,13 @@ impl Default for MaskedInputState {
             dark_offset: Default::default(),
             value: Default::default(),
             invalid: Default::default(),
+            invalid_range: Default::default(),
             overwrite: Default::default(),
             on_focus_gained: Default::default(),
             on_focus_lost: Default::default(),
             focus: Default::default(),
             mouse: Default::default(),
+            spin_area: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -539,6 +577,18 @@ impl MaskedInputState {
         self.value.set_num_symbols(sym);
     }
 
+    /// Set the policy for the display width of graphemes.
+    #[inline]
+    pub fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        self.value.set_width_policy(width_policy);
+    }
+
+    /// Policy for the display width of graphemes.
+    #[inline]
+    pub fn width_policy(&self) -> WidthPolicy {
+        self.value.width_policy()
+    }
+
     /// Set the input mask. This overwrites the display mask and the value
     /// with a default representation of the mask.
     ///
@@ -592,6 +642,19 @@ impl MaskedInputState {
         self.invalid
     }
 
+    /// Renders only this range in invalid style, if the widget
+    /// is set invalid. None marks the whole text.
+    #[inline]
+    pub fn set_invalid_range(&mut self, range: Option<Range<upos_type>>) {
+        self.invalid_range = range;
+    }
+
+    /// Range that is rendered in invalid style.
+    #[inline]
+    pub fn invalid_range(&self) -> Option<Range<upos_type>> {
+        self.invalid_range.clone()
+    }
+
     /// The next edit operation will overwrite the current content
     /// instead of adding text. Any move operations will cancel
     /// this overwrite.
@@ -605,6 +668,30 @@ impl MaskedInputState {
     pub fn overwrite(&self) -> bool {
         self.overwrite
     }
+
+    /// Insert or overtype typed characters.
+    ///
+    /// The widget doesn't change the cursor, use this to
+    /// set an appropriate cursor shape.
+    #[inline]
+    pub fn set_input_mode(&mut self, input_mode: InputMode) {
+        self.value.set_input_mode(input_mode);
+    }
+
+    /// Insert or overtype typed characters.
+    #[inline]
+    pub fn input_mode(&self) -> InputMode {
+        self.value.input_mode()
+    }
+
+    /// Switch between insert and overtype.
+    #[inline]
+    pub fn toggle_input_mode(&mut self) {
+        match self.value.input_mode() {
+            InputMode::Insert => self.value.set_input_mode(InputMode::Overtype),
+            InputMode::Overtype => self.value.set_input_mode(InputMode::Insert),
+        }
+    }
 }
 
 impl MaskedInputState {
@@ -632,7 +719,9 @@ impl MaskedInputState {
             return false;
         };
 
-        _ = clip.set_string(self.selected_text().as_ref());
+        if clip.set_string(self.selected_text().as_ref()).is_err() {
+            feedback(Severity::Warning, "Clipboard not available.");
+        }
 
         true
     }
@@ -646,7 +735,10 @@ impl MaskedInputState {
 
         match clip.set_string(self.selected_text().as_ref()) {
             Ok(_) => self.delete_range(self.selection()),
-            Err(_) => true,
+            Err(_) => {
+                feedback(Severity::Warning, "Clipboard not available.");
+                true
+            }
         }
     }
 
@@ -663,6 +755,7 @@ impl MaskedInputState {
             }
             true
         } else {
+            feedback(Severity::Warning, "Clipboard not available.");
             false
         }
     }
@@ -1298,6 +1391,7 @@ impl RelocatableState for MaskedInputState {
         self.dark_offset = relocate_dark_offset(self.inner, shift, clip);
         self.area = relocate_area(self.area, shift, clip);
         self.inner = relocate_area(self.inner, shift, clip);
+        self.spin_area = relocate_area(self.spin_area, shift, clip);
     }
 }
 
@@ -1493,6 +1587,10 @@ impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for MaskedInputS
                     overwrite(self);
                     tc(self.insert_char(*c))
                 }
+                ct_event!(keycode press Insert) => {
+                    self.toggle_input_mode();
+                    TextOutcome::Changed
+                }
                 ct_event!(keycode press Backspace) => {
                     clear_overwrite(self);
                     tc(self.delete_prev_char())
@@ -1534,6 +1632,7 @@ impl HandleEvent<crossterm::event::Event, Regular, TextOutcome> for MaskedInputS
                 ct_event!(key release _)
                 | ct_event!(key release SHIFT-_)
                 | ct_event!(key release CONTROL_ALT-_)
+                | ct_event!(keycode release Insert)
                 | ct_event!(keycode release Backspace)
                 | ct_event!(keycode release Delete)
                 | ct_event!(keycode release CONTROL-Backspace)
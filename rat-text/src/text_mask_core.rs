@@ -1,9 +1,9 @@
 use crate::clipboard::{global_clipboard, Clipboard};
 use crate::core::{TextCore, TextString};
-use crate::grapheme::GlyphIter;
+use crate::grapheme::{GlyphIter, WidthPolicy};
 use crate::text_mask_core::mask::{EditDirection, Mask, MaskToken};
 use crate::undo_buffer::{UndoBuffer, UndoEntry, UndoVec};
-use crate::{upos_type, Cursor, Glyph, Grapheme, TextError, TextPosition, TextRange};
+use crate::{upos_type, Cursor, Glyph, Grapheme, InputMode, TextError, TextPosition, TextRange};
 use format_num_pattern::core::{clean_num, map_num};
 use format_num_pattern::{CurrencySym, NumberFormat, NumberSymbols};
 use std::borrow::Cow;
@@ -21,6 +21,8 @@ pub struct MaskedCore {
     sym: Option<NumberSymbols>,
     // parsed mask
     mask: Vec<MaskToken>,
+    // insert/overtype
+    input_mode: InputMode,
 }
 
 impl Default for MaskedCore {
@@ -35,6 +37,7 @@ impl Default for MaskedCore {
             masked: value,
             sym: None,
             mask: Default::default(),
+            input_mode: Default::default(),
         }
     }
 }
@@ -44,6 +47,16 @@ impl MaskedCore {
         Self::default()
     }
 
+    /// Insert or overtype.
+    pub fn set_input_mode(&mut self, input_mode: InputMode) {
+        self.input_mode = input_mode;
+    }
+
+    /// Insert or overtype.
+    pub fn input_mode(&self) -> InputMode {
+        self.input_mode
+    }
+
     /// Set the decimal separator and other symbols.
     /// Only used for rendering and to map user input.
     /// The value itself uses "."
@@ -51,6 +64,16 @@ impl MaskedCore {
         self.sym = Some(sym);
     }
 
+    /// Set the policy for the display width of graphemes.
+    pub fn set_width_policy(&mut self, width_policy: WidthPolicy) {
+        self.masked.set_width_policy(width_policy);
+    }
+
+    /// Policy for the display width of graphemes.
+    pub fn width_policy(&self) -> WidthPolicy {
+        self.masked.width_policy()
+    }
+
     fn dec_sep(&self) -> char {
         if let Some(sym) = &self.sym {
             sym.decimal_sep
@@ -698,7 +721,7 @@ impl MaskedCore {
         let mut it = GlyphIter::new(TextPosition::new(0, rows.start), iter);
         it.set_screen_offset(screen_offset);
         it.set_screen_width(screen_width);
-        it.set_tabs(self.masked.tab_width());
+        it.set_width_policy(self.masked.width_policy());
         it.set_show_ctrl(self.masked.glyph_ctrl());
         it.set_line_break(self.masked.glyph_line_break());
         Ok(it)
@@ -766,7 +789,7 @@ impl MaskedCore {
         let mut it = GlyphIter::new(TextPosition::new(0, rows.start), iter);
         it.set_screen_offset(screen_offset);
         it.set_screen_width(screen_width);
-        it.set_tabs(self.masked.tab_width());
+        it.set_width_policy(self.masked.width_policy());
         it.set_show_ctrl(self.masked.glyph_ctrl());
         it.set_line_break(self.masked.glyph_line_break());
         Ok(it)
@@ -1162,6 +1185,11 @@ impl MaskedCore {
                 }
             }
         }
+        if self.input_mode == InputMode::Overtype {
+            if self.overtype(c) {
+                return true;
+            }
+        }
         {
             let mask = &self.mask[cursor.x as usize];
             if mask.right.is_rtol() {
@@ -1191,6 +1219,47 @@ impl MaskedCore {
         false
     }
 
+    /// Replace the char at the cursor.
+    ///
+    /// Number sections only replace digits that have been entered,
+    /// everything else is left to the regular insert.
+    fn overtype(&mut self, c: char) -> bool {
+        let cursor = self.masked.cursor();
+
+        let mask = &self.mask[cursor.x as usize];
+        if mask.right.is_none()
+            || mask.right.is_separator()
+            || mask.right == Mask::DecimalSep
+            || mask.right == Mask::GroupingSep
+            || !self.is_valid_char(&mask.right, c)
+        {
+            return false;
+        }
+        if mask.right.is_rtol() {
+            let g = self
+                .masked
+                .grapheme_at(cursor)
+                .expect("valid_cursor")
+                .expect("mask");
+            if mask.right.can_drop(g.grapheme())
+                || !g.grapheme().chars().all(|v| v.is_ascii_digit())
+            {
+                return false;
+            }
+        }
+
+        self.masked.begin_undo_seq();
+        self.masked
+            .remove_char_range(TextRange::new(cursor, (cursor.x + 1, 0)))
+            .expect("valid_cursor");
+        self.masked.insert_char(cursor, c).expect("valid_cursor");
+        if mask.right.is_rtol() {
+            Self::reformat(&mut self.masked, &self.mask, mask.sub_start..mask.sub_end);
+        }
+        self.masked.end_undo_seq();
+        true
+    }
+
     /// Insert c into a ltor section.
     fn insert_ltor(&mut self, c: char) -> bool {
         let cursor = self.masked.cursor();
@@ -1420,32 +1489,126 @@ impl MaskedCore {
         }
     }
 
-    /// Remove the previous char.
-    pub fn remove_prev(&mut self) {
-        let cursor = self.masked.cursor();
+    /// Is the section of this mask-token empty.
+    fn is_section_empty(&self, mask: &MaskToken) -> bool {
+        let sec_str = self
+            .masked
+            .str_slice(TextRange::new((mask.sub_start, 0), (mask.sub_end, 0)))
+            .expect("valid_range");
+        let sec_mask = &self.mask[mask.sub_start as usize..mask.sub_end as usize];
+        sec_str == MaskToken::empty_section(sec_mask)
+    }
 
-        if cursor.x == 0 {
-            return;
+    /// Position for removing the previous char.
+    ///
+    /// Steps over separators and empty sections to the
+    /// end of the previous section with some content.
+    /// Returns Err with the leftmost position reached if
+    /// there is nothing left to remove.
+    fn prev_remove_pos(&self, mut pos: upos_type) -> Result<upos_type, upos_type> {
+        let start = pos;
+        loop {
+            if pos == 0 {
+                return Err(pos);
+            }
+            let left = &self.mask[pos as usize - 1];
+            if left.right.is_separator() {
+                pos -= 1;
+            } else if self.is_section_empty(left) {
+                pos = left.sub_start;
+            } else {
+                break;
+            }
         }
 
-        let left = &self.mask[cursor.x as usize - 1];
-
-        if left.right.is_rtol() {
-            // Check if the section is empty
-            let sec_empty = if left.right.is_rtol() {
-                let sec_str = self
+        // when entering a ltor section, skip the trailing placeholders.
+        if pos != start && self.mask[pos as usize - 1].right.is_ltor() {
+            let sub_start = self.mask[pos as usize - 1].sub_start;
+            while pos > sub_start {
+                let mask = &self.mask[pos as usize - 1];
+                let g = self
                     .masked
-                    .str_slice(TextRange::new((left.sub_start, 0), (left.sub_end, 0)))
-                    .expect("valid_range");
-                let sec_mask = &self.mask[left.sub_start as usize..left.sub_end as usize];
-                sec_str == MaskToken::empty_section(sec_mask)
+                    .grapheme_at(TextPosition::new(pos - 1, 0))
+                    .expect("valid_pos")
+                    .expect("grapheme");
+                if g.grapheme() == mask.edit.as_ref() {
+                    pos -= 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        Ok(pos)
+    }
+
+    /// Position for removing the next char.
+    ///
+    /// Steps over separators and empty sections to the
+    /// start of the next section with some content.
+    /// Returns Err with the rightmost position reached if
+    /// there is nothing left to remove.
+    fn next_remove_pos(&self, mut pos: upos_type) -> Result<upos_type, upos_type> {
+        let start = pos;
+        loop {
+            if pos as usize >= self.mask.len() - 1 {
+                return Err(pos);
+            }
+            let right = &self.mask[pos as usize];
+            if right.right.is_separator() {
+                pos += 1;
+            } else if self.is_section_empty(right) {
+                pos = right.sub_end;
             } else {
-                false
-            };
+                break;
+            }
+        }
+
+        // when entering a rtol section, skip the leading placeholders.
+        if pos != start && self.mask[pos as usize].right.is_rtol() {
+            let sub_end = self.mask[pos as usize].sub_end;
+            while pos < sub_end {
+                let mask = &self.mask[pos as usize];
+                let g = self
+                    .masked
+                    .grapheme_at(TextPosition::new(pos, 0))
+                    .expect("valid_pos")
+                    .expect("grapheme");
+                if g.grapheme() == mask.edit.as_ref() {
+                    pos += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        Ok(pos)
+    }
+
+    /// Remove the previous char.
+    ///
+    /// The removed char is replaced with the placeholder of the
+    /// section, and the cursor stays in the section, even if it
+    /// is empty afterwards. If the cursor is at the start of a
+    /// section or the section is empty, the last char of the
+    /// previous section is removed instead.
+    pub fn remove_prev(&mut self) {
+        let pos = match self.prev_remove_pos(self.masked.cursor().x) {
+            Ok(pos) => pos,
+            Err(pos) => {
+                self.masked.set_cursor(TextPosition::new(pos, 0), false);
+                return;
+            }
+        };
+        let cursor = TextPosition::new(pos, 0);
+
+        let left = &self.mask[cursor.x as usize - 1];
 
+        if left.right.is_rtol() {
             let l0 = &self.mask[left.sub_start as usize];
 
             self.masked.begin_undo_seq();
+            self.masked.set_cursor(cursor, false);
             self.masked
                 .remove_char_range(TextRange::new((cursor.x - 1, 0), cursor))
                 .expect("valid_range");
@@ -1454,15 +1617,8 @@ impl MaskedCore {
                 .expect("valid_position");
             Self::reformat(&mut self.masked, &self.mask, left.sub_start..left.sub_end);
 
-            // in a rtol field keep the cursor at the same position until the
-            // whole section is empty. Only then put it at the beginning of the section
-            // to continue left of the section.
-            if sec_empty {
-                self.masked
-                    .set_cursor(TextPosition::new(left.sub_start, 0), false);
-            } else {
-                // cursor stays
-            }
+            // in a rtol field the cursor stays at the same position.
+            self.masked.set_cursor(cursor, false);
 
             self.masked.end_undo_seq();
         } else if left.right.is_ltor() {
@@ -1485,13 +1641,22 @@ impl MaskedCore {
         }
     }
 
-    /// Remove the previous char.
+    /// Remove the next char.
+    ///
+    /// The removed char is replaced with the placeholder of the
+    /// section, and the cursor stays in the section, even if it
+    /// is empty afterwards. If the cursor is at the end of a
+    /// section or the section is empty, the first char of the
+    /// next section is removed instead.
     pub fn remove_next(&mut self) {
-        let cursor = self.masked.cursor();
-
-        if cursor.x as usize == self.mask.len() - 1 {
-            return;
-        }
+        let pos = match self.next_remove_pos(self.masked.cursor().x) {
+            Ok(pos) => pos,
+            Err(pos) => {
+                self.masked.set_cursor(TextPosition::new(pos, 0), false);
+                return;
+            }
+        };
+        let cursor = TextPosition::new(pos, 0);
 
         let right = &self.mask[cursor.x as usize];
 
@@ -1513,17 +1678,10 @@ impl MaskedCore {
 
             self.masked.end_undo_seq();
         } else if right.right.is_ltor() {
-            // Check if the section is empty
-            let sec_str = self
-                .masked
-                .str_slice(TextRange::new((right.sub_start, 0), (right.sub_end, 0)))
-                .expect("valid_range");
-            let sec_mask = &self.mask[right.sub_start as usize..right.sub_end as usize];
-            let sec_empty = sec_str == MaskToken::empty_section(sec_mask);
-
             let l9 = &self.mask[right.sub_end as usize - 1];
 
             self.masked.begin_undo_seq();
+            self.masked.set_cursor(cursor, false);
             self.masked
                 .remove_char_range(TextRange::new(cursor, (cursor.x + 1, 0)))
                 .expect("valid_range");
@@ -1533,15 +1691,8 @@ impl MaskedCore {
 
             Self::reformat(&mut self.masked, &self.mask, right.sub_start..right.sub_end);
 
-            // in a ltor field keep the cursor at the same position until the
-            // whole section is empty. Only then put it at the end of the section
-            // to continue right of the section.
-            if sec_empty {
-                self.masked
-                    .set_cursor(TextPosition::new(right.sub_end, 0), false);
-            } else {
-                // cursor stays
-            }
+            // in a ltor field the cursor stays at the same position.
+            self.masked.set_cursor(cursor, false);
 
             self.masked.end_undo_seq();
         }
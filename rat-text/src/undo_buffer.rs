@@ -1,12 +1,13 @@
 //! Undo functionality.
 
+use crate::_private::NonExhaustive;
 use crate::range_map::expand_range_by;
 use crate::TextPosition;
-use crate::_private::NonExhaustive;
 use dyn_clone::DynClone;
 use std::fmt::Debug;
 use std::mem;
 use std::ops::Range;
+use std::time::SystemTime;
 
 /// Undo buffer.
 ///
@@ -81,6 +82,154 @@ pub trait UndoBuffer: DynClone + Debug {
 
     /// Is there undo for setting/removing styles.
     fn undo_styles_enabled(&self) -> bool;
+
+    /// Lists the undo history, one entry per undo step.
+    ///
+    /// Entries before [position](UndoBuffer::position) can be
+    /// undone, the rest can be redone.
+    fn entries(&self) -> Vec<UndoInfo> {
+        Vec::default()
+    }
+
+    /// Current position in the undo history.
+    ///
+    /// This is the number of steps that can be undone.
+    fn position(&self) -> usize {
+        0
+    }
+
+    /// Redo steps that have been dropped by a new change.
+    ///
+    /// The oldest branch comes first.
+    fn branches(&self) -> Vec<UndoBranch> {
+        Vec::default()
+    }
+
+    /// Make the n-th branch the current redo.
+    ///
+    /// This only works if the current [position](UndoBuffer::position)
+    /// is the position of the branch. The redo steps that are currently
+    /// there will become a branch themselves.
+    ///
+    /// Returns false if this is not possible.
+    fn restore_branch(&mut self, n: usize) -> bool {
+        _ = n;
+        false
+    }
+}
+
+/// Kind of change for one undo step.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndoKind {
+    /// Text was inserted.
+    Insert,
+    /// Text was removed.
+    Remove,
+    /// Text was inserted and removed.
+    Replace,
+    /// Only cursor/anchor changed.
+    Cursor,
+    /// Only styles changed.
+    Style,
+}
+
+/// Information about one undo step.
+#[derive(Debug, Clone)]
+pub struct UndoInfo {
+    /// Kind of change.
+    pub kind: UndoKind,
+    /// Byte range touched by the change.
+    ///
+    /// Cursor changes have an empty range.
+    pub bytes: Range<usize>,
+    /// Number of bytes inserted minus the number of bytes removed.
+    pub delta: isize,
+    /// Time of the last change in this step.
+    pub timestamp: SystemTime,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// Redo steps that have been dropped by a new change.
+#[derive(Debug, Clone)]
+pub struct UndoBranch {
+    /// Undo position where the branch starts.
+    pub position: usize,
+    /// Steps of the branch.
+    pub entries: Vec<UndoInfo>,
+    /// Time when the branch was dropped.
+    pub timestamp: SystemTime,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl UndoInfo {
+    fn new(entries: &[UndoEntry]) -> Self {
+        let mut insert = false;
+        let mut remove = false;
+        let mut style = false;
+        let mut bytes: Option<Range<usize>> = None;
+        let mut delta = 0isize;
+        let mut timestamp = SystemTime::UNIX_EPOCH;
+
+        for entry in entries {
+            timestamp = timestamp.max(entry.timestamp);
+
+            let range = match &entry.operation {
+                UndoOp::InsertChar { bytes, .. } | UndoOp::InsertStr { bytes, .. } => {
+                    insert = true;
+                    delta += bytes.len() as isize;
+                    Some(bytes.clone())
+                }
+                UndoOp::RemoveChar { bytes, .. } | UndoOp::RemoveStr { bytes, .. } => {
+                    remove = true;
+                    delta -= bytes.len() as isize;
+                    Some(bytes.clone())
+                }
+                UndoOp::AddStyle { range, .. } | UndoOp::RemoveStyle { range, .. } => {
+                    style = true;
+                    Some(range.clone())
+                }
+                UndoOp::SetStyles {
+                    styles_before,
+                    styles_after,
+                } => {
+                    style = true;
+                    styles_before
+                        .iter()
+                        .chain(styles_after.iter())
+                        .map(|(r, _)| r.clone())
+                        .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+                }
+                UndoOp::Cursor { .. } | UndoOp::SetText { .. } | UndoOp::Undo | UndoOp::Redo => {
+                    None
+                }
+            };
+            if let Some(range) = range {
+                bytes = Some(match bytes {
+                    None => range,
+                    Some(b) => b.start.min(range.start)..b.end.max(range.end),
+                });
+            }
+        }
+
+        let kind = match (insert, remove) {
+            (true, true) => UndoKind::Replace,
+            (true, false) => UndoKind::Insert,
+            (false, true) => UndoKind::Remove,
+            (false, false) if style => UndoKind::Style,
+            (false, false) => UndoKind::Cursor,
+        };
+
+        Self {
+            kind,
+            bytes: bytes.unwrap_or_default(),
+            delta,
+            timestamp,
+            non_exhaustive: NonExhaustive,
+        }
+    }
 }
 
 /// Stores one style change.
@@ -103,6 +252,8 @@ pub struct TextPositionChange {
 pub struct UndoEntry {
     pub sequence: u32,
     pub operation: UndoOp,
+    /// Time the operation was recorded.
+    pub timestamp: SystemTime,
     pub non_exhaustive: NonExhaustive,
 }
 
@@ -223,26 +374,40 @@ pub struct UndoVec {
     undo_styles: bool,
     track_replay: bool,
     undo_count: u32,
+    branch_count: u32,
 
     begin: u8,
     sequence: u32,
     buf: Vec<UndoEntry>,
     replay: Vec<UndoEntry>,
+    // dropped redo
+    branches: Vec<Branch>,
 
     // undo/redo split
     idx: usize,
 }
 
+/// Dropped redo.
+#[derive(Debug, Clone)]
+struct Branch {
+    // index into buf
+    idx: usize,
+    buf: Vec<UndoEntry>,
+    timestamp: SystemTime,
+}
+
 impl Default for UndoVec {
     fn default() -> Self {
         Self {
             undo_styles: false,
             track_replay: false,
             undo_count: 99,
+            branch_count: 8,
             begin: 0,
             sequence: 0,
             buf: Vec::default(),
             replay: Vec::default(),
+            branches: Vec::default(),
             idx: 0,
         }
     }
@@ -277,6 +442,19 @@ impl UndoVec {
         self.undo_styles
     }
 
+    /// How many dropped redo branches are kept.
+    ///
+    /// Default is 8. Set to 0 to disable.
+    pub fn set_branch_count(&mut self, n: u32) {
+        self.branch_count = n;
+        self.trim_branches();
+    }
+
+    /// How many dropped redo branches are kept.
+    pub fn branch_count(&self) -> u32 {
+        self.branch_count
+    }
+
     fn merge_undo(mut last: UndoOp, mut curr: UndoOp) -> (Option<UndoOp>, Option<UndoOp>) {
         match &mut curr {
             UndoOp::InsertChar {
@@ -543,10 +721,11 @@ impl UndoVec {
         false
     }
 
-    fn try_merge(&mut self, undo: UndoOp) -> Option<UndoOp> {
+    fn try_merge(&mut self, undo: UndoOp, timestamp: SystemTime) -> Option<UndoOp> {
         if let Some(UndoEntry {
             sequence,
             operation: last,
+            timestamp: last_timestamp,
             ..
         }) = self.buf.pop()
         {
@@ -556,6 +735,11 @@ impl UndoVec {
                 self.buf.push(UndoEntry {
                     sequence,
                     operation: last,
+                    timestamp: if undo.is_none() {
+                        timestamp
+                    } else {
+                        last_timestamp
+                    },
                     non_exhaustive: NonExhaustive,
                 });
             }
@@ -566,9 +750,15 @@ impl UndoVec {
     }
 
     fn trim_undo(&mut self) {
-        // Dump redo.
-        while self.idx < self.buf.len() {
-            self.buf.pop();
+        // Dump redo, but keep it as a branch.
+        if self.idx < self.buf.len() {
+            let buf = self.buf.split_off(self.idx);
+            self.branches.push(Branch {
+                idx: self.idx,
+                buf,
+                timestamp: SystemTime::now(),
+            });
+            self.trim_branches();
         }
 
         // cap undo at capacity.
@@ -589,16 +779,48 @@ impl UndoVec {
             // don't drop parts of current sequence at all.
             if self.buf[0].sequence != self.sequence {
                 let drop_sequence = self.buf[0].sequence;
+                let mut n = 0;
                 loop {
                     if self.buf[0].sequence == drop_sequence {
                         self.buf.remove(0);
+                        n += 1;
                     } else {
                         break;
                     }
                 }
+                // branches starting in the dropped part are gone too.
+                self.branches.retain_mut(|v| {
+                    if v.idx >= n {
+                        v.idx -= n;
+                        true
+                    } else {
+                        false
+                    }
+                });
             }
         }
     }
+
+    fn trim_branches(&mut self) {
+        if self.branches.len() > self.branch_count as usize {
+            let n = self.branches.len() - self.branch_count as usize;
+            self.branches.drain(0..n);
+        }
+    }
+
+    /// Undo steps as UndoInfo.
+    fn infos(buf: &[UndoEntry]) -> Vec<UndoInfo> {
+        buf.chunk_by(|a, b| a.sequence == b.sequence)
+            .map(UndoInfo::new)
+            .collect()
+    }
+
+    /// Count the undo steps in the buf up to idx.
+    fn steps(&self, idx: usize) -> usize {
+        self.buf[..idx]
+            .chunk_by(|a, b| a.sequence == b.sequence)
+            .count()
+    }
 }
 
 impl UndoBuffer for UndoVec {
@@ -625,6 +847,7 @@ impl UndoBuffer for UndoVec {
     }
 
     fn append(&mut self, undo: UndoOp) {
+        let timestamp = SystemTime::now();
         let track_undo = if self.track_replay {
             Some(undo.clone())
         } else {
@@ -636,7 +859,7 @@ impl UndoBuffer for UndoVec {
             // first begin starts a new sequence.
             // so this shouldn't cross that boundary.
             if last.sequence == self.sequence {
-                self.try_merge(undo)
+                self.try_merge(undo, timestamp)
             } else {
                 Some(undo)
             }
@@ -659,6 +882,7 @@ impl UndoBuffer for UndoVec {
             self.replay.push(UndoEntry {
                 sequence: self.sequence,
                 operation: track_undo,
+                timestamp,
                 non_exhaustive: NonExhaustive,
             });
         }
@@ -676,6 +900,7 @@ impl UndoBuffer for UndoVec {
             self.buf.push(UndoEntry {
                 sequence: self.sequence,
                 operation: add_undo,
+                timestamp,
                 non_exhaustive: NonExhaustive,
             });
 
@@ -687,6 +912,7 @@ impl UndoBuffer for UndoVec {
         let UndoEntry {
             sequence,
             operation: undo,
+            timestamp,
             ..
         } = undo;
 
@@ -695,7 +921,7 @@ impl UndoBuffer for UndoVec {
             // merges act just like sequences, so this
             // works out for both.
             if last.sequence == sequence {
-                self.try_merge(undo)
+                self.try_merge(undo, timestamp)
             } else {
                 Some(undo)
             }
@@ -719,6 +945,7 @@ impl UndoBuffer for UndoVec {
             self.buf.push(UndoEntry {
                 sequence,
                 operation: add_undo,
+                timestamp,
                 non_exhaustive: NonExhaustive,
             });
 
@@ -732,6 +959,7 @@ impl UndoBuffer for UndoVec {
         self.begin = 0;
         self.sequence = 0;
         self.replay.clear();
+        self.branches.clear();
     }
 
     /// Get next undo
@@ -802,4 +1030,42 @@ impl UndoBuffer for UndoVec {
     fn undo_styles_enabled(&self) -> bool {
         self.undo_styles
     }
+
+    fn entries(&self) -> Vec<UndoInfo> {
+        Self::infos(&self.buf)
+    }
+
+    fn position(&self) -> usize {
+        self.steps(self.idx)
+    }
+
+    fn branches(&self) -> Vec<UndoBranch> {
+        self.branches
+            .iter()
+            .map(|v| UndoBranch {
+                position: self.steps(v.idx),
+                entries: Self::infos(&v.buf),
+                timestamp: v.timestamp,
+                non_exhaustive: NonExhaustive,
+            })
+            .collect()
+    }
+
+    fn restore_branch(&mut self, n: usize) -> bool {
+        if n >= self.branches.len() || self.branches[n].idx != self.idx {
+            return false;
+        }
+
+        let branch = self.branches.remove(n);
+        let current = self.buf.split_off(self.idx);
+        if !current.is_empty() {
+            self.branches.push(Branch {
+                idx: self.idx,
+                buf: current,
+                timestamp: SystemTime::now(),
+            });
+        }
+        self.buf.extend(branch.buf);
+        true
+    }
 }
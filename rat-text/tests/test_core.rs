@@ -1,7 +1,9 @@
 use rat_text::clipboard::LocalClipboard;
 use rat_text::core::{TextCore, TextRope, TextStore};
 use rat_text::undo_buffer::UndoVec;
-use rat_text::{TextPosition, TextRange};
+use rat_text::{TextEdit, TextError, TextPosition, TextRange};
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[test]
 fn test_undo() {
@@ -140,3 +142,94 @@ fn test_undo2() {
     s.redo();
     assert_eq!(s.text().string(), "asdf\nxjklö\nuiop\n");
 }
+
+#[test]
+fn test_apply_edits() {
+    let mut s = TextCore::<TextRope>::new(
+        Some(Box::new(UndoVec::new(40))),
+        Some(Box::new(LocalClipboard::new())),
+    );
+    s.set_text(TextRope::new_text("asdf\njklö\nqwer\n"));
+    s.set_cursor(TextPosition::new(2, 2), false);
+
+    // positions refer to the original text.
+    s.apply_edits(&[
+        TextEdit::new(TextRange::new((1, 0), (3, 0)), "XYZ"),
+        TextEdit::new(TextRange::new((0, 2), (0, 2)), "1"),
+        TextEdit::new(TextRange::new((0, 2), (0, 2)), "2"),
+        TextEdit::new(TextRange::new((4, 0), (0, 1)), ""),
+    ])
+    .unwrap();
+    assert_eq!(s.text().string(), "aXYZfjklö\n12qwer\n");
+    assert_eq!(s.cursor(), TextPosition::new(4, 1));
+
+    // one undo step
+    s.undo();
+    assert_eq!(s.text().string(), "asdf\njklö\nqwer\n");
+    s.redo();
+    assert_eq!(s.text().string(), "aXYZfjklö\n12qwer\n");
+}
+
+#[test]
+fn test_apply_edits_fail() {
+    let mut s = TextCore::<TextRope>::new(
+        Some(Box::new(UndoVec::new(40))),
+        Some(Box::new(LocalClipboard::new())),
+    );
+    s.set_text(TextRope::new_text("asdf\njklö\n"));
+
+    // out of bounds
+    let r = s.apply_edits(&[
+        TextEdit::new(TextRange::new((0, 0), (1, 0)), "x"),
+        TextEdit::new(TextRange::new((0, 7), (1, 7)), "y"),
+    ]);
+    assert!(r.is_err());
+    assert_eq!(s.text().string(), "asdf\njklö\n");
+
+    // overlap
+    let r = s.apply_edits(&[
+        TextEdit::new(TextRange::new((0, 0), (3, 0)), "x"),
+        TextEdit::new(TextRange::new((2, 0), (1, 1)), "y"),
+    ]);
+    assert_eq!(
+        r,
+        Err(TextError::TextRangeOverlap(
+            TextRange::new((0, 0), (3, 0)),
+            TextRange::new((2, 0), (1, 1))
+        ))
+    );
+    assert_eq!(s.text().string(), "asdf\njklö\n");
+}
+
+#[test]
+fn test_change_hook() {
+    let mut s = TextCore::<TextRope>::new(
+        Some(Box::new(UndoVec::new(40))),
+        Some(Box::new(LocalClipboard::new())),
+    );
+    s.set_text(TextRope::new_text("asdf\njklö\n"));
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let log2 = log.clone();
+    s.set_change_hook(Some(Box::new(move |r: TextRange, t: &str| {
+        log2.borrow_mut().push((r, t.to_string()));
+    })));
+
+    s.insert_char(TextPosition::new(1, 1), 'x').unwrap();
+    s.remove_str_range(TextRange::new((0, 0), (2, 0))).unwrap();
+    s.apply_edits(&[TextEdit::new(TextRange::new((0, 1), (1, 1)), "JJ")])
+        .unwrap();
+    s.undo();
+
+    assert_eq!(
+        log.borrow().as_slice(),
+        &[
+            (TextRange::new((1, 1), (1, 1)), "x".to_string()),
+            (TextRange::new((0, 0), (2, 0)), "".to_string()),
+            (TextRange::new((0, 1), (1, 1)), "JJ".to_string()),
+            (TextRange::new((0, 1), (2, 1)), "".to_string()),
+            (TextRange::new((0, 1), (0, 1)), "j".to_string()),
+        ]
+    );
+    assert_eq!(s.text().string(), "df\njxklö\n");
+}
@@ -0,0 +1,65 @@
+use rat_text::text_area::{TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut TextAreaState) {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    TextArea::new().render(area, &mut buf, state);
+}
+
+#[test]
+fn test_append() {
+    let mut state = TextAreaState::new();
+    state.set_text("1\n2\n");
+    state.add_style(0..1, 1);
+    render(&mut state);
+    assert_eq!(state.len_lines(), 3);
+
+    state.append_lines("3\n4\n5\n");
+    assert_eq!(state.text(), "1\n2\n3\n4\n5\n");
+    assert_eq!(state.len_lines(), 6);
+    assert_eq!(state.vertical_max_offset(), 3);
+    assert_eq!(state.styles().collect::<Vec<_>>(), vec![(0..1, 1)]);
+}
+
+#[test]
+fn test_follow() {
+    let mut state = TextAreaState::new();
+    state.set_follow_tail(true);
+    state.set_text("1\n2\n");
+    render(&mut state);
+    assert!(state.is_following());
+
+    state.append_lines("3\n4\n5\n");
+    assert_eq!(state.vertical_offset(), 3);
+    render(&mut state);
+    assert_eq!(state.vertical_offset(), 3);
+
+    // scrolling up disengages.
+    state.scroll_up(1);
+    assert!(!state.is_following());
+    state.append_lines("6\n7\n");
+    assert_eq!(state.vertical_offset(), 2);
+    render(&mut state);
+    assert_eq!(state.vertical_offset(), 2);
+
+    // scrolling to the end engages.
+    state.scroll_down(10);
+    assert!(state.is_following());
+    assert_eq!(state.vertical_offset(), 5);
+    state.append_lines("8\n");
+    assert_eq!(state.vertical_offset(), 6);
+}
+
+#[test]
+fn test_no_follow() {
+    let mut state = TextAreaState::new();
+    state.set_text("1\n2\n");
+    render(&mut state);
+    assert!(!state.is_following());
+
+    state.append_lines("3\n4\n5\n");
+    assert_eq!(state.vertical_offset(), 0);
+}
@@ -0,0 +1,98 @@
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, MouseOnly};
+use rat_text::line_number::{
+    LineMarker, LineNumberOutcome, LineNumberPart, LineNumberState, LineNumbers, MarkerPlacement,
+};
+use rat_text::upos_type;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+use std::collections::HashMap;
+
+fn render(widget: LineNumbers<'_>, state: &mut LineNumberState) -> Vec<String> {
+    let area = Rect::new(0, 0, widget.width(), 5);
+    let mut buf = Buffer::empty(area);
+    widget.render(area, &mut buf, state);
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect()
+}
+
+fn click(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn markers() -> HashMap<upos_type, LineMarker> {
+    let mut markers = HashMap::new();
+    markers.insert(11, LineMarker::Breakpoint);
+    markers.insert(13, LineMarker::Added);
+    markers
+}
+
+#[test]
+fn test_markers() {
+    let markers = markers();
+    let mut state = LineNumberState::new();
+
+    let w = LineNumbers::new().start(10).end(14).markers(&markers);
+    assert_eq!(w.width(), 4);
+    let lines = render(w, &mut state);
+    assert_eq!(lines, vec![" 10 ", "●11 ", " 12 ", "▎13 ", "    "]);
+
+    let w = LineNumbers::new()
+        .start(10)
+        .end(14)
+        .markers(&markers)
+        .marker_placement(MarkerPlacement::After);
+    let lines = render(w, &mut state);
+    assert_eq!(lines, vec!["10  ", "11● ", "12  ", "13▎ ", "    "]);
+}
+
+#[test]
+fn test_relative() {
+    let mut state = LineNumberState::new();
+    let w = LineNumbers::new()
+        .start(10)
+        .end(14)
+        .cursor(12)
+        .relative(true);
+    let lines = render(w, &mut state);
+    assert_eq!(lines, vec![" 2 ", " 1 ", " 0 ", " 1 ", "   "]);
+}
+
+#[test]
+fn test_click() {
+    let markers = markers();
+    let mut state = LineNumberState::new();
+    render(
+        LineNumbers::new().start(10).end(14).markers(&markers),
+        &mut state,
+    );
+
+    assert_eq!(
+        state.handle(&click(0, 1), MouseOnly),
+        LineNumberOutcome::Clicked(11, LineNumberPart::Marker)
+    );
+    assert_eq!(
+        state.handle(&click(2, 2), MouseOnly),
+        LineNumberOutcome::Clicked(12, LineNumberPart::Number)
+    );
+    // beyond the end.
+    assert_eq!(
+        state.handle(&click(0, 4), MouseOnly),
+        LineNumberOutcome::Unchanged
+    );
+    assert_eq!(
+        state.handle(&click(10, 1), MouseOnly),
+        LineNumberOutcome::Continue
+    );
+}
@@ -0,0 +1,141 @@
+use rat_text::text_area::{TextArea, TextAreaState};
+use rat_text::TextPosition;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn state(text: &str) -> TextAreaState {
+    let mut state = TextAreaState::new();
+    state.set_text(text);
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    TextArea::new().render(area, &mut buf, &mut state);
+    state
+}
+
+fn select(state: &mut TextAreaState, anchor: (u32, u32), cursor: (u32, u32)) {
+    state.set_selection(
+        TextPosition::new(anchor.0, anchor.1),
+        TextPosition::new(cursor.0, cursor.1),
+    );
+}
+
+#[test]
+fn test_move_up() {
+    let mut state = state("a\nb\nc\nd");
+    select(&mut state, (0, 1), (1, 2));
+    assert!(state.move_lines_up());
+    assert_eq!(state.text(), "b\nc\na\nd");
+    assert_eq!(state.anchor(), TextPosition::new(0, 0));
+    assert_eq!(state.cursor(), TextPosition::new(1, 1));
+
+    // top edge
+    assert!(!state.move_lines_up());
+    assert_eq!(state.text(), "b\nc\na\nd");
+
+    // one undo step
+    assert!(state.undo());
+    assert_eq!(state.text(), "a\nb\nc\nd");
+}
+
+#[test]
+fn test_move_last_line() {
+    let mut state = state("a\nb\nc");
+    state.set_cursor((1, 2), false);
+    assert!(state.move_lines_up());
+    assert_eq!(state.text(), "a\nc\nb");
+    assert_eq!(state.cursor(), TextPosition::new(1, 1));
+
+    assert!(state.move_lines_down());
+    assert_eq!(state.text(), "a\nb\nc");
+    assert_eq!(state.cursor(), TextPosition::new(1, 2));
+
+    // bottom edge
+    assert!(!state.move_lines_down());
+}
+
+#[test]
+fn test_move_down() {
+    let mut state = state("a\nb\nc\nd\n");
+    // selection ending at the start of a line doesn't include the line.
+    select(&mut state, (0, 0), (0, 2));
+    assert!(state.move_lines_down());
+    assert_eq!(state.text(), "c\na\nb\nd\n");
+    assert_eq!(state.selection().start, TextPosition::new(0, 1));
+    assert_eq!(state.selection().end, TextPosition::new(0, 3));
+
+    assert!(state.undo());
+    assert_eq!(state.text(), "a\nb\nc\nd\n");
+}
+
+#[test]
+fn test_move_styles() {
+    let mut state = state("aa\nbb\ncc");
+    state.add_style(0..2, 1);
+    state.add_style(3..5, 2);
+    state.add_style(6..8, 3);
+    state.set_cursor((0, 0), false);
+    assert!(state.move_lines_down());
+    assert_eq!(state.text(), "bb\naa\ncc");
+
+    let mut styles = state.styles().collect::<Vec<_>>();
+    styles.sort_by_key(|v| v.1);
+    assert_eq!(styles, vec![(3..5, 1), (0..2, 2), (6..8, 3)]);
+}
+
+#[test]
+fn test_duplicate() {
+    let mut state = state("a\nb\nc");
+    select(&mut state, (0, 0), (1, 1));
+    assert!(state.duplicate_lines());
+    assert_eq!(state.text(), "a\nb\na\nb\nc");
+    assert_eq!(state.anchor(), TextPosition::new(0, 2));
+    assert_eq!(state.cursor(), TextPosition::new(1, 3));
+
+    assert!(state.undo());
+    assert_eq!(state.text(), "a\nb\nc");
+}
+
+#[test]
+fn test_duplicate_last_line() {
+    let mut state = state("a\nbb");
+    state.add_style(2..4, 1);
+    state.set_cursor((1, 1), false);
+    assert!(state.duplicate_lines());
+    assert_eq!(state.text(), "a\nbb\nbb");
+    assert_eq!(state.cursor(), TextPosition::new(1, 2));
+
+    let mut styles = state.styles().collect::<Vec<_>>();
+    styles.sort_by_key(|v| v.0.start);
+    assert_eq!(styles, vec![(2..4, 1), (5..7, 1)]);
+}
+
+#[test]
+fn test_toggle_comment() {
+    let mut state = state("  x\n    // y\n\n  z");
+    select(&mut state, (0, 0), (3, 3));
+
+    // mixed, comment all
+    assert!(state.toggle_line_comment("// "));
+    assert_eq!(state.text(), "  // x\n  //   // y\n\n  // z");
+    assert_eq!(state.anchor(), TextPosition::new(0, 0));
+
+    // all commented, uncomment
+    assert!(state.toggle_line_comment("// "));
+    assert_eq!(state.text(), "  x\n    // y\n\n  z");
+
+    assert!(state.undo());
+    assert_eq!(state.text(), "  // x\n  //   // y\n\n  // z");
+}
+
+#[test]
+fn test_uncomment_short_prefix() {
+    let mut state = state("//a\n// b");
+    select(&mut state, (0, 0), (0, 1));
+    assert!(state.toggle_line_comment("// "));
+    assert_eq!(state.text(), "a\n// b");
+
+    state.select_all();
+    assert!(state.toggle_line_comment("// "));
+    assert_eq!(state.text(), "// a\n// // b");
+}
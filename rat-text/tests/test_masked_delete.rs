@@ -0,0 +1,130 @@
+use rat_text::core::MaskedCore;
+use rat_text::InputMode;
+
+const DATETIME: &str = "##\\/##\\/#### ##\\:##";
+
+fn datetime(cursor: u32) -> MaskedCore {
+    let mut m = MaskedCore::new();
+    m.set_mask(DATETIME).expect("ok");
+    m.set_text("12/03/2024 10:30");
+    m.set_cursor(cursor, false);
+    m
+}
+
+#[test]
+fn test_backspace_boundaries() {
+    // (cursor, text after, cursor after)
+    let cases = [
+        (16, "12/03/2024 10: 3", 16),
+        (14, "12/03/2024  1:30", 13),
+        (13, "12/03/2024  1:30", 13),
+        (11, "12/03/ 202 10:30", 10),
+        (10, "12/03/ 202 10:30", 10),
+        (6, "12/ 0/2024 10:30", 5),
+        (5, "12/ 0/2024 10:30", 5),
+        (3, " 1/03/2024 10:30", 2),
+        (2, " 1/03/2024 10:30", 2),
+        (0, "12/03/2024 10:30", 0),
+    ];
+    for (cursor, text, new_cursor) in cases {
+        let mut m = datetime(cursor);
+        m.remove_prev();
+        assert_eq!(m.text(), text, "backspace at {}", cursor);
+        assert_eq!(m.cursor(), new_cursor, "backspace at {}", cursor);
+    }
+}
+
+#[test]
+fn test_delete_boundaries() {
+    // (cursor, text after, cursor after)
+    let cases = [
+        (0, " 2/03/2024 10:30", 1),
+        (2, "12/ 3/2024 10:30", 4),
+        (3, "12/ 3/2024 10:30", 4),
+        (5, "12/03/ 024 10:30", 7),
+        (6, "12/03/ 024 10:30", 7),
+        (10, "12/03/2024  0:30", 12),
+        (11, "12/03/2024  0:30", 12),
+        (13, "12/03/2024 10: 0", 15),
+        (14, "12/03/2024 10: 0", 15),
+        (16, "12/03/2024 10:30", 16),
+    ];
+    for (cursor, text, new_cursor) in cases {
+        let mut m = datetime(cursor);
+        m.remove_next();
+        assert_eq!(m.text(), text, "delete at {}", cursor);
+        assert_eq!(m.cursor(), new_cursor, "delete at {}", cursor);
+    }
+}
+
+#[test]
+fn test_backspace_stays_in_section() {
+    let mut m = datetime(16);
+    m.remove_prev();
+    m.remove_prev();
+    assert_eq!(m.text(), "12/03/2024 10:  ");
+    assert_eq!(m.cursor(), 16);
+
+    // empty section: continue with the previous one.
+    m.remove_prev();
+    assert_eq!(m.text(), "12/03/2024  1:  ");
+    assert_eq!(m.cursor(), 13);
+}
+
+#[test]
+fn test_delete_stays_in_section() {
+    let mut m = datetime(0);
+    m.remove_next();
+    m.remove_next();
+    assert_eq!(m.text(), "  /03/2024 10:30");
+    assert_eq!(m.cursor(), 2);
+
+    // empty section: continue with the next one.
+    m.remove_next();
+    assert_eq!(m.text(), "  / 3/2024 10:30");
+    assert_eq!(m.cursor(), 4);
+}
+
+#[test]
+fn test_clear_all() {
+    let mut m = datetime(16);
+    for _ in 0..12 {
+        m.remove_prev();
+    }
+    assert_eq!(m.text(), "  /  /       :  ");
+    m.remove_prev();
+    assert_eq!(m.cursor(), 0);
+
+    let mut m = datetime(0);
+    for _ in 0..12 {
+        m.remove_next();
+    }
+    assert_eq!(m.text(), "  /  /       :  ");
+    m.remove_next();
+    assert_eq!(m.cursor(), 16);
+}
+
+#[test]
+fn test_input_mode() {
+    let mut m = MaskedCore::new();
+    m.set_mask("llll").expect("ok");
+    m.set_text("ab");
+    assert_eq!(m.input_mode(), InputMode::Insert);
+
+    m.set_cursor(1, false);
+    m.insert_char('x');
+    assert_eq!(m.text(), "axb ");
+    assert_eq!(m.cursor(), 2);
+
+    m.set_input_mode(InputMode::Overtype);
+    m.insert_char('y');
+    assert_eq!(m.text(), "axy ");
+    assert_eq!(m.cursor(), 3);
+
+    let mut m = datetime(0);
+    m.set_input_mode(InputMode::Overtype);
+    m.insert_char('3');
+    assert_eq!(m.text(), "32/03/2024 10:30");
+    m.insert_char('1');
+    assert_eq!(m.text(), "31/03/2024 10:30");
+}
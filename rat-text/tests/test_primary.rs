@@ -0,0 +1,220 @@
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, Regular};
+use rat_text::clipboard::{Clipboard, ClipboardError};
+use rat_text::event::{ReadOnly, TextOutcome};
+use rat_text::text_area::{TextArea, TextAreaState};
+use rat_text::text_input::{TextInput, TextInputState};
+use rat_text::TextPosition;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+use std::sync::{Arc, Mutex};
+
+/// Records all calls to the primary selection.
+#[derive(Debug, Default, Clone)]
+struct MockClipboard {
+    primary: Arc<Mutex<String>>,
+    set_calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockClipboard {
+    fn with_primary(s: &str) -> Self {
+        let clip = Self::default();
+        *clip.primary.lock().expect("lock") = s.to_string();
+        clip
+    }
+
+    fn set_calls(&self) -> Vec<String> {
+        self.set_calls.lock().expect("lock").clone()
+    }
+}
+
+impl Clipboard for MockClipboard {
+    fn get_string(&self) -> Result<String, ClipboardError> {
+        Ok(String::new())
+    }
+
+    fn set_string(&self, _s: &str) -> Result<(), ClipboardError> {
+        Ok(())
+    }
+
+    fn get_primary(&self) -> Result<String, ClipboardError> {
+        Ok(self.primary.lock().expect("lock").clone())
+    }
+
+    fn set_primary(&self, s: &str) -> Result<(), ClipboardError> {
+        *self.primary.lock().expect("lock") = s.to_string();
+        self.set_calls.lock().expect("lock").push(s.to_string());
+        Ok(())
+    }
+}
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn drag_select(column0: u16, column1: u16, row: u16) -> Vec<Event> {
+    let mut events = vec![mouse(MouseEventKind::Down(MouseButton::Left), column0, row)];
+    for c in column0 + 1..=column1 {
+        events.push(mouse(MouseEventKind::Drag(MouseButton::Left), c, row));
+    }
+    events.push(mouse(MouseEventKind::Up(MouseButton::Left), column1, row));
+    events
+}
+
+fn area_state(clip: &MockClipboard, primary: bool) -> TextAreaState {
+    let mut state = TextAreaState::named("area");
+    state.set_clipboard(Some(clip.clone()));
+    state.set_text("hello world\nsecond line\n");
+    state.primary_selection = primary;
+    state.focus.set(true);
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buf = Buffer::empty(area);
+    TextArea::new().render(area, &mut buf, &mut state);
+    state
+}
+
+fn input_state(clip: &MockClipboard, primary: bool) -> TextInputState {
+    let mut state = TextInputState::named("input");
+    state.set_clipboard(Some(clip.clone()));
+    state.set_text("hello world");
+    state.primary_selection = primary;
+    state.focus.set(true);
+    let area = Rect::new(0, 0, 20, 1);
+    let mut buf = Buffer::empty(area);
+    TextInput::new().render(area, &mut buf, &mut state);
+    state
+}
+
+#[test]
+fn test_area_drag_sets_primary_once() {
+    let clip = MockClipboard::default();
+    let mut state = area_state(&clip, true);
+
+    let events = drag_select(0, 5, 0);
+    let (up, steps) = events.split_last().expect("events");
+    for event in steps {
+        state.handle(event, Regular);
+    }
+    assert!(clip.set_calls().is_empty());
+
+    state.handle(up, Regular);
+    assert_eq!(clip.set_calls(), vec!["hello".to_string()]);
+
+    // unrelated events don't repeat the same selection.
+    state.handle(&mouse(MouseEventKind::Moved, 3, 3), Regular);
+    assert_eq!(clip.set_calls().len(), 1);
+}
+
+#[test]
+fn test_area_disabled() {
+    let clip = MockClipboard::with_primary("XY");
+    let mut state = area_state(&clip, false);
+
+    for event in drag_select(0, 5, 0) {
+        state.handle(&event, Regular);
+    }
+    assert!(clip.set_calls().is_empty());
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Middle), 6, 1),
+        Regular,
+    );
+    assert_ne!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "hello world\nsecond line\n");
+}
+
+#[test]
+fn test_area_middle_click_pastes() {
+    let clip = MockClipboard::with_primary("XY");
+    let mut state = area_state(&clip, true);
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Middle), 6, 1),
+        Regular,
+    );
+    assert_eq!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "hello world\nsecondXY line\n");
+    assert_eq!(state.cursor(), TextPosition::new(8, 1));
+}
+
+#[test]
+fn test_area_middle_click_read_only() {
+    let clip = MockClipboard::with_primary("XY");
+    let mut state = area_state(&clip, true);
+    state.set_read_only(true);
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Middle), 6, 1),
+        Regular,
+    );
+    assert_eq!(r, TextOutcome::Changed);
+    assert_eq!(state.text(), "hello world\nsecond line\n");
+    assert_eq!(state.cursor(), TextPosition::new(6, 1));
+}
+
+#[test]
+fn test_area_middle_click_read_only_handler() {
+    let clip = MockClipboard::with_primary("XY");
+    let mut state = area_state(&clip, true);
+
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Middle), 6, 1),
+        ReadOnly,
+    );
+    assert_eq!(state.text(), "hello world\nsecond line\n");
+}
+
+#[test]
+fn test_input_primary() {
+    let clip = MockClipboard::default();
+    let mut state = input_state(&clip, true);
+
+    for event in drag_select(6, 11, 0) {
+        state.handle(&event, Regular);
+    }
+    assert_eq!(clip.set_calls(), vec!["world".to_string()]);
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Middle), 0, 0),
+        Regular,
+    );
+    assert_eq!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "worldhello world");
+}
+
+#[test]
+fn test_input_passwd() {
+    let clip = MockClipboard::default();
+    let mut state = input_state(&clip, true);
+    state.passwd = true;
+
+    for event in drag_select(6, 11, 0) {
+        state.handle(&event, Regular);
+    }
+    assert!(clip.set_calls().is_empty());
+}
+
+#[test]
+fn test_input_middle_click_read_only_handler() {
+    let clip = MockClipboard::with_primary("XY");
+    let mut state = input_state(&clip, true);
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Middle), 0, 0),
+        ReadOnly,
+    );
+    assert_ne!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "hello world");
+
+    // copying the selection is still fine.
+    for event in drag_select(6, 11, 0) {
+        state.handle(&event, ReadOnly);
+    }
+    assert_eq!(clip.set_calls(), vec!["world".to_string()]);
+}
@@ -0,0 +1,137 @@
+use chrono::NaiveDate;
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, Regular};
+use rat_text::date_input::{DateInput, DateInputState};
+use rat_text::event::TextOutcome;
+use rat_text::number_input::{NumberInput, NumberInputState};
+use rat_text::Spin;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16, modifiers: KeyModifiers) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers,
+    })
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("date")
+}
+
+#[test]
+fn test_number_buttons() {
+    let mut state = NumberInputState::new_pattern("####").expect("pattern");
+    state.set_value(5).expect("value");
+    state.set_range(Some(0.0), Some(6.0));
+
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    NumberInput::new()
+        .spin(true)
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(state.spin_up, Rect::new(8, 0, 1, 1));
+    assert_eq!(state.spin_down, Rect::new(9, 0, 1, 1));
+    assert_eq!(state.widget.inner.width, 8);
+    assert_eq!(buf[(8, 0)].symbol(), "▲");
+    assert_eq!(buf[(9, 0)].symbol(), "▼");
+
+    let down = mouse(
+        MouseEventKind::Down(MouseButton::Left),
+        8,
+        0,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&down, Regular), TextOutcome::TextChanged);
+    assert_eq!(state.value::<i32>(), Ok(6));
+    assert_eq!(state.spin_pressed, Some(Spin::Up));
+
+    // clamped
+    assert_eq!(state.spin_repeat(), TextOutcome::Unchanged);
+    assert_eq!(state.value::<i32>(), Ok(6));
+
+    let up = mouse(
+        MouseEventKind::Up(MouseButton::Left),
+        3,
+        3,
+        KeyModifiers::NONE,
+    );
+    assert_eq!(state.handle(&up, Regular), TextOutcome::Changed);
+    assert_eq!(state.spin_pressed, None);
+    assert_eq!(state.spin_repeat(), TextOutcome::Continue);
+}
+
+#[test]
+fn test_number_wheel_wrap() {
+    let mut state = NumberInputState::new_pattern("##").expect("pattern");
+    state.set_value(23).expect("value");
+    state.set_range(Some(0.0), Some(23.0));
+    state.set_wrap(true);
+
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    NumberInput::new()
+        .spin(true)
+        .render(area, &mut buf, &mut state);
+
+    let wheel = mouse(MouseEventKind::ScrollUp, 1, 0, KeyModifiers::NONE);
+    assert_eq!(state.handle(&wheel, Regular), TextOutcome::TextChanged);
+    assert_eq!(state.value::<i32>(), Ok(0));
+
+    let wheel = mouse(MouseEventKind::ScrollDown, 1, 0, KeyModifiers::NONE);
+    assert_eq!(state.handle(&wheel, Regular), TextOutcome::TextChanged);
+    assert_eq!(state.value::<i32>(), Ok(23));
+
+    // outside
+    let wheel = mouse(MouseEventKind::ScrollDown, 1, 1, KeyModifiers::NONE);
+    assert_eq!(state.handle(&wheel, Regular), TextOutcome::Continue);
+}
+
+#[test]
+fn test_no_spin() {
+    let mut state = NumberInputState::new_pattern("####").expect("pattern");
+    state.set_value(5).expect("value");
+
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    NumberInput::new().render(area, &mut buf, &mut state);
+
+    assert_eq!(state.spin_up, Rect::default());
+    assert_eq!(state.widget.inner.width, 10);
+
+    let wheel = mouse(MouseEventKind::ScrollUp, 1, 0, KeyModifiers::NONE);
+    state.handle(&wheel, Regular);
+    assert_eq!(state.value::<i32>(), Ok(5));
+}
+
+#[test]
+fn test_date_spin() {
+    let mut state = DateInputState::new()
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+    state.set_value(date(2024, 12, 31));
+
+    let area = Rect::new(0, 0, 14, 1);
+    let mut buf = Buffer::empty(area);
+    DateInput::new()
+        .spin(true)
+        .render(area, &mut buf, &mut state);
+
+    let wheel = mouse(MouseEventKind::ScrollUp, 1, 0, KeyModifiers::NONE);
+    assert_eq!(state.handle(&wheel, Regular), TextOutcome::TextChanged);
+    assert_eq!(state.value(), Ok(date(2025, 1, 1)));
+
+    let wheel = mouse(MouseEventKind::ScrollDown, 1, 0, KeyModifiers::CONTROL);
+    assert_eq!(state.handle(&wheel, Regular), TextOutcome::TextChanged);
+    assert_eq!(state.value(), Ok(date(2024, 12, 1)));
+
+    state.set_range(Some(date(2024, 12, 1)), Some(date(2024, 12, 31)));
+    assert!(!state.spin(Spin::Down, false));
+    state.set_wrap(true);
+    assert!(state.spin(Spin::Down, false));
+    assert_eq!(state.value(), Ok(date(2024, 12, 31)));
+}
@@ -0,0 +1,163 @@
+use rat_text::core::{TextCore, TextRope, TextStore};
+use rat_text::style_provider::{LineStyleCache, StyleProvider, TodoStyleProvider};
+use rat_text::text_area::{TextArea, TextAreaState};
+use rat_text::{upos_type, TextPosition, TextRange};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Todo provider that records the requests.
+#[derive(Debug, Clone, Default)]
+struct Counting {
+    requests: Rc<RefCell<Vec<Range<upos_type>>>>,
+}
+
+impl StyleProvider for Counting {
+    fn styles_for(&self, lines: Range<upos_type>, text: &TextRope) -> Vec<(TextRange, usize)> {
+        self.requests.borrow_mut().push(lines.clone());
+        TodoStyleProvider::new(0).styles_for(lines, text)
+    }
+}
+
+fn requests(p: &Counting) -> Vec<(upos_type, upos_type)> {
+    p.requests
+        .borrow()
+        .iter()
+        .map(|v| (v.start, v.end))
+        .collect()
+}
+
+fn core(txt: &str) -> TextCore<TextRope> {
+    let mut s = TextCore::<TextRope>::new(None, None);
+    s.set_text(TextRope::new_text(txt));
+    s.set_line_style_cache(Some(LineStyleCache::new()));
+    s
+}
+
+fn fill(s: &mut TextCore<TextRope>, p: &Counting) {
+    let rows = 0..s.len_lines();
+    s.fill_line_styles(rows, |rows, text| p.styles_for(rows, text));
+}
+
+fn styles(s: &TextCore<TextRope>, x: upos_type, y: upos_type) -> Vec<usize> {
+    let mut buf = Vec::new();
+    s.line_styles_at(TextPosition::new(x, y), &mut buf);
+    buf
+}
+
+#[test]
+fn test_todo() {
+    let p = Counting::default();
+    let mut s = core("a TODO b\nnothing\nFIXME ö TODO\n");
+    fill(&mut s, &p);
+
+    assert_eq!(requests(&p), vec![(0, 4)]);
+    assert_eq!(styles(&s, 1, 0), vec![]);
+    assert_eq!(styles(&s, 2, 0), vec![0]);
+    assert_eq!(styles(&s, 5, 0), vec![0]);
+    assert_eq!(styles(&s, 6, 0), vec![]);
+    assert_eq!(styles(&s, 0, 2), vec![0]);
+    assert_eq!(styles(&s, 4, 2), vec![0]);
+    assert_eq!(styles(&s, 8, 2), vec![0]);
+    assert_eq!(styles(&s, 11, 2), vec![0]);
+
+    // all cached
+    fill(&mut s, &p);
+    assert_eq!(p.requests.borrow().len(), 1);
+}
+
+#[test]
+fn test_invalidate_line() {
+    let p = Counting::default();
+    let mut s = core("TODO\nxx\nTODO\nyy\n");
+    fill(&mut s, &p);
+    p.requests.borrow_mut().clear();
+
+    s.insert_str(TextPosition::new(0, 1), "FIXME ").unwrap();
+    fill(&mut s, &p);
+    assert_eq!(requests(&p), vec![(1, 2)]);
+    assert_eq!(styles(&s, 0, 1), vec![0]);
+    assert_eq!(styles(&s, 0, 2), vec![0]);
+}
+
+#[test]
+fn test_invalidate_multiline() {
+    let p = Counting::default();
+    let mut s = core("TODO\nxx\nyy\nzz\nTODO\n");
+    fill(&mut s, &p);
+    p.requests.borrow_mut().clear();
+
+    // insert lines
+    s.insert_str(TextPosition::new(1, 1), "\nTODO\n").unwrap();
+    assert_eq!(s.text().string(), "TODO\nx\nTODO\nx\nyy\nzz\nTODO\n");
+    let cache = s.line_style_cache().expect("cache");
+    assert!(cache.contains(0));
+    assert!(!cache.contains(1));
+    assert!(!cache.contains(2));
+    assert!(!cache.contains(3));
+    assert!(cache.contains(4));
+    assert!(cache.contains(6));
+    assert_eq!(styles(&s, 0, 6), vec![0]);
+    fill(&mut s, &p);
+    assert_eq!(requests(&p), vec![(1, 4)]);
+    assert_eq!(styles(&s, 0, 2), vec![0]);
+    p.requests.borrow_mut().clear();
+
+    // remove lines
+    s.remove_str_range(TextRange::new((0, 2), (1, 4))).unwrap();
+    assert_eq!(s.text().string(), "TODO\nx\ny\nzz\nTODO\n");
+    let cache = s.line_style_cache().expect("cache");
+    assert!(cache.contains(1));
+    assert!(!cache.contains(2));
+    assert!(cache.contains(3));
+    assert_eq!(styles(&s, 0, 4), vec![0]);
+    fill(&mut s, &p);
+    assert_eq!(requests(&p), vec![(2, 3)]);
+}
+
+#[test]
+fn test_capacity() {
+    let p = Counting::default();
+    let mut s = TextCore::<TextRope>::new(None, None);
+    s.set_text(TextRope::new_text(&"TODO\n".repeat(100)));
+    s.set_line_style_cache(Some(LineStyleCache::with_capacity(10)));
+
+    s.fill_line_styles(0..8, |rows, text| p.styles_for(rows, text));
+    s.fill_line_styles(50..58, |rows, text| p.styles_for(rows, text));
+    let cache = s.line_style_cache().expect("cache");
+    assert_eq!(cache.len(), 8);
+    assert!(cache.contains(50));
+    assert!(!cache.contains(0));
+}
+
+#[test]
+fn test_render() {
+    let mut state = TextAreaState::new();
+    state.set_text("ab TODO\n");
+    state.set_style_provider(Some(TodoStyleProvider::new(0)));
+
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    TextArea::new()
+        .text_style([Style::new().fg(Color::Red)])
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(buf[(1, 0)].fg, Color::Reset);
+    assert_eq!(buf[(3, 0)].fg, Color::Red);
+    assert_eq!(buf[(6, 0)].fg, Color::Red);
+    assert_eq!(buf[(7, 0)].fg, Color::Reset);
+
+    // edit and render again
+    state.set_cursor((0, 0), false);
+    state.insert_str("x");
+    let mut buf = Buffer::empty(area);
+    TextArea::new()
+        .text_style([Style::new().fg(Color::Red)])
+        .render(area, &mut buf, &mut state);
+    assert_eq!(buf[(3, 0)].fg, Color::Reset);
+    assert_eq!(buf[(4, 0)].fg, Color::Red);
+}
@@ -0,0 +1,168 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Regular};
+use rat_text::clipboard::LocalClipboard;
+use rat_text::text_area::{SelectionKind, TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+
+const TEXT: &str = "hello world\nhi\nthird line\n";
+
+fn render(state: &mut TextAreaState) -> Buffer {
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buf = Buffer::empty(area);
+    TextArea::new()
+        .select_style(Style::new().bg(Color::Red))
+        .render(area, &mut buf, state);
+    buf
+}
+
+fn block_state(text: &str) -> TextAreaState {
+    let mut state = TextAreaState::named("block");
+    state.set_clipboard(Some(LocalClipboard::new()));
+    state.set_text(text);
+    state.focus.set(true);
+    state.set_selection_kind(SelectionKind::Block);
+    render(&mut state);
+    state
+}
+
+#[test]
+fn test_copy() {
+    let mut state = block_state(TEXT);
+    state.set_selection((1, 0), (4, 2));
+    assert_eq!(state.selected_text(), "ell\ni\nhir");
+
+    state.set_selection_kind(SelectionKind::Stream);
+    assert_eq!(state.selected_text(), "ello world\nhi\nthir");
+}
+
+#[test]
+fn test_render() {
+    let mut state = block_state(TEXT);
+    state.set_selection((1, 0), (4, 2));
+    let buf = render(&mut state);
+
+    let selected = |x: u16, y: u16| buf[(x, y)].bg == Color::Red;
+    assert!(!selected(0, 0));
+    assert!(selected(1, 0));
+    assert!(selected(3, 0));
+    assert!(!selected(4, 0));
+    assert!(selected(1, 1));
+    assert!(!selected(2, 1));
+    assert!(!selected(0, 2));
+    assert!(selected(3, 2));
+    assert!(!selected(4, 2));
+}
+
+#[test]
+fn test_delete() {
+    let mut state = block_state(TEXT);
+    state.set_selection((1, 0), (4, 2));
+    assert!(state.delete_prev_char());
+    assert_eq!(state.text(), "ho world\nh\ntd line\n");
+    assert_eq!(state.cursor(), (1, 0).into());
+    assert!(!state.has_selection());
+    assert_eq!(state.selection_kind(), SelectionKind::Stream);
+
+    // one undo restores everything.
+    assert!(state.undo());
+    assert_eq!(state.text(), TEXT);
+}
+
+#[test]
+fn test_cut_paste() {
+    let mut state = block_state(TEXT);
+    state.set_selection((1, 0), (4, 2));
+    state.copy_to_clip();
+
+    // paste at the end of the first line, pads the short lines.
+    state.set_cursor((11, 0), false);
+    assert!(state.paste_from_clip());
+    assert_eq!(
+        state.text(),
+        "hello worldell\nhi         i\nthird line hir\n"
+    );
+    assert!(state.undo());
+    assert_eq!(state.text(), TEXT);
+
+    // cut and paste back.
+    state.set_selection_kind(SelectionKind::Block);
+    state.set_selection((1, 0), (4, 2));
+    assert!(state.cut_to_clip());
+    assert_eq!(state.text(), "ho world\nh\ntd line\n");
+    assert!(state.paste_from_clip());
+    assert_eq!(state.text(), TEXT);
+
+    // appends missing lines.
+    state.set_cursor((0, 3), false);
+    assert!(state.paste_from_clip());
+    assert_eq!(state.text(), "hello world\nhi\nthird line\nell\ni\nhir");
+}
+
+#[test]
+fn test_stream_paste() {
+    let mut state = block_state(TEXT);
+    state.set_selection_kind(SelectionKind::Stream);
+    state.set_selection((1, 0), (4, 0));
+    state.copy_to_clip();
+    state.set_cursor((0, 1), false);
+    state.paste_from_clip();
+    assert_eq!(state.text(), "hello world\nellhi\nthird line\n");
+}
+
+#[test]
+fn test_display_columns() {
+    let mut state = block_state("\tx\n日本語\nabcdefghij\n");
+    state.set_tab_width(8);
+    assert_eq!(state.display_col((1, 0)), 8);
+    assert_eq!(state.display_col((2, 1)), 4);
+
+    // columns 3..8: the tab starts before, 'x' after the block.
+    // '本' straddles the left border and is not selected.
+    state.set_selection((1, 0), (3, 2));
+    assert_eq!(state.selected_text(), "\n語\ndefgh");
+}
+
+#[test]
+fn test_typing_replaces_block() {
+    let mut state = block_state(TEXT);
+    state.set_selection((1, 0), (4, 2));
+    assert!(state.insert_char('X'));
+    assert_eq!(state.text(), "hXo world\nh\ntd line\n");
+}
+
+#[test]
+fn test_events() {
+    let mut state = block_state(TEXT);
+    state.set_selection_kind(SelectionKind::Stream);
+
+    let alt_b = Event::Key(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT));
+    state.handle(&alt_b, Regular);
+    assert_eq!(state.selection_kind(), SelectionKind::Block);
+    state.handle(&alt_b, Regular);
+    assert_eq!(state.selection_kind(), SelectionKind::Stream);
+
+    let mouse = |kind, x, y| {
+        Event::Mouse(MouseEvent {
+            kind,
+            column: x,
+            row: y,
+            modifiers: KeyModifiers::ALT,
+        })
+    };
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), 1, 0),
+        Regular,
+    );
+    state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), 4, 2),
+        Regular,
+    );
+    state.handle(&mouse(MouseEventKind::Up(MouseButton::Left), 4, 2), Regular);
+    assert_eq!(state.selection_kind(), SelectionKind::Block);
+    assert_eq!(state.selected_text(), "ell\ni\nhir");
+}
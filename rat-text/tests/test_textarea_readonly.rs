@@ -0,0 +1,148 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::{HandleEvent, Regular};
+use rat_text::clipboard::LocalClipboard;
+use rat_text::event::TextOutcome;
+use rat_text::text_area::{TextArea, TextAreaState};
+use rat_text::HasScreenCursor;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::StatefulWidget;
+
+const TEXT: &str = "hello world\nsecond line\nthird\n";
+
+fn render(state: &mut TextAreaState) -> Buffer {
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buf = Buffer::empty(area);
+    TextArea::new().render(area, &mut buf, state);
+    buf
+}
+
+fn read_only_state() -> TextAreaState {
+    let mut state = TextAreaState::named("log");
+    state.set_clipboard(Some(LocalClipboard::new()));
+    state.set_text(TEXT);
+    state.set_read_only(true);
+    state.focus.set(true);
+    render(&mut state);
+    state
+}
+
+/// Every key of the keyboard with every modifier combination.
+fn all_keys() -> Vec<Event> {
+    let mut codes = vec![
+        KeyCode::Backspace,
+        KeyCode::Enter,
+        KeyCode::Left,
+        KeyCode::Right,
+        KeyCode::Up,
+        KeyCode::Down,
+        KeyCode::Home,
+        KeyCode::End,
+        KeyCode::PageUp,
+        KeyCode::PageDown,
+        KeyCode::Tab,
+        KeyCode::BackTab,
+        KeyCode::Delete,
+        KeyCode::Insert,
+        KeyCode::Esc,
+    ];
+    codes.extend((1..=12).map(KeyCode::F));
+    codes.extend((' '..='~').map(KeyCode::Char));
+    codes.push(KeyCode::Char('ä'));
+
+    let modifiers = [
+        KeyModifiers::NONE,
+        KeyModifiers::SHIFT,
+        KeyModifiers::CONTROL,
+        KeyModifiers::ALT,
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        KeyModifiers::ALT | KeyModifiers::SHIFT,
+        KeyModifiers::CONTROL | KeyModifiers::ALT,
+        KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT,
+    ];
+
+    let mut keys = Vec::new();
+    for code in codes {
+        for modifiers in modifiers {
+            keys.push(Event::Key(KeyEvent::new(code, modifiers)));
+        }
+    }
+    keys
+}
+
+#[test]
+fn test_no_binding_edits() {
+    for key in all_keys() {
+        let mut state = read_only_state();
+        state
+            .clipboard()
+            .expect("clip")
+            .set_string("pasted")
+            .expect("clip");
+        state.set_selection((0, 0), (5, 1));
+
+        let r = state.handle(&key, Regular);
+        assert_ne!(r, TextOutcome::TextChanged, "{:?}", key);
+        assert_eq!(state.text(), TEXT, "{:?}", key);
+    }
+}
+
+#[test]
+fn test_edits_consumed() {
+    let mut state = read_only_state();
+    let r = state.handle(&Event::Key(KeyEvent::from(KeyCode::Char('x'))), Regular);
+    assert_eq!(r, TextOutcome::Unchanged);
+    let r = state.handle(
+        &Event::Key(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::CONTROL)),
+        Regular,
+    );
+    assert_eq!(r, TextOutcome::Unchanged);
+
+    // the api still works.
+    state.append_lines("4\n");
+    assert!(state.text().ends_with("third\n4\n"));
+}
+
+#[test]
+fn test_select_copy() {
+    let mut state = read_only_state();
+    let r = state.handle(
+        &Event::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::SHIFT)),
+        Regular,
+    );
+    assert_eq!(r, TextOutcome::Changed);
+    let r = state.handle(
+        &Event::Key(KeyEvent::new(
+            KeyCode::Right,
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        )),
+        Regular,
+    );
+    assert_eq!(r, TextOutcome::Changed);
+    assert_eq!(state.selected_text(), "hello");
+
+    state.handle(
+        &Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+        Regular,
+    );
+    assert_eq!(
+        state.clipboard().expect("clip").get_string().expect("clip"),
+        "hello"
+    );
+}
+
+#[test]
+fn test_cursor() {
+    let mut state = read_only_state();
+    state.set_cursor((2, 1), false);
+    let buf = render(&mut state);
+    assert_eq!(state.screen_cursor(), None);
+    assert!(buf[(2, 1)].modifier.contains(Modifier::UNDERLINED));
+    assert!(!buf[(3, 1)].modifier.contains(Modifier::UNDERLINED));
+
+    state.set_read_only(false);
+    let buf = render(&mut state);
+    assert_eq!(state.screen_cursor(), Some((2, 1)));
+    assert!(!buf[(2, 1)].modifier.contains(Modifier::UNDERLINED));
+}
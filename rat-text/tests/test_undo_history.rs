@@ -0,0 +1,116 @@
+use rat_text::clipboard::LocalClipboard;
+use rat_text::core::{TextCore, TextRope, TextStore};
+use rat_text::undo_buffer::{UndoKind, UndoVec};
+use rat_text::TextPosition;
+
+fn core() -> TextCore<TextRope> {
+    let mut s = TextCore::<TextRope>::new(
+        Some(Box::new(UndoVec::new(40))),
+        Some(Box::new(LocalClipboard::new())),
+    );
+    s.set_text(TextRope::new_text("asdf\n"));
+    s
+}
+
+#[test]
+fn test_entries() {
+    let mut s = core();
+
+    s.insert_char(TextPosition::new(0, 0), 'x').unwrap();
+    s.insert_char(TextPosition::new(0, 0), 'y').unwrap();
+    s.remove_next_char(TextPosition::new(2, 0)).unwrap();
+    assert_eq!(s.text().string(), "yxsdf\n");
+
+    let undo = s.undo_buffer().expect("undo");
+    assert_eq!(undo.position(), 3);
+    let entries = undo.entries();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].kind, UndoKind::Insert);
+    assert_eq!(entries[0].bytes, 0..1);
+    assert_eq!(entries[0].delta, 1);
+    assert_eq!(entries[2].kind, UndoKind::Remove);
+    assert_eq!(entries[2].bytes, 2..3);
+    assert_eq!(entries[2].delta, -1);
+    assert!(entries[0].timestamp <= entries[2].timestamp);
+
+    s.undo();
+    assert_eq!(s.undo_buffer().expect("undo").position(), 2);
+    assert_eq!(s.undo_buffer().expect("undo").entries().len(), 3);
+}
+
+#[test]
+fn test_undo_redo_to() {
+    let mut s = core();
+
+    s.insert_char(TextPosition::new(0, 0), 'x').unwrap();
+    s.insert_char(TextPosition::new(0, 0), 'y').unwrap();
+    s.insert_char(TextPosition::new(0, 0), 'z').unwrap();
+    assert_eq!(s.text().string(), "zyxasdf\n");
+
+    assert!(s.undo_to(1));
+    assert_eq!(s.text().string(), "xasdf\n");
+    assert_eq!(s.undo_buffer().expect("undo").position(), 1);
+
+    // wrong direction
+    assert!(!s.undo_to(2));
+    assert_eq!(s.text().string(), "xasdf\n");
+
+    assert!(s.redo_to(3));
+    assert_eq!(s.text().string(), "zyxasdf\n");
+    assert!(!s.redo_to(10));
+
+    assert!(s.undo_to(0));
+    assert_eq!(s.text().string(), "asdf\n");
+}
+
+#[test]
+fn test_branches() {
+    let mut s = core();
+
+    s.insert_char(TextPosition::new(0, 0), 'x').unwrap();
+    s.insert_char(TextPosition::new(0, 0), 'y').unwrap();
+    s.insert_char(TextPosition::new(0, 0), 'z').unwrap();
+    s.undo_to(1);
+    assert!(s.undo_buffer().expect("undo").branches().is_empty());
+
+    // drops y and z
+    s.insert_str(TextPosition::new(4, 0), "1234").unwrap();
+    assert_eq!(s.text().string(), "xasd1234f\n");
+
+    let branches = s.undo_buffer().expect("undo").branches();
+    assert_eq!(branches.len(), 1);
+    assert_eq!(branches[0].position, 1);
+    assert_eq!(branches[0].entries.len(), 2);
+    assert_eq!(s.undo_buffer().expect("undo").entries().len(), 2);
+
+    assert!(s.restore_redo_branch(0));
+    assert_eq!(s.text().string(), "xasdf\n");
+    assert_eq!(s.undo_buffer().expect("undo").position(), 1);
+
+    // the insert is a branch now.
+    let branches = s.undo_buffer().expect("undo").branches();
+    assert_eq!(branches.len(), 1);
+    assert_eq!(branches[0].position, 1);
+    assert_eq!(branches[0].entries[0].delta, 4);
+
+    s.redo_to(3);
+    assert_eq!(s.text().string(), "zyxasdf\n");
+
+    assert!(!s.restore_redo_branch(1));
+}
+
+#[test]
+fn test_branch_count() {
+    let mut undo = UndoVec::new(40);
+    undo.set_branch_count(1);
+    let mut s =
+        TextCore::<TextRope>::new(Some(Box::new(undo)), Some(Box::new(LocalClipboard::new())));
+    s.set_text(TextRope::new_text("asdf\n"));
+
+    for c in ['a', 'b', 'c'] {
+        s.insert_char(TextPosition::new(0, 0), 'x').unwrap();
+        s.undo();
+        s.insert_char(TextPosition::new(0, 0), c).unwrap();
+    }
+    assert_eq!(s.undo_buffer().expect("undo").branches().len(), 1);
+}
@@ -0,0 +1,107 @@
+use rat_text::date_input::{DateInput, DateInputState};
+use rat_text::number_input::NumberInputState;
+use rat_text::{ValueError, ValueErrorKind};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+
+fn date_state(text: &str) -> DateInputState {
+    let mut state = DateInputState::new()
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+    state.widget.set_text(text);
+    state
+}
+
+#[test]
+fn test_date_ok() {
+    let state = date_state("29.02.2024");
+    assert!(state.value().is_ok());
+}
+
+#[test]
+fn test_date_out_of_range() {
+    let err = date_state("12.13.2024").value().expect_err("error");
+    assert_eq!(err.kind, ValueErrorKind::OutOfRange { field: "month" });
+    assert_eq!(err.range, 3..5);
+    assert_eq!(err.to_string(), "month out of range");
+
+    let err = date_state("32.12.2024").value().expect_err("error");
+    assert_eq!(err.kind, ValueErrorKind::OutOfRange { field: "day" });
+    assert_eq!(err.range, 0..2);
+
+    // no 30th february.
+    let err = date_state("30.02.2024").value().expect_err("error");
+    assert_eq!(err.kind, ValueErrorKind::OutOfRange { field: "day" });
+    assert_eq!(err.range, 0..2);
+}
+
+#[test]
+fn test_date_incomplete() {
+    let err = date_state("12.  .2024").value().expect_err("error");
+    assert_eq!(err.kind, ValueErrorKind::IncompleteSection);
+    assert_eq!(err.range, 3..5);
+    assert_eq!(err.pos(), 3);
+    assert_eq!(err.to_string(), "incomplete input at column 4");
+
+    // cleared shows as 00.00.0000
+    let mut state = date_state("");
+    state.clear();
+    let err = state.value().expect_err("error");
+    assert_eq!(err.kind, ValueErrorKind::OutOfRange { field: "day" });
+    assert_eq!(err.range, 0..2);
+}
+
+#[test]
+fn test_number() {
+    let mut state = NumberInputState::new_pattern("###0.00").expect("pattern");
+    state.set_value(300.5).expect("value");
+    assert_eq!(state.value::<f64>(), Ok(300.5));
+
+    let err = state.value::<u8>().expect_err("error");
+    assert_eq!(err.kind, ValueErrorKind::OutOfRange { field: "number" });
+
+    let state = NumberInputState::new_pattern("####.##").expect("pattern");
+    let err = state.value::<f64>().expect_err("error");
+    assert_eq!(err.kind, ValueErrorKind::IncompleteSection);
+    assert_eq!(err.range, 0..7);
+}
+
+#[test]
+fn test_render_range() {
+    let mut state = date_state("12.13.2024");
+    let err = state.value().expect_err("error");
+    state.set_value_error(Some(&err));
+
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    DateInput::new()
+        .invalid_style(Style::new().bg(Color::Red))
+        .render(area, &mut buf, &mut state);
+
+    let red = (0..10)
+        .filter(|x| buf[(*x, 0)].bg == Color::Red)
+        .collect::<Vec<_>>();
+    assert_eq!(red, vec![3, 4]);
+
+    // the whole text.
+    state.set_invalid(true);
+    state.widget.set_invalid_range(None);
+    DateInput::new()
+        .invalid_style(Style::new().bg(Color::Red))
+        .render(area, &mut buf, &mut state);
+    assert!((0..10).all(|x| buf[(x, 0)].bg == Color::Red));
+
+    state.set_value_error(None);
+    assert!(!state.get_invalid());
+    assert_eq!(state.widget.invalid_range(), None);
+}
+
+#[test]
+fn test_display() {
+    let err = ValueError::new(ValueErrorKind::TrailingInput, 10..12);
+    assert_eq!(err.to_string(), "unexpected input at column 11");
+    let err = ValueError::new(ValueErrorKind::Invalid, 0..4);
+    assert_eq!(err.to_string(), "invalid input at column 1");
+}
@@ -0,0 +1,129 @@
+//!
+//! Map colors for terminals with less than true-color support.
+//!
+
+use crate::as_rgb;
+use ratatui::style::{Color, Style};
+
+/// Color capabilities of a terminal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// The 16 ANSI colors.
+    Ansi16,
+    /// The xterm 256 color palette.
+    Ansi256,
+    /// 24-bit RGB.
+    #[default]
+    TrueColor,
+}
+
+impl From<rat_widget::util::ColorDepth> for ColorDepth {
+    fn from(value: rat_widget::util::ColorDepth) -> Self {
+        match value {
+            rat_widget::util::ColorDepth::Ansi16 => ColorDepth::Ansi16,
+            rat_widget::util::ColorDepth::Ansi256 => ColorDepth::Ansi256,
+            rat_widget::util::ColorDepth::TrueColor => ColorDepth::TrueColor,
+        }
+    }
+}
+
+/// The 16 ANSI colors in palette order.
+const ANSI16: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+impl ColorDepth {
+    /// Map the color to the nearest color available with
+    /// this depth.
+    ///
+    /// * Ansi256 maps RGB colors to the color cube and the
+    ///   grayscale ramp of the xterm palette. Indexed and named
+    ///   colors are left as they are.
+    /// * Ansi16 maps RGB and Indexed colors to the named colors.
+    ///
+    /// The nearest color is searched in the OKLab color space.
+    pub fn map_color(self, color: Color) -> Color {
+        match (self, color) {
+            (_, Color::Reset) => Color::Reset,
+            (ColorDepth::TrueColor, c) => c,
+            (ColorDepth::Ansi256, Color::Rgb(_, _, _)) => {
+                let lab = oklab(as_rgb(color));
+                let idx = (16u8..=255)
+                    .min_by(|a, b| {
+                        let da = distance(lab, oklab(as_rgb(Color::Indexed(*a))));
+                        let db = distance(lab, oklab(as_rgb(Color::Indexed(*b))));
+                        da.total_cmp(&db)
+                    })
+                    .expect("color");
+                Color::Indexed(idx)
+            }
+            (ColorDepth::Ansi256, c) => c,
+            (ColorDepth::Ansi16, Color::Indexed(i)) if i < 16 => ANSI16[i as usize],
+            (ColorDepth::Ansi16, Color::Rgb(_, _, _) | Color::Indexed(_)) => {
+                let lab = oklab(as_rgb(color));
+                ANSI16
+                    .iter()
+                    .copied()
+                    .min_by(|a, b| {
+                        let da = distance(lab, oklab(as_rgb(*a)));
+                        let db = distance(lab, oklab(as_rgb(*b)));
+                        da.total_cmp(&db)
+                    })
+                    .expect("color")
+            }
+            (ColorDepth::Ansi16, c) => c,
+        }
+    }
+
+    /// Map all the colors of the style.
+    pub fn map_style(self, style: Style) -> Style {
+        Style {
+            fg: style.fg.map(|c| self.map_color(c)),
+            bg: style.bg.map(|c| self.map_color(c)),
+            underline_color: style.underline_color.map(|c| self.map_color(c)),
+            ..style
+        }
+    }
+}
+
+/// sRGB to OKLab.
+fn oklab((r, g, b): (u8, u8, u8)) -> (f32, f32, f32) {
+    fn linear(c: u8) -> f32 {
+        let c = c as f32 / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    let (r, g, b) = (linear(r), linear(g), linear(b));
+
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+fn distance(a: (f32, f32, f32), b: (f32, f32, f32)) -> f32 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)
+}
@@ -2,13 +2,15 @@
 //! Implements a dark theme.
 //!
 
-use crate::Scheme;
+use crate::{ColorDepth, Scheme};
 use rat_widget::button::ButtonStyle;
 use rat_widget::calendar::CalendarStyle;
 use rat_widget::checkbox::CheckboxStyle;
 use rat_widget::choice::ChoiceStyle;
 use rat_widget::clipper::ClipperStyle;
 use rat_widget::file_dialog::FileDialogStyle;
+use rat_widget::key_hints::KeyHintsStyle;
+use rat_widget::keybindings::HelpStyle;
 use rat_widget::line_number::LineNumberStyle;
 use rat_widget::list::ListStyle;
 use rat_widget::menu::MenuStyle;
@@ -16,13 +18,17 @@ use rat_widget::msgdialog::MsgDialogStyle;
 use rat_widget::pager::PagerStyle;
 use rat_widget::paragraph::ParagraphStyle;
 use rat_widget::popup::PopupStyle;
+use rat_widget::progress::ProgressStyle;
 use rat_widget::radio::{RadioLayout, RadioStyle};
+use rat_widget::scroll_pane::ScrollPaneStyle;
 use rat_widget::scrolled::ScrollStyle;
 use rat_widget::shadow::{ShadowDirection, ShadowStyle};
 use rat_widget::splitter::SplitStyle;
 use rat_widget::tabbed::TabbedStyle;
 use rat_widget::table::TableStyle;
 use rat_widget::text::TextStyle;
+use rat_widget::toast::ToastStyle;
+use rat_widget::tree::TreeStyle;
 use rat_widget::view::ViewStyle;
 use ratatui::prelude::{Style, Stylize};
 use ratatui::style::Color;
@@ -40,11 +46,38 @@ use std::time::Duration;
 pub struct DarkTheme {
     s: Scheme,
     name: String,
+    depth: ColorDepth,
 }
 
 impl DarkTheme {
     pub fn new(name: String, s: Scheme) -> Self {
-        Self { s, name }
+        Self {
+            s,
+            name,
+            depth: ColorDepth::TrueColor,
+        }
+    }
+
+    /// Restrict the theme to the given color depth.
+    ///
+    /// All colors of the scheme are mapped to the nearest color
+    /// available. As every style of the theme is derived from the
+    /// scheme, this covers the composite widget styles too.
+    ///
+    /// The mapping is lossy, so this should be called on the
+    /// original true-color theme.
+    ///
+    /// Takes the depth detected by rat-salsa too.
+    pub fn degrade_to(mut self, depth: impl Into<ColorDepth>) -> Self {
+        let depth = depth.into();
+        self.s = self.s.degrade_to(depth);
+        self.depth = depth;
+        self
+    }
+
+    /// Color depth of the theme.
+    pub fn color_depth(&self) -> ColorDepth {
+        self.depth
     }
 }
 
@@ -74,13 +107,14 @@ impl DarkTheme {
     /// [Scheme::true_dark_color] to ensure a really dark
     /// color.
     pub fn true_dark_style(&self, bg: Color) -> Style {
-        self.s.style(self.s.true_dark_color(bg))
+        self.s
+            .style(self.depth.map_color(self.s.true_dark_color(bg)))
     }
 
     /// Create a style from a gray-scaled version of
     /// the given colorl.
     pub fn grey_style(&self, bg: Color) -> Style {
-        self.s.style(self.s.grey_color(bg))
+        self.s.style(self.depth.map_color(self.s.grey_color(bg)))
     }
 
     /// Create a style from the given white shade.
@@ -195,6 +229,11 @@ impl DarkTheme {
         self.s.style(self.s.secondary[1])
     }
 
+    /// Disabled style
+    pub fn disabled(&self) -> Style {
+        Style::default().fg(self.s.gray[0])
+    }
+
     /// Text field style.
     pub fn text_input(&self) -> Style {
         self.s.style(self.s.gray[3])
@@ -308,6 +347,11 @@ impl DarkTheme {
         LineNumberStyle {
             style: self.container_base().fg(self.s.gray[1]),
             cursor: Some(self.text_select()),
+            added: Some(self.container_base().fg(self.s.green[2])),
+            modified: Some(self.container_base().fg(self.s.yellow[2])),
+            removed: Some(self.container_base().fg(self.s.red[2])),
+            breakpoint: Some(self.container_base().fg(self.s.red[3])),
+            bookmark: Some(self.container_base().fg(self.s.cyan[2])),
             ..LineNumberStyle::default()
         }
     }
@@ -344,14 +388,25 @@ impl DarkTheme {
         }
     }
 
+    pub fn scroll_pane_style(&self) -> ScrollPaneStyle {
+        ScrollPaneStyle {
+            style: self.container_base(),
+            focus: Some(self.focus()),
+            scroll: Some(self.scroll_style()),
+            ..Default::default()
+        }
+    }
+
     pub fn choice_style(&self) -> ChoiceStyle {
         ChoiceStyle {
             style: self.text_input(),
             select: Some(self.text_focus()),
             focus: Some(self.text_focus()),
+            disabled: Some(self.disabled()),
             popup: PopupStyle {
                 style: self.popup_base(),
                 scroll: Some(self.popup_scroll_style()),
+                shadow: Some(self.shadow_style()),
                 block: Some(
                     Block::bordered()
                         .borders(Borders::LEFT)
@@ -368,6 +423,7 @@ impl DarkTheme {
             layout: Some(RadioLayout::Stacked),
             style: self.text_input(),
             focus: Some(self.text_focus()),
+            disabled: Some(self.disabled()),
             ..Default::default()
         }
     }
@@ -377,6 +433,7 @@ impl DarkTheme {
         CheckboxStyle {
             style: self.text_input(),
             focus: Some(self.text_focus()),
+            disabled: Some(self.disabled()),
             ..Default::default()
         }
     }
@@ -390,11 +447,12 @@ impl DarkTheme {
             select: Some(self.select()),
             focus: Some(self.focus()),
             right: Some(Style::default().fg(self.s.bluegreen[0])),
-            disabled: Some(Style::default().fg(self.s.gray[0])),
+            disabled: Some(self.disabled()),
             highlight: Some(Style::default().underlined()),
             popup: PopupStyle {
                 style: menu,
                 block: Some(Block::bordered()),
+                shadow: Some(self.shadow_style()),
                 ..Default::default()
             },
             ..Default::default()
@@ -408,6 +466,8 @@ impl DarkTheme {
             focus: Some(self.focus()),
             armed: Some(self.select()),
             armed_delay: Some(Duration::from_millis(50)),
+            pressed: Some(self.select()),
+            disabled: Some(self.disabled()),
             ..Default::default()
         }
     }
@@ -419,6 +479,7 @@ impl DarkTheme {
             select_row: Some(self.select()),
             show_row_focus: true,
             focus_style: Some(self.focus()),
+            tooltip: Some(self.popup_base()),
             border_style: Some(self.container_border()),
             scroll: Some(self.scroll_style()),
             ..Default::default()
@@ -444,6 +505,27 @@ impl DarkTheme {
         }
     }
 
+    /// Progress bar and spinner.
+    pub fn progress_style(&self) -> ProgressStyle {
+        ProgressStyle {
+            style: self.container_base(),
+            filled: Some(self.container_base().fg(self.s.primary[2])),
+            label: Some(self.container_base().fg(self.s.white[2])),
+            ..Default::default()
+        }
+    }
+
+    /// Tree style
+    pub fn tree_style(&self) -> TreeStyle {
+        TreeStyle {
+            style: self.container_base(),
+            select: Some(self.select()),
+            focus: Some(self.focus()),
+            scroll: Some(self.scroll_style()),
+            ..Default::default()
+        }
+    }
+
     /// Scroll style
     pub fn scroll_style(&self) -> ScrollStyle {
         ScrollStyle {
@@ -553,6 +635,40 @@ impl DarkTheme {
         }
     }
 
+    /// Toast style.
+    pub fn toast_style(&self) -> ToastStyle {
+        ToastStyle {
+            style: self.dialog_base(),
+            info: Some(self.bluegreen(3)),
+            warning: Some(self.yellow(3)),
+            error: Some(self.red(3)),
+            block: Some(Block::bordered()),
+            ..Default::default()
+        }
+    }
+
+    /// Help overlay style.
+    pub fn help_style(&self) -> HelpStyle {
+        HelpStyle {
+            style: self.dialog_base(),
+            key: Some(self.dialog_base().fg(self.s.secondary[0]).bold()),
+            context: Some(self.dialog_base().fg(self.s.primary[0]).underlined()),
+            filter: Some(self.text_input()),
+            block: Some(Block::bordered().title("Keys")),
+            scroll: Some(self.scroll_style()),
+            ..Default::default()
+        }
+    }
+
+    /// Key-hint bar style.
+    pub fn key_hints_style(&self) -> KeyHintsStyle {
+        KeyHintsStyle {
+            style: self.status_base(),
+            key: Some(self.status_base().fg(self.s.secondary[0]).bold()),
+            ..Default::default()
+        }
+    }
+
     /// Pager style.
     pub fn pager_style(&self) -> PagerStyle {
         PagerStyle {
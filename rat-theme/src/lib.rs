@@ -8,6 +8,7 @@ use ratatui::style::Style;
 
 mod base16;
 mod base16r;
+mod color_depth;
 pub mod dark_theme;
 mod imperial;
 mod monekai;
@@ -18,6 +19,8 @@ mod radium;
 mod tundra;
 mod vscode_dark;
 
+pub use color_depth::ColorDepth;
+
 /// Color schemes
 pub mod scheme {
     pub use crate::base16::BASE16;
@@ -380,6 +383,33 @@ impl Scheme {
 }
 
 impl Scheme {
+    /// Maps all colors of the scheme to the given color depth.
+    ///
+    /// See [ColorDepth::map_color].
+    pub fn degrade_to(&self, depth: impl Into<ColorDepth>) -> Scheme {
+        let depth = depth.into();
+        let map = |c: [Color; 4]| c.map(|c| depth.map_color(c));
+        Scheme {
+            white: map(self.white),
+            black: map(self.black),
+            gray: map(self.gray),
+            red: map(self.red),
+            orange: map(self.orange),
+            yellow: map(self.yellow),
+            limegreen: map(self.limegreen),
+            green: map(self.green),
+            bluegreen: map(self.bluegreen),
+            cyan: map(self.cyan),
+            blue: map(self.blue),
+            deepblue: map(self.deepblue),
+            purple: map(self.purple),
+            magenta: map(self.magenta),
+            redpink: map(self.redpink),
+            primary: map(self.primary),
+            secondary: map(self.secondary),
+        }
+    }
+
     /// Create a style with the given background color.
     /// Foreground is calculated with `text_color`.
     pub fn style(&self, color: Color) -> Style {
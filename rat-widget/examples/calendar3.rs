@@ -216,7 +216,7 @@ fn handle_input(
     let f = focus.handle(event, Regular);
 
     let mut r: Outcome = match state.calendar.handle(event, Regular) {
-        CalOutcome::Selected | CalOutcome::Changed => {
+        CalOutcome::Selected(_) | CalOutcome::Changed => {
             rebuild_cal_style(state);
             Outcome::Changed
         }
@@ -15,6 +15,7 @@ use rat_widget::msgdialog::MsgDialogStyle;
 use rat_widget::pager::PagerStyle;
 use rat_widget::paragraph::ParagraphStyle;
 use rat_widget::radio::{RadioLayout, RadioStyle};
+use rat_widget::scroll_pane::ScrollPaneStyle;
 use rat_widget::shadow::{ShadowDirection, ShadowStyle};
 use rat_widget::slider::SliderStyle;
 use rat_widget::splitter::SplitStyle;
@@ -299,6 +300,15 @@ impl Scheme {
         }
     }
 
+    pub fn scroll_pane_style(&self) -> ScrollPaneStyle {
+        ScrollPaneStyle {
+            style: self.data_base(),
+            focus: Some(self.focus()),
+            scroll: Some(self.scroll_style()),
+            ..Default::default()
+        }
+    }
+
     pub fn choice_style(&self) -> ChoiceStyle {
         ChoiceStyle {
             style: self.text_input(),
@@ -338,6 +348,7 @@ impl Scheme {
             bounds: Some(self.gray(2)),
             knob: Some(self.select()),
             focus: Some(self.focus()),
+            range: Some(self.limegreen(1)),
             text_align: Some(Alignment::Center),
             ..Default::default()
         }
@@ -0,0 +1,146 @@
+#![allow(dead_code)]
+
+use crate::mini_salsa::theme::THEME;
+use crate::mini_salsa::{run_ui, setup_logging, MiniSalsaState};
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, Outcome, Regular};
+use rat_focus::{Focus, FocusBuilder, HasFocus};
+use rat_scrolled::{Scroll, ScrollSync};
+use rat_widget::paragraph::{Paragraph, ParagraphState};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::widgets::{Block, StatefulWidget};
+use ratatui::Frame;
+use std::cmp::max;
+
+mod mini_salsa;
+
+/// The right side has GAP extra lines after line AT.
+const AT: usize = 20;
+const GAP: usize = 5;
+
+fn main() -> Result<(), anyhow::Error> {
+    setup_logging()?;
+
+    let left = (0..100)
+        .map(|n| format!("{:3} same old line", n))
+        .collect::<Vec<_>>();
+    let mut right = left.clone();
+    for n in 0..GAP {
+        right.insert(AT + n, format!("  + inserted line {}", n));
+    }
+
+    let mut data = Data {
+        left: left.join("\n"),
+        right: right.join("\n"),
+    };
+
+    let mut state = State {
+        left: Default::default(),
+        right: Default::default(),
+        sync: ScrollSync::new().map(|from, _to, offset| {
+            if from == 0 {
+                if offset > AT {
+                    offset + GAP
+                } else {
+                    offset
+                }
+            } else {
+                if offset > AT + GAP {
+                    offset - GAP
+                } else {
+                    offset.min(AT)
+                }
+            }
+        }),
+    };
+
+    run_ui(
+        "scroll_sync1",
+        handle_text,
+        repaint_text,
+        &mut data,
+        &mut state,
+    )
+}
+
+struct Data {
+    left: String,
+    right: String,
+}
+
+struct State {
+    left: ParagraphState,
+    right: ParagraphState,
+    sync: ScrollSync,
+}
+
+fn repaint_text(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    data: &mut Data,
+    istate: &mut MiniSalsaState,
+    state: &mut State,
+) -> Result<(), anyhow::Error> {
+    let l = Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)])
+        .spacing(1)
+        .split(area);
+
+    Paragraph::new(data.left.as_str())
+        .vscroll(Scroll::new())
+        .block(Block::bordered().title("old"))
+        .styles(THEME.paragraph_style())
+        .render(l[0], frame.buffer_mut(), &mut state.left);
+    Paragraph::new(data.right.as_str())
+        .vscroll(Scroll::new())
+        .block(Block::bordered().title("new"))
+        .styles(THEME.paragraph_style())
+        .render(l[1], frame.buffer_mut(), &mut state.right);
+
+    istate.status[0] = format!(
+        "Ctrl-Q to quit. F2 lock/unlock [{}]",
+        if state.sync.is_locked() {
+            "locked"
+        } else {
+            "unlocked"
+        }
+    );
+
+    Ok(())
+}
+
+fn focus(state: &State) -> Focus {
+    let mut fb = FocusBuilder::default();
+    fb.widget(&state.left).widget(&state.right);
+    fb.build()
+}
+
+fn handle_text(
+    event: &crossterm::event::Event,
+    _data: &mut Data,
+    _istate: &mut MiniSalsaState,
+    state: &mut State,
+) -> Result<Outcome, anyhow::Error> {
+    let f = focus(state).handle(event, Regular);
+
+    let r = state.left.handle(event, Regular);
+    let r = r.or_else(|| state.right.handle(event, Regular));
+    let r = r.or_else(|| match event {
+        ct_event!(keycode press F(2)) => {
+            if state.sync.toggle_locked() {
+                // align with the focused side.
+                let n = if state.right.is_focused() { 1 } else { 0 };
+                state
+                    .sync
+                    .sync_from(n, &mut [&mut state.left.vscroll, &mut state.right.vscroll]);
+            }
+            Outcome::Changed
+        }
+        _ => Outcome::Continue,
+    });
+
+    // one sync after all the event-handling.
+    let s = state
+        .sync
+        .sync(&mut [&mut state.left.vscroll, &mut state.right.vscroll]);
+
+    Ok(max(f, max(r, s)))
+}
@@ -184,14 +184,9 @@ fn repaint_input(
 fn focus(state: &State) -> Focus {
     let mut fb = FocusBuilder::default();
     fb.widget(&state.tabbed);
-    if let Some(sel) = state.tabbed.selected {
-        match sel {
-            0 => _ = fb.widget(&state.tabs_0),
-            1 => _ = fb.widget(&state.tabs_1),
-            2 => _ = fb.widget(&state.tabs_2),
-            _ => {}
-        }
-    }
+    fb.widget_if(state.tabbed.is_active(0), &state.tabs_0);
+    fb.widget_if(state.tabbed.is_active(1), &state.tabs_1);
+    fb.widget_if(state.tabbed.is_active(2), &state.tabs_2);
     fb.widget(&state.menu);
     fb.build()
 }
@@ -11,7 +11,7 @@ use pure_rust_locales::Locale::de_AT_euro;
 use rat_event::{ConsumedEvent, HandleEvent, Outcome, Regular};
 use rat_focus::{match_focus, FocusBuilder, FocusFlag, HasFocus};
 use rat_ftable::edit::vec::{EditableTableVec, EditableTableVecState, TableDataVec};
-use rat_ftable::edit::{TableEditor, TableEditorState};
+use rat_ftable::edit::{EditorValidate, TableEditor, TableEditorState};
 use rat_ftable::textdata::{Cell, Row};
 use rat_ftable::{Table, TableContext, TableData};
 use rat_scrolled::Scroll;
@@ -304,6 +304,8 @@ impl SampleEditorState {
     }
 }
 
+impl EditorValidate for SampleEditorState {}
+
 impl TableEditorState for SampleEditorState {
     type Context<'a> = &'a MiniSalsaState;
     type Value = Sample;
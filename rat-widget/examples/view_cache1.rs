@@ -0,0 +1,150 @@
+#![allow(dead_code)]
+
+//!
+//! Ten views with a paragraph each. With the render cache
+//! enabled only the views whose content changed are rendered
+//! again, compare the render time in the status line.
+//!
+//! F2 toggles the cache, F3 changes the text of the first view.
+//!
+
+use crate::mini_salsa::theme::THEME;
+use crate::mini_salsa::{layout_grid, run_ui, setup_logging, MiniSalsaState};
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, Outcome, Regular};
+use rat_scrolled::Scroll;
+use rat_widget::paragraph::{Paragraph, ParagraphState};
+use rat_widget::view::{View, ViewState};
+use ratatui::layout::{Constraint, Layout, Rect, Size};
+use ratatui::widgets::{Block, StatefulWidget, Wrap};
+use ratatui::Frame;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+mod mini_salsa;
+
+fn main() -> Result<(), anyhow::Error> {
+    setup_logging()?;
+
+    let mut data = Data {
+        text: (0..10)
+            .map(|n| format!("Pane {}\n\n{}", n, SAMPLE.repeat(8)))
+            .collect(),
+        revision: [0; 10],
+    };
+
+    let mut state = State {
+        cache: true,
+        view: Default::default(),
+        para: Default::default(),
+    };
+
+    run_ui(
+        "view_cache1",
+        handle_text,
+        repaint_text,
+        &mut data,
+        &mut state,
+    )
+}
+
+struct Data {
+    text: Vec<String>,
+    revision: [u64; 10],
+}
+
+struct State {
+    cache: bool,
+    view: [ViewState; 10],
+    para: [ParagraphState; 10],
+}
+
+fn cache_key(data: &Data, state: &State, n: usize) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    data.revision[n].hash(&mut hasher);
+    state.para[n].vscroll.offset().hash(&mut hasher);
+    state.para[n].hscroll.offset().hash(&mut hasher);
+    hasher.finish()
+}
+
+fn repaint_text(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    data: &mut Data,
+    istate: &mut MiniSalsaState,
+    state: &mut State,
+) -> Result<(), anyhow::Error> {
+    istate.status[0] = format!(
+        "Ctrl-Q to quit. F2 cache {} | F3 change text",
+        if state.cache { "on" } else { "off" }
+    );
+
+    let l: [[Rect; 2]; 5] = layout_grid(
+        area,
+        Layout::horizontal([Constraint::Fill(1); 5]),
+        Layout::vertical([Constraint::Fill(1); 2]),
+    );
+
+    for n in 0..10 {
+        let pane = l[n % 5][n / 5];
+
+        let mut view = View::new()
+            .layout(Rect::new(0, 0, 60, 40))
+            .view_size(Size::new(60, 40))
+            .vscroll(Scroll::new().style(THEME.block()))
+            .block(Block::bordered().style(THEME.block()));
+        if state.cache {
+            view = view.cache_key(cache_key(data, state, n));
+        }
+        let mut view_buf = view.into_buffer(pane, &mut state.view[n]);
+
+        view_buf.render_stateful(
+            Paragraph::new(data.text[n].as_str())
+                .wrap(Wrap::default())
+                .style(THEME.limegreen(0))
+                .scroll(Scroll::new().style(THEME.block())),
+            Rect::new(0, 0, 60, 40),
+            &mut state.para[n],
+        );
+
+        view_buf
+            .into_widget()
+            .render(pane, frame.buffer_mut(), &mut state.view[n]);
+    }
+
+    Ok(())
+}
+
+fn handle_text(
+    event: &crossterm::event::Event,
+    data: &mut Data,
+    _istate: &mut MiniSalsaState,
+    state: &mut State,
+) -> Result<Outcome, anyhow::Error> {
+    let mut r = Outcome::Continue;
+    for n in 0..10 {
+        r = r.or_else(|| state.para[n].handle(event, Regular));
+        r = r.or_else(|| state.view[n].handle(event, Regular));
+    }
+
+    r = r.or_else(|| match event {
+        ct_event!(keycode press F(2)) => {
+            state.cache = !state.cache;
+            Outcome::Changed
+        }
+        ct_event!(keycode press F(3)) => {
+            data.revision[0] += 1;
+            data.text[0] = format!(
+                "Pane 0 revision {}\n\n{}",
+                data.revision[0],
+                SAMPLE.repeat(8)
+            );
+            Outcome::Changed
+        }
+        _ => Outcome::Continue,
+    });
+
+    Ok(r)
+}
+
+static SAMPLE: &str = "Lorem ipsum dolor sit amet, consetetur sadipscing elitr, sed diam nonumy eirmod tempor invidunt ut labore et dolore magna aliquyam erat, sed diam voluptua. At vero eos et accusam et justo duo dolores et ea rebum. Stet clita kasd gubergren, no sea takimata sanctus est Lorem ipsum dolor sit amet.
+";
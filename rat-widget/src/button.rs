@@ -19,18 +19,23 @@
 //! }
 //! ```
 //!
+//! A button in toggle mode keeps its pressed state until it is
+//! pressed again, and reports [ButtonOutcome::Toggled].
+//! Use [ButtonGroupState](crate::button_group::ButtonGroupState) for a group of toggle buttons where
+//! only one can be pressed at a time.
+//!
 
 use crate::_private::NonExhaustive;
 use crate::button::event::ButtonOutcome;
 use crate::util::{block_size, revert_style};
 use rat_event::util::{have_keyboard_enhancement, MouseFlags};
 use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Regular};
-use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::BlockExt;
-use ratatui::style::Style;
+use ratatui::style::{Style, Stylize};
 use ratatui::text::Text;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
@@ -47,6 +52,8 @@ pub struct Button<'a> {
     hover_style: Option<Style>,
     armed_style: Option<Style>,
     armed_delay: Option<Duration>,
+    pressed_style: Option<Style>,
+    disabled_style: Option<Style>,
     block: Option<Block<'a>>,
 }
 
@@ -61,6 +68,10 @@ pub struct ButtonStyle {
     pub armed: Option<Style>,
     /// Hover style
     pub hover: Option<Style>,
+    /// Style for a toggled button.
+    pub pressed: Option<Style>,
+    /// Style for a disabled button.
+    pub disabled: Option<Style>,
     /// Button border
     pub block: Option<Block<'static>>,
     /// Some terminals repaint too fast to see the click.
@@ -93,6 +104,17 @@ pub struct ButtonState {
     /// Default is 50ms.
     pub armed_delay: Option<Duration>,
 
+    /// Button works as a toggle button.
+    /// __read+write__
+    pub toggle: bool,
+    /// Toggle button is pressed.
+    /// __read+write__
+    pub toggled: bool,
+    /// Button is disabled. It ignores all events and
+    /// is skipped by the focus.
+    /// __read+write__
+    pub disabled: bool,
+
     /// Current focus state.
     /// __read+write__
     pub focus: FocusFlag,
@@ -109,6 +131,8 @@ impl Default for ButtonStyle {
             focus: None,
             armed: None,
             hover: None,
+            pressed: None,
+            disabled: None,
             block: None,
             armed_delay: None,
             non_exhaustive: NonExhaustive,
@@ -147,6 +171,12 @@ impl<'a> Button<'a> {
         if styles.hover.is_some() {
             self.hover_style = styles.hover;
         }
+        if styles.pressed.is_some() {
+            self.pressed_style = styles.pressed;
+        }
+        if styles.disabled.is_some() {
+            self.disabled_style = styles.disabled;
+        }
         if let Some(block) = styles.block {
             self.block = Some(block);
         }
@@ -168,6 +198,14 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Style when disabled.
+    /// This is patched onto the button. Defaults to dim.
+    #[inline]
+    pub fn disabled_style(mut self, style: impl Into<Style>) -> Self {
+        self.disabled_style = Some(style.into());
+        self
+    }
+
     /// Style when clicked but not released.
     #[inline]
     pub fn armed_style(mut self, style: impl Into<Style>) -> Self {
@@ -183,6 +221,13 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Style for a toggled button.
+    #[inline]
+    pub fn pressed_style(mut self, style: impl Into<Style>) -> Self {
+        self.pressed_style = Some(style.into());
+        self
+    }
+
     /// Style for hover over the button.
     pub fn hover_style(mut self, style: impl Into<Style>) -> Self {
         self.hover_style = Some(style.into());
@@ -271,19 +316,36 @@ fn render_ref(widget: &Button<'_>, area: Rect, buf: &mut Buffer, state: &mut But
         buf.set_style(area, widget.style);
     }
 
-    if state.mouse.hover.get() && widget.hover_style.is_some() {
+    let armed_area = Rect::new(
+        state.inner.x + 1,
+        state.inner.y,
+        state.inner.width.saturating_sub(2),
+        state.inner.height,
+    );
+
+    let focused = state.is_focused() && !state.disabled;
+    let hover = state.mouse.hover.get() && widget.hover_style.is_some() && !state.disabled;
+    if hover {
         buf.set_style(state.inner, widget.hover_style.expect("style"))
-    } else if state.is_focused() {
+    } else if focused {
         buf.set_style(state.inner, focus_style);
     }
 
+    if state.toggle && state.toggled {
+        let pressed_style = if let Some(pressed_style) = widget.pressed_style {
+            pressed_style
+        } else {
+            revert_style(widget.style)
+        };
+        // leave the focus/hover visible at the edges.
+        if hover || focused {
+            buf.set_style(armed_area, pressed_style);
+        } else {
+            buf.set_style(state.inner, pressed_style);
+        }
+    }
+
     if state.armed {
-        let armed_area = Rect::new(
-            state.inner.x + 1,
-            state.inner.y,
-            state.inner.width.saturating_sub(2),
-            state.inner.height,
-        );
         buf.set_style(armed_area, armed_style);
     }
 
@@ -291,6 +353,13 @@ fn render_ref(widget: &Button<'_>, area: Rect, buf: &mut Buffer, state: &mut But
     let r = state.inner.height.saturating_sub(h) / 2;
     let area = Rect::new(state.inner.x, state.inner.y + r, state.inner.width, h);
     (&widget.text).render(area, buf);
+
+    if state.disabled {
+        buf.set_style(
+            state.area,
+            widget.disabled_style.unwrap_or(Style::new().dim()),
+        );
+    }
 }
 
 impl Clone for ButtonState {
@@ -301,6 +370,9 @@ impl Clone for ButtonState {
             hover_enabled: false,
             armed: self.armed,
             armed_delay: self.armed_delay,
+            toggle: self.toggle,
+            toggled: self.toggled,
+            disabled: self.disabled,
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -316,6 +388,9 @@ impl Default for ButtonState {
             hover_enabled: false,
             armed: false,
             armed_delay: None,
+            toggle: false,
+            toggled: false,
+            disabled: false,
             focus: Default::default(),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -334,6 +409,66 @@ impl ButtonState {
             ..Default::default()
         }
     }
+
+    /// New toggle button.
+    pub fn named_toggle(name: &str) -> Self {
+        Self {
+            toggle: true,
+            focus: FocusFlag::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// Disable the button.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.armed = false;
+        }
+    }
+
+    /// Button is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Button works as a toggle button.
+    pub fn set_toggle(&mut self, toggle: bool) {
+        self.toggle = toggle;
+    }
+
+    /// Button works as a toggle button.
+    pub fn is_toggle(&self) -> bool {
+        self.toggle
+    }
+
+    /// Toggle button is pressed.
+    pub fn toggled(&self) -> bool {
+        self.toggled
+    }
+
+    /// Set the pressed state of a toggle button.
+    pub fn set_toggled(&mut self, toggled: bool) -> bool {
+        let old_value = self.toggled;
+        self.toggled = toggled;
+        old_value != toggled
+    }
+
+    /// Flip the pressed state of a toggle button.
+    pub fn flip_toggled(&mut self) {
+        self.toggled = !self.toggled;
+    }
+
+    /// Result of a button press.
+    /// Toggles the button if it is a toggle button.
+    fn pressed(&mut self) -> ButtonOutcome {
+        if self.toggle {
+            self.flip_toggled();
+            ButtonOutcome::Toggled(self.toggled)
+        } else {
+            ButtonOutcome::Pressed
+        }
+    }
 }
 
 impl HasFocus for ButtonState {
@@ -350,6 +485,14 @@ impl HasFocus for ButtonState {
     fn area(&self) -> Rect {
         self.area
     }
+
+    fn navigable(&self) -> Navigation {
+        if self.disabled {
+            Navigation::None
+        } else {
+            Navigation::Regular
+        }
+    }
 }
 
 impl RelocatableState for ButtonState {
@@ -364,7 +507,7 @@ pub(crate) mod event {
 
     /// Result value for event-handling.
     ///
-    /// Adds `Pressed` to the general Outcome.
+    /// Adds `Pressed`, `Toggled` and `Selected` to the general Outcome.
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum ButtonOutcome {
         /// The given event was not handled at all.
@@ -375,6 +518,11 @@ pub(crate) mod event {
         Changed,
         /// Button has been pressed.
         Pressed,
+        /// Toggle button has been pressed. Is the new pressed state.
+        Toggled(bool),
+        /// A button of a [ButtonGroupState](crate::button_group::ButtonGroupState)
+        /// has been pressed. Is the index of the button.
+        Selected(usize),
     }
 
     impl ConsumedEvent for ButtonOutcome {
@@ -390,6 +538,8 @@ pub(crate) mod event {
                 ButtonOutcome::Unchanged => Outcome::Unchanged,
                 ButtonOutcome::Changed => Outcome::Changed,
                 ButtonOutcome::Pressed => Outcome::Changed,
+                ButtonOutcome::Toggled(_) => Outcome::Changed,
+                ButtonOutcome::Selected(_) => Outcome::Changed,
             }
         }
     }
@@ -397,6 +547,9 @@ pub(crate) mod event {
 
 impl HandleEvent<crossterm::event::Event, Regular, ButtonOutcome> for ButtonState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> ButtonOutcome {
+        if self.disabled {
+            return ButtonOutcome::Continue;
+        }
         let r = if self.is_focused() {
             // Release keys may not be available.
             if have_keyboard_enhancement() {
@@ -411,7 +564,7 @@ impl HandleEvent<crossterm::event::Event, Regular, ButtonOutcome> for ButtonStat
                                 thread::sleep(delay);
                             }
                             self.armed = false;
-                            ButtonOutcome::Pressed
+                            self.pressed()
                         } else {
                             // single key release happen more often than not.
                             ButtonOutcome::Unchanged
@@ -421,9 +574,7 @@ impl HandleEvent<crossterm::event::Event, Regular, ButtonOutcome> for ButtonStat
                 }
             } else {
                 match event {
-                    ct_event!(keycode press Enter) | ct_event!(key press ' ') => {
-                        ButtonOutcome::Pressed
-                    }
+                    ct_event!(keycode press Enter) | ct_event!(key press ' ') => self.pressed(),
                     _ => ButtonOutcome::Continue,
                 }
             }
@@ -441,6 +592,9 @@ impl HandleEvent<crossterm::event::Event, Regular, ButtonOutcome> for ButtonStat
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, ButtonOutcome> for ButtonState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> ButtonOutcome {
+        if self.disabled {
+            return ButtonOutcome::Continue;
+        }
         match event {
             ct_event!(mouse down Left for column, row) => {
                 if self.area.contains((*column, *row).into()) {
@@ -454,7 +608,7 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, ButtonOutcome> for ButtonSt
                 if self.area.contains((*column, *row).into()) {
                     if self.armed {
                         self.armed = false;
-                        ButtonOutcome::Pressed
+                        self.pressed()
                     } else {
                         ButtonOutcome::Continue
                     }
@@ -484,6 +638,9 @@ impl HandleEvent<crossterm::event::Event, crossterm::event::KeyEvent, ButtonOutc
     ) -> ButtonOutcome {
         use crossterm::event::Event;
 
+        if self.disabled {
+            return ButtonOutcome::Continue;
+        }
         let r = match event {
             Event::Key(key) => {
                 // Release keys may not be available.
@@ -498,7 +655,7 @@ impl HandleEvent<crossterm::event::Event, crossterm::event::KeyEvent, ButtonOutc
                                     thread::sleep(delay);
                                 }
                                 self.armed = false;
-                                ButtonOutcome::Pressed
+                                self.pressed()
                             } else {
                                 // single key release happen more often than not.
                                 ButtonOutcome::Unchanged
@@ -512,7 +669,7 @@ impl HandleEvent<crossterm::event::Event, crossterm::event::KeyEvent, ButtonOutc
                 } else {
                     if hotkey.code == key.code && hotkey.modifiers == key.modifiers {
                         if key.kind == crossterm::event::KeyEventKind::Press {
-                            ButtonOutcome::Pressed
+                            self.pressed()
                         } else {
                             ButtonOutcome::Continue
                         }
@@ -0,0 +1,343 @@
+//!
+//! Group of toggle buttons with exclusive selection.
+//!
+//! Works like a radio group, but shows the options as buttons.
+//! Pressing one of them un-toggles all the others.
+//!
+//! The group is one Tab stop, the arrow keys move between the
+//! buttons and Space/Enter presses the current one.
+//! Rendering stays per button, use [ButtonGroupState::item_mut]
+//! to get the state for each button. This updates the
+//! highlight of the button too.
+//!
+//! ```rust ignore
+//! use rat_widget::button::Button;
+//! use rat_widget::button_group::ButtonGroupState;
+//! use ratatui::widgets::StatefulWidget;
+//!
+//! for (n, text) in ["Left", "Center", "Right"].iter().enumerate() {
+//!     Button::new(*text)
+//!         .styles(THEME.button_style())
+//!         .render(layout[n], frame.buffer_mut(), state.align.item_mut(n));
+//! }
+//!
+//! match state.align.handle(event, Regular) {
+//!     ButtonOutcome::Selected(n) => { /* new alignment */ }
+//!     ...
+//! }
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use crate::button::event::ButtonOutcome;
+use crate::button::ButtonState;
+use crate::util::union_non_empty;
+use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_reloc::RelocatableState;
+use ratatui::layout::Rect;
+
+/// State for a group of toggle buttons.
+///
+/// The group is registered with the focus as a single widget.
+/// The FocusFlags of the buttons are set by the group and
+/// should not be added to a Focus themselves.
+#[derive(Debug)]
+pub struct ButtonGroupState {
+    /// State of the buttons.
+    /// __read+write__ use [ButtonGroupState::select] to change
+    /// the pressed button.
+    pub items: Vec<ButtonState>,
+    /// Current button for keyboard navigation.
+    /// This is kept when the group loses the focus, so
+    /// the same button is highlighted when it regains the focus.
+    /// __read+write__
+    pub current: Option<usize>,
+    /// The pressed button.
+    /// __read only__ use [ButtonGroupState::select].
+    pub selected: Option<usize>,
+
+    /// Current focus state.
+    /// __read+write__
+    pub focus: FocusFlag,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Clone for ButtonGroupState {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            current: self.current,
+            selected: self.selected,
+            focus: FocusFlag::named(self.focus.name()),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Default for ButtonGroupState {
+    fn default() -> Self {
+        Self {
+            items: Default::default(),
+            current: None,
+            selected: None,
+            focus: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl HasFocus for ButtonGroupState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.leaf_widget(self);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    /// Union of all button areas.
+    fn area(&self) -> Rect {
+        self.items
+            .iter()
+            .fold(Rect::default(), |a, v| union_non_empty(a, v.area))
+    }
+}
+
+impl RelocatableState for ButtonGroupState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        for item in self.items.iter_mut() {
+            item.relocate(shift, clip);
+        }
+    }
+}
+
+impl ButtonGroupState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New group with the given number of buttons.
+    pub fn named(name: &str, len: usize) -> Self {
+        Self {
+            items: (0..len)
+                .map(|_| {
+                    let mut item = ButtonState::new();
+                    item.set_toggle(true);
+                    item
+                })
+                .collect(),
+            current: if len > 0 { Some(0) } else { None },
+            focus: FocusFlag::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// Number of buttons.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Any buttons.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Add a button. It is switched to toggle mode.
+    pub fn push(&mut self, mut item: ButtonState) {
+        item.set_toggle(true);
+        item.set_toggled(false);
+        self.items.push(item);
+        if self.current.is_none() {
+            self.current = Some(0);
+        }
+    }
+
+    /// Button state.
+    pub fn item(&self, n: usize) -> &ButtonState {
+        &self.items[n]
+    }
+
+    /// Button state for rendering.
+    /// This sets the focus flag of the button, so it
+    /// is shown highlighted if it is the current button.
+    pub fn item_mut(&mut self, n: usize) -> &mut ButtonState {
+        self.items[n]
+            .focus
+            .set(self.focus.get() && self.current == Some(n));
+        &mut self.items[n]
+    }
+
+    /// The pressed button.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Press a button and release all others.
+    pub fn select(&mut self, select: usize) -> bool {
+        if select >= self.items.len() {
+            return false;
+        }
+        let old_selected = self.selected;
+        self.selected = Some(select);
+        for (n, item) in self.items.iter_mut().enumerate() {
+            item.set_toggled(n == select);
+        }
+        old_selected != self.selected
+    }
+
+    /// Release all buttons.
+    pub fn clear_selection(&mut self) -> bool {
+        let old_selected = self.selected;
+        self.selected = None;
+        for item in self.items.iter_mut() {
+            item.set_toggled(false);
+        }
+        old_selected.is_some()
+    }
+
+    /// Current button for keyboard navigation.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Set the current button.
+    pub fn set_current(&mut self, current: usize) -> bool {
+        let old_current = self.current;
+        if current < self.items.len() {
+            self.current = Some(current);
+        }
+        old_current != self.current
+    }
+
+    /// Next button. Stops at the last one.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        match self.current {
+            Some(n) => self.set_current(n + 1),
+            None => self.set_current(0),
+        }
+    }
+
+    /// Previous button. Stops at the first one.
+    pub fn prev(&mut self) -> bool {
+        match self.current {
+            Some(n) => self.set_current(n.saturating_sub(1)),
+            None => self.set_current(0),
+        }
+    }
+
+    /// Press the current button.
+    pub fn select_current(&mut self) -> bool {
+        if let Some(n) = self.current {
+            self.select(n)
+        } else {
+            false
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, ButtonOutcome> for ButtonGroupState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> ButtonOutcome {
+        let r = if self.is_focused() {
+            match event {
+                ct_event!(keycode press Left) | ct_event!(keycode press Up) => {
+                    if self.prev() {
+                        ButtonOutcome::Changed
+                    } else {
+                        ButtonOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press Right) | ct_event!(keycode press Down) => {
+                    if self.next() {
+                        ButtonOutcome::Changed
+                    } else {
+                        ButtonOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press Home) => {
+                    if self.set_current(0) {
+                        ButtonOutcome::Changed
+                    } else {
+                        ButtonOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press End) => {
+                    if self.set_current(self.items.len().saturating_sub(1)) {
+                        ButtonOutcome::Changed
+                    } else {
+                        ButtonOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press Enter) | ct_event!(key press ' ') => {
+                    if self.select_current() {
+                        ButtonOutcome::Selected(self.current.expect("current"))
+                    } else {
+                        ButtonOutcome::Unchanged
+                    }
+                }
+                _ => ButtonOutcome::Continue,
+            }
+        } else {
+            ButtonOutcome::Continue
+        };
+
+        if r == ButtonOutcome::Continue {
+            HandleEvent::handle(self, event, MouseOnly)
+        } else {
+            r
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, ButtonOutcome> for ButtonGroupState {
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> ButtonOutcome {
+        let mut r = ButtonOutcome::Continue;
+        let mut pressed = None;
+        for (n, item) in self.items.iter_mut().enumerate() {
+            match item.handle(event, MouseOnly) {
+                ButtonOutcome::Continue => {}
+                ButtonOutcome::Toggled(_) => pressed = Some(n),
+                ButtonOutcome::Changed => r = ButtonOutcome::Changed,
+                v => {
+                    if r == ButtonOutcome::Continue {
+                        r = v;
+                    }
+                }
+            }
+        }
+
+        if let Some(n) = pressed {
+            self.set_current(n);
+            if self.select(n) {
+                ButtonOutcome::Selected(n)
+            } else {
+                // the pressed button stays pressed.
+                self.items[n].set_toggled(true);
+                ButtonOutcome::Changed
+            }
+        } else {
+            r
+        }
+    }
+}
+
+/// Handle all events.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut ButtonGroupState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> ButtonOutcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(
+    state: &mut ButtonGroupState,
+    event: &crossterm::event::Event,
+) -> ButtonOutcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
@@ -12,19 +12,22 @@
 //! There is a Calendar3 widget that can display 3 months in a line/column.
 //! Use this as a baseline for other layouts.
 //!
+//! And there is the Months widget that renders N months in a grid.
+//!
 //! There are 3 selection-models that can be used:
 //! - [NoSelection](selection::NoSelection)  Scrolling is still possible.
 //! - [SingleSelection](selection::SingleSelection) Selection of a single day.
 //! - [RangeSelection](selection::RangeSelection) Selection of any date range.
 //!
 
-use chrono::{Datelike, Days, Months, NaiveDate};
+use chrono::{Datelike, Days, NaiveDate};
 
 #[allow(clippy::module_inception)]
 mod calendar;
 mod calendar3;
 pub(crate) mod event;
 mod month;
+mod months;
 mod no_selection;
 mod range_selection;
 mod single_selection;
@@ -33,6 +36,7 @@ mod style;
 pub use calendar::*;
 pub use calendar3::*;
 pub use month::*;
+pub use months::*;
 pub use style::*;
 
 /// Selection model for a calendar.
@@ -86,7 +90,7 @@ fn first_day_of_month(date: NaiveDate) -> NaiveDate {
 }
 
 fn last_day_of_month(date: NaiveDate) -> NaiveDate {
-    date.with_day(1).expect("date") + Months::new(1) - Days::new(1)
+    date.with_day(1).expect("date") + chrono::Months::new(1) - Days::new(1)
 }
 
 fn is_same_month(start: NaiveDate, end: NaiveDate) -> bool {
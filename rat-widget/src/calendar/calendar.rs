@@ -427,7 +427,7 @@ impl<const N: usize> CalendarState<N, SingleSelection> {
         let mut r = CalOutcome::Changed;
 
         if self.selection.borrow_mut().select(date) {
-            r = CalOutcome::Selected;
+            r = CalOutcome::Selected(date);
         }
         match self.home {
             TodayPolicy::Index(primary) => {
@@ -495,12 +495,12 @@ impl<const N: usize> CalendarState<N, SingleSelection> {
 
         if new_date >= base_start && new_date <= base_end {
             if self.selection.borrow_mut().select(new_date) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         } else if self.step > 0 {
             r = r.max(self.scroll_back(self.step));
             if self.selection.borrow_mut().select(new_date) {
-                r = r.max(CalOutcome::Selected);
+                r = r.max(CalOutcome::Selected(new_date));
             }
         }
 
@@ -532,12 +532,12 @@ impl<const N: usize> CalendarState<N, SingleSelection> {
 
         if new_date >= base_start && new_date <= base_end {
             if self.selection.borrow_mut().select(new_date) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         } else if self.step > 0 {
             r = self.scroll_forward(self.step);
             if self.selection.borrow_mut().select(new_date) {
-                r = r.max(CalOutcome::Selected);
+                r = r.max(CalOutcome::Selected(new_date));
             }
         }
 
@@ -606,7 +606,7 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
         let mut r = CalOutcome::Changed;
 
         if self.selection.borrow_mut().select_day(date, false) {
-            r = CalOutcome::Selected;
+            r = CalOutcome::Selected(date);
         }
         match self.home {
             TodayPolicy::Index(primary) => {
@@ -647,12 +647,12 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
         if new_date >= base_start && new_date <= base_end {
             if self.selection.borrow_mut().select_day(new_date, false) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         } else if self.step > 0 {
             r = r.max(self.scroll_back(self.step));
             if self.selection.borrow_mut().select_day(new_date, false) {
-                r = r.max(CalOutcome::Selected);
+                r = r.max(CalOutcome::Selected(new_date));
             }
         }
 
@@ -685,12 +685,12 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
         if new_date >= base_start && new_date <= base_end {
             if self.selection.borrow_mut().select_day(new_date, false) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         } else if self.step > 0 {
             r = self.scroll_forward(self.step);
             if self.selection.borrow_mut().select_day(new_date, false) {
-                r = r.max(CalOutcome::Selected);
+                r = r.max(CalOutcome::Selected(new_date));
             }
         }
 
@@ -721,18 +721,18 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
             if new_date >= base_start && new_date <= base_end {
                 if self.selection.borrow_mut().select_day(new_date, extend) {
-                    r = CalOutcome::Selected;
+                    r = CalOutcome::Selected(new_date);
                 }
             } else if self.step > 0 {
                 r = self.scroll_back(self.step);
                 if self.selection.borrow_mut().select_day(new_date, extend) {
-                    r = CalOutcome::Selected;
+                    r = CalOutcome::Selected(new_date);
                 }
             }
         } else {
             let new_date = self.end_date();
             if self.selection.borrow_mut().select_day(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         }
 
@@ -766,12 +766,12 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
         if new_date >= base_start && new_date <= base_end {
             if self.selection.borrow_mut().select_day(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         } else if self.step > 0 {
             r = self.scroll_forward(self.step);
             if self.selection.borrow_mut().select_day(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         }
 
@@ -804,18 +804,18 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
             if new_date_end >= base_start && new_date_end <= base_end {
                 if self.selection.borrow_mut().select_week(new_date, extend) {
-                    r = CalOutcome::Selected;
+                    r = CalOutcome::Selected(new_date);
                 }
             } else if self.step > 0 {
                 r = self.scroll_back(self.step);
                 if self.selection.borrow_mut().select_week(new_date, extend) {
-                    r = CalOutcome::Selected;
+                    r = CalOutcome::Selected(new_date);
                 }
             }
         } else {
             let new_date = self.end_date();
             if self.selection.borrow_mut().select_week(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         }
 
@@ -850,12 +850,12 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
         if new_date >= base_start && new_date <= base_end {
             if self.selection.borrow_mut().select_week(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         } else if self.step > 0 {
             r = self.scroll_forward(self.step);
             if self.selection.borrow_mut().select_week(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         }
 
@@ -887,18 +887,18 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
             if new_date >= base_start && new_date <= base_end {
                 if self.selection.borrow_mut().select_month(new_date, extend) {
-                    r = CalOutcome::Selected;
+                    r = CalOutcome::Selected(new_date);
                 }
             } else if self.step > 0 {
                 r = self.scroll_back(self.step);
                 if self.selection.borrow_mut().select_month(new_date, extend) {
-                    r = CalOutcome::Selected;
+                    r = CalOutcome::Selected(new_date);
                 }
             }
         } else {
             let new_date = self.end_date();
             if self.selection.borrow_mut().select_month(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         }
 
@@ -933,12 +933,12 @@ impl<const N: usize> CalendarState<N, RangeSelection> {
 
         if new_date >= base_start && new_date <= base_end {
             if self.selection.borrow_mut().select_month(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         } else if self.step > 0 {
             r = self.scroll_forward(self.step);
             if self.selection.borrow_mut().select_month(new_date, extend) {
-                r = CalOutcome::Selected;
+                r = CalOutcome::Selected(new_date);
             }
         }
 
@@ -1,5 +1,5 @@
 use crate::calendar::{CalendarSelection, CalendarState, CalendarStyle, Month};
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Weekday};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Direction, Rect};
 use ratatui::style::Style;
@@ -87,6 +87,24 @@ impl<'a, Selection> Calendar3<'a, Selection> {
         self
     }
 
+    /// Show the column with the ISO week numbers.
+    #[inline]
+    pub fn show_weeknum(mut self, show: bool) -> Self {
+        for i in 0..3 {
+            self.months[i] = mem::take(&mut self.months[i]).show_weeknum(show);
+        }
+        self
+    }
+
+    /// First day of the week.
+    #[inline]
+    pub fn first_weekday(mut self, first: Weekday) -> Self {
+        for i in 0..3 {
+            self.months[i] = mem::take(&mut self.months[i]).first_weekday(first);
+        }
+        self
+    }
+
     /// Set the composite style.
     #[inline]
     pub fn styles(mut self, s: CalendarStyle) -> Self {
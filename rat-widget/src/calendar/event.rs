@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use rat_event::{ConsumedEvent, Outcome};
 
 /// Result of event handling.
@@ -13,7 +14,10 @@ pub enum CalOutcome {
     /// Rendering the ui is advised.
     Changed,
     /// The selection has changed.
-    Selected,
+    ///
+    /// Contains the date that has been selected. For a range selection
+    /// this is the date that was moved to or clicked.
+    Selected(NaiveDate),
 }
 
 impl ConsumedEvent for CalOutcome {
@@ -38,7 +42,7 @@ impl From<CalOutcome> for Outcome {
             CalOutcome::Continue => Outcome::Continue,
             CalOutcome::Unchanged => Outcome::Unchanged,
             CalOutcome::Changed => Outcome::Changed,
-            CalOutcome::Selected => Outcome::Changed,
+            CalOutcome::Selected(_) => Outcome::Changed,
         }
     }
 }
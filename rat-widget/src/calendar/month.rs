@@ -26,6 +26,9 @@ use std::rc::Rc;
 ///
 /// Renders one month of a calendar.
 ///
+/// The first day of the week can be set with `first_weekday()`.
+/// The ISO week-number column can be switched off with `show_weeknum()`.
+///
 /// There is movement and selection support within the month,
 /// but no scrolling. Use a Calendar for full calendar features.
 ///
@@ -42,8 +45,8 @@ use std::rc::Rc;
 ///
 /// ```rust ignore
 /// match state.month1.handle(event, Regular) {
-///     CalOutcome::Selected => {
-///         // doit
+///     CalOutcome::Selected(date) => {
+///         // doit with date
 ///         Outcome::Changed
 ///     }
 ///     r => r.into()
@@ -78,6 +81,10 @@ pub struct Month<'a, Selection> {
     show_month: bool,
     /// Show Weekdays above
     show_weekdays: bool,
+    /// Show ISO week numbers
+    show_weeknum: bool,
+    /// First day of the week.
+    first_weekday: Weekday,
 
     /// Block
     block: Option<Block<'a>>,
@@ -110,6 +117,10 @@ pub struct MonthState<Selection = SingleSelection> {
     /// __readonly__. renewed for each render.
     pub area_weeks: [Rect; 6],
 
+    /// First day of the week.
+    /// __readonly__. renewed for each render.
+    pub first_weekday: Weekday,
+
     /// Startdate
     start_date: NaiveDate,
 
@@ -146,6 +157,8 @@ impl<Selection> Default for Month<'_, Selection> {
             focus_style: Default::default(),
             show_month: true,
             show_weekdays: true,
+            show_weeknum: true,
+            first_weekday: Weekday::Mon,
             block: Default::default(),
             loc: Default::default(),
             phantom: PhantomData,
@@ -188,6 +201,25 @@ impl<'a, Selection> Month<'a, Selection> {
         self
     }
 
+    /// Show the column with the ISO week numbers.
+    ///
+    /// Default is true.
+    #[inline]
+    pub fn show_weeknum(mut self, show: bool) -> Self {
+        self.show_weeknum = show;
+        self
+    }
+
+    /// First day of the week. This is used for the weekday
+    /// titles and the layout of the grid.
+    ///
+    /// Default is Monday.
+    #[inline]
+    pub fn first_weekday(mut self, first: Weekday) -> Self {
+        self.first_weekday = first;
+        self
+    }
+
     /// Set the composite style.
     #[inline]
     pub fn styles(mut self, s: CalendarStyle) -> Self {
@@ -282,7 +314,8 @@ impl<'a, Selection> Month<'a, Selection> {
     /// Inherent width of the widget.
     #[inline]
     pub fn width(&self) -> u16 {
-        8 * 3 + block_size(&self.block).width
+        let w = if self.show_weeknum { 3 } else { 0 };
+        7 * 3 + w + block_size(&self.block).width
     }
 
     /// Inherent height for the widget.
@@ -295,7 +328,7 @@ impl<'a, Selection> Month<'a, Selection> {
             state.start_date
         };
 
-        let r = MonthState::<Selection>::count_weeks(start_date) as u16;
+        let r = MonthState::<Selection>::count_weeks_from(start_date, self.first_weekday) as u16;
         let w = if self.show_weekdays { 1 } else { 0 };
         let b = max(1, block_size(&self.block).height);
         r + w + b
@@ -394,16 +427,18 @@ fn render_ref<Selection: CalendarSelection>(
     state.inner = block.inner(area);
     block.render(area, buf);
 
+    state.first_weekday = widget.first_weekday;
+    let weeknum_width = if widget.show_weeknum { 3 } else { 0 };
+
     let month = day.month();
-    let mut w = 0;
     let mut x = state.inner.x;
     let mut y = state.inner.y;
 
     // week days
     if widget.show_weekdays {
-        let mut week_0 = day.week(Weekday::Mon).first_day();
+        let mut week_0 = day.week(widget.first_weekday).first_day();
 
-        x += 3;
+        x += weeknum_width;
         buf.set_style(Rect::new(x, y, 3 * 7, 1), weekday_style);
         for _ in 0..7 {
             let area = Rect::new(x, y, 2, 1).intersection(state.inner);
@@ -425,69 +460,28 @@ fn render_ref<Selection: CalendarSelection>(
     for i in 0..6 {
         state.area_weeks[i] = Rect::default();
     }
-    state.area_cal = Rect::new(x + 3, y, 7 * 3, state.week_len() as u16);
-    state.area_weeknum = Rect::new(x, y, 3, state.week_len() as u16);
-
-    // first line may omit a few days
-    state.area_weeks[w] = Rect::new(x, y, 2, 1).intersection(state.inner);
-    Span::from(day.format_localized("%V", widget.loc).to_string())
-        .style(week_style)
-        .render(state.area_weeks[w], buf);
-
-    x += 3;
-
-    for wd in [
-        Weekday::Mon,
-        Weekday::Tue,
-        Weekday::Wed,
-        Weekday::Thu,
-        Weekday::Fri,
-        Weekday::Sat,
-        Weekday::Sun,
-    ] {
-        if day.weekday() != wd {
-            x += 3;
-        } else {
-            let day_style = calc_day_style(widget, state, day, day_style, select_style);
-            state.area_days[day.day0() as usize] = Rect::new(x, y, 2, 1).intersection(state.inner);
-
-            Span::from(day.format_localized("%e", widget.loc).to_string())
-                .style(day_style)
-                .render(state.area_days[day.day0() as usize], buf);
-
-            if wd != Weekday::Sun && state.selection.is_selected(day + Days::new(1)) {
-                let mut gap_area = state.area_days[day.day0() as usize];
-                gap_area.x += 2;
-                gap_area.width = 1;
-                Span::from(" ").style(day_style).render(gap_area, buf);
-            }
-
-            x += 3;
-            day = day + Days::new(1);
-        }
-    }
-
-    w += 1;
-    x = state.inner.x;
-    y += 1;
-
-    while month == day.month() {
-        state.area_weeks[w] = Rect::new(x, y, 2, 1).intersection(state.inner);
-        Span::from(day.format_localized("%V", widget.loc).to_string())
+    state.area_cal = Rect::new(x + weeknum_width, y, 7 * 3, state.week_len() as u16);
+    state.area_weeknum = Rect::new(x, y, weeknum_width, state.week_len() as u16);
+
+    // the first and last line may contain days of other months.
+    let mut week_start = day.week(widget.first_weekday).first_day();
+    for w in 0..state.week_len() {
+        if widget.show_weeknum {
+            state.area_weeks[w] = Rect::new(x, y, 2, 1).intersection(state.inner);
+            Span::from(
+                state
+                    .week_date(w)
+                    .format_localized("%V", widget.loc)
+                    .to_string(),
+            )
             .style(week_style)
             .render(state.area_weeks[w], buf);
+        }
 
-        x += 3;
-
-        for wd in [
-            Weekday::Mon,
-            Weekday::Tue,
-            Weekday::Wed,
-            Weekday::Thu,
-            Weekday::Fri,
-            Weekday::Sat,
-            Weekday::Sun,
-        ] {
+        x += weeknum_width;
+
+        for wd in 0..7 {
+            day = week_start + Days::new(wd);
             if day.month() == month {
                 let day_style = calc_day_style(widget, state, day, day_style, select_style);
 
@@ -498,21 +492,17 @@ fn render_ref<Selection: CalendarSelection>(
                     .style(day_style)
                     .render(state.area_days[day.day0() as usize], buf);
 
-                if wd != Weekday::Sun && state.selection.is_selected(day + Days::new(1)) {
+                if wd != 6 && state.selection.is_selected(day + Days::new(1)) {
                     let mut gap_area = state.area_days[day.day0() as usize];
                     gap_area.x += 2;
                     gap_area.width = 1;
                     Span::from(" ").style(day_style).render(gap_area, buf);
                 }
-
-                x += 3;
-                day = day + Days::new(1);
-            } else {
-                x += 3;
             }
+            x += 3;
         }
 
-        w += 1;
+        week_start = week_start + Days::new(7);
         x = state.inner.x;
         y += 1;
     }
@@ -586,6 +576,7 @@ where
             area_days: self.area_days.clone(),
             area_weeknum: self.area_weeknum.clone(),
             area_weeks: self.area_weeks.clone(),
+            first_weekday: self.first_weekday,
             start_date: self.start_date,
             selection: self.selection.clone(),
             container: self.container.clone(),
@@ -608,6 +599,7 @@ where
             area_days: Default::default(),
             area_weeknum: Default::default(),
             area_weeks: Default::default(),
+            first_weekday: Weekday::Mon,
             start_date: Default::default(),
             selection: Default::default(),
             container: Default::default(),
@@ -662,36 +654,31 @@ impl<Selection> MonthState<Selection> {
 
     /// Nr of weeks in this month.
     pub fn week_len(&self) -> usize {
-        Self::count_weeks(self.start_date)
+        Self::count_weeks_from(self.start_date, self.first_weekday)
     }
 
-    /// Nr of weeks for the given month
+    /// A date in the n-th week of the month, that has the
+    /// ISO week number shown for this week.
+    ///
+    /// With a first weekday other than Monday a line in the
+    /// calendar spans two ISO weeks. The Thursday decides.
+    pub fn week_date(&self, n: usize) -> NaiveDate {
+        let week_start = self.start_date.week(self.first_weekday).first_day();
+        let thursday = days_from(self.first_weekday, Weekday::Thu);
+        week_start + Days::new(7 * n as u64 + thursday)
+    }
+
+    /// Nr of weeks for the given month, starting weeks with Monday.
     pub fn count_weeks(day: NaiveDate) -> usize {
-        let mut day = day.with_day0(0).expect("date");
-        let month = day.month();
-
-        let mut weeks = 1;
-        for weekday in [
-            Weekday::Mon,
-            Weekday::Tue,
-            Weekday::Wed,
-            Weekday::Thu,
-            Weekday::Fri,
-            Weekday::Sat,
-            Weekday::Sun,
-        ] {
-            // run through first week
-            if day.weekday() == weekday {
-                day = day + Days::new(1);
-            }
-        }
-        // count mondays
-        while month == day.month() {
-            weeks += 1;
-            day = day + Days::new(7);
-        }
+        Self::count_weeks_from(day, Weekday::Mon)
+    }
 
-        weeks
+    /// Nr of weeks for the given month and first day of the week.
+    pub fn count_weeks_from(day: NaiveDate, first_weekday: Weekday) -> usize {
+        let start = first_day_of_month(day);
+        let end = last_day_of_month(day);
+        let lead = days_from(first_weekday, start.weekday());
+        (lead as usize + end.day() as usize).div_ceil(7)
     }
 
     // is there a container for this month?
@@ -725,7 +712,7 @@ impl MonthState<SingleSelection> {
     pub fn select_day(&mut self, n: usize) -> CalOutcome {
         if let Some(date) = self.start_date.with_day0(n as u32) {
             if self.selection.borrow_mut().select(date) {
-                CalOutcome::Selected
+                CalOutcome::Selected(date)
             } else {
                 CalOutcome::Continue
             }
@@ -738,7 +725,7 @@ impl MonthState<SingleSelection> {
     pub fn select_last(&mut self) -> CalOutcome {
         let date = self.end_date();
         if self.selection.borrow_mut().select(date) {
-            CalOutcome::Selected
+            CalOutcome::Selected(date)
         } else {
             CalOutcome::Continue
         }
@@ -780,7 +767,7 @@ impl MonthState<SingleSelection> {
 
         if self.in_range(date) {
             if self.selection.borrow_mut().select(date) {
-                CalOutcome::Selected
+                CalOutcome::Selected(date)
             } else {
                 CalOutcome::Continue
             }
@@ -808,7 +795,7 @@ impl MonthState<SingleSelection> {
 
         if self.in_range(date) {
             if self.selection.borrow_mut().select(date) {
-                CalOutcome::Selected
+                CalOutcome::Selected(date)
             } else {
                 CalOutcome::Continue
             }
@@ -827,9 +814,9 @@ impl MonthState<RangeSelection> {
     /// Select a week by index.
     pub fn select_week(&mut self, n: usize, extend: bool) -> CalOutcome {
         if n < self.week_len() {
-            let date = self.start_date() + Days::new(7 * n as u64);
+            let date = self.week_date(n);
             if self.selection.borrow_mut().select_week(date, extend) {
-                CalOutcome::Selected
+                CalOutcome::Selected(date)
             } else {
                 CalOutcome::Continue
             }
@@ -842,7 +829,7 @@ impl MonthState<RangeSelection> {
     pub fn select_day(&mut self, n: usize, extend: bool) -> CalOutcome {
         if let Some(date) = self.start_date.with_day0(n as u32) {
             if self.selection.borrow_mut().select_day(date, extend) {
-                CalOutcome::Selected
+                CalOutcome::Selected(date)
             } else {
                 CalOutcome::Continue
             }
@@ -855,7 +842,7 @@ impl MonthState<RangeSelection> {
     pub fn select_last(&mut self, extend: bool) -> CalOutcome {
         let date = self.end_date();
         if self.selection.borrow_mut().select_day(date, extend) {
-            CalOutcome::Selected
+            CalOutcome::Selected(date)
         } else {
             CalOutcome::Continue
         }
@@ -915,7 +902,7 @@ impl MonthState<RangeSelection> {
 
         if self.in_range(date) {
             if self.selection.borrow_mut().select_day(date, extend) {
-                CalOutcome::Selected
+                CalOutcome::Selected(date)
             } else {
                 CalOutcome::Continue
             }
@@ -943,7 +930,7 @@ impl MonthState<RangeSelection> {
 
         if self.in_range(date) {
             if self.selection.borrow_mut().select_day(date, extend) {
-                CalOutcome::Selected
+                CalOutcome::Selected(date)
             } else {
                 CalOutcome::Continue
             }
@@ -968,7 +955,7 @@ impl MonthState<RangeSelection> {
             let new_date_end = new_date.week(Weekday::Mon).last_day();
             if new_date_end >= base_start && new_date_end <= base_end {
                 if self.selection.borrow_mut().select_week(new_date, extend) {
-                    CalOutcome::Selected
+                    CalOutcome::Selected(new_date)
                 } else {
                     CalOutcome::Continue
                 }
@@ -978,7 +965,7 @@ impl MonthState<RangeSelection> {
         } else {
             let new_date = self.end_date();
             if self.selection.borrow_mut().select_week(new_date, extend) {
-                CalOutcome::Selected
+                CalOutcome::Selected(new_date)
             } else {
                 CalOutcome::Continue
             }
@@ -1005,7 +992,7 @@ impl MonthState<RangeSelection> {
 
         if new_date >= start && new_date <= end {
             if self.selection.borrow_mut().select_week(new_date, extend) {
-                CalOutcome::Selected
+                CalOutcome::Selected(new_date)
             } else {
                 CalOutcome::Continue
             }
@@ -1014,3 +1001,8 @@ impl MonthState<RangeSelection> {
         }
     }
 }
+
+/// Days from the weekday `from` to the next `to`.
+fn days_from(from: Weekday, to: Weekday) -> u64 {
+    (7 + to.num_days_from_monday() as u64 - from.num_days_from_monday() as u64) % 7
+}
@@ -0,0 +1,241 @@
+use crate::calendar::{CalendarSelection, CalendarState, CalendarStyle, Month};
+use chrono::{NaiveDate, Weekday};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, StatefulWidget};
+use std::array;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::mem;
+
+///
+/// Calendar with N months on display.
+///
+/// The months are laid out in a grid with the given number
+/// of columns. All months share the selection of the
+/// [CalendarState], keyboard navigation flows from one
+/// month to the next and scrolls the calendar if necessary.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use chrono::{Local, Weekday};
+/// # use pure_rust_locales::Locale;
+/// # use ratatui::buffer::Buffer;
+/// # use ratatui::prelude::Rect;
+/// # use ratatui::widgets::StatefulWidget;
+/// # use rat_widget::calendar::{Months, CalendarState, CalendarStyle, TodayPolicy};
+/// # use rat_widget::calendar::selection::SingleSelection;
+///
+/// # let mut buf = Buffer::empty(Rect::new(0, 0, 100, 40));
+///
+/// let mut state = CalendarState::<12, SingleSelection>::new();
+/// state.set_step(12);
+/// state.set_today_policy(TodayPolicy::Year);
+/// state.move_to_today();
+///
+/// Months::new()
+///         .columns(4)
+///         .locale(Locale::default())
+///         .first_weekday(Weekday::Sun)
+///         .show_weeknum(true)
+///         .styles(CalendarStyle::default())
+///         .render(Rect::new(0, 0, 100, 40), &mut buf, &mut state);
+///
+/// ```
+///
+#[derive(Debug)]
+pub struct Months<'a, const N: usize, Selection> {
+    columns: usize,
+    months: [Month<'a, Selection>; N],
+    phantom: PhantomData<Selection>,
+}
+
+impl<const N: usize, Selection> Default for Months<'_, N, Selection> {
+    fn default() -> Self {
+        Self {
+            columns: N,
+            months: array::from_fn(|_| Month::default()),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, const N: usize, Selection> Months<'a, N, Selection> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of months in one line.
+    ///
+    /// Default is N.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Locale for month-names, day-names.
+    #[inline]
+    pub fn locale(mut self, loc: chrono::Locale) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).locale(loc);
+        }
+        self
+    }
+
+    /// Show weekday titles
+    #[inline]
+    pub fn show_weekdays(mut self) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).show_weekdays();
+        }
+        self
+    }
+
+    /// Show the column with the ISO week numbers.
+    #[inline]
+    pub fn show_weeknum(mut self, show: bool) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).show_weeknum(show);
+        }
+        self
+    }
+
+    /// First day of the week.
+    #[inline]
+    pub fn first_weekday(mut self, first: Weekday) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).first_weekday(first);
+        }
+        self
+    }
+
+    /// Set the composite style.
+    #[inline]
+    pub fn styles(mut self, s: CalendarStyle) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).styles(s.clone());
+        }
+        self
+    }
+
+    /// Style for the selection.
+    pub fn select_style(mut self, style: Style) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).select_style(style);
+        }
+        self
+    }
+
+    /// Style for the focus.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).focus_style(style);
+        }
+        self
+    }
+
+    /// Sets the default day-style.
+    #[inline]
+    pub fn day_style(mut self, s: impl Into<Style>) -> Self {
+        let s = s.into();
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).day_style(s);
+        }
+        self
+    }
+
+    /// Sets all the day-styles.
+    #[inline]
+    pub fn day_styles(mut self, styles: &'a HashMap<NaiveDate, Style>) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).day_styles(styles);
+        }
+        self
+    }
+
+    /// Set the week number style
+    #[inline]
+    pub fn week_style(mut self, s: impl Into<Style>) -> Self {
+        let s = s.into();
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).week_style(s);
+        }
+        self
+    }
+
+    /// Set the week day style
+    #[inline]
+    pub fn weekday_style(mut self, s: impl Into<Style>) -> Self {
+        let s = s.into();
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).weekday_style(s);
+        }
+        self
+    }
+
+    /// Set the month-name style.
+    #[inline]
+    pub fn title_style(mut self, s: impl Into<Style>) -> Self {
+        let s = s.into();
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).title_style(s);
+        }
+        self
+    }
+
+    /// Set the month-name align.
+    #[inline]
+    pub fn title_align(mut self, a: Alignment) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).title_align(a);
+        }
+        self
+    }
+
+    /// Block for each month.
+    #[inline]
+    pub fn block(mut self, b: Block<'a>) -> Self {
+        for i in 0..N {
+            self.months[i] = mem::take(&mut self.months[i]).block(b.clone());
+        }
+        self
+    }
+}
+
+impl<const N: usize, Selection> StatefulWidget for Months<'_, N, Selection>
+where
+    Selection: CalendarSelection,
+{
+    type State = CalendarState<N, Selection>;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let Some(first) = self.months.first() else {
+            return;
+        };
+        let width = first.width();
+
+        let mut month_area = Rect::new(area.x, area.y, width, 0);
+        for row_start in (0..N).step_by(self.columns) {
+            let row_end = (row_start + self.columns).min(N);
+
+            let height = (row_start..row_end)
+                .map(|i| self.months[i].height(&state.months[i]))
+                .max()
+                .unwrap_or_default();
+
+            month_area.x = area.x;
+            month_area.height = height;
+            for i in row_start..row_end {
+                mem::take(&mut self.months[i]).render(
+                    month_area.intersection(area),
+                    buf,
+                    &mut state.months[i],
+                );
+                month_area.x += width + 2;
+            }
+            month_area.y += height + 1;
+        }
+    }
+}
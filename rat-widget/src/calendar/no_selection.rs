@@ -45,7 +45,7 @@ impl<const N: usize> HandleEvent<crossterm::event::Event, Regular, CalOutcome>
         let mut r = 'f: {
             for month in &mut self.months {
                 let r = month.handle(event, Regular);
-                if r == CalOutcome::Selected {
+                if matches!(r, CalOutcome::Selected(_)) {
                     self.focus_lead();
                     break 'f r;
                 }
@@ -416,7 +416,7 @@ impl<const N: usize> HandleEvent<crossterm::event::Event, Regular, CalOutcome>
         let mut r = 'f: {
             for month in &mut self.months {
                 let r = month.handle(event, Regular);
-                if r == CalOutcome::Selected {
+                if matches!(r, CalOutcome::Selected(_)) {
                     self.focus_lead();
                     break 'f r;
                 }
@@ -443,8 +443,9 @@ impl<const N: usize> HandleEvent<crossterm::event::Event, Regular, CalOutcome>
             if self.is_focused() {
                 match event {
                     ct_event!(key press CONTROL-'a') => {
-                        if self.select_month(self.months[self.primary_idx()].start_date(), false) {
-                            CalOutcome::Selected
+                        let date = self.months[self.primary_idx()].start_date();
+                        if self.select_month(date, false) {
+                            CalOutcome::Selected(date)
                         } else {
                             CalOutcome::Continue
                         }
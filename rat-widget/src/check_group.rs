@@ -0,0 +1,333 @@
+//!
+//! Group of checkboxes with a single focus stop.
+//!
+//! The group is one Tab stop, the arrow keys move between the
+//! checkboxes and Space/Enter toggles the current one.
+//! Rendering stays per item, use [CheckGroupState::item_mut]
+//! to get the state for each checkbox. This updates the
+//! highlight of the item too.
+//!
+//! The [Radio](crate::radio::Radio) widget already works this
+//! way, so there is no extra group for it.
+//!
+//! ```rust ignore
+//! use rat_widget::check_group::CheckGroupState;
+//! use rat_widget::checkbox::Checkbox;
+//! use ratatui::widgets::StatefulWidget;
+//!
+//! for (n, text) in ["Carrots", "Potatoes", "Tomatoes"].iter().enumerate() {
+//!     Checkbox::new()
+//!         .text(*text)
+//!         .styles(THEME.checkbox_style())
+//!         .render(layout[n], frame.buffer_mut(), state.group.item_mut(n));
+//! }
+//!
+//! match state.group.handle(event, Regular) {
+//!     CheckOutcome::Value => { /* items changed */ }
+//!     ...
+//! }
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use crate::checkbox::event::CheckOutcome;
+use crate::checkbox::CheckboxState;
+use crate::util::union_non_empty;
+use rat_event::util::{item_at, MouseFlags};
+use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_reloc::RelocatableState;
+use ratatui::layout::Rect;
+
+/// State for a group of checkboxes.
+///
+/// The group is registered with the focus as a single widget.
+/// The FocusFlags of the items are set by the group and
+/// should not be added to a Focus themselves.
+#[derive(Debug)]
+pub struct CheckGroupState {
+    /// State of the checkboxes.
+    /// __read+write__
+    pub items: Vec<CheckboxState>,
+    /// Current item.
+    /// This is kept when the group loses the focus, so
+    /// the same item is highlighted when it regains the focus.
+    /// __read+write__
+    pub selected: Option<usize>,
+
+    /// Current focus state.
+    /// __read+write__
+    pub focus: FocusFlag,
+
+    /// Mouse helper
+    /// __read+write__
+    pub mouse: MouseFlags,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Clone for CheckGroupState {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            selected: self.selected,
+            focus: FocusFlag::named(self.focus.name()),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Default for CheckGroupState {
+    fn default() -> Self {
+        Self {
+            items: Default::default(),
+            selected: None,
+            focus: Default::default(),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl HasFocus for CheckGroupState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.leaf_widget(self);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    /// Union of all item areas.
+    fn area(&self) -> Rect {
+        self.items
+            .iter()
+            .fold(Rect::default(), |a, v| union_non_empty(a, v.area))
+    }
+}
+
+impl RelocatableState for CheckGroupState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        for item in self.items.iter_mut() {
+            item.relocate(shift, clip);
+        }
+    }
+}
+
+impl CheckGroupState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New group with the given number of items.
+    pub fn named(name: &str, len: usize) -> Self {
+        Self {
+            items: (0..len).map(|_| CheckboxState::new()).collect(),
+            selected: if len > 0 { Some(0) } else { None },
+            focus: FocusFlag::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// Number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Any items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Add an item.
+    pub fn push(&mut self, item: CheckboxState) {
+        self.items.push(item);
+        if self.selected.is_none() {
+            self.selected = Some(0);
+        }
+    }
+
+    /// Item state.
+    pub fn item(&self, n: usize) -> &CheckboxState {
+        &self.items[n]
+    }
+
+    /// Item state for rendering.
+    /// This sets the focus flag of the item, so it
+    /// is shown highlighted if it is the current item.
+    pub fn item_mut(&mut self, n: usize) -> &mut CheckboxState {
+        self.items[n]
+            .focus
+            .set(self.focus.get() && self.selected == Some(n));
+        &mut self.items[n]
+    }
+
+    /// Checked state of all items.
+    pub fn values(&self) -> Vec<bool> {
+        self.items.iter().map(|v| v.checked()).collect()
+    }
+
+    /// Current item.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Select an item.
+    pub fn select(&mut self, select: usize) -> bool {
+        let old_selected = self.selected;
+        if select < self.items.len() {
+            self.selected = Some(select);
+        }
+        old_selected != self.selected
+    }
+
+    /// Select the next item. Stops at the last one.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        match self.selected {
+            Some(n) => self.select(n + 1),
+            None => self.select(0),
+        }
+    }
+
+    /// Select the previous item. Stops at the first one.
+    pub fn prev(&mut self) -> bool {
+        match self.selected {
+            Some(n) => self.select(n.saturating_sub(1)),
+            None => self.select(0),
+        }
+    }
+
+    /// Flip the current item.
+    pub fn flip_checked(&mut self) -> bool {
+        if let Some(n) = self.selected {
+            self.items[n].flip_checked();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Set the current item to its default.
+    pub fn set_default(&mut self) -> bool {
+        if let Some(n) = self.selected {
+            let default = self.items[n].default_();
+            self.items[n].set_value(default);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn item_areas(&self) -> Vec<Rect> {
+        self.items.iter().map(|v| v.area).collect()
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, CheckOutcome> for CheckGroupState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> CheckOutcome {
+        let r = if self.is_focused() {
+            match event {
+                ct_event!(keycode press Left) | ct_event!(keycode press Up) => {
+                    if self.prev() {
+                        CheckOutcome::Changed
+                    } else {
+                        CheckOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press Right) | ct_event!(keycode press Down) => {
+                    if self.next() {
+                        CheckOutcome::Changed
+                    } else {
+                        CheckOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press Home) => {
+                    if self.select(0) {
+                        CheckOutcome::Changed
+                    } else {
+                        CheckOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press End) => {
+                    if self.select(self.items.len().saturating_sub(1)) {
+                        CheckOutcome::Changed
+                    } else {
+                        CheckOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press Enter) | ct_event!(key press ' ') => {
+                    if self.flip_checked() {
+                        CheckOutcome::Value
+                    } else {
+                        CheckOutcome::Unchanged
+                    }
+                }
+                ct_event!(keycode press Backspace) | ct_event!(keycode press Delete) => {
+                    if self.set_default() {
+                        CheckOutcome::Value
+                    } else {
+                        CheckOutcome::Unchanged
+                    }
+                }
+                _ => CheckOutcome::Continue,
+            }
+        } else {
+            CheckOutcome::Continue
+        };
+
+        if r == CheckOutcome::Continue {
+            HandleEvent::handle(self, event, MouseOnly)
+        } else {
+            r
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, CheckOutcome> for CheckGroupState {
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> CheckOutcome {
+        match event {
+            ct_event!(mouse any for m) if self.mouse.doubleclick(self.area(), m) => {
+                if let Some(n) = item_at(&self.item_areas(), m.column, m.row) {
+                    self.select(n);
+                    self.flip_checked();
+                    CheckOutcome::Value
+                } else {
+                    CheckOutcome::Unchanged
+                }
+            }
+            ct_event!(mouse down Left for x,y) if self.area().contains((*x, *y).into()) => {
+                if let Some(n) = item_at(&self.item_areas(), *x, *y) {
+                    if self.select(n) {
+                        CheckOutcome::Changed
+                    } else {
+                        CheckOutcome::Unchanged
+                    }
+                } else {
+                    CheckOutcome::Unchanged
+                }
+            }
+            _ => CheckOutcome::Continue,
+        }
+    }
+}
+
+/// Handle all events.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut CheckGroupState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> CheckOutcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(
+    state: &mut CheckGroupState,
+    event: &crossterm::event::Event,
+) -> CheckOutcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
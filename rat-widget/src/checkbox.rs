@@ -25,12 +25,12 @@ use crate::checkbox::event::CheckOutcome;
 use crate::util::{block_size, revert_style};
 use rat_event::util::MouseFlags;
 use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
-use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::{BlockExt, StatefulWidget, Text, Widget};
-use ratatui::style::Style;
+use ratatui::style::{Style, Stylize};
 use ratatui::text::Span;
 use ratatui::widgets::Block;
 #[cfg(feature = "unstable-widget-ref")]
@@ -52,6 +52,7 @@ pub struct Checkbox<'a> {
 
     style: Style,
     focus_style: Option<Style>,
+    disabled_style: Option<Style>,
     block: Option<Block<'a>>,
 }
 
@@ -62,6 +63,8 @@ pub struct CheckboxStyle {
     pub style: Style,
     /// Focused style
     pub focus: Option<Style>,
+    /// Disabled style
+    pub disabled: Option<Style>,
     /// Border
     pub block: Option<Block<'static>>,
 
@@ -96,6 +99,10 @@ pub struct CheckboxState {
     /// Default state.
     /// __read+write__ Maybe overriden by a default set for the widget.
     pub default: bool,
+    /// Checkbox is disabled. It ignores all events and
+    /// is skipped by the focus.
+    /// __read+write__
+    pub disabled: bool,
 
     /// Current focus state.
     /// __read+write__
@@ -147,6 +154,7 @@ impl Default for CheckboxStyle {
         Self {
             style: Default::default(),
             focus: None,
+            disabled: None,
             block: Default::default(),
             true_str: None,
             false_str: None,
@@ -165,6 +173,7 @@ impl Default for Checkbox<'_> {
             false_str: Span::from("[ ]"),
             style: Default::default(),
             focus_style: None,
+            disabled_style: None,
             block: None,
         }
     }
@@ -182,6 +191,9 @@ impl<'a> Checkbox<'a> {
         if styles.focus.is_some() {
             self.focus_style = styles.focus;
         }
+        if styles.disabled.is_some() {
+            self.disabled_style = styles.disabled;
+        }
         if let Some(block) = styles.block {
             self.block = Some(block);
         }
@@ -209,6 +221,14 @@ impl<'a> Checkbox<'a> {
         self
     }
 
+    /// Style when disabled.
+    /// This is patched onto the checkbox. Defaults to dim.
+    #[inline]
+    pub fn disabled_style(mut self, style: impl Into<Style>) -> Self {
+        self.disabled_style = Some(style.into());
+        self
+    }
+
     /// Button text.
     #[inline]
     pub fn text(mut self, text: impl Into<Text<'a>>) -> Self {
@@ -313,13 +333,14 @@ fn render_ref(widget: &Checkbox<'_>, area: Rect, buf: &mut Buffer, state: &mut C
         revert_style(widget.style)
     };
 
+    let focused = state.focus.get() && !state.disabled;
     if widget.block.is_some() {
         widget.block.render(area, buf);
-        if state.focus.get() {
+        if focused {
             buf.set_style(state.inner, focus_style);
         }
     } else {
-        if state.focus.get() {
+        if focused {
             buf.set_style(state.area, focus_style);
         } else {
             buf.set_style(state.area, widget.style);
@@ -333,6 +354,13 @@ fn render_ref(widget: &Checkbox<'_>, area: Rect, buf: &mut Buffer, state: &mut C
     };
     cc.render(state.check_area, buf);
     (&widget.text).render(state.text_area, buf);
+
+    if state.disabled {
+        buf.set_style(
+            state.area,
+            widget.disabled_style.unwrap_or(Style::new().dim()),
+        );
+    }
 }
 
 impl Clone for CheckboxState {
@@ -344,6 +372,7 @@ impl Clone for CheckboxState {
             text_area: self.text_area,
             checked: self.checked,
             default: self.default,
+            disabled: self.disabled,
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -360,6 +389,7 @@ impl Default for CheckboxState {
             text_area: Default::default(),
             checked: false,
             default: false,
+            disabled: false,
             focus: Default::default(),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -379,12 +409,22 @@ impl HasFocus for CheckboxState {
     fn area(&self) -> Rect {
         self.area
     }
+
+    fn navigable(&self) -> Navigation {
+        if self.disabled {
+            Navigation::None
+        } else {
+            Navigation::Regular
+        }
+    }
 }
 
 impl RelocatableState for CheckboxState {
     fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
         self.area = relocate_area(self.area, shift, clip);
         self.inner = relocate_area(self.inner, shift, clip);
+        self.check_area = relocate_area(self.check_area, shift, clip);
+        self.text_area = relocate_area(self.text_area, shift, clip);
     }
 }
 
@@ -400,6 +440,16 @@ impl CheckboxState {
         }
     }
 
+    /// Disable the checkbox.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Checkbox is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
     /// Get the value.
     pub fn checked(&self) -> bool {
         self.checked
@@ -446,6 +496,9 @@ impl CheckboxState {
 
 impl HandleEvent<crossterm::event::Event, Regular, CheckOutcome> for CheckboxState {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> CheckOutcome {
+        if self.disabled {
+            return CheckOutcome::Continue;
+        }
         let r = if self.is_focused() {
             match event {
                 ct_event!(keycode press Enter) | ct_event!(key press ' ') => {
@@ -472,6 +525,9 @@ impl HandleEvent<crossterm::event::Event, Regular, CheckOutcome> for CheckboxSta
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, CheckOutcome> for CheckboxState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> CheckOutcome {
+        if self.disabled {
+            return CheckOutcome::Continue;
+        }
         match event {
             ct_event!(mouse any for m) if self.mouse.doubleclick(self.area, m) => {
                 self.flip_checked();
@@ -45,7 +45,7 @@ use rat_scrolled::{Scroll, ScrollAreaState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Rect};
 use ratatui::prelude::BlockExt;
-use ratatui::style::Style;
+use ratatui::style::{Style, Stylize};
 use ratatui::text::{Line, Span};
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
@@ -98,6 +98,7 @@ where
     button_style: Option<Style>,
     select_style: Option<Style>,
     focus_style: Option<Style>,
+    disabled_style: Option<Style>,
     block: Option<Block<'a>>,
 
     popup_alignment: Alignment,
@@ -122,6 +123,7 @@ where
     style: Style,
     button_style: Option<Style>,
     focus_style: Option<Style>,
+    disabled_style: Option<Style>,
     block: Option<Block<'a>>,
     len: Option<u16>,
 
@@ -158,6 +160,7 @@ pub struct ChoiceStyle {
     pub button: Option<Style>,
     pub select: Option<Style>,
     pub focus: Option<Style>,
+    pub disabled: Option<Style>,
     pub block: Option<Block<'static>>,
 
     pub popup: PopupStyle,
@@ -192,6 +195,10 @@ where
     pub item_areas: Vec<Rect>,
     /// Core
     pub core: ChoiceCore<T>,
+    /// Choice is disabled. It ignores all events and
+    /// is skipped by the focus.
+    /// __read+write__
+    pub disabled: bool,
     /// Popup state.
     pub popup: PopupCoreState,
     /// Behaviour for selecting from the choice popup.
@@ -401,6 +408,7 @@ impl Default for ChoiceStyle {
             button: None,
             select: None,
             focus: None,
+            disabled: None,
             block: None,
             popup: Default::default(),
             popup_len: None,
@@ -424,6 +432,7 @@ where
             button_style: Default::default(),
             select_style: Default::default(),
             focus_style: Default::default(),
+            disabled_style: Default::default(),
             block: Default::default(),
             popup_len: Default::default(),
             popup_alignment: Alignment::Left,
@@ -516,6 +525,9 @@ where
         if styles.focus.is_some() {
             self.focus_style = styles.focus;
         }
+        if styles.disabled.is_some() {
+            self.disabled_style = styles.disabled;
+        }
         if styles.block.is_some() {
             self.block = styles.block;
         }
@@ -564,6 +576,13 @@ where
         self
     }
 
+    /// Disabled style.
+    /// This is patched onto the main widget. Defaults to dim.
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = Some(style);
+        self
+    }
+
     /// Block for the main widget.
     pub fn block(mut self, block: Block<'a>) -> Self {
         self.block = Some(block);
@@ -590,6 +609,12 @@ where
     }
 
     /// Outer boundary for the popup.
+    ///
+    /// The popup flips above/below the widget if there is not
+    /// enough space, and shrinks if it fits on neither side.
+    ///
+    /// __Default__
+    /// Defaults to the area of the buffer.
     pub fn popup_boundary(mut self, boundary: Rect) -> Self {
         self.popup = self.popup.boundary(boundary);
         self
@@ -622,6 +647,12 @@ where
         self
     }
 
+    /// Render a shadow for the popup.
+    pub fn popup_shadow(mut self, shadow: bool) -> Self {
+        self.popup = self.popup.shadow(shadow);
+        self
+    }
+
     /// Adds an extra offset to the widget area.
     ///
     /// This can be used to
@@ -689,6 +720,7 @@ where
                 style: self.style,
                 button_style: self.button_style,
                 focus_style: self.focus_style,
+                disabled_style: self.disabled_style,
                 block: self.block,
                 len: self.popup_len,
                 behave_select: self.behave_select,
@@ -788,7 +820,7 @@ fn render_choice<T: PartialEq + Clone + Default>(
 
     let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
 
-    if state.is_focused() {
+    if state.is_focused() && !state.disabled {
         if widget.block.is_some() {
             widget.block.render(area, buf);
         }
@@ -824,6 +856,10 @@ fn render_choice<T: PartialEq + Clone + Default>(
         Rect::new(state.button_area.x, state.button_area.y + dy, 3, 1),
         buf,
     );
+
+    if state.disabled {
+        buf.set_style(area, widget.disabled_style.unwrap_or(Style::new().dim()));
+    }
 }
 
 impl<T> StatefulWidget for ChoicePopup<'_, T>
@@ -843,7 +879,8 @@ fn render_popup<T: PartialEq + Clone + Default>(
     buf: &mut Buffer,
     state: &mut ChoiceState<T>,
 ) {
-    if state.popup.is_active() {
+    // an empty area means the widget itself is hidden.
+    if state.popup.is_active() && !area.is_empty() {
         let len = widget
             .popup_len
             .unwrap_or_else(|| min(5, widget.items.borrow().len()) as u16);
@@ -869,6 +906,10 @@ fn render_popup<T: PartialEq + Clone + Default>(
             .len()
             .saturating_sub(inner.height as usize);
         state.popup.v_scroll.page_len = inner.height as usize;
+        // the popup may have been shrunk to fit the boundary.
+        if state.popup.active.gained() {
+            state.scroll_to_selected();
+        }
 
         state.item_areas.clear();
         let mut row = inner.y;
@@ -899,6 +940,7 @@ fn render_popup<T: PartialEq + Clone + Default>(
         }
     } else {
         state.popup.clear_areas();
+        state.item_areas.clear();
     }
 }
 
@@ -914,6 +956,7 @@ where
             button_area: self.button_area,
             item_areas: self.item_areas.clone(),
             core: self.core.clone(),
+            disabled: self.disabled,
             popup: self.popup.clone(),
             behave_select: self.behave_select,
             behave_close: self.behave_close,
@@ -936,6 +979,7 @@ where
             button_area: Default::default(),
             item_areas: Default::default(),
             core: Default::default(),
+            disabled: false,
             popup: Default::default(),
             behave_select: Default::default(),
             behave_close: Default::default(),
@@ -952,7 +996,9 @@ where
 {
     fn build(&self, builder: &mut FocusBuilder) {
         builder.widget_with_flags(self.focus(), self.area(), 0, self.navigable());
-        builder.widget_with_flags(self.focus(), self.popup.area, 1, Navigation::Mouse);
+        if !self.disabled {
+            builder.widget_with_flags(self.focus(), self.popup.area, 1, Navigation::Mouse);
+        }
     }
 
     fn focus(&self) -> FocusFlag {
@@ -962,6 +1008,14 @@ where
     fn area(&self) -> Rect {
         self.area
     }
+
+    fn navigable(&self) -> Navigation {
+        if self.disabled {
+            Navigation::None
+        } else {
+            Navigation::Regular
+        }
+    }
 }
 
 impl<T> RelocatableState for ChoiceState<T>
@@ -972,6 +1026,9 @@ where
         self.area = relocate_area(self.area, shift, clip);
         self.item_area = relocate_area(self.item_area, shift, clip);
         self.button_area = relocate_area(self.button_area, shift, clip);
+    }
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
         relocate_areas(&mut self.item_areas, shift, clip);
         self.popup.relocate(shift, clip);
     }
@@ -992,6 +1049,19 @@ where
         }
     }
 
+    /// Disable the choice. This closes the popup.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        if disabled {
+            self.set_popup_active(false);
+        }
+    }
+
+    /// Choice is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
     /// Popup is active?
     pub fn is_popup_active(&self) -> bool {
         self.popup.is_active()
@@ -1232,6 +1302,9 @@ impl<T: PartialEq + Clone + Default> HandleEvent<crossterm::event::Event, Popup,
     for ChoiceState<T>
 {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Popup) -> ChoiceOutcome {
+        if self.disabled {
+            return ChoiceOutcome::Continue;
+        }
         if self.lost_focus() {
             self.set_popup_active(false);
             // focus change triggers the repaint.
@@ -1289,6 +1362,9 @@ impl<T: PartialEq + Clone + Default> HandleEvent<crossterm::event::Event, MouseO
     for ChoiceState<T>
 {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> ChoiceOutcome {
+        if self.disabled {
+            return ChoiceOutcome::Continue;
+        }
         let r0 = handle_mouse(self, event);
         let r1 = handle_select(self, event);
         let r2 = handle_close(self, event);
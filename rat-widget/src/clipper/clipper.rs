@@ -2,7 +2,7 @@ use crate::_private::NonExhaustive;
 use crate::clipper::ClipperStyle;
 use crate::layout::GenericLayout;
 use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Outcome, Regular};
-use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_focus::{Focus, FocusBuilder, FocusFlag, HasFocus};
 use rat_reloc::RelocatableState;
 use rat_scrolled::event::ScrollOutcome;
 use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState};
@@ -29,6 +29,8 @@ where
     vscroll: Option<Scroll<'a>>,
     label_style: Option<Style>,
     label_alignment: Option<Alignment>,
+    page_break_style: Option<Style>,
+    cache_key: Option<u64>,
     phantom: PhantomData<W>,
 }
 
@@ -38,6 +40,8 @@ where
     W: Eq + Clone + Hash,
 {
     layout: Rc<RefCell<GenericLayout<W>>>,
+    // buffer is still valid.
+    cached: bool,
 
     // offset from buffer to scroll area
     offset: Position,
@@ -52,6 +56,7 @@ where
     vscroll: Option<Scroll<'a>>,
     label_style: Option<Style>,
     label_alignment: Option<Alignment>,
+    page_break_style: Option<Style>,
 }
 
 #[derive(Debug)]
@@ -66,6 +71,7 @@ where
     block: Option<Block<'a>>,
     hscroll: Option<Scroll<'a>>,
     vscroll: Option<Scroll<'a>>,
+    page_break_style: Option<Style>,
     phantom: PhantomData<W>,
 }
 
@@ -96,8 +102,15 @@ where
     /// can be used to set a container state.
     pub container: FocusFlag,
 
+    /// Page breaks as y positions of the layout. Sorted.
+    page_breaks: Vec<u16>,
+
     /// For the buffer to survive render()
     buffer: Option<Buffer>,
+    /// Cache key of the buffer.
+    cache_key: Option<u64>,
+    /// Relocation of the cached buffer.
+    cache_relocation: ((i16, i16), Rect),
 
     /// Only construct with `..Default::default()`.
     pub non_exhaustive: NonExhaustive,
@@ -115,6 +128,8 @@ where
             vscroll: self.vscroll.clone(),
             label_style: self.label_style.clone(),
             label_alignment: self.label_alignment.clone(),
+            page_break_style: self.page_break_style,
+            cache_key: self.cache_key,
             phantom: Default::default(),
         }
     }
@@ -132,6 +147,8 @@ where
             vscroll: Default::default(),
             label_style: Default::default(),
             label_alignment: Default::default(),
+            page_break_style: Default::default(),
+            cache_key: Default::default(),
             phantom: Default::default(),
         }
     }
@@ -190,6 +207,28 @@ where
         self
     }
 
+    /// Marks the page breaks on the vertical scrollbar.
+    ///
+    /// See [ClipperState::add_page_break].
+    pub fn page_break_style(mut self, style: Style) -> Self {
+        self.page_break_style = Some(style);
+        self
+    }
+
+    /// Cache the rendered buffer with this key.
+    ///
+    /// If the key is the same next time, and the area and scroll
+    /// position didn't change, the render functions of [ClipperBuffer]
+    /// do nothing and the cached buffer is used. The relocated areas
+    /// in the widget states are still valid in this case.
+    ///
+    /// The key should be a hash of everything that changes the
+    /// rendered content, including the state of the widgets.
+    pub fn cache_key(mut self, key: u64) -> Self {
+        self.cache_key = Some(key);
+        self
+    }
+
     /// Combined style.
     pub fn styles(mut self, styles: ClipperStyle) -> Self {
         self.style = styles.style;
@@ -206,6 +245,9 @@ where
             self.hscroll = self.hscroll.map(|v| v.styles(styles.clone()));
             self.vscroll = self.vscroll.map(|v| v.styles(styles.clone()));
         }
+        if styles.page_break.is_some() {
+            self.page_break_style = styles.page_break;
+        }
         self.block = self.block.map(|v| v.style(styles.style));
         self
     }
@@ -288,11 +330,19 @@ where
             .block(self.block.as_ref())
             .h_scroll(self.hscroll.as_ref())
             .v_scroll(self.vscroll.as_ref());
-        state.widget_area = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
 
         // run the layout
         let (ext_area, max_pos) = self.layout(area, state);
 
+        sa.measure(
+            area,
+            Some(max_pos.x as usize),
+            Some(max_pos.y as usize),
+            Some(&mut state.hscroll),
+            Some(&mut state.vscroll),
+        );
+        state.widget_area = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
+
         // adjust scroll
         state
             .vscroll
@@ -306,21 +356,39 @@ where
             .set_max_offset(max_pos.x.saturating_sub(state.widget_area.width) as usize);
 
         let offset = Position::new(state.hscroll.offset as u16, state.vscroll.offset as u16);
+        let relocation = (
+            (
+                state.widget_area.x as i16 - offset.x as i16,
+                state.widget_area.y as i16 - offset.y as i16,
+            ),
+            state.widget_area,
+        );
 
         // resize buffer to fit all visible widgets.
         let buffer_area = ext_area;
+        let cached = self.cache_key.is_some()
+            && state.cache_key == self.cache_key
+            && state.cache_relocation == relocation
+            && state.buffer.as_ref().map(|v| v.area) == Some(buffer_area);
         // resize buffer to fit the layout.
-        let mut buffer = if let Some(mut buffer) = state.buffer.take() {
-            buffer.reset();
-            buffer.resize(buffer_area);
+        let buffer = if let Some(mut buffer) = state.buffer.take() {
+            if !cached {
+                buffer.reset();
+                buffer.resize(buffer_area);
+                buffer.set_style(buffer_area, self.style);
+            }
             buffer
         } else {
-            Buffer::empty(buffer_area)
+            let mut buffer = Buffer::empty(buffer_area);
+            buffer.set_style(buffer_area, self.style);
+            buffer
         };
-        buffer.set_style(buffer_area, self.style);
+        state.cache_key = self.cache_key;
+        state.cache_relocation = relocation;
 
         ClipperBuffer {
             layout: state.layout.clone(),
+            cached,
             offset,
             buffer,
             widget_area: state.widget_area,
@@ -330,6 +398,7 @@ where
             vscroll: self.vscroll,
             label_style: self.label_style,
             label_alignment: self.label_alignment,
+            page_break_style: self.page_break_style,
         }
     }
 }
@@ -338,6 +407,14 @@ impl<'a, W> ClipperBuffer<'a, W>
 where
     W: Eq + Hash + Clone,
 {
+    /// The buffer is still valid from the last render.
+    ///
+    /// All the render functions do nothing in this case,
+    /// but still report if the widget is visible.
+    pub fn is_cached(&self) -> bool {
+        self.cached
+    }
+
     /// Is the widget visible.
     pub fn is_visible(&self, widget: W) -> bool {
         let layout = self.layout.borrow();
@@ -351,6 +428,9 @@ where
     /// Render the label with the set style and alignment.
     #[inline(always)]
     fn render_auto_label(&mut self, idx: usize) -> bool {
+        if self.cached {
+            return false;
+        }
         let layout = self.layout.borrow();
         let Some(label_area) = self.locate_area(layout.label(idx)) else {
             return false;
@@ -383,6 +463,9 @@ where
         let Some(label_area) = self.locate_area(layout.label(idx)) else {
             return false;
         };
+        if self.cached {
+            return true;
+        }
         let label_str = layout.try_label_str(idx);
 
         render_fn(label_str).render(label_area, &mut self.buffer);
@@ -406,6 +489,9 @@ where
         let Some(widget_area) = self.locate_area(self.layout.borrow().widget(idx)) else {
             return false;
         };
+        if self.cached {
+            return true;
+        }
         render_fn().render(widget_area, &mut self.buffer);
 
         true
@@ -429,14 +515,75 @@ where
             self.hidden(state);
             return false;
         };
+        if self.cached {
+            // the state still has the relocated areas.
+            return true;
+        }
         render_fn().render(widget_area, &mut self.buffer, state);
         self.relocate(state);
 
         true
     }
 
+    /// Render the popup of a stateful widget.
+    ///
+    /// Call this after all widgets have been rendered, so the
+    /// popup is drawn above them. The popup is rendered into the
+    /// clipper buffer at the position of the widget, and only the
+    /// popup areas are relocated. The buffer is extended to the
+    /// visible area, so the popup is not limited to the area
+    /// of the visible widgets.
+    ///
+    /// If the widget is not visible the popup areas are cleared.
+    #[inline(always)]
+    pub fn render_popup<FN, WW, SS>(&mut self, widget: W, render_fn: FN, state: &mut SS) -> bool
+    where
+        FN: FnOnce() -> Option<WW>,
+        WW: StatefulWidget<State = SS>,
+        SS: RelocatableState,
+    {
+        let Some(idx) = self.layout.borrow().try_index_of(widget) else {
+            return false;
+        };
+        let Some(widget_area) = self.locate_area(self.layout.borrow().widget(idx)) else {
+            self.hidden_popup(state);
+            return false;
+        };
+        if self.cached {
+            // the state still has the relocated areas.
+            return true;
+        }
+        if let Some(widget) = render_fn() {
+            self.extend_to_view();
+            widget.render(widget_area, &mut self.buffer, state);
+            self.relocate_popup(state);
+        }
+
+        true
+    }
+
+    // Extend the buffer to cover the visible area.
+    fn extend_to_view(&mut self) {
+        let view = Rect::new(
+            self.offset.x,
+            self.offset.y,
+            self.widget_area.width,
+            self.widget_area.height,
+        );
+        let ext_area = self.buffer.area.union(view);
+        if ext_area != self.buffer.area {
+            let mut buffer = Buffer::empty(ext_area);
+            buffer.set_style(ext_area, self.style);
+            buffer.merge(&self.buffer);
+            self.buffer = buffer;
+        }
+    }
+
     /// Render all visible blocks.
     pub fn render_block(&mut self) {
+        if self.cached {
+            return;
+        }
         let layout = self.layout.borrow();
         for (idx, block_area) in layout.block_area_iter().enumerate() {
             if let Some(block_area) = self.locate_area(*block_area) {
@@ -494,11 +641,36 @@ where
     /// coordinates instead of screen coordinates.
     ///
     /// Call this function to correct this after rendering.
+    ///
+    /// This only relocates the main areas of the widget,
+    /// see [relocate_popup](Self::relocate_popup).
     pub fn relocate<S>(&self, state: &mut S)
     where
         S: RelocatableState,
     {
-        state.relocate(self.shift(), self.widget_area);
+        let (shift, clip) = self.relocation();
+        state.relocate(shift, clip);
+    }
+
+    /// After rendering a popup to the buffer its areas
+    /// are in buffer coordinates.
+    ///
+    /// Call this function to correct this after rendering.
+    pub fn relocate_popup<S>(&self, state: &mut S)
+    where
+        S: RelocatableState,
+    {
+        let (shift, clip) = self.relocation();
+        state.relocate_popup(shift, clip);
+    }
+
+    /// Shift and clip for relocating from buffer to screen coordinates.
+    ///
+    /// If this buffer is rendered into another container, combine
+    /// this with the relocation of the outer container and use
+    /// [RelocatableState::relocate_with].
+    pub fn relocation(&self) -> ((i16, i16), Rect) {
+        (self.shift(), self.widget_area)
     }
 
     /// If a widget is not rendered because it is out of
@@ -506,14 +678,33 @@ where
     /// in its state.
     ///
     /// This uses the mechanism for [relocate](Self::relocate) to zero them out.
+    /// A popup of an invisible widget is hidden too.
     pub fn hidden<S>(&self, state: &mut S)
     where
         S: RelocatableState,
     {
-        state.relocate((0, 0), Rect::default())
+        if self.cached {
+            return;
+        }
+        state.relocate_hidden();
+        state.relocate_popup_hidden();
+    }
+
+    /// Clear the popup areas of an invisible widget.
+    pub fn hidden_popup<S>(&self, state: &mut S)
+    where
+        S: RelocatableState,
+    {
+        if self.cached {
+            return;
+        }
+        state.relocate_popup_hidden();
     }
 
     /// Return a reference to the buffer.
+    ///
+    /// If the buffer [is_cached](Self::is_cached) this
+    /// changes the cached content.
     #[inline]
     pub fn buffer(&mut self) -> &mut Buffer {
         &mut self.buffer
@@ -531,6 +722,7 @@ where
             buffer: self.buffer,
             phantom: Default::default(),
             style: self.style,
+            page_break_style: self.page_break_style,
         }
     }
 }
@@ -602,11 +794,36 @@ where
             tgt.clone_from_slice(src);
         }
 
+        if let Some(page_break_style) = self.page_break_style {
+            render_page_breaks(page_break_style, buf, state);
+        }
+
         // keep buffer
         state.buffer = Some(self.buffer);
     }
 }
 
+/// Mark the page breaks on the vertical scrollbar track.
+fn render_page_breaks<W>(style: Style, buf: &mut Buffer, state: &ClipperState<W>)
+where
+    W: Eq + Clone + Hash,
+{
+    let track = state.vscroll.area;
+    if !state.vscroll.visible || track.is_empty() {
+        return;
+    }
+    let len = state.vscroll.max_offset() + state.vscroll.page_len();
+    if len == 0 {
+        return;
+    }
+    for y in state.page_breaks.iter() {
+        let pos = track.y + (*y as usize * track.height as usize / len) as u16;
+        if pos < track.bottom() {
+            buf.set_style(Rect::new(track.x, pos, track.width, 1), style);
+        }
+    }
+}
+
 impl<W> Default for ClipperState<W>
 where
     W: Eq + Hash + Clone,
@@ -619,7 +836,10 @@ where
             hscroll: Default::default(),
             vscroll: Default::default(),
             container: Default::default(),
+            page_breaks: Default::default(),
             buffer: None,
+            cache_key: None,
+            cache_relocation: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -637,7 +857,10 @@ where
             hscroll: self.hscroll.clone(),
             vscroll: self.vscroll.clone(),
             container: FocusFlag::named(self.container.name()),
+            page_breaks: self.page_breaks.clone(),
             buffer: None,
+            cache_key: None,
+            cache_relocation: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -668,11 +891,20 @@ where
         Self::default()
     }
 
-    /// Clear the layout data and reset any scroll
+    /// Clear the layout data, the page breaks and reset any scroll
     pub fn clear(&mut self) {
         self.layout.borrow_mut().clear();
+        self.page_breaks.clear();
         self.hscroll.clear();
         self.vscroll.clear();
+        self.invalidate();
+    }
+
+    /// Throw away the cached buffer.
+    ///
+    /// The next render will call all render functions again.
+    pub fn invalidate(&mut self) {
+        self.cache_key = None;
     }
 
     /// Layout needs to change?
@@ -684,6 +916,7 @@ where
     /// Set the layout.
     pub fn set_layout(&mut self, layout: GenericLayout<W>) {
         self.layout = Rc::new(RefCell::new(layout));
+        self.invalidate();
     }
 
     /// Layout.
@@ -814,6 +1047,120 @@ where
     }
 }
 
+// Pages
+impl<W> ClipperState<W>
+where
+    W: Eq + Clone + Hash,
+{
+    /// Add a page break at the given y position of the layout.
+    ///
+    /// With page breaks PageUp/PageDown snap to the previous/next
+    /// break instead of scrolling by the height of the view.
+    pub fn add_page_break(&mut self, y: u16) {
+        if y == 0 {
+            return;
+        }
+        if let Err(idx) = self.page_breaks.binary_search(&y) {
+            self.page_breaks.insert(idx, y);
+        }
+    }
+
+    /// Remove all page breaks.
+    pub fn clear_page_breaks(&mut self) {
+        self.page_breaks.clear();
+    }
+
+    /// Page breaks.
+    pub fn page_breaks(&self) -> &[u16] {
+        &self.page_breaks
+    }
+
+    /// Number of pages. Without page breaks this is 1.
+    pub fn page_count(&self) -> usize {
+        self.page_breaks.len() + 1
+    }
+
+    /// First row of the page.
+    fn page_start(&self, page: usize) -> usize {
+        if page == 0 {
+            0
+        } else {
+            self.page_breaks[page - 1] as usize
+        }
+    }
+
+    /// Page at the top of the view.
+    ///
+    /// If the view is scrolled to the end, the last page that starts
+    /// inside the view is the current one.
+    pub fn current_page(&self) -> usize {
+        let offset = self.vscroll.offset();
+        let pos = if offset > 0 && offset >= self.vscroll.max_offset() {
+            offset + self.vscroll.page_len().saturating_sub(1)
+        } else {
+            offset
+        };
+        self.page_breaks
+            .iter()
+            .take_while(|v| **v as usize <= pos)
+            .count()
+    }
+
+    /// Scroll to the start of the page.
+    pub fn goto_page(&mut self, page: usize) -> bool {
+        let page = min(page, self.page_count() - 1);
+        self.set_vertical_offset(self.page_start(page))
+    }
+
+    /// Scroll to the start of the next page.
+    pub fn next_page(&mut self) -> bool {
+        let page = self.current_page();
+        if page + 1 < self.page_count() {
+            self.goto_page(page + 1)
+        } else {
+            false
+        }
+    }
+
+    /// Scroll to the start of the current page, or
+    /// to the previous page if already there.
+    pub fn prev_page(&mut self) -> bool {
+        let page = self.current_page();
+        if self.vscroll.offset() > self.page_start(page) {
+            self.set_vertical_offset(self.page_start(page))
+        } else if page > 0 {
+            self.goto_page(page - 1)
+        } else {
+            false
+        }
+    }
+
+    /// Returns the first widget that starts on the given page.
+    /// This uses insertion order of the widgets, not
+    /// any graphical ordering.
+    pub fn first_of_page(&self, page: usize) -> Option<W> {
+        if page >= self.page_count() {
+            return None;
+        }
+        let start = self.page_start(page);
+        let end = self
+            .page_breaks
+            .get(page)
+            .map(|v| *v as usize)
+            .unwrap_or(usize::MAX);
+
+        let layout = self.layout.borrow();
+        for idx in 0..layout.widget_len() {
+            let area = layout.widget(idx);
+            let y = area.y as usize;
+            if !area.is_empty() && y >= start && y < end {
+                return Some(layout.widget_key(idx).clone());
+            }
+        }
+        None
+    }
+}
+
 impl<W> HandleEvent<crossterm::event::Event, Regular, Outcome> for ClipperState<W>
 where
     W: Eq + Clone + Hash,
@@ -821,6 +1168,12 @@ where
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> Outcome {
         let r = if self.container.is_focused() {
             match event {
+                ct_event!(keycode press PageUp) if !self.page_breaks.is_empty() => {
+                    self.prev_page().into()
+                }
+                ct_event!(keycode press PageDown) if !self.page_breaks.is_empty() => {
+                    self.next_page().into()
+                }
                 ct_event!(keycode press PageUp) => self.scroll_up(self.vscroll.page_len()).into(),
                 ct_event!(keycode press PageDown) => {
                     self.scroll_down(self.vscroll.page_len()).into()
@@ -839,6 +1192,33 @@ where
     }
 }
 
+/// Regular event-handling. Additionally, moving to another page
+/// with PageUp/PageDown focuses the first widget of the page.
+impl<W> HandleEvent<crossterm::event::Event, &Focus, Outcome> for ClipperState<W>
+where
+    W: Eq + Clone + Hash + HasFocus,
+{
+    fn handle(&mut self, event: &crossterm::event::Event, focus: &Focus) -> Outcome {
+        let r = if self.container.is_focused() && !self.page_breaks.is_empty() {
+            match event {
+                ct_event!(keycode press PageUp) => self.prev_page().into(),
+                ct_event!(keycode press PageDown) => self.next_page().into(),
+                _ => Outcome::Continue,
+            }
+        } else {
+            Outcome::Continue
+        };
+
+        if r == Outcome::Changed {
+            if let Some(widget) = self.first_of_page(self.current_page()) {
+                focus.focus(&widget);
+            }
+        }
+
+        r.or_else(|| self.handle(event, Regular))
+    }
+}
+
 impl<W> HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for ClipperState<W>
 where
     W: Eq + Clone + Hash,
@@ -12,6 +12,8 @@ pub struct ClipperStyle {
     pub label_alignment: Option<Alignment>,
     pub block: Option<Block<'static>>,
     pub scroll: Option<ScrollStyle>,
+    /// Marks for the page breaks on the vertical scrollbar.
+    pub page_break: Option<Style>,
     pub non_exhaustive: NonExhaustive,
 }
 
@@ -23,6 +25,7 @@ impl Default for ClipperStyle {
             label_alignment: None,
             block: None,
             scroll: None,
+            page_break: None,
             non_exhaustive: NonExhaustive,
         }
     }
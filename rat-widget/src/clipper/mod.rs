@@ -79,6 +79,17 @@
 //!
 //! ```
 //!
+//! __Pages__
+//!
+//! With [ClipperState::add_page_break] the layout can be divided into
+//! logical pages. PageUp/PageDown snap to the page breaks, and
+//! [ClipperState::current_page], [ClipperState::page_count] and
+//! [ClipperState::goto_page] can be used for navigation.
+//!
+//! If the event-handler is called with the [Focus](rat_focus::Focus)
+//! instead of `Regular`, moving to another page focuses the first
+//! widget on that page.
+//!
 //! __StatefulWidget__
 //!
 //! For this to work with StatefulWidgets they must cooperate
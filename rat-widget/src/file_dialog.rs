@@ -15,7 +15,7 @@ use dirs::{document_dir, home_dir};
 use rat_event::{
     ct_event, flow, try_flow, ConsumedEvent, Dialog, HandleEvent, MouseOnly, Outcome, Regular,
 };
-use rat_focus::{on_lost, Focus, FocusBuilder, FocusFlag, HasFocus};
+use rat_focus::{on_lost, Focus, FocusBuilder, FocusFlag, HasFocus, NavigationPolicy};
 use rat_ftable::event::EditOutcome;
 use rat_scrolled::Scroll;
 use rat_text::text_input::{TextInput, TextInputState};
@@ -23,8 +23,10 @@ use rat_text::{HasScreenCursor, TextStyle};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Direction, Flex, Layout, Rect};
 use ratatui::prelude::{StatefulWidget, Style, Text, Widget};
+use ratatui::style::Modifier;
 use ratatui::widgets::{Block, ListItem};
 use std::cmp::max;
+use std::collections::HashMap;
 use std::ffi::OsString;
 use std::fmt::{Debug, Formatter};
 use std::path::{Path, PathBuf};
@@ -36,6 +38,45 @@ use sysinfo::Disks;
 ///
 /// It can work in Open/Save mode or a separate Directory mode.
 ///
+/// __Keys__
+///
+/// The focused widget gets the first chance to use Enter and Esc.
+/// The path and name fields commit with Enter, the lists
+/// change the directory or choose the file, a focused button
+/// is pressed.
+///
+/// Everything else is up to [FileDialogState::default_button] for
+/// Enter and [FileDialogState::cancel_button] for Esc. The default
+/// button is rendered with its own style.
+///
+/// Ctrl+B jumps to the next root.
+///
+/// __Path__
+///
+/// The path field at the top can be edited. Enter goes to the
+/// directory, relative paths start at the current directory and
+/// `~` is the home directory. If the path is a file, the dialog
+/// goes to its directory and selects it. A path that doesn't
+/// exist is marked as invalid.
+///
+/// Tab completes the last part of the path with the names in its
+/// directory. A unique match completes in place, otherwise the
+/// common prefix is completed. If there is no common prefix left,
+/// each Tab shows the next candidate. Tab only completes if the
+/// path has been edited and doesn't end with a separator, otherwise
+/// it moves the focus as usual.
+///
+/// __Locations__
+///
+/// The roots at the side work as bookmarks, see [FileDialogState::add_root].
+/// Below them are the recently used directories. Those are updated
+/// with each successful dialog, and can be stored with
+/// [FileDialogState::recent] and [FileDialogState::set_recent].
+///
+/// With [FileDialogState::set_purpose] each kind of dialog can start
+/// in the directory last used for it, e.g. one for "export" and
+/// one for "open".
+///
 #[derive(Debug, Default, Clone)]
 pub struct FileDialog<'a> {
     block: Option<Block<'a>>,
@@ -45,6 +86,7 @@ pub struct FileDialog<'a> {
     roots_style: Option<ListStyle>,
     text_style: Option<TextStyle>,
     button_style: Option<ButtonStyle>,
+    default_button_style: Option<ButtonStyle>,
     ok_text: &'a str,
     cancel_text: &'a str,
 }
@@ -61,12 +103,28 @@ pub struct FileDialogStyle {
     pub text: Option<TextStyle>,
     /// Buttons.
     pub button: Option<ButtonStyle>,
+    /// The default button.
+    pub default_button: Option<ButtonStyle>,
     /// Outer border.
     pub block: Option<Block<'static>>,
 
     pub non_exhaustive: NonExhaustive,
 }
 
+/// Number of remembered recent directories.
+const MAX_RECENT: usize = 10;
+
+/// Buttons of the FileDialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileDialogButton {
+    /// Choose the selected file/directory.
+    Ok,
+    /// Cancel the dialog.
+    Cancel,
+    /// Create a new directory.
+    New,
+}
+
 /// Open/Save or Directory dialog.
 #[derive(Debug, PartialEq, Eq)]
 #[allow(dead_code)]
@@ -81,6 +139,16 @@ enum Mode {
 pub struct FileDialogState {
     /// Dialog is active.
     pub active: bool,
+    /// Enter activates this button, if the focused widget
+    /// doesn't use Enter itself. Rendered with the default style.
+    /// Defaults to None.
+    /// __read+write__
+    pub default_button: Option<FileDialogButton>,
+    /// Esc activates this button, if the focused widget
+    /// doesn't use Esc itself.
+    /// Defaults to Cancel.
+    /// __read+write__
+    pub cancel_button: Option<FileDialogButton>,
 
     mode: Mode,
 
@@ -92,6 +160,10 @@ pub struct FileDialogState {
     files: Vec<OsString>,
     use_default_roots: bool,
     roots: Vec<(OsString, PathBuf)>,
+    recent: Vec<PathBuf>,
+    purpose: Option<String>,
+    last_dirs: HashMap<String, PathBuf>,
+    completion: Option<Completion>,
 
     path_state: TextInputState,
     root_state: ListState<RowSelection>,
@@ -103,6 +175,17 @@ pub struct FileDialogState {
     ok_state: ButtonState,
 }
 
+/// Cycles through the candidates for an ambiguous completion.
+#[derive(Debug)]
+struct Completion {
+    /// Text of the path field as set by the last completion.
+    text: String,
+    /// Directory part of the text, including the separator.
+    dir: String,
+    candidates: Vec<String>,
+    idx: usize,
+}
+
 pub(crate) mod event {
     use rat_event::{ConsumedEvent, Outcome};
     use std::path::PathBuf;
@@ -170,6 +253,8 @@ impl Debug for FileDialogState {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FileOpenState")
             .field("active", &self.active)
+            .field("default_button", &self.default_button)
+            .field("cancel_button", &self.cancel_button)
             .field("mode", &self.mode)
             .field("path", &self.path)
             .field("save_name", &self.save_name)
@@ -177,6 +262,10 @@ impl Debug for FileDialogState {
             .field("files", &self.files)
             .field("use_default_roots", &self.use_default_roots)
             .field("roots", &self.roots)
+            .field("recent", &self.recent)
+            .field("purpose", &self.purpose)
+            .field("last_dirs", &self.last_dirs)
+            .field("completion", &self.completion)
             .field("path_state", &self.path_state)
             .field("root_state", &self.root_state)
             .field("dir_state", &self.dir_state)
@@ -195,6 +284,7 @@ impl Default for FileDialogStyle {
             list: None,
             roots: None,
             button: None,
+            default_button: None,
             block: None,
             non_exhaustive: NonExhaustive,
             text: None,
@@ -206,6 +296,8 @@ impl Default for FileDialogState {
     fn default() -> Self {
         let mut s = Self {
             active: false,
+            default_button: None,
+            cancel_button: Some(FileDialogButton::Cancel),
             mode: Mode::Open,
             path: Default::default(),
             save_name: None,
@@ -215,6 +307,10 @@ impl Default for FileDialogState {
             files: vec![],
             use_default_roots: false,
             roots: vec![],
+            recent: vec![],
+            purpose: None,
+            last_dirs: Default::default(),
+            completion: None,
             path_state: Default::default(),
             root_state: Default::default(),
             dir_state: Default::default(),
@@ -241,6 +337,7 @@ impl<'a> FileDialog<'a> {
             roots_style: None,
             text_style: None,
             button_style: None,
+            default_button_style: None,
             ok_text: "Ok",
             cancel_text: "Cancel",
         }
@@ -295,6 +392,14 @@ impl<'a> FileDialog<'a> {
         self
     }
 
+    /// Style for the default button.
+    ///
+    /// Defaults to the button style in bold.
+    pub fn default_button_style(mut self, style: ButtonStyle) -> Self {
+        self.default_button_style = Some(style);
+        self
+    }
+
     /// All styles.
     pub fn styles(mut self, styles: FileDialogStyle) -> Self {
         self.style = styles.style;
@@ -310,6 +415,9 @@ impl<'a> FileDialog<'a> {
         if styles.button.is_some() {
             self.button_style = styles.button;
         }
+        if styles.default_button.is_some() {
+            self.default_button_style = styles.default_button;
+        }
         if styles.block.is_some() {
             self.block = styles.block;
         }
@@ -429,8 +537,7 @@ impl StatefulWidget for FileDialog<'_> {
 
         let mut l_n = layout.widget_for(DialogItem::Button(1));
         l_n.width = 10;
-        Button::new(Text::from("New").alignment(Alignment::Center))
-            .styles_opt(self.button_style.clone())
+        self.button("New", FileDialogButton::New, state.default_button)
             .render(l_n, buf, &mut state.new_state);
 
         let l_oc = Layout::horizontal([Constraint::Length(10), Constraint::Length(10)])
@@ -438,16 +545,44 @@ impl StatefulWidget for FileDialog<'_> {
             .flex(Flex::End)
             .split(layout.widget_for(DialogItem::Button(2)));
 
-        Button::new(Text::from(self.cancel_text).alignment(Alignment::Center))
-            .styles_opt(self.button_style.clone())
-            .render(l_oc[0], buf, &mut state.cancel_state);
+        self.button(
+            self.cancel_text,
+            FileDialogButton::Cancel,
+            state.default_button,
+        )
+        .render(l_oc[0], buf, &mut state.cancel_state);
 
-        Button::new(Text::from(self.ok_text).alignment(Alignment::Center))
-            .styles_opt(self.button_style.clone())
+        self.button(self.ok_text, FileDialogButton::Ok, state.default_button)
             .render(l_oc[1], buf, &mut state.ok_state);
     }
 }
 
+impl<'a> FileDialog<'a> {
+    fn button(
+        &self,
+        text: &'a str,
+        button: FileDialogButton,
+        default: Option<FileDialogButton>,
+    ) -> Button<'a> {
+        let b = Button::new(Text::from(text).alignment(Alignment::Center))
+            .styles_opt(self.button_style.clone());
+        if default == Some(button) {
+            if let Some(style) = &self.default_button_style {
+                b.styles(style.clone())
+            } else {
+                let style = self
+                    .button_style
+                    .as_ref()
+                    .map(|v| v.style)
+                    .unwrap_or(self.style);
+                b.style(style.add_modifier(Modifier::BOLD))
+            }
+        } else {
+            b
+        }
+    }
+}
+
 fn render_open_dir(
     widget: &FileDialog<'_>,
     area: Rect,
@@ -474,10 +609,7 @@ fn render_open_dir(
         .render(l_path, buf, &mut state.path_state);
 
     List::default()
-        .items(state.roots.iter().map(|v| {
-            let s = v.0.to_string_lossy();
-            ListItem::from(format!("{}", s))
-        }))
+        .items(state.root_items())
         .scroll(Scroll::new())
         .styles_opt(widget.roots_style.clone())
         .render(l_grid.widget_for((0, 1)), buf, &mut state.root_state);
@@ -519,10 +651,7 @@ fn render_open(widget: &FileDialog<'_>, area: Rect, buf: &mut Buffer, state: &mu
         .render(l_path, buf, &mut state.path_state);
 
     List::default()
-        .items(state.roots.iter().map(|v| {
-            let s = v.0.to_string_lossy();
-            ListItem::from(format!("{}", s))
-        }))
+        .items(state.root_items())
         .scroll(Scroll::new())
         .styles_opt(widget.roots_style.clone())
         .render(l_grid.widget_for((0, 1)), buf, &mut state.root_state);
@@ -577,10 +706,7 @@ fn render_save(widget: &FileDialog<'_>, area: Rect, buf: &mut Buffer, state: &mu
         .render(l_path, buf, &mut state.path_state);
 
     List::default()
-        .items(state.roots.iter().map(|v| {
-            let s = v.0.to_string_lossy();
-            ListItem::from(format!("{}", s))
-        }))
+        .items(state.root_items())
         .scroll(Scroll::new())
         .styles_opt(widget.roots_style.clone())
         .render(l_grid.widget_for((0, 1)), buf, &mut state.root_state);
@@ -634,6 +760,44 @@ impl FileDialogState {
             .push((OsString::from(name.as_ref()), path.into()))
     }
 
+    /// Area of the roots at the side.
+    pub fn roots_area(&self) -> Rect {
+        self.root_state.area
+    }
+
+    /// Recently used directories, most recent first.
+    pub fn recent(&self) -> &[PathBuf] {
+        &self.recent
+    }
+
+    /// Set the recently used directories, most recent first.
+    pub fn set_recent(&mut self, recent: Vec<PathBuf>) {
+        self.recent = recent;
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    /// Purpose of the next dialogs.
+    ///
+    /// Each purpose remembers its last directory, and the next
+    /// dialog with the same purpose starts there. None uses
+    /// the last directory of any dialog.
+    pub fn set_purpose(&mut self, purpose: Option<&str>) {
+        self.purpose = purpose.map(|v| v.to_string());
+    }
+
+    /// Last directory for each purpose.
+    pub fn last_dirs(&self) -> Vec<(String, PathBuf)> {
+        self.last_dirs
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    /// Set the last directory for each purpose.
+    pub fn set_last_dirs(&mut self, last_dirs: Vec<(String, PathBuf)>) {
+        self.last_dirs = last_dirs.into_iter().collect();
+    }
+
     /// Clear all roots.
     pub fn clear_roots(&mut self) {
         self.roots.clear();
@@ -677,7 +841,7 @@ impl FileDialogState {
     /// Show as directory-dialog.
     pub fn directory_dialog(&mut self, path: impl AsRef<Path>) -> Result<(), io::Error> {
         let path = path.as_ref();
-        let old_path = self.path.clone();
+        let old_path = self.start_path();
 
         self.active = true;
         self.mode = Mode::Dir;
@@ -694,6 +858,8 @@ impl FileDialogState {
             } else {
                 self.set_path(path)?;
             }
+        } else if self.purpose.is_some() && old_path.exists() {
+            self.set_path(&old_path)?;
         } else {
             self.set_path(path)?;
         }
@@ -704,7 +870,7 @@ impl FileDialogState {
     /// Show as open-dialog.
     pub fn open_dialog(&mut self, path: impl AsRef<Path>) -> Result<(), io::Error> {
         let path = path.as_ref();
-        let old_path = self.path.clone();
+        let old_path = self.start_path();
 
         self.active = true;
         self.mode = Mode::Open;
@@ -721,6 +887,8 @@ impl FileDialogState {
             } else {
                 self.set_path(path)?;
             }
+        } else if self.purpose.is_some() && old_path.exists() {
+            self.set_path(&old_path)?;
         } else {
             self.set_path(path)?;
         }
@@ -745,7 +913,7 @@ impl FileDialogState {
         ext: impl AsRef<str>,
     ) -> Result<(), io::Error> {
         let path = path.as_ref();
-        let old_path = self.path.clone();
+        let old_path = self.start_path();
 
         self.active = true;
         self.mode = Mode::Save;
@@ -762,6 +930,8 @@ impl FileDialogState {
             } else {
                 self.set_path(path)?;
             }
+        } else if self.purpose.is_some() && old_path.exists() {
+            self.set_path(&old_path)?;
         } else {
             self.set_path(path)?;
         }
@@ -769,6 +939,38 @@ impl FileDialogState {
         Ok(())
     }
 
+    /// Last directory for the current purpose.
+    fn start_path(&self) -> PathBuf {
+        if let Some(purpose) = &self.purpose {
+            self.last_dirs.get(purpose).cloned().unwrap_or_default()
+        } else {
+            self.path.clone()
+        }
+    }
+
+    /// Remember the current directory after a successful dialog.
+    fn remember_path(&mut self) {
+        if let Some(purpose) = &self.purpose {
+            self.last_dirs.insert(purpose.clone(), self.path.clone());
+        }
+        self.recent.retain(|v| *v != self.path);
+        self.recent.insert(0, self.path.clone());
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    /// Items for the roots list. Roots first, then the recent directories.
+    fn root_items(&self) -> Vec<ListItem<'static>> {
+        let roots = self
+            .roots
+            .iter()
+            .map(|v| ListItem::from(v.0.to_string_lossy().to_string()));
+        let recent = self.recent.iter().map(|v| {
+            let name = v.file_name().unwrap_or(v.as_os_str());
+            ListItem::from(format!("\u{21bb} {}", name.to_string_lossy()))
+        });
+        roots.chain(recent).collect()
+    }
+
     fn find_parent(&self, path: &Path) -> Option<PathBuf> {
         if path == Path::new(".") || path.file_name().is_none() {
             let parent = path.join("..");
@@ -828,6 +1030,7 @@ impl FileDialogState {
             self.path = path;
             self.dirs = dirs;
             self.files = files;
+            self.completion = None;
 
             self.path_state.set_text(self.path.to_string_lossy());
             if self.path_state.inner.width != 0 {
@@ -866,15 +1069,148 @@ impl FileDialogState {
         }
     }
 
-    fn use_path_input(&mut self) -> Result<FileOutcome, io::Error> {
-        let path = PathBuf::from(self.path_state.text());
-        if !path.exists() || !path.is_dir() {
-            self.path_state.invalid = true;
+    /// Path for the text of the path field.
+    ///
+    /// Expands `~` and resolves relative paths against
+    /// the current directory.
+    fn resolve_path(&self, txt: &str) -> PathBuf {
+        let path = expand_home(txt);
+        if path.is_relative() {
+            self.path.join(path)
         } else {
+            path
+        }
+    }
+
+    fn use_path_input(&mut self) -> Result<FileOutcome, io::Error> {
+        let txt = self.path_state.text();
+        if txt == self.path.to_string_lossy() {
+            self.path_state.invalid = false;
+            return Ok(FileOutcome::Unchanged);
+        }
+
+        let path = self.resolve_path(txt);
+        if path.is_dir() {
             self.path_state.invalid = false;
             self.set_path(&path)?;
+        } else if path.is_file() && self.mode != Mode::Dir {
+            let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+                self.path_state.invalid = true;
+                return Ok(FileOutcome::Changed);
+            };
+            self.path_state.invalid = false;
+            self.set_path(parent)?;
+            if let Some(idx) = self.files.iter().position(|v| v == name) {
+                self.file_state.select(Some(idx));
+                self.file_state.scroll_to_selected();
+                if self.mode == Mode::Save {
+                    self.save_name_state.set_text(name.to_string_lossy());
+                }
+            }
+        } else {
+            self.path_state.invalid = true;
+        }
+
+        Ok(FileOutcome::Changed)
+    }
+
+    /// Tab completion for the path field.
+    fn complete_path(&mut self) -> Result<FileOutcome, io::Error> {
+        let txt = self.path_state.text().to_string();
+
+        // continue cycling
+        if let Some(completion) = &mut self.completion {
+            if completion.text == txt {
+                completion.idx = (completion.idx + 1) % completion.candidates.len();
+                completion.text = format!(
+                    "{}{}",
+                    completion.dir, completion.candidates[completion.idx]
+                );
+                self.path_state.set_text(completion.text.as_str());
+                self.path_state.move_to_line_end(false);
+                return Ok(FileOutcome::Changed);
+            }
         }
+        self.completion = None;
 
+        if txt.is_empty() || txt == self.path.to_string_lossy() {
+            return Ok(FileOutcome::Continue);
+        }
+        let (dir, prefix) = match txt.rfind(std::path::is_separator) {
+            Some(idx) => txt.split_at(idx + 1),
+            None => ("", txt.as_str()),
+        };
+        if prefix.is_empty() {
+            return Ok(FileOutcome::Continue);
+        }
+
+        let dir = if dir.starts_with('~') {
+            let mut home = expand_home(dir).to_string_lossy().to_string();
+            if !home.ends_with(std::path::is_separator) {
+                home.push(std::path::MAIN_SEPARATOR);
+            }
+            home
+        } else {
+            dir.to_string()
+        };
+        let dir_path = self.resolve_path(&dir);
+
+        let mut candidates = Vec::new();
+        if let Ok(rd) = dir_path.read_dir() {
+            for r in rd.flatten() {
+                let name = r.file_name().to_string_lossy().to_string();
+                if !name.starts_with(prefix) {
+                    continue;
+                }
+                let Ok(meta) = r.metadata() else {
+                    continue;
+                };
+                if meta.is_dir() {
+                    candidates.push((name, true));
+                } else if meta.is_file() && self.mode != Mode::Dir {
+                    if let Some(filter) = self.filter.as_ref() {
+                        if !filter(&r.path()) {
+                            continue;
+                        }
+                    }
+                    candidates.push((name, false));
+                }
+            }
+        }
+        candidates.sort();
+
+        let txt = match candidates.len() {
+            0 => {
+                return Ok(FileOutcome::Unchanged);
+            }
+            1 => {
+                let (name, is_dir) = &candidates[0];
+                if *is_dir {
+                    format!("{}{}{}", dir, name, std::path::MAIN_SEPARATOR)
+                } else {
+                    format!("{}{}", dir, name)
+                }
+            }
+            _ => {
+                let common = common_prefix(candidates.iter().map(|v| v.0.as_str()));
+                if common.len() > prefix.len() {
+                    format!("{}{}", dir, common)
+                } else {
+                    let txt = format!("{}{}", dir, candidates[0].0);
+                    self.completion = Some(Completion {
+                        text: txt.clone(),
+                        dir,
+                        candidates: candidates.into_iter().map(|v| v.0).collect(),
+                        idx: 0,
+                    });
+                    txt
+                }
+            }
+        };
+
+        self.path_state.invalid = false;
+        self.path_state.set_text(txt);
+        self.path_state.move_to_line_end(false);
         Ok(FileOutcome::Changed)
     }
 
@@ -892,14 +1228,33 @@ impl FileDialogState {
 
     fn chroot_selected(&mut self) -> Result<FileOutcome, io::Error> {
         if let Some(select) = self.root_state.selected() {
-            if let Some(d) = self.roots.get(select).cloned() {
-                self.set_path(&d.1)?;
+            let path = if select < self.roots.len() {
+                self.roots.get(select).map(|v| v.1.clone())
+            } else {
+                self.recent.get(select - self.roots.len()).cloned()
+            };
+            if let Some(path) = path {
+                self.set_path(&path)?;
                 return Ok(FileOutcome::Changed);
             }
         }
         Ok(FileOutcome::Unchanged)
     }
 
+    /// Jump to the next root.
+    fn chroot_next(&mut self) -> Result<FileOutcome, io::Error> {
+        let len = self.roots.len() + self.recent.len();
+        if len == 0 {
+            return Ok(FileOutcome::Unchanged);
+        }
+        let next = match self.root_state.selected() {
+            Some(n) => (n + 1) % len,
+            None => 0,
+        };
+        self.root_state.select(Some(next));
+        self.chroot_selected()
+    }
+
     fn chdir_selected(&mut self) -> Result<FileOutcome, io::Error> {
         if let Some(select) = self.dir_state.list.selected() {
             if let Some(dir) = self.dirs.get(select).cloned() {
@@ -966,6 +1321,15 @@ impl FileDialogState {
         }
     }
 
+    /// Act as if the button was pressed.
+    fn activate(&mut self, button: FileDialogButton) -> FileOutcome {
+        match button {
+            FileDialogButton::Ok => self.choose_selected(),
+            FileDialogButton::Cancel => self.close_cancel(),
+            FileDialogButton::New => self.start_edit_dir(),
+        }
+    }
+
     /// Cancel the dialog.
     fn close_cancel(&mut self) -> FileOutcome {
         self.active = false;
@@ -1022,7 +1386,8 @@ impl HasScreenCursor for FileDialogState {
 
 impl FileDialogState {
     fn focus(&self) -> Focus {
-        let mut fb = FocusBuilder::default();
+        // Tab cycles within the dialog.
+        let mut fb = FocusBuilder::default().policy(NavigationPolicy::Wrap);
         fb.widget(&self.dir_state);
         if self.mode == Mode::Save || self.mode == Mode::Open {
             fb.widget(&self.file_state);
@@ -1051,6 +1416,9 @@ impl HandleEvent<crossterm::event::Event, Dialog, Result<FileOutcome, io::Error>
             return Ok(FileOutcome::Continue);
         }
 
+        // Tab completion goes before the focus.
+        try_flow!(handle_complete(self, event)?);
+
         let f: FileOutcome = self.focus().handle(event, Regular).into();
         let f = f.and_try(|| {
             handle_path(self, event)?
@@ -1066,9 +1434,14 @@ impl HandleEvent<crossterm::event::Event, Dialog, Result<FileOutcome, io::Error>
                 .or_else_try(|| handle_roots(self, event))?
                 .or_else_try(|| handle_new(self, event))?
                 .or_else_try(|| handle_cancel(self, event))?
-                .or_else_try(|| handle_ok(self, event))
+                .or_else_try(|| handle_ok(self, event))?
+                .or_else_try(|| handle_default_keys(self, event))
         })?;
 
+        if matches!(f, FileOutcome::Ok(_)) {
+            self.remember_path();
+        }
+
         Ok(max(f, FileOutcome::Unchanged))
     }
 }
@@ -1113,10 +1486,23 @@ fn handle_cancel(
         }
         r => Outcome::from(r).into(),
     });
+    Ok(FileOutcome::Continue)
+}
+
+/// Enter and Esc if no one else wants them.
+fn handle_default_keys(
+    state: &mut FileDialogState,
+    event: &crossterm::event::Event,
+) -> Result<FileOutcome, io::Error> {
     try_flow!(match event {
-        ct_event!(keycode press Esc) => {
-            state.close_cancel()
-        }
+        ct_event!(keycode press Enter) => match state.default_button {
+            Some(button) => state.activate(button),
+            None => FileOutcome::Continue,
+        },
+        ct_event!(keycode press Esc) => match state.cancel_button {
+            Some(button) => state.activate(button),
+            None => FileOutcome::Continue,
+        },
         _ => FileOutcome::Continue,
     });
     Ok(FileOutcome::Continue)
@@ -1138,6 +1524,21 @@ fn handle_name(
     Ok(FileOutcome::Continue)
 }
 
+fn handle_complete(
+    state: &mut FileDialogState,
+    event: &crossterm::event::Event,
+) -> Result<FileOutcome, io::Error> {
+    if state.path_state.is_focused() {
+        try_flow!(match event {
+            ct_event!(keycode press Tab) => {
+                state.complete_path()?
+            }
+            _ => FileOutcome::Continue,
+        });
+    }
+    Ok(FileOutcome::Continue)
+}
+
 fn handle_path(
     state: &mut FileDialogState,
     event: &crossterm::event::Event,
@@ -1147,7 +1548,13 @@ fn handle_path(
         try_flow!(match event {
             ct_event!(keycode press Enter) => {
                 state.use_path_input()?;
-                state.focus().focus_no_lost(&state.dir_state.list);
+                if !state.path_state.invalid {
+                    if state.file_state.selected().is_some() && state.mode != Mode::Dir {
+                        state.focus().focus_no_lost(&state.file_state);
+                    } else {
+                        state.focus().focus_no_lost(&state.dir_state.list);
+                    }
+                }
                 FileOutcome::Changed
             }
             _ => FileOutcome::Continue,
@@ -1171,6 +1578,12 @@ fn handle_roots(
         }
         r => r.into(),
     });
+    try_flow!(match event {
+        ct_event!(key press CONTROL-'b') => {
+            state.chroot_next()?
+        }
+        _ => FileOutcome::Continue,
+    });
     Ok(FileOutcome::Continue)
 }
 
@@ -1262,6 +1675,45 @@ fn handle_nav(
     FileOutcome::Continue
 }
 
+/// Expand a leading `~` to the home directory.
+fn expand_home(txt: &str) -> PathBuf {
+    let rest = if txt == "~" {
+        Some("")
+    } else {
+        txt.strip_prefix('~')
+            .and_then(|v| v.strip_prefix(std::path::is_separator))
+    };
+    match (rest, home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(txt),
+    }
+}
+
+#[cfg(not(feature = "user_directories"))]
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// Longest common prefix.
+fn common_prefix<'a>(mut names: impl Iterator<Item = &'a str>) -> &'a str {
+    let Some(first) = names.next() else {
+        return "";
+    };
+    let mut len = first.len();
+    for name in names {
+        len = first
+            .char_indices()
+            .zip(name.chars())
+            .take_while(|((idx, c0), c1)| *idx < len && c0 == c1)
+            .last()
+            .map(|((idx, c0), _)| idx + c0.len_utf8())
+            .unwrap_or(0);
+    }
+    &first[..len]
+}
+
 #[allow(clippy::question_mark)]
 fn find_next_by_key(c: char, start: usize, names: &[OsString]) -> Option<usize> {
     let Some(c) = c.to_lowercase().next() else {
@@ -0,0 +1,336 @@
+//!
+//! Copy data between a struct and the widget states of a form.
+//!
+//! ```rust
+//! # use rat_widget::checkbox::CheckboxState;
+//! # use rat_widget::form_binder::FormBinder;
+//! # use rat_widget::number_input::NumberInputState;
+//! # use rat_widget::text_input::TextInputState;
+//! struct Person {
+//!     name: String,
+//!     age: u32,
+//!     active: bool,
+//! }
+//!
+//! struct PersonForm {
+//!     name: TextInputState,
+//!     age: NumberInputState,
+//!     active: CheckboxState,
+//! }
+//!
+//! # let mut form = PersonForm {
+//! #     name: TextInputState::named("name"),
+//! #     age: NumberInputState::named("age").with_pattern("###").expect("pattern"),
+//! #     active: CheckboxState::named("active"),
+//! # };
+//! let mut data = Person {
+//!     name: "Kim".into(),
+//!     age: 42,
+//!     active: true,
+//! };
+//!
+//! let mut binder = FormBinder::new();
+//! binder.bind_text(&mut form.name, |d: &Person| &d.name, |d, v| d.name = v);
+//! binder.bind_number(&mut form.age, |d: &Person| d.age, |d, v| d.age = v);
+//! binder.bind_checkbox(&mut form.active, |d: &Person| d.active, |d, v| d.active = v);
+//!
+//! binder.load(&data);
+//! // ... edit
+//! if let Err(errors) = binder.store(&mut data) {
+//!     // focus errors[0].focus
+//! }
+//! ```
+//!
+
+use crate::checkbox::CheckboxState;
+use crate::choice::ChoiceState;
+use crate::date_input::DateInputState;
+use crate::number_input::NumberInputState;
+use crate::radio::RadioState;
+use crate::text_input::TextInputState;
+use chrono::NaiveDate;
+use rat_focus::{FocusFlag, HasFocus};
+use std::fmt::{Debug, Display, Formatter, LowerExp};
+use std::str::FromStr;
+
+/// Error while storing a field.
+#[derive(Debug, Clone)]
+pub struct FieldError {
+    /// Focus of the widget.
+    pub focus: FocusFlag,
+    /// Error message.
+    pub message: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.focus.name(), self.message)
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+/// One binding.
+trait Binding<D> {
+    fn focus(&self) -> FocusFlag;
+
+    fn load(&mut self, data: &D);
+
+    fn store(&mut self, data: &mut D) -> Result<(), String>;
+}
+
+struct FieldBinding<'a, S, L, T> {
+    state: &'a mut S,
+    load: L,
+    store: T,
+}
+
+impl<D, S, L, T> Binding<D> for FieldBinding<'_, S, L, T>
+where
+    S: HasFocus,
+    L: FnMut(&mut S, &D),
+    T: FnMut(&mut S, &mut D) -> Result<(), String>,
+{
+    fn focus(&self) -> FocusFlag {
+        self.state.focus()
+    }
+
+    fn load(&mut self, data: &D) {
+        (self.load)(self.state, data)
+    }
+
+    fn store(&mut self, data: &mut D) -> Result<(), String> {
+        (self.store)(self.state, data)
+    }
+}
+
+/// Binds the widget states of a form to the fields of a struct.
+///
+/// Register each widget state with accessor functions, then
+/// use [load](FormBinder::load) and [store](FormBinder::store)
+/// to transfer the data.
+pub struct FormBinder<'a, D> {
+    bindings: Vec<Box<dyn Binding<D> + 'a>>,
+}
+
+impl<D> Default for FormBinder<'_, D> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<D> Debug for FormBinder<'_, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormBinder")
+            .field(
+                "bindings",
+                &self.bindings.iter().map(|v| v.focus()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<'a, D> FormBinder<'a, D> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind any widget state.
+    ///
+    /// * load: copy from the data to the widget.
+    /// * store: copy from the widget to the data. Returns an error
+    ///   message if the value can't be parsed or is invalid.
+    pub fn bind<S: HasFocus>(
+        &mut self,
+        state: &'a mut S,
+        load: impl FnMut(&mut S, &D) + 'a,
+        store: impl FnMut(&mut S, &mut D) -> Result<(), String> + 'a,
+    ) -> &mut Self {
+        self.bindings
+            .push(Box::new(FieldBinding { state, load, store }));
+        self
+    }
+
+    /// Bind a text-input.
+    pub fn bind_text(
+        &mut self,
+        state: &'a mut TextInputState,
+        get: impl Fn(&D) -> &String + 'a,
+        set: impl Fn(&mut D, String) + 'a,
+    ) -> &mut Self {
+        self.bind(
+            state,
+            move |s, d| s.set_text(get(d).as_str()),
+            move |s, d| {
+                set(d, s.text().to_string());
+                Ok(())
+            },
+        )
+    }
+
+    /// Bind a number-input.
+    ///
+    /// Parse errors mark the offending part of the widget as invalid.
+    pub fn bind_number<T>(
+        &mut self,
+        state: &'a mut NumberInputState,
+        get: impl Fn(&D) -> T + 'a,
+        set: impl Fn(&mut D, T) + 'a,
+    ) -> &mut Self
+    where
+        T: FromStr + LowerExp + Display + Debug,
+    {
+        self.bind(
+            state,
+            move |s, d| {
+                let r = s.set_value(get(d));
+                s.set_invalid(r.is_err());
+            },
+            move |s, d| match s.value::<T>() {
+                Ok(v) => {
+                    s.set_value_error(None);
+                    set(d, v);
+                    Ok(())
+                }
+                Err(e) => {
+                    s.set_value_error(Some(&e));
+                    Err(e.to_string())
+                }
+            },
+        )
+    }
+
+    /// Bind a date-input.
+    ///
+    /// Parse errors mark the offending part of the widget as invalid.
+    pub fn bind_date(
+        &mut self,
+        state: &'a mut DateInputState,
+        get: impl Fn(&D) -> NaiveDate + 'a,
+        set: impl Fn(&mut D, NaiveDate) + 'a,
+    ) -> &mut Self {
+        self.bind(
+            state,
+            move |s, d| {
+                s.set_value(get(d));
+                s.set_invalid(false);
+            },
+            move |s, d| match s.value() {
+                Ok(v) => {
+                    s.set_value_error(None);
+                    set(d, v);
+                    Ok(())
+                }
+                Err(e) => {
+                    s.set_value_error(Some(&e));
+                    Err(e.to_string())
+                }
+            },
+        )
+    }
+
+    /// Bind a checkbox.
+    pub fn bind_checkbox(
+        &mut self,
+        state: &'a mut CheckboxState,
+        get: impl Fn(&D) -> bool + 'a,
+        set: impl Fn(&mut D, bool) + 'a,
+    ) -> &mut Self {
+        self.bind(
+            state,
+            move |s, d| {
+                s.set_value(get(d));
+            },
+            move |s, d| {
+                set(d, s.value());
+                Ok(())
+            },
+        )
+    }
+
+    /// Bind a choice.
+    pub fn bind_choice<T>(
+        &mut self,
+        state: &'a mut ChoiceState<T>,
+        get: impl Fn(&D) -> T + 'a,
+        set: impl Fn(&mut D, T) + 'a,
+    ) -> &mut Self
+    where
+        T: PartialEq + Clone + Default,
+    {
+        self.bind(
+            state,
+            move |s, d| {
+                s.set_value(get(d));
+            },
+            move |s, d| {
+                set(d, s.value());
+                Ok(())
+            },
+        )
+    }
+
+    /// Bind a radio-group.
+    pub fn bind_radio<T>(
+        &mut self,
+        state: &'a mut RadioState<T>,
+        get: impl Fn(&D) -> T + 'a,
+        set: impl Fn(&mut D, T) + 'a,
+    ) -> &mut Self
+    where
+        T: PartialEq + Clone + Default,
+    {
+        self.bind(
+            state,
+            move |s, d| {
+                s.set_value(get(d));
+            },
+            move |s, d| {
+                set(d, s.value());
+                Ok(())
+            },
+        )
+    }
+
+    /// Number of bindings.
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    /// No bindings.
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Copy the data to the widgets.
+    pub fn load(&mut self, data: &D) {
+        for b in self.bindings.iter_mut() {
+            b.load(data);
+        }
+    }
+
+    /// Copy the widget values to the data.
+    ///
+    /// All fields are processed, even if some fail. The fields
+    /// that failed leave the data unchanged. The errors are
+    /// returned in the order of the bindings, so the first one
+    /// can be used to focus the first bad field.
+    pub fn store(&mut self, data: &mut D) -> Result<(), Vec<FieldError>> {
+        let mut errors = Vec::new();
+        for b in self.bindings.iter_mut() {
+            if let Err(message) = b.store(data) {
+                errors.push(FieldError {
+                    focus: b.focus(),
+                    message,
+                });
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//!
+//! Key-hint bar that shows the keys for the focused widget.
+//!
+//! Hint sets are registered with the [KeyHintsState] per [FocusFlag].
+//! When rendering, the [Focus] is queried for the currently focused
+//! widget and its hints are shown. If the area is too narrow the hints
+//! with the lowest priority are dropped first.
+//!
+//! ```rust ignore
+//! static LIST_HINTS: &[KeyHint] = &[
+//!     KeyHint::new("Enter", "open").priority(2),
+//!     KeyHint::new("F2", "rename"),
+//!     KeyHint::new("Del", "delete"),
+//! ];
+//!
+//! state.hints.register(&state.list.focus, LIST_HINTS);
+//!
+//! // stateful hints
+//! state.hints.set_dynamic(&state.player.focus, vec![
+//!     KeyHint::new("Space", if state.paused { "resume" } else { "pause" }),
+//! ]);
+//!
+//! // render
+//! KeyHints::new()
+//!     .focus(&focus)
+//!     .styles(theme.key_hints_style())
+//!     .render(status_area, buf, &mut state.hints);
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use rat_focus::{Focus, FocusFlag};
+use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{StatefulWidget, Widget};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use unicode_display_width::width as unicode_width;
+
+/// One key hint.
+///
+/// Can be constructed in a const context, so hint sets
+/// can be static slices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyHint {
+    /// Key chord, e.g. `Ctrl+S`.
+    pub key: Cow<'static, str>,
+    /// Description.
+    pub text: Cow<'static, str>,
+    /// Hints with a lower priority are dropped first,
+    /// if there is not enough space.
+    pub priority: u8,
+}
+
+/// Renders the key hints for the focused widget.
+#[derive(Debug, Clone)]
+pub struct KeyHints<'a> {
+    focus: Option<&'a Focus>,
+
+    style: Style,
+    key_style: Option<Style>,
+    spacing: u16,
+}
+
+/// Combined style.
+#[derive(Debug, Clone)]
+pub struct KeyHintsStyle {
+    pub style: Style,
+    /// Key chord.
+    pub key: Option<Style>,
+    /// Space between two hints.
+    pub spacing: Option<u16>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State & registry of the hint sets.
+#[derive(Debug, Clone)]
+pub struct KeyHintsState {
+    /// Total area.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Areas for each rendered hint.
+    /// __readonly__. renewed for each render.
+    pub areas: Vec<Rect>,
+    /// Hints that have been dropped for lack of space.
+    /// __readonly__. renewed for each render.
+    pub collapsed: Vec<usize>,
+
+    /// Static hint sets.
+    static_hints: HashMap<FocusFlag, &'static [KeyHint]>,
+    /// Dynamic hint sets. These override the static ones.
+    dynamic_hints: HashMap<FocusFlag, Vec<KeyHint>>,
+    /// Hints when nothing registered has the focus.
+    default_hints: &'static [KeyHint],
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl KeyHint {
+    /// New hint with priority 0.
+    pub const fn new(key: &'static str, text: &'static str) -> Self {
+        Self {
+            key: Cow::Borrowed(key),
+            text: Cow::Borrowed(text),
+            priority: 0,
+        }
+    }
+
+    /// New hint with owned texts.
+    pub fn owned(key: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            key: Cow::Owned(key.into()),
+            text: Cow::Owned(text.into()),
+            priority: 0,
+        }
+    }
+
+    /// Priority.
+    pub const fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Display width of key and text.
+    fn width(&self) -> u16 {
+        (unicode_width(&self.key) + 1 + unicode_width(&self.text)) as u16
+    }
+}
+
+impl Default for KeyHintsStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            key: None,
+            spacing: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Default for KeyHints<'_> {
+    fn default() -> Self {
+        Self {
+            focus: None,
+            style: Default::default(),
+            key_style: None,
+            spacing: 2,
+        }
+    }
+}
+
+impl<'a> KeyHints<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Focus used to find the focused widget.
+    ///
+    /// Without this only the default hints are shown.
+    pub fn focus(mut self, focus: &'a Focus) -> Self {
+        self.focus = Some(focus);
+        self
+    }
+
+    /// Set all styles.
+    pub fn styles(mut self, styles: KeyHintsStyle) -> Self {
+        self.style = styles.style;
+        if styles.key.is_some() {
+            self.key_style = styles.key;
+        }
+        if let Some(spacing) = styles.spacing {
+            self.spacing = spacing;
+        }
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style for the key chords.
+    pub fn key_style(mut self, style: Style) -> Self {
+        self.key_style = Some(style);
+        self
+    }
+
+    /// Space between two hints.
+    ///
+    /// Default is 2.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl StatefulWidgetRef for KeyHints<'_> {
+    type State = KeyHintsState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for KeyHints<'_> {
+    type State = KeyHintsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self, area, buf, state);
+    }
+}
+
+fn render_ref(widget: &KeyHints<'_>, area: Rect, buf: &mut Buffer, state: &mut KeyHintsState) {
+    state.area = area;
+    state.areas.clear();
+    state.collapsed.clear();
+
+    buf.set_style(area, widget.style);
+
+    let key_style = widget.key_style.unwrap_or(widget.style.bold());
+
+    let focused = widget.focus.and_then(|v| v.focused());
+    let hints = state.hints(focused.as_ref());
+    let collapsed = collapse(hints, area.width, widget.spacing);
+
+    let mut areas = Vec::new();
+    let mut x = area.x;
+    for (n, hint) in hints.iter().enumerate() {
+        if collapsed.contains(&n) {
+            continue;
+        }
+        if x > area.x {
+            x = x.saturating_add(widget.spacing);
+        }
+        let hint_area = Rect::new(x, area.y, hint.width(), 1).intersection(area);
+        Line::from(vec![
+            Span::from(hint.key.as_ref()).style(key_style),
+            Span::from(" "),
+            Span::from(hint.text.as_ref()),
+        ])
+        .render(hint_area, buf);
+        areas.push(hint_area);
+        x = x.saturating_add(hint.width());
+    }
+
+    state.areas = areas;
+    state.collapsed = collapsed;
+}
+
+/// Hints that must be dropped to fit the width.
+///
+/// With equal priority the hints at the end are dropped first.
+fn collapse(hints: &[KeyHint], width: u16, spacing: u16) -> Vec<usize> {
+    let mut total = hints.iter().map(|v| v.width() as u32).sum::<u32>()
+        + (hints.len().saturating_sub(1) as u32) * spacing as u32;
+
+    let mut order = (0..hints.len()).rev().collect::<Vec<_>>();
+    order.sort_by_key(|v| hints[*v].priority);
+
+    let mut collapsed = Vec::new();
+    for idx in order {
+        if total <= width as u32 {
+            break;
+        }
+        total = total.saturating_sub(hints[idx].width() as u32 + spacing as u32);
+        collapsed.push(idx);
+    }
+    collapsed.sort();
+    collapsed
+}
+
+impl Default for KeyHintsState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            areas: Default::default(),
+            collapsed: Default::default(),
+            static_hints: Default::default(),
+            dynamic_hints: Default::default(),
+            default_hints: &[],
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RelocatableState for KeyHintsState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        relocate_areas(self.areas.as_mut(), shift, clip);
+    }
+}
+
+impl KeyHintsState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the hints for a widget.
+    pub fn register(&mut self, focus: &FocusFlag, hints: &'static [KeyHint]) {
+        self.static_hints.insert(focus.clone(), hints);
+    }
+
+    /// Remove all hints for a widget.
+    pub fn unregister(&mut self, focus: &FocusFlag) {
+        self.static_hints.remove(focus);
+        self.dynamic_hints.remove(focus);
+    }
+
+    /// Set hints for a widget that override the registered ones.
+    ///
+    /// Use this for hints that depend on the state of the widget.
+    pub fn set_dynamic(&mut self, focus: &FocusFlag, hints: Vec<KeyHint>) {
+        self.dynamic_hints.insert(focus.clone(), hints);
+    }
+
+    /// Remove the override. The registered hints apply again.
+    pub fn clear_dynamic(&mut self, focus: &FocusFlag) {
+        self.dynamic_hints.remove(focus);
+    }
+
+    /// Hints that are shown if no registered widget has the focus.
+    pub fn set_default(&mut self, hints: &'static [KeyHint]) {
+        self.default_hints = hints;
+    }
+
+    /// The hints for the given widget.
+    pub fn hints(&self, focus: Option<&FocusFlag>) -> &[KeyHint] {
+        if let Some(focus) = focus {
+            if let Some(hints) = self.dynamic_hints.get(focus) {
+                return hints.as_slice();
+            }
+            if let Some(hints) = self.static_hints.get(focus) {
+                return hints;
+            }
+        }
+        self.default_hints
+    }
+}
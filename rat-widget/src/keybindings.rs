@@ -0,0 +1,520 @@
+//!
+//! Declarative key-binding table and a generated help overlay.
+//!
+//! [KeyBindings] collects (context, chord, description) entries.
+//! The same table can be used for dispatch via [KeyBindings::lookup]
+//! and for a cheat-sheet with [HelpOverlay].
+//!
+//! ```rust ignore
+//! let mut keys = KeyBindings::new();
+//! keys.add("Global", KeyCode::F(1), KeyModifiers::NONE, "Help")
+//!     .add("Global", KeyCode::Char('q'), KeyModifiers::CONTROL, "Quit")
+//!     .add("Editor", KeyCode::Char('s'), KeyModifiers::CONTROL, "Save");
+//!
+//! // render
+//! HelpOverlay::new(&keys)
+//!     .styles(theme.help_style())
+//!     .render(dialog_area, buf, &mut state.help);
+//!
+//! // events
+//! flow!(state.help.handle(event, Dialog));
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::util::reset_buf_area;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use rat_event::{ct_event, flow, Dialog, HandleEvent, MouseOnly, Outcome};
+use rat_reloc::{relocate_area, RelocatableState};
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+
+/// One key-binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    /// Context, e.g. the name of the widget or screen.
+    pub context: String,
+    /// Key code.
+    pub code: KeyCode,
+    /// Modifiers.
+    pub modifiers: KeyModifiers,
+    /// Description.
+    pub description: String,
+}
+
+/// Table of key-bindings.
+#[derive(Debug, Default, Clone)]
+pub struct KeyBindings {
+    bindings: Vec<Binding>,
+}
+
+/// Renders the key-bindings grouped by context.
+///
+/// This is a modal overlay. Typing filters the descriptions,
+/// Esc closes it.
+#[derive(Debug, Clone)]
+pub struct HelpOverlay<'a> {
+    bindings: &'a KeyBindings,
+
+    style: Style,
+    key_style: Option<Style>,
+    context_style: Option<Style>,
+    filter_style: Option<Style>,
+    block: Option<Block<'a>>,
+    scroll: Option<Scroll<'a>>,
+}
+
+/// Combined style.
+#[derive(Debug, Clone)]
+pub struct HelpStyle {
+    pub style: Style,
+    /// Key chord.
+    pub key: Option<Style>,
+    /// Context header.
+    pub context: Option<Style>,
+    /// Filter line.
+    pub filter: Option<Style>,
+    pub block: Option<Block<'static>>,
+    pub scroll: Option<ScrollStyle>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State & event handling.
+#[derive(Debug, Clone)]
+pub struct HelpOverlayState {
+    /// Full area.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Area inside the block.
+    /// __readonly__. renewed for each render.
+    pub inner: Rect,
+
+    /// Overlay is active.
+    /// __read+write__
+    pub active: bool,
+    /// Filter for the descriptions.
+    /// __read+write__
+    pub filter: String,
+    /// Vertical offset.
+    /// __read+write__
+    pub scroll: ScrollState,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Binding {
+    /// Does the key-event match this binding.
+    ///
+    /// For characters the SHIFT modifier is not compared,
+    /// the case of the character is sufficient.
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        if event.kind == KeyEventKind::Release {
+            return false;
+        }
+        match (self.code, event.code) {
+            (KeyCode::Char(c0), KeyCode::Char(c1)) => {
+                c0 == c1
+                    && self.modifiers - KeyModifiers::SHIFT == event.modifiers - KeyModifiers::SHIFT
+            }
+            (c0, c1) => c0 == c1 && self.modifiers == event.modifiers,
+        }
+    }
+
+    /// Display text for the key chord, e.g. `Ctrl+S`.
+    pub fn chord(&self) -> String {
+        let mut s = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            s.push_str("Ctrl+");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            s.push_str("Alt+");
+        }
+        if self.modifiers.contains(KeyModifiers::SUPER) {
+            s.push_str("Super+");
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT)
+            && !matches!(self.code, KeyCode::Char(_) | KeyCode::BackTab)
+        {
+            s.push_str("Shift+");
+        }
+        match self.code {
+            KeyCode::Char(' ') => s.push_str("Space"),
+            KeyCode::Char(c) if self.modifiers.is_empty() => s.push(c),
+            KeyCode::Char(c) => s.push(c.to_ascii_uppercase()),
+            KeyCode::F(n) => s.push_str(&format!("F{}", n)),
+            KeyCode::BackTab => s.push_str("Shift+Tab"),
+            KeyCode::PageUp => s.push_str("PgUp"),
+            KeyCode::PageDown => s.push_str("PgDn"),
+            c => s.push_str(&format!("{:?}", c)),
+        }
+        s
+    }
+}
+
+impl KeyBindings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a binding.
+    pub fn add(
+        &mut self,
+        context: impl Into<String>,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+        description: impl Into<String>,
+    ) -> &mut Self {
+        self.bindings.push(Binding {
+            context: context.into(),
+            code,
+            modifiers,
+            description: description.into(),
+        });
+        self
+    }
+
+    /// All bindings.
+    pub fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+
+    /// Contexts in the order of their first use.
+    pub fn contexts(&self) -> Vec<&str> {
+        let mut contexts = Vec::<&str>::new();
+        for b in &self.bindings {
+            if !contexts.contains(&b.context.as_str()) {
+                contexts.push(b.context.as_str());
+            }
+        }
+        contexts
+    }
+
+    /// All bindings that match the key-event.
+    pub fn lookup<'a>(&'a self, event: &'a KeyEvent) -> impl Iterator<Item = &'a Binding> + 'a {
+        self.bindings.iter().filter(|v| v.matches(event))
+    }
+
+    /// All bindings for the context that match the key-event.
+    pub fn lookup_in<'a>(
+        &'a self,
+        context: &'a str,
+        event: &'a KeyEvent,
+    ) -> impl Iterator<Item = &'a Binding> + 'a {
+        self.lookup(event).filter(move |v| v.context == context)
+    }
+
+    /// Bindings grouped by context, filtered by description.
+    /// The filter is case-insensitive.
+    fn grouped(&self, filter: &str) -> Vec<(&str, Vec<&Binding>)> {
+        let filter = filter.to_lowercase();
+        self.contexts()
+            .into_iter()
+            .map(|ctx| {
+                let items = self
+                    .bindings
+                    .iter()
+                    .filter(|v| v.context == ctx)
+                    .filter(|v| v.description.to_lowercase().contains(&filter))
+                    .collect::<Vec<_>>();
+                (ctx, items)
+            })
+            .filter(|(_, items)| !items.is_empty())
+            .collect()
+    }
+}
+
+impl Default for HelpStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            key: None,
+            context: None,
+            filter: None,
+            block: None,
+            scroll: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<'a> HelpOverlay<'a> {
+    pub fn new(bindings: &'a KeyBindings) -> Self {
+        Self {
+            bindings,
+            style: Default::default(),
+            key_style: None,
+            context_style: None,
+            filter_style: None,
+            block: None,
+            scroll: None,
+        }
+    }
+
+    /// Set all styles.
+    pub fn styles(mut self, styles: HelpStyle) -> Self {
+        self.style = styles.style;
+        if styles.key.is_some() {
+            self.key_style = styles.key;
+        }
+        if styles.context.is_some() {
+            self.context_style = styles.context;
+        }
+        if styles.filter.is_some() {
+            self.filter_style = styles.filter;
+        }
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        if let Some(styles) = styles.scroll {
+            self.scroll = self.scroll.map(|v| v.styles(styles));
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self.block = self.block.map(|v| v.style(style));
+        self
+    }
+
+    /// Style for the key chords.
+    pub fn key_style(mut self, style: Style) -> Self {
+        self.key_style = Some(style);
+        self
+    }
+
+    /// Style for the context headers.
+    pub fn context_style(mut self, style: Style) -> Self {
+        self.context_style = Some(style);
+        self
+    }
+
+    /// Style for the filter line.
+    pub fn filter_style(mut self, style: Style) -> Self {
+        self.filter_style = Some(style);
+        self
+    }
+
+    /// Block.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block.style(self.style));
+        self
+    }
+
+    /// Scroll.
+    pub fn scroll(mut self, scroll: Scroll<'a>) -> Self {
+        self.scroll = Some(scroll);
+        self
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl StatefulWidgetRef for HelpOverlay<'_> {
+    type State = HelpOverlayState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for HelpOverlay<'_> {
+    type State = HelpOverlayState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self, area, buf, state);
+    }
+}
+
+fn render_ref(
+    widget: &HelpOverlay<'_>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut HelpOverlayState,
+) {
+    if !state.active {
+        return;
+    }
+
+    state.area = area;
+
+    let sa = ScrollArea::new()
+        .block(widget.block.as_ref())
+        .v_scroll(widget.scroll.as_ref());
+    state.inner = sa.inner(area, None, Some(&state.scroll));
+
+    let key_style = widget.key_style.unwrap_or(widget.style.bold());
+    let context_style = widget.context_style.unwrap_or(widget.style.underlined());
+    let filter_style = widget.style.patch(widget.filter_style.unwrap_or_default());
+
+    // content
+    let groups = widget.bindings.grouped(&state.filter);
+    let key_width = groups
+        .iter()
+        .flat_map(|(_, v)| v.iter())
+        .map(|v| v.chord().chars().count())
+        .max()
+        .unwrap_or(0) as u16;
+    let mut lines = Vec::new();
+    for (n, (ctx, items)) in groups.iter().enumerate() {
+        if n > 0 {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::from(*ctx).style(context_style)));
+        for b in items {
+            let chord = b.chord();
+            let pad = " ".repeat(key_width as usize + 2 - chord.chars().count());
+            lines.push(Line::from(vec![
+                Span::from(format!(" {}", chord)).style(key_style),
+                Span::from(pad),
+                Span::from(b.description.as_str()),
+            ]));
+        }
+    }
+
+    // the filter line takes one row.
+    let page = state.inner.height.saturating_sub(1) as usize;
+    state.scroll.set_page_len(page);
+    state
+        .scroll
+        .set_max_offset(lines.len().saturating_sub(page));
+    let offset = state.scroll.limit_offset(state.scroll.offset());
+    state.scroll.set_offset(offset);
+
+    reset_buf_area(area, buf);
+    buf.set_style(area, widget.style);
+    sa.render(
+        area,
+        buf,
+        &mut ScrollAreaState::new().v_scroll(&mut state.scroll),
+    );
+
+    let inner = state.inner;
+    if inner.is_empty() {
+        return;
+    }
+
+    let filter_area = Rect::new(inner.x, inner.y, inner.width, 1);
+    buf.set_style(filter_area, filter_style);
+    Line::from(format!("Filter: {}", state.filter)).render(filter_area, buf);
+
+    for (n, line) in lines.into_iter().skip(offset).take(page).enumerate() {
+        let row_area = Rect::new(inner.x, inner.y + 1 + n as u16, inner.width, 1);
+        line.render(row_area, buf);
+    }
+}
+
+impl Default for HelpOverlayState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            active: false,
+            filter: Default::default(),
+            scroll: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RelocatableState for HelpOverlayState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        self.scroll.relocate(shift, clip);
+    }
+}
+
+impl HelpOverlayState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the overlay. Resets filter and offset.
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        self.filter.clear();
+        self.scroll.set_offset(0);
+    }
+
+    /// Overlay is active.
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    /// Set the filter.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        self.scroll.set_offset(0);
+    }
+}
+
+/// Modal event-handling. Consumes all events while active.
+impl HandleEvent<crossterm::event::Event, Dialog, Outcome> for HelpOverlayState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Dialog) -> Outcome {
+        if !self.active {
+            return Outcome::Continue;
+        }
+
+        flow!(match event {
+            ct_event!(keycode press Esc) => {
+                self.set_active(false);
+                Outcome::Changed
+            }
+            ct_event!(keycode press Backspace) => {
+                if self.filter.pop().is_some() {
+                    self.scroll.set_offset(0);
+                    Outcome::Changed
+                } else {
+                    Outcome::Unchanged
+                }
+            }
+            ct_event!(key press c) | ct_event!(key press SHIFT-c) => {
+                self.filter.push(*c);
+                self.scroll.set_offset(0);
+                Outcome::Changed
+            }
+            ct_event!(keycode press Up) => self.scroll.scroll_up(1).into(),
+            ct_event!(keycode press Down) => self.scroll.scroll_down(1).into(),
+            ct_event!(keycode press PageUp) => {
+                self.scroll.scroll_up(self.scroll.page_len()).into()
+            }
+            ct_event!(keycode press PageDown) => {
+                self.scroll.scroll_down(self.scroll.page_len()).into()
+            }
+            ct_event!(keycode press Home) => self.scroll.set_offset(0).into(),
+            ct_event!(keycode press End) => self.scroll.set_offset(self.scroll.max_offset()).into(),
+            _ => Outcome::Continue,
+        });
+
+        flow!(self.handle(event, MouseOnly));
+
+        // mandatory consume everything else.
+        Outcome::Unchanged
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for HelpOverlayState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> Outcome {
+        let mut sas = ScrollAreaState::new()
+            .area(self.inner)
+            .v_scroll(&mut self.scroll);
+        match sas.handle(event, MouseOnly) {
+            ScrollOutcome::Up(v) => self.scroll.scroll_up(v).into(),
+            ScrollOutcome::Down(v) => self.scroll.scroll_down(v).into(),
+            ScrollOutcome::VPos(v) => self.scroll.set_offset(v).into(),
+            ScrollOutcome::Continue => Outcome::Continue,
+            ScrollOutcome::Unchanged => Outcome::Unchanged,
+            _ => Outcome::Changed,
+        }
+    }
+}
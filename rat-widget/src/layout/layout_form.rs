@@ -1,12 +1,16 @@
 use crate::layout::generic_layout::GenericLayout;
 use crate::util::block_padding;
 use ratatui::layout::{Flex, Rect, Size};
+use ratatui::style::Style;
+use ratatui::text::Line;
 use ratatui::widgets::{Block, Padding};
 use std::borrow::Cow;
 use std::cmp::{max, min};
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::mem;
 use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Label constraints.
 ///
@@ -127,7 +131,18 @@ pub enum FormWidget {
     WideStretchXY(u16, u16),
 }
 
-/// Create a layout with a single column of label+widget.
+/// Order of the widgets in a multi-column layout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FormFlow {
+    /// Fill the first column top to bottom, then the next one.
+    /// The columns are balanced by height.
+    #[default]
+    Down,
+    /// Fill each row left to right, then the next row.
+    Across,
+}
+
+/// Create a layout with columns of label+widget.
 ///
 /// There are a number of possible constraints that influence
 /// the exact layout: [FormLabel] and [FormWidget].
@@ -151,6 +166,24 @@ pub enum FormWidget {
 /// * Spacing/Line spacing.
 /// * Supports Flex.
 /// * Manual page breaks.
+/// * Section headers that span the full width.
+/// * A maximum label width. Labels that don't fit get an ellipsis.
+///
+/// __Columns__
+///
+/// With [columns](LayoutForm::columns) the widgets are distributed
+/// over multiple columns according to the [FormFlow]. Each column
+/// gets its own label width. Headers and widgets added with
+/// [widget_span](LayoutForm::widget_span) span all columns, and
+/// [column_break](LayoutForm::column_break) starts the next column
+/// or row manually.
+///
+/// The widgets are still added to the layout in the order they
+/// were defined, and both flows keep this order visually. So
+/// building the focus in the same order follows the layout.
+///
+/// With more than one column, groups/blocks are not supported.
+/// Flex, mirroring and the vertical stretch of widgets are ignored.
 ///
 /// ```rust no_run
 /// # use ratatui::buffer::Buffer;
@@ -253,6 +286,23 @@ where
     blocks: Vec<BlockDef>,
     /// Page breaks.
     page_breaks: Vec<usize>,
+    /// Headers before the widget at the index.
+    /// With the number of blocks started before the header.
+    headers: Vec<(usize, usize, Cow<'static, str>)>,
+    /// Header style.
+    header_style: Style,
+
+    /// Number of columns.
+    columns: u16,
+    /// Spacing between columns.
+    column_spacing: u16,
+    /// Widget flow for columns.
+    flow: FormFlow,
+    /// Column breaks before the widget at the index.
+    column_breaks: Vec<usize>,
+
+    /// limit for the label width.
+    label_limit: Option<u16>,
 
     /// maximum width
     max_label: u16,
@@ -294,6 +344,8 @@ where
     // optional bottom border. all containers that
     // do not end exactly at this widget contribute.
     opt_bottom_border: u16,
+    // spans all columns.
+    span: bool,
 }
 
 /// Tag for a group/block.
@@ -412,6 +464,13 @@ where
             flex: Default::default(),
             widgets: Default::default(),
             page_breaks: Default::default(),
+            headers: Default::default(),
+            header_style: Default::default(),
+            columns: 1,
+            column_spacing: 2,
+            flow: Default::default(),
+            column_breaks: Default::default(),
+            label_limit: None,
             max_label: Default::default(),
             max_widget: Default::default(),
             blocks: Default::default(),
@@ -474,6 +533,54 @@ where
         self
     }
 
+    /// Limit the label width. Longer labels are cut
+    /// and get an ellipsis.
+    pub fn max_label(mut self, width: u16) -> Self {
+        self.label_limit = Some(width);
+        self
+    }
+
+    /// Number of columns.
+    pub fn columns(mut self, columns: u16) -> Self {
+        self.columns = max(columns, 1);
+        self
+    }
+
+    /// Spacing between columns. Default is 2.
+    pub fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Flow of the widgets with more than one column.
+    pub fn flow(mut self, flow: FormFlow) -> Self {
+        self.flow = flow;
+        self
+    }
+
+    /// Style for the headers.
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Add a section header before the next widget.
+    ///
+    /// The header is one row high and spans all columns.
+    /// It is added to the layout as a block with a title.
+    pub fn header(&mut self, title: impl Into<Cow<'static, str>>) {
+        self.headers
+            .push((self.widgets.len(), self.blocks.len(), title.into()));
+    }
+
+    /// The next widget starts a new column. With [FormFlow::Across]
+    /// it starts a new row.
+    ///
+    /// Only used with more than one column.
+    pub fn column_break(&mut self) {
+        self.column_breaks.push(self.widgets.len());
+    }
+
     /// Start a group/block.
     ///
     /// This will create a block that covers all widgets added
@@ -552,6 +659,19 @@ where
     /// Add label + widget constraint.
     /// Key must be a unique identifier.
     pub fn widget(&mut self, key: W, label: FormLabel, widget: FormWidget) {
+        self.add_widget(key, label, widget, false);
+    }
+
+    /// Add label + widget constraint. The widget spans
+    /// all columns.
+    ///
+    /// The label uses the label width of the first column.
+    /// Key must be a unique identifier.
+    pub fn widget_span(&mut self, key: W, label: FormLabel, widget: FormWidget) {
+        self.add_widget(key, label, widget, true);
+    }
+
+    fn add_widget(&mut self, key: W, label: FormLabel, widget: FormWidget, span: bool) {
         // split label by sample
         let (label, label_str) = match label {
             FormLabel::Str(s) => {
@@ -598,6 +718,7 @@ where
             top_border: self.c_top,
             bottom_border: self.c_bottom,
             opt_bottom_border: self.c_bottom,
+            span,
         });
 
         // top padding is only used once.
@@ -615,6 +736,10 @@ where
 
     // Adjust widths to the available sapce.
     fn adjust_widths(&mut self, page_width: u16, border: Padding) {
+        if let Some(label_limit) = self.label_limit {
+            self.max_label = min(self.max_label, label_limit);
+        }
+
         // cut excess
         let page_width = page_width.saturating_sub(
             border.left + self.max_left_padding + self.max_right_padding + border.right,
@@ -778,6 +903,9 @@ where
     /// Calculate the layout for the given page size and padding.
     fn _layout<const ENDLESS: bool>(mut self, page: Size, border: Padding) -> GenericLayout<W> {
         self.validate_containers();
+        if self.columns > 1 {
+            return self._layout_columns::<ENDLESS>(page, border);
+        }
         self.adjust_widths(page.width, border);
         let pos_even = self.find_pos(page.width, border);
         let pos_odd = if self.mirror {
@@ -822,11 +950,13 @@ where
             // line spacing
             page.next_widget(self.line_spacing);
             // start container
-            for cc in self.blocks.iter_mut() {
-                if cc.range.start == idx {
-                    page.start_container(cc);
-                }
-            }
+            page.start_widget(
+                idx,
+                &mut self.blocks,
+                &self.headers,
+                self.header_style,
+                &mut tmp,
+            );
             // get areas + advance
             let (mut label_area, mut widget_area) = page.widget_area(&widget, pos);
             // end and push containers
@@ -884,11 +1014,13 @@ where
                 // line spacing
                 page.next_widget(self.line_spacing);
                 // start container
-                for cc in self.blocks.iter_mut() {
-                    if idx == cc.range.start {
-                        page.start_container(cc);
-                    }
-                }
+                page.start_widget(
+                    idx,
+                    &mut self.blocks,
+                    &self.headers,
+                    self.header_style,
+                    &mut tmp,
+                );
                 // get areas + advance
                 (label_area, widget_area) = page.widget_area(&widget, pos);
                 // end and push containers
@@ -906,7 +1038,12 @@ where
                 stretch_y.push(gen_layout.widget_len());
             }
             // add label + widget
-            gen_layout.add(widget.id.clone(), widget_area, widget.label_str, label_area);
+            gen_layout.add(
+                widget.id.clone(),
+                widget_area,
+                truncate_label(widget.label_str, label_area.width),
+                label_area,
+            );
             // pop reverts the ordering for render
             while let Some(cc) = tmp.pop() {
                 gen_layout.add_block(cc.area, cc.block);
@@ -946,6 +1083,271 @@ where
         gen_layout
     }
 
+    /// Calculate the layout with more than one column.
+    fn _layout_columns<const ENDLESS: bool>(self, page: Size, border: Padding) -> GenericLayout<W> {
+        let n_cols = self.columns;
+        let total_width = page.width.saturating_sub(border.left + border.right);
+        let col_width =
+            total_width.saturating_sub((n_cols - 1).saturating_mul(self.column_spacing)) / n_cols;
+
+        let mut gen_layout = GenericLayout::with_capacity(self.widgets.len(), self.headers.len());
+        gen_layout.set_page_size(page);
+
+        let mut page = Page {
+            width: page.width,
+            height: page.height,
+            top: border.top,
+            bottom: border.bottom,
+            max_height: page.height.saturating_sub(border.top + border.bottom),
+
+            page_no: 0,
+            y_page: 0,
+            y: border.top,
+
+            line_spacing: 0,
+
+            container_left: border.left,
+            container_right: border.left + total_width,
+        };
+        let no_pos = Positions::default();
+
+        let heights = self
+            .widgets
+            .iter()
+            .map(|v| {
+                let mut tmp = Page {
+                    max_height: page.max_height,
+                    ..Default::default()
+                };
+                tmp.widget_area(v, &no_pos);
+                tmp.y
+            })
+            .collect::<Vec<_>>();
+
+        // column + y for every widget. None for spanning widgets.
+        let mut place = vec![(None, 0u16); self.widgets.len()];
+        // header y + title
+        let mut header_rows = Vec::new();
+
+        let mut idx = 0;
+        while idx < self.widgets.len() {
+            // find the end of the section
+            let end = if self.widgets[idx].span {
+                idx + 1
+            } else {
+                let mut end = idx + 1;
+                while end < self.widgets.len()
+                    && !self.widgets[end].span
+                    && !self.headers.iter().any(|v| v.0 == end)
+                    && !self.page_breaks.contains(&(end - 1))
+                {
+                    end += 1;
+                }
+                end
+            };
+
+            // line spacing
+            page.next_widget(self.line_spacing);
+            // headers
+            for (_, _, title) in self.headers.iter().filter(|v| v.0 == idx) {
+                if page.y.saturating_add(1) > page.bottom_y::<ENDLESS>() && !page.is_fresh() {
+                    page.next_page(&no_pos, &no_pos);
+                }
+                header_rows.push((page.y, title.clone()));
+                page.y = page.y.saturating_add(1);
+            }
+
+            if self.widgets[idx].span {
+                if page.y.saturating_add(heights[idx]) > page.bottom_y::<ENDLESS>()
+                    && !page.is_fresh()
+                {
+                    page.next_page(&no_pos, &no_pos);
+                }
+                place[idx] = (None, page.y);
+                page.y = page.y.saturating_add(heights[idx]);
+            } else {
+                match self.flow {
+                    FormFlow::Across => {
+                        self.layout_across::<ENDLESS>(&mut page, idx..end, &heights, &mut place)
+                    }
+                    FormFlow::Down => {
+                        self.layout_down::<ENDLESS>(&mut page, idx..end, &heights, &mut place)
+                    }
+                }
+            }
+
+            if !ENDLESS && self.page_breaks.contains(&(end - 1)) {
+                page.next_page(&no_pos, &no_pos);
+            }
+
+            idx = end;
+        }
+
+        // label width for each column.
+        let mut label_width = vec![0u16; n_cols as usize];
+        for (widget, (col, _)) in self.widgets.iter().zip(place.iter()) {
+            if let Some(col) = col {
+                let w = match widget.label {
+                    FormLabel::Width(w) => w,
+                    FormLabel::Size(w, _) => w,
+                    _ => 0,
+                };
+                label_width[*col as usize] = max(label_width[*col as usize], w);
+            }
+        }
+        for w in label_width.iter_mut() {
+            if let Some(label_limit) = self.label_limit {
+                *w = min(*w, label_limit);
+            }
+            if *w + self.spacing > col_width {
+                *w = col_width.saturating_sub(self.spacing) / 2;
+            }
+        }
+
+        let col_pos = |x: u16, width: u16, label_width: u16| Positions {
+            label_x: x,
+            label_width,
+            widget_x: x + label_width + self.spacing,
+            widget_width: width.saturating_sub(label_width + self.spacing),
+            container_left: x,
+            container_right: x + width,
+            total_width: width,
+        };
+
+        for (widget, (col, y)) in self.widgets.into_iter().zip(place) {
+            let pos = if let Some(col) = col {
+                let x = border.left + col * (col_width + self.column_spacing);
+                col_pos(x, col_width, label_width[col as usize])
+            } else {
+                col_pos(border.left, total_width, label_width[0])
+            };
+            let mut tmp = Page {
+                max_height: page.max_height,
+                y,
+                container_left: pos.container_left,
+                container_right: pos.container_right,
+                ..Default::default()
+            };
+            let (label_area, widget_area) = tmp.widget_area(&widget, &pos);
+            gen_layout.add(
+                widget.id,
+                widget_area,
+                truncate_label(widget.label_str, label_area.width),
+                label_area,
+            );
+        }
+        for (y, title) in header_rows {
+            gen_layout.add_block(
+                Rect::new(border.left, y, total_width, 1),
+                Some(header_block(title, self.header_style)),
+            );
+        }
+
+        gen_layout.set_page_count((page.page_no + 1) as usize);
+
+        gen_layout
+    }
+
+    // fill the columns row by row.
+    fn layout_across<const ENDLESS: bool>(
+        &self,
+        page: &mut Page,
+        range: Range<usize>,
+        heights: &[u16],
+        place: &mut [(Option<u16>, u16)],
+    ) {
+        let no_pos = Positions::default();
+
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        for idx in range {
+            if row.len() == self.columns as usize
+                || (!row.is_empty() && self.column_breaks.contains(&idx))
+            {
+                rows.push(mem::take(&mut row));
+            }
+            row.push(idx);
+        }
+        if !row.is_empty() {
+            rows.push(row);
+        }
+
+        for (n, row) in rows.into_iter().enumerate() {
+            if n > 0 {
+                page.next_widget(self.line_spacing);
+            }
+            let height = row.iter().map(|v| heights[*v]).max().unwrap_or_default();
+            if page.y.saturating_add(height) > page.bottom_y::<ENDLESS>() && !page.is_fresh() {
+                page.next_page(&no_pos, &no_pos);
+                page.next_widget(self.line_spacing);
+            }
+            for (col, idx) in row.into_iter().enumerate() {
+                place[idx] = (Some(col as u16), page.y);
+            }
+            page.y = page.y.saturating_add(height);
+        }
+    }
+
+    // fill the columns top to bottom, balanced by height.
+    fn layout_down<const ENDLESS: bool>(
+        &self,
+        page: &mut Page,
+        range: Range<usize>,
+        heights: &[u16],
+        place: &mut [(Option<u16>, u16)],
+    ) {
+        let no_pos = Positions::default();
+        let n_cols = self.columns as u32;
+
+        let mut idx = range.start;
+        while idx < range.end {
+            if idx > range.start {
+                page.next_widget(self.line_spacing);
+            }
+            if page.y.saturating_add(heights[idx]) > page.bottom_y::<ENDLESS>() && !page.is_fresh()
+            {
+                page.next_page(&no_pos, &no_pos);
+                page.next_widget(self.line_spacing);
+            }
+
+            let avail = page.bottom_y::<ENDLESS>().saturating_sub(page.y) as u32;
+            let rest = heights[idx..range.end]
+                .iter()
+                .map(|v| *v as u32 + self.line_spacing as u32)
+                .sum::<u32>()
+                .saturating_sub(self.line_spacing as u32);
+            let target = min(rest.div_ceil(n_cols), avail);
+
+            let mut max_height = 0;
+            for col in 0..n_cols {
+                let limit = if col + 1 == n_cols { avail } else { target };
+                let mut acc = 0u32;
+                while idx < range.end {
+                    if acc > 0 && self.column_breaks.contains(&idx) {
+                        break;
+                    }
+                    let spacing = if acc > 0 { self.line_spacing as u32 } else { 0 };
+                    let height = spacing + heights[idx] as u32;
+                    if acc > 0 && acc + height > limit {
+                        break;
+                    }
+                    if acc == 0 && col > 0 && height > avail {
+                        break;
+                    }
+                    place[idx] = (Some(col as u16), page.y + (acc + spacing) as u16);
+                    acc += height;
+                    idx += 1;
+                }
+                max_height = max(max_height, acc);
+            }
+            page.y = page.y.saturating_add(max_height as u16);
+
+            if !ENDLESS && idx < range.end {
+                page.next_page(&no_pos, &no_pos);
+            }
+        }
+    }
+
     // some stretching
     // stretch_y contains the recorded widget indexes that need adjustment.
     fn adjust_y_stretch(
@@ -1199,12 +1601,65 @@ impl Page {
         }
     }
 
+    // bottom of the current page.
+    fn bottom_y<const ENDLESS: bool>(&self) -> u16 {
+        if ENDLESS {
+            u16::MAX
+        } else {
+            self.y_page
+                .saturating_add(self.height.saturating_sub(self.bottom))
+        }
+    }
+
+    // nothing on this page yet.
+    fn is_fresh(&self) -> bool {
+        self.y == self.y_page.saturating_add(self.top)
+    }
+
     // advance to next widget
     fn next_widget(&mut self, adjust_spacing: u16) {
         self.y = self.y.saturating_add(self.line_spacing);
         self.line_spacing = adjust_spacing;
     }
 
+    // open the containers and add the headers before the widget.
+    fn start_widget(
+        &mut self,
+        idx: usize,
+        blocks: &mut [BlockDef],
+        headers: &[(usize, usize, Cow<'static, str>)],
+        header_style: Style,
+        tmp: &mut Vec<BlockOut>,
+    ) {
+        // containers started before the header come first.
+        let mut n_blocks = 0;
+        for (_, h_blocks, title) in headers.iter().filter(|v| v.0 == idx) {
+            for cc in blocks.iter_mut() {
+                if cc.range.start == idx && cc.id.0 >= n_blocks && cc.id.0 < *h_blocks {
+                    self.start_container(cc);
+                }
+            }
+            n_blocks = max(n_blocks, *h_blocks);
+
+            let area = Rect::new(
+                self.container_left,
+                self.y,
+                self.container_right.saturating_sub(self.container_left),
+                1,
+            );
+            self.y = self.y.saturating_add(1);
+            tmp.push(BlockOut {
+                block: Some(header_block(title.clone(), header_style)),
+                area,
+            });
+        }
+        for cc in blocks.iter_mut() {
+            if cc.range.start == idx && cc.id.0 >= n_blocks {
+                self.start_container(cc);
+            }
+        }
+    }
+
     // close the given container
     fn end_container(&mut self, cc: &mut BlockDef) {
         self.y = self.y.saturating_add(cc.padding.bottom);
@@ -1225,3 +1680,30 @@ impl Page {
         self.container_right = self.container_right.saturating_sub(cc.padding.right);
     }
 }
+
+// block used for a header.
+fn header_block(title: Cow<'static, str>, style: Style) -> Block<'static> {
+    Block::new().title(Line::from(title)).style(style)
+}
+
+// cut the label and add an ellipsis if it's too wide.
+fn truncate_label(label: Option<Cow<'static, str>>, width: u16) -> Option<Cow<'static, str>> {
+    let label = label?;
+    if unicode_display_width::width(&label) <= width as u64 {
+        return Some(label);
+    }
+    let mut res = String::new();
+    let mut res_width = 0;
+    for g in label.graphemes(true) {
+        let g_width = unicode_display_width::width(g);
+        if res_width + g_width + 1 > width as u64 {
+            break;
+        }
+        res.push_str(g);
+        res_width += g_width;
+    }
+    if width > 0 {
+        res.push('…');
+    }
+    Some(Cow::Owned(res))
+}
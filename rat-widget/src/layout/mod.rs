@@ -8,6 +8,6 @@ mod layout_middle;
 pub use generic_layout::GenericLayout;
 pub use layout_dialog::{layout_dialog, DialogItem};
 pub use layout_edit::{layout_edit, EditConstraint};
-pub use layout_form::{FormLabel, FormWidget, LayoutForm};
+pub use layout_form::{FormFlow, FormLabel, FormWidget, LayoutForm};
 pub use layout_grid::layout_grid;
 pub use layout_middle::layout_middle;
@@ -21,13 +21,16 @@ pub mod event {
     pub use crate::file_dialog::event::FileOutcome;
     pub use crate::pager::event::PagerOutcome;
     pub use crate::radio::event::RadioOutcome;
-    pub use crate::slider::event::SliderOutcome;
+    pub use crate::slider::event::{RangeSliderOutcome, SliderOutcome};
+    pub use crate::splitter::event::SplitOutcome;
     pub use crate::tabbed::event::TabbedOutcome;
+    pub use crate::tree::event::TreeOutcome;
     pub use rat_ftable::event::{DoubleClickOutcome, EditOutcome, TableOutcome};
     pub use rat_menu::event::MenuOutcome;
     pub use rat_popup::event::PopupOutcome;
     pub use rat_scrolled::event::ScrollOutcome;
     pub use rat_text::event::{ReadOnly, TextOutcome};
+    pub use rat_text::line_number::LineNumberOutcome;
 }
 
 /// Module for focus-handling functionality.
@@ -55,7 +58,7 @@ pub mod reloc {
 /// See [rat-scrolled](https://docs.rs/rat-scrolled/latest/rat_scrolled/)
 pub mod scrolled {
     pub use rat_scrolled::{
-        Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle, ScrollSymbols,
+        Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle, ScrollSymbols, ScrollSync,
         ScrollbarPolicy, SCROLLBAR_DOUBLE_HORIZONTAL, SCROLLBAR_DOUBLE_VERTICAL,
         SCROLLBAR_HORIZONTAL, SCROLLBAR_VERTICAL,
     };
@@ -76,7 +79,9 @@ pub mod text {
 // --- widget modules here --- (alphabetical)
 
 pub mod button;
+pub mod button_group;
 pub mod calendar;
+pub mod check_group;
 pub mod checkbox;
 pub mod choice;
 pub mod clipper;
@@ -88,11 +93,17 @@ pub mod date_input {
     };
 }
 pub mod file_dialog;
+pub mod form_binder;
 pub mod hover;
+pub mod key_hints;
+pub mod keybindings;
 /// Line numbers widget.
 /// For use with TextArea mostly.
 pub mod line_number {
-    pub use rat_text::line_number::{LineNumberState, LineNumberStyle, LineNumbers};
+    pub use rat_text::line_number::{
+        handle_mouse_events, LineMarker, LineNumberPart, LineNumberState, LineNumberStyle,
+        LineNumbers, MarkerPlacement,
+    };
 }
 pub mod list;
 /// Menu widgets.
@@ -102,6 +113,7 @@ pub mod menu {
     pub use rat_menu::menuitem::{MenuItem, Separator};
     pub use rat_menu::menuline::{MenuLine, MenuLineState};
     pub use rat_menu::popup_menu::{PopupConstraint, PopupMenu, PopupMenuState};
+    pub use rat_menu::recent::RecentList;
     pub use rat_menu::{MenuBuilder, MenuStructure, MenuStyle, StaticMenu};
 
     pub mod menubar {
@@ -130,7 +142,9 @@ pub mod paragraph;
 pub mod popup {
     pub use rat_popup::{Placement, PopupConstraint, PopupCore, PopupCoreState, PopupStyle};
 }
+pub mod progress;
 pub mod radio;
+pub mod scroll_pane;
 pub mod shadow;
 pub mod splitter;
 pub mod statusline;
@@ -173,11 +187,14 @@ pub mod text_input_mask {
 /// See [rat-text](https://docs.rs/rat-text/latest/rat_text/)
 pub mod textarea {
     pub use rat_text::text_area::{
-        handle_events, handle_mouse_events, handle_readonly_events, TextArea, TextAreaState,
+        handle_events, handle_mouse_events, handle_readonly_events, SelectionKind, TextArea,
+        TextAreaState,
     };
 }
 pub mod range_op;
 pub mod slider;
+pub mod toast;
+pub mod tree;
 pub mod util;
 pub mod view;
 
@@ -11,11 +11,13 @@ use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
 use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::style::{Style, Stylize};
 use ratatui::widgets::{Block, ListDirection, ListItem, StatefulWidget};
 use std::cmp::min;
 use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 pub mod edit;
 
@@ -36,20 +38,109 @@ pub trait ListSelection {
     }
 }
 
+/// Render-context for rendering a list-item.
+#[derive(Debug)]
+pub struct ListContext {
+    /// Focus flag is set.
+    pub focus: bool,
+    /// Item is selected.
+    pub selected: bool,
+
+    /// Base style
+    pub style: Style,
+    /// Selection style. This is the focus style if the
+    /// list is focused.
+    pub select_style: Style,
+
+    /// Full area of the item. The area given to render_item
+    /// is clipped to the visible part of the list.
+    pub item_area: Rect,
+
+    /// Construct with `..Default::default()`
+    pub non_exhaustive: NonExhaustive,
+}
+
+///
+/// Trait for accessing the list-data by the List.
+///
+/// This is the alternative to handing over a Vec of ListItems.
+/// It allows items with varying heights and completely
+/// custom rendering of the items.
+///
+/// See [List::data].
+pub trait ListData {
+    /// Number of items.
+    fn rows(&self) -> usize;
+
+    /// Item height.
+    ///
+    /// The heights are cached in the ListState and only
+    /// queried again when the number of items changes.
+    /// Use [ListState::invalidate_item_heights] otherwise.
+    #[allow(unused_variables)]
+    fn item_height(&self, n: usize) -> u16 {
+        1
+    }
+
+    /// Item width. Only needed for horizontal scrolling.
+    #[allow(unused_variables)]
+    fn item_width(&self, n: usize) -> u16 {
+        0
+    }
+
+    /// Render the item.
+    ///
+    /// The area is already filled with the base style or
+    /// the selection style.
+    /// * ctx - context data.
+    fn render_item(&self, ctx: &ListContext, n: usize, area: Rect, buf: &mut Buffer);
+}
+
+impl Default for ListContext {
+    fn default() -> Self {
+        Self {
+            focus: false,
+            selected: false,
+            style: Default::default(),
+            select_style: Default::default(),
+            item_area: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+/// List data wrapper. Keeps List Clone.
+#[derive(Clone)]
+struct DataRepr<'a>(Rc<dyn ListData + 'a>);
+
+impl Debug for DataRepr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListData")
+            .field("rows", &self.0.rows())
+            .finish()
+    }
+}
+
 /// List widget.
 ///
 /// Fully compatible with ratatui List.
 /// Adds Scroll, selection models, and event-handling.
+///
+/// The items are either given as ListItems or via the
+/// [ListData] trait for custom rendering.
 #[derive(Debug, Default, Clone)]
 pub struct List<'a, Selection> {
     block: Option<Block<'a>>,
     scroll: Option<Scroll<'a>>,
+    hscroll: Option<Scroll<'a>>,
 
     items: Vec<ListItem<'a>>,
+    data: Option<DataRepr<'a>>,
 
     style: Style,
     select_style: Option<Style>,
     focus_style: Option<Style>,
+    drop_style: Option<Style>,
     direction: ListDirection,
 
     _phantom: PhantomData<Selection>,
@@ -64,6 +155,8 @@ pub struct ListStyle {
     pub select: Option<Style>,
     /// Style for selection when focused.
     pub focus: Option<Style>,
+    /// Drop indicator when reordering items.
+    pub drop: Option<Style>,
 
     pub block: Option<Block<'static>>,
     pub scroll: Option<ScrollStyle>,
@@ -83,6 +176,10 @@ pub struct ListState<Selection> {
     /// Areas for the rendered items.
     /// __readonly__. renewed for each render.
     pub row_areas: Vec<Rect>,
+    /// Prefix sums of the item heights. `item_pos[n]` is the
+    /// first line of item n, the last entry is the total height.
+    /// __readonly__. cached, renewed when the number of items changes.
+    pub item_pos: Vec<usize>,
 
     /// Length in items.
     /// __mostly readonly__. renewed for each render.
@@ -90,6 +187,9 @@ pub struct ListState<Selection> {
     /// Offset etc.
     /// __read+write__
     pub scroll: ScrollState,
+    /// Horizontal offset in columns.
+    /// __read+write__
+    pub hscroll: ScrollState,
 
     /// Focus
     /// __read+write__
@@ -98,6 +198,25 @@ pub struct ListState<Selection> {
     /// __read+write__
     pub selection: Selection,
 
+    /// Reorder items by drag and drop, or with Alt+Up/Down.
+    /// Only supported for [RowSelection]. A move is reported
+    /// with [take_moved](ListState::take_moved).
+    /// __read+write__
+    pub reorder: bool,
+    /// Selected item under the last mouse-down. A reorder
+    /// drag starts with the next mouse drag.
+    /// __readonly__
+    pub drag_anchor: Option<usize>,
+    /// Current target item of a reorder drag.
+    /// __readonly__
+    pub drag_hover: Option<usize>,
+    /// The last move of an item, as (from, to).
+    /// The application must move the item in its data.
+    /// The selection is already at the new position.
+    /// See [take_moved](ListState::take_moved).
+    /// __readonly__
+    pub moved: Option<(usize, usize)>,
+
     /// Helper for mouse events.
     /// __used for mouse interaction__
     pub mouse: MouseFlags,
@@ -109,6 +228,7 @@ impl Default for ListStyle {
             style: Default::default(),
             select: None,
             focus: None,
+            drop: None,
             block: None,
             scroll: None,
             non_exhaustive: NonExhaustive,
@@ -128,10 +248,13 @@ impl<'a, Selection> List<'a, Selection> {
         Self {
             block: None,
             scroll: None,
+            hscroll: None,
             items,
+            data: None,
             style: Default::default(),
             select_style: Default::default(),
             focus_style: Default::default(),
+            drop_style: Default::default(),
             direction: Default::default(),
             _phantom: Default::default(),
         }
@@ -145,6 +268,51 @@ impl<'a, Selection> List<'a, Selection> {
     {
         let items = items.into_iter().map(|v| v.into()).collect();
         self.items = items;
+        self.data = None;
+        self
+    }
+
+    /// Use a [ListData] instead of ListItems.
+    ///
+    /// This supports items with varying heights and
+    /// custom rendering.
+    ///
+    /// ```rust
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::text::Line;
+    /// use ratatui::widgets::Widget;
+    /// use rat_widget::list::{List, ListContext, ListData};
+    /// use rat_widget::list::selection::RowSelection;
+    ///
+    /// struct Mails<'a>(&'a [(&'a str, &'a str)]);
+    ///
+    /// impl ListData for Mails<'_> {
+    ///     fn rows(&self) -> usize {
+    ///         self.0.len()
+    ///     }
+    ///
+    ///     fn item_height(&self, _n: usize) -> u16 {
+    ///         2
+    ///     }
+    ///
+    ///     fn render_item(&self, ctx: &ListContext, n: usize, area: Rect, buf: &mut Buffer) {
+    ///         let (subject, from) = self.0[n];
+    ///         Line::from(subject).render(area, buf);
+    ///         if area.height > 1 {
+    ///             let from_area = Rect::new(area.x + 2, area.y + 1, area.width.saturating_sub(2), 1);
+    ///             Line::from(from).render(from_area, buf);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mails = [("Hello", "Alice"), ("Re: Hello", "Bob")];
+    /// let list = List::<RowSelection>::default().data(Mails(&mails));
+    /// ```
+    #[inline]
+    pub fn data(mut self, data: impl ListData + 'a) -> Self {
+        self.data = Some(DataRepr(Rc::new(data)));
+        self.items.clear();
         self
     }
 
@@ -163,6 +331,13 @@ impl<'a, Selection> List<'a, Selection> {
         self
     }
 
+    /// Horizontal scroll support for long items.
+    #[inline]
+    pub fn hscroll(mut self, scroll: Scroll<'a>) -> Self {
+        self.hscroll = Some(scroll.override_horizontal());
+        self
+    }
+
     /// Set all styles.
     #[inline]
     pub fn styles_opt(self, styles: Option<ListStyle>) -> Self {
@@ -183,8 +358,12 @@ impl<'a, Selection> List<'a, Selection> {
         if styles.focus.is_some() {
             self.focus_style = styles.focus;
         }
+        if styles.drop.is_some() {
+            self.drop_style = styles.drop;
+        }
         if let Some(styles) = styles.scroll {
-            self.scroll = self.scroll.map(|v| v.styles(styles));
+            self.scroll = self.scroll.map(|v| v.styles(styles.clone()));
+            self.hscroll = self.hscroll.map(|v| v.styles(styles));
         }
         if let Some(block) = styles.block {
             self.block = Some(block);
@@ -215,6 +394,16 @@ impl<'a, Selection> List<'a, Selection> {
         self
     }
 
+    /// Style for the drop indicator when reordering items.
+    /// It's patched onto the line above the drop position.
+    ///
+    /// Defaults to underlined.
+    #[inline]
+    pub fn drop_style<S: Into<Style>>(mut self, drop_style: S) -> Self {
+        self.drop_style = Some(drop_style.into());
+        self
+    }
+
     /// List direction.
     #[inline]
     pub fn direction(mut self, direction: ListDirection) -> Self {
@@ -225,13 +414,17 @@ impl<'a, Selection> List<'a, Selection> {
     /// Number of items.
     #[inline]
     pub fn len(&self) -> usize {
-        self.items.len()
+        if let Some(data) = &self.data {
+            data.0.rows()
+        } else {
+            self.items.len()
+        }
     }
 
     /// Empty?
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.len() == 0
     }
 }
 
@@ -259,19 +452,49 @@ fn render_list<Selection: ListSelection>(
     state: &mut ListState<Selection>,
 ) {
     state.area = area;
-    state.rows = widget.items.len();
+    state.rows = widget.len();
+
+    // item positions
+    if let Some(data) = &widget.data {
+        if state.item_pos.len() != state.rows + 1 {
+            state.set_item_heights((0..state.rows).map(|n| data.0.item_height(n) as usize));
+        }
+    } else {
+        state.set_item_heights(widget.items.iter().map(|v| v.height()));
+    }
+
+    let max_width = if widget.hscroll.is_some() {
+        if let Some(data) = &widget.data {
+            (0..state.rows)
+                .map(|n| data.0.item_width(n) as usize)
+                .max()
+                .unwrap_or(0)
+        } else {
+            widget.items.iter().map(|v| v.width()).max().unwrap_or(0)
+        }
+    } else {
+        0
+    };
 
     let sa = ScrollArea::new()
         .block(widget.block.as_ref())
+        .h_scroll(widget.hscroll.as_ref())
         .v_scroll(widget.scroll.as_ref());
-    state.inner = sa.inner(area, None, Some(&state.scroll));
+    sa.measure(
+        area,
+        widget.hscroll.as_ref().map(|_| max_width),
+        Some(state.item_pos[state.rows]),
+        Some(&mut state.hscroll),
+        Some(&mut state.scroll),
+    );
+    state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.scroll));
 
     // area for each item
     state.row_areas.clear();
     let mut item_area = Rect::new(state.inner.x, state.inner.y, state.inner.width, 1);
     let mut total_height = 0;
-    for item in widget.items.iter().skip(state.offset()) {
-        item_area.height = item.height() as u16;
+    for n in state.offset()..state.rows {
+        item_area.height = state.item_height(n);
 
         state.row_areas.push(item_area);
 
@@ -297,8 +520,8 @@ fn render_list<Selection: ListSelection>(
     // max_v_offset
     let mut n = 0;
     let mut height = 0;
-    for item in widget.items.iter().rev() {
-        height += item.height();
+    for row in (0..state.rows).rev() {
+        height += state.item_height(row) as usize;
         if height > state.inner.height as usize {
             break;
         }
@@ -306,6 +529,19 @@ fn render_list<Selection: ListSelection>(
     }
     state.scroll.set_max_offset(state.rows.saturating_sub(n));
 
+    // max_h_offset
+    if widget.hscroll.is_some() {
+        state
+            .hscroll
+            .set_max_offset(max_width.saturating_sub(state.inner.width as usize));
+        state.hscroll.set_page_len(state.inner.width as usize);
+    } else {
+        state.hscroll.set_max_offset(0);
+        state.hscroll.set_page_len(state.inner.width as usize);
+    }
+    let h_offset = state.hscroll.limit_offset(state.hscroll.offset());
+    state.hscroll.set_offset(h_offset);
+
     let (style, select_style) = if state.is_focused() {
         (widget.style, focus_style)
     } else {
@@ -315,34 +551,136 @@ fn render_list<Selection: ListSelection>(
     sa.render(
         area,
         buf,
-        &mut ScrollAreaState::new().v_scroll(&mut state.scroll),
+        &mut ScrollAreaState::new()
+            .h_scroll(&mut state.hscroll)
+            .v_scroll(&mut state.scroll),
     );
 
     // rendering
-    let items = widget
-        .items
-        .into_iter()
-        .enumerate()
-        .map(|(i, v)| {
-            if state.selection.is_selected(i) {
-                v.style(select_style)
-            } else {
-                v.style(style)
+    if let Some(data) = &widget.data {
+        let focus = state.is_focused();
+        let offset = state.scroll.offset();
+        let row_areas = &state.row_areas;
+        let selection = &state.selection;
+        render_hscrolled(state.inner, h_offset as u16, buf, |area, buf| {
+            for (i, row_area) in row_areas.iter().enumerate() {
+                let n = offset + i;
+                let selected = selection.is_selected(n);
+                let ctx = ListContext {
+                    focus,
+                    selected,
+                    style,
+                    select_style,
+                    item_area: Rect::new(area.x, row_area.y, area.width, row_area.height),
+                    ..Default::default()
+                };
+                let item_area = ctx.item_area.intersection(area);
+                buf.set_style(item_area, if selected { select_style } else { style });
+                data.0.render_item(&ctx, n, item_area, buf);
             }
-        })
-        .collect::<Vec<_>>();
-
-    let mut list_state = ratatui::widgets::ListState::default().with_offset(state.scroll.offset());
+        });
+    } else {
+        let items = widget
+            .items
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if state.selection.is_selected(i) {
+                    v.style(select_style)
+                } else {
+                    v.style(style)
+                }
+            })
+            .collect::<Vec<_>>();
 
-    StatefulWidget::render(
-        ratatui::widgets::List::default()
+        let mut list_state =
+            ratatui::widgets::ListState::default().with_offset(state.scroll.offset());
+        let list = ratatui::widgets::List::default()
             .items(items)
             .style(widget.style)
-            .direction(widget.direction),
-        state.inner,
-        buf,
-        &mut list_state,
-    );
+            .direction(widget.direction);
+
+        render_hscrolled(state.inner, h_offset as u16, buf, |area, buf| {
+            StatefulWidget::render(list, area, buf, &mut list_state);
+        });
+    }
+
+    if let (Some(from), Some(to)) = (state.drag_anchor, state.drag_hover) {
+        render_drop_indicator(
+            &state.row_areas,
+            state.scroll.offset(),
+            from,
+            to,
+            widget.drop_style.unwrap_or(Style::new().underlined()),
+            buf,
+        );
+    }
+}
+
+/// Renders to the inner area. If there is a horizontal offset
+/// this renders wider and copies the visible part.
+fn render_hscrolled(
+    inner: Rect,
+    h_offset: u16,
+    buf: &mut Buffer,
+    render: impl FnOnce(Rect, &mut Buffer),
+) {
+    if h_offset == 0 {
+        render(inner, buf);
+    } else {
+        let mut tmp = Buffer::empty(Rect::new(
+            inner.x,
+            inner.y,
+            inner.width.saturating_add(h_offset),
+            inner.height,
+        ));
+        render(tmp.area, &mut tmp);
+        for y in inner.top()..inner.bottom() {
+            for x in inner.left()..inner.right() {
+                if let Some(cell) = tmp.cell((x + h_offset, y)) {
+                    if let Some(dst) = buf.cell_mut((x, y)) {
+                        *dst = cell.clone();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Patches the style onto the last line of the item above the
+/// drop position. Uses the first line of the target item if
+/// the item above is not visible.
+fn render_drop_indicator(
+    row_areas: &[Rect],
+    offset: usize,
+    from: usize,
+    to: usize,
+    style: Style,
+    buf: &mut Buffer,
+) {
+    if from == to {
+        return;
+    }
+
+    let visible = |row: usize| {
+        row.checked_sub(offset)
+            .and_then(|v| row_areas.get(v))
+            .filter(|v| !v.is_empty())
+    };
+
+    let above = if to > from {
+        Some(to)
+    } else {
+        to.checked_sub(1)
+    };
+    let line = if let Some(area) = above.and_then(visible) {
+        Rect::new(area.x, area.bottom() - 1, area.width, 1)
+    } else if let Some(area) = visible(to) {
+        Rect::new(area.x, area.y, area.width, 1)
+    } else {
+        return;
+    };
+    buf.set_style(line, style);
 }
 
 impl<Selection> HasFocus for ListState<Selection> {
@@ -367,10 +705,16 @@ impl<Selection: Default> Default for ListState<Selection> {
             area: Default::default(),
             inner: Default::default(),
             row_areas: Default::default(),
+            item_pos: Default::default(),
             rows: Default::default(),
             scroll: Default::default(),
+            hscroll: Default::default(),
             focus: Default::default(),
             selection: Default::default(),
+            reorder: Default::default(),
+            drag_anchor: Default::default(),
+            drag_hover: Default::default(),
+            moved: Default::default(),
             mouse: Default::default(),
         }
     }
@@ -382,10 +726,16 @@ impl<Selection: Clone> Clone for ListState<Selection> {
             area: self.area,
             inner: self.inner,
             row_areas: self.row_areas.clone(),
+            item_pos: self.item_pos.clone(),
             rows: self.rows,
             scroll: self.scroll.clone(),
+            hscroll: self.hscroll.clone(),
             focus: FocusFlag::named(self.focus.name()),
             selection: self.selection.clone(),
+            reorder: self.reorder,
+            drag_anchor: None,
+            drag_hover: None,
+            moved: None,
             mouse: Default::default(),
         }
     }
@@ -397,6 +747,7 @@ impl<Selection> RelocatableState for ListState<Selection> {
         self.inner = relocate_area(self.inner, shift, clip);
         relocate_areas(self.row_areas.as_mut_slice(), shift, clip);
         self.scroll.relocate(shift, clip);
+        self.hscroll.relocate(shift, clip);
     }
 }
 
@@ -469,12 +820,23 @@ impl<Selection: ListSelection> ListState<Selection> {
         }
     }
 
-    #[inline]
+    /// Scroll to make the item visible.
+    ///
+    /// Uses the item heights of the last render, so this
+    /// works with varying heights too.
     pub fn scroll_to(&mut self, pos: usize) -> bool {
-        if pos >= self.offset() + self.page_len() {
-            self.set_offset(pos - self.page_len() + 1)
-        } else if pos < self.offset() {
+        if pos < self.offset() {
             self.set_offset(pos)
+        } else if let Some(bottom) = self.item_pos.get(pos + 1) {
+            // first offset where the item fits the page.
+            let height = self.inner.height as usize;
+            let mut offset = self.offset();
+            while offset < pos && bottom - self.item_pos[offset] > height {
+                offset += 1;
+            }
+            self.set_offset(offset)
+        } else if pos >= self.offset() + self.page_len() {
+            self.set_offset(pos - self.page_len() + 1)
         } else {
             false
         }
@@ -489,6 +851,96 @@ impl<Selection: ListSelection> ListState<Selection> {
     pub fn scroll_down(&mut self, n: usize) -> bool {
         self.scroll.scroll_down(n)
     }
+
+    /// Scroll up by n lines. This is used for the mouse-wheel.
+    ///
+    /// Scrolls whole items, as many as are needed to cover
+    /// n lines. With tall items this is just one item.
+    pub fn scroll_lines_up(&mut self, n: usize) -> bool {
+        let offset = self.offset();
+        if self.item_pos.len() != self.rows + 1 || offset > self.rows {
+            return self.scroll_up(n);
+        }
+        let mut items = 0;
+        while items < offset && self.item_pos[offset] - self.item_pos[offset - items] < n {
+            items += 1;
+        }
+        self.scroll_up(items)
+    }
+
+    /// Scroll down by n lines. This is used for the mouse-wheel.
+    ///
+    /// Scrolls whole items, as many as are needed to cover
+    /// n lines. With tall items this is just one item.
+    pub fn scroll_lines_down(&mut self, n: usize) -> bool {
+        let offset = self.offset();
+        if self.item_pos.len() != self.rows + 1 || offset > self.rows {
+            return self.scroll_down(n);
+        }
+        let mut items = 0;
+        while offset + items < self.rows
+            && self.item_pos[offset + items] - self.item_pos[offset] < n
+        {
+            items += 1;
+        }
+        self.scroll_down(items)
+    }
+
+    /// Height of the item, as of the last render.
+    pub fn item_height(&self, n: usize) -> u16 {
+        match (self.item_pos.get(n), self.item_pos.get(n + 1)) {
+            (Some(top), Some(bottom)) => (bottom - top) as u16,
+            _ => 1,
+        }
+    }
+
+    /// Forget the cached item heights. They are queried
+    /// again from the [ListData] with the next render.
+    ///
+    /// Only necessary if the heights change without a
+    /// change of the number of items.
+    pub fn invalidate_item_heights(&mut self) {
+        self.item_pos.clear();
+    }
+
+    /// Recalculate the prefix sums.
+    fn set_item_heights(&mut self, heights: impl Iterator<Item = usize>) {
+        self.item_pos.clear();
+        self.item_pos.push(0);
+        let mut pos = 0;
+        for height in heights {
+            pos += height;
+            self.item_pos.push(pos);
+        }
+    }
+
+    /// Horizontal offset.
+    #[inline]
+    pub fn x_offset(&self) -> usize {
+        self.hscroll.offset()
+    }
+
+    /// Set the horizontal offset.
+    #[inline]
+    pub fn set_x_offset(&mut self, offset: usize) -> bool {
+        self.hscroll.set_offset(offset)
+    }
+
+    /// Maximum horizontal offset.
+    #[inline]
+    pub fn x_max_offset(&self) -> usize {
+        self.hscroll.max_offset()
+    }
+
+    #[inline]
+    pub fn scroll_left(&mut self, n: usize) -> bool {
+        self.hscroll.scroll_left(n)
+    }
+
+    #[inline]
+    pub fn scroll_right(&mut self, n: usize) -> bool {
+        self.hscroll.scroll_right(n)
+    }
 }
 
 impl<Selection: ListSelection> ListState<Selection> {
@@ -527,6 +979,7 @@ impl ListState<RowSelection> {
         self.scroll.items_added(pos, n);
         self.selection.items_added(pos, n);
         self.rows += n;
+        self.item_pos.clear();
     }
 
     /// Update the state to match removing items.
@@ -537,6 +990,7 @@ impl ListState<RowSelection> {
         self.selection
             .items_removed(pos, n, self.rows.saturating_sub(1));
         self.rows -= n;
+        self.item_pos.clear();
     }
 
     /// When scrolling the table, change the selection instead of the offset.
@@ -605,6 +1059,45 @@ impl ListState<RowSelection> {
         let s = self.scroll_to(self.selection.selected().expect("row"));
         r || s
     }
+
+    /// Is a reorder drag active.
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.drag_hover.is_some()
+    }
+
+    /// Returns the last move of an item by drag and drop or
+    /// by keyboard, as (from, to), and resets it.
+    ///
+    /// Check this when event-handling returns Outcome::Changed.
+    #[inline]
+    pub fn take_moved(&mut self) -> Option<(usize, usize)> {
+        self.moved.take()
+    }
+
+    /// Cancel a reorder drag.
+    pub fn cancel_drag(&mut self) -> bool {
+        self.drag_hover = None;
+        self.drag_anchor.take().is_some()
+    }
+
+    /// Move the selected item by keyboard.
+    ///
+    /// Moves the selection and returns the (from, to) positions
+    /// of the move, if any. This is not recorded for
+    /// [take_moved](ListState::take_moved).
+    pub fn move_selected_item(&mut self, up: bool) -> Option<(usize, usize)> {
+        let from = self.selected().filter(|v| *v < self.rows)?;
+        let to = if up {
+            from.checked_sub(1)?
+        } else if from + 1 < self.rows {
+            from + 1
+        } else {
+            return None;
+        };
+        self.move_to(to);
+        Some((from, to))
+    }
 }
 
 impl ListState<RowSetSelection> {
@@ -721,6 +1214,7 @@ pub mod selection {
     use rat_ftable::TableSelection;
     use rat_scrolled::event::ScrollOutcome;
     use rat_scrolled::ScrollAreaState;
+    use std::cmp::min;
     use std::mem;
 
     /// No selection
@@ -748,6 +1242,12 @@ pub mod selection {
                 match event {
                     ct_event!(keycode press Down) => self.scroll_down(1).into(),
                     ct_event!(keycode press Up) => self.scroll_up(1).into(),
+                    ct_event!(keycode press Left) if self.x_max_offset() > 0 => {
+                        self.scroll_left(1).into()
+                    }
+                    ct_event!(keycode press Right) if self.x_max_offset() > 0 => {
+                        self.scroll_right(1).into()
+                    }
                     ct_event!(keycode press CONTROL-Down) | ct_event!(keycode press End) => {
                         self.scroll_to(self.max_offset()).into()
                     }
@@ -778,14 +1278,15 @@ pub mod selection {
         fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> Outcome {
             let mut sas = ScrollAreaState::new()
                 .area(self.inner)
+                .h_scroll(&mut self.hscroll)
                 .v_scroll(&mut self.scroll);
             let r = match sas.handle(event, MouseOnly) {
-                ScrollOutcome::Up(v) => self.scroll_up(v),
-                ScrollOutcome::Down(v) => self.scroll_down(v),
+                ScrollOutcome::Up(v) => self.scroll_lines_up(v),
+                ScrollOutcome::Down(v) => self.scroll_lines_down(v),
                 ScrollOutcome::VPos(v) => self.set_offset(v),
-                ScrollOutcome::Left(_) => false,
-                ScrollOutcome::Right(_) => false,
-                ScrollOutcome::HPos(_) => false,
+                ScrollOutcome::Left(v) => self.scroll_left(v),
+                ScrollOutcome::Right(v) => self.scroll_right(v),
+                ScrollOutcome::HPos(v) => self.set_x_offset(v),
 
                 ScrollOutcome::Continue => false,
                 ScrollOutcome::Unchanged => false,
@@ -828,10 +1329,39 @@ pub mod selection {
 
     impl HandleEvent<crossterm::event::Event, Regular, Outcome> for ListState<RowSelection> {
         fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> Outcome {
+            if self.is_dragging() {
+                if let ct_event!(keycode press Esc) = event {
+                    self.cancel_drag();
+                    return Outcome::Changed;
+                }
+            }
+
             let res = if self.is_focused() {
                 match event {
+                    ct_event!(keycode press ALT-Up) if self.reorder => {
+                        if let Some(moved) = self.move_selected_item(true) {
+                            self.moved = Some(moved);
+                            Outcome::Changed
+                        } else {
+                            Outcome::Unchanged
+                        }
+                    }
+                    ct_event!(keycode press ALT-Down) if self.reorder => {
+                        if let Some(moved) = self.move_selected_item(false) {
+                            self.moved = Some(moved);
+                            Outcome::Changed
+                        } else {
+                            Outcome::Unchanged
+                        }
+                    }
                     ct_event!(keycode press Down) => self.move_down(1).into(),
                     ct_event!(keycode press Up) => self.move_up(1).into(),
+                    ct_event!(keycode press Left) if self.x_max_offset() > 0 => {
+                        self.scroll_left(1).into()
+                    }
+                    ct_event!(keycode press Right) if self.x_max_offset() > 0 => {
+                        self.scroll_right(1).into()
+                    }
                     ct_event!(keycode press CONTROL-Down) | ct_event!(keycode press End) => {
                         self.move_to(self.rows.saturating_sub(1)).into()
                     }
@@ -860,6 +1390,10 @@ pub mod selection {
 
     impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for ListState<RowSelection> {
         fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> Outcome {
+            if self.reorder {
+                flow!(handle_reorder(self, event));
+            }
+
             flow!(match event {
                 ct_event!(mouse any for m) if self.mouse.drag(self.inner, m) => {
                     self.move_to(self.row_at_drag((m.column, m.row))).into()
@@ -881,20 +1415,21 @@ pub mod selection {
 
             let mut sas = ScrollAreaState::new()
                 .area(self.inner)
+                .h_scroll(&mut self.hscroll)
                 .v_scroll(&mut self.scroll);
             let r = match sas.handle(event, MouseOnly) {
                 ScrollOutcome::Up(v) => {
                     if ListSelection::scroll_selected(&self.selection) {
                         self.move_up(1)
                     } else {
-                        self.scroll_up(v)
+                        self.scroll_lines_up(v)
                     }
                 }
                 ScrollOutcome::Down(v) => {
                     if ListSelection::scroll_selected(&self.selection) {
                         self.move_down(1)
                     } else {
-                        self.scroll_down(v)
+                        self.scroll_lines_down(v)
                     }
                 }
                 ScrollOutcome::VPos(v) => {
@@ -904,9 +1439,9 @@ pub mod selection {
                         self.set_offset(v)
                     }
                 }
-                ScrollOutcome::Left(_) => false,
-                ScrollOutcome::Right(_) => false,
-                ScrollOutcome::HPos(_) => false,
+                ScrollOutcome::Left(v) => self.scroll_left(v),
+                ScrollOutcome::Right(v) => self.scroll_right(v),
+                ScrollOutcome::HPos(v) => self.set_x_offset(v),
 
                 ScrollOutcome::Continue => false,
                 ScrollOutcome::Unchanged => false,
@@ -920,6 +1455,64 @@ pub mod selection {
         }
     }
 
+    /// Drag and drop of the selected item.
+    ///
+    /// A mouse-down on the selected item is left to the normal
+    /// click handling. The drag starts with the first mouse drag
+    /// after that.
+    fn handle_reorder(
+        state: &mut ListState<RowSelection>,
+        event: &crossterm::event::Event,
+    ) -> Outcome {
+        match event {
+            ct_event!(mouse down Left for column, row) => {
+                state.drag_anchor = if state.inner.contains((*column, *row).into()) {
+                    state
+                        .row_at_clicked((*column, *row))
+                        .filter(|v| Some(*v) == state.selected())
+                } else {
+                    None
+                };
+                Outcome::Continue
+            }
+            ct_event!(mouse drag Left for column, row) if state.drag_anchor.is_some() => {
+                // auto-scroll at the edges.
+                let scrolled = if *row <= state.inner.top() {
+                    state.scroll_up(1)
+                } else if *row + 1 >= state.inner.bottom() {
+                    state.scroll_down(1)
+                } else {
+                    false
+                };
+                let hover = min(
+                    state.row_at_drag((*column, *row)),
+                    state.rows.saturating_sub(1),
+                );
+                if scrolled || state.drag_hover != Some(hover) {
+                    state.drag_hover = Some(hover);
+                    Outcome::Changed
+                } else {
+                    Outcome::Unchanged
+                }
+            }
+            ct_event!(mouse up Left for _column, _row) if state.is_dragging() => {
+                let from = state.drag_anchor.take().expect("anchor");
+                let to = state.drag_hover.take().expect("hover");
+                state.mouse.drag.set(false);
+                if from != to {
+                    state.move_to(to);
+                    state.moved = Some((from, to));
+                }
+                Outcome::Changed
+            }
+            ct_event!(mouse up Left for _column, _row) => {
+                state.drag_anchor = None;
+                Outcome::Continue
+            }
+            _ => Outcome::Continue,
+        }
+    }
+
     pub type RowSetSelection = rat_ftable::selection::RowSetSelection;
 
     impl ListSelection for RowSetSelection {
@@ -972,6 +1565,12 @@ pub mod selection {
                     ct_event!(keycode press SHIFT-Down) => self.move_down(1, true).into(),
                     ct_event!(keycode press Up) => self.move_up(1, false).into(),
                     ct_event!(keycode press SHIFT-Up) => self.move_up(1, true).into(),
+                    ct_event!(keycode press Left) if self.x_max_offset() > 0 => {
+                        self.scroll_left(1).into()
+                    }
+                    ct_event!(keycode press Right) if self.x_max_offset() > 0 => {
+                        self.scroll_right(1).into()
+                    }
                     ct_event!(keycode press CONTROL-Down) | ct_event!(keycode press End) => {
                         self.move_to(self.rows.saturating_sub(1), false).into()
                     }
@@ -1066,14 +1665,15 @@ pub mod selection {
 
             let mut sas = ScrollAreaState::new()
                 .area(self.inner)
+                .h_scroll(&mut self.hscroll)
                 .v_scroll(&mut self.scroll);
             let r = match sas.handle(event, MouseOnly) {
-                ScrollOutcome::Up(v) => self.scroll_up(v),
-                ScrollOutcome::Down(v) => self.scroll_down(v),
+                ScrollOutcome::Up(v) => self.scroll_lines_up(v),
+                ScrollOutcome::Down(v) => self.scroll_lines_down(v),
                 ScrollOutcome::VPos(v) => self.set_offset(v),
-                ScrollOutcome::Left(_) => false,
-                ScrollOutcome::Right(_) => false,
-                ScrollOutcome::HPos(_) => false,
+                ScrollOutcome::Left(v) => self.scroll_left(v),
+                ScrollOutcome::Right(v) => self.scroll_right(v),
+                ScrollOutcome::HPos(v) => self.set_x_offset(v),
 
                 ScrollOutcome::Continue => false,
                 ScrollOutcome::Unchanged => false,
@@ -130,6 +130,10 @@ where
         self.editor.relocate(shift, clip);
         self.list.relocate(shift, clip);
     }
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        self.editor.relocate_popup(shift, clip);
+    }
 }
 
 impl<S> HasScreenCursor for EditListState<S>
@@ -1,6 +1,13 @@
 //!
 //! A message dialog.
 //!
+//! Long messages are word-wrapped and can be scrolled with
+//! the arrow keys, PageUp/PageDown and the mouse wheel.
+//! Ctrl+C copies the full message to the global clipboard.
+//!
+//! Enter and Esc close the dialog. Both can be changed with
+//! [MsgDialogState::default_button] and [MsgDialogState::esc_close].
+//!
 
 use crate::_private::NonExhaustive;
 use crate::button::{Button, ButtonState, ButtonStyle};
@@ -10,17 +17,18 @@ use crate::paragraph::{Paragraph, ParagraphState};
 use crate::util::{block_padding2, reset_buf_area};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use rat_event::{ct_event, ConsumedEvent, Dialog, HandleEvent, Outcome, Regular};
-use rat_focus::{Focus, FocusBuilder};
+use rat_focus::{Focus, FocusBuilder, NavigationPolicy};
 use rat_scrolled::{Scroll, ScrollStyle};
+use rat_text::clipboard::global_clipboard;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Flex, Rect};
-use ratatui::style::Style;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Text};
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
-use ratatui::widgets::{Block, Padding, StatefulWidget, Widget};
+use ratatui::widgets::{Block, Padding, StatefulWidget, Widget, Wrap};
 use std::cell::{Cell, RefCell};
-use std::cmp::max;
+use std::cmp::{max, min};
 use std::fmt::Debug;
 
 /// Basic status dialog for longer messages.
@@ -29,7 +37,9 @@ pub struct MsgDialog<'a> {
     style: Style,
     scroll_style: Option<ScrollStyle>,
     button_style: Option<ButtonStyle>,
+    default_button_style: Option<ButtonStyle>,
     block: Option<Block<'a>>,
+    auto_height: Option<u16>,
 }
 
 /// Combined style.
@@ -39,6 +49,7 @@ pub struct MsgDialogStyle {
     pub scroll: Option<ScrollStyle>,
     pub block: Option<Block<'static>>,
     pub button: Option<ButtonStyle>,
+    pub default_button: Option<ButtonStyle>,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -62,6 +73,16 @@ pub struct MsgDialogState {
     /// Dialog text.
     /// __read+write__
     pub message: RefCell<String>,
+    /// Ok is the default button. Enter closes the dialog
+    /// wherever the focus is. Rendered with the default style.
+    /// Otherwise Enter only works with the focused button.
+    /// Defaults to true.
+    /// __read+write__
+    pub default_button: Cell<bool>,
+    /// Esc closes the dialog.
+    /// Defaults to true.
+    /// __read+write__
+    pub esc_close: Cell<bool>,
 
     /// Ok button
     button: RefCell<ButtonState>,
@@ -77,6 +98,8 @@ impl<'a> MsgDialog<'a> {
             style: Default::default(),
             scroll_style: Default::default(),
             button_style: Default::default(),
+            default_button_style: Default::default(),
+            auto_height: None,
         }
     }
 
@@ -99,6 +122,9 @@ impl<'a> MsgDialog<'a> {
         if styles.button.is_some() {
             self.button_style = styles.button;
         }
+        if styles.default_button.is_some() {
+            self.default_button_style = styles.default_button;
+        }
         self.block = self.block.map(|v| v.style(self.style));
         self
     }
@@ -121,6 +147,26 @@ impl<'a> MsgDialog<'a> {
         self.button_style = Some(style);
         self
     }
+
+    /// Style for the default button.
+    ///
+    /// Defaults to the button style in bold.
+    pub fn default_button_style(mut self, style: ButtonStyle) -> Self {
+        self.default_button_style = Some(style);
+        self
+    }
+
+    /// Fit the height of the dialog to the message.
+    ///
+    /// The dialog grows up to `max_percent` of the height of
+    /// the given area, and is centered vertically. Longer
+    /// messages can be scrolled.
+    ///
+    /// Without this the dialog uses the full area.
+    pub fn auto_height(mut self, max_percent: u16) -> Self {
+        self.auto_height = Some(min(max_percent, 100));
+        self
+    }
 }
 
 impl Default for MsgDialogStyle {
@@ -130,6 +176,7 @@ impl Default for MsgDialogStyle {
             scroll: None,
             block: None,
             button: Default::default(),
+            default_button: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -160,6 +207,13 @@ impl MsgDialogState {
         *self.message_title.borrow_mut() = title.into();
     }
 
+    /// Copy the full message to the global clipboard.
+    pub fn copy_to_clip(&self) -> bool {
+        global_clipboard()
+            .set_string(self.message.borrow().as_str())
+            .is_ok()
+    }
+
     /// *Append* to the message.
     pub fn append(&self, msg: &str) {
         self.set_active(true);
@@ -178,6 +232,8 @@ impl Default for MsgDialogState {
             area: Default::default(),
             inner: Default::default(),
             message: Default::default(),
+            default_button: Cell::new(true),
+            esc_close: Cell::new(true),
             button: Default::default(),
             paragraph: Default::default(),
             message_title: Default::default(),
@@ -189,7 +245,8 @@ impl Default for MsgDialogState {
 
 impl MsgDialogState {
     fn focus(&self) -> Focus {
-        let mut fb = FocusBuilder::default();
+        // Tab cycles within the dialog.
+        let mut fb = FocusBuilder::default().policy(NavigationPolicy::Wrap);
         fb.widget(&*self.paragraph.borrow())
             .widget(&*self.button.borrow());
         fb.build()
@@ -234,9 +291,44 @@ fn render_ref(widget: &MsgDialog<'_>, area: Rect, buf: &mut Buffer, state: &mut
             &block
         };
 
+        let scroll = if let Some(style) = &widget.scroll_style {
+            Scroll::new().styles(style.clone())
+        } else {
+            Scroll::new().style(widget.style)
+        };
+
+        let message = state.message.borrow();
+        let mut lines = Vec::new();
+        for t in message.split('\n') {
+            lines.push(Line::from(t));
+        }
+        let text = Text::from(lines).alignment(Alignment::Center);
+        let para = Paragraph::new(text)
+            .wrap(Wrap { trim: false })
+            .vscroll(scroll);
+
+        let padding = block_padding2(block);
+        let area = if let Some(max_percent) = widget.auto_height {
+            let content_width = area.width.saturating_sub(padding.left + padding.right);
+            // content + spacer + buttons
+            let height = para.line_height(content_width) as u16 + 2 + padding.top + padding.bottom;
+            let height = min(
+                height,
+                (area.height as u32 * max_percent as u32 / 100) as u16,
+            );
+            Rect::new(
+                area.x,
+                area.y + (area.height - height) / 2,
+                area.width,
+                height,
+            )
+        } else {
+            area
+        };
+
         let l_dlg = layout_dialog(
             area, //
-            block_padding2(block),
+            padding,
             [Constraint::Length(10)],
             0,
             Flex::End,
@@ -247,33 +339,30 @@ fn render_ref(widget: &MsgDialog<'_>, area: Rect, buf: &mut Buffer, state: &mut
         reset_buf_area(state.area, buf);
         block.render(state.area, buf);
 
-        {
-            let scroll = if let Some(style) = &widget.scroll_style {
-                Scroll::new().styles(style.clone())
-            } else {
-                Scroll::new().style(widget.style)
-            };
+        para.render(
+            l_dlg.widget_for(DialogItem::Content),
+            buf,
+            &mut state.paragraph.borrow_mut(),
+        );
 
-            let message = state.message.borrow();
-            let mut lines = Vec::new();
-            for t in message.split('\n') {
-                lines.push(Line::from(t));
+        let mut button = Button::new("Ok").styles_opt(widget.button_style.clone());
+        if state.default_button.get() {
+            if let Some(style) = &widget.default_button_style {
+                button = button.styles(style.clone());
+            } else {
+                let style = widget
+                    .button_style
+                    .as_ref()
+                    .map(|v| v.style)
+                    .unwrap_or(widget.style);
+                button = button.style(style.add_modifier(Modifier::BOLD));
             }
-            let text = Text::from(lines).alignment(Alignment::Center);
-            Paragraph::new(text).scroll(scroll).render(
-                l_dlg.widget_for(DialogItem::Content),
-                buf,
-                &mut state.paragraph.borrow_mut(),
-            );
         }
-
-        Button::new("Ok")
-            .styles_opt(widget.button_style.clone())
-            .render(
-                l_dlg.widget_for(DialogItem::Button(0)),
-                buf,
-                &mut state.button.borrow_mut(),
-            );
+        button.render(
+            l_dlg.widget_for(DialogItem::Button(0)),
+            buf,
+            &mut state.button.borrow_mut(),
+        );
     }
 }
 
@@ -283,11 +372,14 @@ impl HandleEvent<crossterm::event::Event, Dialog, Outcome> for MsgDialogState {
             let mut focus = self.focus();
             let f = focus.handle(event, Regular);
 
-            let mut r = match self
-                .button
-                .borrow_mut()
-                .handle(event, KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
-            {
+            let r = if self.default_button.get() {
+                self.button
+                    .borrow_mut()
+                    .handle(event, KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            } else {
+                self.button.borrow_mut().handle(event, Regular)
+            };
+            let mut r = match r {
                 ButtonOutcome::Pressed => {
                     self.clear();
                     self.active.set(false);
@@ -297,7 +389,11 @@ impl HandleEvent<crossterm::event::Event, Dialog, Outcome> for MsgDialogState {
             };
             r = r.or_else(|| self.paragraph.borrow_mut().handle(event, Regular));
             r = r.or_else(|| match event {
-                ct_event!(keycode press Esc) => {
+                ct_event!(key press CONTROL-'c') => {
+                    self.copy_to_clip();
+                    Outcome::Unchanged
+                }
+                ct_event!(keycode press Esc) if self.esc_close.get() => {
                     self.clear();
                     self.active.set(false);
                     Outcome::Changed
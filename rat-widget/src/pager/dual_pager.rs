@@ -241,6 +241,34 @@ where
         }
     }
 
+    /// Render the popup of a stateful widget.
+    ///
+    /// Call this after all widgets have been rendered, so the
+    /// popup is drawn above them. The popup is rendered at the
+    /// position of the widget.
+    ///
+    /// If the widget is not visible the popup areas are cleared.
+    #[inline(always)]
+    pub fn render_popup<FN, WW, SS>(&mut self, widget: W, render_fn: FN, state: &mut SS) -> bool
+    where
+        FN: FnOnce() -> Option<WW>,
+        WW: StatefulWidget<State = SS>,
+        SS: RelocatableState,
+    {
+        let Some(idx) = self.pager0.widget_idx(widget) else {
+            return false;
+        };
+        let rendered = if self.pager0.is_visible(idx) {
+            self.pager0.render_opt(idx, render_fn, state)
+        } else {
+            self.pager1.render_opt(idx, render_fn, state)
+        };
+        if !rendered {
+            self.hidden_popup(state);
+        }
+        rendered
+    }
+
     /// Render a stateful widget and its label, if any.
     #[inline(always)]
     pub fn render<FN, WW, SS>(&mut self, widget: W, render_fn: FN, state: &mut SS) -> bool
@@ -356,13 +384,31 @@ where
     {
     }
 
+    /// Does nothing for pager.
+    /// Just to keep the api in sync with [Clipper](crate::clipper::Clipper).
+    pub fn relocate_popup<S>(&self, _state: &mut S)
+    where
+        S: RelocatableState,
+    {
+    }
+
     /// Clear the areas in the widget-state.
     /// This is called by render_xx whenever a widget is invisible.
+    /// A popup of an invisible widget is hidden too.
     pub fn hidden<S>(&self, state: &mut S)
     where
         S: RelocatableState,
     {
-        state.relocate((0, 0), Rect::default())
+        state.relocate_hidden();
+        state.relocate_popup_hidden();
+    }
+
+    /// Clear the popup areas of an invisible widget.
+    pub fn hidden_popup<S>(&self, state: &mut S)
+    where
+        S: RelocatableState,
+    {
+        state.relocate_popup_hidden();
     }
 
     /// Get access to the buffer during rendering a page.
@@ -160,6 +160,11 @@ where
         self.first.relocate(shift, clip);
         self.second.relocate(shift, clip);
     }
+
+    fn relocate_popup(&mut self, shift: (i16, i16), clip: Rect) {
+        self.first.relocate_popup(shift, clip);
+        self.second.relocate_popup(shift, clip);
+    }
 }
 
 impl<'a, TS, US> PairedState<'a, TS, US> {
@@ -12,12 +12,13 @@ use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Position, Rect};
 use ratatui::style::Style;
-use ratatui::text::Text;
+use ratatui::text::{Line, Span, Text};
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{Block, StatefulWidget, Widget, Wrap};
 use std::cell::RefCell;
 use std::cmp::min;
+use std::collections::VecDeque;
 use std::mem;
 use std::ops::DerefMut;
 
@@ -31,7 +32,9 @@ pub struct Paragraph<'a> {
     focus_style: Option<Style>,
 
     wrap: Option<Wrap>,
+    alignment: Option<Alignment>,
     para: RefCell<ratatui::widgets::Paragraph<'a>>,
+    buffer: Option<&'a StyledLinesBuffer>,
 
     block: Option<Block<'a>>,
     vscroll: Option<Scroll<'a>>,
@@ -61,6 +64,9 @@ pub struct ParagraphState {
 
     /// Text lines
     pub lines: usize,
+    /// Rows evicted from the [StyledLinesBuffer] at the last render.
+    /// __internal__
+    pub buffer_evicted: Option<usize>,
 
     /// Vertical scroll.
     /// __read+write__
@@ -69,6 +75,14 @@ pub struct ParagraphState {
     /// __read+write__
     pub hscroll: ScrollState,
 
+    /// Keep the view at the end of the text while it grows.
+    /// __read+write__
+    pub follow_tail: bool,
+    /// Follow-tail is engaged. Scrolling up disengages,
+    /// scrolling back to the end engages again.
+    /// __readonly__
+    pub following: bool,
+
     /// Focus.
     /// __read+write__
     pub focus: FocusFlag,
@@ -109,6 +123,14 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Render the lines of a [StyledLinesBuffer] instead of the text.
+    ///
+    /// Only the visible lines are rendered, nothing is cloned.
+    pub fn buffer(mut self, buffer: &'a StyledLinesBuffer) -> Self {
+        self.buffer = Some(buffer);
+        self
+    }
+
     /// Block.
     pub fn block(mut self, block: Block<'a>) -> Self {
         self.block = Some(block);
@@ -178,6 +200,7 @@ impl<'a> Paragraph<'a> {
 
     /// Text alignment.
     pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
         let mut para = mem::take(self.para.borrow_mut().deref_mut());
         para = para.alignment(alignment);
         self.para = RefCell::new(para);
@@ -202,7 +225,11 @@ impl<'a> Paragraph<'a> {
 
     /// Line width when not wrapped.
     pub fn line_width(&self) -> usize {
-        self.para.borrow().line_width()
+        if let Some(buffer) = self.buffer {
+            buffer.line_width()
+        } else {
+            self.para.borrow().line_width()
+        }
     }
 
     /// Line height for the supposed width.
@@ -212,10 +239,13 @@ impl<'a> Paragraph<'a> {
             .h_scroll(self.hscroll.as_ref())
             .v_scroll(self.vscroll.as_ref());
         let padding = sa.padding();
+        let width = width.saturating_sub(padding.left + padding.right);
 
-        self.para
-            .borrow()
-            .line_count(width.saturating_sub(padding.left + padding.right))
+        if let Some(buffer) = self.buffer {
+            buffer.height(width, self.wrap)
+        } else {
+            self.para.borrow().line_count(width)
+        }
     }
 }
 
@@ -255,17 +285,35 @@ fn render_paragraph(
     // not the final inner, showing the scrollbar might change this.
     let tmp_inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
     let pad_inner = sa.padding();
-
-    state.lines = para.line_count(area.width.saturating_sub(pad_inner.left + pad_inner.right));
+    let text_width = area.width.saturating_sub(pad_inner.left + pad_inner.right);
+
+    if let Some(buffer) = widget.buffer {
+        state.lines = buffer.height(text_width, widget.wrap);
+        // keep the view steady when lines are dropped at the front.
+        if let Some(evicted) = state.buffer_evicted {
+            let n = buffer.evicted_rows().saturating_sub(evicted);
+            state.vscroll.offset = state.vscroll.offset.saturating_sub(n);
+        }
+        state.buffer_evicted = Some(buffer.evicted_rows());
+    } else {
+        state.lines = para.line_count(text_width);
+        state.buffer_evicted = None;
+    }
 
     state
         .vscroll
         .set_max_offset(state.lines.saturating_sub(tmp_inner.height as usize));
     state.vscroll.set_page_len(tmp_inner.height as usize);
+    // the line count changes with the width.
+    if state.is_following() {
+        state.vscroll.set_offset(state.vscroll.max_offset());
+    } else {
+        state.vscroll.set_offset(state.vscroll.offset());
+    }
     state.hscroll.set_max_offset(if widget.wrap.is_some() {
         0
     } else {
-        para.line_width().saturating_sub(tmp_inner.width as usize)
+        widget.line_width().saturating_sub(tmp_inner.width as usize)
     });
     state.hscroll.set_page_len(tmp_inner.width as usize);
     state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
@@ -278,8 +326,22 @@ fn render_paragraph(
             .v_scroll(&mut state.vscroll),
     );
 
-    para = para.scroll((state.vscroll.offset() as u16, state.hscroll.offset() as u16));
-    (&para).render(state.inner, buf);
+    if let Some(buffer) = widget.buffer {
+        // only the visible part.
+        let (text, skip) = buffer.visible(state.vscroll.offset(), state.inner.height as usize);
+        let mut vis = ratatui::widgets::Paragraph::new(text)
+            .scroll((skip as u16, state.hscroll.offset() as u16));
+        if let Some(wrap) = widget.wrap {
+            vis = vis.wrap(wrap);
+        }
+        if let Some(alignment) = widget.alignment {
+            vis = vis.alignment(alignment);
+        }
+        vis.render(state.inner, buf);
+    } else {
+        para = para.scroll((state.vscroll.offset() as u16, state.hscroll.offset() as u16));
+        (&para).render(state.inner, buf);
+    }
 
     if state.is_focused() {
         let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
@@ -345,8 +407,11 @@ impl Clone for ParagraphState {
             area: self.area,
             inner: self.inner,
             lines: self.lines,
+            buffer_evicted: self.buffer_evicted,
             vscroll: self.vscroll.clone(),
             hscroll: self.hscroll.clone(),
+            follow_tail: self.follow_tail,
+            following: self.following,
             focus: FocusFlag::named(self.focus.name()),
             non_exhaustive: NonExhaustive,
         }
@@ -361,8 +426,11 @@ impl Default for ParagraphState {
             focus: Default::default(),
             vscroll: Default::default(),
             hscroll: Default::default(),
+            follow_tail: false,
+            following: true,
             non_exhaustive: NonExhaustive,
             lines: 0,
+            buffer_evicted: None,
         }
     }
 }
@@ -386,7 +454,9 @@ impl ParagraphState {
 
     /// Set limited offset.
     pub fn set_line_offset(&mut self, offset: usize) -> bool {
-        self.vscroll.set_offset(offset)
+        let r = self.vscroll.set_offset(offset);
+        self.update_following();
+        r
     }
 
     /// Current offset.
@@ -411,12 +481,39 @@ impl ParagraphState {
 
     /// Scroll up by n.
     pub fn scroll_up(&mut self, n: usize) -> bool {
-        self.vscroll.scroll_up(n)
+        let r = self.vscroll.scroll_up(n);
+        self.update_following();
+        r
     }
 
     /// Scroll down by n.
     pub fn scroll_down(&mut self, n: usize) -> bool {
-        self.vscroll.scroll_down(n)
+        let r = self.vscroll.scroll_down(n);
+        self.update_following();
+        r
+    }
+
+    /// Keep the view at the end of the text while it grows.
+    ///
+    /// Scrolling up disengages, scrolling back to the end
+    /// or pressing End engages again.
+    pub fn set_follow_tail(&mut self, follow_tail: bool) {
+        self.follow_tail = follow_tail;
+        self.following = true;
+    }
+
+    /// Keep the view at the end of the text while it grows.
+    pub fn follow_tail(&self) -> bool {
+        self.follow_tail
+    }
+
+    /// Is follow-tail active and currently engaged.
+    pub fn is_following(&self) -> bool {
+        self.follow_tail && self.following
+    }
+
+    fn update_following(&mut self) {
+        self.following = self.vscroll.offset() >= self.vscroll.max_offset();
     }
 }
 
@@ -501,3 +598,209 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for ParagraphState
         }
     }
 }
+
+/// Append-only buffer of styled lines for log and chat views.
+///
+/// Lines are appended with [push_line](Self::push_line). With
+/// [max_lines](Self::with_max_lines) set, the oldest lines are
+/// dropped. Render it with [Paragraph::buffer].
+///
+/// The wrapped height of each line is cached, and a
+/// [ParagraphState] shifts its offset by the rows that have been
+/// dropped since the last render, so the view doesn't jump.
+#[derive(Debug, Default)]
+pub struct StyledLinesBuffer {
+    lines: VecDeque<Line<'static>>,
+    widths: VecDeque<usize>,
+    max_lines: Option<usize>,
+    evicted: usize,
+    evicted_rows: usize,
+    heights: RefCell<LineHeights>,
+}
+
+/// Rendered height of each line.
+#[derive(Debug, Default)]
+struct LineHeights {
+    width: u16,
+    wrap: Option<Wrap>,
+    /// Heights of the first lines. Lines pushed since the
+    /// last render are not measured yet.
+    rows: VecDeque<usize>,
+    total: usize,
+}
+
+impl StyledLinesBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most max lines.
+    pub fn with_max_lines(max: usize) -> Self {
+        Self {
+            max_lines: Some(max),
+            ..Default::default()
+        }
+    }
+
+    /// Keep at most max lines. None for no limit.
+    ///
+    /// Drops lines from the front if necessary.
+    pub fn set_max_lines(&mut self, max: Option<usize>) {
+        self.max_lines = max;
+        self.evict();
+    }
+
+    /// Maximum number of lines.
+    pub fn max_lines(&self) -> Option<usize> {
+        self.max_lines
+    }
+
+    /// Append a line.
+    ///
+    /// Drops the first line when max_lines is exceeded.
+    pub fn push_line(&mut self, line: impl Into<Line<'static>>) {
+        let line = line.into();
+        self.widths.push_back(line.width());
+        self.lines.push_back(line);
+        self.evict();
+    }
+
+    /// Remove all lines.
+    pub fn clear(&mut self) {
+        let len = self.lines.len();
+        self.drop_front(len);
+    }
+
+    /// Number of lines.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Line by index.
+    pub fn line(&self, n: usize) -> Option<&Line<'static>> {
+        self.lines.get(n)
+    }
+
+    /// All lines.
+    pub fn lines(&self) -> impl Iterator<Item = &Line<'static>> {
+        self.lines.iter()
+    }
+
+    /// Number of lines dropped from the front so far.
+    pub fn evicted(&self) -> usize {
+        self.evicted
+    }
+
+    /// Number of rendered rows dropped from the front so far.
+    ///
+    /// Uses the wrapped height of the last render.
+    pub fn evicted_rows(&self) -> usize {
+        self.evicted_rows
+    }
+
+    /// Width of the longest line.
+    pub fn line_width(&self) -> usize {
+        self.widths.iter().copied().max().unwrap_or_default()
+    }
+
+    /// Total rendered height for the given text width.
+    pub fn height(&self, width: u16, wrap: Option<Wrap>) -> usize {
+        self.measure(width, wrap);
+        self.heights.borrow().total
+    }
+
+    fn evict(&mut self) {
+        if let Some(max) = self.max_lines {
+            let n = self.lines.len().saturating_sub(max);
+            self.drop_front(n);
+        }
+    }
+
+    fn drop_front(&mut self, n: usize) {
+        let heights = self.heights.get_mut();
+        for _ in 0..n {
+            self.lines.pop_front();
+            self.widths.pop_front();
+            let rows = if let Some(rows) = heights.rows.pop_front() {
+                heights.total -= rows;
+                rows
+            } else {
+                1
+            };
+            self.evicted += 1;
+            self.evicted_rows += rows;
+        }
+    }
+
+    /// Measure new lines, or everything if the width changed.
+    fn measure(&self, width: u16, wrap: Option<Wrap>) {
+        let mut heights = self.heights.borrow_mut();
+        if heights.width != width || heights.wrap != wrap {
+            heights.width = width;
+            heights.wrap = wrap;
+            heights.rows.clear();
+            heights.total = 0;
+        }
+        for n in heights.rows.len()..self.lines.len() {
+            let rows = if let Some(wrap) = wrap {
+                ratatui::widgets::Paragraph::new(borrow_line(&self.lines[n]))
+                    .wrap(wrap)
+                    .line_count(width)
+                    .max(1)
+            } else {
+                1
+            };
+            heights.rows.push_back(rows);
+            heights.total += rows;
+        }
+    }
+
+    /// Lines visible at the given row offset, and the rows
+    /// to skip in the first line.
+    ///
+    /// Uses the heights of the last call to height().
+    fn visible(&self, offset: usize, page: usize) -> (Text<'_>, usize) {
+        let heights = self.heights.borrow();
+
+        let mut row = 0;
+        let mut first = heights.rows.len();
+        let mut skip = 0;
+        for (n, rows) in heights.rows.iter().enumerate() {
+            if row + rows > offset {
+                first = n;
+                skip = offset - row;
+                break;
+            }
+            row += rows;
+        }
+
+        let mut lines = Vec::new();
+        let mut row = 0;
+        for n in first..heights.rows.len() {
+            if row >= skip + page {
+                break;
+            }
+            lines.push(borrow_line(&self.lines[n]));
+            row += heights.rows[n];
+        }
+
+        (Text::from(lines), skip)
+    }
+}
+
+/// Line referencing the content of the original.
+fn borrow_line<'a>(line: &'a Line<'_>) -> Line<'a> {
+    Line {
+        style: line.style,
+        alignment: line.alignment,
+        spans: line
+            .spans
+            .iter()
+            .map(|v| Span::styled(v.content.as_ref(), v.style))
+            .collect(),
+    }
+}
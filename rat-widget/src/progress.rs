@@ -0,0 +1,476 @@
+//!
+//! Progress bar and spinner.
+//!
+//! Both are display-only widgets and don't take part in the focus.
+//!
+//! ```rust ignore
+//! ProgressBar::new()
+//!     .styles(theme.progress_style())
+//!     .render(area, buf, &mut state.progress);
+//!
+//! // advance from a timer or the Rendered event.
+//! state.spinner.tick();
+//! Spinner::new()
+//!     .label("loading")
+//!     .render(area, buf, &mut state.spinner);
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use rat_reloc::{relocate_area, RelocatableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::BlockExt;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+
+/// Partial blocks for the sub-cell resolution. Index is eighths.
+const PARTIAL: [&str; 8] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+/// Full block.
+const FULL: &str = "█";
+
+/// Default spinner frames.
+pub const SPINNER_DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+/// Spinner frames for limited fonts.
+pub const SPINNER_ASCII: &[&str] = &["|", "/", "-", "\\"];
+
+/// Determinate progress bar.
+#[derive(Debug, Default, Clone)]
+pub struct ProgressBar<'a> {
+    style: Style,
+    filled_style: Option<Style>,
+    label_style: Option<Style>,
+    block: Option<Block<'a>>,
+    label: Option<Line<'a>>,
+    percent: bool,
+}
+
+/// Indeterminate progress.
+#[derive(Debug, Clone)]
+pub struct Spinner<'a> {
+    style: Style,
+    label_style: Option<Style>,
+    block: Option<Block<'a>>,
+    label: Option<Line<'a>>,
+    frames: &'a [&'a str],
+}
+
+/// Combined style for ProgressBar and Spinner.
+#[derive(Debug, Clone)]
+pub struct ProgressStyle {
+    /// Base style. The fg color is used for the bar.
+    pub style: Style,
+    /// Style for the filled part of the bar.
+    pub filled: Option<Style>,
+    /// Style for the label.
+    pub label: Option<Style>,
+    /// Border
+    pub block: Option<Block<'static>>,
+    /// Frames for the spinner.
+    pub frames: Option<&'static [&'static str]>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State for the ProgressBar.
+#[derive(Debug, Clone)]
+pub struct ProgressBarState {
+    /// Full area.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Area inside the block.
+    /// __readonly__. renewed for each render.
+    pub inner: Rect,
+
+    /// Progress as ratio `0.0..=1.0`.
+    /// __read+write__
+    pub ratio: f64,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State for the Spinner.
+#[derive(Debug, Clone)]
+pub struct SpinnerState {
+    /// Full area.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Area inside the block.
+    /// __readonly__. renewed for each render.
+    pub inner: Rect,
+
+    /// Current frame. Wraps around with the number of frames.
+    /// __read+write__
+    pub frame: usize,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for ProgressStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            filled: None,
+            label: None,
+            block: None,
+            frames: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<'a> ProgressBar<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set all styles.
+    pub fn styles(mut self, styles: ProgressStyle) -> Self {
+        self.style = styles.style;
+        if styles.filled.is_some() {
+            self.filled_style = styles.filled;
+        }
+        if styles.label.is_some() {
+            self.label_style = styles.label;
+        }
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self.block = self.block.map(|v| v.style(style));
+        self
+    }
+
+    /// Style for the filled part.
+    pub fn filled_style(mut self, style: Style) -> Self {
+        self.filled_style = Some(style);
+        self
+    }
+
+    /// Style for the label.
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = Some(style);
+        self
+    }
+
+    /// Block
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Label shown in the middle of the bar.
+    /// Overrides the percentage.
+    pub fn label(mut self, label: impl Into<Line<'a>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Show the percentage as label.
+    pub fn percent(mut self, show: bool) -> Self {
+        self.percent = show;
+        self
+    }
+}
+
+impl Default for Spinner<'_> {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            label_style: None,
+            block: None,
+            label: None,
+            frames: SPINNER_DOTS,
+        }
+    }
+}
+
+impl<'a> Spinner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set all styles.
+    pub fn styles(mut self, styles: ProgressStyle) -> Self {
+        self.style = styles.style;
+        if styles.label.is_some() {
+            self.label_style = styles.label;
+        }
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        if let Some(frames) = styles.frames {
+            self.frames = frames;
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self.block = self.block.map(|v| v.style(style));
+        self
+    }
+
+    /// Style for the label.
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = Some(style);
+        self
+    }
+
+    /// Block
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Label after the spinner.
+    pub fn label(mut self, label: impl Into<Line<'a>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Animation frames.
+    pub fn frames(mut self, frames: &'a [&'a str]) -> Self {
+        self.frames = frames;
+        self
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl StatefulWidgetRef for ProgressBar<'_> {
+    type State = ProgressBarState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_bar(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for ProgressBar<'_> {
+    type State = ProgressBarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_bar(&self, area, buf, state);
+    }
+}
+
+fn render_bar(
+    widget: &ProgressBar<'_>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut ProgressBarState,
+) {
+    state.area = area;
+    state.inner = widget.block.inner_if_some(area);
+
+    buf.set_style(area, widget.style);
+    widget.block.render(area, buf);
+
+    let inner = state.inner;
+    if inner.is_empty() {
+        return;
+    }
+
+    let filled_style = widget.style.patch(widget.filled_style.unwrap_or_default());
+    let label_style = widget.style.patch(widget.label_style.unwrap_or_default());
+
+    let eighths = (state.ratio() * inner.width as f64 * 8.0).round() as u32;
+    let full = (eighths / 8) as u16;
+    let part = (eighths % 8) as usize;
+
+    let label = if let Some(label) = &widget.label {
+        Some(label.clone())
+    } else if widget.percent {
+        Some(Line::from(format!("{}%", (state.ratio() * 100.0).round())))
+    } else {
+        None
+    };
+    let label_row = inner.y + inner.height / 2;
+    let (label_x, label_width) = if let Some(label) = &label {
+        let width = (label.width() as u16).min(inner.width);
+        (inner.x + (inner.width - width) / 2, width)
+    } else {
+        (inner.x, 0)
+    };
+
+    for y in inner.top()..inner.bottom() {
+        for (n, x) in (inner.left()..inner.right()).enumerate() {
+            let n = n as u16;
+            let symbol = if n < full {
+                FULL
+            } else if n == full {
+                PARTIAL[part]
+            } else {
+                " "
+            };
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.set_symbol(symbol);
+                cell.set_style(filled_style);
+            }
+        }
+
+        if y == label_row {
+            if let Some(label) = &label {
+                let label_area = Rect::new(label_x, y, label_width, 1);
+                label.render(label_area, buf);
+                for x in label_area.left()..label_area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        // inverse colors over the filled part.
+                        if x - inner.x < full {
+                            cell.set_style(filled_style.reversed());
+                        } else {
+                            cell.set_style(label_style);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl StatefulWidgetRef for Spinner<'_> {
+    type State = SpinnerState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_spinner(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for Spinner<'_> {
+    type State = SpinnerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_spinner(&self, area, buf, state);
+    }
+}
+
+fn render_spinner(widget: &Spinner<'_>, area: Rect, buf: &mut Buffer, state: &mut SpinnerState) {
+    state.area = area;
+    state.inner = widget.block.inner_if_some(area);
+
+    buf.set_style(area, widget.style);
+    widget.block.render(area, buf);
+
+    let inner = state.inner;
+    if inner.is_empty() {
+        return;
+    }
+
+    let frame = if widget.frames.is_empty() {
+        ""
+    } else {
+        widget.frames[state.frame % widget.frames.len()]
+    };
+    let frame_width = Span::from(frame).width() as u16;
+    Span::from(frame).render(inner, buf);
+
+    if let Some(label) = &widget.label {
+        let label_area = Rect::new(
+            inner.x + frame_width + 1,
+            inner.y,
+            inner.width.saturating_sub(frame_width + 1),
+            1,
+        )
+        .intersection(inner);
+        label.render(label_area, buf);
+        if let Some(label_style) = widget.label_style {
+            buf.set_style(label_area, label_style);
+        }
+    }
+}
+
+impl Default for ProgressBarState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            ratio: 0.0,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RelocatableState for ProgressBarState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+    }
+}
+
+impl ProgressBarState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Progress as ratio `0.0..=1.0`.
+    pub fn ratio(&self) -> f64 {
+        self.ratio.clamp(0.0, 1.0)
+    }
+
+    /// Set the progress as ratio `0.0..=1.0`.
+    pub fn set_ratio(&mut self, ratio: f64) {
+        self.ratio = ratio.clamp(0.0, 1.0);
+    }
+
+    /// Set the progress as done of total.
+    /// A total of 0 counts as complete.
+    pub fn set_progress(&mut self, done: u64, total: u64) {
+        if total == 0 {
+            self.ratio = 1.0;
+        } else {
+            self.ratio = (done.min(total) as f64) / (total as f64);
+        }
+    }
+
+    /// Progress is complete.
+    pub fn is_complete(&self) -> bool {
+        self.ratio() >= 1.0
+    }
+}
+
+impl Default for SpinnerState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            frame: 0,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RelocatableState for SpinnerState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+    }
+}
+
+impl SpinnerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance to the next frame.
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    /// Set the frame, e.g. from a frame counter.
+    pub fn set_frame(&mut self, frame: usize) {
+        self.frame = frame;
+    }
+}
@@ -7,7 +7,7 @@ use crate::event::RadioOutcome;
 use crate::util::{block_size, fill_buf_area, revert_style, union_non_empty};
 use rat_event::util::{item_at, MouseFlags};
 use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
-use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Direction, Rect, Size};
@@ -49,6 +49,7 @@ where
     style: Style,
     select_style: Option<Style>,
     focus_style: Option<Style>,
+    disabled_style: Option<Style>,
     block: Option<Block<'a>>,
 }
 
@@ -64,6 +65,8 @@ pub struct RadioStyle {
     pub select: Option<Style>,
     /// Focused style
     pub focus: Option<Style>,
+    /// Disabled style
+    pub disabled: Option<Style>,
     /// Border
     pub block: Option<Block<'static>>,
 
@@ -106,6 +109,10 @@ where
 
     /// Core
     pub core: ChoiceCore<T>,
+    /// Radio is disabled. It ignores all events and
+    /// is skipped by the focus.
+    /// __read+write__
+    pub disabled: bool,
 
     /// Current focus state.
     /// __read+write__
@@ -159,6 +166,7 @@ impl Default for RadioStyle {
             style: Default::default(),
             select: None,
             focus: None,
+            disabled: None,
             block: Default::default(),
             true_str: None,
             false_str: None,
@@ -185,6 +193,7 @@ where
             style: Default::default(),
             select_style: None,
             focus_style: None,
+            disabled_style: None,
             block: None,
         }
     }
@@ -234,6 +243,9 @@ where
         if styles.focus.is_some() {
             self.focus_style = styles.focus;
         }
+        if styles.disabled.is_some() {
+            self.disabled_style = styles.disabled;
+        }
         if styles.select.is_some() {
             self.select_style = styles.focus;
         }
@@ -271,6 +283,14 @@ where
         self
     }
 
+    /// Style when disabled.
+    /// This is patched onto the radio. Defaults to dim.
+    #[inline]
+    pub fn disabled_style(mut self, style: impl Into<Style>) -> Self {
+        self.disabled_style = Some(style.into());
+        self
+    }
+
     /// Radio direction
     #[inline]
     pub fn direction(mut self, direction: Direction) -> Self {
@@ -739,7 +759,8 @@ where
             buf.set_style(state.area, self.style);
         }
 
-        if state.is_focused() {
+        let focused = state.is_focused() && !state.disabled;
+        if focused {
             buf.set_style(state.marker_area, focus_style);
         }
 
@@ -747,11 +768,7 @@ where
             if Some(i) == state.core.selected() {
                 buf.set_style(
                     union_non_empty(state.check_areas[i], state.text_areas[i]),
-                    if state.is_focused() {
-                        focus_style
-                    } else {
-                        select_style
-                    },
+                    if focused { focus_style } else { select_style },
                 );
                 (&self.true_str).render(state.check_areas[i], buf);
             } else {
@@ -764,6 +781,13 @@ where
             fill_buf_area(buf, state.continue_area, " ", self.style);
             self.continue_str.render(state.continue_area, buf);
         }
+
+        if state.disabled {
+            buf.set_style(
+                state.area,
+                self.disabled_style.unwrap_or(Style::new().dim()),
+            );
+        }
     }
 }
 
@@ -780,6 +804,7 @@ where
             check_areas: self.check_areas.clone(),
             text_areas: self.text_areas.clone(),
             core: self.core.clone(),
+            disabled: self.disabled,
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -800,6 +825,7 @@ where
             check_areas: Default::default(),
             text_areas: Default::default(),
             core: Default::default(),
+            disabled: false,
             focus: Default::default(),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -822,6 +848,14 @@ where
     fn area(&self) -> Rect {
         self.area
     }
+
+    fn navigable(&self) -> Navigation {
+        if self.disabled {
+            Navigation::None
+        } else {
+            Navigation::Regular
+        }
+    }
 }
 
 impl<T> RelocatableState for RadioState<T>
@@ -831,6 +865,8 @@ where
     fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
         self.area = relocate_area(self.area, shift, clip);
         self.inner = relocate_area(self.inner, shift, clip);
+        self.marker_area = relocate_area(self.marker_area, shift, clip);
+        self.continue_area = relocate_area(self.continue_area, shift, clip);
         relocate_areas(self.check_areas.as_mut_slice(), shift, clip);
         relocate_areas(self.text_areas.as_mut_slice(), shift, clip);
     }
@@ -851,6 +887,16 @@ where
         }
     }
 
+    /// Disable the radio.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Radio is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
     pub fn is_empty(&self) -> bool {
         self.text_areas.is_empty()
     }
@@ -965,6 +1011,9 @@ where
     T: PartialEq + Clone + Default,
 {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> RadioOutcome {
+        if self.disabled {
+            return RadioOutcome::Continue;
+        }
         let r = if self.is_focused() {
             match event {
                 ct_event!(keycode press Left) => {
@@ -1039,6 +1088,9 @@ where
     T: PartialEq + Clone + Default,
 {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> RadioOutcome {
+        if self.disabled {
+            return RadioOutcome::Continue;
+        }
         match event {
             ct_event!(mouse any for m) if self.mouse.drag(self.area, m) => {
                 if let Some(sel) = item_at(self.text_areas.as_slice(), m.column, m.row)
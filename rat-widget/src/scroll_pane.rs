@@ -0,0 +1,358 @@
+//!
+//! Keyboard scrolling for read-only content.
+//!
+//! A help text or some other read-only pane has nothing
+//! focusable, so it can't be scrolled with the keyboard.
+//! ScrollPane gives it a focus stop and maps the navigation
+//! keys and the mouse wheel to its ScrollState.
+//!
+//! ScrollPane only renders the block and the scrollbar.
+//! The content is rendered to [ScrollPaneState::inner]
+//! using the [ScrollPaneState::offset].
+//!
+//! ```rust ignore
+//! use rat_widget::scroll_pane::{ScrollPane, ScrollPaneState};
+//!
+//! ScrollPane::new()
+//!     .block(Block::bordered())
+//!     .vscroll(Scroll::new())
+//!     .content_len(text.lines.len())
+//!     .styles(THEME.scroll_pane_style())
+//!     .render(area, frame.buffer_mut(), &mut state.help);
+//!
+//! ratatui::widgets::Paragraph::new(text)
+//!     .scroll((state.help.offset() as u16, 0))
+//!     .render(state.help.inner, frame.buffer_mut());
+//! ```
+//!
+//! If the pane has a block, the border is rendered with
+//! the focus style while the pane is focused.
+//!
+use crate::_private::NonExhaustive;
+use crate::util::revert_style;
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Outcome, Regular};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_reloc::{relocate_area, RelocatableState};
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{Block, StatefulWidget};
+
+/// Scrollable pane for read-only content.
+#[derive(Debug, Default, Clone)]
+pub struct ScrollPane<'a> {
+    style: Style,
+    focus_style: Option<Style>,
+    content_len: usize,
+
+    block: Option<Block<'a>>,
+    vscroll: Option<Scroll<'a>>,
+}
+
+/// Combined styles.
+#[derive(Debug, Clone)]
+pub struct ScrollPaneStyle {
+    pub style: Style,
+    /// Border style while focused.
+    pub focus: Option<Style>,
+
+    pub block: Option<Block<'static>>,
+    pub scroll: Option<ScrollStyle>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State & event handling.
+#[derive(Debug)]
+pub struct ScrollPaneState {
+    /// Full area of the widget.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Area for the content.
+    /// __readonly__. renewed for each render.
+    pub inner: Rect,
+
+    /// Vertical scroll.
+    /// __read+write__
+    pub vscroll: ScrollState,
+
+    /// Focus.
+    /// __read+write__
+    pub focus: FocusFlag,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for ScrollPaneStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            focus: None,
+            block: None,
+            scroll: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<'a> ScrollPane<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of lines of the content.
+    pub fn content_len(mut self, len: usize) -> Self {
+        self.content_len = len;
+        self
+    }
+
+    /// Block.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Vertical scroll.
+    pub fn vscroll(mut self, scroll: Scroll<'a>) -> Self {
+        self.vscroll = Some(scroll.override_vertical());
+        self
+    }
+
+    /// Styles.
+    pub fn styles(mut self, styles: ScrollPaneStyle) -> Self {
+        self.style = styles.style;
+        if styles.focus.is_some() {
+            self.focus_style = styles.focus;
+        }
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        if let Some(styles) = styles.scroll {
+            self.vscroll = self.vscroll.map(|v| v.styles(styles));
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Border style while focused.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl<'a> StatefulWidgetRef for ScrollPane<'a> {
+    type State = ScrollPaneState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_scroll_pane(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for ScrollPane<'_> {
+    type State = ScrollPaneState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_scroll_pane(&self, area, buf, state);
+    }
+}
+
+fn render_scroll_pane(
+    widget: &ScrollPane<'_>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut ScrollPaneState,
+) {
+    state.area = area;
+
+    let block = if state.is_focused() {
+        widget
+            .block
+            .clone()
+            .map(|v| v.border_style(widget.focus_style.unwrap_or(revert_style(widget.style))))
+    } else {
+        widget.block.clone()
+    };
+
+    let sa = ScrollArea::new()
+        .block(block.as_ref())
+        .v_scroll(widget.vscroll.as_ref());
+    state.inner = sa.inner(area, None, Some(&state.vscroll));
+
+    state.vscroll.set_max_offset(
+        widget
+            .content_len
+            .saturating_sub(state.inner.height as usize),
+    );
+    state.vscroll.set_page_len(state.inner.height as usize);
+    state.vscroll.set_offset(state.vscroll.offset());
+
+    buf.set_style(area, widget.style);
+    sa.render(
+        area,
+        buf,
+        &mut ScrollAreaState::new().v_scroll(&mut state.vscroll),
+    );
+}
+
+impl HasFocus for ScrollPaneState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.leaf_widget(self);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+impl RelocatableState for ScrollPaneState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        self.vscroll.relocate(shift, clip);
+    }
+}
+
+impl Clone for ScrollPaneState {
+    fn clone(&self) -> Self {
+        Self {
+            area: self.area,
+            inner: self.inner,
+            vscroll: self.vscroll.clone(),
+            focus: FocusFlag::named(self.focus.name()),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Default for ScrollPaneState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            vscroll: Default::default(),
+            focus: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl ScrollPaneState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn named(name: &str) -> Self {
+        Self {
+            focus: FocusFlag::named(name),
+            ..Self::default()
+        }
+    }
+
+    /// Current offset.
+    pub fn offset(&self) -> usize {
+        self.vscroll.offset()
+    }
+
+    /// Set limited offset.
+    pub fn set_offset(&mut self, offset: usize) -> bool {
+        self.vscroll.set_offset(offset)
+    }
+
+    /// Scroll up by n.
+    pub fn scroll_up(&mut self, n: usize) -> bool {
+        self.vscroll.scroll_up(n)
+    }
+
+    /// Scroll down by n.
+    pub fn scroll_down(&mut self, n: usize) -> bool {
+        self.vscroll.scroll_down(n)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, Outcome> for ScrollPaneState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> Outcome {
+        flow!(if self.is_focused() {
+            match event {
+                ct_event!(keycode press Up) => self.scroll_up(1).into(),
+                ct_event!(keycode press Down) => self.scroll_down(1).into(),
+                ct_event!(keycode press PageUp) => {
+                    self.scroll_up(self.vscroll.page_len() * 6 / 10).into()
+                }
+                ct_event!(keycode press PageDown) => {
+                    self.scroll_down(self.vscroll.page_len() * 6 / 10).into()
+                }
+                ct_event!(keycode press Home) => self.set_offset(0).into(),
+                ct_event!(keycode press End) => self.set_offset(self.vscroll.max_offset()).into(),
+                _ => Outcome::Continue,
+            }
+        } else {
+            Outcome::Continue
+        });
+
+        self.handle(event, MouseOnly)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for ScrollPaneState {
+    fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> Outcome {
+        let mut sas = ScrollAreaState::new()
+            .area(self.inner)
+            .v_scroll(&mut self.vscroll);
+        match sas.handle(event, MouseOnly) {
+            ScrollOutcome::Up(v) => {
+                if self.scroll_up(v) {
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            ScrollOutcome::Down(v) => {
+                if self.scroll_down(v) {
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            ScrollOutcome::VPos(v) => self.set_offset(v).into(),
+            r => r.into(),
+        }
+    }
+}
+
+/// Handle all events.
+/// Key events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut ScrollPaneState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(
+    state: &mut ScrollPaneState,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
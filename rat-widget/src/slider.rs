@@ -16,15 +16,18 @@
 //!
 //! ```
 //!
+//! [RangeSlider] does the same with two knobs for selecting
+//! a range of values.
+//!
 
 use crate::_private::NonExhaustive;
 use crate::range_op::RangeOp;
-use crate::slider::event::SliderOutcome;
+use crate::slider::event::{RangeSliderOutcome, SliderOutcome};
 use crate::util::revert_style;
 use map_range_int::MapRange;
 use rat_event::util::MouseFlags;
 use rat_event::{ct_event, HandleEvent, MouseOnly, Regular};
-use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Direction, Position, Rect};
@@ -53,6 +56,7 @@ where
     bounds_style: Option<Style>,
     knob_style: Option<Style>,
     focus_style: Option<Style>,
+    disabled_style: Option<Style>,
 
     direction: Direction,
 
@@ -84,6 +88,10 @@ pub struct SliderStyle {
     pub knob: Option<Style>,
     /// Style when focused.
     pub focus: Option<Style>,
+    /// Style when disabled.
+    pub disabled: Option<Style>,
+    /// Style for the track between the knobs of a RangeSlider.
+    pub range: Option<Style>,
 
     /// Alignment for all text.
     pub text_align: Option<Alignment>,
@@ -146,6 +154,11 @@ where
     /// Value
     pub value: T,
 
+    /// Slider is disabled. It ignores all events and
+    /// is skipped by the focus.
+    /// __read+write__
+    pub disabled: bool,
+
     /// Current focus state.
     /// __read+write__
     pub focus: FocusFlag,
@@ -189,6 +202,40 @@ pub(crate) mod event {
             }
         }
     }
+
+    /// Result value for event-handling of the RangeSlider.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum RangeSliderOutcome<T> {
+        /// The given event was not handled at all.
+        Continue,
+        /// The event was handled, no repaint necessary.
+        Unchanged,
+        /// The event was handled, repaint necessary.
+        Changed,
+        /// The value has changed. Contains the knob that was
+        /// moved by the user and the new (low, high) values.
+        ///
+        /// With [RangeCollision::Push](super::RangeCollision::Push)
+        /// the other knob may have changed too.
+        Value(super::RangeKnob, (T, T)),
+    }
+
+    impl<T> ConsumedEvent for RangeSliderOutcome<T> {
+        fn is_consumed(&self) -> bool {
+            !matches!(self, RangeSliderOutcome::Continue)
+        }
+    }
+
+    impl<T> From<RangeSliderOutcome<T>> for Outcome {
+        fn from(value: RangeSliderOutcome<T>) -> Self {
+            match value {
+                RangeSliderOutcome::Continue => Outcome::Continue,
+                RangeSliderOutcome::Unchanged => Outcome::Unchanged,
+                RangeSliderOutcome::Changed => Outcome::Changed,
+                RangeSliderOutcome::Value(_, _) => Outcome::Changed,
+            }
+        }
+    }
 }
 
 impl Default for SliderStyle {
@@ -198,6 +245,8 @@ impl Default for SliderStyle {
             bounds: None,
             knob: None,
             focus: None,
+            disabled: None,
+            range: None,
             text_align: None,
             lower_bound: None,
             upper_bound: None,
@@ -221,6 +270,7 @@ where
             bounds_style: None,
             knob_style: None,
             focus_style: None,
+            disabled_style: None,
             direction: Direction::Horizontal,
             range: None,
             step: None,
@@ -283,6 +333,9 @@ where
         if styles.focus.is_some() {
             self.focus_style = styles.focus;
         }
+        if styles.disabled.is_some() {
+            self.disabled_style = styles.disabled;
+        }
         if let Some(align) = styles.text_align {
             self.text_align = align;
         }
@@ -321,6 +374,13 @@ where
         self
     }
 
+    /// Style when disabled.
+    /// This is patched onto the slider. Defaults to dim.
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = Some(style);
+        self
+    }
+
     /// Style for the bounds text.
     pub fn bounds_style(mut self, style: Style) -> Self {
         self.bounds_style = Some(style);
@@ -614,7 +674,7 @@ fn render_slider<T>(
         widget.style
     };
     let bounds_style = widget.bounds_style.unwrap_or(style);
-    let knob_style = if state.is_focused() {
+    let knob_style = if state.is_focused() && !state.disabled {
         widget.focus_style.unwrap_or(revert_style(style))
     } else {
         widget.knob_style.unwrap_or(revert_style(style))
@@ -695,18 +755,31 @@ fn render_slider<T>(
         }
     }
 
+    render_knob(widget, buf, state, knob_style);
+
+    if state.disabled {
+        buf.set_style(area, widget.disabled_style.unwrap_or(Style::new().dim()));
+    }
+}
+
+fn render_knob<T>(widget: &Slider<'_, T>, buf: &mut Buffer, state: &SliderState<T>, style: Style)
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialEq,
+    u16: MapRange<T>,
+{
+    let focused = state.is_focused() && !state.disabled;
     match widget.direction {
         Direction::Horizontal => {
-            let knob_str = widget.render_knob_str(state.knob.height, state.is_focused());
+            let knob_str = widget.render_knob_str(state.knob.height, focused);
             Text::from(knob_str.as_ref())
-                .style(knob_style)
+                .style(style)
                 .render(state.knob, buf);
         }
         Direction::Vertical => {
-            let knob_str = widget.render_knob_str(state.knob.width, state.is_focused());
+            let knob_str = widget.render_knob_str(state.knob.width, focused);
             Line::from(knob_str)
                 .alignment(widget.text_align)
-                .style(knob_style)
+                .style(style)
                 .render(state.knob, buf);
         }
     }
@@ -753,6 +826,14 @@ where
     fn area(&self) -> Rect {
         self.area
     }
+
+    fn navigable(&self) -> Navigation {
+        if self.disabled {
+            Navigation::None
+        } else {
+            Navigation::Regular
+        }
+    }
 }
 
 impl<T> RelocatableState for SliderState<T>
@@ -787,6 +868,7 @@ macro_rules! slider_new {
                     step: 1,
                     long_step: None,
                     value: Default::default(),
+                    disabled: false,
                     focus: Default::default(),
                     mouse: Default::default(),
                     non_exhaustive: NonExhaustive,
@@ -818,6 +900,7 @@ macro_rules! slider_new_f {
                     step: 1.,
                     long_step: None,
                     value: Default::default(),
+                    disabled: false,
                     focus: Default::default(),
                     mouse: Default::default(),
                     non_exhaustive: NonExhaustive,
@@ -852,6 +935,7 @@ where
             step: self.step,
             long_step: self.long_step,
             value: self.value,
+            disabled: self.disabled,
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -894,12 +978,23 @@ where
             step,
             long_step: None,
             value: Default::default(),
+            disabled: false,
             focus: Default::default(),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
 
+    /// Disable the slider.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Slider is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
     /// Set the value.
     ///
     /// Any value you set is good, there will be no bounds check.
@@ -1017,6 +1112,9 @@ where
     u16: MapRange<T>,
 {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> SliderOutcome {
+        if self.disabled {
+            return SliderOutcome::Continue;
+        }
         let r = if self.is_focused() {
             match event {
                 ct_event!(keycode press CONTROL-Left)
@@ -1098,6 +1196,9 @@ where
     u16: MapRange<T>,
 {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> SliderOutcome {
+        if self.disabled {
+            return SliderOutcome::Continue;
+        }
         match event {
             ct_event!(mouse drag Left for x,y) | ct_event!(mouse down Left for x,y) => {
                 if self.inner.contains(Position::new(*x, *y)) {
@@ -1186,3 +1287,748 @@ where
 {
     HandleEvent::handle(state, event, MouseOnly)
 }
+
+/// Slider with two knobs for selecting a range of values.
+///
+/// Uses the same layout and styles as [Slider]. The track between
+/// the knobs is rendered with the range style.
+///
+/// Space switches the active knob, the keys move the active knob
+/// the same way as for the Slider. A mouse-click activates the
+/// nearest knob.
+#[derive(Debug, Clone)]
+pub struct RangeSlider<'a, T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    slider: Slider<'a, T>,
+    range_style: Option<Style>,
+}
+
+/// The knobs of a RangeSlider.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RangeKnob {
+    /// Knob for the lower value.
+    #[default]
+    Low,
+    /// Knob for the upper value.
+    High,
+}
+
+/// What happens if the active knob runs into the other one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RangeCollision {
+    /// The active knob stops at the other one.
+    #[default]
+    Block,
+    /// The active knob pushes the other one.
+    Push,
+}
+
+/// State for the RangeSlider.
+#[derive(Debug)]
+pub struct RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    /// State for the lower knob. Contains the areas
+    /// for the bounds and the track too.
+    /// __read+write__ the value, the rest is renewed for each render.
+    pub low: SliderState<T>,
+    /// State for the upper knob.
+    /// __read+write__ the value, the rest is renewed for each render.
+    pub high: SliderState<T>,
+
+    /// Active knob.
+    /// __read+write__
+    pub active: RangeKnob,
+    /// Collision behaviour.
+    /// __read+write__
+    pub collision: RangeCollision,
+
+    /// Slider is disabled. It ignores all events and
+    /// is skipped by the focus.
+    /// __read+write__
+    pub disabled: bool,
+
+    /// Current focus state.
+    /// __read+write__
+    pub focus: FocusFlag,
+
+    /// Mouse helper
+    /// __read+write__
+    pub mouse: MouseFlags,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl<T> Default for RangeSlider<'_, T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    fn default() -> Self {
+        Self {
+            slider: Default::default(),
+            range_style: None,
+        }
+    }
+}
+
+impl<'a, T> RangeSlider<'a, T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    /// New
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Direction for the slider.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.slider = self.slider.direction(direction);
+        self
+    }
+
+    /// Overrides the range of the slider.
+    pub fn range(mut self, range: (T, T)) -> Self {
+        self.slider = self.slider.range(range);
+        self
+    }
+
+    /// First step size.
+    pub fn step(mut self, step: <T as RangeOp>::Step) -> Self {
+        self.slider = self.slider.step(step);
+        self
+    }
+
+    /// Second step size.
+    pub fn long_step(mut self, step: <T as RangeOp>::Step) -> Self {
+        self.slider = self.slider.long_step(step);
+        self
+    }
+
+    /// Set all styles.
+    pub fn styles(mut self, styles: SliderStyle) -> Self {
+        if styles.range.is_some() {
+            self.range_style = styles.range;
+        }
+        self.slider = self.slider.styles(styles);
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.slider = self.slider.style(style);
+        self
+    }
+
+    /// Style for focus.
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.slider = self.slider.focus_style(style);
+        self
+    }
+
+    /// Style when disabled.
+    /// This is patched onto the slider. Defaults to dim.
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.slider = self.slider.disabled_style(style);
+        self
+    }
+
+    /// Style for the bounds text.
+    pub fn bounds_style(mut self, style: Style) -> Self {
+        self.slider = self.slider.bounds_style(style);
+        self
+    }
+
+    /// Style for the knobs.
+    pub fn knob_style(mut self, style: Style) -> Self {
+        self.slider = self.slider.knob_style(style);
+        self
+    }
+
+    /// Style for the track between the knobs.
+    pub fn range_style(mut self, style: Style) -> Self {
+        self.range_style = Some(style);
+        self
+    }
+
+    /// Text alignment. Used for the bounds and the knob.
+    pub fn text_align(mut self, align: Alignment) -> Self {
+        self.slider = self.slider.text_align(align);
+        self
+    }
+
+    /// Text for the lower bound. Can contain newlines.
+    pub fn lower_bound(mut self, bound: impl Into<Cow<'a, str>>) -> Self {
+        self.slider = self.slider.lower_bound(bound);
+        self
+    }
+
+    /// Text for the upper bound. Can contain newlines.
+    pub fn upper_bound(mut self, bound: impl Into<Cow<'a, str>>) -> Self {
+        self.slider = self.slider.upper_bound(bound);
+        self
+    }
+
+    /// Fill char for the track.
+    pub fn track_char(mut self, bound: impl Into<Cow<'a, str>>) -> Self {
+        self.slider = self.slider.track_char(bound);
+        self
+    }
+
+    /// Text for the horizontal knob. Can contain newlines for
+    /// multiline sliders.
+    pub fn horizontal_knob(mut self, knob: impl Into<Cow<'a, str>>) -> Self {
+        self.slider = self.slider.horizontal_knob(knob);
+        self
+    }
+
+    /// Text for the vertical knob. Can contain newlines for a
+    /// multiline knob.
+    pub fn vertical_knob(mut self, knob: impl Into<Cow<'a, str>>) -> Self {
+        self.slider = self.slider.vertical_knob(knob);
+        self
+    }
+
+    /// Block for borders.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.slider = self.slider.block(block);
+        self
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl<'a, T> StatefulWidgetRef for RangeSlider<'a, T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    type State = RangeSliderState<T>;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_range_slider(self, area, buf, state);
+    }
+}
+
+impl<T> StatefulWidget for RangeSlider<'_, T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    type State = RangeSliderState<T>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_range_slider(&self, area, buf, state);
+    }
+}
+
+fn render_range_slider<T>(
+    widget: &RangeSlider<'_, T>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut RangeSliderState<T>,
+) where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    let focused = state.is_focused() && !state.disabled;
+    state.low.disabled = state.disabled;
+    state.high.disabled = state.disabled;
+    state
+        .low
+        .focus
+        .set(focused && state.active == RangeKnob::Low);
+    state
+        .high
+        .focus
+        .set(focused && state.active == RangeKnob::High);
+
+    // bounds, track and the lower knob.
+    render_slider(&widget.slider, area, buf, &mut state.low);
+    widget.slider.layout(area, &mut state.high);
+
+    let style = if widget.slider.style == Default::default() {
+        Style::default().black().on_gray()
+    } else {
+        widget.slider.style
+    };
+    let range_style = widget
+        .range_style
+        .unwrap_or(widget.slider.knob_style.unwrap_or(revert_style(style)));
+    let range_area = match widget.slider.direction {
+        Direction::Horizontal => Rect::new(
+            state.low.knob.right(),
+            state.low.track.y,
+            state.high.knob.x.saturating_sub(state.low.knob.right()),
+            state.low.track.height,
+        ),
+        Direction::Vertical => Rect::new(
+            state.low.track.x,
+            state.low.knob.bottom(),
+            state.low.track.width,
+            state.high.knob.y.saturating_sub(state.low.knob.bottom()),
+        ),
+    };
+    buf.set_style(range_area, range_style);
+
+    let knob_style = if state.high.is_focused() {
+        widget.slider.focus_style.unwrap_or(revert_style(style))
+    } else {
+        widget.slider.knob_style.unwrap_or(revert_style(style))
+    };
+    render_knob(&widget.slider, buf, &state.high, knob_style);
+
+    if state.disabled {
+        buf.set_style(
+            area,
+            widget.slider.disabled_style.unwrap_or(Style::new().dim()),
+        );
+    }
+}
+
+impl<T> Clone for RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            low: self.low.clone(),
+            high: self.high.clone(),
+            active: self.active,
+            collision: self.collision,
+            disabled: self.disabled,
+            focus: FocusFlag::named(self.focus.name()),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<T> Default for RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+    SliderState<T>: Default,
+{
+    fn default() -> Self {
+        let mut low = SliderState::default();
+        let mut high = SliderState::default();
+        low.value = low.range.0;
+        high.value = high.range.1;
+        Self {
+            low,
+            high,
+            active: Default::default(),
+            collision: Default::default(),
+            disabled: false,
+            focus: Default::default(),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<T> HasFocus for RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.leaf_widget(self);
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    fn area(&self) -> Rect {
+        self.low.area
+    }
+
+    fn navigable(&self) -> Navigation {
+        if self.disabled {
+            Navigation::None
+        } else {
+            Navigation::Regular
+        }
+    }
+}
+
+impl<T> RelocatableState for RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.low.relocate(shift, clip);
+        self.high.relocate(shift, clip);
+    }
+}
+
+impl<T> RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    /// New state with a given range and step.
+    /// The values start with the complete range.
+    ///
+    /// The range will still be overridden when set with the Widget.
+    pub fn new_range(range: (T, T), step: T::Step) -> Self {
+        let mut low = SliderState::new_range(range, step);
+        let mut high = SliderState::new_range(range, step);
+        low.value = range.0;
+        high.value = range.1;
+        Self {
+            low,
+            high,
+            active: Default::default(),
+            collision: Default::default(),
+            disabled: false,
+            focus: Default::default(),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+
+    /// Disable the slider.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+        self.low.disabled = disabled;
+        self.high.disabled = disabled;
+    }
+
+    /// Slider is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Set the values. They will be swapped if low > high.
+    ///
+    /// There will be no bounds check.
+    pub fn set_value(&mut self, value: (T, T)) -> bool {
+        let old_value = self.value();
+        if value.0 > value.1 {
+            self.low.value = value.1;
+            self.high.value = value.0;
+        } else {
+            self.low.value = value.0;
+            self.high.value = value.1;
+        }
+        old_value != self.value()
+    }
+
+    /// Current values as (low, high).
+    pub fn value(&self) -> (T, T) {
+        (self.low.value, self.high.value)
+    }
+
+    /// Set the range.
+    pub fn set_range(&mut self, range: (T, T)) {
+        self.low.range = range;
+        self.high.range = range;
+    }
+
+    /// Range.
+    pub fn range(&self) -> (T, T) {
+        self.low.range
+    }
+
+    /// Minor step size.
+    pub fn set_step(&mut self, step: T::Step) {
+        self.low.step = step;
+        self.high.step = step;
+    }
+
+    /// Minor step size.
+    pub fn step(&self) -> T::Step {
+        self.low.step
+    }
+
+    /// Major step size.
+    pub fn set_long_step(&mut self, step: T::Step) {
+        self.low.long_step = Some(step);
+        self.high.long_step = Some(step);
+    }
+
+    /// Major step size.
+    pub fn long_step(&self) -> Option<T::Step> {
+        self.low.long_step
+    }
+
+    /// Active knob.
+    pub fn active(&self) -> RangeKnob {
+        self.active
+    }
+
+    /// Set the active knob.
+    pub fn set_active(&mut self, active: RangeKnob) -> bool {
+        let old_active = self.active;
+        self.active = active;
+        old_active != self.active
+    }
+
+    /// Switch the active knob.
+    pub fn flip_active(&mut self) {
+        self.active = match self.active {
+            RangeKnob::Low => RangeKnob::High,
+            RangeKnob::High => RangeKnob::Low,
+        };
+    }
+
+    /// Collision behaviour.
+    pub fn collision(&self) -> RangeCollision {
+        self.collision
+    }
+
+    /// Collision behaviour.
+    pub fn set_collision(&mut self, collision: RangeCollision) {
+        self.collision = collision;
+    }
+
+    /// Next value of the active knob by one step.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        self.change_active(|v| v.next())
+    }
+
+    /// Previous value of the active knob by one step.
+    pub fn prev(&mut self) -> bool {
+        self.change_active(|v| v.prev())
+    }
+
+    /// Next value of the active knob by one major step.
+    pub fn next_major(&mut self) -> bool {
+        self.change_active(|v| v.next_major())
+    }
+
+    /// Previous value of the active knob by one major step.
+    pub fn prev_major(&mut self) -> bool {
+        self.change_active(|v| v.prev_major())
+    }
+
+    /// Move the active knob to the lower bound of the range.
+    pub fn set_lower_bound(&mut self) -> bool {
+        self.change_active(|v| v.set_value(v.range.0))
+    }
+
+    /// Move the active knob to the upper bound of the range.
+    pub fn set_upper_bound(&mut self) -> bool {
+        self.change_active(|v| v.set_value(v.range.1))
+    }
+
+    /// Clicked in the range or at the boundary.
+    /// Activates the nearest knob and moves it to the position.
+    pub fn clicked_at(&mut self, x: u16, y: u16) -> bool {
+        let (pos, low, high) = match self.low.direction {
+            Direction::Horizontal => (
+                x,
+                self.low.knob.x + self.low.knob.width / 2,
+                self.high.knob.x + self.high.knob.width / 2,
+            ),
+            Direction::Vertical => (
+                y,
+                self.low.knob.y + self.low.knob.height / 2,
+                self.high.knob.y + self.high.knob.height / 2,
+            ),
+        };
+        self.active = if pos.abs_diff(low) < pos.abs_diff(high) {
+            RangeKnob::Low
+        } else if pos.abs_diff(low) > pos.abs_diff(high) {
+            RangeKnob::High
+        } else if pos < low {
+            RangeKnob::Low
+        } else {
+            RangeKnob::High
+        };
+        self.dragged_at(x, y)
+    }
+
+    /// Dragged in the range or at the boundary.
+    /// Moves the active knob to the position.
+    pub fn dragged_at(&mut self, x: u16, y: u16) -> bool {
+        self.change_active(|v| v.clicked_at(x, y))
+    }
+
+    // Change the active knob and resolve collisions.
+    fn change_active(&mut self, f: impl FnOnce(&mut SliderState<T>) -> bool) -> bool {
+        let old_value = self.value();
+        match self.active {
+            RangeKnob::Low => f(&mut self.low),
+            RangeKnob::High => f(&mut self.high),
+        };
+        if self.low.value > self.high.value {
+            match (self.collision, self.active) {
+                (RangeCollision::Block, RangeKnob::Low) => self.low.value = self.high.value,
+                (RangeCollision::Block, RangeKnob::High) => self.high.value = self.low.value,
+                (RangeCollision::Push, RangeKnob::Low) => self.high.value = self.low.value,
+                (RangeCollision::Push, RangeKnob::High) => self.low.value = self.high.value,
+            }
+        }
+        old_value != self.value()
+    }
+
+    fn value_outcome(&self, changed: bool) -> RangeSliderOutcome<T> {
+        if changed {
+            RangeSliderOutcome::Value(self.active, self.value())
+        } else {
+            RangeSliderOutcome::Unchanged
+        }
+    }
+}
+
+impl<T> HandleEvent<crossterm::event::Event, Regular, RangeSliderOutcome<T>> for RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    fn handle(
+        &mut self,
+        event: &crossterm::event::Event,
+        _qualifier: Regular,
+    ) -> RangeSliderOutcome<T> {
+        if self.disabled {
+            return RangeSliderOutcome::Continue;
+        }
+        let r = if self.is_focused() {
+            match event {
+                ct_event!(key press ' ') => {
+                    self.flip_active();
+                    RangeSliderOutcome::Changed
+                }
+
+                ct_event!(keycode press CONTROL-Left)
+                | ct_event!(keycode press CONTROL-Up)
+                | ct_event!(keycode press Home) => {
+                    let r = self.set_lower_bound();
+                    self.value_outcome(r)
+                }
+
+                ct_event!(keycode press CONTROL-Right)
+                | ct_event!(keycode press CONTROL-Down)
+                | ct_event!(keycode press End) => {
+                    let r = self.set_upper_bound();
+                    self.value_outcome(r)
+                }
+
+                ct_event!(keycode press Up)
+                | ct_event!(keycode press Left)
+                | ct_event!(key press '-') => {
+                    let r = self.prev();
+                    self.value_outcome(r)
+                }
+                ct_event!(keycode press Down)
+                | ct_event!(keycode press Right)
+                | ct_event!(key press '+') => {
+                    let r = self.next();
+                    self.value_outcome(r)
+                }
+
+                ct_event!(keycode press PageUp)
+                | ct_event!(keycode press ALT-Up)
+                | ct_event!(keycode press ALT-Left)
+                | ct_event!(key press ALT-'-') => {
+                    let r = self.prev_major();
+                    self.value_outcome(r)
+                }
+                ct_event!(keycode press PageDown)
+                | ct_event!(keycode press ALT-Down)
+                | ct_event!(keycode press ALT-Right)
+                | ct_event!(key press ALT-'+') => {
+                    let r = self.next_major();
+                    self.value_outcome(r)
+                }
+                _ => RangeSliderOutcome::Continue,
+            }
+        } else {
+            RangeSliderOutcome::Continue
+        };
+
+        if r == RangeSliderOutcome::Continue {
+            HandleEvent::handle(self, event, MouseOnly)
+        } else {
+            r
+        }
+    }
+}
+
+impl<T> HandleEvent<crossterm::event::Event, MouseOnly, RangeSliderOutcome<T>>
+    for RangeSliderState<T>
+where
+    T: RangeOp<Step: Copy + Debug> + MapRange<u16> + Debug + Default + Copy + PartialOrd,
+    u16: MapRange<T>,
+{
+    fn handle(
+        &mut self,
+        event: &crossterm::event::Event,
+        _keymap: MouseOnly,
+    ) -> RangeSliderOutcome<T> {
+        if self.disabled {
+            return RangeSliderOutcome::Continue;
+        }
+        match event {
+            ct_event!(mouse down Left for x,y) => {
+                if self.low.inner.contains(Position::new(*x, *y)) {
+                    let old_active = self.active;
+                    let r = self.clicked_at(*x, *y);
+                    if r {
+                        self.value_outcome(r)
+                    } else if old_active != self.active {
+                        RangeSliderOutcome::Changed
+                    } else {
+                        RangeSliderOutcome::Unchanged
+                    }
+                } else {
+                    RangeSliderOutcome::Continue
+                }
+            }
+            ct_event!(mouse drag Left for x,y) => {
+                if self.low.inner.contains(Position::new(*x, *y)) {
+                    let r = self.dragged_at(*x, *y);
+                    self.value_outcome(r)
+                } else {
+                    RangeSliderOutcome::Continue
+                }
+            }
+            ct_event!(scroll down for x,y) => {
+                if self.low.track.contains(Position::new(*x, *y)) {
+                    let r = self.next();
+                    self.value_outcome(r)
+                } else {
+                    RangeSliderOutcome::Continue
+                }
+            }
+            ct_event!(scroll up for x,y) => {
+                if self.low.track.contains(Position::new(*x, *y)) {
+                    let r = self.prev();
+                    self.value_outcome(r)
+                } else {
+                    RangeSliderOutcome::Continue
+                }
+            }
+            ct_event!(scroll ALT down for x,y) => {
+                if self.low.track.contains(Position::new(*x, *y)) {
+                    let r = self.next_major();
+                    self.value_outcome(r)
+                } else {
+                    RangeSliderOutcome::Continue
+                }
+            }
+            ct_event!(scroll ALT up for x,y) => {
+                if self.low.track.contains(Position::new(*x, *y)) {
+                    let r = self.prev_major();
+                    self.value_outcome(r)
+                } else {
+                    RangeSliderOutcome::Continue
+                }
+            }
+            _ => RangeSliderOutcome::Continue,
+        }
+    }
+}
@@ -3,9 +3,10 @@
 //!
 
 use crate::_private::NonExhaustive;
+use crate::splitter::event::SplitOutcome;
 use crate::util::{fill_buf_area, revert_style};
-use rat_event::util::MouseFlagsN;
-use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Outcome, Regular};
+use rat_event::util::{DragOutcome, DragTracker, MouseFlagsN};
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_reloc::{relocate_area, relocate_areas, relocate_positions, RelocatableState};
 use ratatui::buffer::Buffer;
@@ -196,10 +197,73 @@ pub struct SplitState {
     /// Mouseflags.
     /// __read+write__
     pub mouse: MouseFlagsN,
+    /// Dragging the split.
+    /// __read+write__
+    pub drag: DragTracker,
 
     pub non_exhaustive: NonExhaustive,
 }
 
+pub(crate) mod event {
+    use rat_event::{ConsumedEvent, Outcome};
+
+    /// Result value for event-handling.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum SplitOutcome {
+        /// The given event was not handled at all.
+        Continue,
+        /// The event was handled, no repaint necessary.
+        Unchanged,
+        /// The event was handled, repaint necessary.
+        Changed,
+        /// The nth split has been moved to the given position.
+        Resized(usize, u16),
+        /// Enter/Esc while the splitter has the focus.
+        ///
+        /// The splitter can't give up the focus by itself,
+        /// the application should move the focus elsewhere.
+        Leave,
+    }
+
+    impl ConsumedEvent for SplitOutcome {
+        fn is_consumed(&self) -> bool {
+            *self != SplitOutcome::Continue
+        }
+    }
+
+    impl From<bool> for SplitOutcome {
+        fn from(value: bool) -> Self {
+            if value {
+                SplitOutcome::Changed
+            } else {
+                SplitOutcome::Unchanged
+            }
+        }
+    }
+
+    impl From<Outcome> for SplitOutcome {
+        fn from(value: Outcome) -> Self {
+            match value {
+                Outcome::Continue => SplitOutcome::Continue,
+                Outcome::Unchanged => SplitOutcome::Unchanged,
+                Outcome::Changed => SplitOutcome::Changed,
+            }
+        }
+    }
+
+    impl From<SplitOutcome> for Outcome {
+        fn from(value: SplitOutcome) -> Self {
+            match value {
+                SplitOutcome::Continue => Outcome::Continue,
+                SplitOutcome::Unchanged => Outcome::Unchanged,
+                SplitOutcome::Changed => Outcome::Changed,
+                SplitOutcome::Resized(_, _) => Outcome::Changed,
+                SplitOutcome::Leave => Outcome::Changed,
+            }
+        }
+    }
+}
+
 impl SplitType {
     pub fn is_full(&self) -> bool {
         use SplitType::*;
@@ -894,7 +958,7 @@ fn render_split(split: &Split<'_>, buf: &mut Buffer, state: &mut SplitState) {
             }
         }
 
-        let (style, arrow_style) = if Some(n) == state.mouse.drag.get()
+        let (style, arrow_style) = if Some(n) == state.drag.item()
             || Some(n) == state.focus_marker
             || Some(n) == state.mouse.hover.get()
         {
@@ -970,6 +1034,7 @@ impl Default for SplitState {
             focus: Default::default(),
             focus_marker: Default::default(),
             mouse: Default::default(),
+            drag: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -992,6 +1057,7 @@ impl Clone for SplitState {
             focus: FocusFlag::named(self.focus.name()),
             focus_marker: self.focus_marker,
             mouse: Default::default(),
+            drag: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -1082,7 +1148,7 @@ impl SplitState {
     /// length to 0.
     pub fn move_split_left(&mut self, n: usize, delta: u16) -> bool {
         let split_pos = self.split_pos(n);
-        self.set_split_pos(n, split_pos - delta);
+        self.set_split_pos(n, split_pos.saturating_sub(delta));
 
         split_pos != self.split_pos(n)
     }
@@ -1091,7 +1157,7 @@ impl SplitState {
     /// Does nothing if the change is bigger than the length of the split.
     pub fn move_split_right(&mut self, n: usize, delta: u16) -> bool {
         let split_pos = self.split_pos(n);
-        self.set_split_pos(n, split_pos + delta);
+        self.set_split_pos(n, split_pos.saturating_add(delta));
 
         split_pos != self.split_pos(n)
     }
@@ -1171,6 +1237,78 @@ impl SplitState {
         }
     }
 
+    /// Snapshot of the absolute pane sizes.
+    ///
+    /// Use this with [set_layout](Self::set_layout) to persist
+    /// the layout of the splitter.
+    pub fn layout(&self) -> Vec<u16> {
+        self.area_length.clone()
+    }
+
+    /// Restore a snapshot of the pane sizes.
+    ///
+    /// If the snapshot was taken with a different size of the
+    /// splitter, the sizes are scaled to the current size and keep
+    /// their ratios. Panes with a size of 0 stay hidden, all other
+    /// panes get at least 1 cell if possible.
+    ///
+    /// If the splitter has not been rendered yet, this is done
+    /// with the next render.
+    ///
+    /// If the number of panes doesn't match the constraints of
+    /// the splitter, the next render will ignore the snapshot.
+    pub fn set_layout(&mut self, layout: &[u16]) {
+        let total = if self.direction == Direction::Horizontal {
+            self.inner.width
+        } else {
+            self.inner.height
+        };
+        let sum = layout.iter().map(|v| *v as u32).sum::<u32>();
+
+        if total == 0 || sum == 0 || sum == total as u32 {
+            self.set_area_lengths(layout.to_vec());
+            return;
+        }
+
+        // scale and distribute the rounding error by the largest remainder.
+        let mut lengths = Vec::with_capacity(layout.len());
+        let mut remainders = Vec::with_capacity(layout.len());
+        for (i, v) in layout.iter().enumerate() {
+            let scaled = *v as u32 * total as u32;
+            lengths.push((scaled / sum) as u16);
+            remainders.push((scaled % sum, i));
+        }
+        remainders.sort_by_key(|v| std::cmp::Reverse(v.0));
+        let mut missing = total - lengths.iter().sum::<u16>();
+        for (_, i) in remainders {
+            if missing == 0 {
+                break;
+            }
+            if layout[i] > 0 {
+                lengths[i] += 1;
+                missing -= 1;
+            }
+        }
+
+        // keep visible panes visible.
+        for i in 0..lengths.len() {
+            if layout[i] > 0 && lengths[i] == 0 {
+                let (max_idx, max_len) = lengths
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .max_by_key(|(_, v)| *v)
+                    .expect("lengths");
+                if max_len > 1 {
+                    lengths[max_idx] -= 1;
+                    lengths[i] = 1;
+                }
+            }
+        }
+
+        self.set_area_lengths(lengths);
+    }
+
     /// Get the value of the hidden lengths.
     pub fn hidden_lengths(&self) -> &[u16] {
         &self.hidden_length
@@ -1510,57 +1648,137 @@ impl SplitState {
     }
 }
 
-impl HandleEvent<crossterm::event::Event, Regular, Outcome> for SplitState {
-    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> Outcome {
+impl SplitState {
+    /// Move the split relative to where it was grabbed.
+    fn drag_split_pos(&mut self, n: usize) -> SplitOutcome {
+        let (dx, dy) = self.drag.delta();
+        let (delta, max_pos) = if self.direction == Direction::Horizontal {
+            (dx, self.inner.width)
+        } else {
+            (dy, self.inner.height)
+        };
+        let pos = (self.drag.start_value.get() as i32 + delta).clamp(0, max_pos as i32) as u16;
+
+        let split_pos = self.split_pos(n);
+        self.set_split_pos(n, pos);
+        self.resized(n, split_pos != self.split_pos(n))
+    }
+
+    fn resized(&self, n: usize, changed: bool) -> SplitOutcome {
+        if changed {
+            SplitOutcome::Resized(n, self.split_pos(n))
+        } else {
+            SplitOutcome::Unchanged
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, SplitOutcome> for SplitState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> SplitOutcome {
         flow!(if self.is_focused() {
             if let Some(n) = self.focus_marker {
                 match event {
-                    ct_event!(keycode press Left) => self.move_split_left(n, 1).into(),
-                    ct_event!(keycode press Right) => self.move_split_right(n, 1).into(),
-                    ct_event!(keycode press Up) => self.move_split_up(n, 1).into(),
-                    ct_event!(keycode press Down) => self.move_split_down(n, 1).into(),
+                    ct_event!(keycode press Left) => {
+                        let r = self.move_split_left(n, 1);
+                        self.resized(n, r)
+                    }
+                    ct_event!(keycode press Right) => {
+                        let r = self.move_split_right(n, 1);
+                        self.resized(n, r)
+                    }
+                    ct_event!(keycode press Up) => {
+                        let r = self.move_split_up(n, 1);
+                        self.resized(n, r)
+                    }
+                    ct_event!(keycode press Down) => {
+                        let r = self.move_split_down(n, 1);
+                        self.resized(n, r)
+                    }
+                    ct_event!(keycode press SHIFT-Left) => {
+                        let r = self.move_split_left(n, 5);
+                        self.resized(n, r)
+                    }
+                    ct_event!(keycode press SHIFT-Right) => {
+                        let r = self.move_split_right(n, 5);
+                        self.resized(n, r)
+                    }
+                    ct_event!(keycode press SHIFT-Up) => {
+                        let r = self.move_split_up(n, 5);
+                        self.resized(n, r)
+                    }
+                    ct_event!(keycode press SHIFT-Down) => {
+                        let r = self.move_split_down(n, 5);
+                        self.resized(n, r)
+                    }
 
                     ct_event!(keycode press ALT-Left) => self.select_prev_split().into(),
                     ct_event!(keycode press ALT-Right) => self.select_next_split().into(),
                     ct_event!(keycode press ALT-Up) => self.select_prev_split().into(),
                     ct_event!(keycode press ALT-Down) => self.select_next_split().into(),
-                    _ => Outcome::Continue,
+
+                    ct_event!(keycode press Enter) | ct_event!(keycode press Esc) => {
+                        SplitOutcome::Leave
+                    }
+                    _ => SplitOutcome::Continue,
                 }
             } else {
-                Outcome::Continue
+                SplitOutcome::Continue
             }
         } else {
-            Outcome::Continue
+            SplitOutcome::Continue
         });
 
         self.handle(event, MouseOnly)
     }
 }
 
-impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for SplitState {
-    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> Outcome {
+impl HandleEvent<crossterm::event::Event, MouseOnly, SplitOutcome> for SplitState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> SplitOutcome {
         match event {
             ct_event!(mouse any for m) if self.mouse.hover(&self.splitline_areas, m) => {
-                Outcome::Changed
+                SplitOutcome::Changed
             }
-            ct_event!(mouse any for m) => {
-                let was_drag = self.mouse.drag.get();
-                if self.mouse.drag(&self.splitline_areas, m) {
-                    if let Some(n) = self.mouse.drag.get() {
-                        self.set_screen_split_pos(n, self.mouse.pos_of(m)).into()
-                    } else {
-                        Outcome::Continue
+            _ => {
+                let n = self.drag.item();
+                match self.drag.handle_n(&self.splitline_areas, event) {
+                    DragOutcome::Started(_) => {
+                        let Some(n) = n else {
+                            return SplitOutcome::Continue;
+                        };
+                        self.drag.start_value.set(self.split_pos(n) as usize);
+                        self.drag_split_pos(n)
+                    }
+                    DragOutcome::Moved(_, _) => {
+                        let Some(n) = n else {
+                            return SplitOutcome::Continue;
+                        };
+                        self.drag_split_pos(n)
+                    }
+                    DragOutcome::Canceled => {
+                        let Some(n) = n else {
+                            return SplitOutcome::Continue;
+                        };
+                        let split_pos = self.split_pos(n);
+                        self.set_split_pos(n, self.drag.start_value.get() as u16);
+                        if split_pos != self.split_pos(n) {
+                            SplitOutcome::Resized(n, self.split_pos(n))
+                        } else {
+                            // repaint after drag is finished. resets the displayed style.
+                            SplitOutcome::Changed
+                        }
                     }
-                } else {
                     // repaint after drag is finished. resets the displayed style.
-                    if was_drag.is_some() {
-                        Outcome::Changed
-                    } else {
-                        Outcome::Continue
+                    DragOutcome::Ended(_) | DragOutcome::Clicked(_) => SplitOutcome::Changed,
+                    DragOutcome::Continue => {
+                        // repaint when the press changes the displayed style.
+                        if n != self.drag.item() {
+                            SplitOutcome::Changed
+                        } else {
+                            SplitOutcome::Continue
+                        }
                     }
                 }
             }
-            _ => Outcome::Continue,
         }
     }
 }
@@ -1,6 +1,9 @@
 //!
 //! Statusbar with multiple sections.
 //!
+//! If the area is too narrow for all sections, the sections
+//! with the lowest priority are collapsed first.
+//!
 
 use crate::_private::NonExhaustive;
 use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
@@ -12,12 +15,17 @@ use ratatui::text::Span;
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{StatefulWidget, Widget};
 use std::fmt::Debug;
+use unicode_display_width::width as unicode_width;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Statusbar with multiple sections.
 #[derive(Debug, Default, Clone)]
 pub struct StatusLine {
     style: Vec<Style>,
     widths: Vec<Constraint>,
+    priorities: Vec<u8>,
+    min_widths: Vec<u16>,
+    ellipsis: bool,
 }
 
 /// State & event handling.
@@ -29,6 +37,9 @@ pub struct StatusLineState {
     /// Areas for each section.
     /// __readonly__. renewed for each render.
     pub areas: Vec<Rect>,
+    /// Sections that have been hidden or truncated for lack of space.
+    /// __readonly__. renewed for each render.
+    pub collapsed: Vec<usize>,
 
     /// Statustext for each section.
     /// __read+write__
@@ -43,6 +54,9 @@ impl StatusLine {
         Self {
             style: Default::default(),
             widths: Default::default(),
+            priorities: Default::default(),
+            min_widths: Default::default(),
+            ellipsis: Default::default(),
         }
     }
 
@@ -64,6 +78,84 @@ impl StatusLine {
         self.style = style.into_iter().map(|v| v.into()).collect();
         self
     }
+
+    /// Priority for each section.
+    ///
+    /// If there is not enough space for all sections, the sections
+    /// with the lowest priority are collapsed first. With equal
+    /// priority the sections at the start collapse before the
+    /// ones at the end.
+    ///
+    /// Default is 0 for every section.
+    pub fn priorities(mut self, priorities: impl IntoIterator<Item = u8>) -> Self {
+        self.priorities = priorities.into_iter().collect();
+        self
+    }
+
+    /// Minimum width for each section.
+    ///
+    /// Defaults to the value of a `Length` or `Min` constraint, 0 otherwise.
+    pub fn min_widths(mut self, min_widths: impl IntoIterator<Item = u16>) -> Self {
+        self.min_widths = min_widths.into_iter().collect();
+        self
+    }
+
+    /// Truncate text with an ellipsis if it doesn't fit.
+    ///
+    /// When sections need to be collapsed, the last section collapsed
+    /// keeps the remaining space and is shown truncated instead of
+    /// being hidden.
+    pub fn ellipsis(mut self, ellipsis: bool) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    fn min_width(&self, idx: usize) -> u16 {
+        if let Some(min_width) = self.min_widths.get(idx) {
+            *min_width
+        } else {
+            match self.widths[idx] {
+                Constraint::Min(v) => v,
+                Constraint::Length(v) => v,
+                _ => 0,
+            }
+        }
+    }
+
+    /// Constraints after collapsing sections.
+    fn collapse(&self, width: u16, collapsed: &mut Vec<usize>) -> Vec<Constraint> {
+        let mut widths = self.widths.clone();
+
+        let mut order = (0..widths.len()).collect::<Vec<_>>();
+        order.sort_by_key(|v| self.priorities.get(*v).copied().unwrap_or_default());
+
+        let mut total = (0..widths.len())
+            .map(|v| self.min_width(v))
+            .fold(0u16, |a, v| a.saturating_add(v));
+
+        let mut last = None;
+        for idx in order {
+            if total <= width {
+                break;
+            }
+            total = total.saturating_sub(self.min_width(idx));
+            widths[idx] = Constraint::Length(0);
+            collapsed.push(idx);
+            last = Some(idx);
+        }
+
+        if self.ellipsis {
+            if let Some(last) = last {
+                let rest = width - total;
+                if rest >= 2 {
+                    widths[last] = Constraint::Length(rest);
+                }
+            }
+        }
+
+        collapsed.sort();
+        widths
+    }
 }
 
 impl Default for StatusLineState {
@@ -71,6 +163,7 @@ impl Default for StatusLineState {
         Self {
             area: Default::default(),
             areas: Default::default(),
+            collapsed: Default::default(),
             status: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -122,14 +215,41 @@ impl StatefulWidget for StatusLine {
 
 fn render_ref(widget: &StatusLine, area: Rect, buf: &mut Buffer, state: &mut StatusLineState) {
     state.area = area;
+    state.collapsed.clear();
 
-    let layout = Layout::horizontal(widget.widths.iter()).split(state.area);
+    let widths = widget.collapse(area.width, &mut state.collapsed);
+    let layout = Layout::horizontal(widths).split(state.area);
+    state.areas = layout.to_vec();
 
     for (i, rect) in layout.iter().enumerate() {
+        if rect.is_empty() {
+            continue;
+        }
+
         let style = widget.style.get(i).copied().unwrap_or_default();
         let txt = state.status.get(i).map(|v| v.as_str()).unwrap_or("");
 
         buf.set_style(*rect, style);
-        Span::from(txt).render(*rect, buf);
+        if widget.ellipsis && unicode_width(txt) > rect.width as u64 {
+            Span::from(truncate(txt, rect.width)).render(*rect, buf);
+        } else {
+            Span::from(txt).render(*rect, buf);
+        }
+    }
+}
+
+/// Truncate to the given width and append an ellipsis.
+fn truncate(txt: &str, width: u16) -> String {
+    let mut res = String::new();
+    let mut res_width = 0;
+    for g in txt.graphemes(true) {
+        let g_width = unicode_width(g);
+        if res_width + g_width + 1 > width as u64 {
+            break;
+        }
+        res.push_str(g);
+        res_width += g_width;
     }
+    res.push('…');
+    res
 }
@@ -68,6 +68,24 @@ pub enum TabType {
 /// Use [TabbedState::selected] and [TabbedState::widget_area] to render
 /// the actual content of the tab.
 ///
+/// Only the widgets of the active tab should take part in
+/// the focus. Use [TabbedState::is_active] for this:
+///
+/// ```rust ignore
+/// fn build(&self, builder: &mut FocusBuilder) {
+///     builder.widget(&self.tabbed);
+///     builder.widget_if(self.tabbed.is_active(0), &self.tab0);
+///     builder.widget_if(self.tabbed.is_active(1), &self.tab1);
+/// }
+/// ```
+///
+/// and rebuild the focus after the selection changed.
+///
+/// To check a change before it happens, e.g. to flush pending edits
+/// of the old tab, set [TabbedState::confirm_select]. Then the
+/// event-handler only returns [TabbedOutcome::WillSelect] and the
+/// application does the [TabbedState::select] itself, or not.
+///
 #[derive(Debug, Default)]
 pub struct Tabbed<'a> {
     tab_type: TabType,
@@ -125,6 +143,10 @@ pub struct TabbedState {
     /// Otherwise, set to 0 on render.
     /// __read+write___
     pub selected: Option<usize>,
+    /// Don't change the selection in the event-handler,
+    /// return [TabbedOutcome::WillSelect] instead.
+    /// __read+write__
+    pub confirm_select: bool,
 
     /// Focus
     /// __read+write__
@@ -152,6 +174,12 @@ pub(crate) mod event {
         Changed,
         /// Tab selection changed.
         Select(usize),
+        /// The tab will be selected, if the application does so.
+        /// Only used with [TabbedState::confirm_select](crate::tabbed::TabbedState::confirm_select).
+        ///
+        /// Call [TabbedState::select](crate::tabbed::TabbedState::select)
+        /// to accept, or ignore it to veto.
+        WillSelect(usize),
         /// Selected tab should be closed.
         Close(usize),
     }
@@ -190,6 +218,7 @@ pub(crate) mod event {
                 TabbedOutcome::Unchanged => Outcome::Unchanged,
                 TabbedOutcome::Changed => Outcome::Changed,
                 TabbedOutcome::Select(_) => Outcome::Changed,
+                TabbedOutcome::WillSelect(_) => Outcome::Changed,
                 TabbedOutcome::Close(_) => Outcome::Changed,
             }
         }
@@ -345,6 +374,7 @@ impl Clone for TabbedState {
             tab_title_areas: self.tab_title_areas.clone(),
             tab_title_close_areas: self.tab_title_close_areas.clone(),
             selected: self.selected,
+            confirm_select: self.confirm_select,
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
         }
@@ -401,6 +431,14 @@ impl TabbedState {
         self.selected = selected;
     }
 
+    /// Is this the selected tab.
+    ///
+    /// Use with [FocusBuilder::widget_if] to add only the
+    /// widgets of the visible tab.
+    pub fn is_active(&self, n: usize) -> bool {
+        self.selected == Some(n)
+    }
+
     /// Selects the next tab. Stops at the end.
     pub fn next_tab(&mut self) -> bool {
         let old_selected = self.selected;
@@ -427,6 +465,44 @@ impl TabbedState {
 
         old_selected != self.selected
     }
+
+    /// Next tab for the event-handler. Respects confirm_select.
+    fn next_tab_outcome(&mut self) -> TabbedOutcome {
+        if self.confirm_select {
+            match self.selected {
+                Some(n) if n + 1 < self.tab_title_areas.len() => TabbedOutcome::WillSelect(n + 1),
+                _ => TabbedOutcome::Unchanged,
+            }
+        } else {
+            self.next_tab().into()
+        }
+    }
+
+    /// Previous tab for the event-handler. Respects confirm_select.
+    fn prev_tab_outcome(&mut self) -> TabbedOutcome {
+        if self.confirm_select {
+            match self.selected {
+                Some(n) if n > 0 => TabbedOutcome::WillSelect(n - 1),
+                _ => TabbedOutcome::Unchanged,
+            }
+        } else {
+            self.prev_tab().into()
+        }
+    }
+
+    /// Select a tab for the event-handler. Respects confirm_select.
+    fn select_outcome(&mut self, n: usize) -> TabbedOutcome {
+        if self.confirm_select {
+            if self.selected == Some(n) {
+                TabbedOutcome::Unchanged
+            } else {
+                TabbedOutcome::WillSelect(n)
+            }
+        } else {
+            self.select(Some(n));
+            TabbedOutcome::Select(n)
+        }
+    }
 }
 
 /// Handle the regular events for Tabbed.
@@ -434,8 +510,8 @@ impl HandleEvent<crossterm::event::Event, Regular, TabbedOutcome> for TabbedStat
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> TabbedOutcome {
         if self.is_focused() {
             flow!(match event {
-                ct_event!(keycode press Right) => self.next_tab().into(),
-                ct_event!(keycode press Left) => self.prev_tab().into(),
+                ct_event!(keycode press Right) => self.next_tab_outcome(),
+                ct_event!(keycode press Left) => self.prev_tab_outcome(),
                 _ => TabbedOutcome::Continue,
             });
         }
@@ -452,8 +528,7 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, TabbedOutcome> for TabbedSt
             }
             ct_event!(mouse any for e) if self.mouse.drag(&[self.tab_title_area], e) => {
                 if let Some(n) = self.mouse.item_at(&self.tab_title_areas, e.column, e.row) {
-                    self.select(Some(n));
-                    TabbedOutcome::Select(n)
+                    self.select_outcome(n)
                 } else {
                     TabbedOutcome::Unchanged
                 }
@@ -464,8 +539,7 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, TabbedOutcome> for TabbedSt
                 if let Some(sel) = self.mouse.item_at(&self.tab_title_close_areas, *x, *y) {
                     TabbedOutcome::Close(sel)
                 } else if let Some(sel) = self.mouse.item_at(&self.tab_title_areas, *x, *y) {
-                    self.select(Some(sel));
-                    TabbedOutcome::Select(sel)
+                    self.select_outcome(sel)
                 } else {
                     TabbedOutcome::Continue
                 }
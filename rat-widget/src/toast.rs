@@ -0,0 +1,496 @@
+//!
+//! Transient notifications stacked in a corner.
+//!
+//! Toasts are non-modal and don't take part in the focus.
+//! They are rendered above everything else, so render them
+//! last.
+//!
+//! ```rust ignore
+//! // somewhere
+//! state.toasts.push(Toast::new("saved", Severity::Info));
+//!
+//! // render
+//! Toasts::new()
+//!     .styles(theme.toast_style())
+//!     .render(frame_area, buf, &mut state.toasts);
+//!
+//! // from a repeat timer or the Rendered event
+//! flow!(state.toasts.expire(Instant::now()));
+//! // events
+//! flow!(state.toasts.handle(event, Regular));
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::util::{block_size, reset_buf_area};
+use rat_event::util::MouseFlagsN;
+use rat_event::{ct_event, HandleEvent, MouseOnly, Outcome, Regular};
+use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::BlockExt;
+use ratatui::style::Style;
+use ratatui::text::{Line, Text};
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::time::{Duration, Instant};
+
+/// Severity of a toast. Determines the style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+/// Corner where the toasts are stacked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToastCorner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// One notification.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Unique id, assigned by [ToastsState::push].
+    pub id: usize,
+    /// Text. May contain multiple lines.
+    pub text: String,
+    /// Severity
+    pub severity: Severity,
+    /// Creation time.
+    pub created_at: Instant,
+    /// Time to live.
+    pub duration: Duration,
+}
+
+/// Renders the newest toasts stacked in a corner.
+#[derive(Debug, Clone)]
+pub struct Toasts<'a> {
+    style: Style,
+    info_style: Option<Style>,
+    warning_style: Option<Style>,
+    error_style: Option<Style>,
+    close_style: Option<Style>,
+    block: Option<Block<'a>>,
+    corner: ToastCorner,
+    width: u16,
+    max_visible: usize,
+}
+
+/// Combined style.
+#[derive(Debug, Clone)]
+pub struct ToastStyle {
+    pub style: Style,
+    pub info: Option<Style>,
+    pub warning: Option<Style>,
+    pub error: Option<Style>,
+    pub close: Option<Style>,
+    pub block: Option<Block<'static>>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State & event handling.
+#[derive(Debug, Clone)]
+pub struct ToastsState {
+    /// Full area.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Areas of the visible toasts, newest first.
+    /// __readonly__. renewed for each render.
+    pub toast_areas: Vec<Rect>,
+    /// Areas of the close buttons, newest first.
+    /// __readonly__. renewed for each render.
+    pub close_areas: Vec<Rect>,
+
+    /// Queue of toasts, oldest first.
+    /// __read+write__
+    pub toasts: Vec<Toast>,
+    /// Number of visible toasts.
+    /// __read+write__ overwritten by the widget while rendering.
+    pub max_visible: usize,
+
+    /// Id counter.
+    next_id: usize,
+
+    /// Mouse flags.
+    /// __read+write__
+    pub mouse: MouseFlagsN,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Toast {
+    /// New toast with a duration of 5 seconds.
+    pub fn new(text: impl Into<String>, severity: Severity) -> Self {
+        Self {
+            id: 0,
+            text: text.into(),
+            severity,
+            created_at: Instant::now(),
+            duration: Duration::from_secs(5),
+        }
+    }
+
+    /// Time to live.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Creation time.
+    pub fn created_at(mut self, created_at: Instant) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Is the toast expired at the given time.
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.created_at) >= self.duration
+    }
+}
+
+impl Default for ToastStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            info: None,
+            warning: None,
+            error: None,
+            close: None,
+            block: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Default for Toasts<'_> {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            info_style: None,
+            warning_style: None,
+            error_style: None,
+            close_style: None,
+            block: None,
+            corner: Default::default(),
+            width: 40,
+            max_visible: 3,
+        }
+    }
+}
+
+impl<'a> Toasts<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set all styles.
+    pub fn styles(mut self, styles: ToastStyle) -> Self {
+        self.style = styles.style;
+        if styles.info.is_some() {
+            self.info_style = styles.info;
+        }
+        if styles.warning.is_some() {
+            self.warning_style = styles.warning;
+        }
+        if styles.error.is_some() {
+            self.error_style = styles.error;
+        }
+        if styles.close.is_some() {
+            self.close_style = styles.close;
+        }
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style for [Severity::Info].
+    pub fn info_style(mut self, style: Style) -> Self {
+        self.info_style = Some(style);
+        self
+    }
+
+    /// Style for [Severity::Warning].
+    pub fn warning_style(mut self, style: Style) -> Self {
+        self.warning_style = Some(style);
+        self
+    }
+
+    /// Style for [Severity::Error].
+    pub fn error_style(mut self, style: Style) -> Self {
+        self.error_style = Some(style);
+        self
+    }
+
+    /// Style for the close button.
+    pub fn close_style(mut self, style: Style) -> Self {
+        self.close_style = Some(style);
+        self
+    }
+
+    /// Block around each toast.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Corner for the stack.
+    pub fn corner(mut self, corner: ToastCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Maximum width of a toast.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Number of visible toasts.
+    pub fn max_visible(mut self, n: usize) -> Self {
+        self.max_visible = n;
+        self
+    }
+
+    fn severity_style(&self, severity: Severity) -> Style {
+        let style = match severity {
+            Severity::Info => self.info_style,
+            Severity::Warning => self.warning_style,
+            Severity::Error => self.error_style,
+        };
+        self.style.patch(style.unwrap_or_default())
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl StatefulWidgetRef for Toasts<'_> {
+    type State = ToastsState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for Toasts<'_> {
+    type State = ToastsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self, area, buf, state);
+    }
+}
+
+fn render_ref(widget: &Toasts<'_>, area: Rect, buf: &mut Buffer, state: &mut ToastsState) {
+    state.area = area;
+    state.max_visible = widget.max_visible;
+    state.toast_areas.clear();
+    state.close_areas.clear();
+
+    let frame_size = block_size(&widget.block);
+
+    let mut used = 0u16;
+    for toast in state.toasts.iter().rev().take(widget.max_visible) {
+        let text = Text::from(toast.text.as_str());
+        // 2 for the close button.
+        let width = (text.width() as u16 + 2 + frame_size.width)
+            .min(widget.width)
+            .min(area.width);
+        let height = text.height() as u16 + frame_size.height;
+        if used + height > area.height {
+            break;
+        }
+
+        let x = match widget.corner {
+            ToastCorner::TopLeft | ToastCorner::BottomLeft => area.x,
+            ToastCorner::TopRight | ToastCorner::BottomRight => area.right() - width,
+        };
+        let y = match widget.corner {
+            ToastCorner::TopLeft | ToastCorner::TopRight => area.y + used,
+            ToastCorner::BottomLeft | ToastCorner::BottomRight => area.bottom() - used - height,
+        };
+        used += height;
+
+        let toast_area = Rect::new(x, y, width, height);
+        let inner = widget.block.inner_if_some(toast_area);
+        let close_area =
+            Rect::new(inner.right().saturating_sub(1), inner.y, 1, 1).intersection(inner);
+        let text_area = Rect::new(
+            inner.x,
+            inner.y,
+            inner.width.saturating_sub(2),
+            inner.height,
+        );
+
+        let style = widget.severity_style(toast.severity);
+        reset_buf_area(toast_area, buf);
+        buf.set_style(toast_area, style);
+        if let Some(block) = &widget.block {
+            block.clone().style(style).render(toast_area, buf);
+        }
+        text.render(text_area, buf);
+        Line::from("×")
+            .style(style.patch(widget.close_style.unwrap_or_default()))
+            .render(close_area, buf);
+
+        state.toast_areas.push(toast_area);
+        state.close_areas.push(close_area);
+    }
+}
+
+impl Default for ToastsState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            toast_areas: Default::default(),
+            close_areas: Default::default(),
+            toasts: Default::default(),
+            max_visible: 3,
+            next_id: 0,
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RelocatableState for ToastsState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        relocate_areas(self.toast_areas.as_mut(), shift, clip);
+        relocate_areas(self.close_areas.as_mut(), shift, clip);
+    }
+}
+
+impl ToastsState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a toast. Returns the assigned id.
+    pub fn push(&mut self, mut toast: Toast) -> usize {
+        toast.id = self.next_id;
+        self.next_id += 1;
+        self.toasts.push(toast);
+        self.next_id - 1
+    }
+
+    /// Any toasts.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Number of toasts, including the ones currently not visible.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Ids of the visible toasts, newest first.
+    pub fn visible(&self) -> Vec<usize> {
+        self.toasts
+            .iter()
+            .rev()
+            .take(self.max_visible)
+            .map(|v| v.id)
+            .collect()
+    }
+
+    /// Remove the toast with the given id.
+    ///
+    /// Returns Changed if the visible toasts changed.
+    pub fn dismiss(&mut self, id: usize) -> Outcome {
+        let old_visible = self.visible();
+        self.toasts.retain(|v| v.id != id);
+        self.changed(old_visible)
+    }
+
+    /// Remove all toasts.
+    pub fn clear(&mut self) -> Outcome {
+        let old_visible = self.visible();
+        self.toasts.clear();
+        self.changed(old_visible)
+    }
+
+    /// Remove all toasts that are expired at the given time.
+    ///
+    /// Returns Changed if the visible toasts changed, Continue
+    /// otherwise. Call this from a repeating timer or the
+    /// Rendered event.
+    pub fn expire(&mut self, now: Instant) -> Outcome {
+        let old_visible = self.visible();
+        self.toasts.retain(|v| !v.is_expired(now));
+        self.changed(old_visible)
+    }
+
+    fn changed(&self, old_visible: Vec<usize>) -> Outcome {
+        if self.visible() != old_visible {
+            Outcome::Changed
+        } else {
+            Outcome::Continue
+        }
+    }
+
+    /// Id of the visible toast at the given position.
+    pub fn toast_at(&self, pos: (u16, u16)) -> Option<usize> {
+        let n = self.mouse.item_at(&self.toast_areas, pos.0, pos.1)?;
+        self.visible().get(n).copied()
+    }
+}
+
+/// Handles the close button and Esc for the hovered toast.
+impl HandleEvent<crossterm::event::Event, Regular, Outcome> for ToastsState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> Outcome {
+        match event {
+            ct_event!(keycode press Esc) => {
+                if let Some(n) = self.mouse.hover.get() {
+                    if let Some(id) = self.visible().get(n).copied() {
+                        self.mouse.hover.set(None);
+                        return self.dismiss(id);
+                    }
+                }
+                Outcome::Continue
+            }
+            _ => self.handle(event, MouseOnly),
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for ToastsState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> Outcome {
+        match event {
+            ct_event!(mouse any for m) if self.mouse.hover(&self.toast_areas, m) => {
+                // no visual change
+                Outcome::Continue
+            }
+            ct_event!(mouse down Left for x, y) => {
+                if let Some(n) = self.mouse.item_at(&self.close_areas, *x, *y) {
+                    if let Some(id) = self.visible().get(n).copied() {
+                        return match self.dismiss(id) {
+                            Outcome::Continue => Outcome::Unchanged,
+                            r => r,
+                        };
+                    }
+                }
+                if self.mouse.item_at(&self.toast_areas, *x, *y).is_some() {
+                    // toasts are above everything else.
+                    Outcome::Unchanged
+                } else {
+                    Outcome::Continue
+                }
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
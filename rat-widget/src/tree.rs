@@ -0,0 +1,939 @@
+//!
+//! Tree widget.
+//!
+//! The nodes are provided via the [TreeData] trait. Each node
+//! is identified by a stable id supplied by the application.
+//! The expansion state and the selection are kept in [TreeState]
+//! and are keyed by that id.
+//!
+//! Children can be loaded lazily. If [TreeData::children] returns
+//! None for a node, expanding it emits [TreeOutcome::Load] and
+//! the application can fetch the children and provide them
+//! with the next render. [TreeStore] is a simple in-memory
+//! implementation of TreeData that supports this with
+//! [TreeStore::set_children].
+//!
+//! ```rust ignore
+//! match state.tree.handle(event, Regular) {
+//!     TreeOutcome::Load(id) => {
+//!         state.files.set_children(Some(id.clone()), read_dir(&id)?);
+//!         Control::Changed
+//!     }
+//!     TreeOutcome::Activate(id) => open(&id),
+//!     r => r.into(),
+//! }
+//! ```
+//!
+
+use crate::_private::NonExhaustive;
+use crate::event::util::MouseFlags;
+use crate::tree::event::TreeOutcome;
+use crate::util::{fallback_select_style, revert_style};
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Regular};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Provides the nodes for the tree.
+pub trait TreeData<'a> {
+    /// Stable id of a node.
+    type Id: Clone + Eq + Hash + Debug;
+
+    /// Children of the given node. `None` is the root.
+    ///
+    /// Returns None if the children are not known yet.
+    /// Expanding such a node emits [TreeOutcome::Load].
+    fn children(&self, parent: Option<&Self::Id>) -> Option<impl Iterator<Item = Self::Id>>;
+
+    /// This node can't be expanded.
+    fn is_leaf(&self, id: &Self::Id) -> bool;
+
+    /// Render the label of the node.
+    fn render_node(&self, id: &Self::Id, area: Rect, buf: &mut Buffer);
+}
+
+/// Tree widget.
+#[derive(Debug, Clone)]
+pub struct Tree<'a, Data> {
+    data: Data,
+
+    block: Option<Block<'a>>,
+    scroll: Option<Scroll<'a>>,
+
+    style: Style,
+    select_style: Option<Style>,
+    focus_style: Option<Style>,
+
+    indent: u16,
+    expanded_str: &'a str,
+    collapsed_str: &'a str,
+    leaf_str: &'a str,
+
+    _phantom: PhantomData<&'a ()>,
+}
+
+/// Collected styles.
+#[derive(Debug, Clone)]
+pub struct TreeStyle {
+    /// Style
+    pub style: Style,
+    /// Style for selection
+    pub select: Option<Style>,
+    /// Style for selection when focused.
+    pub focus: Option<Style>,
+
+    pub block: Option<Block<'static>>,
+    pub scroll: Option<ScrollStyle>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// One visible row of the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeRow<Id> {
+    /// Node id.
+    pub id: Id,
+    /// Parent node id.
+    pub parent: Option<Id>,
+    /// Nesting depth. 0 for the root level.
+    pub depth: usize,
+    /// Node is a leaf.
+    pub leaf: bool,
+    /// Children are known.
+    pub loaded: bool,
+    /// Node is expanded.
+    pub expanded: bool,
+}
+
+/// State & event handling.
+#[derive(Debug)]
+pub struct TreeState<Id> {
+    /// Total area
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Area inside the block.
+    /// __readonly__. renewed for each render.
+    pub inner: Rect,
+    /// Areas for the rendered rows.
+    /// __readonly__. renewed for each render.
+    pub row_areas: Vec<Rect>,
+    /// Areas for the expander glyph of the rendered rows.
+    /// __readonly__. renewed for each render.
+    pub expander_areas: Vec<Rect>,
+
+    /// Visible rows of the tree, depth-first.
+    /// __readonly__. renewed for each render.
+    pub rows: Vec<TreeRow<Id>>,
+    /// Expanded nodes.
+    /// __read+write__
+    pub expanded: HashSet<Id>,
+    /// Selected node.
+    /// __read+write__
+    pub selected: Option<Id>,
+
+    /// Offset etc.
+    /// __read+write__
+    pub scroll: ScrollState,
+
+    /// Focus
+    /// __read+write__
+    pub focus: FocusFlag,
+    /// Helper for mouse events.
+    /// __used for mouse interaction__
+    pub mouse: MouseFlags,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+pub(crate) mod event {
+    use rat_event::{ConsumedEvent, Outcome};
+
+    /// Result value for event-handling.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum TreeOutcome<Id> {
+        /// The given event was not handled at all.
+        Continue,
+        /// The event was handled, no repaint necessary.
+        Unchanged,
+        /// The event was handled, repaint necessary.
+        Changed,
+        /// The selection changed.
+        Select(Id),
+        /// The node has been expanded.
+        Expand(Id),
+        /// The node has been collapsed.
+        Collapse(Id),
+        /// The node has been expanded, but the children are not
+        /// known yet. Load them now.
+        Load(Id),
+        /// Enter or double-click on the node.
+        Activate(Id),
+    }
+
+    impl<Id> ConsumedEvent for TreeOutcome<Id> {
+        fn is_consumed(&self) -> bool {
+            !matches!(self, TreeOutcome::Continue)
+        }
+    }
+
+    impl<Id> From<bool> for TreeOutcome<Id> {
+        fn from(value: bool) -> Self {
+            if value {
+                TreeOutcome::Changed
+            } else {
+                TreeOutcome::Unchanged
+            }
+        }
+    }
+
+    impl<Id> From<Outcome> for TreeOutcome<Id> {
+        fn from(value: Outcome) -> Self {
+            match value {
+                Outcome::Continue => TreeOutcome::Continue,
+                Outcome::Unchanged => TreeOutcome::Unchanged,
+                Outcome::Changed => TreeOutcome::Changed,
+            }
+        }
+    }
+
+    impl<Id> From<TreeOutcome<Id>> for Outcome {
+        fn from(value: TreeOutcome<Id>) -> Self {
+            match value {
+                TreeOutcome::Continue => Outcome::Continue,
+                TreeOutcome::Unchanged => Outcome::Unchanged,
+                TreeOutcome::Changed => Outcome::Changed,
+                TreeOutcome::Select(_) => Outcome::Changed,
+                TreeOutcome::Expand(_) => Outcome::Changed,
+                TreeOutcome::Collapse(_) => Outcome::Changed,
+                TreeOutcome::Load(_) => Outcome::Changed,
+                TreeOutcome::Activate(_) => Outcome::Changed,
+            }
+        }
+    }
+}
+
+impl Default for TreeStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            select: None,
+            focus: None,
+            block: None,
+            scroll: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<'a, Data> Tree<'a, Data>
+where
+    Data: TreeData<'a>,
+{
+    /// New tree.
+    pub fn new(data: Data) -> Self {
+        Self {
+            data,
+            block: None,
+            scroll: None,
+            style: Default::default(),
+            select_style: None,
+            focus_style: None,
+            indent: 2,
+            expanded_str: "▼",
+            collapsed_str: "▶",
+            leaf_str: " ",
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Border support.
+    #[inline]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Scroll support.
+    #[inline]
+    pub fn scroll(mut self, scroll: Scroll<'a>) -> Self {
+        self.scroll = Some(scroll);
+        self
+    }
+
+    /// Set all styles.
+    #[inline]
+    pub fn styles(mut self, styles: TreeStyle) -> Self {
+        self.style = styles.style;
+        if styles.select.is_some() {
+            self.select_style = styles.select;
+        }
+        if styles.focus.is_some() {
+            self.focus_style = styles.focus;
+        }
+        if let Some(styles) = styles.scroll {
+            self.scroll = self.scroll.map(|v| v.styles(styles));
+        }
+        if let Some(block) = styles.block {
+            self.block = Some(block);
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style
+    #[inline]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Select style.
+    #[inline]
+    pub fn select_style<S: Into<Style>>(mut self, select_style: S) -> Self {
+        self.select_style = Some(select_style.into());
+        self
+    }
+
+    /// Focused style.
+    #[inline]
+    pub fn focus_style<S: Into<Style>>(mut self, focus_style: S) -> Self {
+        self.focus_style = Some(focus_style.into());
+        self
+    }
+
+    /// Indent per level. Default is 2.
+    #[inline]
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Glyphs for the expander. Each should be one column wide.
+    #[inline]
+    pub fn expander(mut self, expanded: &'a str, collapsed: &'a str, leaf: &'a str) -> Self {
+        self.expanded_str = expanded;
+        self.collapsed_str = collapsed;
+        self.leaf_str = leaf;
+        self
+    }
+}
+
+impl<'a, Data> StatefulWidget for Tree<'a, Data>
+where
+    Data: TreeData<'a>,
+{
+    type State = TreeState<Data::Id>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_tree(&self, area, buf, state)
+    }
+}
+
+/// Collect the visible rows depth-first.
+fn flatten<'a, Data: TreeData<'a>>(
+    data: &Data,
+    parent: Option<&Data::Id>,
+    depth: usize,
+    expanded: &HashSet<Data::Id>,
+    rows: &mut Vec<TreeRow<Data::Id>>,
+) {
+    let Some(children) = data.children(parent) else {
+        return;
+    };
+    for id in children {
+        let leaf = data.is_leaf(&id);
+        let is_expanded = !leaf && expanded.contains(&id);
+        let loaded = leaf || data.children(Some(&id)).is_some();
+        rows.push(TreeRow {
+            id: id.clone(),
+            parent: parent.cloned(),
+            depth,
+            leaf,
+            loaded,
+            expanded: is_expanded,
+        });
+        if is_expanded {
+            flatten(data, Some(&id), depth + 1, expanded, rows);
+        }
+    }
+}
+
+fn render_tree<'a, Data: TreeData<'a>>(
+    widget: &Tree<'a, Data>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut TreeState<Data::Id>,
+) {
+    state.area = area;
+
+    state.rows.clear();
+    flatten(&widget.data, None, 0, &state.expanded, &mut state.rows);
+
+    let sa = ScrollArea::new()
+        .block(widget.block.as_ref())
+        .v_scroll(widget.scroll.as_ref());
+    state.inner = sa.inner(area, None, Some(&state.scroll));
+
+    state.scroll.set_page_len(state.inner.height as usize);
+    state
+        .scroll
+        .set_max_offset(state.rows.len().saturating_sub(state.inner.height as usize));
+    let offset = state.scroll.limit_offset(state.scroll.offset());
+    state.scroll.set_offset(offset);
+
+    sa.render(
+        area,
+        buf,
+        &mut ScrollAreaState::new().v_scroll(&mut state.scroll),
+    );
+
+    let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
+    let select_style = widget
+        .select_style
+        .unwrap_or(fallback_select_style(widget.style));
+    let select_style = if state.is_focused() {
+        focus_style
+    } else {
+        select_style
+    };
+
+    buf.set_style(state.inner, widget.style);
+
+    state.row_areas.clear();
+    state.expander_areas.clear();
+    let inner = state.inner;
+    for (n, row) in state
+        .rows
+        .iter()
+        .skip(offset)
+        .take(inner.height as usize)
+        .enumerate()
+    {
+        let row_area = Rect::new(inner.x, inner.y + n as u16, inner.width, 1);
+        let indent = (row.depth as u16).saturating_mul(widget.indent);
+        let expander_area =
+            Rect::new(row_area.x.saturating_add(indent), row_area.y, 1, 1).intersection(row_area);
+        let label_area = Rect::new(
+            expander_area.right() + 1,
+            row_area.y,
+            row_area.width.saturating_sub(indent + 2),
+            1,
+        )
+        .intersection(row_area);
+
+        let expander = if row.leaf {
+            widget.leaf_str
+        } else if row.expanded {
+            widget.expanded_str
+        } else {
+            widget.collapsed_str
+        };
+        Line::from(expander).render(expander_area, buf);
+        widget.data.render_node(&row.id, label_area, buf);
+
+        if state.selected.as_ref() == Some(&row.id) {
+            buf.set_style(row_area, select_style);
+        }
+
+        state.row_areas.push(row_area);
+        state.expander_areas.push(expander_area);
+    }
+}
+
+impl<Id> HasFocus for TreeState<Id> {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.leaf_widget(self);
+    }
+
+    #[inline]
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    #[inline]
+    fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+impl<Id> Default for TreeState<Id> {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            row_areas: Default::default(),
+            expander_areas: Default::default(),
+            rows: Default::default(),
+            expanded: Default::default(),
+            selected: None,
+            scroll: Default::default(),
+            focus: Default::default(),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<Id: Clone> Clone for TreeState<Id> {
+    fn clone(&self) -> Self {
+        Self {
+            area: self.area,
+            inner: self.inner,
+            row_areas: self.row_areas.clone(),
+            expander_areas: self.expander_areas.clone(),
+            rows: self.rows.clone(),
+            expanded: self.expanded.clone(),
+            selected: self.selected.clone(),
+            scroll: self.scroll.clone(),
+            focus: FocusFlag::named(self.focus.name()),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<Id> RelocatableState for TreeState<Id> {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        relocate_areas(self.row_areas.as_mut_slice(), shift, clip);
+        relocate_areas(self.expander_areas.as_mut_slice(), shift, clip);
+        self.scroll.relocate(shift, clip);
+    }
+}
+
+impl<Id: Clone + Eq + Hash> TreeState<Id> {
+    /// New initial state.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// New state with a focus name
+    pub fn named(name: &str) -> Self {
+        Self {
+            focus: FocusFlag::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// Number of visible rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.scroll.offset()
+    }
+
+    #[inline]
+    pub fn set_offset(&mut self, offset: usize) -> bool {
+        self.scroll.set_offset(offset)
+    }
+
+    #[inline]
+    pub fn page_len(&self) -> usize {
+        self.scroll.page_len()
+    }
+
+    #[inline]
+    pub fn scroll_up(&mut self, n: usize) -> bool {
+        self.scroll.scroll_up(n)
+    }
+
+    #[inline]
+    pub fn scroll_down(&mut self, n: usize) -> bool {
+        self.scroll.scroll_down(n)
+    }
+
+    /// Scroll to the given row.
+    pub fn scroll_to(&mut self, pos: usize) -> bool {
+        if pos >= self.offset() + self.page_len() {
+            self.set_offset(pos - self.page_len() + 1)
+        } else if pos < self.offset() {
+            self.set_offset(pos)
+        } else {
+            false
+        }
+    }
+
+    /// Scroll to the selected node, if it is visible.
+    pub fn scroll_to_selected(&mut self) -> bool {
+        if let Some(row) = self.selected_row() {
+            self.scroll_to(row)
+        } else {
+            false
+        }
+    }
+
+    /// Row of the given node, if it is visible.
+    pub fn row_of(&self, id: &Id) -> Option<usize> {
+        self.rows.iter().position(|v| &v.id == id)
+    }
+
+    /// Row of the selected node, if it is visible.
+    pub fn selected_row(&self) -> Option<usize> {
+        self.selected.as_ref().and_then(|v| self.row_of(v))
+    }
+
+    /// Selected node.
+    pub fn selected(&self) -> Option<&Id> {
+        self.selected.as_ref()
+    }
+
+    /// Select a node.
+    pub fn select(&mut self, id: Option<Id>) -> bool {
+        let old = self.selected.take();
+        self.selected = id;
+        old != self.selected
+    }
+
+    /// Select the node at the given row and scroll to it.
+    pub fn select_row(&mut self, row: usize) -> bool {
+        let Some(id) = self.rows.get(row).map(|v| v.id.clone()) else {
+            return false;
+        };
+        let r = self.select(Some(id));
+        self.scroll_to(row);
+        r
+    }
+
+    /// Move the selection down.
+    pub fn move_down(&mut self, n: usize) -> bool {
+        let row = match self.selected_row() {
+            Some(row) => row.saturating_add(n),
+            None => 0,
+        };
+        self.select_row(min_row(row, self.rows.len()))
+    }
+
+    /// Move the selection up.
+    pub fn move_up(&mut self, n: usize) -> bool {
+        let row = match self.selected_row() {
+            Some(row) => row.saturating_sub(n),
+            None => 0,
+        };
+        self.select_row(row)
+    }
+
+    /// Node is expanded.
+    pub fn is_expanded(&self, id: &Id) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Expand the node. The rows are updated with the next render.
+    pub fn expand(&mut self, id: Id) -> bool {
+        self.expanded.insert(id)
+    }
+
+    /// Collapse the node. The rows are updated with the next render.
+    ///
+    /// If the selection is inside the collapsed node it moves
+    /// to the collapsed node.
+    pub fn collapse(&mut self, id: &Id) -> bool {
+        if self.expanded.remove(id) {
+            if self.is_descendant(self.selected.as_ref(), id) {
+                self.selected = Some(id.clone());
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks the visible rows if node is a descendant of parent.
+    fn is_descendant(&self, node: Option<&Id>, parent: &Id) -> bool {
+        let mut node = node;
+        while let Some(id) = node {
+            let Some(row) = self.row_of(id) else {
+                return false;
+            };
+            node = self.rows[row].parent.as_ref();
+            if node == Some(parent) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Expand or collapse the node at the given row.
+    fn toggle_row(&mut self, row: usize) -> TreeOutcome<Id> {
+        let Some(tree_row) = self.rows.get(row).cloned() else {
+            return TreeOutcome::Continue;
+        };
+        if tree_row.leaf {
+            TreeOutcome::Unchanged
+        } else if self.is_expanded(&tree_row.id) {
+            self.collapse(&tree_row.id);
+            TreeOutcome::Collapse(tree_row.id)
+        } else {
+            self.expand(tree_row.id.clone());
+            if tree_row.loaded {
+                TreeOutcome::Expand(tree_row.id)
+            } else {
+                TreeOutcome::Load(tree_row.id)
+            }
+        }
+    }
+
+    /// Right key. Expands the selected node, or moves to the
+    /// first child if it is expanded already.
+    fn expand_or_child(&mut self) -> TreeOutcome<Id> {
+        let Some(row) = self.selected_row() else {
+            return self.move_down(0).into();
+        };
+        let tree_row = &self.rows[row];
+        if tree_row.leaf {
+            TreeOutcome::Unchanged
+        } else if tree_row.expanded {
+            match self.rows.get(row + 1) {
+                Some(next) if next.parent.as_ref() == Some(&tree_row.id) => {
+                    let id = next.id.clone();
+                    self.select_row(row + 1);
+                    TreeOutcome::Select(id)
+                }
+                _ => TreeOutcome::Unchanged,
+            }
+        } else {
+            self.toggle_row(row)
+        }
+    }
+
+    /// Left key. Collapses the selected node, or moves to the
+    /// parent if it is collapsed already.
+    fn collapse_or_parent(&mut self) -> TreeOutcome<Id> {
+        let Some(row) = self.selected_row() else {
+            return self.move_down(0).into();
+        };
+        let tree_row = &self.rows[row];
+        if tree_row.expanded {
+            self.toggle_row(row)
+        } else if let Some(parent) = tree_row.parent.clone() {
+            if let Some(parent_row) = self.row_of(&parent) {
+                self.select_row(parent_row);
+            }
+            TreeOutcome::Select(parent)
+        } else {
+            TreeOutcome::Unchanged
+        }
+    }
+
+    fn selection_changed(&self, changed: bool) -> TreeOutcome<Id> {
+        match (changed, &self.selected) {
+            (true, Some(id)) => TreeOutcome::Select(id.clone()),
+            (true, None) => TreeOutcome::Changed,
+            (false, _) => TreeOutcome::Unchanged,
+        }
+    }
+}
+
+fn min_row(row: usize, len: usize) -> usize {
+    row.min(len.saturating_sub(1))
+}
+
+impl<Id: Clone + Eq + Hash> HandleEvent<crossterm::event::Event, Regular, TreeOutcome<Id>>
+    for TreeState<Id>
+{
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> TreeOutcome<Id> {
+        if self.is_focused() {
+            flow!(match event {
+                ct_event!(keycode press Down) => {
+                    let r = self.move_down(1);
+                    self.selection_changed(r)
+                }
+                ct_event!(keycode press Up) => {
+                    let r = self.move_up(1);
+                    self.selection_changed(r)
+                }
+                ct_event!(keycode press CONTROL-Down) | ct_event!(keycode press End) => {
+                    let r = self.select_row(self.rows.len().saturating_sub(1));
+                    self.selection_changed(r)
+                }
+                ct_event!(keycode press CONTROL-Up) | ct_event!(keycode press Home) => {
+                    let r = self.select_row(0);
+                    self.selection_changed(r)
+                }
+                ct_event!(keycode press PageUp) => {
+                    let r = self.move_up(self.page_len().saturating_sub(1));
+                    self.selection_changed(r)
+                }
+                ct_event!(keycode press PageDown) => {
+                    let r = self.move_down(self.page_len().saturating_sub(1));
+                    self.selection_changed(r)
+                }
+                ct_event!(keycode press Right) => self.expand_or_child(),
+                ct_event!(keycode press Left) => self.collapse_or_parent(),
+                ct_event!(keycode press Enter) => {
+                    if let Some(id) = self.selected.clone() {
+                        TreeOutcome::Activate(id)
+                    } else {
+                        TreeOutcome::Unchanged
+                    }
+                }
+                _ => TreeOutcome::Continue,
+            });
+        }
+
+        self.handle(event, MouseOnly)
+    }
+}
+
+impl<Id: Clone + Eq + Hash> HandleEvent<crossterm::event::Event, MouseOnly, TreeOutcome<Id>>
+    for TreeState<Id>
+{
+    fn handle(
+        &mut self,
+        event: &crossterm::event::Event,
+        _qualifier: MouseOnly,
+    ) -> TreeOutcome<Id> {
+        flow!(match event {
+            ct_event!(mouse any for m) if self.mouse.doubleclick(self.inner, m) => {
+                if let Some(n) = self.mouse.row_at(&self.row_areas, m.row) {
+                    if self.mouse.item_at(&self.expander_areas, m.column, m.row) == Some(n) {
+                        TreeOutcome::Unchanged
+                    } else {
+                        let row = self.offset() + n;
+                        if let Some(tree_row) = self.rows.get(row) {
+                            TreeOutcome::Activate(tree_row.id.clone())
+                        } else {
+                            TreeOutcome::Unchanged
+                        }
+                    }
+                } else {
+                    TreeOutcome::Continue
+                }
+            }
+            ct_event!(mouse down Left for column, row)
+                if self.inner.contains((*column, *row).into()) =>
+            {
+                if let Some(n) = self.mouse.item_at(&self.expander_areas, *column, *row) {
+                    self.toggle_row(self.offset() + n)
+                } else if let Some(n) = self.mouse.row_at(&self.row_areas, *row) {
+                    let r = self.select_row(self.offset() + n);
+                    self.selection_changed(r)
+                } else {
+                    TreeOutcome::Unchanged
+                }
+            }
+            _ => TreeOutcome::Continue,
+        });
+
+        let mut sas = ScrollAreaState::new()
+            .area(self.inner)
+            .v_scroll(&mut self.scroll);
+        match sas.handle(event, MouseOnly) {
+            ScrollOutcome::Up(v) => self.scroll_up(v).into(),
+            ScrollOutcome::Down(v) => self.scroll_down(v).into(),
+            ScrollOutcome::VPos(v) => self.set_offset(v).into(),
+            ScrollOutcome::Continue => TreeOutcome::Continue,
+            ScrollOutcome::Unchanged => TreeOutcome::Unchanged,
+            _ => TreeOutcome::Changed,
+        }
+    }
+}
+
+/// One node for [TreeStore].
+#[derive(Debug, Clone)]
+pub struct TreeNode<Id> {
+    pub id: Id,
+    pub label: Line<'static>,
+    pub leaf: bool,
+}
+
+/// Simple in-memory tree data with lazy children.
+///
+/// Nodes without known children are loaded when expanded.
+/// Use [TreeStore::set_children] when the tree emits
+/// [TreeOutcome::Load].
+#[derive(Debug, Clone)]
+pub struct TreeStore<Id> {
+    labels: HashMap<Id, (Line<'static>, bool)>,
+    root: Option<Vec<Id>>,
+    children: HashMap<Id, Vec<Id>>,
+}
+
+impl<Id> Default for TreeStore<Id> {
+    fn default() -> Self {
+        Self {
+            labels: Default::default(),
+            root: None,
+            children: Default::default(),
+        }
+    }
+}
+
+impl<Id: Clone + Eq + Hash + Debug> TreeStore<Id> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the children of a node. `None` is the root.
+    ///
+    /// Any previous children are removed.
+    pub fn set_children(
+        &mut self,
+        parent: Option<Id>,
+        children: impl IntoIterator<Item = TreeNode<Id>>,
+    ) {
+        self.clear_children(parent.as_ref());
+        let mut ids = Vec::new();
+        for node in children {
+            self.labels.insert(node.id.clone(), (node.label, node.leaf));
+            ids.push(node.id);
+        }
+        match parent {
+            None => self.root = Some(ids),
+            Some(parent) => {
+                self.children.insert(parent, ids);
+            }
+        }
+    }
+
+    /// Forget the children of a node. They will be loaded
+    /// again when the node is expanded.
+    pub fn clear_children(&mut self, parent: Option<&Id>) {
+        let old = match parent {
+            None => self.root.take(),
+            Some(parent) => self.children.remove(parent),
+        };
+        for id in old.into_iter().flatten() {
+            self.clear_children(Some(&id));
+            self.labels.remove(&id);
+        }
+    }
+}
+
+impl<'a, Id: Clone + Eq + Hash + Debug> TreeData<'a> for &'a TreeStore<Id> {
+    type Id = Id;
+
+    fn children(&self, parent: Option<&Self::Id>) -> Option<impl Iterator<Item = Self::Id>> {
+        let children = match parent {
+            None => self.root.as_ref(),
+            Some(parent) => self.children.get(parent),
+        };
+        children.map(|v| v.iter().cloned())
+    }
+
+    fn is_leaf(&self, id: &Self::Id) -> bool {
+        self.labels.get(id).map(|v| v.1).unwrap_or(true)
+    }
+
+    fn render_node(&self, id: &Self::Id, area: Rect, buf: &mut Buffer) {
+        if let Some((label, _)) = self.labels.get(id) {
+            label.render(area, buf);
+        }
+    }
+}
@@ -8,6 +8,21 @@ use ratatui::style::{Style, Stylize};
 use ratatui::widgets::{Block, Padding};
 use std::{fmt, mem};
 
+/// Color capabilities of a terminal.
+///
+/// rat-theme converts this to its own ColorDepth, which can
+/// map the colors of a theme.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// The 16 ANSI colors.
+    Ansi16,
+    /// The xterm 256 color palette.
+    Ansi256,
+    /// 24-bit RGB.
+    #[default]
+    TrueColor,
+}
+
 /// Union the areas, but regard only non-empty ones.
 ///
 /// This can help if you want to union two non-adjacent areas.
@@ -46,6 +46,17 @@
 //!     .render(l2[1], &mut buf, &mut state.view);
 //!
 //! ```
+//!
+//! __Caching__
+//!
+//! With a [cache_key](View::cache_key) the view keeps the rendered
+//! buffer. If the key is the same next time, and the area and scroll
+//! position didn't change, the render functions of [ViewBuffer] do
+//! nothing and the cached buffer is used. The relocated areas in the
+//! widget states are still valid in this case.
+//!
+//! The key should be a hash of everything that changes the
+//! rendering, including the state of the widgets.
 
 use std::cmp::{max, min};
 
@@ -64,6 +75,7 @@ use ratatui::widgets::Block;
 pub struct View<'a> {
     layout: Rect,
     view_size: Option<Size>,
+    cache_key: Option<u64>,
 
     block: Option<Block<'a>>,
     hscroll: Option<Scroll<'a>>,
@@ -80,6 +92,8 @@ pub struct View<'a> {
 pub struct ViewBuffer<'a> {
     // page layout
     layout: Rect,
+    // buffer is still valid.
+    cached: bool,
 
     // Scroll offset into the view.
     offset: Position,
@@ -137,6 +151,10 @@ pub struct ViewState {
 
     /// For the buffer to survive render()
     buffer: Option<Buffer>,
+    /// Cache key of the buffer.
+    cache_key: Option<u64>,
+    /// Relocation of the cached buffer.
+    cache_relocation: ((i16, i16), Rect),
 }
 
 impl<'a> View<'a> {
@@ -183,6 +201,15 @@ impl<'a> View<'a> {
         self
     }
 
+    /// Cache the rendered buffer with this key.
+    ///
+    /// The key should be a hash of everything that changes the
+    /// rendered content. See [ViewBuffer::is_cached].
+    pub fn cache_key(mut self, key: u64) -> Self {
+        self.cache_key = Some(key);
+        self
+    }
+
     /// Combined style.
     pub fn styles(mut self, styles: ViewStyle) -> Self {
         if styles.block.is_some() {
@@ -218,7 +245,6 @@ impl<'a> View<'a> {
             .block(self.block.as_ref())
             .h_scroll(self.hscroll.as_ref())
             .v_scroll(self.vscroll.as_ref());
-        state.widget_area = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
 
         let max_x = if let Some(view_size) = self.view_size {
             max(state.layout.right(), view_size.width)
@@ -231,6 +257,15 @@ impl<'a> View<'a> {
             state.layout.bottom()
         };
 
+        sa.measure(
+            area,
+            Some(max_x as usize),
+            Some(max_y as usize),
+            Some(&mut state.hscroll),
+            Some(&mut state.vscroll),
+        );
+        state.widget_area = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
+
         state
             .hscroll
             .set_max_offset(max_x.saturating_sub(state.widget_area.width) as usize);
@@ -246,18 +281,35 @@ impl<'a> View<'a> {
         // internal buffer starts at (view.x,view.y)
         let offset = Position::new(state.hscroll.offset as u16, state.vscroll.offset as u16);
 
+        let relocation = (
+            (
+                state.widget_area.x as i16 - offset.x as i16,
+                state.widget_area.y as i16 - offset.y as i16,
+            ),
+            state.widget_area,
+        );
+
         // resize buffer to fit the layout.
         let buffer_area = state.layout;
+        let cached = self.cache_key.is_some()
+            && state.cache_key == self.cache_key
+            && state.cache_relocation == relocation
+            && state.buffer.as_ref().map(|v| v.area) == Some(buffer_area);
         let buffer = if let Some(mut buffer) = state.buffer.take() {
-            buffer.reset();
-            buffer.resize(buffer_area);
+            if !cached {
+                buffer.reset();
+                buffer.resize(buffer_area);
+            }
             buffer
         } else {
             Buffer::empty(buffer_area)
         };
+        state.cache_key = self.cache_key;
+        state.cache_relocation = relocation;
 
         ViewBuffer {
             layout: self.layout,
+            cached,
             offset,
             buffer,
             widget_area: state.widget_area,
@@ -269,12 +321,24 @@ impl<'a> View<'a> {
 }
 
 impl<'a> ViewBuffer<'a> {
+    /// The buffer is still valid from the last render.
+    ///
+    /// All the render functions do nothing in this case.
+    /// If there is some expensive preparation for rendering
+    /// it can be skipped too.
+    pub fn is_cached(&self) -> bool {
+        self.cached
+    }
+
     /// Render a widget to the temp buffer.
     #[inline(always)]
     pub fn render_widget<W>(&mut self, widget: W, area: Rect)
     where
         W: Widget,
     {
+        if self.cached {
+            return;
+        }
         if area.intersects(self.buffer.area) {
             // render the actual widget.
             widget.render(area, self.buffer());
@@ -289,6 +353,10 @@ impl<'a> ViewBuffer<'a> {
         W: StatefulWidget<State = S>,
         S: RelocatableState,
     {
+        if self.cached {
+            // the state still has the relocated areas.
+            return;
+        }
         if area.intersects(self.buffer.area) {
             // render the actual widget.
             widget.render(area, self.buffer(), state);
@@ -299,6 +367,31 @@ impl<'a> ViewBuffer<'a> {
         }
     }
 
+    /// Render the popup of a stateful widget to the temp buffer.
+    ///
+    /// Call this after all widgets have been rendered, so the
+    /// popup is drawn above them. The area is the area of the
+    /// main widget, and only the popup areas are relocated.
+    ///
+    /// If the widget is not visible the popup areas are cleared.
+    #[inline(always)]
+    pub fn render_popup<W, S>(&mut self, widget: W, area: Rect, state: &mut S)
+    where
+        W: StatefulWidget<State = S>,
+        S: RelocatableState,
+    {
+        if self.cached {
+            // the state still has the relocated areas.
+            return;
+        }
+        if area.intersects(self.buffer.area) {
+            widget.render(area, self.buffer(), state);
+            self.relocate_popup(state);
+        } else {
+            self.hidden_popup(state);
+        }
+    }
+
     /// Return the buffer layout.
     pub fn layout(&self) -> Rect {
         self.layout
@@ -328,11 +421,36 @@ impl<'a> ViewBuffer<'a> {
     /// coordinates instead of screen coordinates.
     ///
     /// Call this function to correct this after rendering.
+    ///
+    /// This only relocates the main areas of the widget,
+    /// see [relocate_popup](Self::relocate_popup).
     pub fn relocate<S>(&self, state: &mut S)
     where
         S: RelocatableState,
     {
-        state.relocate(self.shift(), self.widget_area);
+        let (shift, clip) = self.relocation();
+        state.relocate(shift, clip);
+    }
+
+    /// After rendering a popup to the buffer its areas
+    /// are in buffer coordinates.
+    ///
+    /// Call this function to correct this after rendering.
+    pub fn relocate_popup<S>(&self, state: &mut S)
+    where
+        S: RelocatableState,
+    {
+        let (shift, clip) = self.relocation();
+        state.relocate_popup(shift, clip);
+    }
+
+    /// Shift and clip for relocating from buffer to screen coordinates.
+    ///
+    /// If this buffer is rendered into another container, combine
+    /// this with the relocation of the outer container and use
+    /// [RelocatableState::relocate_with].
+    pub fn relocation(&self) -> ((i16, i16), Rect) {
+        (self.shift(), self.widget_area)
     }
 
     /// If a widget is not rendered because it is out of
@@ -340,17 +458,35 @@ impl<'a> ViewBuffer<'a> {
     /// in its state.
     ///
     /// This uses the mechanism for [relocate](Self::relocate) to zero them out.
+    /// A popup of an invisible widget is hidden too.
     pub fn hidden<S>(&self, state: &mut S)
     where
         S: RelocatableState,
     {
-        state.relocate((0, 0), Rect::default())
+        if self.cached {
+            return;
+        }
+        state.relocate_hidden();
+        state.relocate_popup_hidden();
+    }
+
+    /// Clear the popup areas of an invisible widget.
+    pub fn hidden_popup<S>(&self, state: &mut S)
+    where
+        S: RelocatableState,
+    {
+        if self.cached {
+            return;
+        }
+        state.relocate_popup_hidden();
     }
 
     /// Access the temporary buffer.
     ///
     /// __Note__
     /// Use of render_widget is preferred.
+    /// If the buffer [is_cached](Self::is_cached) this
+    /// changes the cached content.
     pub fn buffer(&mut self) -> &mut Buffer {
         &mut self.buffer
     }
@@ -452,6 +588,13 @@ impl ViewState {
         Self::default()
     }
 
+    /// Throw away the cached buffer.
+    ///
+    /// The next render will call all render functions again.
+    pub fn invalidate(&mut self) {
+        self.cache_key = None;
+    }
+
     /// Show this rect.
     pub fn show_area(&mut self, area: Rect) {
         self.hscroll.scroll_to_pos(area.x as usize);
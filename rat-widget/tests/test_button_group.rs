@@ -0,0 +1,156 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Regular};
+use rat_widget::button::{Button, ButtonState};
+use rat_widget::button_group::ButtonGroupState;
+use rat_widget::event::ButtonOutcome;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn mouse(kind: MouseEventKind, x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn click(state: &mut ButtonGroupState, x: u16, y: u16) -> ButtonOutcome {
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), x, y),
+        Regular,
+    );
+    state.handle(&mouse(MouseEventKind::Up(MouseButton::Left), x, y), Regular)
+}
+
+fn render_group(state: &mut ButtonGroupState) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 1));
+    for (n, text) in ["Left", "Center", "Right"].iter().enumerate() {
+        Button::new(*text).pressed_style(Style::new().red()).render(
+            Rect::new(n as u16 * 10, 0, 10, 1),
+            &mut buf,
+            state.item_mut(n),
+        );
+    }
+    buf
+}
+
+#[test]
+fn test_toggle() {
+    let mut state = ButtonState::named_toggle("bold");
+    state.focus.set(true);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Char(' ')), Regular),
+        ButtonOutcome::Toggled(true)
+    );
+    assert!(state.toggled());
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Regular),
+        ButtonOutcome::Toggled(false)
+    );
+    assert!(!state.toggled());
+
+    // a plain button doesn't keep a state.
+    let mut state = ButtonState::named("ok");
+    state.focus.set(true);
+    assert_eq!(
+        state.handle(&key(KeyCode::Char(' ')), Regular),
+        ButtonOutcome::Pressed
+    );
+    assert!(!state.toggled());
+}
+
+#[test]
+fn test_pressed_style() {
+    let mut state = ButtonState::named_toggle("bold");
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    Button::new("Bold")
+        .pressed_style(Style::new().red())
+        .render(Rect::new(0, 0, 10, 1), &mut buf, &mut state);
+    assert_ne!(buf[(0, 0)].fg, ratatui::style::Color::Red);
+
+    state.set_toggled(true);
+    Button::new("Bold")
+        .pressed_style(Style::new().red())
+        .render(Rect::new(0, 0, 10, 1), &mut buf, &mut state);
+    assert_eq!(buf[(0, 0)].fg, ratatui::style::Color::Red);
+}
+
+#[test]
+fn test_group_keyboard() {
+    let mut state = ButtonGroupState::named("align", 3);
+    state.focus.set(true);
+    render_group(&mut state);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Char(' ')), Regular),
+        ButtonOutcome::Selected(0)
+    );
+    assert_eq!(state.selected(), Some(0));
+
+    // arrows move, but don't press.
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        ButtonOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        ButtonOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        ButtonOutcome::Unchanged
+    );
+    assert_eq!(state.current(), Some(2));
+    assert_eq!(state.selected(), Some(0));
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Regular),
+        ButtonOutcome::Selected(2)
+    );
+    assert_eq!(
+        state.items.iter().map(|v| v.toggled()).collect::<Vec<_>>(),
+        vec![false, false, true]
+    );
+    // pressing again keeps it.
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Regular),
+        ButtonOutcome::Unchanged
+    );
+    assert_eq!(state.selected(), Some(2));
+
+    let buf = render_group(&mut state);
+    assert_eq!(buf[(0, 0)].fg, ratatui::style::Color::Reset);
+    assert_eq!(buf[(21, 0)].fg, ratatui::style::Color::Red);
+}
+
+#[test]
+fn test_group_mouse() {
+    let mut state = ButtonGroupState::named("align", 3);
+    render_group(&mut state);
+
+    assert_eq!(click(&mut state, 12, 0), ButtonOutcome::Selected(1));
+    assert_eq!(state.current(), Some(1));
+    assert_eq!(click(&mut state, 2, 0), ButtonOutcome::Selected(0));
+    assert_eq!(
+        state.items.iter().map(|v| v.toggled()).collect::<Vec<_>>(),
+        vec![true, false, false]
+    );
+
+    // the pressed button stays pressed.
+    assert_eq!(click(&mut state, 2, 0), ButtonOutcome::Changed);
+    assert!(state.item(0).toggled());
+    assert_eq!(state.selected(), Some(0));
+
+    // outside.
+    assert_eq!(click(&mut state, 40, 0), ButtonOutcome::Continue);
+}
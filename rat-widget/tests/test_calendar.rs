@@ -0,0 +1,108 @@
+use chrono::{NaiveDate, Weekday};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::{HandleEvent, Regular};
+use rat_widget::calendar::selection::SingleSelection;
+use rat_widget::calendar::{CalendarState, Month, MonthState, Months};
+use rat_widget::event::CalOutcome;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("date")
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].symbol())
+        .collect::<String>()
+}
+
+#[test]
+fn test_first_weekday() {
+    // 2024-09-01 is a sunday.
+    let mut state = MonthState::<SingleSelection>::new();
+    state.set_start_date(date(2024, 9, 1));
+    assert_eq!(state.week_len(), 6);
+
+    let month = Month::new().first_weekday(Weekday::Sun);
+    assert_eq!(month.height(&state), 7);
+
+    let area = Rect::new(0, 0, 24, 8);
+    let mut buf = Buffer::empty(area);
+    month.render(area, &mut buf, &mut state);
+
+    assert_eq!(state.first_weekday, Weekday::Sun);
+    assert_eq!(state.week_len(), 5);
+    assert_eq!(line(&buf, 1), "   Su Mo Tu We Th Fr Sa ");
+    assert_eq!(line(&buf, 2), "36  1  2  3  4  5  6  7 ");
+    assert_eq!(line(&buf, 6), "40 29 30                ");
+    assert_eq!(state.area_days[0], Rect::new(3, 2, 2, 1));
+    assert_eq!(state.week_date(0), date(2024, 9, 5));
+}
+
+#[test]
+fn test_no_weeknum() {
+    let mut state = MonthState::<SingleSelection>::new();
+    state.set_start_date(date(2024, 9, 1));
+
+    let month = Month::new().show_weeknum(false);
+    assert_eq!(month.width(), 21);
+
+    let area = Rect::new(0, 0, 21, 8);
+    let mut buf = Buffer::empty(area);
+    month.render(area, &mut buf, &mut state);
+
+    assert_eq!(line(&buf, 1), "Mo Tu We Th Fr Sa Su ");
+    assert_eq!(line(&buf, 2), "                   1 ");
+    assert_eq!(state.area_weeks[0], Rect::default());
+}
+
+#[test]
+fn test_months_navigation() {
+    let mut state = CalendarState::<2, SingleSelection>::new();
+    state.set_start_date(date(2024, 1, 1));
+    state.focus.set(true);
+
+    let area = Rect::new(0, 0, 60, 10);
+    let mut buf = Buffer::empty(area);
+    Months::new().render(area, &mut buf, &mut state);
+    assert_eq!(state.months[1].area.x, state.months[0].area.right() + 2);
+    assert_eq!(state.months[1].area.y, state.months[0].area.y);
+
+    // selection flows into the next month.
+    state.select(date(2024, 1, 31));
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        CalOutcome::Selected(date(2024, 2, 1))
+    );
+    assert_eq!(state.primary_idx(), 1);
+
+    // and scrolls beyond the last one.
+    state.select(date(2024, 2, 29));
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        CalOutcome::Selected(date(2024, 3, 1))
+    );
+    assert_eq!(state.start_date(), date(2024, 2, 1));
+    assert_eq!(state.selected(), Some(date(2024, 3, 1)));
+}
+
+#[test]
+fn test_months_grid() {
+    let mut state = CalendarState::<4, SingleSelection>::new();
+    state.set_start_date(date(2024, 1, 1));
+
+    let area = Rect::new(0, 0, 60, 20);
+    let mut buf = Buffer::empty(area);
+    Months::new().columns(2).render(area, &mut buf, &mut state);
+
+    assert_eq!(state.months[0].area.y, 0);
+    assert_eq!(state.months[1].area.y, 0);
+    assert_eq!(state.months[2].area.x, 0);
+    assert_eq!(state.months[2].area.y, state.months[0].area.bottom() + 1);
+}
@@ -0,0 +1,120 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Regular};
+use rat_focus::{FocusBuilder, HasFocus};
+use rat_widget::check_group::CheckGroupState;
+use rat_widget::checkbox::Checkbox;
+use rat_widget::event::CheckOutcome;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut CheckGroupState) {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+    for n in 0..state.len() {
+        Checkbox::new().text(format!("item {}", n)).render(
+            Rect::new(0, n as u16, 20, 1),
+            &mut buf,
+            state.item_mut(n),
+        );
+    }
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_keys() {
+    let mut state = CheckGroupState::named("group", 4);
+    state.focus.set(true);
+    render(&mut state);
+
+    assert_eq!(state.area(), Rect::new(0, 0, 20, 4));
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        CheckOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        CheckOutcome::Changed
+    );
+    assert_eq!(state.selected(), Some(2));
+    assert_eq!(
+        state.handle(&key(KeyCode::Char(' ')), Regular),
+        CheckOutcome::Value
+    );
+    assert_eq!(state.values(), [false, false, true, false]);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::End), Regular),
+        CheckOutcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        CheckOutcome::Unchanged
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Up), Regular),
+        CheckOutcome::Changed
+    );
+    assert_eq!(state.selected(), Some(2));
+
+    render(&mut state);
+    assert!(state.item(2).focus.get());
+    assert!(!state.item(0).focus.get());
+}
+
+#[test]
+fn test_mouse() {
+    let mut state = CheckGroupState::named("group", 4);
+    render(&mut state);
+
+    let r = state.handle(&click(3, 3), Regular);
+    assert_eq!(r, CheckOutcome::Changed);
+    assert_eq!(state.selected(), Some(3));
+    assert_eq!(state.handle(&click(3, 8), Regular), CheckOutcome::Continue);
+}
+
+#[test]
+fn test_focus() {
+    let mut group = CheckGroupState::named("group", 4);
+    let mut other = CheckGroupState::named("other", 2);
+    render(&mut group);
+    group.select(2);
+    for n in 0..2 {
+        other.item_mut(n).area = Rect::new(0, 5 + n as u16, 20, 1);
+    }
+
+    let mut fb = FocusBuilder::new(None);
+    fb.widget(&group);
+    fb.widget(&other);
+    let focus = fb.build();
+
+    // a single tab stop each.
+    focus.first();
+    assert!(group.is_focused());
+    focus.next();
+    assert!(other.is_focused());
+    assert!(group.lost_focus());
+    render(&mut group);
+    assert!(!group.item(2).focus.get());
+
+    // selection is kept.
+    focus.next();
+    assert!(group.is_focused());
+    assert!(group.gained_focus());
+    render(&mut group);
+    assert!(group.item(2).focus.get());
+}
@@ -0,0 +1,166 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_event::{HandleEvent, Outcome, Regular};
+use rat_focus::{FocusBuilder, FocusFlag};
+use rat_scrolled::Scroll;
+use rat_widget::clipper::{Clipper, ClipperState};
+use rat_widget::layout::GenericLayout;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+/// 3 pages of 20 rows with two widgets each.
+fn state(widgets: &[FocusFlag]) -> ClipperState<FocusFlag> {
+    let mut state = ClipperState::new();
+    let mut layout = GenericLayout::new();
+    for (n, w) in widgets.iter().enumerate() {
+        layout.add(
+            w.clone(),
+            Rect::new(0, n as u16 * 10, 10, 1),
+            None,
+            Rect::default(),
+        );
+    }
+    state.set_layout(layout);
+    state.add_page_break(40);
+    state.add_page_break(20);
+    state.add_page_break(20);
+    state.container.set(true);
+    state
+}
+
+fn render(state: &mut ClipperState<FocusFlag>, style: Option<Style>) -> Buffer {
+    let area = Rect::new(0, 0, 20, 10);
+    let mut buf = Buffer::empty(area);
+    let mut clipper = Clipper::new().vscroll(Scroll::new());
+    if let Some(style) = style {
+        clipper = clipper.page_break_style(style);
+    }
+    clipper
+        .into_buffer(area, state)
+        .into_widget()
+        .render(area, &mut buf, state);
+    buf
+}
+
+fn flags() -> Vec<FocusFlag> {
+    (0..6)
+        .map(|n| FocusFlag::named(&format!("w{}", n)))
+        .collect()
+}
+
+#[test]
+fn test_pages() {
+    let widgets = flags();
+    let mut state = state(&widgets);
+    render(&mut state, None);
+
+    assert_eq!(state.page_breaks(), &[20, 40]);
+    assert_eq!(state.page_count(), 3);
+    assert_eq!(state.current_page(), 0);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown), Regular),
+        Outcome::Changed
+    );
+    assert_eq!(state.vertical_offset(), 20);
+    assert_eq!(state.current_page(), 1);
+
+    // scrolled into the page, PageUp goes to its start first.
+    state.scroll_down(3);
+    assert_eq!(state.current_page(), 1);
+    assert_eq!(
+        state.handle(&key(KeyCode::PageUp), Regular),
+        Outcome::Changed
+    );
+    assert_eq!(state.vertical_offset(), 20);
+    assert_eq!(
+        state.handle(&key(KeyCode::PageUp), Regular),
+        Outcome::Changed
+    );
+    assert_eq!(state.vertical_offset(), 0);
+    assert_eq!(
+        state.handle(&key(KeyCode::PageUp), Regular),
+        Outcome::Unchanged
+    );
+
+    assert!(state.goto_page(2));
+    assert_eq!(state.current_page(), 2);
+    assert!(!state.goto_page(7));
+
+    assert_eq!(state.first_of_page(0), Some(widgets[0].clone()));
+    assert_eq!(state.first_of_page(1), Some(widgets[2].clone()));
+    assert_eq!(state.first_of_page(2), Some(widgets[4].clone()));
+    assert_eq!(state.first_of_page(3), None);
+}
+
+#[test]
+fn test_no_pages() {
+    let widgets = flags();
+    let mut state = state(&widgets);
+    state.clear_page_breaks();
+    render(&mut state, None);
+
+    assert_eq!(state.page_count(), 1);
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown), Regular),
+        Outcome::Changed
+    );
+    assert_eq!(state.vertical_offset(), 10);
+    assert_eq!(state.current_page(), 0);
+}
+
+#[test]
+fn test_page_focus() {
+    let widgets = flags();
+    let mut state = state(&widgets);
+    render(&mut state, None);
+
+    let mut fb = FocusBuilder::default();
+    for w in widgets.iter() {
+        fb.widget(w);
+    }
+    let focus = fb.build();
+    focus.focus(&widgets[0]);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown), &focus),
+        Outcome::Changed
+    );
+    assert!(widgets[2].get());
+
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown), &focus),
+        Outcome::Changed
+    );
+    assert!(widgets[4].get());
+
+    // no page change, focus stays.
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown), &focus),
+        Outcome::Unchanged
+    );
+    assert!(widgets[4].get());
+}
+
+#[test]
+fn test_page_marks() {
+    let widgets = flags();
+    let mut state = state(&widgets);
+    let buf = render(&mut state, Some(Style::new().red()));
+
+    // 10 rows of track for 51 rows of content.
+    let track = state.vscroll.area;
+    assert_eq!(buf[(track.x, track.y + 3)].fg, ratatui::style::Color::Red);
+    assert_eq!(buf[(track.x, track.y + 7)].fg, ratatui::style::Color::Red);
+    assert_ne!(buf[(track.x, track.y + 6)].fg, ratatui::style::Color::Red);
+}
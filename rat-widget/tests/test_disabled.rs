@@ -0,0 +1,227 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, MouseOnly, Popup, Regular};
+use rat_focus::{FocusBuilder, HasFocus, Navigation};
+use rat_widget::button::{Button, ButtonState};
+use rat_widget::checkbox::{Checkbox, CheckboxState};
+use rat_widget::choice::{Choice, ChoiceState};
+use rat_widget::event::{
+    ButtonOutcome, CheckOutcome, ChoiceOutcome, RadioOutcome, RangeSliderOutcome, SliderOutcome,
+};
+use rat_widget::radio::{Radio, RadioState};
+use rat_widget::slider::{RangeSlider, RangeSliderState, Slider, SliderState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::StatefulWidget;
+
+const AREA: Rect = Rect::new(0, 0, 20, 3);
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn mouse(kind: MouseEventKind, x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+/// Everything a widget might react to.
+fn barrage() -> Vec<Event> {
+    let mut events = Vec::new();
+    for code in [
+        KeyCode::Enter,
+        KeyCode::Char(' '),
+        KeyCode::Char('a'),
+        KeyCode::Left,
+        KeyCode::Right,
+        KeyCode::Up,
+        KeyCode::Down,
+        KeyCode::Home,
+        KeyCode::End,
+        KeyCode::PageUp,
+        KeyCode::PageDown,
+        KeyCode::Delete,
+        KeyCode::Backspace,
+        KeyCode::Esc,
+    ] {
+        events.push(key(code));
+        events.push(Event::Key(KeyEvent::new(code, KeyModifiers::CONTROL)));
+        events.push(Event::Key(KeyEvent::new(code, KeyModifiers::ALT)));
+    }
+    for y in AREA.top()..AREA.bottom() {
+        for x in AREA.left()..AREA.right() {
+            for kind in [
+                MouseEventKind::Down(MouseButton::Left),
+                MouseEventKind::Up(MouseButton::Left),
+                MouseEventKind::Drag(MouseButton::Left),
+                MouseEventKind::Moved,
+                MouseEventKind::ScrollUp,
+                MouseEventKind::ScrollDown,
+            ] {
+                events.push(mouse(kind, x, y));
+            }
+            // double click
+            events.push(mouse(MouseEventKind::Down(MouseButton::Left), x, y));
+            events.push(mouse(MouseEventKind::Up(MouseButton::Left), x, y));
+        }
+    }
+    events
+}
+
+fn assert_disabled_style(buf: &Buffer) {
+    assert!(buf[(0, 0)].modifier.contains(Modifier::DIM));
+}
+
+#[test]
+fn test_button() {
+    let mut state = ButtonState::new();
+    state.set_disabled(true);
+    state.focus.set(true);
+    let mut buf = Buffer::empty(AREA);
+    Button::new("Ok").render(AREA, &mut buf, &mut state);
+    assert_disabled_style(&buf);
+    assert_eq!(state.navigable(), Navigation::None);
+
+    for event in barrage() {
+        assert_eq!(state.handle(&event, Regular), ButtonOutcome::Continue);
+        assert_eq!(state.handle(&event, MouseOnly), ButtonOutcome::Continue);
+    }
+}
+
+#[test]
+fn test_checkbox() {
+    let mut state = CheckboxState::new();
+    state.set_disabled(true);
+    state.focus.set(true);
+    let mut buf = Buffer::empty(AREA);
+    Checkbox::new()
+        .text("Check")
+        .render(AREA, &mut buf, &mut state);
+    assert_disabled_style(&buf);
+    assert_eq!(state.navigable(), Navigation::None);
+
+    for event in barrage() {
+        assert_eq!(state.handle(&event, Regular), CheckOutcome::Continue);
+        assert_eq!(state.handle(&event, MouseOnly), CheckOutcome::Continue);
+    }
+    assert!(!state.value());
+}
+
+#[test]
+fn test_radio() {
+    let mut state = RadioState::new();
+    state.set_disabled(true);
+    state.focus.set(true);
+    let mut buf = Buffer::empty(AREA);
+    Radio::new()
+        .items([(0, "a"), (1, "b"), (2, "c")])
+        .render(AREA, &mut buf, &mut state);
+    assert_disabled_style(&buf);
+    assert_eq!(state.navigable(), Navigation::None);
+
+    for event in barrage() {
+        assert_eq!(state.handle(&event, Regular), RadioOutcome::Continue);
+        assert_eq!(state.handle(&event, MouseOnly), RadioOutcome::Continue);
+    }
+}
+
+#[test]
+fn test_choice() {
+    let mut state = ChoiceState::new();
+    state.set_disabled(true);
+    state.focus.set(true);
+    let mut buf = Buffer::empty(AREA);
+    let (widget, popup) = Choice::new()
+        .items([(0, "a"), (1, "b"), (2, "c")])
+        .into_widgets();
+    widget.render(Rect::new(0, 0, 20, 1), &mut buf, &mut state);
+    popup.render(AREA, &mut buf, &mut state);
+    assert_disabled_style(&buf);
+    assert_eq!(state.navigable(), Navigation::None);
+
+    for event in barrage() {
+        assert_eq!(state.handle(&event, Popup), ChoiceOutcome::Continue);
+        assert_eq!(state.handle(&event, MouseOnly), ChoiceOutcome::Continue);
+    }
+    assert!(!state.is_popup_active());
+}
+
+#[test]
+fn test_slider() {
+    let mut state = SliderState::<u8>::new_range((0, 10), 1);
+    state.set_disabled(true);
+    state.focus.set(true);
+    let mut buf = Buffer::empty(AREA);
+    Slider::new().render(AREA, &mut buf, &mut state);
+    assert_disabled_style(&buf);
+    assert_eq!(state.navigable(), Navigation::None);
+
+    for event in barrage() {
+        assert_eq!(state.handle(&event, Regular), SliderOutcome::Continue);
+        assert_eq!(state.handle(&event, MouseOnly), SliderOutcome::Continue);
+    }
+    assert_eq!(state.value(), 0);
+}
+
+#[test]
+fn test_range_slider() {
+    let mut state = RangeSliderState::<u8>::new_range((0, 10), 1);
+    state.set_disabled(true);
+    state.focus.set(true);
+    let mut buf = Buffer::empty(AREA);
+    RangeSlider::new().render(AREA, &mut buf, &mut state);
+    assert_disabled_style(&buf);
+    assert_eq!(state.navigable(), Navigation::None);
+
+    for event in barrage() {
+        assert_eq!(state.handle(&event, Regular), RangeSliderOutcome::Continue);
+        assert_eq!(
+            state.handle(&event, MouseOnly),
+            RangeSliderOutcome::Continue
+        );
+    }
+    assert_eq!(state.value(), (0, 10));
+}
+
+#[test]
+fn test_disabled_style() {
+    let mut state = CheckboxState::new();
+    state.set_disabled(true);
+    let mut buf = Buffer::empty(AREA);
+    Checkbox::new()
+        .text("Check")
+        .disabled_style(Style::new().fg(Color::Gray))
+        .render(AREA, &mut buf, &mut state);
+    assert_eq!(buf[(0, 0)].fg, Color::Gray);
+    assert!(!buf[(0, 0)].modifier.contains(Modifier::DIM));
+}
+
+#[test]
+fn test_focus_skips() {
+    let mut first = CheckboxState::named("first");
+    let mut second = CheckboxState::named("second");
+    let mut third = CheckboxState::named("third");
+    first.area = Rect::new(0, 0, 10, 1);
+    second.area = Rect::new(0, 1, 10, 1);
+    third.area = Rect::new(0, 2, 10, 1);
+    second.set_disabled(true);
+
+    let focus = FocusBuilder::build_for_parts(&[&first, &second, &third]);
+    focus.first();
+    assert!(first.is_focused());
+    focus.next();
+    assert!(third.is_focused());
+
+    // picked up after a rebuild.
+    second.set_disabled(false);
+    let focus = FocusBuilder::build_for_parts(&[&first, &second, &third]);
+    focus.first();
+    focus.next();
+    assert!(second.is_focused());
+}
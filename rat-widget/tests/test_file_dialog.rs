@@ -0,0 +1,299 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::{Dialog, HandleEvent};
+use rat_widget::event::FileOutcome;
+use rat_widget::file_dialog::{FileDialog, FileDialogButton, FileDialogState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+use std::fs;
+use std::path::PathBuf;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+fn render(state: &mut FileDialogState) {
+    let area = Rect::new(0, 0, 80, 24);
+    let mut buf = Buffer::empty(area);
+    FileDialog::new().render(area, &mut buf, state);
+}
+
+fn dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rat-widget-{}", name));
+    _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("dir");
+    fs::write(dir.join("a.txt"), "a").expect("file");
+    dir
+}
+
+fn handle(state: &mut FileDialogState, code: KeyCode, modifiers: KeyModifiers) -> FileOutcome {
+    state.handle(&key(code, modifiers), Dialog).expect("io")
+}
+
+/// Open dialog with the focus on the roots list,
+/// which doesn't use Enter.
+fn open(dir: &PathBuf) -> FileDialogState {
+    let mut state = FileDialogState::new();
+    state.use_default_roots(false);
+    state.add_root("tmp", dir);
+    state.open_dialog(dir).expect("open");
+    render(&mut state);
+    // files -> dirs -> path -> roots
+    for _ in 0..3 {
+        handle(&mut state, KeyCode::BackTab, KeyModifiers::SHIFT);
+    }
+    state
+}
+
+#[test]
+fn test_default_button() {
+    let dir = dir("default-button");
+
+    let mut state = open(&dir);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Unchanged
+    );
+    assert!(state.active);
+
+    let mut state = open(&dir);
+    state.default_button = Some(FileDialogButton::Ok);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(dir.join("a.txt"))
+    );
+    assert!(!state.active);
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_cancel_button() {
+    let dir = dir("cancel-button");
+
+    let mut state = open(&dir);
+    assert_eq!(
+        handle(&mut state, KeyCode::Esc, KeyModifiers::NONE),
+        FileOutcome::Cancel
+    );
+
+    let mut state = open(&dir);
+    state.cancel_button = None;
+    assert_eq!(
+        handle(&mut state, KeyCode::Esc, KeyModifiers::NONE),
+        FileOutcome::Unchanged
+    );
+    assert!(state.active);
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_recent_and_purpose() {
+    let dir = dir("recent");
+    let sub = dir.join("sub");
+    fs::create_dir_all(&sub).expect("dir");
+    fs::write(sub.join("b.txt"), "b").expect("file");
+
+    let mut state = FileDialogState::new();
+    state.use_default_roots(false);
+
+    state.set_purpose(Some("export"));
+    state.open_dialog(&sub).expect("open");
+    render(&mut state);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(sub.join("b.txt"))
+    );
+    assert_eq!(state.recent(), std::slice::from_ref(&sub));
+    assert_eq!(state.last_dirs(), [("export".to_string(), sub.clone())]);
+
+    state.set_purpose(Some("import"));
+    state.open_dialog(&dir).expect("open");
+    render(&mut state);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(dir.join("a.txt"))
+    );
+    assert_eq!(state.recent(), [dir.clone(), sub.clone()]);
+
+    // starts where the last export went.
+    state.set_purpose(Some("export"));
+    state.open_dialog(&dir).expect("open");
+    render(&mut state);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(sub.join("b.txt"))
+    );
+    assert_eq!(state.recent(), [sub.clone(), dir.clone()]);
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_next_root() {
+    let dir = dir("next-root");
+    let sub = dir.join("sub");
+    fs::create_dir_all(&sub).expect("dir");
+    let file = sub.join("b.txt");
+    fs::write(&file, "b").expect("file");
+
+    let mut state = FileDialogState::new();
+    state.use_default_roots(false);
+    state.add_root("dir", &dir);
+    state.set_recent(vec![sub]);
+    state.open_dialog(&dir).expect("open");
+    render(&mut state);
+
+    // roots, then recent
+    for _ in 0..2 {
+        assert_eq!(
+            handle(&mut state, KeyCode::Char('b'), KeyModifiers::CONTROL),
+            FileOutcome::Changed
+        );
+    }
+    render(&mut state);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(file)
+    );
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+fn screen(state: &mut FileDialogState) -> String {
+    let area = Rect::new(0, 0, 80, 24);
+    let mut buf = Buffer::empty(area);
+    FileDialog::new().render(area, &mut buf, state);
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Focus the path field and replace its text.
+fn type_path(state: &mut FileDialogState, txt: &str) {
+    // roots -> path
+    handle(state, KeyCode::Tab, KeyModifiers::NONE);
+    handle(state, KeyCode::Char('a'), KeyModifiers::CONTROL);
+    handle(state, KeyCode::Backspace, KeyModifiers::NONE);
+    for c in txt.chars() {
+        handle(state, KeyCode::Char(c), KeyModifiers::NONE);
+    }
+}
+
+fn completion_dir(name: &str) -> PathBuf {
+    let dir = dir(name);
+    fs::write(dir.join("alpha.txt"), "a").expect("file");
+    fs::create_dir_all(dir.join("sub")).expect("dir");
+    fs::write(dir.join("sub").join("b.txt"), "b").expect("file");
+    dir
+}
+
+#[test]
+fn test_complete_unique() {
+    let dir = completion_dir("complete-unique");
+
+    let mut state = open(&dir);
+    type_path(&mut state, &format!("{}/s", dir.display()));
+    assert_eq!(
+        handle(&mut state, KeyCode::Tab, KeyModifiers::NONE),
+        FileOutcome::Changed
+    );
+    assert!(screen(&mut state).contains(&format!("{}/sub/", dir.display())));
+
+    // enter goes there and selects the first file.
+    handle(&mut state, KeyCode::Enter, KeyModifiers::NONE);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(dir.join("sub").join("b.txt"))
+    );
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_complete_cycle() {
+    let dir = completion_dir("complete-cycle");
+
+    let mut state = open(&dir);
+    type_path(&mut state, &format!("{}/a", dir.display()));
+    handle(&mut state, KeyCode::Tab, KeyModifiers::NONE);
+    assert!(screen(&mut state).contains(&format!("{}/a.txt", dir.display())));
+    handle(&mut state, KeyCode::Tab, KeyModifiers::NONE);
+    assert!(screen(&mut state).contains(&format!("{}/alpha.txt", dir.display())));
+
+    // a file is selected in the list.
+    handle(&mut state, KeyCode::Enter, KeyModifiers::NONE);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(dir.join("alpha.txt"))
+    );
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_complete_fallthrough() {
+    let dir = completion_dir("complete-fallthrough");
+
+    let mut state = open(&dir);
+    // unchanged path, tab navigates.
+    handle(&mut state, KeyCode::Tab, KeyModifiers::NONE);
+    assert_eq!(
+        handle(&mut state, KeyCode::Tab, KeyModifiers::NONE),
+        FileOutcome::Changed
+    );
+    assert_eq!(
+        handle(&mut state, KeyCode::Char('x'), KeyModifiers::NONE),
+        FileOutcome::Unchanged
+    );
+
+    // no candidates.
+    let mut state = open(&dir);
+    type_path(&mut state, "zzz");
+    assert_eq!(
+        handle(&mut state, KeyCode::Tab, KeyModifiers::NONE),
+        FileOutcome::Unchanged
+    );
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_path_input() {
+    let dir = completion_dir("path-input");
+
+    // relative
+    let mut state = open(&dir);
+    type_path(&mut state, "sub");
+    handle(&mut state, KeyCode::Enter, KeyModifiers::NONE);
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Ok(dir.join("sub").join("b.txt"))
+    );
+
+    // doesn't exist
+    let mut state = open(&dir);
+    type_path(&mut state, "nothing");
+    assert_eq!(
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE),
+        FileOutcome::Changed
+    );
+    assert!(state.active);
+    assert!(screen(&mut state).contains("nothing"));
+
+    // home
+    if let Some(home) = std::env::var_os("HOME") {
+        let mut state = open(&dir);
+        type_path(&mut state, "~");
+        handle(&mut state, KeyCode::Enter, KeyModifiers::NONE);
+        assert!(screen(&mut state).contains(&*PathBuf::from(home).to_string_lossy()));
+    }
+
+    _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,119 @@
+use chrono::NaiveDate;
+use rat_widget::checkbox::CheckboxState;
+use rat_widget::choice::ChoiceState;
+use rat_widget::date_input::DateInputState;
+use rat_widget::form_binder::FormBinder;
+use rat_widget::number_input::NumberInputState;
+use rat_widget::text_input::TextInputState;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Kind {
+    #[default]
+    Private,
+    Business,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Data {
+    name: String,
+    age: u32,
+    active: bool,
+    kind: Kind,
+    birthday: NaiveDate,
+}
+
+struct Form {
+    name: TextInputState,
+    age: NumberInputState,
+    active: CheckboxState,
+    kind: ChoiceState<Kind>,
+    birthday: DateInputState,
+}
+
+impl Form {
+    fn new() -> Self {
+        Self {
+            name: TextInputState::named("name"),
+            age: NumberInputState::named("age")
+                .with_pattern("###")
+                .expect("pattern"),
+            active: CheckboxState::named("active"),
+            kind: ChoiceState::named("kind"),
+            birthday: DateInputState::named("birthday")
+                .with_pattern("%Y-%m-%d")
+                .expect("pattern"),
+        }
+    }
+}
+
+fn binder<'a>(form: &'a mut Form) -> FormBinder<'a, Data> {
+    let mut binder = FormBinder::new();
+    binder
+        .bind_text(&mut form.name, |d: &Data| &d.name, |d, v| d.name = v)
+        .bind_number(&mut form.age, |d: &Data| d.age, |d, v| d.age = v)
+        .bind_checkbox(&mut form.active, |d: &Data| d.active, |d, v| d.active = v)
+        .bind_choice(&mut form.kind, |d: &Data| d.kind, |d, v| d.kind = v)
+        .bind_date(
+            &mut form.birthday,
+            |d: &Data| d.birthday,
+            |d, v| d.birthday = v,
+        );
+    binder
+}
+
+fn data() -> Data {
+    Data {
+        name: "Kim".into(),
+        age: 42,
+        active: true,
+        kind: Kind::Business,
+        birthday: NaiveDate::from_ymd_opt(1982, 4, 1).expect("date"),
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let mut form = Form::new();
+    let data = data();
+
+    binder(&mut form).load(&data);
+    assert_eq!(form.name.text(), "Kim");
+    assert_eq!(form.age.value::<u32>(), Ok(42));
+    assert!(form.active.value());
+    assert_eq!(form.kind.value(), Kind::Business);
+    assert_eq!(
+        form.birthday.value(),
+        Ok(NaiveDate::from_ymd_opt(1982, 4, 1).expect("date"))
+    );
+
+    form.name.set_text("Alex");
+    form.active.set_value(false);
+
+    let mut stored = data.clone();
+    assert!(binder(&mut form).store(&mut stored).is_ok());
+    assert_eq!(stored.name, "Alex");
+    assert!(!stored.active);
+    assert_eq!(stored.age, 42);
+    assert_eq!(stored.kind, Kind::Business);
+}
+
+#[test]
+fn test_errors() {
+    let mut form = Form::new();
+    let data = data();
+
+    binder(&mut form).load(&data);
+
+    form.age.clear();
+    form.birthday.clear();
+
+    let mut stored = data.clone();
+    let errors = binder(&mut form).store(&mut stored).expect_err("errors");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].focus.name(), "age");
+    assert_eq!(errors[1].focus.name(), "birthday");
+    assert!(form.age.get_invalid());
+    assert!(form.birthday.get_invalid());
+    // unchanged
+    assert_eq!(stored, data);
+}
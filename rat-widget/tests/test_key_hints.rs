@@ -0,0 +1,85 @@
+use rat_focus::{FocusBuilder, FocusFlag};
+use rat_widget::key_hints::{KeyHint, KeyHints, KeyHintsState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+static LIST: &[KeyHint] = &[
+    KeyHint::new("Enter", "open").priority(2),
+    KeyHint::new("F2", "rename"),
+    KeyHint::new("Del", "delete").priority(1),
+];
+
+static GLOBAL: &[KeyHint] = &[KeyHint::new("F1", "help")];
+
+fn line(buf: &Buffer, width: u16) -> String {
+    (0..width).map(|x| buf[(x, 0)].symbol()).collect()
+}
+
+#[test]
+fn test_focused() {
+    let list = FocusFlag::named("list");
+    let player = FocusFlag::named("player");
+
+    let mut state = KeyHintsState::new();
+    state.register(&list, LIST);
+    state.set_default(GLOBAL);
+
+    let mut fb = FocusBuilder::default();
+    fb.widget(&list).widget(&player);
+    let focus = fb.build();
+
+    focus.focus(&list);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 40, 1));
+    KeyHints::new()
+        .focus(&focus)
+        .render(Rect::new(0, 0, 40, 1), &mut buf, &mut state);
+    assert_eq!(line(&buf, 40), "Enter open  F2 rename  Del delete       ");
+    assert_eq!(state.areas.len(), 3);
+    assert_eq!(state.areas[1], Rect::new(12, 0, 9, 1));
+    assert!(state.collapsed.is_empty());
+
+    // nothing registered
+    focus.focus(&player);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 40, 1));
+    KeyHints::new()
+        .focus(&focus)
+        .render(Rect::new(0, 0, 40, 1), &mut buf, &mut state);
+    assert_eq!(line(&buf, 7), "F1 help");
+
+    // dynamic
+    state.set_dynamic(&player, vec![KeyHint::new("Space", "pause")]);
+    assert_eq!(state.hints(Some(&player))[0].text, "pause");
+    state.set_dynamic(&player, vec![KeyHint::owned("Space", "resume")]);
+    assert_eq!(state.hints(Some(&player))[0].text, "resume");
+    state.clear_dynamic(&player);
+    assert_eq!(state.hints(Some(&player)), GLOBAL);
+}
+
+#[test]
+fn test_collapse() {
+    let list = FocusFlag::named("list");
+    let mut state = KeyHintsState::new();
+    state.register(&list, LIST);
+
+    let mut fb = FocusBuilder::default();
+    fb.widget(&list);
+    let focus = fb.build();
+    focus.focus(&list);
+
+    // F2 goes first
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 1));
+    KeyHints::new()
+        .focus(&focus)
+        .render(Rect::new(0, 0, 30, 1), &mut buf, &mut state);
+    assert_eq!(state.collapsed, vec![1]);
+    assert_eq!(line(&buf, 30), "Enter open  Del delete        ");
+
+    // then Del
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+    KeyHints::new()
+        .focus(&focus)
+        .render(Rect::new(0, 0, 12, 1), &mut buf, &mut state);
+    assert_eq!(state.collapsed, vec![1, 2]);
+    assert_eq!(state.areas, vec![Rect::new(0, 0, 10, 1)]);
+}
@@ -0,0 +1,117 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::{Dialog, HandleEvent, Outcome};
+use rat_widget::keybindings::{HelpOverlay, HelpOverlayState, KeyBindings};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn bindings() -> KeyBindings {
+    let mut keys = KeyBindings::new();
+    keys.add("Global", KeyCode::F(1), KeyModifiers::NONE, "Help")
+        .add("Global", KeyCode::Char('q'), KeyModifiers::CONTROL, "Quit")
+        .add("Editor", KeyCode::Char('s'), KeyModifiers::CONTROL, "Save")
+        .add("Editor", KeyCode::BackTab, KeyModifiers::SHIFT, "Previous")
+        .add("Editor", KeyCode::Char('P'), KeyModifiers::SHIFT, "Print");
+    keys
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+fn render(keys: &KeyBindings, state: &mut HelpOverlayState) -> Vec<String> {
+    let area = Rect::new(0, 0, 20, 8);
+    let mut buf = Buffer::empty(area);
+    HelpOverlay::new(keys).render(area, &mut buf, state);
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn test_lookup() {
+    let keys = bindings();
+
+    let ev = key(KeyCode::Char('s'), KeyModifiers::CONTROL);
+    let found = keys
+        .lookup(&ev)
+        .map(|v| v.description.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(found, vec!["Save"]);
+
+    let ev = key(KeyCode::Char('P'), KeyModifiers::NONE);
+    assert_eq!(keys.lookup(&ev).count(), 1);
+    let ev = key(KeyCode::Char('p'), KeyModifiers::NONE);
+    assert_eq!(keys.lookup(&ev).count(), 0);
+
+    let ev = key(KeyCode::Char('q'), KeyModifiers::CONTROL);
+    assert_eq!(keys.lookup_in("Editor", &ev).count(), 0);
+    assert_eq!(keys.lookup_in("Global", &ev).count(), 1);
+}
+
+#[test]
+fn test_chord() {
+    let keys = bindings();
+    let chords = keys
+        .bindings()
+        .iter()
+        .map(|v| v.chord())
+        .collect::<Vec<_>>();
+    assert_eq!(chords, vec!["F1", "Ctrl+Q", "Ctrl+S", "Shift+Tab", "P"]);
+}
+
+#[test]
+fn test_overlay() {
+    let keys = bindings();
+    let mut state = HelpOverlayState::new();
+
+    assert_eq!(render(&keys, &mut state), vec![""; 8]);
+
+    state.set_active(true);
+    assert_eq!(
+        render(&keys, &mut state),
+        vec![
+            "Filter:",
+            "Global",
+            " F1         Help",
+            " Ctrl+Q     Quit",
+            "",
+            "Editor",
+            " Ctrl+S     Save",
+            " Shift+Tab  Previous",
+        ]
+    );
+
+    for c in "pr".chars() {
+        let ev = Event::Key(key(KeyCode::Char(c), KeyModifiers::NONE));
+        assert_eq!(state.handle(&ev, Dialog), Outcome::Changed);
+    }
+    assert_eq!(
+        render(&keys, &mut state),
+        vec![
+            "Filter: pr",
+            "Editor",
+            " Shift+Tab  Previous",
+            " P          Print",
+            "",
+            "",
+            "",
+            "",
+        ]
+    );
+
+    // modal
+    let ev = Event::Key(key(KeyCode::F(5), KeyModifiers::NONE));
+    assert_eq!(state.handle(&ev, Dialog), Outcome::Unchanged);
+
+    let ev = Event::Key(key(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(state.handle(&ev, Dialog), Outcome::Changed);
+    assert!(!state.active());
+    assert_eq!(state.handle(&ev, Dialog), Outcome::Continue);
+}
@@ -1,4 +1,4 @@
-use rat_widget::layout::{FormLabel, FormWidget, LayoutForm};
+use rat_widget::layout::{FormFlow, FormLabel, FormWidget, LayoutForm};
 use ratatui::layout::{Rect, Size};
 use ratatui::widgets::{Block, Padding};
 
@@ -128,3 +128,133 @@ fn test_overflow2() {
     let l = layout.paged(Size::new(100, u16::MAX), Padding::new(0, 0, 1, 1));
     dbg!(l);
 }
+
+#[test]
+fn test_columns_down() {
+    let mut layout = LayoutForm::<i32>::new().columns(2);
+
+    layout.widget(1, FormLabel::Str("a"), FormWidget::Width(10));
+    layout.widget(2, FormLabel::Str("bbbb"), FormWidget::Width(10));
+    layout.widget(3, FormLabel::Str("cc"), FormWidget::Width(10));
+    layout.widget(4, FormLabel::Str("d"), FormWidget::Width(10));
+
+    let g = layout.endless(40, Padding::default());
+
+    // col_width = (40 - 2) / 2
+    assert_eq!(g.label_for(1), Rect::new(0, 0, 4, 1));
+    assert_eq!(g.widget_for(1), Rect::new(5, 0, 10, 1));
+    assert_eq!(g.widget_for(2), Rect::new(5, 1, 10, 1));
+    assert_eq!(g.label_for(3), Rect::new(21, 0, 2, 1));
+    assert_eq!(g.widget_for(3), Rect::new(24, 0, 10, 1));
+    assert_eq!(g.widget_for(4), Rect::new(24, 1, 10, 1));
+    // definition order
+    assert_eq!(g.widget_key(2), 3);
+}
+
+#[test]
+fn test_columns_across() {
+    let mut layout = LayoutForm::<i32>::new().columns(2).flow(FormFlow::Across);
+
+    layout.widget(1, FormLabel::Str("a"), FormWidget::Width(10));
+    layout.widget(2, FormLabel::Str("bbbb"), FormWidget::Width(10));
+    layout.widget(3, FormLabel::Str("cc"), FormWidget::Width(10));
+    layout.widget(4, FormLabel::Str("d"), FormWidget::Width(10));
+
+    let g = layout.endless(40, Padding::default());
+
+    assert_eq!(g.widget_for(1), Rect::new(3, 0, 10, 1));
+    assert_eq!(g.widget_for(2), Rect::new(26, 0, 10, 1));
+    assert_eq!(g.widget_for(3), Rect::new(3, 1, 10, 1));
+    assert_eq!(g.widget_for(4), Rect::new(26, 1, 10, 1));
+}
+
+#[test]
+fn test_columns_break() {
+    let mut layout = LayoutForm::<i32>::new().columns(2);
+
+    layout.widget(1, FormLabel::Str("a"), FormWidget::Width(10));
+    layout.column_break();
+    layout.widget(2, FormLabel::Str("b"), FormWidget::Width(10));
+    layout.widget(3, FormLabel::Str("c"), FormWidget::Width(10));
+    layout.widget(4, FormLabel::Str("d"), FormWidget::Width(10));
+
+    let g = layout.endless(40, Padding::default());
+
+    assert_eq!(g.widget_for(1).y, 0);
+    assert_eq!(g.widget_for(2), Rect::new(23, 0, 10, 1));
+    assert_eq!(g.widget_for(3), Rect::new(23, 1, 10, 1));
+    assert_eq!(g.widget_for(4), Rect::new(23, 2, 10, 1));
+}
+
+#[test]
+fn test_columns_paged() {
+    let mut layout = LayoutForm::<i32>::new().columns(2);
+    for i in 1..=8 {
+        layout.widget(i, FormLabel::Str("a"), FormWidget::Width(10));
+    }
+
+    let g = layout.paged(Size::new(40, 3), Padding::default());
+
+    assert_eq!(g.page_count(), 2);
+    assert_eq!(g.widget_for(3).y, 2);
+    assert_eq!(g.widget_for(4), Rect::new(23, 0, 10, 1));
+    assert_eq!(g.widget_for(6), Rect::new(23, 2, 10, 1));
+    assert_eq!(g.page_of(7), Some(1));
+    assert_eq!(g.widget_for(7), Rect::new(2, 3, 10, 1));
+    assert_eq!(g.widget_for(8), Rect::new(23, 3, 10, 1));
+}
+
+#[test]
+fn test_columns_header_span() {
+    let mut layout = LayoutForm::<i32>::new().columns(2);
+
+    layout.header("General");
+    layout.widget(1, FormLabel::Str("a"), FormWidget::Width(10));
+    layout.widget(2, FormLabel::Str("b"), FormWidget::Width(10));
+    layout.widget_span(3, FormLabel::Str("text"), FormWidget::StretchX(10, 3));
+    layout.header("More");
+    layout.widget(4, FormLabel::Str("d"), FormWidget::Width(10));
+
+    let g = layout.endless(40, Padding::default());
+
+    assert_eq!(g.block_len(), 2);
+    assert_eq!(g.block_area(0), Rect::new(0, 0, 40, 1));
+    assert_eq!(g.widget_for(1), Rect::new(2, 1, 10, 1));
+    assert_eq!(g.widget_for(2), Rect::new(23, 1, 10, 1));
+    // label width of the first column
+    assert_eq!(g.label_for(3), Rect::new(0, 2, 1, 1));
+    assert_eq!(g.widget_for(3), Rect::new(2, 2, 38, 3));
+    assert_eq!(g.block_area(1), Rect::new(0, 5, 40, 1));
+    assert_eq!(g.widget_for(4), Rect::new(2, 6, 10, 1));
+}
+
+#[test]
+fn test_header() {
+    let mut layout = LayoutForm::<i32>::new();
+
+    layout.widget(1, FormLabel::Str("a"), FormWidget::Width(10));
+    layout.header("Section");
+    layout.widget(2, FormLabel::Str("b"), FormWidget::Width(10));
+
+    let g = layout.endless(40, Padding::default());
+
+    assert_eq!(g.block_len(), 1);
+    // spans the form width.
+    assert_eq!(g.block_area(0), Rect::new(0, 1, 12, 1));
+    assert_eq!(g.widget_for(2).y, 2);
+}
+
+#[test]
+fn test_max_label() {
+    let mut layout = LayoutForm::<i32>::new().max_label(4);
+
+    layout.widget(1, FormLabel::Str("Description"), FormWidget::Width(10));
+    layout.widget(2, FormLabel::Str("Id"), FormWidget::Width(10));
+
+    let g = layout.endless(40, Padding::default());
+
+    assert_eq!(g.label_for(1).width, 4);
+    assert_eq!(g.label_str_for(1), "Des…");
+    assert_eq!(g.label_str_for(2), "Id");
+    assert_eq!(g.widget_for(1).x, 5);
+}
@@ -0,0 +1,159 @@
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, Outcome, Regular};
+use rat_scrolled::Scroll;
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListContext, ListData, ListState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{StatefulWidget, Widget};
+
+/// Item n has a height of 1 + n%3.
+struct Data(usize);
+
+impl ListData for Data {
+    fn rows(&self) -> usize {
+        self.0
+    }
+
+    fn item_height(&self, n: usize) -> u16 {
+        1 + (n % 3) as u16
+    }
+
+    fn render_item(&self, ctx: &ListContext, n: usize, area: Rect, buf: &mut Buffer) {
+        let text = if ctx.selected {
+            format!("*{}", n)
+        } else {
+            format!("{}", n)
+        };
+        text.render(area, buf);
+        for y in area.top() + 1..area.bottom() {
+            buf[(area.x, y)].set_symbol(".");
+        }
+    }
+}
+
+fn render(state: &mut ListState<RowSelection>) -> Buffer {
+    let area = Rect::new(0, 0, 10, 6);
+    let mut buf = Buffer::empty(area);
+    List::default()
+        .data(Data(10))
+        .scroll(Scroll::new().scroll_by(3))
+        .select_style(Style::new().bg(Color::Red))
+        .render(area, &mut buf, state);
+    buf
+}
+
+fn lines(buf: &Buffer) -> Vec<String> {
+    (0..buf.area.height)
+        .map(|y| {
+            (0..3)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim()
+                .to_string()
+        })
+        .collect()
+}
+
+fn mouse(kind: MouseEventKind, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: 1,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_render() {
+    let mut state = ListState::<RowSelection>::new();
+    state.select(Some(1));
+    let buf = render(&mut state);
+
+    assert_eq!(state.item_pos, vec![0, 1, 3, 6, 7, 9, 12, 13, 15, 18, 19]);
+    assert_eq!(lines(&buf), vec!["0", "*1", ".", "2", ".", "."]);
+    assert_eq!(
+        state.row_areas,
+        vec![
+            Rect::new(0, 0, 9, 1),
+            Rect::new(0, 1, 9, 2),
+            Rect::new(0, 3, 9, 3)
+        ]
+    );
+    // the whole item has the selection style.
+    assert_eq!(buf[(5, 2)].bg, Color::Red);
+    assert_ne!(buf[(5, 3)].bg, Color::Red);
+    // the last items fit in 6 lines.
+    assert_eq!(state.max_offset(), 7);
+}
+
+#[test]
+fn test_scroll_to_selected() {
+    let mut state = ListState::<RowSelection>::new();
+    render(&mut state);
+
+    // item 4 ends at line 9, needs an offset of 2.
+    assert!(state.move_to(4));
+    assert_eq!(state.offset(), 2);
+    let buf = render(&mut state);
+    assert_eq!(lines(&buf), vec!["2", ".", ".", "3", "*4", "."]);
+
+    // visible, no change.
+    assert!(state.move_to(3));
+    assert_eq!(state.offset(), 2);
+
+    assert!(state.move_to(0));
+    assert_eq!(state.offset(), 0);
+}
+
+#[test]
+fn test_click() {
+    let mut state = ListState::<RowSelection>::new();
+    render(&mut state);
+    state.set_offset(2);
+    render(&mut state);
+
+    let r = state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 5), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(state.selected(), Some(4));
+    let r = state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 2), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(state.selected(), Some(2));
+}
+
+#[test]
+fn test_wheel() {
+    let mut state = ListState::<RowSelection>::new();
+    render(&mut state);
+
+    // 3 lines are the items 0 and 1.
+    let r = state.handle(&mouse(MouseEventKind::ScrollDown, 1), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(state.offset(), 2);
+    // item 2 is 3 lines high.
+    state.handle(&mouse(MouseEventKind::ScrollDown, 1), Regular);
+    assert_eq!(state.offset(), 3);
+    state.handle(&mouse(MouseEventKind::ScrollUp, 1), Regular);
+    assert_eq!(state.offset(), 2);
+    state.handle(&mouse(MouseEventKind::ScrollUp, 1), Regular);
+    assert_eq!(state.offset(), 0);
+}
+
+#[test]
+fn test_items_changed() {
+    let mut state = ListState::<RowSelection>::new();
+    render(&mut state);
+    assert_eq!(state.item_pos.len(), 11);
+
+    state.items_added(10, 2);
+    assert!(state.item_pos.is_empty());
+
+    let area = Rect::new(0, 0, 10, 6);
+    let mut buf = Buffer::empty(area);
+    List::default()
+        .data(Data(12))
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.item_pos.len(), 13);
+    assert_eq!(state.item_pos[12], 24);
+}
@@ -0,0 +1,122 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Outcome, Regular};
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut ListState<RowSelection>) -> Buffer {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    List::new((0..20).map(|v| format!("{}", v))).render(area, &mut buf, state);
+    buf
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+fn mouse(kind: MouseEventKind, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: 1,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn state() -> ListState<RowSelection> {
+    let mut state = ListState::<RowSelection>::new();
+    state.reorder = true;
+    state.focus.set(true);
+    render(&mut state);
+    state.select(Some(2));
+    state
+}
+
+#[test]
+fn test_drag_up() {
+    let mut state = state();
+
+    // not the selected item
+    let r = state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 1), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(state.drag_anchor, None);
+    state.handle(&mouse(MouseEventKind::Up(MouseButton::Left), 1), Regular);
+    state.select(Some(2));
+
+    // the mouse-down is a normal click.
+    let r = state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 2), Regular);
+    assert_eq!(r, Outcome::Unchanged);
+    assert_eq!(state.drag_anchor, Some(2));
+    assert!(!state.is_dragging());
+
+    // the drag starts with the first mouse drag.
+    let r = state.handle(&mouse(MouseEventKind::Drag(MouseButton::Left), 1), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert!(state.is_dragging());
+    assert_eq!(state.drag_hover, Some(1));
+
+    // indicator below item 0
+    let buf = render(&mut state);
+    assert!(buf[(0, 0)].modifier.contains(Modifier::UNDERLINED));
+    assert!(!buf[(0, 1)].modifier.contains(Modifier::UNDERLINED));
+
+    let r = state.handle(&mouse(MouseEventKind::Up(MouseButton::Left), 1), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(state.take_moved(), Some((2, 1)));
+    assert_eq!(state.take_moved(), None);
+    assert_eq!(state.selected(), Some(1));
+}
+
+#[test]
+fn test_click() {
+    let mut state = state();
+
+    // a click without drag doesn't start a reorder.
+    state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 2), Regular);
+    let r = state.handle(&mouse(MouseEventKind::Up(MouseButton::Left), 2), Regular);
+    assert_eq!(r, Outcome::Continue);
+    assert_eq!(state.drag_anchor, None);
+    assert!(!state.is_dragging());
+    assert_eq!(state.take_moved(), None);
+    assert_eq!(state.selected(), Some(2));
+}
+
+#[test]
+fn test_drag_cancel() {
+    let mut state = state();
+
+    state.handle(&mouse(MouseEventKind::Down(MouseButton::Left), 2), Regular);
+    state.handle(&mouse(MouseEventKind::Drag(MouseButton::Left), 3), Regular);
+    assert_eq!(
+        state.handle(&key(KeyCode::Esc, KeyModifiers::NONE), Regular),
+        Outcome::Changed
+    );
+    assert!(!state.is_dragging());
+    assert_eq!(state.drag_hover, None);
+    assert_eq!(state.selected(), Some(2));
+}
+
+#[test]
+fn test_keyboard() {
+    let mut state = state();
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Down, KeyModifiers::ALT), Regular),
+        Outcome::Changed
+    );
+    assert_eq!(state.take_moved(), Some((2, 3)));
+    assert_eq!(state.selected(), Some(3));
+
+    state.reorder = false;
+    assert_eq!(
+        state.handle(&key(KeyCode::Down, KeyModifiers::ALT), Regular),
+        Outcome::Continue
+    );
+    assert_eq!(state.take_moved(), None);
+}
@@ -0,0 +1,66 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::{HandleEvent, Popup};
+use rat_widget::event::MenuOutcome;
+use rat_widget::menu::{Menubar, MenubarState, RecentList};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+use std::path::PathBuf;
+
+fn render(recent: &RecentList<PathBuf>, state: &mut MenubarState) {
+    let area = Rect::new(0, 0, 40, 1);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+    let (line, popup) = Menubar::new(recent).popup_width(30).into_widgets();
+    line.render(area, &mut buf, state);
+    popup.render(area, &mut buf, state);
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn test_recent() {
+    let mut recent = RecentList::new(3);
+    recent.add(PathBuf::from("a"));
+    recent.add(PathBuf::from("b"));
+    recent.add(PathBuf::from("a"));
+    assert_eq!(recent.to_vec(), [PathBuf::from("a"), PathBuf::from("b")]);
+
+    recent.add(PathBuf::from("c"));
+    recent.add(PathBuf::from("d"));
+    assert_eq!(
+        recent.to_vec(),
+        [PathBuf::from("d"), PathBuf::from("c"), PathBuf::from("a")]
+    );
+
+    let restored = RecentList::from_vec(3, recent.to_vec());
+    assert_eq!(restored.to_vec(), recent.to_vec());
+}
+
+#[test]
+fn test_payload() {
+    let mut recent = RecentList::new(5);
+    recent.set_max_width(11);
+    recent.add(PathBuf::from("/home/user/projects/long/file.txt"));
+    recent.add(PathBuf::from("short.txt"));
+
+    let mut state = MenubarState::named("menu");
+    state.bar.focus.set(true);
+    state.bar.select(Some(0));
+    state.set_popup_active(true);
+    render(&recent, &mut state);
+
+    assert_eq!(state.popup.item_areas.len(), 2);
+    assert!(state.payload(0, 1).is_some());
+    assert!(state.payload(1, 1).is_none());
+
+    state.popup.select(Some(1));
+    let r = state.handle(&key(KeyCode::Enter), Popup);
+    assert_eq!(r, MenuOutcome::MenuActivatedPayload(0, 1));
+    let path = state
+        .payload(0, 1)
+        .and_then(|v| v.downcast_ref::<PathBuf>())
+        .expect("payload");
+    assert_eq!(path, &PathBuf::from("/home/user/projects/long/file.txt"));
+}
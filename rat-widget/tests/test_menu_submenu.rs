@@ -0,0 +1,198 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, Popup};
+use rat_widget::event::MenuOutcome;
+use rat_widget::menu::{MenuItem, PopupConstraint, PopupMenu, PopupMenuState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::widgets::{Block, StatefulWidget};
+use std::time::Duration;
+
+fn render(x: u16, state: &mut PopupMenuState) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 60, 20));
+    PopupMenu::new()
+        .item_str("Open")
+        .item(MenuItem::new_str("Recent").submenu(vec![
+            MenuItem::new_str("a.txt"),
+            MenuItem::new_str("More").submenu(vec![
+                MenuItem::new_str("x.txt"),
+                MenuItem::new_str("y.txt"),
+            ]),
+        ]))
+        .item_str("Quit")
+        .width(10)
+        .block(Block::bordered())
+        .constraint(PopupConstraint::Below(
+            Alignment::Left,
+            Rect::new(x, 0, 1, 1),
+        ))
+        .render(Rect::default(), &mut buf, state);
+    buf
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn moved(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Moved,
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn active() -> PopupMenuState {
+    let mut state = PopupMenuState::new();
+    state.set_active(true);
+    state
+}
+
+#[test]
+fn test_keyboard() {
+    let mut state = active();
+    let buf = render(0, &mut state);
+    assert_eq!(state.has_submenu, vec![false, true, false]);
+    let marker = state.item_areas[1].right() - 1;
+    assert_eq!(buf[(marker, state.item_areas[1].y)].symbol(), "\u{25B8}");
+
+    state.select(Some(1));
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Popup),
+        MenuOutcome::Changed
+    );
+    render(0, &mut state);
+    assert!(state.is_submenu_open());
+    let sub = state.submenu.as_ref().expect("submenu");
+    assert_eq!(sub.selected, Some(0));
+    // right of the parent, first items on the same row.
+    assert_eq!(sub.popup.area.x, state.popup.area.right());
+    assert_eq!(sub.item_areas[0].y, state.item_areas[1].y);
+
+    // second level.
+    assert!(matches!(
+        state.handle(&key(KeyCode::Down), Popup),
+        MenuOutcome::Selected(1)
+    ));
+    assert_eq!(state.selected, Some(1));
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Popup),
+        MenuOutcome::Changed
+    );
+    render(0, &mut state);
+    assert_eq!(state.submenu_areas().len(), 2);
+
+    // left closes one level.
+    assert_eq!(
+        state.handle(&key(KeyCode::Left), Popup),
+        MenuOutcome::Changed
+    );
+    render(0, &mut state);
+    assert_eq!(state.submenu_areas().len(), 1);
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Popup),
+        MenuOutcome::Changed
+    );
+    render(0, &mut state);
+
+    // activate a leaf.
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Popup),
+        MenuOutcome::Selected(1)
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Popup),
+        MenuOutcome::SubmenuActivated(1, 1)
+    );
+    assert_eq!(state.activated_path(), &[1, 1, 1]);
+    assert!(!state.is_active());
+    assert!(state.submenu.is_none());
+}
+
+#[test]
+fn test_esc() {
+    let mut state = active();
+    render(0, &mut state);
+    state.select(Some(1));
+    state.open_submenu();
+    render(0, &mut state);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Esc), Popup),
+        MenuOutcome::Changed
+    );
+    assert!(state.is_active());
+    assert!(!state.is_submenu_open());
+}
+
+#[test]
+fn test_flip() {
+    let mut state = active();
+    render(50, &mut state);
+    state.select(Some(1));
+    state.open_submenu();
+    render(50, &mut state);
+
+    let sub = state.submenu.as_ref().expect("submenu");
+    assert_eq!(sub.popup.area.right(), state.popup.area.x);
+}
+
+#[test]
+fn test_hover() {
+    let mut state = active();
+    render(0, &mut state);
+    let recent = state.item_areas[1];
+
+    assert_eq!(
+        state.handle(&moved(recent.x + 1, recent.y), Popup),
+        MenuOutcome::Selected(1)
+    );
+    assert!(!state.is_submenu_open());
+    assert_eq!(
+        state.tick(Duration::from_millis(100)),
+        MenuOutcome::Unchanged
+    );
+    assert!(!state.is_submenu_open());
+    assert_eq!(state.tick(Duration::from_millis(250)), MenuOutcome::Changed);
+    assert!(state.is_submenu_open());
+    render(0, &mut state);
+    // no selection when opened by the mouse.
+    assert_eq!(state.submenu.as_ref().expect("submenu").selected, None);
+}
+
+#[test]
+fn test_safe_triangle() {
+    let mut state = active();
+    render(0, &mut state);
+    let recent = state.item_areas[1];
+    let quit = state.item_areas[2];
+
+    state.handle(&moved(recent.x + 2, recent.y), Popup);
+    state.tick(Duration::from_millis(300));
+    render(0, &mut state);
+    assert!(state.is_submenu_open());
+
+    // moving towards the submenu keeps it open.
+    assert_eq!(
+        state.handle(&moved(quit.right() - 1, quit.y), Popup),
+        MenuOutcome::Unchanged
+    );
+    assert_eq!(state.selected, Some(1));
+    assert!(state.is_submenu_open());
+
+    // until the mouse stays there.
+    assert_eq!(state.tick(Duration::from_millis(300)), MenuOutcome::Changed);
+    assert_eq!(state.selected, Some(2));
+    assert!(!state.is_submenu_open());
+
+    // moving away switches immediately.
+    state.handle(&moved(recent.x, recent.y), Popup);
+    state.tick(Duration::from_millis(300));
+    render(0, &mut state);
+    assert!(state.is_submenu_open());
+    assert_eq!(
+        state.handle(&moved(quit.x, quit.y), Popup),
+        MenuOutcome::Selected(2)
+    );
+    assert!(!state.is_submenu_open());
+}
@@ -0,0 +1,160 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Popup};
+use rat_widget::event::MenuOutcome;
+use rat_widget::menu::{Menubar, MenubarState, StaticMenu};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+static MENU: StaticMenu = StaticMenu {
+    menu: &[
+        ("_File", &["_Open", "_Save"]),
+        ("_Edit", &["_Copy"]),
+        ("_View", &["_Zoom"]),
+        ("_Tools", &["_Options", "_Plugins"]),
+        ("_Help", &["_About"]),
+    ],
+};
+
+fn render(width: u16, state: &mut MenubarState) -> Buffer {
+    let area = Rect::new(0, 0, width, 1);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+    let (line, popup) = Menubar::new(&MENU).popup_width(12).into_widgets();
+    line.render(area, &mut buf, state);
+    popup.render(area, &mut buf, state);
+    buf
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn focused() -> MenubarState {
+    let state = MenubarState::named("menu");
+    state.bar.focus.set(true);
+    state
+}
+
+#[test]
+fn test_fits() {
+    let mut state = focused();
+    render(40, &mut state);
+    assert_eq!(state.bar.overflow, None);
+    assert_eq!(state.bar.item_areas.len(), 5);
+    assert_eq!(state.bar.item_areas[4], Rect::new(21, 0, 4, 1));
+}
+
+#[test]
+fn test_layout() {
+    let mut state = focused();
+    let buf = render(16, &mut state);
+
+    // File Edit View ≡
+    assert_eq!(state.bar.overflow, Some(3));
+    assert_eq!(state.bar.overflow_area, Rect::new(15, 0, 1, 1));
+    assert_eq!(state.bar.len(), 5);
+    assert_eq!(state.bar.item_areas[2], Rect::new(10, 0, 4, 1));
+    assert_eq!(state.bar.item_areas[3], state.bar.overflow_area);
+    assert_eq!(state.bar.item_areas[4], state.bar.overflow_area);
+    assert_eq!(buf[(15, 0)].symbol(), "\u{2261}");
+    assert!(state.bar.is_hidden(3));
+    assert!(!state.bar.is_hidden(2));
+
+    // the title of a selected hidden menu.
+    state.bar.select(Some(3));
+    let buf = render(20, &mut state);
+    assert_eq!(state.bar.overflow_area, Rect::new(15, 0, 5, 1));
+    assert_eq!(buf[(17, 0)].symbol(), "T");
+}
+
+#[test]
+fn test_keyboard() {
+    let mut state = focused();
+    state.bar.select(Some(2));
+    state.set_popup_active(true);
+    render(16, &mut state);
+
+    // into the overflow.
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Popup),
+        MenuOutcome::Selected(3)
+    );
+    render(16, &mut state);
+    assert!(state.popup_active());
+    assert!(!state.overflow_list);
+    assert_eq!(state.popup.len(), 2);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Popup),
+        MenuOutcome::Selected(4)
+    );
+    render(16, &mut state);
+    assert_eq!(state.popup.len(), 1);
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Popup),
+        MenuOutcome::Unchanged
+    );
+
+    // back again.
+    assert_eq!(
+        state.handle(&key(KeyCode::Left), Popup),
+        MenuOutcome::Selected(3)
+    );
+    render(16, &mut state);
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Popup),
+        MenuOutcome::MenuSelected(3, 0)
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Popup),
+        MenuOutcome::MenuActivated(3, 0)
+    );
+}
+
+#[test]
+fn test_overflow_list() {
+    let mut state = focused();
+    render(16, &mut state);
+
+    assert_eq!(state.handle(&click(15, 0), Popup), MenuOutcome::Selected(3));
+    render(16, &mut state);
+    assert!(state.overflow_list);
+    assert_eq!(state.popup.len(), 2);
+    assert_eq!(state.selected(), (Some(3), None));
+    // popup below the overflow item.
+    assert_eq!(state.popup.popup.area.y, 1);
+
+    // selection follows in the menu line.
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Popup),
+        MenuOutcome::Selected(4)
+    );
+    assert_eq!(state.bar.selected(), Some(4));
+
+    // swap in the items of the menu.
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Popup),
+        MenuOutcome::Selected(4)
+    );
+    render(16, &mut state);
+    assert!(state.popup_active());
+    assert!(!state.overflow_list);
+    assert_eq!(state.popup.len(), 1);
+
+    state.popup.select(Some(0));
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Popup),
+        MenuOutcome::MenuActivated(4, 0)
+    );
+}
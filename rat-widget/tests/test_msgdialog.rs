@@ -0,0 +1,118 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::{Dialog, HandleEvent, Outcome};
+use rat_widget::msgdialog::{MsgDialog, MsgDialogState};
+use rat_widget::text::clipboard::global_clipboard;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+fn render(state: &mut MsgDialogState, area: Rect) {
+    let mut buf = Buffer::empty(area);
+    MsgDialog::new()
+        .auto_height(50)
+        .render(area, &mut buf, state);
+}
+
+#[test]
+fn test_auto_height() {
+    let mut state = MsgDialogState::default();
+    state.append("short");
+
+    // border + padding + one line + spacer + buttons
+    render(&mut state, Rect::new(0, 0, 40, 40));
+    assert_eq!(state.area, Rect::new(0, 16, 40, 7));
+
+    // limited to 50%
+    let trace = (0..100)
+        .map(|v| format!("frame {}", v))
+        .collect::<Vec<_>>()
+        .join("\n");
+    state.append(&trace);
+    render(&mut state, Rect::new(0, 0, 40, 40));
+    assert_eq!(state.area, Rect::new(0, 10, 40, 20));
+}
+
+#[test]
+fn test_wrap_and_scroll() {
+    let mut state = MsgDialogState::default();
+    state.append(&"word ".repeat(100));
+    render(&mut state, Rect::new(0, 0, 30, 20));
+    assert_eq!(state.area.height, 10);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown, KeyModifiers::NONE), Dialog),
+        Outcome::Changed
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::End, KeyModifiers::NONE), Dialog),
+        Outcome::Changed
+    );
+
+    // a wider terminal needs fewer lines, the offset follows.
+    render(&mut state, Rect::new(0, 0, 200, 20));
+    assert_eq!(state.area.height, 9);
+    render(&mut state, Rect::new(0, 0, 30, 20));
+    assert_eq!(
+        state.handle(&key(KeyCode::End, KeyModifiers::NONE), Dialog),
+        Outcome::Changed
+    );
+}
+
+#[test]
+fn test_copy() {
+    let mut state = MsgDialogState::default();
+    state.append("first line");
+    state.append("second line");
+    render(&mut state, Rect::new(0, 0, 40, 40));
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('c'), KeyModifiers::CONTROL), Dialog),
+        Outcome::Unchanged
+    );
+    assert_eq!(
+        global_clipboard().get_string().expect("clip"),
+        "first line\nsecond line"
+    );
+    assert!(state.active());
+}
+
+#[test]
+fn test_default_keys() {
+    let mut state = MsgDialogState::default();
+    state.append("message");
+    render(&mut state, Rect::new(0, 0, 40, 20));
+
+    // Enter only for the focused button.
+    state.default_button.set(false);
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter, KeyModifiers::NONE), Dialog),
+        Outcome::Unchanged
+    );
+    assert!(state.active());
+
+    state.default_button.set(true);
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter, KeyModifiers::NONE), Dialog),
+        Outcome::Changed
+    );
+    assert!(!state.active());
+
+    state.append("message");
+    state.esc_close.set(false);
+    assert_eq!(
+        state.handle(&key(KeyCode::Esc, KeyModifiers::NONE), Dialog),
+        Outcome::Unchanged
+    );
+    assert!(state.active());
+
+    state.esc_close.set(true);
+    assert_eq!(
+        state.handle(&key(KeyCode::Esc, KeyModifiers::NONE), Dialog),
+        Outcome::Changed
+    );
+    assert!(!state.active());
+}
@@ -0,0 +1,187 @@
+use rat_widget::paragraph::{Paragraph, ParagraphState, StyledLinesBuffer};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{StatefulWidget, Wrap};
+use std::cmp::min;
+
+fn render(lines: usize, state: &mut ParagraphState) {
+    let text = (0..lines)
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    Paragraph::new(text).render(area, &mut buf, state);
+}
+
+#[test]
+fn test_follow_tail() {
+    let mut state = ParagraphState::new();
+    state.set_follow_tail(true);
+
+    render(5, &mut state);
+    assert!(state.is_following());
+    assert_eq!(state.line_offset(), 2);
+    render(8, &mut state);
+    assert_eq!(state.line_offset(), 5);
+
+    // scrolling up disengages.
+    state.scroll_up(1);
+    assert!(!state.is_following());
+    render(10, &mut state);
+    assert_eq!(state.line_offset(), 4);
+
+    // back at the end engages again.
+    state.set_line_offset(7);
+    assert!(state.is_following());
+    render(12, &mut state);
+    assert_eq!(state.line_offset(), 9);
+}
+
+#[test]
+fn test_no_follow_tail() {
+    let mut state = ParagraphState::new();
+    render(5, &mut state);
+    assert!(!state.is_following());
+    assert_eq!(state.line_offset(), 0);
+    render(8, &mut state);
+    assert_eq!(state.line_offset(), 0);
+}
+
+fn render_buffer(lines: &StyledLinesBuffer, wrap: bool, state: &mut ParagraphState) -> Buffer {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    let mut para = Paragraph::default().buffer(lines);
+    if wrap {
+        para = para.wrap(Wrap { trim: false });
+    }
+    para.render(area, &mut buf, state);
+    buf
+}
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[test]
+fn test_buffer_render() {
+    let mut lines = StyledLinesBuffer::new();
+    for n in 0..10 {
+        lines.push_line(format!("line {}", n));
+    }
+    lines.push_line(Line::from(vec![
+        Span::from("a").red(),
+        Span::from("b").style(Style::new().bg(Color::Blue)),
+    ]));
+    lines.push_line(Line::from("styled").green());
+
+    let mut state = ParagraphState::new();
+    let buf = render_buffer(&lines, false, &mut state);
+    assert_eq!(state.lines, 12);
+    assert_eq!(row(&buf, 0), "line 0");
+    assert_eq!(row(&buf, 2), "line 2");
+
+    state.set_line_offset(9);
+    let buf = render_buffer(&lines, false, &mut state);
+    assert_eq!(row(&buf, 0), "line 9");
+    assert_eq!(row(&buf, 1), "ab");
+    assert_eq!(buf[(0, 1)].fg, Color::Red);
+    assert_eq!(buf[(1, 1)].bg, Color::Blue);
+    assert_eq!(row(&buf, 2), "styled");
+    assert_eq!(buf[(0, 2)].fg, Color::Green);
+}
+
+#[test]
+fn test_buffer_max_lines() {
+    let mut lines = StyledLinesBuffer::with_max_lines(5);
+    for n in 0..8 {
+        lines.push_line(format!("line {}", n));
+    }
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines.evicted(), 3);
+    assert_eq!(lines.line(0), Some(&Line::from("line 3")));
+
+    lines.set_max_lines(Some(2));
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines.evicted(), 6);
+
+    lines.clear();
+    assert!(lines.is_empty());
+    assert_eq!(lines.evicted(), 8);
+}
+
+#[test]
+fn test_buffer_eviction_offset() {
+    let mut lines = StyledLinesBuffer::with_max_lines(10);
+    for n in 0..10 {
+        lines.push_line(format!("line {}", n));
+    }
+    let mut state = ParagraphState::new();
+    render_buffer(&lines, false, &mut state);
+    state.set_line_offset(5);
+    let buf = render_buffer(&lines, false, &mut state);
+    assert_eq!(row(&buf, 0), "line 5");
+
+    // two lines dropped at the front.
+    lines.push_line("line 10");
+    lines.push_line("line 11");
+    let buf = render_buffer(&lines, false, &mut state);
+    assert_eq!(state.line_offset(), 3);
+    assert_eq!(row(&buf, 0), "line 5");
+
+    // the top line itself is dropped.
+    for n in 12..20 {
+        lines.push_line(format!("line {}", n));
+    }
+    let buf = render_buffer(&lines, false, &mut state);
+    assert_eq!(state.line_offset(), 0);
+    assert_eq!(row(&buf, 0), "line 10");
+}
+
+#[test]
+fn test_buffer_wrap() {
+    let mut lines = StyledLinesBuffer::with_max_lines(3);
+    lines.push_line("aaaa bbbb cccc dddd");
+    lines.push_line("x");
+    lines.push_line("yyyy zzzz");
+
+    let mut state = ParagraphState::new();
+    let buf = render_buffer(&lines, true, &mut state);
+    assert_eq!(state.lines, 4);
+    assert_eq!(row(&buf, 0), "aaaa bbbb");
+    assert_eq!(row(&buf, 1), "cccc dddd");
+    assert_eq!(row(&buf, 2), "x");
+
+    // start in the middle of a wrapped line.
+    state.set_line_offset(1);
+    let buf = render_buffer(&lines, true, &mut state);
+    assert_eq!(row(&buf, 0), "cccc dddd");
+    assert_eq!(row(&buf, 2), "yyyy zzzz");
+
+    // the wrapped line is dropped, that's 2 rows.
+    lines.push_line("w");
+    assert_eq!(lines.evicted_rows(), 2);
+    let buf = render_buffer(&lines, true, &mut state);
+    assert_eq!(state.line_offset(), 0);
+    assert_eq!(row(&buf, 0), "x");
+}
+
+#[test]
+fn test_buffer_follow_tail() {
+    let mut lines = StyledLinesBuffer::with_max_lines(100);
+    let mut state = ParagraphState::new();
+    state.set_follow_tail(true);
+
+    for n in 0..150 {
+        lines.push_line(format!("line {}", n));
+        let buf = render_buffer(&lines, false, &mut state);
+        assert_eq!(row(&buf, min(n, 2) as u16), format!("line {}", n));
+    }
+    assert!(state.is_following());
+}
@@ -0,0 +1,143 @@
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, Popup};
+use rat_focus::FocusFlag;
+use rat_scrolled::Scroll;
+use rat_widget::choice::{Choice, ChoiceState};
+use rat_widget::clipper::{Clipper, ClipperState};
+use rat_widget::layout::GenericLayout;
+use rat_widget::view::{View, ViewState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn click(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].symbol())
+        .collect::<String>()
+}
+
+fn choice<'a>() -> Choice<'a, usize> {
+    Choice::new()
+        .item(1, "one")
+        .item(2, "two")
+        .item(3, "three")
+        .popup_len(3)
+}
+
+fn clipper_state(flag: &FocusFlag) -> ClipperState<FocusFlag> {
+    let mut state = ClipperState::new();
+    let mut layout = GenericLayout::new();
+    layout.add(flag.clone(), Rect::new(0, 10, 10, 1), None, Rect::default());
+    layout.add(
+        FocusFlag::named("bottom"),
+        Rect::new(0, 30, 10, 1),
+        None,
+        Rect::default(),
+    );
+    state.set_layout(layout);
+    state
+}
+
+fn render_clipper(
+    flag: &FocusFlag,
+    state: &mut ClipperState<FocusFlag>,
+    choice_state: &mut ChoiceState<usize>,
+) -> Buffer {
+    let area = Rect::new(0, 0, 20, 12);
+    let mut buf = Buffer::empty(area);
+    let mut clip = Clipper::new()
+        .vscroll(Scroll::new())
+        .into_buffer(area, state);
+    let (widget, popup) = choice().into_widgets();
+    clip.render(flag.clone(), || widget, choice_state);
+    clip.render_popup(flag.clone(), || Some(popup), choice_state);
+    clip.into_widget().render(area, &mut buf, state);
+    buf
+}
+
+#[test]
+fn test_clipper_popup() {
+    let flag = FocusFlag::named("choice");
+    let mut state = clipper_state(&flag);
+    let mut choice_state = ChoiceState::named("choice");
+    choice_state.set_popup_active(true);
+
+    // establishes the max offset.
+    _ = render_clipper(&flag, &mut state, &mut choice_state);
+    state.set_vertical_offset(5);
+    let buf = render_clipper(&flag, &mut state, &mut choice_state);
+
+    assert_eq!(choice_state.area.y, 5);
+    assert_eq!(choice_state.popup.area.y, 6);
+    assert_eq!(choice_state.item_areas.len(), 3);
+    assert_eq!(choice_state.item_areas[0].y, 6);
+    assert!(line(&buf, 6).contains("one"));
+    assert!(line(&buf, 8).contains("three"));
+
+    // select by mouse at the visible position.
+    choice_state.handle(&click(1, 7), Popup);
+    assert_eq!(choice_state.value(), 2);
+    choice_state.set_popup_active(true);
+
+    // scroll the widget out of view.
+    state.set_vertical_offset(11);
+    let buf = render_clipper(&flag, &mut state, &mut choice_state);
+
+    assert_eq!(choice_state.area, Rect::default());
+    assert_eq!(choice_state.popup.area, Rect::default());
+    assert!(choice_state.item_areas.iter().all(|v| v.is_empty()));
+    assert!(!line(&buf, 8).contains("three"));
+
+    // the stale popup area doesn't select anything.
+    choice_state.handle(&click(1, 8), Popup);
+    assert_eq!(choice_state.value(), 2);
+}
+
+fn render_view(state: &mut ViewState, choice_state: &mut ChoiceState<usize>) -> Buffer {
+    let area = Rect::new(0, 0, 20, 12);
+    let mut buf = Buffer::empty(area);
+    let mut view = View::new()
+        .layout(Rect::new(0, 0, 20, 30))
+        .vscroll(Scroll::new())
+        .into_buffer(area, state);
+    let (widget, popup) = choice().into_widgets();
+    let choice_area = Rect::new(0, 10, 10, 1);
+    view.render_stateful(widget, choice_area, choice_state);
+    view.render_popup(popup, choice_area, choice_state);
+    view.into_widget().render(area, &mut buf, state);
+    buf
+}
+
+#[test]
+fn test_view_popup() {
+    let mut state = ViewState::new();
+    let mut choice_state = ChoiceState::named("choice");
+    choice_state.set_popup_active(true);
+
+    _ = render_view(&mut state, &mut choice_state);
+    state.set_vertical_offset(5);
+    let buf = render_view(&mut state, &mut choice_state);
+
+    assert_eq!(choice_state.area.y, 5);
+    assert_eq!(choice_state.popup.area.y, 6);
+    assert_eq!(choice_state.item_areas[2].y, 8);
+    assert!(line(&buf, 8).contains("three"));
+
+    state.set_vertical_offset(15);
+    _ = render_view(&mut state, &mut choice_state);
+
+    assert_eq!(choice_state.area, Rect::default());
+    assert_eq!(choice_state.popup.area, Rect::default());
+
+    choice_state.handle(&click(1, 8), Popup);
+    assert_eq!(choice_state.value(), 0);
+}
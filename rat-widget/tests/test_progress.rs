@@ -0,0 +1,70 @@
+use rat_widget::progress::{ProgressBar, ProgressBarState, Spinner, SpinnerState, SPINNER_ASCII};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn row(buf: &Buffer, y: u16) -> String {
+    let mut s = String::new();
+    for x in buf.area.left()..buf.area.right() {
+        s.push_str(buf[(x, y)].symbol());
+    }
+    s
+}
+
+#[test]
+fn test_bar() {
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = ProgressBarState::new();
+
+    state.set_progress(45, 100);
+    ProgressBar::new().render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "████▌     ");
+    assert!(!state.is_complete());
+
+    state.set_progress(200, 100);
+    ProgressBar::new().render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "██████████");
+    assert!(state.is_complete());
+
+    state.set_progress(0, 0);
+    assert!(state.is_complete());
+}
+
+#[test]
+fn test_bar_percent() {
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = ProgressBarState::new();
+
+    state.set_ratio(0.0);
+    ProgressBar::new()
+        .percent(true)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "    0%    ");
+
+    ProgressBar::new()
+        .percent(true)
+        .label("wait")
+        .render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "   wait   ");
+}
+
+#[test]
+fn test_spinner() {
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = SpinnerState::new();
+
+    let spinner = Spinner::new().frames(SPINNER_ASCII).label("load");
+    spinner.clone().render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "| load    ");
+
+    state.tick();
+    spinner.clone().render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "/ load    ");
+
+    state.set_frame(5);
+    spinner.render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "/ load    ");
+}
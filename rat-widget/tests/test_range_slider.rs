@@ -0,0 +1,109 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Regular};
+use rat_widget::event::RangeSliderOutcome;
+use rat_widget::slider::{RangeCollision, RangeKnob, RangeSlider, RangeSliderState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut RangeSliderState<u8>) -> Buffer {
+    let area = Rect::new(0, 0, 23, 1);
+    let mut buf = Buffer::empty(area);
+    RangeSlider::new()
+        .range_style(Style::new().bg(Color::Green))
+        .render(area, &mut buf, state);
+    buf
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn state(collision: RangeCollision) -> RangeSliderState<u8> {
+    let mut state = RangeSliderState::new_range((0, 10), 1);
+    state.set_collision(collision);
+    state.set_value((4, 6));
+    state.focus.set(true);
+    render(&mut state);
+    state
+}
+
+#[test]
+fn test_value() {
+    let mut state = RangeSliderState::<u8>::new_range((0, 10), 1);
+    assert_eq!(state.value(), (0, 10));
+    state.set_value((8, 2));
+    assert_eq!(state.value(), (2, 8));
+}
+
+#[test]
+fn test_block() {
+    let mut state = state(RangeCollision::Block);
+
+    let r = state.handle(&key(KeyCode::Right), Regular);
+    assert_eq!(r, RangeSliderOutcome::Value(RangeKnob::Low, (5, 6)));
+    let r = state.handle(&key(KeyCode::Right), Regular);
+    assert_eq!(r, RangeSliderOutcome::Value(RangeKnob::Low, (6, 6)));
+    let r = state.handle(&key(KeyCode::Right), Regular);
+    assert_eq!(r, RangeSliderOutcome::Unchanged);
+
+    let r = state.handle(&key(KeyCode::Char(' ')), Regular);
+    assert_eq!(r, RangeSliderOutcome::Changed);
+    assert_eq!(state.active(), RangeKnob::High);
+    let r = state.handle(&key(KeyCode::End), Regular);
+    assert_eq!(r, RangeSliderOutcome::Value(RangeKnob::High, (6, 10)));
+}
+
+#[test]
+fn test_push() {
+    let mut state = state(RangeCollision::Push);
+
+    state.handle(&key(KeyCode::Right), Regular);
+    state.handle(&key(KeyCode::Right), Regular);
+    let r = state.handle(&key(KeyCode::Right), Regular);
+    assert_eq!(r, RangeSliderOutcome::Value(RangeKnob::Low, (7, 7)));
+    let r = state.handle(&key(KeyCode::End), Regular);
+    assert_eq!(r, RangeSliderOutcome::Value(RangeKnob::Low, (10, 10)));
+}
+
+#[test]
+fn test_mouse() {
+    let mut state = state(RangeCollision::Block);
+
+    // near the high knob
+    let x = state.high.knob.right() + 2;
+    let r = state.handle(&click(x, 0), Regular);
+    assert_eq!(state.active(), RangeKnob::High);
+    assert!(matches!(r, RangeSliderOutcome::Value(RangeKnob::High, _)));
+    assert!(state.value().1 > 6);
+
+    // near the low knob
+    let r = state.handle(&click(0, 0), Regular);
+    assert_eq!(
+        r,
+        RangeSliderOutcome::Value(RangeKnob::Low, (0, state.value().1))
+    );
+}
+
+#[test]
+fn test_render() {
+    let mut state = state(RangeCollision::Block);
+    let buf = render(&mut state);
+
+    let x = state.low.knob.right();
+    assert!(x < state.high.knob.x);
+    assert_eq!(buf[(x, 0)].bg, Color::Green);
+    assert_ne!(buf[(state.low.track.x, 0)].bg, Color::Green);
+}
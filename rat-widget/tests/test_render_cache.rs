@@ -0,0 +1,139 @@
+use rat_widget::clipper::{Clipper, ClipperState};
+use rat_widget::layout::GenericLayout;
+use rat_widget::paragraph::{Paragraph, ParagraphState};
+use rat_widget::view::{View, ViewState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::Style;
+use ratatui::widgets::{StatefulWidget, Widget};
+use std::cell::Cell;
+
+struct Counted<'a>(&'a Cell<usize>);
+
+impl Widget for Counted<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.0.set(self.0.get() + 1);
+        buf.set_string(area.x, area.y, "counted", Style::default());
+    }
+}
+
+fn render_view(
+    area: Rect,
+    key: Option<u64>,
+    state: &mut ViewState,
+    para: &mut ParagraphState,
+    count: &Cell<usize>,
+) -> (bool, Buffer) {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 40, 20));
+    let mut view = View::new().layout(Rect::new(0, 0, 60, 60));
+    if let Some(key) = key {
+        view = view.cache_key(key);
+    }
+    let mut view_buf = view.into_buffer(area, state);
+    let cached = view_buf.is_cached();
+    view_buf.render_widget(Counted(count), Rect::new(1, 1, 10, 1));
+    view_buf.render_stateful(Paragraph::new("text"), Rect::new(2, 3, 10, 2), para);
+    view_buf.into_widget().render(area, &mut buf, state);
+    (cached, buf)
+}
+
+#[test]
+fn test_view_cache() {
+    let count = Cell::new(0);
+    let mut state = ViewState::new();
+    let mut para = ParagraphState::new();
+    let area = Rect::new(5, 5, 30, 10);
+
+    let (cached, buf1) = render_view(area, Some(1), &mut state, &mut para, &count);
+    assert!(!cached);
+    assert_eq!(count.get(), 1);
+    assert_eq!(para.area, Rect::new(7, 8, 10, 2));
+
+    // same key
+    let (cached, buf2) = render_view(area, Some(1), &mut state, &mut para, &count);
+    assert!(cached);
+    assert_eq!(count.get(), 1);
+    assert_eq!(buf1, buf2);
+    assert_eq!(para.area, Rect::new(7, 8, 10, 2));
+
+    // new key
+    let (cached, _) = render_view(area, Some(2), &mut state, &mut para, &count);
+    assert!(!cached);
+    assert_eq!(count.get(), 2);
+
+    // scrolling
+    state.scroll_down(1);
+    let (cached, _) = render_view(area, Some(2), &mut state, &mut para, &count);
+    assert!(!cached);
+    assert_eq!(count.get(), 3);
+    assert_eq!(para.area, Rect::new(7, 7, 10, 2));
+
+    // resize
+    let (cached, _) = render_view(
+        Rect::new(5, 5, 20, 10),
+        Some(2),
+        &mut state,
+        &mut para,
+        &count,
+    );
+    assert!(!cached);
+    assert_eq!(count.get(), 4);
+
+    // explicit
+    state.invalidate();
+    let (cached, _) = render_view(
+        Rect::new(5, 5, 20, 10),
+        Some(2),
+        &mut state,
+        &mut para,
+        &count,
+    );
+    assert!(!cached);
+    assert_eq!(count.get(), 5);
+
+    // no key
+    render_view(area, None, &mut state, &mut para, &count);
+    let (cached, _) = render_view(area, None, &mut state, &mut para, &count);
+    assert!(!cached);
+    assert_eq!(count.get(), 7);
+}
+
+fn render_clipper(
+    key: u64,
+    state: &mut ClipperState<usize>,
+    para: &mut ParagraphState,
+    count: &Cell<usize>,
+) -> bool {
+    let area = Rect::new(0, 0, 30, 10);
+    let mut buf = Buffer::empty(area);
+    let mut clip_buf = Clipper::new().cache_key(key).into_buffer(area, state);
+    let cached = clip_buf.is_cached();
+    assert!(clip_buf.render_widget(0, || Counted(count)));
+    assert!(clip_buf.render(1, || Paragraph::new("text"), para));
+    clip_buf.into_widget().render(area, &mut buf, state);
+    cached
+}
+
+#[test]
+fn test_clipper_cache() {
+    let count = Cell::new(0);
+    let mut state = ClipperState::new();
+    let mut para = ParagraphState::new();
+
+    let mut layout = GenericLayout::new();
+    layout.set_page_size(Size::new(30, u16::MAX));
+    layout.add(0, Rect::new(0, 0, 10, 1), None, Rect::default());
+    layout.add(1, Rect::new(0, 2, 10, 2), None, Rect::default());
+    state.set_layout(layout.clone());
+
+    assert!(!render_clipper(1, &mut state, &mut para, &count));
+    assert_eq!(count.get(), 1);
+    assert!(render_clipper(1, &mut state, &mut para, &count));
+    assert_eq!(count.get(), 1);
+    assert_eq!(para.area, Rect::new(0, 2, 10, 2));
+
+    // a new layout invalidates
+    state.set_layout(layout);
+    assert!(!render_clipper(1, &mut state, &mut para, &count));
+    assert_eq!(count.get(), 2);
+}
@@ -0,0 +1,94 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_event::{HandleEvent, Outcome, Regular};
+use rat_focus::{FocusBuilder, HasFocus};
+use rat_widget::scroll_pane::{ScrollPane, ScrollPaneState};
+use rat_widget::scrolled::Scroll;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, StatefulWidget};
+
+fn render(state: &mut ScrollPaneState) -> Buffer {
+    let area = Rect::new(0, 0, 20, 7);
+    let mut buf = Buffer::empty(area);
+    ScrollPane::new()
+        .block(Block::bordered())
+        .vscroll(Scroll::new())
+        .content_len(20)
+        .focus_style(Style::new().fg(Color::Yellow))
+        .render(area, &mut buf, state);
+    buf
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn wheel_down(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_keys() {
+    let mut state = ScrollPaneState::named("help");
+    render(&mut state);
+    assert_eq!(state.inner.height, 5);
+    assert_eq!(state.vscroll.max_offset(), 15);
+
+    // not focused.
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        Outcome::Continue
+    );
+
+    state.focus.set(true);
+    assert_eq!(state.handle(&key(KeyCode::Down), Regular), Outcome::Changed);
+    assert_eq!(state.offset(), 1);
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown), Regular),
+        Outcome::Changed
+    );
+    assert_eq!(state.offset(), 4);
+    assert_eq!(state.handle(&key(KeyCode::End), Regular), Outcome::Changed);
+    assert_eq!(state.offset(), 15);
+    assert_eq!(
+        state.handle(&key(KeyCode::End), Regular),
+        Outcome::Unchanged
+    );
+    assert_eq!(state.handle(&key(KeyCode::Home), Regular), Outcome::Changed);
+    assert_eq!(state.offset(), 0);
+
+    // nothing else.
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Regular),
+        Outcome::Continue
+    );
+}
+
+#[test]
+fn test_wheel() {
+    let mut state = ScrollPaneState::new();
+    render(&mut state);
+    let r = state.handle(&wheel_down(2, 2), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert!(state.offset() > 0);
+}
+
+#[test]
+fn test_focus() {
+    let mut state = ScrollPaneState::named("help");
+    let buf = render(&mut state);
+    assert_ne!(buf[(0, 0)].fg, Color::Yellow);
+
+    let focus = FocusBuilder::build_for(&state);
+    focus.first();
+    assert!(state.is_focused());
+
+    let buf = render(&mut state);
+    assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+}
@@ -0,0 +1,166 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, MouseOnly, Regular};
+use rat_widget::event::SplitOutcome;
+use rat_widget::splitter::{Split, SplitState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut SplitState, width: u16) {
+    let area = Rect::new(0, 0, width, 10);
+    let mut buf = Buffer::empty(area);
+    let (split, _overlay) = Split::horizontal()
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ])
+        .into_widgets();
+    split.render(area, &mut buf, state);
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+#[test]
+fn test_layout_same_size() {
+    let mut state = SplitState::new();
+    render(&mut state, 90);
+    state.set_split_pos(0, 20);
+    let layout = state.layout();
+    assert_eq!(layout, vec![20, 40, 30]);
+
+    let mut state = SplitState::new();
+    render(&mut state, 90);
+    state.set_layout(&layout);
+    assert_eq!(state.area_lengths(), &[20, 40, 30]);
+    render(&mut state, 90);
+    assert_eq!(state.area_lengths(), &[20, 40, 30]);
+}
+
+#[test]
+fn test_layout_smaller() {
+    let layout = vec![20, 40, 30];
+
+    // restored after the first render.
+    let mut state = SplitState::new();
+    render(&mut state, 45);
+    state.set_layout(&layout);
+    assert_eq!(state.area_lengths(), &[10, 20, 15]);
+    assert_eq!(state.total_area_len(), 45);
+
+    // restored before the first render.
+    let mut state = SplitState::new();
+    state.set_layout(&layout);
+    render(&mut state, 45);
+    assert_eq!(state.total_area_len(), 45);
+    assert_eq!(state.area_lengths(), &[10, 20, 15]);
+
+    // and back again.
+    let small = state.layout();
+    let mut state = SplitState::new();
+    render(&mut state, 90);
+    state.set_layout(&small);
+    assert_eq!(state.area_lengths(), &[20, 40, 30]);
+}
+
+#[test]
+fn test_layout_rounding() {
+    let mut state = SplitState::new();
+    render(&mut state, 10);
+    state.set_layout(&[1, 0, 99]);
+    assert_eq!(state.total_area_len(), 10);
+    assert_eq!(state.area_lengths(), &[1, 0, 9]);
+
+    state.set_layout(&[33, 33, 34]);
+    assert_eq!(state.total_area_len(), 10);
+    assert_eq!(state.area_lengths(), &[3, 3, 4]);
+}
+
+#[test]
+fn test_keyboard() {
+    let mut state = SplitState::new();
+    state.focus.set(true);
+    render(&mut state, 90);
+    assert_eq!(state.focus_marker, Some(0));
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Right, KeyModifiers::NONE), Regular),
+        SplitOutcome::Resized(0, 31)
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Left, KeyModifiers::SHIFT), Regular),
+        SplitOutcome::Resized(0, 26)
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Esc, KeyModifiers::NONE), Regular),
+        SplitOutcome::Leave
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter, KeyModifiers::NONE), Regular),
+        SplitOutcome::Leave
+    );
+
+    state.focus.set(false);
+    assert_eq!(
+        state.handle(&key(KeyCode::Right, KeyModifiers::NONE), Regular),
+        SplitOutcome::Continue
+    );
+}
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_drag() {
+    let mut state = SplitState::new();
+    render(&mut state, 90);
+    let split = state.splitline_areas[0];
+    let pos = state.split_pos(0);
+
+    // grabbing the split doesn't move it.
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), split.x, 5),
+        MouseOnly,
+    );
+    assert_eq!(r, SplitOutcome::Changed);
+    let r = state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), split.x + 3, 5),
+        MouseOnly,
+    );
+    assert_eq!(r, SplitOutcome::Resized(0, pos + 3));
+    let r = state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), split.x - 2, 6),
+        MouseOnly,
+    );
+    assert_eq!(r, SplitOutcome::Resized(0, pos - 2));
+    let r = state.handle(
+        &mouse(MouseEventKind::Up(MouseButton::Left), split.x - 2, 6),
+        MouseOnly,
+    );
+    assert_eq!(r, SplitOutcome::Changed);
+    assert_eq!(state.split_pos(0), pos - 2);
+
+    // esc restores the position
+    render(&mut state, 90);
+    let split = state.splitline_areas[0];
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), split.x, 5),
+        MouseOnly,
+    );
+    state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), split.x + 5, 5),
+        MouseOnly,
+    );
+    let r = state.handle(&key(KeyCode::Esc, KeyModifiers::NONE), MouseOnly);
+    assert_eq!(r, SplitOutcome::Resized(0, pos - 2));
+}
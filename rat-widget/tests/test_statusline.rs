@@ -0,0 +1,106 @@
+use rat_widget::statusline::{StatusLine, StatusLineState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::StatefulWidget;
+
+fn row(buf: &Buffer, y: u16) -> String {
+    let mut s = String::new();
+    for x in buf.area.left()..buf.area.right() {
+        s.push_str(buf[(x, y)].symbol());
+    }
+    s
+}
+
+fn status() -> StatusLineState {
+    let mut state = StatusLineState::new();
+    state.status(0, "start");
+    state.status(1, "center");
+    state.status(2, "R 12ms");
+    state
+}
+
+fn render(widget: StatusLine, width: u16, state: &mut StatusLineState) -> String {
+    let area = Rect::new(0, 0, width, 1);
+    let mut buf = Buffer::empty(area);
+    widget.render(area, &mut buf, state);
+    row(&buf, 0)
+}
+
+fn widget() -> StatusLine {
+    StatusLine::new()
+        .layout([
+            Constraint::Length(6),
+            Constraint::Fill(1),
+            Constraint::Length(6),
+        ])
+        .min_widths([6, 7, 6])
+        .priorities([2, 0, 1])
+}
+
+#[test]
+fn test_all_visible() {
+    let mut state = status();
+    let r = render(widget(), 20, &mut state);
+    assert_eq!(r, "start center  R 12ms");
+    assert!(state.collapsed.is_empty());
+}
+
+#[test]
+fn test_center_first() {
+    let mut state = status();
+    let r = render(widget(), 18, &mut state);
+    assert_eq!(r, "start R 12ms      ");
+    assert_eq!(state.collapsed, vec![1]);
+    assert_eq!(state.areas[1].width, 0);
+}
+
+#[test]
+fn test_end_second() {
+    let mut state = status();
+    let r = render(widget(), 8, &mut state);
+    assert_eq!(r, "start   ");
+    assert_eq!(state.collapsed, vec![1, 2]);
+}
+
+#[test]
+fn test_all_collapsed() {
+    let mut state = status();
+    let r = render(widget(), 4, &mut state);
+    assert_eq!(r, "    ");
+    assert_eq!(state.collapsed, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_default_priority() {
+    // equal priority: the end survives the longest.
+    let mut state = status();
+    let r = render(
+        StatusLine::new().layout([
+            Constraint::Length(6),
+            Constraint::Length(7),
+            Constraint::Length(6),
+        ]),
+        14,
+        &mut state,
+    );
+    assert_eq!(r, "center R 12ms ");
+    assert_eq!(state.collapsed, vec![0]);
+}
+
+#[test]
+fn test_ellipsis() {
+    let mut state = status();
+    let r = render(widget().ellipsis(true), 16, &mut state);
+    assert_eq!(r, "start cen…R 12ms");
+    assert_eq!(state.collapsed, vec![1]);
+
+    let mut state = status();
+    let r = render(widget().ellipsis(true), 15, &mut state);
+    assert_eq!(r, "start ce…R 12ms");
+    assert_eq!(state.collapsed, vec![1]);
+
+    let mut state = status();
+    let r = render(widget().ellipsis(true), 13, &mut state);
+    assert_eq!(r, "start R 12ms ");
+    assert_eq!(state.collapsed, vec![1]);
+}
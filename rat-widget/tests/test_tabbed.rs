@@ -0,0 +1,135 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Regular};
+use rat_focus::{Focus, FocusBuilder};
+use rat_widget::button::ButtonState;
+use rat_widget::event::TabbedOutcome;
+use rat_widget::tabbed::{TabType, Tabbed, TabbedState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn render(state: &mut TabbedState) {
+    let area = Rect::new(0, 0, 40, 10);
+    let mut buf = Buffer::empty(area);
+    Tabbed::new()
+        .tab_type(TabType::Glued)
+        .tabs(["One", "Two", "Three"])
+        .render(area, &mut buf, state);
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(area: Rect) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: area.x,
+        row: area.y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn state(confirm: bool) -> TabbedState {
+    let mut state = TabbedState::named("tabs");
+    state.confirm_select = confirm;
+    state.focus.set(true);
+    render(&mut state);
+    state
+}
+
+#[test]
+fn test_select() {
+    let mut state = state(false);
+    assert_eq!(state.selected(), Some(0));
+
+    let r = state.handle(&key(KeyCode::Right), Regular);
+    assert_eq!(r, TabbedOutcome::Changed);
+    assert_eq!(state.selected(), Some(1));
+
+    let area = state.tab_title_areas[2];
+    let r = state.handle(&click(area), Regular);
+    assert_eq!(r, TabbedOutcome::Select(2));
+    assert_eq!(state.selected(), Some(2));
+}
+
+#[test]
+fn test_will_select() {
+    let mut state = state(true);
+
+    // veto
+    let r = state.handle(&key(KeyCode::Right), Regular);
+    assert_eq!(r, TabbedOutcome::WillSelect(1));
+    assert_eq!(state.selected(), Some(0));
+
+    // nowhere to go
+    let r = state.handle(&key(KeyCode::Left), Regular);
+    assert_eq!(r, TabbedOutcome::Unchanged);
+    assert_eq!(state.selected(), Some(0));
+
+    // accept
+    let area = state.tab_title_areas[2];
+    let r = state.handle(&click(area), Regular);
+    assert_eq!(r, TabbedOutcome::WillSelect(2));
+    assert_eq!(state.selected(), Some(0));
+    state.select(Some(2));
+    assert_eq!(state.selected(), Some(2));
+
+    // no change
+    let r = state.handle(&click(area), Regular);
+    assert_eq!(r, TabbedOutcome::Unchanged);
+
+    let r = state.handle(&key(KeyCode::Right), Regular);
+    assert_eq!(r, TabbedOutcome::Unchanged);
+}
+
+struct Tabs {
+    tabbed: TabbedState,
+    tab0: ButtonState,
+    tab1: ButtonState,
+    menu: ButtonState,
+}
+
+fn focus(tabs: &Tabs) -> Focus {
+    let mut fb = FocusBuilder::new(None);
+    fb.widget(&tabs.tabbed);
+    fb.widget_if(tabs.tabbed.is_active(0), &tabs.tab0);
+    fb.widget_if(tabs.tabbed.is_active(1), &tabs.tab1);
+    fb.widget(&tabs.menu);
+    fb.build()
+}
+
+fn focus_cycle(focus: &Focus) -> Vec<String> {
+    let mut names = Vec::new();
+    focus.first();
+    for _ in 0..3 {
+        names.push(focus.focused_name().unwrap_or_default());
+        focus.next();
+    }
+    names
+}
+
+#[test]
+fn test_focus_rebuild() {
+    let mut tabs = Tabs {
+        tabbed: TabbedState::named("tabs"),
+        tab0: ButtonState::named("tab0"),
+        tab1: ButtonState::named("tab1"),
+        menu: ButtonState::named("menu"),
+    };
+    tabs.tab0.area = Rect::new(0, 2, 10, 1);
+    tabs.tab1.area = Rect::new(0, 2, 10, 1);
+    tabs.menu.area = Rect::new(0, 9, 10, 1);
+    render(&mut tabs.tabbed);
+
+    assert!(tabs.tabbed.is_active(0));
+    assert!(!tabs.tabbed.is_active(1));
+    assert_eq!(focus_cycle(&focus(&tabs)), ["tab0", "menu", "tab0"]);
+
+    tabs.tabbed.select(Some(1));
+    assert!(!tabs.tabbed.is_active(0));
+    assert!(tabs.tabbed.is_active(1));
+    assert_eq!(focus_cycle(&focus(&tabs)), ["tab1", "menu", "tab1"]);
+}
@@ -0,0 +1,133 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, Outcome, Regular};
+use rat_widget::toast::{Severity, Toast, ToastCorner, Toasts, ToastsState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, StatefulWidget};
+use std::time::{Duration, Instant};
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn render(state: &mut ToastsState) -> Buffer {
+    let area = Rect::new(0, 0, 30, 10);
+    let mut buf = Buffer::empty(area);
+    Toasts::new()
+        .block(Block::bordered())
+        .corner(ToastCorner::BottomRight)
+        .max_visible(2)
+        .render(area, &mut buf, state);
+    buf
+}
+
+#[test]
+fn test_expire() {
+    let now = Instant::now();
+    let mut state = ToastsState::new();
+    state.max_visible = 2;
+
+    state.push(Toast::new("a", Severity::Info).created_at(now));
+    state.push(
+        Toast::new("b", Severity::Warning)
+            .created_at(now)
+            .duration(Duration::from_secs(10)),
+    );
+    state.push(Toast::new("c", Severity::Error).created_at(now));
+    assert_eq!(state.visible(), vec![2, 1]);
+
+    // nothing expired
+    assert_eq!(
+        state.expire(now + Duration::from_secs(1)),
+        Outcome::Continue
+    );
+    // a and c expire, only c was visible
+    assert_eq!(state.expire(now + Duration::from_secs(6)), Outcome::Changed);
+    assert_eq!(state.visible(), vec![1]);
+    assert_eq!(
+        state.expire(now + Duration::from_secs(7)),
+        Outcome::Continue
+    );
+    assert_eq!(
+        state.expire(now + Duration::from_secs(10)),
+        Outcome::Changed
+    );
+    assert!(state.is_empty());
+}
+
+#[test]
+fn test_hidden_expire() {
+    let now = Instant::now();
+    let mut state = ToastsState::new();
+    state.max_visible = 1;
+
+    state.push(Toast::new("a", Severity::Info).created_at(now));
+    state.push(
+        Toast::new("b", Severity::Info)
+            .created_at(now)
+            .duration(Duration::from_secs(10)),
+    );
+    // a is not visible
+    assert_eq!(
+        state.expire(now + Duration::from_secs(6)),
+        Outcome::Continue
+    );
+    assert_eq!(state.len(), 1);
+}
+
+#[test]
+fn test_render() {
+    let mut state = ToastsState::new();
+    state.push(Toast::new("first", Severity::Info));
+    state.push(Toast::new("second", Severity::Info));
+    state.push(Toast::new("third", Severity::Info));
+    render(&mut state);
+
+    // newest in the corner
+    assert_eq!(
+        state.toast_areas,
+        vec![Rect::new(21, 7, 9, 3), Rect::new(20, 4, 10, 3)]
+    );
+    assert_eq!(
+        state.close_areas,
+        vec![Rect::new(28, 8, 1, 1), Rect::new(28, 5, 1, 1)]
+    );
+}
+
+#[test]
+fn test_dismiss() {
+    let mut state = ToastsState::new();
+    state.push(Toast::new("first", Severity::Info));
+    state.push(Toast::new("second", Severity::Info));
+    render(&mut state);
+
+    // esc without hover
+    let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(state.handle(&esc, Regular), Outcome::Continue);
+
+    // hover the older one
+    assert_eq!(
+        state.handle(&mouse(MouseEventKind::Moved, 22, 5), Regular),
+        Outcome::Continue
+    );
+    assert_eq!(state.handle(&esc, Regular), Outcome::Changed);
+    assert_eq!(state.visible(), vec![1]);
+    render(&mut state);
+
+    // close button
+    assert_eq!(
+        state.handle(
+            &mouse(MouseEventKind::Down(MouseButton::Left), 28, 8),
+            Regular
+        ),
+        Outcome::Changed
+    );
+    assert!(state.is_empty());
+}
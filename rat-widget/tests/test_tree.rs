@@ -0,0 +1,157 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::HandleEvent;
+use rat_event::Regular;
+use rat_widget::event::TreeOutcome;
+use rat_widget::tree::{Tree, TreeNode, TreeState, TreeStore};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn node(id: &str, leaf: bool) -> TreeNode<String> {
+    TreeNode {
+        id: id.to_string(),
+        label: id.to_string().into(),
+        leaf,
+    }
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn render(store: &TreeStore<String>, state: &mut TreeState<String>) -> Vec<String> {
+    let area = Rect::new(0, 0, 12, 4);
+    let mut buf = Buffer::empty(area);
+    Tree::new(store).render(area, &mut buf, state);
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+fn id(s: &str) -> String {
+    s.to_string()
+}
+
+#[test]
+fn test_lazy() {
+    let mut store = TreeStore::new();
+    store.set_children(None, [node("a", false), node("b", true)]);
+
+    let mut state = TreeState::named("tree");
+    state.focus.set(true);
+
+    assert_eq!(render(&store, &mut state), vec!["▶ a", "  b", "", ""]);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        TreeOutcome::Select(id("a"))
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        TreeOutcome::Load(id("a"))
+    );
+    store.set_children(Some(id("a")), [node("a1", true), node("a2", false)]);
+    assert_eq!(
+        render(&store, &mut state),
+        vec!["▼ a", "    a1", "  ▶ a2", "  b"]
+    );
+
+    // already expanded, go to first child
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        TreeOutcome::Select(id("a1"))
+    );
+    // leaf, go to parent
+    assert_eq!(
+        state.handle(&key(KeyCode::Left), Regular),
+        TreeOutcome::Select(id("a"))
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Left), Regular),
+        TreeOutcome::Collapse(id("a"))
+    );
+    assert_eq!(render(&store, &mut state), vec!["▶ a", "  b", "", ""]);
+    // known children
+    assert_eq!(
+        state.handle(&key(KeyCode::Right), Regular),
+        TreeOutcome::Expand(id("a"))
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Regular),
+        TreeOutcome::Activate(id("a"))
+    );
+}
+
+#[test]
+fn test_collapse_selection() {
+    let mut store = TreeStore::new();
+    store.set_children(None, [node("a", false)]);
+    store.set_children(Some(id("a")), [node("a1", false)]);
+    store.set_children(Some(id("a1")), [node("a11", true)]);
+
+    let mut state = TreeState::new();
+    state.expand(id("a"));
+    state.expand(id("a1"));
+    state.select(Some(id("a11")));
+    render(&store, &mut state);
+    assert_eq!(state.selected_row(), Some(2));
+
+    state.collapse(&id("a"));
+    assert_eq!(state.selected(), Some(&id("a")));
+}
+
+#[test]
+fn test_mouse() {
+    let mut store = TreeStore::new();
+    store.set_children(None, [node("a", false), node("b", true)]);
+    store.set_children(Some(id("a")), []);
+
+    let mut state = TreeState::new();
+    render(&store, &mut state);
+
+    // expander
+    assert_eq!(
+        state.handle(&click(0, 0), Regular),
+        TreeOutcome::Expand(id("a"))
+    );
+    assert_eq!(state.selected(), None);
+    // label
+    assert_eq!(
+        state.handle(&click(3, 1), Regular),
+        TreeOutcome::Select(id("b"))
+    );
+}
+
+#[test]
+fn test_scroll() {
+    let mut store = TreeStore::new();
+    store.set_children(None, (0..10).map(|v| node(&v.to_string(), true)));
+
+    let mut state = TreeState::new();
+    state.focus.set(true);
+    render(&store, &mut state);
+
+    for _ in 0..6 {
+        state.handle(&key(KeyCode::Down), Regular);
+    }
+    assert_eq!(state.selected(), Some(&id("5")));
+    assert_eq!(state.offset(), 2);
+    assert_eq!(render(&store, &mut state), vec!["  2", "  3", "  4", "  5"]);
+}